@@ -3,6 +3,8 @@ use rustc_hash::FxHashSet;
 use std::hash::Hash;
 use std::{fmt::Debug, sync::Arc};
 
+use biome_console::MarkupBuf;
+use biome_diagnostics::Applicability;
 use biome_rowan::{AnySyntaxNode, Language, RawSyntaxKind, SyntaxKind, SyntaxNode, WalkEvent};
 
 use crate::matcher::SignalRuleKey;
@@ -20,13 +22,52 @@ pub trait AnalyzerPlugin: Debug + Send + Sync {
 
     fn query(&self) -> Vec<RawSyntaxKind>;
 
-    fn evaluate(&self, node: AnySyntaxNode, path: Arc<Utf8PathBuf>) -> Vec<RuleDiagnostic>;
+    fn evaluate(&self, node: AnySyntaxNode, path: Arc<Utf8PathBuf>) -> Vec<PluginEvaluationResult>;
+}
+
+/// A diagnostic reported by an analyzer plugin, with the fix it offers, if any.
+#[derive(Debug)]
+pub struct PluginEvaluationResult {
+    pub diagnostic: RuleDiagnostic,
+    pub action: Option<PluginAction>,
+}
+
+impl PluginEvaluationResult {
+    pub fn with_action(mut self, action: PluginAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+}
+
+impl From<RuleDiagnostic> for PluginEvaluationResult {
+    fn from(diagnostic: RuleDiagnostic) -> Self {
+        Self {
+            diagnostic,
+            action: None,
+        }
+    }
+}
+
+/// A code fix offered by an analyzer plugin.
+///
+/// The plugin provides the evaluated node with the fix applied, and the
+/// analyzer turns the differences between the two trees into a code action.
+#[derive(Debug)]
+pub struct PluginAction {
+    pub applicability: Applicability,
+    pub message: MarkupBuf,
+    /// The evaluated node, with the fix applied. It must be a node of the same
+    /// language as the evaluated node.
+    pub fixed_node: AnySyntaxNode,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum PluginTargetLanguage {
     JavaScript,
     Css,
+    Json,
+    Html,
+    Graphql,
 }
 
 /// A syntax visitor that queries nodes and evaluates in a plugin.
@@ -103,15 +144,21 @@ where
             .plugin
             .evaluate(node.clone().into(), ctx.options.file_path.clone())
             .into_iter()
-            .map(|diagnostic| {
+            .map(|PluginEvaluationResult { diagnostic, action }| {
                 let name = diagnostic
                     .subcategory
                     .clone()
                     .unwrap_or_else(|| "anonymous".into());
+                let text_range = diagnostic.span().unwrap_or_default();
+
+                let mut signal = PluginSignal::<L>::new(diagnostic);
+                if let Some(action) = action {
+                    signal = signal.with_action(node.clone(), action);
+                }
 
                 SignalEntry {
-                    text_range: diagnostic.span().unwrap_or_default(),
-                    signal: Box::new(PluginSignal::<L>::new(diagnostic)),
+                    text_range,
+                    signal: Box::new(signal),
                     rule: SignalRuleKey::Plugin(name.into()),
                     category: RuleCategory::Lint,
                     instances: Default::default(),
@@ -134,6 +134,18 @@ impl AnalyzerDiagnostic {
         self
     }
 
+    /// Adds the given tags to the diagnostic
+    pub fn with_tags(mut self, tags: DiagnosticTags) -> Self {
+        self.kind = match self.kind {
+            DiagnosticKind::Rule(mut rule_diagnostic) => {
+                rule_diagnostic.tags |= tags;
+                DiagnosticKind::Rule(rule_diagnostic)
+            }
+            DiagnosticKind::Raw(error) => DiagnosticKind::Raw(error.with_tags(tags)),
+        };
+        self
+    }
+
     /// The location of the diagnostic is shifted using this offset.
     /// This is only applied when the `Self::kind` is [DiagnosticKind::Rule]
     pub fn add_diagnostic_offset(&mut self, offset: TextSize) {
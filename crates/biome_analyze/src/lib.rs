@@ -28,7 +28,8 @@ mod visitor;
 pub use biome_diagnostics::category_concat;
 
 pub use crate::analyzer_plugin::{
-    AnalyzerPlugin, AnalyzerPluginSlice, AnalyzerPluginVec, PluginTargetLanguage, PluginVisitor,
+    AnalyzerPlugin, AnalyzerPluginSlice, AnalyzerPluginVec, PluginAction, PluginEvaluationResult,
+    PluginTargetLanguage, PluginVisitor,
 };
 pub use crate::categories::{
     ActionCategory, OtherActionCategory, RefactorKind, RuleCategories, RuleCategoriesBuilder,
@@ -52,6 +53,7 @@ pub use crate::rule::{
 pub use crate::services::{
     ExtendedConfigurationProvider, FromServices, ServiceBag, ServicesDiagnostic,
 };
+use crate::signals::SuppressedSignal;
 pub use crate::signals::{
     AnalyzerAction, AnalyzerSignal, AnalyzerTransformation, DiagnosticSignal, PluginSignal,
 };
@@ -399,38 +401,40 @@ where
                 break;
             }
 
-            if self
+            let is_category_suppressed = self
                 .suppressions
                 .top_level_suppression
                 .suppressed_categories
-                .contains(entry.category)
-            {
-                self.signal_queue.pop();
-                continue;
-            }
-
-            let is_suppressed = match &entry.rule {
-                SignalRuleKey::Rule(rule) => {
-                    self.suppressions
-                        .top_level_suppression
-                        .contains_rule_key(&entry.category, rule)
-                        || self.suppressions.range_suppressions.suppress_rule(
-                            &entry.category,
-                            rule,
-                            &entry.text_range,
-                        )
-                }
-                SignalRuleKey::Plugin(plugin) => {
-                    self.suppressions
-                        .top_level_suppression
-                        .suppressed_plugin(plugin)
-                        || self
-                            .suppressions
-                            .range_suppressions
-                            .suppress_plugin(plugin.as_ref(), &entry.text_range)
-                }
-            };
+                .contains(entry.category);
+
+            let is_suppressed = is_category_suppressed
+                || match &entry.rule {
+                    SignalRuleKey::Rule(rule) => {
+                        self.suppressions
+                            .top_level_suppression
+                            .contains_rule_key(&entry.category, rule)
+                            || self.suppressions.range_suppressions.suppress_rule(
+                                &entry.category,
+                                rule,
+                                &entry.text_range,
+                            )
+                    }
+                    SignalRuleKey::Plugin(plugin) => {
+                        self.suppressions
+                            .top_level_suppression
+                            .suppressed_plugin(plugin)
+                            || self
+                                .suppressions
+                                .range_suppressions
+                                .suppress_plugin(plugin.as_ref(), &entry.text_range)
+                    }
+                };
             if is_suppressed {
+                if self.options.emit_suppressed_signals()
+                    && range_match(self.range, entry.text_range)
+                {
+                    (self.emit_signal)(&SuppressedSignal::new(&*entry.signal))?;
+                }
                 self.signal_queue.pop();
                 continue;
             }
@@ -492,9 +496,13 @@ where
 
             // If the signal is being suppressed, mark the line suppression as
             // hit, otherwise emit the signal
-            if !is_fully_suppressed && range_match(self.range, entry.text_range) {
-                // TODO: would be nice to remove suppressed instances, if any, before emitting
-                (self.emit_signal)(&*entry.signal)?;
+            if range_match(self.range, entry.text_range) {
+                if !is_fully_suppressed {
+                    // TODO: would be nice to remove suppressed instances, if any, before emitting
+                    (self.emit_signal)(&*entry.signal)?;
+                } else if self.options.emit_suppressed_signals() {
+                    (self.emit_signal)(&SuppressedSignal::new(&*entry.signal))?;
+                }
             }
 
             // Remove signal from the queue.
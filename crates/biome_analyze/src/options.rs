@@ -130,6 +130,10 @@ pub struct AnalyzerOptions {
 
     /// Suppression reason used when applying a suppression code action
     pub(crate) suppression_reason: Option<String>,
+
+    /// Whether signals suppressed by a suppression comment should be emitted,
+    /// tagged as suppressed, instead of being discarded
+    pub(crate) emit_suppressed_signals: bool,
 }
 
 impl AnalyzerOptions {
@@ -148,6 +152,15 @@ impl AnalyzerOptions {
         self
     }
 
+    pub fn with_suppressed_signals(mut self, emit_suppressed_signals: bool) -> Self {
+        self.emit_suppressed_signals = emit_suppressed_signals;
+        self
+    }
+
+    pub fn emit_suppressed_signals(&self) -> bool {
+        self.emit_suppressed_signals
+    }
+
     pub fn push_globals(&mut self, globals: impl IntoIterator<Item = Box<str>>) {
         self.configuration.globals.extend(globals);
     }
@@ -58,6 +58,8 @@ pub trait RegistryVisitor<L: Language> {
 #[derive(Debug, Default)]
 pub struct MetadataRegistry {
     inner: BTreeSet<MetadataKey>,
+    /// Static metadata of the rules recorded in this registry
+    rules: BTreeMap<MetadataKey, RuleMetadata>,
 }
 
 impl MetadataRegistry {
@@ -73,6 +75,15 @@ impl MetadataRegistry {
         Some(key.into_rule_key())
     }
 
+    /// Return the metadata of a rule if it's known by this registry
+    pub fn find_rule_metadata(&self, group: &str, rule: &str) -> Option<RegistryRuleMetadata> {
+        let (key, metadata) = self.rules.get_key_value(&(group, rule))?;
+        Some(RegistryRuleMetadata {
+            group: key.inner.0,
+            rule: metadata.clone(),
+        })
+    }
+
     pub(crate) fn insert_rule(&mut self, group: &'static str, rule: &'static str) {
         self.inner.insert(MetadataKey {
             inner: (group, rule),
@@ -85,7 +96,14 @@ impl<L: Language> RegistryVisitor<L> for MetadataRegistry {
     where
         R: Rule<Query: Queryable<Language = L, Output: Clone>> + 'static,
     {
-        self.insert_rule(<R::Group as RuleGroup>::NAME, R::METADATA.name);
+        let group = <R::Group as RuleGroup>::NAME;
+        self.insert_rule(group, R::METADATA.name);
+        self.rules.insert(
+            MetadataKey {
+                inner: (group, R::METADATA.name),
+            },
+            R::METADATA,
+        );
     }
 }
 
@@ -2,16 +2,19 @@ use crate::categories::{
     SUPPRESSION_INLINE_ACTION_CATEGORY, SUPPRESSION_TOP_LEVEL_ACTION_CATEGORY,
 };
 use crate::{
-    AnalyzerDiagnostic, AnalyzerOptions, OtherActionCategory, Queryable, RuleDiagnostic, RuleGroup,
-    ServiceBag, SuppressionAction,
+    AnalyzerDiagnostic, AnalyzerOptions, OtherActionCategory, PluginAction, Queryable,
+    RuleDiagnostic, RuleGroup, ServiceBag, SuppressionAction,
     categories::ActionCategory,
     context::RuleContext,
     registry::{RuleLanguage, RuleRoot},
     rule::Rule,
 };
 use biome_console::{MarkupBuf, markup};
-use biome_diagnostics::{Applicability, CodeSuggestion, Error, advice::CodeSuggestionAdvice};
-use biome_rowan::{BatchMutation, Language};
+use biome_diagnostics::{
+    Applicability, CodeSuggestion, DiagnosticTags, Error, advice::CodeSuggestionAdvice,
+};
+use biome_rowan::{BatchMutation, Language, SyntaxNode, SyntaxSlot};
+use std::borrow::Cow;
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
 use std::vec::IntoIter;
@@ -109,18 +112,51 @@ where
 /// Unlike [DiagnosticSignal] which converts through [Error] into
 /// [DiagnosticKind::Raw](crate::diagnostics::DiagnosticKind::Raw), this type
 /// directly converts via `AnalyzerDiagnostic::from(RuleDiagnostic)`.
-pub struct PluginSignal<L> {
+pub struct PluginSignal<L: Language> {
     diagnostic: RuleDiagnostic,
-    _phantom: PhantomData<L>,
+    action: Option<PluginSignalAction<L>>,
+}
+
+/// Code fix offered by a [PluginSignal]
+struct PluginSignalAction<L: Language> {
+    category: ActionCategory,
+    applicability: Applicability,
+    message: MarkupBuf,
+    /// The node evaluated by the plugin
+    node: SyntaxNode<L>,
+    /// The evaluated node, with the fix applied
+    fixed_node: SyntaxNode<L>,
 }
 
-impl<L: Language> PluginSignal<L> {
+impl<L: Language + 'static> PluginSignal<L> {
     pub fn new(diagnostic: RuleDiagnostic) -> Self {
         Self {
             diagnostic,
-            _phantom: PhantomData,
+            action: None,
         }
     }
+
+    /// Attaches the fix computed by the plugin when it evaluated `node`.
+    ///
+    /// The fix is ignored when its node doesn't belong to the language `L`.
+    pub fn with_action(mut self, node: SyntaxNode<L>, action: PluginAction) -> Self {
+        let name = self
+            .diagnostic
+            .subcategory
+            .as_deref()
+            .unwrap_or("anonymous");
+        self.action = action
+            .fixed_node
+            .downcast_ref::<SyntaxNode<L>>()
+            .map(|fixed_node| PluginSignalAction {
+                category: ActionCategory::QuickFix(Cow::Owned(format!("plugin.{name}"))),
+                applicability: action.applicability,
+                message: action.message,
+                node,
+                fixed_node: fixed_node.clone(),
+            });
+        self
+    }
 }
 
 impl<L: Language> AnalyzerSignal<L> for PluginSignal<L> {
@@ -128,6 +164,115 @@ impl<L: Language> AnalyzerSignal<L> for PluginSignal<L> {
         Some(AnalyzerDiagnostic::from(self.diagnostic.clone()))
     }
 
+    fn actions(&self) -> AnalyzerActionIter<L> {
+        let Some(action) = &self.action else {
+            return AnalyzerActionIter::new(vec![]);
+        };
+
+        let mut replacements = Vec::new();
+        diff_nodes(&action.node, &action.fixed_node, &mut replacements);
+
+        // A single evaluation of the plugin may fix several places at once:
+        // only keep the changes related to the diagnostic, if there are any.
+        if let Some(span) = self.diagnostic.span()
+            && replacements
+                .iter()
+                .any(|(node, _)| node.text_trimmed_range().intersect(span).is_some())
+        {
+            replacements.retain(|(node, _)| node.text_trimmed_range().intersect(span).is_some());
+        }
+
+        if replacements.is_empty() {
+            return AnalyzerActionIter::new(vec![]);
+        }
+
+        let root = action
+            .node
+            .ancestors()
+            .last()
+            .unwrap_or_else(|| action.node.clone());
+        let mut mutation = BatchMutation::new(root);
+        for (node, fixed_node) in replacements {
+            mutation.replace_element_discard_trivia(node.into(), fixed_node.into());
+        }
+
+        AnalyzerActionIter::new(vec![AnalyzerAction {
+            rule_name: None,
+            category: action.category.clone(),
+            applicability: action.applicability,
+            message: action.message.clone(),
+            mutation,
+        }])
+    }
+
+    fn transformations(&self) -> AnalyzerTransformationIter<L> {
+        AnalyzerTransformationIter::new(vec![])
+    }
+}
+
+/// Collects the outermost nodes of `node` that differ from their counterpart
+/// in `fixed_node`, so the edits of a plugin fix are as small as possible.
+///
+/// Nodes are compared slot by slot. When the kind or the shape of a node
+/// changed, or one of its tokens changed, the whole node is replaced.
+fn diff_nodes<L: Language>(
+    node: &SyntaxNode<L>,
+    fixed_node: &SyntaxNode<L>,
+    replacements: &mut Vec<(SyntaxNode<L>, SyntaxNode<L>)>,
+) {
+    if node.text_with_trivia() == fixed_node.text_with_trivia() && node.kind() == fixed_node.kind()
+    {
+        return;
+    }
+
+    if node.kind() != fixed_node.kind() || node.slots().len() != fixed_node.slots().len() {
+        replacements.push((node.clone(), fixed_node.clone()));
+        return;
+    }
+
+    let mut children = Vec::new();
+    for slots in node.slots().zip(fixed_node.slots()) {
+        match slots {
+            (SyntaxSlot::Node(child), SyntaxSlot::Node(fixed_child)) => {
+                children.push((child, fixed_child));
+            }
+            (SyntaxSlot::Token(token), SyntaxSlot::Token(fixed_token))
+                if token.kind() == fixed_token.kind() && token.text() == fixed_token.text() => {}
+            (SyntaxSlot::Empty { .. }, SyntaxSlot::Empty { .. }) => {}
+            _ => {
+                replacements.push((node.clone(), fixed_node.clone()));
+                return;
+            }
+        }
+    }
+
+    for (child, fixed_child) in children {
+        diff_nodes(&child, &fixed_child, replacements);
+    }
+}
+
+/// Implementation of [AnalyzerSignal] wrapping a signal that was suppressed by
+/// a suppression comment.
+///
+/// Its diagnostic is tagged with [DiagnosticTags::SUPPRESSED], and it doesn't
+/// emit code actions nor transformations, since there's nothing left to fix.
+pub(crate) struct SuppressedSignal<'a, L: Language> {
+    signal: &'a dyn AnalyzerSignal<L>,
+}
+
+impl<'a, L: Language> SuppressedSignal<'a, L> {
+    pub(crate) fn new(signal: &'a dyn AnalyzerSignal<L>) -> Self {
+        Self { signal }
+    }
+}
+
+impl<L: Language> AnalyzerSignal<L> for SuppressedSignal<'_, L> {
+    fn diagnostic(&self) -> Option<AnalyzerDiagnostic> {
+        self.signal
+            .diagnostic()
+            .map(|diagnostic| diagnostic.with_tags(DiagnosticTags::SUPPRESSED))
+    }
+
     fn actions(&self) -> AnalyzerActionIter<L> {
         AnalyzerActionIter::new(vec![])
     }
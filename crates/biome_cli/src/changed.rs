@@ -1,6 +1,8 @@
 use crate::CliDiagnostic;
 use biome_configuration::Configuration;
-use biome_fs::FileSystem;
+use biome_fs::{BiomePath, FileSystem};
+use biome_service::Workspace;
+use biome_service::workspace::GetDependentPackagesParams;
 use camino::Utf8Path;
 use std::ffi::OsString;
 
@@ -49,3 +51,29 @@ pub(crate) fn get_staged_files(fs: &dyn FileSystem) -> Result<Vec<OsString>, Cli
 
     Ok(filtered_staged_files)
 }
+
+/// Adds the packages of the workspace that depend on the packages of the
+/// changed `paths`, so that they're processed as well.
+///
+/// Relies on the manifests that were indexed by the scanner.
+pub(crate) fn expand_to_dependent_packages(
+    workspace: &dyn Workspace,
+    working_dir: &Utf8Path,
+    mut paths: Vec<String>,
+) -> Result<Vec<String>, CliDiagnostic> {
+    let result = workspace.get_dependent_packages(GetDependentPackagesParams {
+        paths: paths
+            .iter()
+            .map(|path| BiomePath::new(working_dir.join(path)))
+            .collect(),
+    })?;
+
+    paths.extend(result.package_paths.iter().map(|package_path| {
+        package_path
+            .strip_prefix(working_dir)
+            .unwrap_or(package_path.as_path())
+            .to_string()
+    }));
+
+    Ok(paths)
+}
@@ -45,6 +45,11 @@ pub struct CliOptions {
     #[bpaf(long("skip-parse-errors"), switch)]
     pub skip_parse_errors: bool,
 
+    /// Reuse the results of the previous runs for the files that didn't change. The results are stored in the cache directory of Biome, and they are removed by `biome clean`.
+    /// The cache isn't used when files are written, or when rules that need the whole project are enabled.
+    #[bpaf(long("cache"), switch)]
+    pub cache: bool,
+
     /// Silence errors that would be emitted in case no files were processed during the execution of the command.
     #[bpaf(long("no-errors-on-unmatched"), switch)]
     pub no_errors_on_unmatched: bool,
@@ -56,7 +61,7 @@ pub struct CliOptions {
     /// Allows to change how diagnostics and summary are reported.
     #[bpaf(
         long("reporter"),
-        argument("json|json-pretty|github|junit|summary|gitlab|checkstyle|rdjson"),
+        argument("json|json-pretty|github|junit|summary|gitlab|checkstyle|rdjson|sarif"),
         fallback(CliReporter::default())
     )]
     pub reporter: CliReporter,
@@ -119,6 +124,34 @@ impl CliOptions {
     }
 }
 
+/// Options to adopt new rules incrementally, by recording the existing diagnostics in a baseline
+#[derive(Debug, Clone, Default, Bpaf)]
+pub struct BaselineOptions {
+    /// Path to a baseline file. The diagnostics recorded in the baseline aren't reported, so only the new ones fail the command.
+    /// The entries of the baseline that no longer occur in the processed files are reported.
+    #[bpaf(long("baseline"), argument("PATH"), optional)]
+    pub baseline: Option<String>,
+
+    /// Record the diagnostics emitted by the command in the baseline file, instead of reporting them.
+    /// If `--baseline` isn't provided, the baseline is written to `biome-baseline.json`.
+    #[bpaf(long("write-baseline"), switch)]
+    pub write_baseline: bool,
+}
+
+impl BaselineOptions {
+    /// The path of the baseline file, if a baseline should be used
+    pub(crate) fn path(&self) -> Option<&str> {
+        match self.baseline.as_deref() {
+            Some(path) => Some(path),
+            None if self.write_baseline => Some(DEFAULT_BASELINE_FILE),
+            None => None,
+        }
+    }
+}
+
+/// The file used by `--write-baseline` when `--baseline` isn't provided
+pub(crate) const DEFAULT_BASELINE_FILE: &str = "biome-baseline.json";
+
 #[derive(Debug, Clone)]
 pub enum ColorsArg {
     Off,
@@ -169,6 +202,8 @@ pub enum CliReporter {
     Checkstyle,
     /// Reports diagnostics using the [Reviewdog JSON format](https://deepwiki.com/reviewdog/reviewdog/3.2-reviewdog-diagnostic-format)
     RdJson,
+    /// Reports diagnostics using the [SARIF 2.1.0 format](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+    Sarif,
 }
 
 impl CliReporter {
@@ -190,6 +225,7 @@ impl FromStr for CliReporter {
             "gitlab" => Ok(Self::GitLab),
             "checkstyle" => Ok(Self::Checkstyle),
             "rdjson" => Ok(Self::RdJson),
+            "sarif" => Ok(Self::Sarif),
             _ => Err(format!(
                 "value {s:?} is not valid for the --reporter argument"
             )),
@@ -209,6 +245,7 @@ impl Display for CliReporter {
             Self::GitLab => f.write_str("gitlab"),
             Self::Checkstyle => f.write_str("checkstyle"),
             Self::RdJson => f.write_str("rdjson"),
+            Self::Sarif => f.write_str("sarif"),
         }
     }
 }
@@ -1,6 +1,7 @@
 use super::{FixFileModeOptions, LoadEditorConfig, determine_fix_file_mode};
-use crate::cli_options::CliOptions;
+use crate::cli_options::{BaselineOptions, CliOptions};
 use crate::commands::{CommandRunner, get_files_to_process_with_cli_options};
+use crate::execute::Baseline;
 use crate::{CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::analyzer::LinterEnabled;
 use biome_configuration::analyzer::assist::{AssistConfiguration, AssistEnabled};
@@ -32,6 +33,7 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) format_with_errors: Option<FormatWithErrorsEnabled>,
     pub(crate) json_parser: Option<JsonParserConfiguration>,
     pub(crate) css_parser: Option<CssParserConfiguration>,
+    pub(crate) baseline: BaselineOptions,
 }
 
 impl LoadEditorConfig for CheckCommandPayload {
@@ -145,7 +147,7 @@ impl CommandRunner for CheckCommandPayload {
         &self,
         cli_options: &CliOptions,
         console: &mut dyn Console,
-        _workspace: &dyn Workspace,
+        workspace: &dyn Workspace,
     ) -> Result<Execution, CliDiagnostic> {
         let fix_file_mode = determine_fix_file_mode(FixFileModeOptions {
             write: self.write,
@@ -162,6 +164,7 @@ impl CommandRunner for CheckCommandPayload {
             enforce_assist: self.enforce_assist,
             skip_parse_errors: cli_options.skip_parse_errors,
         })
-        .set_report(cli_options))
+        .set_report(cli_options)
+        .set_baseline(Baseline::load(workspace.fs(), &self.baseline)?))
     }
 }
@@ -1,6 +1,7 @@
 use crate::changed::get_changed_files;
-use crate::cli_options::CliOptions;
+use crate::cli_options::{BaselineOptions, CliOptions};
 use crate::commands::{CommandRunner, LoadEditorConfig};
+use crate::execute::Baseline;
 use crate::{CliDiagnostic, Execution};
 use biome_configuration::analyzer::LinterEnabled;
 use biome_configuration::analyzer::assist::{AssistConfiguration, AssistEnabled};
@@ -29,6 +30,7 @@ pub(crate) struct CiCommandPayload {
     pub(crate) format_with_errors: Option<FormatWithErrorsEnabled>,
     pub(crate) json_parser: Option<JsonParserConfiguration>,
     pub(crate) css_parser: Option<CssParserConfiguration>,
+    pub(crate) baseline: BaselineOptions,
 }
 
 impl LoadEditorConfig for CiCommandPayload {
@@ -139,14 +141,15 @@ impl CommandRunner for CiCommandPayload {
         &self,
         cli_options: &CliOptions,
         _console: &mut dyn Console,
-        _workspace: &dyn Workspace,
+        workspace: &dyn Workspace,
     ) -> Result<Execution, CliDiagnostic> {
         Ok(Execution::new_ci(
             (false, self.changed).into(),
             self.enforce_assist,
             cli_options.skip_parse_errors,
         )
-        .set_report(cli_options))
+        .set_report(cli_options)
+        .set_baseline(Baseline::load(workspace.fs(), &self.baseline)?))
     }
 
     fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
@@ -1,4 +1,5 @@
 use crate::commands::daemon::default_biome_log_path;
+use crate::execute::cache::analysis_cache_directory;
 use crate::{CliDiagnostic, CliSession};
 use biome_flags::biome_env;
 use camino::Utf8PathBuf;
@@ -10,6 +11,12 @@ pub fn clean(_cli_session: CliSession) -> Result<(), CliDiagnostic> {
         .biome_log_path
         .value()
         .map_or(default_biome_log_path(), Utf8PathBuf::from);
+
+    let cache_path = analysis_cache_directory();
+    if cache_path.exists() {
+        remove_dir_all(cache_path)?;
+    }
+
     remove_dir_all(logs_path.clone()).and_then(|_| create_dir(logs_path))?;
     Ok(())
 }
@@ -1,6 +1,7 @@
 use super::{FixFileModeOptions, determine_fix_file_mode};
-use crate::cli_options::CliOptions;
+use crate::cli_options::{BaselineOptions, CliOptions};
 use crate::commands::{CommandRunner, get_files_to_process_with_cli_options};
+use crate::execute::Baseline;
 use crate::{CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::analyzer::AnalyzerSelector;
 use biome_configuration::css::{CssLinterConfiguration, CssParserConfiguration};
@@ -38,6 +39,7 @@ pub(crate) struct LintCommandPayload {
     pub(crate) graphql_linter: Option<GraphqlLinterConfiguration>,
     pub(crate) json_parser: Option<JsonParserConfiguration>,
     pub(crate) css_parser: Option<CssParserConfiguration>,
+    pub(crate) baseline: BaselineOptions,
 }
 
 impl CommandRunner for LintCommandPayload {
@@ -134,7 +136,7 @@ impl CommandRunner for LintCommandPayload {
         &self,
         cli_options: &CliOptions,
         console: &mut dyn Console,
-        _workspace: &dyn Workspace,
+        workspace: &dyn Workspace,
     ) -> Result<Execution, CliDiagnostic> {
         let fix_file_mode = determine_fix_file_mode(FixFileModeOptions {
             write: self.write,
@@ -153,6 +155,7 @@ impl CommandRunner for LintCommandPayload {
             suppression_reason: self.suppression_reason.clone(),
             skip_parse_errors: cli_options.skip_parse_errors,
         })
-        .set_report(cli_options))
+        .set_report(cli_options)
+        .set_baseline(Baseline::load(workspace.fs(), &self.baseline)?))
     }
 }
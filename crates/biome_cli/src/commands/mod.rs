@@ -1,7 +1,10 @@
-use crate::changed::{get_changed_files, get_staged_files};
-use crate::cli_options::{CliOptions, CliReporter, ColorsArg, cli_options};
+use crate::changed::{expand_to_dependent_packages, get_changed_files, get_staged_files};
+use crate::cli_options::{
+    BaselineOptions, CliOptions, CliReporter, ColorsArg, baseline_options, cli_options,
+};
 use crate::commands::scan_kind::derive_best_scan_kind;
 use crate::execute::Stdin;
+use crate::execute::cache::CacheFingerprint;
 use crate::logging::LoggingKind;
 use crate::{
     CliDiagnostic, CliSession, Execution, LoggingLevel, TraversalMode, VERSION, execute_mode,
@@ -19,7 +22,11 @@ use biome_configuration::javascript::{JsFormatterConfiguration, JsLinterConfigur
 use biome_configuration::json::{
     JsonFormatterConfiguration, JsonLinterConfiguration, JsonParserConfiguration,
 };
+use biome_configuration::markdown::{
+    MarkdownFormatterConfiguration, markdown_formatter_configuration,
+};
 use biome_configuration::vcs::VcsConfiguration;
+use biome_configuration::yaml::{YamlFormatterConfiguration, yaml_formatter_configuration};
 use biome_configuration::{BiomeDiagnostic, Configuration};
 use biome_configuration::{
     FilesConfiguration, FormatterConfiguration, LinterConfiguration, configuration,
@@ -171,6 +178,8 @@ pub enum BiomeCommand {
         configuration: Option<Configuration>,
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
+        #[bpaf(external(baseline_options), hide_usage)]
+        baseline: BaselineOptions,
         /// Use this option when you want to format code piped from `stdin`, and
         /// print the output to `stdout`.
         ///
@@ -264,6 +273,8 @@ pub enum BiomeCommand {
 
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
+        #[bpaf(external(baseline_options), hide_usage)]
+        baseline: BaselineOptions,
 
         /// Run only the given rule, group of rules or domain.
         /// If the severity level of a rule is `off`,
@@ -346,6 +357,12 @@ pub enum BiomeCommand {
         #[bpaf(external(html_formatter_configuration), optional, hide_usage, hide)]
         html_formatter: Option<HtmlFormatterConfiguration>,
 
+        #[bpaf(external(yaml_formatter_configuration), optional, hide_usage, hide)]
+        yaml_formatter: Option<YamlFormatterConfiguration>,
+
+        #[bpaf(external(markdown_formatter_configuration), optional, hide_usage, hide)]
+        markdown_formatter: Option<MarkdownFormatterConfiguration>,
+
         #[bpaf(external(vcs_configuration), optional, hide_usage)]
         vcs_configuration: Option<VcsConfiguration>,
 
@@ -432,6 +449,8 @@ pub enum BiomeCommand {
         configuration: Option<Configuration>,
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
+        #[bpaf(external(baseline_options), hide_usage)]
+        baseline: BaselineOptions,
 
         /// When set to true, only the files that have been changed compared to your `defaultBranch`
         /// configuration will be linted.
@@ -556,9 +575,17 @@ pub enum BiomeCommand {
         #[bpaf(long("language"), short('l'))]
         language: Option<GritTargetLanguage>,
 
+        /// Writes the rewrites of the pattern to the files.
+        ///
+        /// Without this option, the command prints the diff of the files that
+        /// the rewrites would change.
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
         /// The GritQL pattern to search for.
         ///
-        /// Note that the search command (currently) does not support rewrites.
+        /// When the pattern contains rewrites, such as `` `$a == $b` => `$a === $b` ``,
+        /// the command prints how they change the files.
         #[bpaf(positional("PATTERN"))]
         pattern: String,
 
@@ -586,7 +613,7 @@ pub enum BiomeCommand {
     },
 
     #[bpaf(command)]
-    /// Cleans the logs emitted by the daemon.
+    /// Cleans the logs emitted by the daemon, and the cache of the results of the analysis.
     Clean,
 
     #[bpaf(command("__run_server"), hide)]
@@ -638,6 +665,16 @@ pub enum MigrateSubCommand {
         #[bpaf(long("include-nursery"))]
         include_nursery: bool,
     },
+    /// It attempts to find the Stylelint configuration file in the working directory, and update the Biome's configuration file as a result.
+    #[bpaf(command)]
+    Stylelint {
+        /// Includes rules inspired from a Stylelint rule in the migration
+        #[bpaf(long("include-inspired"))]
+        include_inspired: bool,
+        /// Includes nursery rules in the migration
+        #[bpaf(long("include-nursery"))]
+        include_nursery: bool,
+    },
 }
 
 impl MigrateSubCommand {
@@ -1025,6 +1062,13 @@ pub(crate) trait CommandRunner: Sized {
             &configuration,
         );
 
+        let mut cache_fingerprint =
+            (cli_options.cache && !execution.requires_write_access() && !scan_kind.is_project())
+                .then(|| CacheFingerprint::new(&execution));
+        if let Some(cache_fingerprint) = cache_fingerprint.as_mut() {
+            cache_fingerprint.write_configuration(fs, project_dir, &configuration);
+        }
+
         // Update the settings of the project
         let result = workspace.update_settings(UpdateSettingsParams {
             project_key: open_project_result.project_key,
@@ -1046,6 +1090,9 @@ pub(crate) trait CommandRunner: Sized {
         // Scan the project
         let scan_kind = match (scan_kind, execution.traversal_mode()) {
             (scan_kind, TraversalMode::Migrate { .. }) => scan_kind,
+            // With `--changed`, we need the manifests of the whole project to
+            // find the packages that depend on the changed files.
+            (ScanKind::KnownFiles, _) if execution.is_vcs_changed() => ScanKind::KnownFiles,
             (ScanKind::KnownFiles, _) => {
                 let target_paths = paths
                     .iter()
@@ -1074,8 +1121,19 @@ pub(crate) trait CommandRunner: Sized {
             )?;
         }
 
+        let paths = if execution.is_vcs_changed() {
+            expand_to_dependent_packages(workspace, &working_dir, paths)?
+        } else {
+            paths
+        };
+
+        let cache = cache_fingerprint.map(|mut cache_fingerprint| {
+            cache_fingerprint.write_nested_configurations(fs, &result.configuration_files);
+            cache_fingerprint.finish()
+        });
+
         Ok(ConfiguredWorkspace {
-            execution,
+            execution: execution.set_cache(cache),
             paths,
             duration: Some(result.duration),
             configuration_files: result.configuration_files,
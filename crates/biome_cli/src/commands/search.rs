@@ -19,6 +19,7 @@ pub(crate) struct SearchCommandPayload {
     pub(crate) language: Option<GritTargetLanguage>,
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) vcs_configuration: Option<VcsConfiguration>,
+    pub(crate) write: bool,
 }
 
 impl CommandRunner for SearchCommandPayload {
@@ -55,7 +56,7 @@ impl CommandRunner for SearchCommandPayload {
     }
 
     fn should_write(&self) -> bool {
-        false
+        self.write
     }
 
     fn get_execution(
@@ -72,6 +73,8 @@ impl CommandRunner for SearchCommandPayload {
             .pattern_id;
         Ok(Execution::new(TraversalMode::Search {
             pattern,
+            pattern_source: self.pattern.clone(),
+            write: self.should_write(),
             language: self.language.clone(),
             stdin: self.get_stdin(console)?,
         })
@@ -56,6 +56,8 @@ pub enum CliDiagnostic {
     NoFilesWereProcessed(NoFilesWereProcessed),
     /// Errors thrown when running the `biome migrate` command
     MigrateError(MigrationDiagnostic),
+    /// Emitted when the baseline file passed via `--baseline` can't be loaded
+    Baseline(BaselineDiagnostic),
     /// Emitted during the reporting phase
     Report(ReportDiagnostic),
     /// Emitted when there's an error emitted when using stdin mode
@@ -257,6 +259,20 @@ pub struct MigrationDiagnostic {
     pub reason: String,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "baseline",
+    severity = Error,
+    message(
+        message("The baseline file "<Emphasis>{self.path}</Emphasis>" can't be loaded: "{{&self.reason}}),
+        description = "The baseline file {path} can't be loaded: {reason}"
+    )
+)]
+pub struct BaselineDiagnostic {
+    pub path: String,
+    pub reason: String,
+}
+
 #[derive(Debug, Diagnostic)]
 pub enum ReportDiagnostic {
     /// Emitted when trying to serialise the report
@@ -362,6 +378,14 @@ impl CliDiagnostic {
         })
     }
 
+    /// Emitted when the baseline file can't be read or parsed
+    pub fn baseline(path: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::Baseline(BaselineDiagnostic {
+            path: path.into(),
+            reason: reason.into(),
+        })
+    }
+
     /// Emitted when errors were emitted while running `check` command
     pub fn check_error(category: &'static Category) -> Self {
         Self::CheckError(CheckError {
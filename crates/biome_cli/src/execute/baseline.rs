@@ -0,0 +1,356 @@
+//! Baseline of the diagnostics emitted by the rules.
+//!
+//! A baseline records the diagnostics of a project at a given point, so new
+//! rules can be adopted incrementally: the diagnostics recorded in the
+//! baseline aren't reported, and only the new ones fail the command.
+//!
+//! A diagnostic is identified by its rule, the path of its file relative to
+//! the working directory, and a hash of the source text it highlights along
+//! with the lines that contain it. The lines tell apart the diagnostics of
+//! short nodes, e.g. identifiers, that would otherwise share a fingerprint.
+//! The position of the node isn't part of the fingerprint, so the entries
+//! survive the code being moved around in the file. The same code can be
+//! reported more than once, so each entry keeps a count.
+
+use crate::CliDiagnostic;
+use crate::cli_options::BaselineOptions;
+use crate::execute::diagnostics::StaleBaselineDiagnostic;
+use biome_diagnostics::Error;
+use biome_fs::{FileSystem, FileSystemExt};
+use camino::Utf8PathBuf;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::Mutex;
+
+/// The version of the format of the baseline file
+const BASELINE_VERSION: u32 = 1;
+
+/// The baseline passed via `--baseline` or `--write-baseline`
+#[derive(Clone, Debug)]
+pub(crate) struct Baseline {
+    /// The path of the baseline file, as provided by the user
+    path: String,
+    /// The path of the baseline file, resolved against the working directory
+    resolved_path: Utf8PathBuf,
+    /// The working directory, the paths of the entries are relative to it
+    working_directory: Utf8PathBuf,
+    /// Whether the diagnostics of the run are written to the baseline,
+    /// instead of being filtered by it
+    write: bool,
+    /// The entries stored in the baseline file
+    entries: BTreeMap<BaselineKey, u32>,
+}
+
+impl Baseline {
+    /// Loads the baseline requested by the options, if any.
+    ///
+    /// When the baseline is written, the file doesn't need to exist yet.
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        options: &BaselineOptions,
+    ) -> Result<Option<Self>, CliDiagnostic> {
+        let Some(path) = options.path() else {
+            return Ok(None);
+        };
+        let working_directory = fs.working_directory().unwrap_or_default();
+        let resolved_path = working_directory.join(path);
+
+        let entries = if options.write_baseline && !fs.path_exists(&resolved_path) {
+            BTreeMap::new()
+        } else {
+            let content = fs.read_file_from_path(&resolved_path).map_err(|_| {
+                let reason = if fs.path_exists(&resolved_path) {
+                    "the file can't be read"
+                } else {
+                    "the file doesn't exist"
+                };
+                CliDiagnostic::baseline(path, reason)
+            })?;
+            let file: BaselineFile = serde_json::from_str(&content)
+                .map_err(|error| CliDiagnostic::baseline(path, error.to_string()))?;
+            if file.version != BASELINE_VERSION {
+                return Err(CliDiagnostic::baseline(
+                    path,
+                    format!("the version {} isn't supported", file.version),
+                ));
+            }
+
+            let mut entries = BTreeMap::new();
+            for entry in file.entries {
+                let key = BaselineKey {
+                    path: entry.path,
+                    rule: entry.rule,
+                    hash: entry.hash,
+                };
+                *entries.entry(key).or_default() += entry.count;
+            }
+            entries
+        };
+
+        Ok(Some(Self {
+            path: path.to_string(),
+            resolved_path,
+            working_directory,
+            write: options.write_baseline,
+            entries,
+        }))
+    }
+
+    /// The path of the baseline file, as provided by the user
+    pub(crate) fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Whether the diagnostics of the run are written to the baseline
+    pub(crate) fn is_write(&self) -> bool {
+        self.write
+    }
+
+    /// Whether the entries of the file at `path` belong to the current run:
+    /// the file was processed, or it no longer exists.
+    fn is_covered(
+        &self,
+        fs: &dyn FileSystem,
+        evaluated_paths: &FxHashSet<String>,
+        path: &str,
+    ) -> bool {
+        evaluated_paths.contains(path) || !fs.path_exists(&self.working_directory.join(path))
+    }
+}
+
+/// Matches the diagnostics of a traversal against a [Baseline]
+pub(crate) struct BaselineFilter<'a> {
+    baseline: &'a Baseline,
+    /// The entries that weren't matched yet, or the entries recorded so far
+    /// when the baseline is written
+    entries: Mutex<BTreeMap<BaselineKey, u32>>,
+}
+
+impl<'a> BaselineFilter<'a> {
+    pub(crate) fn new(baseline: &'a Baseline) -> Self {
+        let entries = if baseline.write {
+            BTreeMap::new()
+        } else {
+            baseline.entries.clone()
+        };
+        Self {
+            baseline,
+            entries: Mutex::new(entries),
+        }
+    }
+
+    pub(crate) fn baseline(&self) -> &Baseline {
+        self.baseline
+    }
+
+    /// Returns `true` if the diagnostic is part of the baseline, so it must
+    /// not be reported.
+    ///
+    /// When the baseline is written, all the diagnostics emitted by the rules
+    /// are recorded.
+    pub(crate) fn matches(&self, file_path: &str, content: &str, diagnostic: &Error) -> bool {
+        let Some(key) = BaselineKey::from_diagnostic(file_path, content, diagnostic) else {
+            return false;
+        };
+        let mut entries = self.entries.lock().unwrap();
+        if self.baseline.write {
+            *entries.entry(key).or_default() += 1;
+            return true;
+        }
+
+        let Some(count) = entries.get_mut(&key) else {
+            return false;
+        };
+        *count -= 1;
+        if *count == 0 {
+            entries.remove(&key);
+        }
+        true
+    }
+
+    /// Returns a diagnostic for each rule and file whose entries weren't
+    /// matched during the traversal.
+    ///
+    /// Only the entries of the files that belong to the run can be stale, the
+    /// other files weren't processed.
+    pub(crate) fn stale_diagnostics(
+        self,
+        fs: &dyn FileSystem,
+        evaluated_paths: &FxHashSet<String>,
+    ) -> Vec<Error> {
+        let mut stale: BTreeMap<(String, String), u32> = BTreeMap::new();
+        for (key, count) in self.entries.into_inner().unwrap() {
+            if self.baseline.is_covered(fs, evaluated_paths, &key.path) {
+                *stale.entry((key.path, key.rule)).or_default() += count;
+            }
+        }
+
+        stale
+            .into_iter()
+            .map(|((file_path, rule), count)| {
+                Error::from(StaleBaselineDiagnostic {
+                    file_path,
+                    rule,
+                    count,
+                })
+            })
+            .collect()
+    }
+
+    /// Writes the recorded entries to the baseline file, and returns how many
+    /// diagnostics it contains.
+    ///
+    /// The entries of the files that don't belong to the run are kept.
+    pub(crate) fn write(
+        self,
+        fs: &dyn FileSystem,
+        evaluated_paths: &FxHashSet<String>,
+    ) -> Result<u32, CliDiagnostic> {
+        let mut entries = self.entries.into_inner().unwrap();
+        for (key, count) in &self.baseline.entries {
+            if !self.baseline.is_covered(fs, evaluated_paths, &key.path) {
+                entries.insert(key.clone(), *count);
+            }
+        }
+
+        let total = entries.values().sum();
+        let file = BaselineFile {
+            version: BASELINE_VERSION,
+            entries: entries
+                .into_iter()
+                .map(|(key, count)| BaselineEntry {
+                    path: key.path,
+                    rule: key.rule,
+                    hash: key.hash,
+                    count,
+                })
+                .collect(),
+        };
+        let mut content = serde_json::to_string_pretty(&file)
+            .map_err(|error| CliDiagnostic::baseline(&self.baseline.path, error.to_string()))?;
+        content.push('\n');
+
+        fs.create(&self.baseline.resolved_path)
+            .and_then(|mut file| file.set_content(content.as_bytes()))
+            .map_err(CliDiagnostic::io_error)?;
+
+        Ok(total)
+    }
+}
+
+/// The fingerprint of a diagnostic
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct BaselineKey {
+    /// The path of the file, relative to the working directory
+    path: String,
+    /// The category of the diagnostic, e.g. `lint/suspicious/noDebugger`
+    rule: String,
+    /// The hash of the source text highlighted by the diagnostic, and of the
+    /// lines that contain it
+    hash: String,
+}
+
+impl BaselineKey {
+    /// Computes the fingerprint of a diagnostic emitted by a rule.
+    ///
+    /// Returns [None] for the diagnostics that don't come from a rule, e.g.
+    /// parse errors.
+    fn from_diagnostic(file_path: &str, content: &str, diagnostic: &Error) -> Option<Self> {
+        let rule = diagnostic.category()?.name();
+        if !(rule.starts_with("lint/") || rule.starts_with("assist/") || rule == "plugin") {
+            return None;
+        }
+        let range = diagnostic
+            .location()
+            .span
+            .map(Range::<usize>::from)
+            .filter(|range| content.get(range.clone()).is_some());
+        let (text, lines) = match range {
+            Some(range) => {
+                let lines_start = content[..range.start]
+                    .rfind('\n')
+                    .map_or(0, |index| index + 1);
+                let lines_end = content[range.end..]
+                    .find('\n')
+                    .map_or(content.len(), |index| range.end + index);
+                (&content[range], &content[lines_start..lines_end])
+            }
+            None => ("", ""),
+        };
+
+        Some(Self {
+            path: file_path.replace('\\', "/"),
+            rule: rule.to_string(),
+            hash: hash_source(&[text, lines]),
+        })
+    }
+}
+
+/// Hashes the given source texts, ignoring the differences in whitespace, so
+/// the fingerprint survives the code being re-indented.
+///
+/// The hash is stored in the baseline file, so it uses FNV-1a, which is stable
+/// across platforms and versions.
+fn hash_source(texts: &[&str]) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for (text_index, text) in texts.iter().enumerate() {
+        // The texts are separated by a byte that never appears in UTF-8
+        let text_separator: &[u8] = if text_index > 0 { &[0xff] } else { b"" };
+        let words = text.split_whitespace().enumerate();
+        let words = words.map(|(index, word)| {
+            let separator: &[u8] = if index > 0 { b" " } else { b"" };
+            separator.iter().chain(word.as_bytes())
+        });
+        for byte in text_separator.iter().chain(words.flatten()) {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+    format!("{hash:016x}")
+}
+
+/// The content of the baseline file
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BaselineFile {
+    version: u32,
+    entries: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BaselineEntry {
+    path: String,
+    rule: String,
+    hash: String,
+    count: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hash_source;
+
+    #[test]
+    fn hash_ignores_whitespace_differences() {
+        assert_eq!(
+            hash_source(&["if (a) {\n  debugger;\n}"]),
+            hash_source(&["if (a) {\n\t\tdebugger;\n    }"])
+        );
+        assert_ne!(hash_source(&["debugger;"]), hash_source(&["debug ger;"]));
+        assert_eq!(hash_source(&[""]), "cbf29ce484222325");
+    }
+
+    #[test]
+    fn hash_separates_the_texts() {
+        assert_ne!(hash_source(&["a", "b"]), hash_source(&["ab", ""]));
+        assert_ne!(
+            hash_source(&["a", "let a;"]),
+            hash_source(&["a", "let b = a;"])
+        );
+    }
+}
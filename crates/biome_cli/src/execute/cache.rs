@@ -0,0 +1,466 @@
+//! On-disk cache of the results of the traversal.
+//!
+//! When the cache is enabled, the results of processing a file — the messages
+//! sent to the display thread and the final [FileStatus] — are stored in the
+//! cache directory of Biome. The next runs reuse them, without parsing or
+//! analyzing the file again, as long as the content of the file didn't change,
+//! and the fingerprint of the run is the same.
+//!
+//! The fingerprint covers the version of Biome, the options of the traversal,
+//! the resolved configuration, the nested configuration files and the plugins.
+//! Each file has a single entry per command, which stores the fingerprint and
+//! the content it was computed for, so the entry of a file is overwritten when
+//! either of them changes. `biome clean` removes all the entries.
+//!
+//! The entries outlive the process, so they're identified with [StableHasher]
+//! rather than [std::hash::Hash], whose output isn't guaranteed across
+//! platforms and versions of Rust.
+
+use crate::execute::process_file::{DiffKind, FileResult, FileStatus, Message};
+use crate::execute::{Execution, TraversalMode};
+use biome_configuration::{Configuration, ConfigurationPathHint, VERSION};
+use biome_diagnostics::Error;
+use biome_diagnostics::serde::Diagnostic;
+use biome_fs::{BiomePath, FileSystem, FileSystemExt, normalize_path};
+use biome_plugin_loader::{PluginConfiguration, Plugins};
+use biome_resolver::FsWithResolverProxy;
+use biome_service::configuration::load_configuration;
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::{Deserialize, Serialize};
+use siphasher::sip128::{Hasher128, SipHasher13};
+use std::cell::RefCell;
+use std::hash::Hasher;
+use tracing::warn;
+
+/// Returns the directory where the entries of the cache are stored
+pub(crate) fn analysis_cache_directory() -> Utf8PathBuf {
+    biome_fs::ensure_cache_dir().join("biome-analysis-cache")
+}
+
+/// A 128-bit hasher whose output only depends on the data written into it.
+///
+/// Variable-length data is prefixed with its length, so that consecutive
+/// writes can't be confused with each other.
+struct StableHasher(SipHasher13);
+
+impl StableHasher {
+    fn new() -> Self {
+        Self(SipHasher13::new())
+    }
+
+    fn write_len(&mut self, len: usize) {
+        self.0.write(&(len as u64).to_le_bytes());
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_len(bytes.len());
+        self.0.write(bytes);
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_bytes(value.as_bytes());
+    }
+
+    fn write_bool(&mut self, value: bool) {
+        self.0.write(&[u8::from(value)]);
+    }
+
+    fn write_option_str(&mut self, value: Option<&str>) {
+        self.write_bool(value.is_some());
+        if let Some(value) = value {
+            self.write_str(value);
+        }
+    }
+
+    /// Hashes a value through its JSON representation
+    fn write_json(&mut self, value: &impl Serialize) {
+        self.write_str(&serde_json::to_string(value).unwrap_or_default());
+    }
+
+    fn finish(&self) -> u128 {
+        self.0.finish128().as_u128()
+    }
+}
+
+/// Computes the hash of everything, besides the content of a file, that
+/// affects the result of processing that file.
+pub(crate) struct CacheFingerprint {
+    hasher: StableHasher,
+    /// The name of the command, e.g. `lint`
+    command: String,
+}
+
+impl CacheFingerprint {
+    pub(crate) fn new(execution: &Execution) -> Self {
+        let mut hasher = StableHasher::new();
+        hasher.write_str(VERSION);
+        write_traversal_mode(&mut hasher, execution.traversal_mode());
+        hasher.write_bool(execution.should_report_suppressed());
+        Self {
+            hasher,
+            command: execution.traversal_mode().to_string(),
+        }
+    }
+
+    /// Hashes a resolved configuration, and the content of the plugins it
+    /// references. Plugin paths are resolved against `base_path`.
+    pub(crate) fn write_configuration(
+        &mut self,
+        fs: &dyn FsWithResolverProxy,
+        base_path: &Utf8Path,
+        configuration: &Configuration,
+    ) {
+        self.hasher.write_json(configuration);
+
+        let override_plugins = configuration
+            .overrides
+            .iter()
+            .flat_map(|overrides| overrides.0.iter())
+            .filter_map(|pattern| pattern.plugins.as_ref());
+        for plugins in configuration.plugins.iter().chain(override_plugins) {
+            for PluginConfiguration::Path(plugin_path) in Plugins::iter(plugins) {
+                let plugin_path = normalize_path(&base_path.join(plugin_path));
+                let content = fs.read_file_from_path(&plugin_path).ok();
+                self.hasher.write_option_str(content.as_deref());
+            }
+        }
+    }
+
+    /// Hashes the nested configuration files found while scanning the project
+    pub(crate) fn write_nested_configurations(
+        &mut self,
+        fs: &dyn FsWithResolverProxy,
+        configuration_files: &[BiomePath],
+    ) {
+        for path in configuration_files {
+            self.hasher.write_str(path.as_str());
+            let Ok(loaded_configuration) =
+                load_configuration(fs, ConfigurationPathHint::FromUser(path.to_path_buf()))
+            else {
+                continue;
+            };
+            let base_path = loaded_configuration
+                .directory_path
+                .or_else(|| path.parent().map(Utf8Path::to_path_buf))
+                .unwrap_or_default();
+            self.write_configuration(fs, &base_path, &loaded_configuration.configuration);
+        }
+    }
+
+    pub(crate) fn finish(self) -> AnalysisCache {
+        let directory = analysis_cache_directory();
+        if let Err(error) = std::fs::create_dir_all(&directory) {
+            warn!("Failed to create the cache directory {directory}: {error}");
+        }
+
+        AnalysisCache {
+            directory,
+            command: self.command,
+            fingerprint: self.hasher.finish(),
+        }
+    }
+}
+
+/// Hashes the options of the traversal that affect the results of processing
+/// a file. The options that only select the processed files, e.g. `--changed`
+/// or stdin, or that only affect how the results are printed, aren't hashed.
+fn write_traversal_mode(hasher: &mut StableHasher, traversal_mode: &TraversalMode) {
+    hasher.write_str(&traversal_mode.to_string());
+    match traversal_mode {
+        TraversalMode::Check {
+            fix_file_mode,
+            stdin: _,
+            vcs_targeted: _,
+            enforce_assist,
+            skip_parse_errors,
+        } => {
+            hasher.write_json(fix_file_mode);
+            hasher.write_bool(*enforce_assist);
+            hasher.write_bool(*skip_parse_errors);
+        }
+        TraversalMode::Lint {
+            fix_file_mode,
+            stdin: _,
+            only,
+            skip,
+            vcs_targeted: _,
+            suppress,
+            suppression_reason,
+            skip_parse_errors,
+        } => {
+            hasher.write_json(fix_file_mode);
+            for selectors in [only, skip] {
+                hasher.write_len(selectors.len());
+                for selector in selectors {
+                    hasher.write_str(&selector.to_string());
+                }
+            }
+            hasher.write_bool(*suppress);
+            hasher.write_option_str(suppression_reason.as_deref());
+            hasher.write_bool(*skip_parse_errors);
+        }
+        TraversalMode::CI {
+            environment: _,
+            vcs_targeted: _,
+            enforce_assist,
+            skip_parse_errors,
+        } => {
+            hasher.write_bool(*enforce_assist);
+            hasher.write_bool(*skip_parse_errors);
+        }
+        TraversalMode::Format {
+            skip_parse_errors,
+            write,
+            stdin: _,
+            vcs_targeted: _,
+        } => {
+            hasher.write_bool(*skip_parse_errors);
+            hasher.write_bool(*write);
+        }
+        // Migrations don't process the files of the traversal
+        TraversalMode::Migrate { .. } => {}
+        TraversalMode::Search {
+            pattern: _,
+            pattern_source,
+            write,
+            language,
+            stdin: _,
+        } => {
+            hasher.write_str(pattern_source);
+            hasher.write_bool(*write);
+            hasher.write_json(language);
+        }
+    }
+}
+
+/// The on-disk cache used during the traversal
+#[derive(Clone, Debug)]
+pub(crate) struct AnalysisCache {
+    /// The directory where the entries are stored
+    directory: Utf8PathBuf,
+    /// The name of the command, which separates the entries of the commands
+    command: String,
+    /// The fingerprint of the current run, see [CacheFingerprint]
+    fingerprint: u128,
+}
+
+impl AnalysisCache {
+    /// Reads the file at `path`, and retrieves the results stored for its
+    /// current content and the current fingerprint, if any.
+    ///
+    /// Returns [None] when the file can't be read.
+    pub(crate) fn entry(&self, fs: &dyn FileSystem, path: &BiomePath) -> Option<CacheEntry> {
+        let content = fs.read_file_from_path(path).ok()?;
+
+        let mut hasher = StableHasher::new();
+        hasher.write_str(&self.command);
+        hasher.write_str(path.as_str());
+        let location = self
+            .directory
+            .join(format!("{:032x}.json", hasher.finish()));
+
+        let mut hasher = StableHasher::new();
+        hasher.0.write(&self.fingerprint.to_le_bytes());
+        hasher.write_str(&content);
+        let key = format!("{:032x}", hasher.finish());
+
+        let cached = fs
+            .read_file_from_path(&location)
+            .ok()
+            .and_then(|json| serde_json::from_str::<CachedFile>(&json).ok())
+            .filter(|cached| cached.key == key);
+
+        Some(CacheEntry {
+            location,
+            key,
+            path: path.to_string(),
+            content,
+            cached,
+            messages: RefCell::default(),
+        })
+    }
+}
+
+/// The entry of a single file in the [AnalysisCache]
+pub(crate) struct CacheEntry {
+    /// Where the entry is stored
+    location: Utf8PathBuf,
+    /// The hash of the fingerprint and of the content of the file, in hexadecimal
+    key: String,
+    /// The path of the file
+    path: String,
+    /// The content of the file
+    content: String,
+    /// The results stored by a previous run, if they belong to the current
+    /// fingerprint and content of the file
+    cached: Option<CachedFile>,
+    /// The messages sent to the display thread while processing the file
+    messages: RefCell<Vec<CachedMessage>>,
+}
+
+impl CacheEntry {
+    /// Sends the messages stored by a previous run to the display thread, and
+    /// returns the status of the file.
+    ///
+    /// Returns [None] when no results are stored for the content of the file.
+    pub(crate) fn replay(&mut self, send: impl Fn(Message)) -> Option<FileStatus> {
+        let cached = self.cached.take()?;
+        for message in &cached.messages {
+            send(message.to_message(&self.path, &self.content));
+        }
+
+        Some(cached.status.to_status(&self.path, &self.content))
+    }
+
+    /// Records a message sent while processing the file, and returns it.
+    pub(crate) fn record(&self, message: Message) -> Message {
+        let cached = CachedMessage::from(message);
+        let message = cached.to_message(&self.path, &self.content);
+        self.messages.borrow_mut().push(cached);
+        message
+    }
+
+    /// Stores the recorded messages and the status of the file.
+    ///
+    /// Failures aren't stored, so the file is processed again during the next run.
+    pub(crate) fn store(self, fs: &dyn FileSystem, result: FileResult) -> FileResult {
+        let status = match result {
+            Ok(FileStatus::Unchanged) => CachedStatus::Unchanged,
+            Ok(FileStatus::Ignored) => CachedStatus::Ignored,
+            Ok(FileStatus::Message(message)) => CachedStatus::Message(CachedMessage::from(message)),
+            Ok(FileStatus::SearchResult(matches, message)) => CachedStatus::SearchResult {
+                matches,
+                message: CachedMessage::from(message),
+            },
+            result => return result,
+        };
+        let result = Ok(status.to_status(&self.path, &self.content));
+
+        let cached = CachedFile {
+            key: self.key,
+            messages: self.messages.into_inner(),
+            status,
+        };
+        let stored = serde_json::to_string(&cached)
+            .map_err(std::io::Error::from)
+            .and_then(|json| {
+                fs.create(&self.location)
+                    .and_then(|mut file| file.set_content(json.as_bytes()))
+            });
+        if let Err(error) = stored {
+            warn!("Failed to store the cache entry of {}: {error}", self.path);
+        }
+
+        result
+    }
+}
+
+/// The results of processing a file, as stored on disk
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedFile {
+    key: String,
+    messages: Vec<CachedMessage>,
+    status: CachedStatus,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum CachedStatus {
+    Unchanged,
+    Ignored,
+    Message(CachedMessage),
+    SearchResult {
+        matches: usize,
+        message: CachedMessage,
+    },
+}
+
+impl CachedStatus {
+    fn to_status(&self, path: &str, content: &str) -> FileStatus {
+        match self {
+            Self::Unchanged => FileStatus::Unchanged,
+            Self::Ignored => FileStatus::Ignored,
+            Self::Message(message) => FileStatus::Message(message.to_message(path, content)),
+            Self::SearchResult { matches, message } => {
+                FileStatus::SearchResult(*matches, message.to_message(path, content))
+            }
+        }
+    }
+}
+
+/// Serializable version of [Message]. The path and the content of the file
+/// aren't stored, because the cache is only used when files aren't written,
+/// so they're always the ones of the processed file.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum CachedMessage {
+    #[serde(rename_all = "camelCase")]
+    SkippedFixes {
+        skipped_suggested_fixes: u32,
+    },
+    Failure,
+    Error(Diagnostic),
+    #[serde(rename_all = "camelCase")]
+    Diagnostics {
+        diagnostics: Vec<Diagnostic>,
+        skipped_diagnostics: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    Diff {
+        new: String,
+        diff_kind: DiffKind,
+    },
+}
+
+impl CachedMessage {
+    fn to_message(&self, path: &str, content: &str) -> Message {
+        match self {
+            Self::SkippedFixes {
+                skipped_suggested_fixes,
+            } => Message::SkippedFixes {
+                skipped_suggested_fixes: *skipped_suggested_fixes,
+            },
+            Self::Failure => Message::Failure,
+            Self::Error(diagnostic) => Message::Error(Error::from(diagnostic.clone())),
+            Self::Diagnostics {
+                diagnostics,
+                skipped_diagnostics,
+            } => Message::Diagnostics {
+                file_path: path.to_string(),
+                content: content.to_string(),
+                diagnostics: diagnostics.iter().cloned().map(Error::from).collect(),
+                skipped_diagnostics: *skipped_diagnostics,
+            },
+            Self::Diff { new, diff_kind } => Message::Diff {
+                file_name: path.to_string(),
+                old: content.to_string(),
+                new: new.clone(),
+                diff_kind: *diff_kind,
+            },
+        }
+    }
+}
+
+impl From<Message> for CachedMessage {
+    fn from(message: Message) -> Self {
+        match message {
+            Message::SkippedFixes {
+                skipped_suggested_fixes,
+            } => Self::SkippedFixes {
+                skipped_suggested_fixes,
+            },
+            Message::Failure => Self::Failure,
+            Message::Error(error) => Self::Error(Diagnostic::new(error)),
+            Message::Diagnostics {
+                diagnostics,
+                skipped_diagnostics,
+                ..
+            } => Self::Diagnostics {
+                diagnostics: diagnostics.into_iter().map(Diagnostic::new).collect(),
+                skipped_diagnostics,
+            },
+            Message::Diff { new, diff_kind, .. } => Self::Diff { new, diff_kind },
+        }
+    }
+}
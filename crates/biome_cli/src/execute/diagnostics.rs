@@ -27,6 +27,17 @@ pub(crate) struct FormatDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "search",
+    severity = Information,
+    message = "The pattern would have rewritten the following content:"
+)]
+pub(crate) struct RewriteDiffDiagnostic {
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
 	category = "migrate",
@@ -82,6 +93,23 @@ pub(crate) struct SkippedDiagnostic;
 #[diagnostic(category = "search", severity = Information)]
 pub(crate) struct SearchDiagnostic;
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "baseline",
+    severity = Information,
+    message(
+        description = "The baseline records {count} diagnostic(s) of the rule {rule} that no longer occur in this file.",
+        message("The baseline records "{self.count}" diagnostic(s) of the rule "<Emphasis>{self.rule}</Emphasis>" that no longer occur in this file.")
+    ),
+    advice = "Run the command with --write-baseline to remove the stale entries from the baseline."
+)]
+pub(crate) struct StaleBaselineDiagnostic {
+    #[location(resource)]
+    pub(crate) file_path: String,
+    pub(crate) rule: String,
+    pub(crate) count: u32,
+}
+
 /// Extension trait for turning [Display]-able error types into [TraversalError]
 pub(crate) trait ResultExt {
     type Result;
@@ -36,6 +36,9 @@ mod eslint_unicorn;
 mod ignorefile;
 mod node;
 mod prettier;
+mod stylelint;
+mod stylelint_any_rule_to_biome;
+mod stylelint_to_biome;
 mod unsupported_rules;
 
 pub(crate) struct MigratePayload<'a> {
@@ -354,6 +357,79 @@ fn migrate_file(payload: MigrateFile) -> Result<MigrationFileResult, CliDiagnost
             console.log(markup! {{PrintDiagnostic::simple(&results)}});
             Ok(result)
         }
+        Some(MigrateSubCommand::Stylelint {
+            include_inspired,
+            include_nursery,
+        }) => {
+            let stylelint::Config {
+                path: stylelint_path,
+                data: mut stylelint_config,
+            } = stylelint::read_stylelint_config(fs, console)?;
+            let biome_config =
+                deserialize_from_json_ast::<Configuration>(&parsed.tree(), "").into_deserialized();
+            let Some(mut biome_config) = biome_config else {
+                return Ok(MigrationFileResult::HasErrors);
+            };
+            let ignore_files = std::mem::take(&mut stylelint_config.ignore_files);
+            let (biome_stylelint_config, mut results) =
+                stylelint_config.into_biome_config(&eslint_to_biome::MigrationOptions {
+                    include_inspired: *include_inspired,
+                    include_nursery: *include_nursery,
+                });
+            let old_biome_config = biome_config.clone();
+            biome_config.merge_with(biome_stylelint_config);
+            let ignore_globs = stylelint_to_biome::to_biome_ignore_globs(&ignore_files);
+            if !ignore_globs.is_empty() {
+                biome_config
+                    .files
+                    .get_or_insert(Default::default())
+                    .includes
+                    .get_or_insert_with(|| vec!["**".parse().expect("valid glob")])
+                    .extend(ignore_globs);
+            }
+            let result = if biome_config == old_biome_config {
+                MigrationFileResult::NoMigrationNeeded
+            } else {
+                let new_content = serde_json::to_string(&biome_config).map_err(|err| {
+                    CliDiagnostic::MigrateError(MigrationDiagnostic {
+                        reason: err.to_string(),
+                    })
+                })?;
+                workspace.change_file(ChangeFileParams {
+                    project_key,
+                    path: biome_path.clone(),
+                    content: new_content,
+                    version: 1,
+                })?;
+                let printed = workspace.format_file(FormatFileParams {
+                    project_key,
+                    path: biome_path,
+                })?;
+                if write {
+                    biome_config_file.set_content(printed.as_code().as_bytes())?;
+                    MigrationFileResult::Migrated
+                } else {
+                    let file_name = configuration_file_path.to_string();
+                    let diagnostic = MigrateDiffDiagnostic {
+                        file_name,
+                        diff: ContentDiffAdvice {
+                            old: biome_config_content,
+                            new: printed.as_code().to_string(),
+                        },
+                    };
+                    console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+                    MigrationFileResult::NeedsMigration
+                }
+            };
+            if let Some(working_directory) = fs.working_directory() {
+                let path = working_directory.join(stylelint_path);
+                let path = path.canonicalize_utf8().unwrap_or(path);
+                results.stylelint_path = Some(path.to_string().into());
+            }
+            results.write = write;
+            console.log(markup! {{PrintDiagnostic::simple(&results)}});
+            Ok(result)
+        }
         None => {
             let mut tree = parsed.tree();
             let mut actions = Vec::new();
@@ -0,0 +1,566 @@
+use crate::CliDiagnostic;
+use crate::diagnostics::MigrationDiagnostic;
+use biome_console::{Console, ConsoleExt, markup};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::{
+    Deserializable, DeserializableType, DeserializableTypes, DeserializableValue,
+    DeserializationContext, DeserializationVisitor, Merge,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::{DiagnosticExt, PrintDiagnostic};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_json_parser::JsonParserOptions;
+use biome_rowan::TextRange;
+use camino::Utf8Path;
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::{any::TypeId, marker::PhantomData};
+
+use super::eslint_eslint::ShorthandVec;
+use super::node;
+
+/// This modules includes implementations for loading and deserializing a Stylelint configuration.
+///
+/// See [super::stylelint_to_biome] for converting a Stylelint config to a Biome config.
+///
+/// Stylelint configuration filenames.
+///
+/// See https://stylelint.io/user-guide/configure
+///
+/// Order is important.
+/// It translates the priority of the files.
+const CONFIG_FILES: [&str; 10] = [
+    ".stylelintrc",
+    ".stylelintrc.json",
+    ".stylelintrc.yaml",
+    ".stylelintrc.yml",
+    // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
+    "./.stylelintrc.js",
+    "./.stylelintrc.mjs",
+    "./.stylelintrc.cjs",
+    "./stylelint.config.js",
+    "./stylelint.config.mjs",
+    "./stylelint.config.cjs",
+];
+
+/// A Stylelint config can be embedded in `package.json`
+const PACKAGE_JSON: &str = "package.json";
+
+/// Returns the Stylelint configuration file in the working directory with the highest priority.
+///
+/// Unlike Stylelint, it doesn't look for a configuration file in parent directories
+/// when no configuration file is found in the working directory.
+///
+/// Deserialization errors are reported using `console`.
+/// Other errors (File Not found, unsupported config format, ...) are directly returned.
+///
+/// We extract the Stylelint configuration from a JavaScript file, by invoking `node`.
+///
+/// The `extends` field is recursively resolved.
+pub(crate) fn read_stylelint_config(
+    fs: &dyn FileSystem,
+    console: &mut dyn Console,
+) -> Result<Config, CliDiagnostic> {
+    // We don't report an error if the Stylelint config is not embedded in `PACKAGE_JSON`.
+    if let Ok(data) = load_config_data(fs, Utf8Path::new(PACKAGE_JSON), console) {
+        return Ok(Config {
+            path: PACKAGE_JSON,
+            data,
+        });
+    }
+    for config_path_str in CONFIG_FILES {
+        let path = Utf8Path::new(config_path_str);
+        if fs.path_exists(path) {
+            return load_config_data(fs, path, console).map(|data| Config {
+                path: config_path_str,
+                data,
+            });
+        }
+    }
+    Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+        reason: "The default Stylelint configuration file `.stylelintrc[.*]` or `stylelint.config.*` was not found in the working directory.".to_string(),
+    }))
+}
+
+#[derive(Debug)]
+pub(crate) struct Config {
+    /// Path of the Stylelint config file
+    pub(crate) path: &'static str,
+    /// Resolved Stylelint config
+    pub(crate) data: ConfigData,
+}
+
+/// Load a Stylelint config
+/// See https://stylelint.io/user-guide/configure
+fn load_config_data(
+    fs: &dyn FileSystem,
+    path: &Utf8Path,
+    console: &mut dyn Console,
+) -> Result<ConfigData, CliDiagnostic> {
+    let (deserialized, diagnostics) = match path.extension() {
+        None | Some("json") => {
+            let mut file = fs.open_with_options(path, OpenOptions::default().read(true))?;
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+            let parser_options = JsonParserOptions::default()
+                .with_allow_trailing_commas()
+                .with_allow_comments();
+            if path.file_name().is_some_and(|name| name == PACKAGE_JSON) {
+                let (deserialized, _) =
+                    deserialize_from_json_str::<StylelintPackageJson>(&content, parser_options, "")
+                        .consume();
+                (
+                    deserialized.and_then(|packagejson| packagejson.stylelint),
+                    vec![],
+                )
+            } else {
+                deserialize_from_json_str::<ConfigData>(&content, parser_options, "").consume()
+            }
+        }
+        Some("js" | "cjs" | "mjs") => {
+            let node::Resolution { content, .. } = node::load_config(path.as_ref())?;
+            deserialize_from_json_str::<ConfigData>(&content, JsonParserOptions::default(), "")
+                .consume()
+        }
+        Some(ext) => {
+            return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+                reason: format!(
+                    "Stylelint configuration ending with the extension `{ext}` are not supported."
+                ),
+            }));
+        }
+    };
+    let path_str = path.to_string();
+    for diagnostic in diagnostics.into_iter().filter(|diag| {
+        matches!(
+            diag.severity(),
+            biome_diagnostics::Severity::Fatal
+                | biome_diagnostics::Severity::Error
+                | biome_diagnostics::Severity::Warning
+        )
+    }) {
+        let diagnostic = diagnostic.with_file_path(path_str.clone());
+        console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+    }
+    if let Some(mut result) = deserialized {
+        // recursively resolve the `extends` field.
+        while !result.extends.is_empty() {
+            resolve_extends(&mut result, console);
+        }
+        Ok(result)
+    } else if path.extension().is_none() {
+        // The Stylelint config file may be a YAML file.
+        Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Could not deserialize the Stylelint configuration file.\nOnly JSON configurations are supported.".to_string(),
+        }))
+    } else {
+        Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Could not deserialize the Stylelint configuration file".to_string(),
+        }))
+    }
+}
+
+/// Returns the shared configuration `name` or an error if the resolution failed.
+///
+/// `name` is either a package name such as `stylelint-config-standard`,
+/// or a path to a configuration file.
+fn load_extends_config(name: &str) -> Result<ConfigData, CliDiagnostic> {
+    let node::Resolution {
+        content,
+        resolved_path,
+    } = node::load_config(name)?;
+    let Some(mut deserialized) =
+        deserialize_from_json_str::<ConfigData>(&content, JsonParserOptions::default(), "")
+            .into_deserialized()
+    else {
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: format!(
+                "The Stylelint configuration of the module '{name}' cannot be extracted. This is likely an internal error."
+            ),
+        }));
+    };
+    // Resolve relative path in `extends`.
+    deserialized.extends.iter_mut().for_each(|extends_item| {
+        if extends_item.starts_with('.') {
+            let Some(resolved_path) = Path::new(&resolved_path).parent() else {
+                return;
+            };
+            let mut path = PathBuf::new();
+            path.push(resolved_path);
+            path.push(Path::new(&extends_item));
+            *extends_item = path.to_string_lossy().to_string();
+        }
+    });
+    Ok(deserialized)
+}
+
+/// Load and merge included configuration via `config.extends`.
+///
+/// Configurations that cannot be loaded are reported and ignored.
+/// `config.extends` is replaced by an empty array.
+fn resolve_extends(config: &mut ConfigData, console: &mut dyn Console) {
+    let extensions: Vec<_> = config
+        .extends
+        .iter()
+        .filter_map(|name| match load_extends_config(name) {
+            Ok(extension) => Some(extension),
+            Err(diag) => {
+                console.error(markup! {{PrintDiagnostic::simple(&diag)}});
+                None
+            }
+        })
+        .collect();
+    config.extends.clear();
+    // A configuration takes precedence over the configurations it extends,
+    // and an extended configuration takes precedence over the previous ones.
+    for mut extension in extensions.into_iter().rev() {
+        extension.apply_default_severity();
+        config.merge_with(extension);
+    }
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct StylelintPackageJson {
+    pub(crate) stylelint: Option<ConfigData>,
+}
+
+/// The defined types follow the Stylelint configuration schema described at
+/// <https://stylelint.io/user-guide/configure>.
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct ConfigData {
+    pub(crate) extends: ShorthandVec<String>,
+    /// The glob patterns of the ignored files.
+    pub(crate) ignore_files: ShorthandVec<Box<str>>,
+    /// Severity of the rules that don't specify one.
+    pub(crate) default_severity: Option<Severity>,
+    pub(crate) rules: Rules,
+    pub(crate) overrides: Vec<OverrideConfigData>,
+}
+impl ConfigData {
+    /// Sets the severity of the rules that don't specify one to the default severity.
+    pub(crate) fn apply_default_severity(&mut self) {
+        if let Some(default_severity) = self.default_severity.take() {
+            self.rules.apply_default_severity(default_severity);
+            for override_elt in &mut self.overrides {
+                override_elt.rules.apply_default_severity(default_severity);
+            }
+        }
+    }
+}
+impl Merge for ConfigData {
+    fn merge_with(&mut self, mut other: Self) {
+        self.extends.merge_with(other.extends);
+        self.ignore_files.merge_with(other.ignore_files);
+        self.rules.merge_with(other.rules);
+        self.overrides.append(&mut other.overrides);
+    }
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct OverrideConfigData {
+    /// The glob patterns for target files.
+    pub(crate) files: ShorthandVec<Box<str>>,
+    pub(crate) rules: Rules,
+}
+
+#[derive(Clone, Copy, Debug, Deserializable)]
+pub(crate) enum Severity {
+    Warning,
+    Error,
+}
+impl From<Severity> for biome_configuration::RulePlainConfiguration {
+    fn from(value: Severity) -> Self {
+        match value {
+            Severity::Warning => Self::Warn,
+            Severity::Error => Self::Error,
+        }
+    }
+}
+
+/// Model the possible shapes of a Stylelint's rule configuration
+#[derive(Debug, Clone)]
+pub(crate) enum RuleConf<T = ()> {
+    /// `{ rule: null }`
+    Off,
+    /// `{ rule: <primary> }` and `{ rule: [<primary>, <secondary>] }`
+    ///
+    /// The severity is taken from the secondary options.
+    On(Option<Severity>, T),
+}
+impl<T> RuleConf<T> {
+    /// Returns the Biome severity of the rule.
+    ///
+    /// Stylelint reports errors when no severity is set.
+    pub(crate) fn severity(&self) -> biome_configuration::RulePlainConfiguration {
+        match self {
+            Self::Off => biome_configuration::RulePlainConfiguration::Off,
+            Self::On(severity, _) => severity.unwrap_or(Severity::Error).into(),
+        }
+    }
+
+    fn apply_default_severity(&mut self, default_severity: Severity) {
+        if let Self::On(severity, _) = self {
+            severity.get_or_insert(default_severity);
+        }
+    }
+}
+impl<T: Deserializable + Default + 'static> Deserializable for RuleConf<T> {
+    fn deserialize(
+        ctx: &mut impl DeserializationContext,
+        value: &impl DeserializableValue,
+        name: &str,
+    ) -> Option<Self> {
+        struct Visitor<T>(PhantomData<T>);
+        impl<T: Deserializable + Default + 'static> DeserializationVisitor for Visitor<T> {
+            type Output = RuleConf<T>;
+            const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::ARRAY;
+            fn visit_array(
+                self,
+                ctx: &mut impl DeserializationContext,
+                values: impl Iterator<Item = Option<impl DeserializableValue>>,
+                _range: TextRange,
+                _name: &str,
+            ) -> Option<Self::Output> {
+                let values: Vec<_> = values.flatten().collect();
+                // An array is a primary option unless it is followed by secondary options.
+                let [primary, secondary] = values.as_slice() else {
+                    return Some(RuleConf::On(None, T::default()));
+                };
+                if secondary.visitable_type()? != DeserializableType::Map {
+                    return Some(RuleConf::On(None, T::default()));
+                }
+                if primary.visitable_type()? == DeserializableType::Null {
+                    return Some(RuleConf::Off);
+                }
+                let SecondaryOptions { severity } = Deserializable::deserialize(ctx, secondary, "")
+                    // Recover by ignoring the failed deserialization
+                    .unwrap_or_default();
+                if TypeId::of::<T>() == TypeId::of::<()>() {
+                    return Some(RuleConf::On(severity, T::default()));
+                }
+                let options = T::deserialize(ctx, secondary, "").unwrap_or_default();
+                Some(RuleConf::On(severity, options))
+            }
+        }
+        match value.visitable_type()? {
+            DeserializableType::Null => Some(Self::Off),
+            DeserializableType::Array => value.deserialize(ctx, Visitor(PhantomData), name),
+            _ => Some(Self::On(None, T::default())),
+        }
+    }
+}
+
+/// Secondary options shared by all Stylelint rules.
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct SecondaryOptions {
+    severity: Option<Severity>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Rules(pub(crate) rustc_hash::FxHashSet<Rule>);
+impl Rules {
+    fn apply_default_severity(&mut self, default_severity: Severity) {
+        self.0 = std::mem::take(&mut self.0)
+            .into_iter()
+            .map(|mut rule| {
+                match &mut rule {
+                    Rule::Any(_, conf) => conf.apply_default_severity(default_severity),
+                    Rule::AtRuleNoUnknown(conf) => conf.apply_default_severity(default_severity),
+                }
+                rule
+            })
+            .collect();
+    }
+}
+impl Merge for Rules {
+    fn merge_with(&mut self, other: Self) {
+        self.0.extend(other.0);
+    }
+}
+impl Deserializable for Rules {
+    fn deserialize(
+        ctx: &mut impl DeserializationContext,
+        value: &impl DeserializableValue,
+        name: &str,
+    ) -> Option<Self> {
+        struct Visitor;
+        impl DeserializationVisitor for Visitor {
+            type Output = Rules;
+            const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::MAP;
+            fn visit_map(
+                self,
+                ctx: &mut impl DeserializationContext,
+                members: impl Iterator<
+                    Item = Option<(impl DeserializableValue, impl DeserializableValue)>,
+                >,
+                _range: TextRange,
+                name: &str,
+            ) -> Option<Self::Output> {
+                use biome_deserialize::Text;
+                let mut result = rustc_hash::FxHashSet::default();
+                for (key, value) in members.flatten() {
+                    let Some(rule_name) = Text::deserialize(ctx, &key, "") else {
+                        continue;
+                    };
+                    match rule_name.text() {
+                        // Stylelint rules with options that we handle
+                        "at-rule-no-unknown" => {
+                            if let Some(conf) = RuleConf::deserialize(ctx, &value, name) {
+                                result.insert(Rule::AtRuleNoUnknown(conf));
+                            }
+                        }
+                        // Other rules
+                        rule_name => {
+                            if let Some(conf) = RuleConf::<()>::deserialize(ctx, &value, name) {
+                                result.insert(Rule::Any(Cow::Owned(rule_name.to_string()), conf));
+                            }
+                        }
+                    }
+                }
+                Some(Rules(result))
+            }
+        }
+        value.deserialize(ctx, Visitor, name)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum Rule {
+    /// Any rule without its options.
+    Any(Cow<'static, str>, RuleConf),
+    // Stylelint rules with its options
+    // We use this to configure equivalent Biome's rules.
+    AtRuleNoUnknown(RuleConf<AtRuleNoUnknownOptions>),
+    // If you add new variants, don't forget to update [Rules::deserialize].
+}
+impl Rule {
+    pub(crate) fn name(&self) -> Cow<'static, str> {
+        match self {
+            Self::Any(name, _) => name.clone(),
+            Self::AtRuleNoUnknown(_) => Cow::Borrowed("at-rule-no-unknown"),
+        }
+    }
+}
+impl Eq for Rule {}
+impl PartialEq for Rule {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+}
+impl Hash for Rule {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+    }
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct AtRuleNoUnknownOptions {
+    /// At-rules to ignore. Regular expressions such as `/^my-/` are also accepted.
+    pub(crate) ignore_at_rules: Vec<String>,
+}
+impl From<AtRuleNoUnknownOptions>
+    for biome_rule_options::no_unknown_at_rules::NoUnknownAtRulesOptions
+{
+    fn from(val: AtRuleNoUnknownOptions) -> Self {
+        Self {
+            // Biome doesn't support regular expressions.
+            ignore: val
+                .ignore_at_rules
+                .into_iter()
+                .filter(|at_rule| !at_rule.starts_with('/'))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_configuration::RulePlainConfiguration;
+
+    fn rule_severity(rules: &Rules, name: &str) -> Option<RulePlainConfiguration> {
+        rules.0.iter().find_map(|rule| match rule {
+            Rule::Any(rule_name, conf) if rule_name == name => Some(conf.severity()),
+            Rule::AtRuleNoUnknown(conf) if name == "at-rule-no-unknown" => Some(conf.severity()),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn deserialize_rule_configurations() {
+        let config = deserialize_from_json_str::<ConfigData>(
+            r#"{
+                "rules": {
+                    "block-no-empty": true,
+                    "unit-allowed-list": ["px", "em"],
+                    "color-named": ["never", { "severity": "warning" }],
+                    "unit-no-unknown": null,
+                    "at-rule-no-unknown": [true, { "ignoreAtRules": ["tailwind"] }]
+                }
+            }"#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+
+        let rules = &config.rules;
+        assert_eq!(
+            rule_severity(rules, "block-no-empty"),
+            Some(RulePlainConfiguration::Error)
+        );
+        assert_eq!(
+            rule_severity(rules, "unit-allowed-list"),
+            Some(RulePlainConfiguration::Error)
+        );
+        assert_eq!(
+            rule_severity(rules, "color-named"),
+            Some(RulePlainConfiguration::Warn)
+        );
+        assert_eq!(
+            rule_severity(rules, "unit-no-unknown"),
+            Some(RulePlainConfiguration::Off)
+        );
+        let at_rule_no_unknown = rules.0.iter().find_map(|rule| match rule {
+            Rule::AtRuleNoUnknown(RuleConf::On(_, options)) => Some(options),
+            _ => None,
+        });
+        assert_eq!(
+            at_rule_no_unknown.map(|options| options.ignore_at_rules.as_slice()),
+            Some(["tailwind".to_string()].as_slice())
+        );
+    }
+
+    #[test]
+    fn default_severity_applies_to_rules_without_severity() {
+        let mut config = deserialize_from_json_str::<ConfigData>(
+            r#"{
+                "defaultSeverity": "warning",
+                "rules": {
+                    "block-no-empty": true,
+                    "unit-no-unknown": [true, { "severity": "error" }]
+                }
+            }"#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+        config.apply_default_severity();
+
+        assert_eq!(
+            rule_severity(&config.rules, "block-no-empty"),
+            Some(RulePlainConfiguration::Warn)
+        );
+        assert_eq!(
+            rule_severity(&config.rules, "unit-no-unknown"),
+            Some(RulePlainConfiguration::Error)
+        );
+    }
+}
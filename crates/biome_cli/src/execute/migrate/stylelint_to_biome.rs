@@ -0,0 +1,329 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::eslint_to_biome::{MigrationOptions, RuleMigrationResult, UnsupportedRuleReason};
+use super::stylelint;
+use super::stylelint_any_rule_to_biome::migrate_stylelint_any_rule;
+use super::unsupported_rules::UNSUPPORTED_RULES;
+use biome_analyze::RuleSource;
+use biome_configuration::analyzer::SeverityOrGroup;
+use biome_configuration::{self as biome_config};
+use biome_console::markup;
+use biome_diagnostics::Location;
+
+/// This modules includes implementations for converting a Stylelint config to a Biome config.
+///
+/// The conversion relies on:
+/// - the generated [super::stylelint_any_rule_to_biome::migrate_stylelint_any_rule]
+///   module that relies on Biome's rule metadata to determine
+///   the equivalent Biome's rule of a Stylelint rule
+/// - hand-written handling of Biome rules that have options in the current module.
+
+#[derive(Debug, Default)]
+pub(crate) struct MigrationResults {
+    /// Path to the migrated Stylelint configuration
+    pub(crate) stylelint_path: Option<Box<str>>,
+    /// Is the Biome configuration updated?
+    pub(crate) write: bool,
+    // Contains inspired rules that were not migrated because `include_inspired` is disabled
+    pub(crate) inspired: BTreeSet<Box<str>>,
+    pub(crate) nursery: BTreeSet<Box<str>>,
+    pub(crate) migrated: BTreeSet<Box<str>>,
+    pub(crate) unsupported: BTreeMap<Box<str>, UnsupportedRuleReason>,
+}
+impl MigrationResults {
+    pub(crate) fn add(&mut self, rule_name: &str, status: RuleMigrationResult) {
+        let rule_name = rule_name.into();
+        match status {
+            RuleMigrationResult::Migrated => {
+                self.migrated.insert(rule_name);
+            }
+            RuleMigrationResult::Inspired => {
+                self.inspired.insert(rule_name);
+            }
+            RuleMigrationResult::Nursery => {
+                self.nursery.insert(rule_name);
+            }
+            RuleMigrationResult::Unsupported => {
+                let reason = unsupported_rule_reason(&rule_name);
+                self.unsupported.insert(rule_name, reason);
+            }
+        }
+    }
+
+    pub(crate) fn rule_count(&self) -> usize {
+        self.migrated.len() + self.inspired.len() + self.nursery.len() + self.unsupported.len()
+    }
+}
+impl biome_diagnostics::Diagnostic for MigrationResults {
+    fn category(&self) -> Option<&'static biome_diagnostics::Category> {
+        Some(biome_diagnostics::category!("migrate"))
+    }
+
+    fn severity(&self) -> biome_diagnostics::Severity {
+        biome_diagnostics::Severity::Information
+    }
+
+    fn location(&self) -> Location<'_> {
+        let mut builder = Location::builder();
+        if let Some(path) = self.stylelint_path.as_ref() {
+            builder = builder.resource(path);
+        }
+        builder.build()
+    }
+
+    fn message(&self, fmt: &mut biome_console::fmt::Formatter<'_>) -> std::io::Result<()> {
+        let count = self.rule_count();
+        if count != 0 {
+            let formatter_covers_count = self
+                .unsupported
+                .values()
+                .filter(|reason| {
+                    matches!(
+                        reason,
+                        UnsupportedRuleReason::FormatterCovers
+                            | UnsupportedRuleReason::FormatterOption(_)
+                    )
+                })
+                .count();
+
+            let directly_covered_count = self.migrated.len();
+            let inspired_count = self.inspired.len();
+            let nursery_count = self.nursery.len();
+
+            let total_covered_count =
+                directly_covered_count + inspired_count + nursery_count + formatter_covers_count;
+            let total_covered_percent = total_covered_count * 100 / count;
+            let directly_covered_percent = directly_covered_count * 100 / count;
+
+            fmt.write_markup(markup! { <Emphasis>{count}" Stylelint rules found\n"</Emphasis> })?;
+            if formatter_covers_count > 0 {
+                fmt.write_markup(markup! { "- "<Emphasis><Success>{formatter_covers_count}</Success>" are obsolete"</Emphasis>" because of Biome's formatter\n" })?;
+            }
+
+            if self.write {
+                fmt.write_markup(markup! { "- "<Emphasis><Success>{directly_covered_count}</Success>" have been migrated"</Emphasis>" to Biome's rules\n" })?;
+            } else {
+                fmt.write_markup(markup! { "- "<Emphasis><Success>{directly_covered_count}</Success>" can be migrated"</Emphasis>" to Biome's rules (run with --write to migrate)\n" })?;
+                if inspired_count > 0 {
+                    fmt.write_markup(markup! { "  - "<Emphasis><Success>"+"{inspired_count}</Success></Emphasis>" with --include-inspired\n" })?;
+                }
+                if nursery_count > 0 {
+                    fmt.write_markup(markup! { "  - "<Emphasis><Success>"+"{nursery_count}</Success></Emphasis>" with --include-nursery (experimental rules)\n" })?;
+                }
+            }
+
+            fmt.write_markup(markup! {
+                "- "<Emphasis><Success>{total_covered_percent}"% ("{total_covered_count}")"</Success>" of your Stylelint rules are fully covered by Biome\n"</Emphasis>
+            })?;
+            fmt.write_markup(markup! {
+                "  - "{directly_covered_percent}"% ("{directly_covered_count}") via direct migration to Biome rules\n"
+            })
+        } else {
+            fmt.write_markup(markup! { "No rules to migrate." })
+        }
+    }
+
+    fn advices(&self, visitor: &mut dyn biome_diagnostics::Visit) -> std::io::Result<()> {
+        fn record_rules<'a>(
+            visitor: &mut dyn biome_diagnostics::Visit,
+            rules: impl Iterator<Item = &'a Box<str>>,
+        ) -> std::io::Result<()> {
+            let rules: Vec<&str> = rules.map(AsRef::as_ref).collect();
+            let list: Vec<_> = rules
+                .iter()
+                .map(|rule| rule as &dyn biome_console::fmt::Display)
+                .collect();
+            visitor.record_list(list.as_slice())
+        }
+
+        if !self.migrated.is_empty() && self.migrated.len() != self.rule_count() {
+            visitor.record_log(
+                biome_diagnostics::LogCategory::Info,
+                &if self.write {
+                    markup! { "Migrated rules:" }
+                } else {
+                    markup! { "Rules that can be migrated:" }
+                },
+            )?;
+            record_rules(visitor, self.migrated.iter())?;
+        }
+        if !self.inspired.is_empty() {
+            visitor.record_log(
+                biome_diagnostics::LogCategory::Info,
+                &markup! { "Rules that can be migrated to an inspired rule using "<Emphasis>"--include-inspired"</Emphasis>":" },
+            )?;
+            record_rules(visitor, self.inspired.iter())?;
+        }
+        if !self.nursery.is_empty() {
+            visitor.record_log(
+                biome_diagnostics::LogCategory::Info,
+                &markup! { "Rules that can be migrated to a nursery rule using "<Emphasis>"--include-nursery"</Emphasis>":" },
+            )?;
+            record_rules(visitor, self.nursery.iter())?;
+        }
+        if !self.unsupported.is_empty() {
+            let rules_with = |predicate: fn(&UnsupportedRuleReason) -> bool| {
+                self.unsupported
+                    .iter()
+                    .filter(move |(_, reason)| predicate(reason))
+                    .map(|(rule, _)| rule)
+            };
+            let formatter_covers = rules_with(|reason| {
+                matches!(
+                    reason,
+                    UnsupportedRuleReason::FormatterCovers
+                        | UnsupportedRuleReason::FormatterOption(_)
+                        | UnsupportedRuleReason::Stylistic
+                )
+            });
+            let not_implemented = rules_with(|reason| {
+                matches!(reason, UnsupportedRuleReason::KnownSourceNotImplemented)
+            });
+            let unknown_source =
+                rules_with(|reason| matches!(reason, UnsupportedRuleReason::UnknownSource));
+            let formatter_covers_count = formatter_covers.clone().count();
+            let not_implemented_count = not_implemented.clone().count();
+            let unknown_source_count = unknown_source.clone().count();
+
+            visitor.record_log(
+                biome_diagnostics::LogCategory::Info,
+                &markup! { "Unsupported rules ("{formatter_covers_count}" made obsolete by the formatter, "{not_implemented_count}" not yet implemented, "{unknown_source_count}" from a Stylelint plugin):" },
+            )?;
+            if formatter_covers_count > 0 {
+                visitor.record_log(
+                    biome_diagnostics::LogCategory::Info,
+                    &markup! { "These rules enforce code styles that are handled by the formatter:" },
+                )?;
+                record_rules(visitor, formatter_covers)?;
+            }
+            if not_implemented_count > 0 {
+                visitor.record_log(
+                    biome_diagnostics::LogCategory::Info,
+                    &markup! { "These rules have not yet been implemented:" },
+                )?;
+                record_rules(visitor, not_implemented)?;
+            }
+            if unknown_source_count > 0 {
+                visitor.record_log(
+                    biome_diagnostics::LogCategory::Info,
+                    &markup! { "These rules originate from a Stylelint plugin that Biome doesn't know about:" },
+                )?;
+                record_rules(visitor, unknown_source)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn unsupported_rule_reason(rule_name: &str) -> UnsupportedRuleReason {
+    // Rules of Stylelint plugins are namespaced, e.g. `scss/at-rule-no-unknown`.
+    if rule_name.contains('/') {
+        return UnsupportedRuleReason::UnknownSource;
+    }
+    let sourced_rule = RuleSource::Stylelint(rule_name);
+    if let Ok(index) = UNSUPPORTED_RULES.binary_search_by(|rule| rule.0.cmp_any(&sourced_rule)) {
+        return UNSUPPORTED_RULES[index].1.clone();
+    }
+    UnsupportedRuleReason::KnownSourceNotImplemented
+}
+
+impl stylelint::ConfigData {
+    pub(crate) fn into_biome_config(
+        mut self,
+        options: &MigrationOptions,
+    ) -> (biome_config::Configuration, MigrationResults) {
+        self.apply_default_severity();
+        let mut results = MigrationResults::default();
+        let mut biome_config = biome_config::Configuration::default();
+        let rules = self.rules.into_biome_rules(options, &mut results);
+        biome_config.linter = Some(biome_config::LinterConfiguration {
+            rules: Some(rules),
+            ..Default::default()
+        });
+        if !self.overrides.is_empty() {
+            let mut overrides = biome_config::Overrides::default();
+            for override_elt in self.overrides {
+                let mut override_pattern = biome_config::OverridePattern::default();
+                let includes: Vec<biome_glob::NormalizedGlob> = override_elt
+                    .files
+                    .iter()
+                    .filter_map(|glob| glob.parse().ok())
+                    .collect();
+                override_pattern.includes = (!includes.is_empty())
+                    .then_some(biome_config::OverrideGlobs::Globs(includes.into()));
+                if !override_elt.rules.0.is_empty() {
+                    override_pattern.linter = Some(biome_config::OverrideLinterConfiguration {
+                        rules: Some(override_elt.rules.into_biome_rules(options, &mut results)),
+                        ..Default::default()
+                    });
+                }
+                overrides.0.push(override_pattern);
+            }
+            biome_config.overrides = Some(overrides);
+        }
+        (biome_config, results)
+    }
+}
+
+impl stylelint::Rules {
+    pub(crate) fn into_biome_rules(
+        self,
+        options: &MigrationOptions,
+        results: &mut MigrationResults,
+    ) -> biome_config::Rules {
+        let mut rules = biome_config::Rules::default();
+        for stylelint_rule in self.0 {
+            migrate_stylelint_rule(&mut rules, stylelint_rule, options, results);
+        }
+        rules
+    }
+}
+
+/// Look for an equivalent Biome rule for Stylelint `rule`,
+/// and then mutate `rules` if an equivalent rule is found.
+/// Also, takes care of Biome's rules with options.
+fn migrate_stylelint_rule(
+    rules: &mut biome_config::Rules,
+    rule: stylelint::Rule,
+    opts: &MigrationOptions,
+    results: &mut MigrationResults,
+) {
+    let name = rule.name();
+    match rule {
+        stylelint::Rule::Any(name, conf) => {
+            let _ = migrate_stylelint_any_rule(rules, &name, conf.severity(), opts, results);
+        }
+        stylelint::Rule::AtRuleNoUnknown(conf) => {
+            let level = conf.severity();
+            if migrate_stylelint_any_rule(rules, &name, level, opts, results)
+                && let stylelint::RuleConf::On(_, rule_options) = conf
+                && !rule_options.ignore_at_rules.is_empty()
+            {
+                let group = rules.suspicious.get_or_insert_with(Default::default);
+                if let SeverityOrGroup::Group(group) = group {
+                    group.no_unknown_at_rules = Some(biome_config::RuleConfiguration::WithOptions(
+                        biome_config::RuleWithOptions {
+                            level,
+                            options: rule_options.into(),
+                        },
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Converts Stylelint's `ignoreFiles` to negated globs of Biome's `files.includes`.
+pub(crate) fn to_biome_ignore_globs(
+    ignore_files: &[impl AsRef<str>],
+) -> Vec<biome_glob::NormalizedGlob> {
+    ignore_files
+        .iter()
+        .filter_map(|glob| {
+            glob.as_ref()
+                .parse()
+                .map(|glob: biome_glob::NormalizedGlob| glob.negated())
+                .ok()
+        })
+        .collect()
+}
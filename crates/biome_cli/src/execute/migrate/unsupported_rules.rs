@@ -5,7 +5,7 @@ use biome_analyze::RuleSource::*;
 use crate::execute::migrate::eslint_to_biome::UnsupportedRule;
 use crate::execute::migrate::eslint_to_biome::UnsupportedRuleReason::*;
 
-// Sorted ESLint unsupported rules.
+// Sorted ESLint and Stylelint unsupported rules.
 /// The array is sorted to allow binary search.
 pub const UNSUPPORTED_RULES: &[UnsupportedRule] = &[
     UnsupportedRule(Eslint("array-bracket-newline"), FormatterCovers),
@@ -328,6 +328,48 @@ pub const UNSUPPORTED_RULES: &[UnsupportedRule] = &[
     UnsupportedRule(EslintVueJs("space-infix-ops"), FormatterCovers),
     UnsupportedRule(EslintVueJs("space-unary-ops"), FormatterCovers),
     UnsupportedRule(EslintVueJs("template-curly-spacing"), FormatterCovers),
+    UnsupportedRule(
+        Stylelint("block-closing-brace-newline-after"),
+        FormatterCovers,
+    ),
+    UnsupportedRule(
+        Stylelint("block-closing-brace-newline-before"),
+        FormatterCovers,
+    ),
+    UnsupportedRule(
+        Stylelint("block-opening-brace-newline-after"),
+        FormatterCovers,
+    ),
+    UnsupportedRule(
+        Stylelint("block-opening-brace-space-before"),
+        FormatterCovers,
+    ),
+    UnsupportedRule(
+        Stylelint("declaration-block-semicolon-newline-after"),
+        FormatterCovers,
+    ),
+    UnsupportedRule(
+        Stylelint("declaration-block-trailing-semicolon"),
+        FormatterCovers,
+    ),
+    UnsupportedRule(Stylelint("declaration-colon-space-after"), FormatterCovers),
+    UnsupportedRule(Stylelint("declaration-colon-space-before"), FormatterCovers),
+    UnsupportedRule(Stylelint("function-comma-space-after"), FormatterCovers),
+    UnsupportedRule(Stylelint("indentation"), FormatterOption("indentStyle")),
+    UnsupportedRule(Stylelint("linebreaks"), FormatterOption("lineEnding")),
+    UnsupportedRule(Stylelint("max-empty-lines"), FormatterCovers),
+    UnsupportedRule(Stylelint("max-line-length"), FormatterOption("lineWidth")),
+    UnsupportedRule(Stylelint("no-eol-whitespace"), FormatterCovers),
+    UnsupportedRule(Stylelint("no-extra-semicolons"), FormatterCovers),
+    UnsupportedRule(
+        Stylelint("no-missing-end-of-source-newline"),
+        FormatterCovers,
+    ),
+    UnsupportedRule(
+        Stylelint("selector-list-comma-newline-after"),
+        FormatterCovers,
+    ),
+    UnsupportedRule(Stylelint("string-quotes"), FormatterOption("quoteStyle")),
 ];
 
 #[cfg(test)]
@@ -1,3 +1,5 @@
+mod baseline;
+pub(crate) mod cache;
 mod diagnostics;
 mod migrate;
 mod process_file;
@@ -7,6 +9,8 @@ pub(crate) mod traverse;
 use crate::cli_options::{CliOptions, CliReporter};
 use crate::commands::MigrateSubCommand;
 use crate::diagnostics::ReportDiagnostic;
+pub(crate) use crate::execute::baseline::Baseline;
+use crate::execute::cache::AnalysisCache;
 use crate::execute::migrate::MigratePayload;
 use crate::execute::traverse::{TraverseResult, traverse};
 use crate::reporter::checkstyle::CheckstyleReporter;
@@ -15,6 +19,7 @@ use crate::reporter::gitlab::{GitLabReporter, GitLabReporterVisitor};
 use crate::reporter::json::{JsonReporter, JsonReporterVisitor};
 use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
 use crate::reporter::rdjson::{RdJsonReporter, RdJsonReporterVisitor};
+use crate::reporter::sarif::{SarifReporter, SarifReporterVisitor};
 use crate::reporter::summary::{SummaryReporter, SummaryReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
 use crate::{
@@ -48,6 +53,12 @@ pub struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u32,
+
+    /// The on-disk cache of the results, when enabled via `--cache`
+    cache: Option<AnalysisCache>,
+
+    /// The baseline of diagnostics, when enabled via `--baseline` or `--write-baseline`
+    baseline: Option<Baseline>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -178,9 +189,17 @@ pub enum TraversalMode {
     Search {
         /// The GritQL pattern to search for.
         ///
-        /// Note that the search command does not support rewrites.
+        /// When the pattern contains rewrites, the command prints the diff of
+        /// the rewritten files.
         pattern: PatternId,
 
+        /// The source of the GritQL pattern. Unlike [PatternId], which is only
+        /// a handle to the parsed pattern, it identifies the pattern across runs.
+        pattern_source: String,
+
+        /// Write the rewrites of the pattern to disk
+        write: bool,
+
         /// The language to query for.
         ///
         /// Grit queries are specific to the grammar of the language they
@@ -252,6 +271,8 @@ pub enum ReportMode {
     Checkstyle,
     /// Reports information in [reviewdog JSON format](https://deepwiki.com/reviewdog/reviewdog/3.2-reviewdog-diagnostic-format)
     RdJson,
+    /// Reports diagnostics in [SARIF 2.1.0 format](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+    Sarif,
 }
 
 impl Default for ReportMode {
@@ -276,6 +297,7 @@ impl From<CliReporter> for ReportMode {
             CliReporter::GitLab => Self::GitLab {},
             CliReporter::Checkstyle => Self::Checkstyle,
             CliReporter::RdJson => Self::RdJson,
+            CliReporter::Sarif => Self::Sarif,
         }
     }
 }
@@ -286,6 +308,8 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            cache: None,
+            baseline: None,
         }
     }
 
@@ -312,6 +336,8 @@ impl Execution {
                 skip_parse_errors,
             },
             max_diagnostics: 20,
+            cache: None,
+            baseline: None,
         }
     }
 
@@ -321,6 +347,26 @@ impl Execution {
         self
     }
 
+    /// It sets the on-disk cache used during the traversal
+    pub(crate) fn set_cache(mut self, cache: Option<AnalysisCache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    pub(crate) fn cache(&self) -> Option<&AnalysisCache> {
+        self.cache.as_ref()
+    }
+
+    /// It sets the baseline used to filter the diagnostics of the traversal
+    pub(crate) fn set_baseline(mut self, baseline: Option<Baseline>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    pub(crate) fn baseline(&self) -> Option<&Baseline> {
+        self.baseline.as_ref()
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
@@ -410,8 +456,10 @@ impl Execution {
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
-            TraversalMode::CI { .. } | TraversalMode::Search { .. } => false,
-            TraversalMode::Format { write, .. } | TraversalMode::Migrate { write, .. } => write,
+            TraversalMode::CI { .. } => false,
+            TraversalMode::Format { write, .. }
+            | TraversalMode::Migrate { write, .. }
+            | TraversalMode::Search { write, .. } => write,
         }
     }
 
@@ -435,6 +483,17 @@ impl Execution {
         }
     }
 
+    /// Returns [true] if the user used the `--changed` option
+    pub(crate) fn is_vcs_changed(&self) -> bool {
+        match &self.traversal_mode {
+            TraversalMode::Check { vcs_targeted, .. }
+            | TraversalMode::Lint { vcs_targeted, .. }
+            | TraversalMode::Format { vcs_targeted, .. }
+            | TraversalMode::CI { vcs_targeted, .. } => vcs_targeted.changed,
+            TraversalMode::Migrate { .. } | TraversalMode::Search { .. } => false,
+        }
+    }
+
     /// Returns [true] if the user used the `--write`/`--fix` option
     pub(crate) fn is_write(&self) -> bool {
         match self.traversal_mode {
@@ -443,7 +502,7 @@ impl Execution {
             TraversalMode::CI { .. } => false,
             TraversalMode::Format { write, .. } => write,
             TraversalMode::Migrate { write, .. } => write,
-            TraversalMode::Search { .. } => false,
+            TraversalMode::Search { write, .. } => write,
         }
     }
 
@@ -457,6 +516,8 @@ impl Execution {
             },
             report_mode: ReportMode::default(),
             max_diagnostics: 0,
+            cache: None,
+            baseline: None,
         }
     }
 
@@ -480,7 +541,7 @@ impl Execution {
     #[instrument(level = "debug", skip(self), fields(result))]
     pub(crate) fn should_write(&self) -> bool {
         let result = match self.traversal_mode {
-            TraversalMode::Format { write, .. } => write,
+            TraversalMode::Format { write, .. } | TraversalMode::Search { write, .. } => write,
 
             _ => self.is_safe_fixes_enabled() || self.is_safe_and_unsafe_fixes_enabled(),
         };
@@ -518,6 +579,12 @@ impl Execution {
             _ => false,
         }
     }
+
+    /// Whether the diagnostics suppressed by suppression comments should be
+    /// collected, because the reporter keeps track of them
+    pub(crate) fn should_report_suppressed(&self) -> bool {
+        matches!(self.report_mode, ReportMode::Sarif)
+    }
 }
 
 /// Based on the [mode](TraversalMode), the function might launch a traversal of the file system
@@ -731,6 +798,15 @@ pub fn execute_mode(
             };
             reporter.write(&mut RdJsonReporterVisitor(console))?;
         }
+        ReportMode::Sarif => {
+            let reporter = SarifReporter {
+                diagnostics_payload,
+                execution: execution.clone(),
+                verbose: cli_options.verbose,
+                working_directory: fs.working_directory().clone(),
+            };
+            reporter.write(&mut SarifReporterVisitor(console))?;
+        }
     }
 
     // Processing emitted error diagnostics, exit with a non-zero code
@@ -5,6 +5,7 @@ mod search;
 pub(crate) mod workspace_file;
 
 use crate::execute::TraversalMode;
+use crate::execute::cache::CacheEntry;
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::traverse::TraversalOptions;
 use biome_analyze::RuleCategoriesBuilder;
@@ -17,6 +18,7 @@ use check::check_file;
 use format::format;
 use lint_and_assist::lint_and_assist;
 use search::search;
+use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
 use std::ops::Deref;
 
@@ -30,6 +32,9 @@ pub(crate) enum FileStatus {
     Message(Message),
     /// A match was found while searching a file
     SearchResult(usize, Message),
+    /// The rewrites of a pattern were written to the file, after finding the
+    /// given number of matches
+    Rewritten(usize),
     /// File ignored, it should not be count as "handled"
     Ignored,
     /// Files that belong to other tools and shouldn't be touched
@@ -71,9 +76,12 @@ impl Message {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) enum DiffKind {
     Format,
+    /// The rewrites of a GritQL pattern, applied by `biome search`
+    Rewrite,
 }
 
 impl<D> From<D> for Message
@@ -101,16 +109,33 @@ pub(crate) type FileResult = Result<FileStatus, Message>;
 /// compiler constraints set by the lifetimes of the [TraversalOptions]
 pub(crate) struct SharedTraversalOptions<'ctx, 'app> {
     inner: &'app TraversalOptions<'ctx, 'app>,
+    /// The cache entry of the processed file, which records the messages sent
+    /// to the display thread
+    cache_entry: Option<&'app CacheEntry>,
     _p: PhantomData<&'app ()>,
 }
 
 impl<'ctx, 'app> SharedTraversalOptions<'ctx, 'app> {
-    fn new(t: &'app TraversalOptions<'ctx, 'app>) -> Self {
+    fn new(t: &'app TraversalOptions<'ctx, 'app>, cache_entry: Option<&'app CacheEntry>) -> Self {
         Self {
             _p: PhantomData,
             inner: t,
+            cache_entry,
         }
     }
+
+    /// Send a message to the display thread
+    pub(crate) fn push_message(&self, msg: impl Into<Message>) {
+        let msg = msg.into();
+        match self.cache_entry {
+            Some(cache_entry) => self.inner.push_message(cache_entry.record(msg)),
+            None => self.inner.push_message(msg),
+        }
+    }
+
+    pub(crate) fn push_diagnostic(&self, error: Error) {
+        self.push_message(error);
+    }
 }
 
 impl<'ctx, 'app> Deref for SharedTraversalOptions<'ctx, 'app> {
@@ -182,9 +207,20 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
         };
     }
 
-    let shared_context = &SharedTraversalOptions::new(ctx);
+    let mut cache_entry = ctx
+        .execution
+        .cache()
+        .and_then(|cache| cache.entry(ctx.fs, biome_path));
+    if let Some(status) = cache_entry
+        .as_mut()
+        .and_then(|cache_entry| cache_entry.replay(|msg| ctx.push_message(msg)))
+    {
+        return Ok(status);
+    }
+
+    let shared_context = &SharedTraversalOptions::new(ctx, cache_entry.as_ref());
 
-    match ctx.execution.traversal_mode {
+    let result = match ctx.execution.traversal_mode {
         TraversalMode::Lint {
             ref suppression_reason,
             suppress,
@@ -215,5 +251,10 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
             // the unsupported case should be handled already at this point
             search(shared_context, biome_path.clone(), pattern)
         }
+    };
+
+    match cache_entry {
+        Some(cache_entry) => cache_entry.store(ctx.fs, result),
+        None => result,
     }
 }
@@ -4,7 +4,7 @@ use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::DiagnosticExt;
-use biome_fs::{BiomePath, TraversalContext};
+use biome_fs::BiomePath;
 use biome_service::diagnostics::FileTooLarge;
 use biome_service::workspace::FeaturesSupported;
 
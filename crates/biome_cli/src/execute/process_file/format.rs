@@ -5,7 +5,7 @@ use crate::execute::process_file::{
 };
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, Error, Severity, category};
-use biome_fs::{BiomePath, TraversalContext};
+use biome_fs::BiomePath;
 use biome_service::diagnostics::FileTooLarge;
 use biome_service::file_handlers::astro::AstroFileHandler;
 use biome_service::file_handlers::svelte::SvelteFileHandler;
@@ -46,6 +46,7 @@ pub(crate) fn format_with_guard<'ctx>(
             Vec::new(),
             Vec::new(),
             false, // NOTE: probably to revisit
+            false,
         )
         .with_file_path_and_code(workspace_file.path.to_string(), category!("format"))?;
 
@@ -4,7 +4,7 @@ use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
 use biome_analyze::RuleCategories;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, Error, Severity, category};
-use biome_fs::{BiomePath, TraversalContext};
+use biome_fs::BiomePath;
 use biome_rowan::TextSize;
 use biome_service::diagnostics::FileTooLarge;
 use biome_service::file_handlers::astro::AstroFileHandler;
@@ -120,7 +120,13 @@ pub(crate) fn analyze_with_guard<'ctx>(
 
     let pull_diagnostics_result = workspace_file
         .guard()
-        .pull_diagnostics(categories, only, skip, true)
+        .pull_diagnostics(
+            categories,
+            only,
+            skip,
+            true,
+            ctx.execution.should_report_suppressed(),
+        )
         .with_file_path_and_code(
             workspace_file.path.to_string(),
             ctx.execution.as_diagnostic_category(),
@@ -1,9 +1,11 @@
 use crate::execute::TraversalMode;
 use crate::execute::diagnostics::{ResultExt, SearchDiagnostic};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
-use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use crate::execute::process_file::{
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
 use biome_diagnostics::{DiagnosticExt, category};
-use biome_fs::{BiomePath, TraversalContext};
+use biome_fs::BiomePath;
 use biome_grit_patterns::{GritTargetLanguage, JsTargetLanguage};
 use biome_service::diagnostics::FileTooLarge;
 use biome_service::file_handlers::DocumentFileSource;
@@ -29,14 +31,14 @@ pub(crate) fn search<'ctx>(
 }
 
 pub(crate) fn search_with_guard<'ctx>(
-    _ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     workspace_file: &mut WorkspaceFile,
     pattern: &PatternId,
 ) -> FileResult {
     let _ = tracing::info_span!("Search ", path =? workspace_file.path).entered();
 
     let file_source = DocumentFileSource::from_path(workspace_file.path.as_path(), false);
-    let pattern_language = match &_ctx.execution.traversal_mode {
+    let pattern_language = match &ctx.execution.traversal_mode {
         TraversalMode::Search {
             language: Some(pattern_language),
             ..
@@ -62,6 +64,24 @@ pub(crate) fn search_with_guard<'ctx>(
     let file_name = workspace_file.path.to_string();
     let matches_len = result.matches.len();
 
+    if let Some(rewritten) = result.rewritten.filter(|rewritten| rewritten != &input) {
+        if ctx.execution.should_write() {
+            workspace_file.update_file(rewritten)?;
+            return Ok(FileStatus::Rewritten(matches_len));
+        }
+
+        // Without `--write`, the diff of the rewrites replaces the matches
+        return Ok(FileStatus::SearchResult(
+            matches_len,
+            Message::Diff {
+                file_name,
+                old: input,
+                new: rewritten,
+                diff_kind: DiffKind::Rewrite,
+            },
+        ));
+    }
+
     let search_results = Message::Diagnostics {
         file_path: file_name,
         content: input,
@@ -85,5 +105,14 @@ fn is_file_compatible_with_pattern(
         GritTargetLanguage::CssTargetLanguage(_) => {
             matches!(file_source, DocumentFileSource::Css(_))
         }
+        GritTargetLanguage::JsonTargetLanguage(_) => {
+            matches!(file_source, DocumentFileSource::Json(_))
+        }
+        GritTargetLanguage::HtmlTargetLanguage(_) => {
+            matches!(file_source, DocumentFileSource::Html(_))
+        }
+        GritTargetLanguage::GraphqlTargetLanguage(_) => {
+            matches!(file_source, DocumentFileSource::Graphql(_))
+        }
     }
 }
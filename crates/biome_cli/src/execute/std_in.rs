@@ -3,19 +3,21 @@
 use crate::cli_options::CliOptions;
 use crate::diagnostics::StdinDiagnostic;
 use crate::execute::Execution;
+use crate::execute::diagnostics::{ContentDiffAdvice, RewriteDiffDiagnostic};
 use crate::{CliDiagnostic, CliSession, TraversalMode};
 use biome_analyze::RuleCategoriesBuilder;
 use biome_console::{ConsoleExt, markup};
-use biome_diagnostics::Diagnostic;
 use biome_diagnostics::PrintDiagnostic;
+use biome_diagnostics::{Diagnostic, DiagnosticExt};
 use biome_fs::BiomePath;
-use biome_service::WorkspaceError;
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::projects::ProjectKey;
 use biome_service::workspace::{
     ChangeFileParams, CloseFileParams, DropPatternParams, FeaturesBuilder, FileContent,
-    FileFeaturesResult, FixFileParams, FormatFileParams, OpenFileParams, SupportsFeatureParams,
+    FileFeaturesResult, FixFileParams, FormatFileParams, OpenFileParams, PatternId,
+    SearchPatternParams, SupportsFeatureParams,
 };
+use biome_service::{Workspace, WorkspaceError};
 use std::borrow::Cow;
 
 pub(crate) fn run<'a>(
@@ -247,16 +249,64 @@ pub(crate) fn run<'a>(
             project_key,
             path: biome_path.clone(),
         })?;
-    } else if let TraversalMode::Search { pattern, .. } = mode.traversal_mode() {
+    } else if let TraversalMode::Search { pattern, write, .. } = mode.traversal_mode() {
+        let result = rewrite_stdin(workspace, project_key, &biome_path, pattern, content);
+
         // Make sure patterns are always cleaned up at the end of execution.
         let _ = session.app.workspace.drop_pattern(DropPatternParams {
             pattern: pattern.clone(),
         });
 
-        console.append(markup! {{content}});
+        match result?.filter(|rewritten| rewritten != content) {
+            Some(rewritten) if *write => console.append(markup! {{rewritten}}),
+            Some(rewritten) => {
+                // Without `--write`, the diff of the rewrites is printed, like
+                // during the traversal of the files
+                let diagnostic = RewriteDiffDiagnostic {
+                    diff: ContentDiffAdvice {
+                        old: content.to_string(),
+                        new: rewritten,
+                    },
+                }
+                .with_file_path(biome_path.to_string())
+                .with_file_source_code(content);
+                console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+                console.append(markup! {{content}});
+            }
+            None => console.append(markup! {{content}}),
+        }
     } else {
         console.append(markup! {{content}});
     }
 
     Ok(())
 }
+
+/// Returns the content of the file piped via `stdin`, after applying the
+/// rewrites of the pattern.
+fn rewrite_stdin(
+    workspace: &dyn Workspace,
+    project_key: ProjectKey,
+    biome_path: &BiomePath,
+    pattern: &PatternId,
+    content: &str,
+) -> Result<Option<String>, WorkspaceError> {
+    workspace.open_file(OpenFileParams {
+        project_key,
+        path: biome_path.clone(),
+        content: FileContent::from_client(content),
+        document_file_source: None,
+        persist_node_cache: false,
+    })?;
+    let result = workspace.search_pattern(SearchPatternParams {
+        project_key,
+        path: biome_path.clone(),
+        pattern: pattern.clone(),
+    });
+    workspace.close_file(CloseFileParams {
+        project_key,
+        path: biome_path.clone(),
+    })?;
+
+    Ok(result?.rewritten)
+}
@@ -1,11 +1,14 @@
+use super::baseline::BaselineFilter;
 use super::process_file::{DiffKind, FileStatus, Message, process_file};
 use super::{Execution, TraversalMode};
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     CIFormatDiffDiagnostic, ContentDiffAdvice, FormatDiffDiagnostic, PanicDiagnostic,
+    RewriteDiffDiagnostic,
 };
 use crate::reporter::TraversalSummary;
 use crate::{CliDiagnostic, CliSession};
+use biome_console::{ConsoleExt, markup};
 use biome_diagnostics::DiagnosticTags;
 use biome_diagnostics::{DiagnosticExt, Error, Resource, Severity, category};
 use biome_fs::{BiomePath, FileSystem, PathInterner};
@@ -56,12 +59,13 @@ pub(crate) fn traverse(
     let max_diagnostics = execution.get_max_diagnostics();
 
     let working_directory = fs.working_directory();
-    let printer = DiagnosticsPrinter::new(execution, working_directory.as_deref())
+    let mut printer = DiagnosticsPrinter::new(execution, working_directory.as_deref())
         .with_verbose(cli_options.verbose)
         .with_diagnostic_level(cli_options.diagnostic_level)
-        .with_max_diagnostics(max_diagnostics);
+        .with_max_diagnostics(max_diagnostics)
+        .with_baseline(execution.baseline().map(BaselineFilter::new));
 
-    let (duration, evaluated_paths, diagnostics) = thread::scope(|s| {
+    let (duration, evaluated_paths, mut diagnostics) = thread::scope(|s| {
         let handler = thread::Builder::new()
             .name(String::from("biome::console"))
             .spawn_scoped(s, || printer.run(receiver, recv_files))
@@ -99,9 +103,34 @@ pub(crate) fn traverse(
         });
     }
 
+    let mut infos = printer.infos();
+    if let Some(baseline) = printer.baseline.take() {
+        let evaluated_paths = evaluated_paths
+            .iter()
+            .map(|path| {
+                printer
+                    .to_relative_file_path(path.as_str())
+                    .replace('\\', "/")
+            })
+            .collect();
+        if baseline.baseline().is_write() {
+            let path = baseline.baseline().path().to_string();
+            let count = baseline.write(fs, &evaluated_paths)?;
+            session.app.console.log(markup! {
+                <Info>"The baseline "<Emphasis>{path}</Emphasis>" has been written with "{count}" diagnostic(s)."</Info>
+            });
+        } else if !printer.should_skip_diagnostic(Severity::Information, DiagnosticTags::empty()) {
+            for diagnostic in baseline.stale_diagnostics(fs, &evaluated_paths) {
+                infos += 1;
+                if printer.should_print() || execution.is_ci() {
+                    diagnostics.push(diagnostic);
+                }
+            }
+        }
+    }
+
     let errors = printer.errors();
     let warnings = printer.warnings();
-    let infos = printer.infos();
     let changed = changed.load(Ordering::Relaxed);
     let unchanged = unchanged.load(Ordering::Relaxed);
     let matches = matches.load(Ordering::Relaxed);
@@ -181,6 +210,9 @@ struct DiagnosticsPrinter<'ctx> {
 
     /// The current working directory, borrowed from [FileSystem]
     working_directory: Option<&'ctx Utf8Path>,
+
+    /// The baseline that filters the diagnostics, when enabled
+    baseline: Option<BaselineFilter<'ctx>>,
 }
 
 impl<'ctx> DiagnosticsPrinter<'ctx> {
@@ -198,6 +230,7 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
             printed_diagnostics: AtomicU32::new(0),
             total_skipped_suggested_fixes: AtomicU32::new(0),
             working_directory,
+            baseline: None,
         }
     }
 
@@ -216,6 +249,11 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         self
     }
 
+    fn with_baseline(mut self, baseline: Option<BaselineFilter<'ctx>>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
     fn errors(&self) -> u32 {
         self.errors.load(Ordering::Relaxed)
     }
@@ -344,9 +382,29 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                         .fetch_add(skipped_diagnostics, Ordering::Relaxed);
                     for diag in diagnostics {
                         let severity = diag.severity();
+                        // Diagnostics recorded in the baseline aren't reported. They're
+                        // matched before any filtering, so that the baseline is the same
+                        // regardless of the options that affect what's printed
+                        if !diag.tags().is_suppressed()
+                            && self.baseline.as_ref().is_some_and(|baseline| {
+                                baseline.matches(&file_path, &content, &diag)
+                            })
+                        {
+                            continue;
+                        }
                         if self.should_skip_diagnostic(severity, diag.tags()) {
                             continue;
                         }
+                        // Suppressed diagnostics are only collected for the reporters
+                        // that track them, they don't count towards the summary nor
+                        // towards the maximum number of printed diagnostics
+                        if diag.tags().is_suppressed() {
+                            diagnostics_to_print.push(
+                                diag.with_file_path(file_path.as_str())
+                                    .with_file_source_code(&content),
+                            );
+                            continue;
+                        }
                         if severity == Severity::Error {
                             self.errors.fetch_add(1, Ordering::Relaxed);
                         }
@@ -374,19 +432,23 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     diff_kind,
                 } => {
                     let file_path = self.to_relative_file_path(&file_name);
-                    // A diff is an error in CI mode and in format check mode
-                    let is_error = self.execution.is_ci() || !self.execution.is_format_write();
-                    if is_error {
+                    let severity: Severity = match diff_kind {
+                        DiffKind::Format => {
+                            // A diff is an error in CI mode and in format check mode
+                            if self.execution.is_ci() || !self.execution.is_format_write() {
+                                Severity::Error
+                            } else {
+                                // we set lowest
+                                Severity::Hint
+                            }
+                        }
+                        // A rewrite that wasn't written is a preview, like the search matches
+                        DiffKind::Rewrite => Severity::Information,
+                    };
+                    if severity == Severity::Error {
                         self.errors.fetch_add(1, Ordering::Relaxed);
                     }
 
-                    let severity: Severity = if is_error {
-                        Severity::Error
-                    } else {
-                        // we set lowest
-                        Severity::Hint
-                    };
-
                     if self.should_skip_diagnostic(severity, DiagnosticTags::empty()) {
                         continue;
                     }
@@ -421,6 +483,18 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                     diagnostics_to_print.push(diag);
                                 }
                             }
+                            DiffKind::Rewrite => {
+                                let diag = RewriteDiffDiagnostic {
+                                    diff: ContentDiffAdvice {
+                                        old: old.clone(),
+                                        new: new.clone(),
+                                    },
+                                }
+                                .with_severity(severity)
+                                .with_file_source_code(old.clone())
+                                .with_file_path(file_path.clone());
+                                diagnostics_to_print.push(diag);
+                            }
                         }
                     }
                 }
@@ -645,6 +719,10 @@ fn handle_file(ctx: &TraversalOptions, path: &BiomePath) {
             ctx.increment_matches(num_matches);
             ctx.push_message(msg);
         }
+        Ok(Ok(FileStatus::Rewritten(num_matches))) => {
+            ctx.increment_changed(path);
+            ctx.increment_matches(num_matches);
+        }
         Ok(Ok(FileStatus::Message(msg))) => {
             ctx.increment_unchanged();
             ctx.push_message(msg);
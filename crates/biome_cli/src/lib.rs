@@ -94,6 +94,7 @@ impl<'app> CliSession<'app> {
                 format_with_errors,
                 json_parser,
                 css_parser,
+                baseline,
             } => run_command(
                 self,
                 &cli_options,
@@ -114,6 +115,7 @@ impl<'app> CliSession<'app> {
                     format_with_errors,
                     json_parser,
                     css_parser,
+                    baseline,
                 },
             ),
             BiomeCommand::Lint {
@@ -139,6 +141,7 @@ impl<'app> CliSession<'app> {
                 graphql_linter,
                 css_parser,
                 json_parser,
+                baseline,
             } => run_command(
                 self,
                 &cli_options,
@@ -164,6 +167,7 @@ impl<'app> CliSession<'app> {
                     graphql_linter,
                     css_parser,
                     json_parser,
+                    baseline,
                 },
             ),
             BiomeCommand::Ci {
@@ -179,6 +183,7 @@ impl<'app> CliSession<'app> {
                 format_with_errors,
                 css_parser,
                 json_parser,
+                baseline,
                 ..
             } => run_command(
                 self,
@@ -195,6 +200,7 @@ impl<'app> CliSession<'app> {
                     format_with_errors,
                     css_parser,
                     json_parser,
+                    baseline,
                 },
             ),
             BiomeCommand::Format {
@@ -211,6 +217,8 @@ impl<'app> CliSession<'app> {
                 css_formatter,
                 graphql_formatter,
                 html_formatter,
+                yaml_formatter,
+                markdown_formatter,
                 staged,
                 changed,
                 since,
@@ -232,6 +240,8 @@ impl<'app> CliSession<'app> {
                     css_formatter,
                     graphql_formatter,
                     html_formatter,
+                    yaml_formatter,
+                    markdown_formatter,
                     staged,
                     changed,
                     since,
@@ -270,6 +280,7 @@ impl<'app> CliSession<'app> {
                 language,
                 stdin_file_path,
                 vcs_configuration,
+                write,
             } => run_command(
                 self,
                 &cli_options,
@@ -280,6 +291,7 @@ impl<'app> CliSession<'app> {
                     language,
                     stdin_file_path,
                     vcs_configuration,
+                    write,
                 },
             ),
             BiomeCommand::RunServer {
@@ -4,6 +4,7 @@ pub(crate) mod gitlab;
 pub(crate) mod json;
 pub(crate) mod junit;
 pub(crate) mod rdjson;
+pub(crate) mod sarif;
 pub(crate) mod summary;
 pub(crate) mod terminal;
 
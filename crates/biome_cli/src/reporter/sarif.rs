@@ -0,0 +1,485 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary, VERSION};
+use biome_analyze::RegistryRuleMetadata;
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{Console, ConsoleExt, MarkupBuf, markup};
+use biome_diagnostics::display::{SourceFile, markup_to_string};
+use biome_diagnostics::{
+    Category, Error, LineIndex, LogCategory, PrintDescription, Severity, SourceCode, Visit,
+};
+use biome_rowan::{TextRange, TextSize};
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Serialize;
+use std::collections::HashMap;
+
+pub(crate) struct SarifReporter {
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) execution: Execution,
+    pub(crate) verbose: bool,
+    pub(crate) working_directory: Option<Utf8PathBuf>,
+}
+
+impl Reporter for SarifReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> std::io::Result<()> {
+        visitor.report_diagnostics(
+            &self.execution,
+            self.diagnostics_payload,
+            self.verbose,
+            self.working_directory.as_deref(),
+        )?;
+        Ok(())
+    }
+}
+
+pub(crate) struct SarifReporterVisitor<'a>(pub(crate) &'a mut dyn Console);
+
+impl ReporterVisitor for SarifReporterVisitor<'_> {
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        _summary: TraversalSummary,
+        _verbose: bool,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+        verbose: bool,
+        _working_directory: Option<&Utf8Path>,
+    ) -> std::io::Result<()> {
+        let mut rules = SarifRules::default();
+        let results: Vec<_> = payload
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity() >= payload.diagnostic_level)
+            .filter(|diagnostic| verbose || !diagnostic.tags().is_verbose())
+            .filter_map(|diagnostic| diagnostic_to_sarif(diagnostic, &mut rules).transpose())
+            .collect::<std::io::Result<_>>()?;
+
+        let report = SarifLog {
+            schema: "https://json.schemastore.org/sarif-2.1.0.json",
+            version: "2.1.0",
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifToolComponent {
+                        name: "Biome",
+                        information_uri: "https://biomejs.dev",
+                        version: VERSION,
+                        rules: rules.descriptors,
+                    },
+                },
+                results,
+            }],
+        };
+
+        let result = serde_json::to_string_pretty(&report)?;
+
+        self.0.log(markup! {
+            {result}
+        });
+
+        Ok(())
+    }
+}
+
+/// Collects the rules referenced by the results of a run, so that each rule is
+/// described only once in the `tool.driver.rules` array
+#[derive(Default)]
+struct SarifRules {
+    descriptors: Vec<SarifReportingDescriptor>,
+    indices: HashMap<&'static str, usize>,
+}
+
+impl SarifRules {
+    /// Returns the index of the descriptor of the given category, recording it
+    /// if it's seen for the first time
+    fn index_of(&mut self, category: &'static Category) -> usize {
+        if let Some(index) = self.indices.get(category.name()) {
+            return *index;
+        }
+
+        let metadata = find_rule_metadata(category);
+        let descriptor = SarifReportingDescriptor {
+            id: category.name(),
+            short_description: metadata
+                .as_ref()
+                .map(|metadata| SarifMessage {
+                    text: rule_description(metadata.rule.docs),
+                })
+                .filter(|description| !description.text.is_empty()),
+            help_uri: category.link(),
+            default_configuration: metadata.map(|metadata| SarifReportingConfiguration {
+                level: to_sarif_level(metadata.rule.severity),
+            }),
+        };
+
+        let index = self.descriptors.len();
+        self.descriptors.push(descriptor);
+        self.indices.insert(category.name(), index);
+        index
+    }
+}
+
+/// Looks up the metadata of the rule that emits the diagnostics of the given
+/// category, e.g. `lint/suspicious/noDebugger`
+fn find_rule_metadata(category: &Category) -> Option<RegistryRuleMetadata> {
+    let name = category.name();
+    let (group, rule) = name
+        .strip_prefix("lint/")
+        .or_else(|| name.strip_prefix("assist/"))?
+        .split_once('/')?;
+
+    biome_js_analyze::METADATA
+        .find_rule_metadata(group, rule)
+        .or_else(|| biome_css_analyze::METADATA.find_rule_metadata(group, rule))
+        .or_else(|| biome_json_analyze::METADATA.find_rule_metadata(group, rule))
+        .or_else(|| biome_graphql_analyze::METADATA.find_rule_metadata(group, rule))
+        .or_else(|| biome_html_analyze::METADATA.find_rule_metadata(group, rule))
+        .or_else(|| biome_yaml_analyze::METADATA.find_rule_metadata(group, rule))
+}
+
+/// Returns the first paragraph of the documentation of a rule
+fn rule_description(docs: &str) -> String {
+    docs.lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn to_sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Hint | Severity::Information => "note",
+        Severity::Warning => "warning",
+        Severity::Error | Severity::Fatal => "error",
+    }
+}
+
+/// Converts a diagnostic into a SARIF result. Returns `None` for the
+/// diagnostics that don't have a category, since SARIF results require a rule.
+fn diagnostic_to_sarif(
+    diagnostic: &Error,
+    rules: &mut SarifRules,
+) -> std::io::Result<Option<SarifResult>> {
+    let Some(category) = diagnostic.category() else {
+        return Ok(None);
+    };
+    let location = diagnostic.location();
+    let uri = location
+        .resource
+        .and_then(|resource| resource.as_file().map(|path| path.replace('\\', "/")))
+        .unwrap_or_default();
+    let region = match (location.span, location.source_code) {
+        (Some(span), Some(source_code)) => {
+            to_sarif_region(&SourceFile::new(source_code), source_code.text, span)
+        }
+        _ => None,
+    };
+
+    let fixes = match location.source_code {
+        Some(source_code) if !uri.is_empty() => to_sarif_fixes(diagnostic, source_code, &uri)?,
+        _ => Vec::new(),
+    };
+
+    let suppressions = if diagnostic.tags().is_suppressed() {
+        vec![SarifSuppression { kind: "inSource" }]
+    } else {
+        Vec::new()
+    };
+
+    let locations = if uri.is_empty() {
+        Vec::new()
+    } else {
+        vec![SarifLocation {
+            physical_location: SarifPhysicalLocation {
+                artifact_location: SarifArtifactLocation { uri },
+                region,
+            },
+        }]
+    };
+
+    Ok(Some(SarifResult {
+        rule_id: category.name(),
+        rule_index: rules.index_of(category),
+        level: to_sarif_level(diagnostic.severity()),
+        message: SarifMessage {
+            text: PrintDescription(diagnostic).to_string(),
+        },
+        locations,
+        fixes,
+        suppressions,
+    }))
+}
+
+/// Converts a range of `text` into a SARIF region. The columns are counted in
+/// UTF-16 code units, which is the default `columnKind` of SARIF.
+fn to_sarif_region(source: &SourceFile, text: &str, range: TextRange) -> Option<SarifRegion> {
+    let start = source.location(range.start()).ok()?;
+    let end = source.location(range.end()).ok()?;
+    Some(SarifRegion {
+        start_line: start.line_number.get(),
+        start_column: utf16_column(text, range.start())?,
+        end_line: end.line_number.get(),
+        end_column: utf16_column(text, range.end())?,
+    })
+}
+
+/// Returns the one-based column of `offset` in its line, in UTF-16 code units
+fn utf16_column(text: &str, offset: TextSize) -> Option<usize> {
+    let before = text.get(..usize::from(offset))?;
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    Some(before[line_start..].encode_utf16().count() + 1)
+}
+
+/// Collects the code suggestions of a diagnostic as SARIF fixes. Each code
+/// suggestion is recorded as a log advice describing the fix, followed by
+/// the diff of the fix.
+struct FixesVisitor<'a> {
+    source_code: SourceCode<&'a str, &'a LineIndex>,
+    uri: &'a str,
+    last_message: Option<String>,
+    fixes: Vec<SarifFix>,
+}
+
+impl Visit for FixesVisitor<'_> {
+    fn record_log(&mut self, _category: LogCategory, text: &dyn Display) -> std::io::Result<()> {
+        let mut message = MarkupBuf::default();
+        let mut fmt = Formatter::new(&mut message);
+        fmt.write_markup(markup!({ { text } }))?;
+        let message = markup_to_string(&message).ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid markup in advice")
+        })?;
+        self.last_message = Some(message);
+        Ok(())
+    }
+
+    fn record_diff(&mut self, diff: &TextEdit) -> std::io::Result<()> {
+        let source = SourceFile::new(self.source_code);
+        let replacements = to_sarif_replacements(&source, self.source_code.text, diff);
+        if replacements.is_empty() {
+            return Ok(());
+        }
+
+        self.fixes.push(SarifFix {
+            description: SarifMessage {
+                text: self.last_message.take().unwrap_or_default(),
+            },
+            artifact_changes: vec![SarifArtifactChange {
+                artifact_location: SarifArtifactLocation {
+                    uri: self.uri.to_string(),
+                },
+                replacements,
+            }],
+        });
+
+        Ok(())
+    }
+}
+
+fn to_sarif_fixes(
+    diagnostic: &Error,
+    source_code: SourceCode<&str, &LineIndex>,
+    uri: &str,
+) -> std::io::Result<Vec<SarifFix>> {
+    let mut visitor = FixesVisitor {
+        source_code,
+        uri,
+        last_message: None,
+        fixes: Vec::new(),
+    };
+
+    diagnostic.advices(&mut visitor)?;
+
+    Ok(visitor.fixes)
+}
+
+/// Converts a [TextEdit] into the list of regions of the original text that
+/// are replaced, along with their new content
+fn to_sarif_replacements(
+    source: &SourceFile,
+    text: &str,
+    diff: &TextEdit,
+) -> Vec<SarifReplacement> {
+    let mut edits: Vec<(TextRange, String)> = Vec::new();
+    let mut offset = TextSize::from(0);
+
+    for op in diff.iter() {
+        match op {
+            CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                offset += range.len();
+            }
+            CompressedOp::DiffOp(DiffOp::Insert { range }) => {
+                // Merge with a previous delete operation if possible
+                match edits.last_mut() {
+                    Some((last_range, content)) if last_range.end() == offset => {
+                        content.push_str(diff.get_text(*range));
+                    }
+                    _ => {
+                        edits.push((TextRange::empty(offset), diff.get_text(*range).to_string()));
+                    }
+                }
+            }
+            CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                let deleted = TextRange::at(offset, range.len());
+                match edits.last_mut() {
+                    Some((last_range, content))
+                        if last_range.end() == offset && content.is_empty() =>
+                    {
+                        *last_range = last_range.cover(deleted);
+                    }
+                    _ => edits.push((deleted, String::new())),
+                }
+                offset += range.len();
+            }
+            CompressedOp::EqualLines { line_count } => {
+                let input = &text[usize::from(offset)..];
+                for line in input
+                    .split_inclusive('\n')
+                    .take(line_count.get() as usize + 1)
+                {
+                    offset += TextSize::of(line);
+                }
+            }
+        }
+    }
+
+    edits
+        .into_iter()
+        .filter_map(|(range, content)| {
+            Some(SarifReplacement {
+                deleted_region: to_sarif_region(source, text, range)?,
+                inserted_content: (!content.is_empty())
+                    .then_some(SarifArtifactContent { text: content }),
+            })
+        })
+        .collect()
+}
+
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifToolComponent,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifToolComponent {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<SarifReportingDescriptor>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReportingDescriptor {
+    id: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_description: Option<SarifMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_configuration: Option<SarifReportingConfiguration>,
+}
+
+#[derive(Serialize)]
+struct SarifReportingConfiguration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: &'static str,
+    rule_index: usize,
+    level: &'static str,
+    message: SarifMessage,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suppressions: Vec<SarifSuppression>,
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    description: SarifMessage,
+    artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inserted_content: Option<SarifArtifactContent>,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactContent {
+    text: String,
+}
+
+#[derive(Serialize)]
+struct SarifSuppression {
+    kind: &'static str,
+}
@@ -168,7 +168,12 @@ struct SummaryDetail<'a>(pub(crate) &'a TraversalMode, usize);
 impl fmt::Display for SummaryDetail<'_> {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
         let Self(mode, files) = self;
-        if let TraversalMode::Search { .. } = mode {
+        if let TraversalMode::Search { write, .. } = mode {
+            if *write && *files > 0 {
+                return fmt.write_markup(markup! {
+                    " Rewrote "{Files(*files)}"."
+                });
+            }
             return Ok(());
         }
 
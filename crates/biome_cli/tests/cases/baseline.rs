@@ -0,0 +1,252 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystem, MemoryFileSystem};
+use bpaf::Args;
+use camino::Utf8Path;
+
+const DEBUGGER: &str = "debugger;\n";
+
+#[test]
+fn writes_the_diagnostics_to_the_baseline() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\nvar a = 1;\ndebugger;\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--write-baseline", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(fs.path_exists(Utf8Path::new("biome-baseline.json")));
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "writes_the_diagnostics_to_the_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_only_the_diagnostics_missing_from_the_baseline() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    let baseline_path = Utf8Path::new("baseline.json");
+    fs.insert(file_path.into(), DEBUGGER.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write-baseline",
+                "--baseline",
+                baseline_path.as_str(),
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The recorded diagnostic moved to another line, and a new one was added
+    fs.insert(
+        file_path.into(),
+        "function f() {}\n\n  debugger;\nf();\ndebugger;\n".as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--baseline",
+                baseline_path.as_str(),
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_only_the_diagnostics_missing_from_the_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_the_stale_entries_of_the_baseline() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), DEBUGGER.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--write-baseline", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.insert(file_path.into(), "console.log(1);\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--baseline=biome-baseline.json",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_the_stale_entries_of_the_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn records_the_diagnostics_hidden_by_the_diagnostic_level() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\nvar a = 1;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write-baseline",
+                "--diagnostic-level=error",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The warning of `noUnusedVariables` was recorded too
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--error-on-warnings",
+                "--baseline=biome-baseline.json",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "records_the_diagnostics_hidden_by_the_diagnostic_level",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ci_filters_the_diagnostics_recorded_in_the_baseline() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), DEBUGGER.as_bytes());
+    fs.insert(
+        Utf8Path::new("biome-baseline.json").into(),
+        r#"{
+  "version": 1,
+  "entries": [
+    {
+      "path": "file.js",
+      "rule": "lint/suspicious/noDebugger",
+      "hash": "bea666795f4cfaa4",
+      "count": 1
+    }
+  ]
+}
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["ci", "--baseline=biome-baseline.json", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ci_filters_the_diagnostics_recorded_in_the_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn errors_when_the_baseline_is_missing() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), DEBUGGER.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--baseline=missing.json", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "errors_when_the_baseline_is_missing",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,183 @@
+use crate::run_cli;
+use biome_console::BufferConsole;
+use biome_fs::{FileSystem, MemoryFileSystem};
+use bpaf::Args;
+use camino::{Utf8Path, Utf8PathBuf};
+
+const DEBUGGER: &str = "debugger;\n";
+const DEBUGGER_TWICE: &str = "debugger;\ndebugger;\n";
+
+/// Returns the paths of the cache entries stored in `fs`
+fn cache_entries(fs: &MemoryFileSystem) -> Vec<Utf8PathBuf> {
+    let cache_directory = biome_fs::ensure_cache_dir().join("biome-analysis-cache");
+    fs.files
+        .read()
+        .keys()
+        .filter(|path| path.starts_with(&cache_directory))
+        .cloned()
+        .collect()
+}
+
+/// Removes the stored messages from the cache entry at `path`, so that we can
+/// tell whether the next run reused it
+fn empty_cache_entry(fs: &MemoryFileSystem, path: &Utf8Path) {
+    let content = fs.read_file_from_path(path).unwrap();
+    let mut entry: serde_json::Value = serde_json::from_str(&content).unwrap();
+    entry["messages"] = serde_json::json!([]);
+    fs.insert(path.into(), entry.to_string());
+}
+
+#[test]
+fn reuses_the_results_of_unchanged_files() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), DEBUGGER.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let entries = cache_entries(&fs);
+    assert_eq!(entries.len(), 1, "expected a single cache entry");
+    empty_cache_entry(&fs, &entries[0]);
+
+    let (_, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn processes_changed_files_again() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), DEBUGGER.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let entries = cache_entries(&fs);
+    assert_eq!(entries.len(), 1, "expected a single cache entry");
+    empty_cache_entry(&fs, &entries[0]);
+    fs.insert(file_path.into(), DEBUGGER_TWICE.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_eq!(
+        cache_entries(&fs),
+        entries,
+        "expected the entry to be replaced"
+    );
+}
+
+#[test]
+fn replaces_the_entries_of_other_configurations() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), DEBUGGER.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let entries = cache_entries(&fs);
+    assert_eq!(entries.len(), 1, "expected a single cache entry");
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#.as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(
+        cache_entries(&fs),
+        entries,
+        "expected the entry to be replaced"
+    );
+}
+
+#[test]
+fn doesnt_store_results_when_writing() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), DEBUGGER.as_bytes());
+
+    let (fs, _) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--write", "--cache", file_path.as_str()].as_slice()),
+    );
+
+    assert!(cache_entries(&fs).is_empty(), "expected no cache entries");
+}
+
+#[test]
+fn replays_the_rewrites_of_search_patterns() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "const a = 'foo';\n".as_bytes());
+
+    let args = [
+        "search",
+        "--cache",
+        "`'foo'` => `'bar'`",
+        file_path.as_str(),
+    ];
+    let (fs, result) = run_cli(fs, &mut console, Args::from(args.as_slice()));
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(cache_entries(&fs).len(), 1, "expected a single cache entry");
+
+    // A replayed rewrite is still a preview, not a formatting error
+    let (fs, result) = run_cli(fs, &mut console, Args::from(args.as_slice()));
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--cache",
+                "`'foo'` => `'baz'`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(
+        cache_entries(&fs).len(),
+        1,
+        "expected the entry to be replaced"
+    );
+}
@@ -0,0 +1,217 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const CONFIGURATION: &str = r#"{
+    "javascript": {
+        "embeddedLanguages": {
+            "enabled": true
+        }
+    }
+}"#;
+
+const UNFORMATTED: &str = r#"import styled, { css, createGlobalStyle } from "styled-components";
+
+export const Button = styled.button`
+    color:${(props) => props.color};
+  padding:4px ${space}px;
+  ${Icon}:hover{margin:0}
+  &:hover{color:RED}
+  ${mixin}
+`;
+
+export const Title = styled(Heading).attrs(attrs)`
+font-size:2em;${property}:bold
+`;
+
+export const mixin = css`border:1px solid ${border};`;
+
+export const GlobalStyle = createGlobalStyle`
+body{margin:0}
+`;
+
+export const html = String.raw`a{color:red}`;
+"#;
+
+const FORMATTED: &str = r#"import styled, { css, createGlobalStyle } from "styled-components";
+
+export const Button = styled.button`
+	color: ${(props) => props.color};
+	padding: 4px ${space}px;
+	${Icon}:hover {
+		margin: 0;
+	}
+	&:hover {
+		color: RED;
+	}
+	${mixin}
+`;
+
+export const Title = styled(Heading).attrs(attrs)`
+	font-size: 2em;
+	${property}: bold;
+`;
+
+export const mixin = css`
+	border: 1px solid ${border};
+`;
+
+export const GlobalStyle = createGlobalStyle`
+	body {
+		margin: 0;
+	}
+`;
+
+export const html = String.raw`a{color:red}`;
+"#;
+
+#[test]
+fn css_in_js_is_not_formatted_by_default() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "css_in_js_is_not_formatted_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_css_in_js() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("biome.json").into(), CONFIGURATION.as_bytes());
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_css_in_js",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_css_in_js_with_configured_tags() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "javascript": {
+        "embeddedLanguages": {
+            "enabled": true,
+            "cssTags": ["style"]
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"const a = style.div`color:red`;
+const b = css`color:red`;
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"const a = style.div`
+	color: red;
+`;
+const b = css`color:red`;
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_css_in_js_with_configured_tags",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_css_in_js() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("biome.json").into(), CONFIGURATION.as_bytes());
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"export const Button = styled.button`
+  color: ${(props) => props.color};
+  colr: red;
+  ${Icon}:hover {
+    margin: 0;
+  }
+`;
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_css_in_js",
+        fs,
+        console,
+        result,
+    ));
+}
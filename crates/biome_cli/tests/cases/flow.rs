@@ -0,0 +1,105 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const UNFORMATTED: &str = r#"// @flow
+opaque   type Id: string = string;
+type User = {|id: Id, name: ?string|};
+function getName(user: User): ?string { return user.name }
+"#;
+
+const FORMATTED: &str = r#"// @flow
+opaque type Id: string = string;
+type User = {| id: Id, name: ?string |};
+function getName(user: User): ?string {
+	return user.name;
+}
+"#;
+
+#[test]
+fn format_js_file_with_flow_pragma() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_js_file_with_flow_pragma",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_flow_file() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("index.js.flow");
+    fs.insert(
+        file_path.into(),
+        UNFORMATTED.trim_start_matches("// @flow\n").as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED.trim_start_matches("// @flow\n"));
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_flow_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_js_file_without_flow_pragma() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        UNFORMATTED.trim_start_matches("// @flow\n").as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_js_file_without_flow_pragma",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,183 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const CONFIGURATION: &str = r#"{
+    "javascript": {
+        "embeddedLanguages": {
+            "enabled": true
+        }
+    }
+}"#;
+
+const UNFORMATTED: &str = r#"import { gql } from "@apollo/client";
+import { graphql } from "./gql";
+
+export const GET_USER = gql`
+  query GetUser($id: ID!) { user(id: $id) { id, ...UserFields  } }
+  ${USER_FIELDS}
+`;
+
+export const GET_POSTS = graphql(`
+query GetPosts { posts(first: ${count}) { id title } }
+`);
+
+export const text = String.raw`query { user }`;
+"#;
+
+const FORMATTED: &str = r#"import { gql } from "@apollo/client";
+import { graphql } from "./gql";
+
+export const GET_USER = gql`
+	query GetUser($id: ID!) {
+		user(id: $id) {
+			id
+			...UserFields
+		}
+	}
+	${USER_FIELDS}
+`;
+
+export const GET_POSTS = graphql(`
+	query GetPosts {
+		posts(first: ${count}) {
+			id
+			title
+		}
+	}
+`);
+
+export const text = String.raw`query { user }`;
+"#;
+
+#[test]
+fn format_graphql_in_js() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("biome.json").into(), CONFIGURATION.as_bytes());
+
+    let file_path = Utf8Path::new("file.ts");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_graphql_in_js",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_graphql_in_js_with_configured_tags() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "javascript": {
+        "embeddedLanguages": {
+            "enabled": true,
+            "graphqlTags": ["query"]
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("file.ts");
+    fs.insert(
+        file_path.into(),
+        r#"const a = query`query A { a }`;
+const b = query(`query B { b }`);
+const c = gql`query C { c }`;
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"const a = query`
+	query A {
+		a
+	}
+`;
+const b = query(`
+	query B {
+		b
+	}
+`);
+const c = gql`query C { c }`;
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_graphql_in_js_with_configured_tags",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_graphql_in_js() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("biome.json").into(), CONFIGURATION.as_bytes());
+
+    let file_path = Utf8Path::new("file.ts");
+    fs.insert(
+        file_path.into(),
+        r#"export const GET_USER = gql`
+  query {
+    user(id: ${id}) {
+      name
+    }
+  }
+`;
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_graphql_in_js",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,131 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const UNFORMATTED: &str = r#"// JSON5 configuration
+{unquoted: 'single', "quoted": 0xFF, leading: .5, trailing: 5., infinity: -Infinity,}
+"#;
+
+#[test]
+fn format_json5_file() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.json5");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"// JSON5 configuration
+{
+	unquoted: 'single',
+	"quoted": 0xff,
+	leading: 0.5,
+	trailing: 5,
+	infinity: -Infinity
+}
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_json5_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_json5_file_with_quote_properties_as_needed() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.json5");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                "--json-formatter-quote-properties=as-needed",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"// JSON5 configuration
+{
+	unquoted: 'single',
+	quoted: 0xff,
+	leading: 0.5,
+	trailing: 5,
+	infinity: -Infinity
+}
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_json5_file_with_quote_properties_as_needed",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn quote_properties_has_no_effect_on_json_files() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.json");
+    fs.insert(file_path.into(), r#"{ "quoted": 1 }"#.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                "--json-formatter-quote-properties=as-needed",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "{ \"quoted\": 1 }\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "quote_properties_has_no_effect_on_json_files",
+        fs,
+        console,
+        result,
+    ));
+}
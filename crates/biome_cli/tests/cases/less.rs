@@ -0,0 +1,94 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const UNFORMATTED: &str = r#"@gutter:10px;
+
+.bordered(@width:2px;@style:solid) when (@width>0){border:@width @style black}
+
+.block{
+  .bordered(4px;dashed);
+  margin:-@gutter;
+  filter:~"ms:alwaysHasItsOwnSyntax.For.Stuff()";
+}
+"#;
+
+const FORMATTED: &str = r#"@gutter: 10px;
+
+.bordered(@width: 2px; @style: solid) when (@width > 0) {
+	border: @width @style black;
+}
+
+.block {
+	.bordered(4px; dashed);
+	margin: -@gutter;
+	filter: ~"ms:alwaysHasItsOwnSyntax.For.Stuff()";
+}
+"#;
+
+#[test]
+fn format_less_files() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.less");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_less_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_less_files() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.less");
+    fs.insert(
+        file_path.into(),
+        r#"@font-stack: Helvetica, sans-serif;
+
+.block {
+  font: 100% @font-stack;
+  color: darken(#333, 10%);
+  background: e("red");
+  height: unknown(10px);
+}
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_less_files",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,164 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const UNFORMATTED: &str = r#"# Title
+
+* first
+* second
+
+Some __bold__ text.
+
+```js
+const a   =   {b:1}
+```
+
+```css
+a{color:red}
+```
+
+```json
+{"key":[1,2]}
+```
+
+```graphql
+query { user(id:1) { name } }
+```
+"#;
+
+const FORMATTED: &str = r#"# Title
+
+- first
+- second
+
+Some **bold** text.
+
+```js
+const a = { b: 1 };
+```
+
+```css
+a {
+  color: red;
+}
+```
+
+```json
+{ "key": [1, 2] }
+```
+
+```graphql
+query {
+  user(id: 1) {
+    name
+  }
+}
+```
+"#;
+
+#[test]
+fn markdown_formatter_is_disabled_by_default() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.md");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "markdown_formatter_is_disabled_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_markdown_files_with_configuration() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "markdown": {
+        "formatter": {
+            "enabled": true
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("file.md");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_markdown_files_with_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_markdown_files_with_cli_options() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.md");
+    fs.insert(
+        file_path.into(),
+        "- first\n- second\n\nSome *italic* text.\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                "--markdown-formatter-enabled=true",
+                "--markdown-formatter-bullet-list-marker=asterisk",
+                "--markdown-formatter-emphasis-style=underscore",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "* first\n* second\n\nSome _italic_ text.\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_markdown_files_with_cli_options",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -2,15 +2,20 @@
 //! case that affects many commands
 
 mod assist;
+mod baseline;
 mod biome_json_support;
+mod cache;
 mod config_extends;
 mod config_path;
+mod css_in_js;
 mod css_parsing;
 mod cts_files;
 mod diagnostics;
 mod editorconfig;
+mod flow;
 mod format_with_errors;
 mod graphql;
+mod graphql_in_js;
 mod handle_astro_files;
 mod handle_css_files;
 mod handle_svelte_files;
@@ -18,9 +23,12 @@ mod handle_vue_files;
 mod html;
 mod included_files;
 mod indent_script_and_style;
+mod json5;
 mod json_parsing;
+mod less;
 mod linter_domains;
 mod linter_groups_plain;
+mod markdown;
 mod migrate_v2;
 mod monorepo;
 mod overrides_formatter;
@@ -33,10 +41,13 @@ mod reporter_github;
 mod reporter_gitlab;
 mod reporter_junit;
 mod reporter_rdjson;
+mod reporter_sarif;
 mod reporter_summary;
 mod reporter_terminal;
 mod rules_via_dependencies;
+mod scss;
 mod suppressions;
 mod tailwind_directives;
 mod unknown_files;
 mod vcs_ignored_files;
+mod yaml;
@@ -0,0 +1,102 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const UNFORMATTED: &str = r#"@use "sass:math";
+$gutter:10px !default;
+
+@mixin theme($theme:DarkGray){background:$theme}
+
+.block{
+  @include theme;
+  width:math.div($gutter,2);
+  &__element{margin:-$gutter}
+}
+"#;
+
+const FORMATTED: &str = r#"@use "sass:math";
+$gutter: 10px !default;
+
+@mixin theme($theme: DarkGray) {
+	background: $theme;
+}
+
+.block {
+	@include theme;
+	width: math.div($gutter, 2);
+	&__element {
+		margin: -$gutter;
+	}
+}
+"#;
+
+#[test]
+fn format_scss_files() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.scss");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_scss_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_scss_files() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.scss");
+    fs.insert(
+        file_path.into(),
+        r#"@use "sass:math";
+$font-stack: Helvetica, sans-serif;
+
+.block {
+  font: 100% $font-stack;
+  width: math.div(10px, 2);
+  color: darken(#333, 10%);
+  height: unknown(10px);
+  &__element {
+    color: red;
+  }
+}
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_scss_files",
+        fs,
+        console,
+        result,
+    ));
+}
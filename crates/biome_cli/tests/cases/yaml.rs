@@ -0,0 +1,153 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const UNFORMATTED: &str = r#"key:   'value'
+list:
+-   a
+-   b
+nested:
+      inner: [1,2,   3]
+"#;
+
+const FORMATTED: &str = "key: \"value\"\nlist:\n  - a\n  - b\nnested:\n  inner: [1, 2, 3]\n";
+
+#[test]
+fn format_yaml_files() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.yaml");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_yaml_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_and_write_yaml_files() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.yml");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_and_write_yaml_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_yaml_files_with_configuration() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "yaml": {
+        "formatter": {
+            "quoteStyle": "single",
+            "indentSequences": false
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("file.yaml");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "key: 'value'\nlist:\n- a\n- b\nnested:\n  inner: [1, 2, 3]\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_yaml_files_with_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_yaml_files_with_cli_options() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.yaml");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                "--yaml-formatter-indent-width=4",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "key: \"value\"\nlist:\n    - a\n    - b\nnested:\n    inner: [1, 2, 3]\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_yaml_files_with_cli_options",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -3200,6 +3200,100 @@ const foo = 'bad'
     ));
 }
 
+#[test]
+fn check_plugin_applies_safe_fixes() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8PathBuf::from("biome.json"),
+        br#"{
+    "plugins": ["useConsoleInfo.grit"]
+}
+"#,
+    );
+
+    fs.insert(
+        Utf8PathBuf::from("useConsoleInfo.grit"),
+        br#"language js;
+
+`console.log($message)` as $call where {
+    register_diagnostic(
+        span = $call,
+        message = "Use console.info instead.",
+        fix_kind = "safe"
+    ),
+    $call => `console.info($message)`
+}
+"#,
+    );
+
+    let file_path = "file.js";
+    fs.insert(
+        file_path.into(),
+        br#"console.log("first");
+const value = 1;
+console.log(value);
+"#,
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["check", "--write", file_path].as_slice()),
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_plugin_applies_safe_fixes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_plugin_reports_unsafe_fixes() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8PathBuf::from("biome.json"),
+        br#"{
+    "plugins": ["useConsoleInfo.grit"]
+}
+"#,
+    );
+
+    fs.insert(
+        Utf8PathBuf::from("useConsoleInfo.grit"),
+        br#"language js;
+
+`console.log($message)` as $call where {
+    register_diagnostic(span = $call, message = "Use console.info instead."),
+    $call => `console.info($message)`
+}
+"#,
+    );
+
+    let file_path = "file.js";
+    fs.insert(file_path.into(), b"console.log(\"first\");\n");
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["check", "--write", file_path].as_slice()),
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_plugin_reports_unsafe_fixes",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn doesnt_check_file_when_assist_is_disabled() {
     let fs = MemoryFileSystem::default();
@@ -2291,6 +2291,124 @@ fn should_only_processes_changed_files_when_changed_flag_is_set() {
     ));
 }
 
+#[test]
+fn should_process_dependent_packages_when_changed_flag_is_set() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_files(Box::new(|| vec![String::from("packages/shared/index.js")]));
+
+    fs.insert(
+        Utf8Path::new("package.json").into(),
+        r#"{ "name": "monorepo", "workspaces": ["packages/*"] }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/shared/package.json").into(),
+        r#"{ "name": "shared" }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/shared/index.js").into(),
+        "debugger;".as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/app/package.json").into(),
+        r#"{ "name": "app", "dependencies": { "shared": "workspace:*" } }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/app/index.js").into(),
+        "debugger;".as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/other/package.json").into(),
+        r#"{ "name": "other" }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/other/index.js").into(),
+        "debugger;".as_bytes(),
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", "--changed", "--since=main"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_process_dependent_packages_when_changed_flag_is_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_process_dependent_pnpm_packages_when_changed_flag_is_set() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_files(Box::new(|| vec![String::from("packages/shared/index.js")]));
+
+    fs.insert(
+        Utf8Path::new("package.json").into(),
+        r#"{ "name": "monorepo" }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("pnpm-workspace.yaml").into(),
+        "packages:\n  - \"packages/*\"\n".as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/shared/package.json").into(),
+        r#"{ "name": "shared" }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/shared/index.js").into(),
+        "debugger;".as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/lib/package.json").into(),
+        r#"{ "name": "lib", "dependencies": { "shared": "workspace:^" } }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/lib/index.js").into(),
+        "debugger;".as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/app/package.json").into(),
+        r#"{ "name": "app", "dependencies": { "lib": "workspace:*" } }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/app/index.js").into(),
+        "debugger;".as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/other/package.json").into(),
+        r#"{ "name": "other" }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/other/index.js").into(),
+        "debugger;".as_bytes(),
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", "--changed", "--since=main"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_process_dependent_pnpm_packages_when_changed_flag_is_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn should_error_if_changed_flag_is_used_without_since_or_default_branch_config() {
     let mut console = BufferConsole::default();
@@ -3819,6 +3937,61 @@ fn linter_finds_package_json_for_no_undeclared_dependencies() {
     );
 }
 
+#[test]
+fn no_undeclared_dependencies_reports_workspace_packages() {
+    let mut console = BufferConsole::default();
+    let fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "correctness": {
+                "noUndeclaredDependencies": "on"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    fs.insert(
+        Utf8Path::new("package.json").into(),
+        r#"{ "name": "monorepo", "workspaces": ["packages/*"] }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/shared/package.json").into(),
+        r#"{ "name": "@monorepo/shared" }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("packages/app/package.json").into(),
+        r#"{ "name": "@monorepo/app" }"#.as_bytes(),
+    );
+
+    let file = Utf8Path::new("packages/app/index.js");
+    fs.insert(file.into(), r#"import "@monorepo/shared";"#.as_bytes());
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", file.as_str()].as_slice()),
+    );
+    assert_cli_snapshot_with_redactor(
+        SnapshotPayload::new(
+            module_path!(),
+            "no_undeclared_dependencies_reports_workspace_packages",
+            fs,
+            console,
+            result,
+        ),
+        |content| {
+            content
+                .replace("packages\\app\\", "packages/app/")
+                .replace("packages\\shared", "packages/shared")
+        },
+    );
+}
+
 #[test]
 fn linter_finds_nested_package_json_for_no_undeclared_dependencies() {
     let mut console = BufferConsole::default();
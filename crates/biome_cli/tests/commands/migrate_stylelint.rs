@@ -0,0 +1,254 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+#[test]
+fn migrate_stylelintrcjson() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"{
+        "extends": [],
+        "ignoreFiles": ["dist/**", "**/*.min.css"],
+        "rules": {
+            "block-no-empty": true,
+            "color-no-hex": true,
+            "declaration-block-no-duplicate-properties": [true, { "severity": "warning" }],
+            "font-family-no-missing-generic-family-keyword": null,
+            "at-rule-no-unknown": [true, { "ignoreAtRules": ["tailwind", "/^my-/"] }],
+            "indentation": 2,
+            "selector-max-id": 0,
+            "scss/dollar-variable-pattern": "^foo"
+        },
+        "overrides": [{
+            "files": ["legacy/**/*.css"],
+            "rules": {
+                "block-no-empty": null
+            }
+        }]
+    }"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Utf8Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrcjson",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintrcjson_write() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"{
+        "extends": [],
+        "ignoreFiles": ["dist/**", "**/*.min.css"],
+        "rules": {
+            "block-no-empty": true,
+            "color-no-hex": true,
+            "declaration-block-no-duplicate-properties": [true, { "severity": "warning" }],
+            "font-family-no-missing-generic-family-keyword": null,
+            "at-rule-no-unknown": [true, { "ignoreAtRules": ["tailwind", "/^my-/"] }],
+            "indentation": 2,
+            "selector-max-id": 0,
+            "scss/dollar-variable-pattern": "^foo"
+        },
+        "overrides": [{
+            "files": ["legacy/**/*.css"],
+            "rules": {
+                "block-no-empty": null
+            }
+        }]
+    }"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Utf8Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrcjson_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintrcjson_include_nursery() {
+    let biomejson = r#"{}"#;
+    let stylelintrc = r#"{
+        "defaultSeverity": "warning",
+        "rules": {
+            "color-no-hex": true,
+            "unit-no-unknown": [true, { "severity": "error" }]
+        }
+    }"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Utf8Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint", "--include-nursery", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrcjson_include_nursery",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintrc_files_includes() {
+    let biomejson = r#"{ "files": { "includes": ["src/**"] } }"#;
+    let stylelintrc = r#"{
+        // comments are allowed
+        "ignoreFiles": "src/vendor/**",
+        "rules": { "unit-no-unknown": true }
+    }"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Utf8Path::new(".stylelintrc").into(), stylelintrc.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrc_files_includes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelint_config_packagejson() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let packagejson = r#"{
+        "name": "foo",
+        "version": "0.0.0",
+        "stylelint": {
+            "rules": {
+                "unit-no-unknown": true
+            }
+        }
+    }"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Utf8Path::new("package.json").into(), packagejson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelint_config_packagejson",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintrcyaml_unsupported() {
+    let biomejson = r#"{}"#;
+    let stylelintrc = "";
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Utf8Path::new(".stylelintrc.yaml").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrcyaml_unsupported",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_no_stylelint_config() {
+    let biomejson = r#"{}"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_no_stylelint_config",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1,11 +1,11 @@
-use biome_console::BufferConsole;
+use biome_console::{BufferConsole, markup};
 use biome_fs::MemoryFileSystem;
 use bpaf::Args;
 use camino::Utf8Path;
 
 use crate::{
     run_cli,
-    snap_test::{SnapshotPayload, assert_cli_snapshot},
+    snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents, markup_to_string},
 };
 
 // Feel free to add content at the end of this dummy file. It shouldn't affect
@@ -18,6 +18,26 @@ const CSS_FILE_CONTENT: &str = r#"div {
 // existing tests.
 const JS_FILE_CONTENT: &str = r#"const a = 'foo';"#;
 
+// Feel free to add content at the end of this dummy file. It shouldn't affect
+// existing tests.
+const JSON_FILE_CONTENT: &str = r#"{
+    "dependencies": {
+        "lodash": "*"
+    }
+}"#;
+
+// Feel free to add content at the end of this dummy file. It shouldn't affect
+// existing tests.
+const HTML_FILE_CONTENT: &str = r#"<div class="hidden"></div>"#;
+
+// Feel free to add content at the end of this dummy file. It shouldn't affect
+// existing tests.
+const GRAPHQL_FILE_CONTENT: &str = r#"query {
+    user(id: 1) {
+        name
+    }
+}"#;
+
 #[test]
 fn search_css_pattern() {
     let fs = MemoryFileSystem::default();
@@ -169,3 +189,271 @@ fn search_css_pattern_skips_js_files() {
         result,
     ));
 }
+
+#[test]
+fn search_js_pattern_prints_rewrites() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), JS_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["search", "`'foo'` => `'bar'`", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_js_pattern_prints_rewrites",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_js_pattern_writes_rewrites() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), JS_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--write",
+                "`'foo'` => `'bar'`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "const a = 'bar';");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_js_pattern_writes_rewrites",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_stdin_writes_rewrites() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push(JS_FILE_CONTENT.to_string());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--write",
+                "--stdin-file-path=file.js",
+                "`'foo'` => `'bar'`",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+    assert_eq!(content, "const a = 'bar';");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_stdin_writes_rewrites",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_stdin_prints_rewrites() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push(JS_FILE_CONTENT.to_string());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["search", "--stdin-file-path=file.js", "`'foo'` => `'bar'`"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .last()
+        .expect("Console should have written a message");
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+    assert_eq!(content, JS_FILE_CONTENT);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_stdin_prints_rewrites",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_json_pattern() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("package.json");
+    fs.insert(file_path.into(), JSON_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--language=json",
+                "`\"lodash\": $version`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_json_pattern",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_html_pattern() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("index.html");
+    fs.insert(file_path.into(), HTML_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--language=html",
+                "`class='hidden'`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_html_pattern",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_graphql_pattern() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("query.graphql");
+    fs.insert(file_path.into(), GRAPHQL_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--language=graphql",
+                "`user(id: $id) { $fields }`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_graphql_pattern",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_json_pattern_skips_js_files() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let js_file_path = Utf8Path::new("file.js");
+    let json_file_path = Utf8Path::new("file.json");
+    fs.insert(js_file_path.into(), JS_FILE_CONTENT.as_bytes());
+    fs.insert(json_file_path.into(), JSON_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--language=json",
+                "`\"*\"`",
+                js_file_path.as_str(),
+                json_file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_json_pattern_skips_js_files",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -94,6 +94,7 @@ pub enum RuleName {
     NoAdjacentSpacesInRegex,
     NoAlert,
     NoAmbiguousAnchorText,
+    NoAmbiguousBooleanValues,
     NoApproximativeNumericConstant,
     NoArguments,
     NoAriaHiddenOnFocusable,
@@ -157,6 +158,7 @@ pub enum RuleName {
     NoDuplicateGraphqlOperationName,
     NoDuplicateInputFieldNames,
     NoDuplicateJsxProps,
+    NoDuplicateMappingKeys,
     NoDuplicateObjectKeys,
     NoDuplicateParameters,
     NoDuplicateProperties,
@@ -222,6 +224,7 @@ pub enum RuleName {
     NoInvalidPositionAtImportRule,
     NoInvalidUseBeforeDeclaration,
     NoIrregularWhitespace,
+    NoJsonSchemaViolations,
     NoJsxLiterals,
     NoJsxPropsBind,
     NoLabelVar,
@@ -305,6 +308,7 @@ pub enum RuleName {
     NoThisInStatic,
     NoTsIgnore,
     NoUnassignedVariables,
+    NoUndeclaredAliases,
     NoUndeclaredDependencies,
     NoUndeclaredEnvVars,
     NoUndeclaredVariables,
@@ -396,6 +400,7 @@ pub enum RuleName {
     UseConsistentCurlyBraces,
     UseConsistentEnumValueType,
     UseConsistentGraphqlDescriptions,
+    UseConsistentIndentation,
     UseConsistentMemberAccessibility,
     UseConsistentMethodSignatures,
     UseConsistentObjectDefinitions,
@@ -485,6 +490,7 @@ pub enum RuleName {
     UseSingleVarDeclarator,
     UseSolidForComponent,
     UseSortedClasses,
+    UseSortedMappingKeys,
     UseSpread,
     UseStaticResponseMethods,
     UseStrictMode,
@@ -534,6 +540,7 @@ impl RuleName {
             Self::NoAdjacentSpacesInRegex => "noAdjacentSpacesInRegex",
             Self::NoAlert => "noAlert",
             Self::NoAmbiguousAnchorText => "noAmbiguousAnchorText",
+            Self::NoAmbiguousBooleanValues => "noAmbiguousBooleanValues",
             Self::NoApproximativeNumericConstant => "noApproximativeNumericConstant",
             Self::NoArguments => "noArguments",
             Self::NoAriaHiddenOnFocusable => "noAriaHiddenOnFocusable",
@@ -599,6 +606,7 @@ impl RuleName {
             Self::NoDuplicateGraphqlOperationName => "noDuplicateGraphqlOperationName",
             Self::NoDuplicateInputFieldNames => "noDuplicateInputFieldNames",
             Self::NoDuplicateJsxProps => "noDuplicateJsxProps",
+            Self::NoDuplicateMappingKeys => "noDuplicateMappingKeys",
             Self::NoDuplicateObjectKeys => "noDuplicateObjectKeys",
             Self::NoDuplicateParameters => "noDuplicateParameters",
             Self::NoDuplicateProperties => "noDuplicateProperties",
@@ -666,6 +674,7 @@ impl RuleName {
             Self::NoInvalidPositionAtImportRule => "noInvalidPositionAtImportRule",
             Self::NoInvalidUseBeforeDeclaration => "noInvalidUseBeforeDeclaration",
             Self::NoIrregularWhitespace => "noIrregularWhitespace",
+            Self::NoJsonSchemaViolations => "noJsonSchemaViolations",
             Self::NoJsxLiterals => "noJsxLiterals",
             Self::NoJsxPropsBind => "noJsxPropsBind",
             Self::NoLabelVar => "noLabelVar",
@@ -751,6 +760,7 @@ impl RuleName {
             Self::NoThisInStatic => "noThisInStatic",
             Self::NoTsIgnore => "noTsIgnore",
             Self::NoUnassignedVariables => "noUnassignedVariables",
+            Self::NoUndeclaredAliases => "noUndeclaredAliases",
             Self::NoUndeclaredDependencies => "noUndeclaredDependencies",
             Self::NoUndeclaredEnvVars => "noUndeclaredEnvVars",
             Self::NoUndeclaredVariables => "noUndeclaredVariables",
@@ -842,6 +852,7 @@ impl RuleName {
             Self::UseConsistentCurlyBraces => "useConsistentCurlyBraces",
             Self::UseConsistentEnumValueType => "useConsistentEnumValueType",
             Self::UseConsistentGraphqlDescriptions => "useConsistentGraphqlDescriptions",
+            Self::UseConsistentIndentation => "useConsistentIndentation",
             Self::UseConsistentMemberAccessibility => "useConsistentMemberAccessibility",
             Self::UseConsistentMethodSignatures => "useConsistentMethodSignatures",
             Self::UseConsistentObjectDefinitions => "useConsistentObjectDefinitions",
@@ -931,6 +942,7 @@ impl RuleName {
             Self::UseSingleVarDeclarator => "useSingleVarDeclarator",
             Self::UseSolidForComponent => "useSolidForComponent",
             Self::UseSortedClasses => "useSortedClasses",
+            Self::UseSortedMappingKeys => "useSortedMappingKeys",
             Self::UseSpread => "useSpread",
             Self::UseStaticResponseMethods => "useStaticResponseMethods",
             Self::UseStrictMode => "useStrictMode",
@@ -982,6 +994,7 @@ impl RuleName {
             Self::NoAdjacentSpacesInRegex => RuleGroup::Complexity,
             Self::NoAlert => RuleGroup::Suspicious,
             Self::NoAmbiguousAnchorText => RuleGroup::Nursery,
+            Self::NoAmbiguousBooleanValues => RuleGroup::Nursery,
             Self::NoApproximativeNumericConstant => RuleGroup::Suspicious,
             Self::NoArguments => RuleGroup::Complexity,
             Self::NoAriaHiddenOnFocusable => RuleGroup::A11y,
@@ -1045,6 +1058,7 @@ impl RuleName {
             Self::NoDuplicateGraphqlOperationName => RuleGroup::Nursery,
             Self::NoDuplicateInputFieldNames => RuleGroup::Nursery,
             Self::NoDuplicateJsxProps => RuleGroup::Suspicious,
+            Self::NoDuplicateMappingKeys => RuleGroup::Nursery,
             Self::NoDuplicateObjectKeys => RuleGroup::Suspicious,
             Self::NoDuplicateParameters => RuleGroup::Suspicious,
             Self::NoDuplicateProperties => RuleGroup::Suspicious,
@@ -1110,6 +1124,7 @@ impl RuleName {
             Self::NoInvalidPositionAtImportRule => RuleGroup::Correctness,
             Self::NoInvalidUseBeforeDeclaration => RuleGroup::Correctness,
             Self::NoIrregularWhitespace => RuleGroup::Suspicious,
+            Self::NoJsonSchemaViolations => RuleGroup::Nursery,
             Self::NoJsxLiterals => RuleGroup::Nursery,
             Self::NoJsxPropsBind => RuleGroup::Nursery,
             Self::NoLabelVar => RuleGroup::Suspicious,
@@ -1193,6 +1208,7 @@ impl RuleName {
             Self::NoThisInStatic => RuleGroup::Complexity,
             Self::NoTsIgnore => RuleGroup::Suspicious,
             Self::NoUnassignedVariables => RuleGroup::Suspicious,
+            Self::NoUndeclaredAliases => RuleGroup::Nursery,
             Self::NoUndeclaredDependencies => RuleGroup::Correctness,
             Self::NoUndeclaredEnvVars => RuleGroup::Nursery,
             Self::NoUndeclaredVariables => RuleGroup::Correctness,
@@ -1284,6 +1300,7 @@ impl RuleName {
             Self::UseConsistentCurlyBraces => RuleGroup::Style,
             Self::UseConsistentEnumValueType => RuleGroup::Nursery,
             Self::UseConsistentGraphqlDescriptions => RuleGroup::Nursery,
+            Self::UseConsistentIndentation => RuleGroup::Nursery,
             Self::UseConsistentMemberAccessibility => RuleGroup::Style,
             Self::UseConsistentMethodSignatures => RuleGroup::Nursery,
             Self::UseConsistentObjectDefinitions => RuleGroup::Style,
@@ -1373,6 +1390,7 @@ impl RuleName {
             Self::UseSingleVarDeclarator => RuleGroup::Style,
             Self::UseSolidForComponent => RuleGroup::Performance,
             Self::UseSortedClasses => RuleGroup::Nursery,
+            Self::UseSortedMappingKeys => RuleGroup::Nursery,
             Self::UseSpread => RuleGroup::Nursery,
             Self::UseStaticResponseMethods => RuleGroup::Suspicious,
             Self::UseStrictMode => RuleGroup::Suspicious,
@@ -1425,6 +1443,7 @@ impl std::str::FromStr for RuleName {
             "noAdjacentSpacesInRegex" => Ok(Self::NoAdjacentSpacesInRegex),
             "noAlert" => Ok(Self::NoAlert),
             "noAmbiguousAnchorText" => Ok(Self::NoAmbiguousAnchorText),
+            "noAmbiguousBooleanValues" => Ok(Self::NoAmbiguousBooleanValues),
             "noApproximativeNumericConstant" => Ok(Self::NoApproximativeNumericConstant),
             "noArguments" => Ok(Self::NoArguments),
             "noAriaHiddenOnFocusable" => Ok(Self::NoAriaHiddenOnFocusable),
@@ -1492,6 +1511,7 @@ impl std::str::FromStr for RuleName {
             "noDuplicateGraphqlOperationName" => Ok(Self::NoDuplicateGraphqlOperationName),
             "noDuplicateInputFieldNames" => Ok(Self::NoDuplicateInputFieldNames),
             "noDuplicateJsxProps" => Ok(Self::NoDuplicateJsxProps),
+            "noDuplicateMappingKeys" => Ok(Self::NoDuplicateMappingKeys),
             "noDuplicateObjectKeys" => Ok(Self::NoDuplicateObjectKeys),
             "noDuplicateParameters" => Ok(Self::NoDuplicateParameters),
             "noDuplicateProperties" => Ok(Self::NoDuplicateProperties),
@@ -1559,6 +1579,7 @@ impl std::str::FromStr for RuleName {
             "noInvalidPositionAtImportRule" => Ok(Self::NoInvalidPositionAtImportRule),
             "noInvalidUseBeforeDeclaration" => Ok(Self::NoInvalidUseBeforeDeclaration),
             "noIrregularWhitespace" => Ok(Self::NoIrregularWhitespace),
+            "noJsonSchemaViolations" => Ok(Self::NoJsonSchemaViolations),
             "noJsxLiterals" => Ok(Self::NoJsxLiterals),
             "noJsxPropsBind" => Ok(Self::NoJsxPropsBind),
             "noLabelVar" => Ok(Self::NoLabelVar),
@@ -1644,6 +1665,7 @@ impl std::str::FromStr for RuleName {
             "noThisInStatic" => Ok(Self::NoThisInStatic),
             "noTsIgnore" => Ok(Self::NoTsIgnore),
             "noUnassignedVariables" => Ok(Self::NoUnassignedVariables),
+            "noUndeclaredAliases" => Ok(Self::NoUndeclaredAliases),
             "noUndeclaredDependencies" => Ok(Self::NoUndeclaredDependencies),
             "noUndeclaredEnvVars" => Ok(Self::NoUndeclaredEnvVars),
             "noUndeclaredVariables" => Ok(Self::NoUndeclaredVariables),
@@ -1735,6 +1757,7 @@ impl std::str::FromStr for RuleName {
             "useConsistentCurlyBraces" => Ok(Self::UseConsistentCurlyBraces),
             "useConsistentEnumValueType" => Ok(Self::UseConsistentEnumValueType),
             "useConsistentGraphqlDescriptions" => Ok(Self::UseConsistentGraphqlDescriptions),
+            "useConsistentIndentation" => Ok(Self::UseConsistentIndentation),
             "useConsistentMemberAccessibility" => Ok(Self::UseConsistentMemberAccessibility),
             "useConsistentMethodSignatures" => Ok(Self::UseConsistentMethodSignatures),
             "useConsistentObjectDefinitions" => Ok(Self::UseConsistentObjectDefinitions),
@@ -1824,6 +1847,7 @@ impl std::str::FromStr for RuleName {
             "useSingleVarDeclarator" => Ok(Self::UseSingleVarDeclarator),
             "useSolidForComponent" => Ok(Self::UseSolidForComponent),
             "useSortedClasses" => Ok(Self::UseSortedClasses),
+            "useSortedMappingKeys" => Ok(Self::UseSortedMappingKeys),
             "useSpread" => Ok(Self::UseSpread),
             "useStaticResponseMethods" => Ok(Self::UseStaticResponseMethods),
             "useStrictMode" => Ok(Self::UseStrictMode),
@@ -44,6 +44,11 @@ pub struct JsConfiguration {
     #[bpaf(hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub jsx_runtime: Option<JsxRuntime>,
+
+    /// Options for the snippets of other languages embedded in tagged template literals.
+    #[bpaf(hide, pure(Default::default()))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedded_languages: Option<JsEmbeddedLanguagesConfiguration>,
 }
 
 pub type UnsafeParameterDecoratorsEnabled = Bool<false>;
@@ -78,6 +83,48 @@ pub struct JsParserConfiguration {
     pub jsx_everywhere: Option<JsxEverywhere>,
 }
 
+pub type JsEmbeddedLanguagesEnabled = Bool<false>;
+
+/// The tags of the template literals that contain CSS, when they aren't configured.
+pub const DEFAULT_EMBEDDED_CSS_TAGS: &[&str] = &[
+    "css",
+    "keyframes",
+    "createGlobalStyle",
+    "injectGlobal",
+    "styled",
+];
+
+/// The tags of the template literals that contain GraphQL, when they aren't configured.
+pub const DEFAULT_EMBEDDED_GRAPHQL_TAGS: &[&str] = &["gql", "graphql"];
+
+/// Options that control the formatting and linting of the snippets of other languages
+/// embedded in tagged template literals, such as ``styled.div`color: red;` `` and
+/// ``gql`query { user }` ``.
+#[derive(Clone, Debug, Default, Deserializable, Deserialize, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsEmbeddedLanguagesConfiguration {
+    /// Enables the formatting and linting of the embedded snippets.
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<JsEmbeddedLanguagesEnabled>,
+
+    /// The tags of the template literals that contain CSS. A template literal matches
+    /// when the leftmost identifier of its tag is in the list, so `styled` matches
+    /// ``styled.div`...` `` and ``styled(Button)`...` ``.
+    ///
+    /// Defaults to `["css", "keyframes", "createGlobalStyle", "injectGlobal", "styled"]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub css_tags: Option<Vec<Box<str>>>,
+
+    /// The tags of the template literals that contain GraphQL. A template literal also matches
+    /// when it's the only argument of a function in the list, such as ``graphql(`...`)``.
+    ///
+    /// Defaults to `["gql", "graphql"]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub graphql_tags: Option<Vec<Box<str>>>,
+}
+
 /// Indicates the type of runtime or transformation used for interpreting JSX.
 #[derive(
     Bpaf, Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
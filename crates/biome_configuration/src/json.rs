@@ -1,7 +1,7 @@
 use crate::bool::Bool;
 use biome_deserialize_macros::{Deserializable, Merge};
 use biome_formatter::{BracketSpacing, Expand, IndentStyle, IndentWidth, LineEnding, LineWidth};
-use biome_json_formatter::context::TrailingCommas;
+use biome_json_formatter::context::{QuoteProperties, TrailingCommas};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
 
@@ -106,6 +106,14 @@ pub struct JsonFormatterConfiguration {
     #[bpaf(long("json-formatter-bracket-spacing"), argument("true|false"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bracket_spacing: Option<BracketSpacing>,
+
+    /// When properties in objects are quoted in JSON5 files. Defaults to "preserve".
+    #[bpaf(
+        long("json-formatter-quote-properties"),
+        argument("preserve|as-needed")
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_properties: Option<QuoteProperties>,
 }
 
 impl JsonFormatterConfiguration {
@@ -131,6 +139,13 @@ pub struct JsonLinterConfiguration {
     #[bpaf(long("json-linter-enabled"), argument("true|false"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enabled: Option<JsonLinterEnabled>,
+
+    /// Path to a local JSON Schema that the files must satisfy, relative to the
+    /// configuration file. Usually set inside `overrides`. A `$schema` property in
+    /// the file takes precedence.
+    #[bpaf(hide, pure(Default::default()))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
 }
 
 pub type JsonAssistEnabled = Bool<true>;
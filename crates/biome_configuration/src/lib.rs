@@ -18,9 +18,11 @@ pub mod grit;
 pub mod html;
 pub mod javascript;
 pub mod json;
+pub mod markdown;
 pub mod max_size;
 mod overrides;
 pub mod vcs;
+pub mod yaml;
 
 use crate::analyzer::assist::{Actions, AssistConfiguration, Source, assist_configuration};
 use crate::analyzer::{RuleAssistConfiguration, RuleDomains};
@@ -34,8 +36,10 @@ use crate::graphql::{GraphqlFormatterConfiguration, GraphqlLinterConfiguration};
 pub use crate::grit::{GritConfiguration, grit_configuration};
 use crate::javascript::{JsFormatterConfiguration, JsLinterConfiguration};
 use crate::json::{JsonFormatterConfiguration, JsonLinterConfiguration};
+use crate::markdown::MarkdownFormatterConfiguration;
 use crate::max_size::MaxSize;
 use crate::vcs::{VcsConfiguration, vcs_configuration};
+use crate::yaml::YamlFormatterConfiguration;
 pub use analyzer::{
     LinterConfiguration, RuleConfiguration, RuleFixConfiguration, RulePlainConfiguration,
     RuleWithFixOptions, RuleWithOptions, Rules, linter_configuration,
@@ -59,6 +63,7 @@ pub use graphql::{GraphqlConfiguration, graphql_configuration};
 pub use html::{HtmlConfiguration, html_configuration};
 pub use javascript::{JsConfiguration, js_configuration};
 pub use json::{JsonConfiguration, json_configuration};
+pub use markdown::{MarkdownConfiguration, markdown_configuration};
 pub use overrides::{
     OverrideAssistConfiguration, OverrideFilesConfiguration, OverrideFormatterConfiguration,
     OverrideGlobs, OverrideLinterConfiguration, OverridePattern, Overrides,
@@ -72,6 +77,7 @@ use std::slice::Iter;
 use std::str::FromStr;
 use std::sync::LazyLock;
 use vcs::VcsClientKind;
+pub use yaml::{YamlConfiguration, yaml_configuration};
 
 pub const DEFAULT_SCANNER_IGNORE_ENTRIES: &[&[u8]] = &[
     b".cache",
@@ -169,6 +175,16 @@ pub struct Configuration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub html: Option<HtmlConfiguration>,
 
+    /// Specific configuration for the YAML language
+    #[bpaf(external(yaml_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yaml: Option<YamlConfiguration>,
+
+    /// Specific configuration for the Markdown language
+    #[bpaf(external(markdown_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<MarkdownConfiguration>,
+
     /// A list of granular patterns that should be applied only to a sub set of files
     #[bpaf(hide, pure(Default::default()))]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -402,6 +418,22 @@ impl Configuration {
             .cloned()
             .unwrap_or_default()
     }
+
+    pub fn get_yaml_formatter_configuration(&self) -> YamlFormatterConfiguration {
+        self.yaml
+            .as_ref()
+            .and_then(|lang| lang.formatter.as_ref())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn get_markdown_formatter_configuration(&self) -> MarkdownFormatterConfiguration {
+        self.markdown
+            .as_ref()
+            .and_then(|lang| lang.formatter.as_ref())
+            .cloned()
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize, Bpaf, Merge)]
@@ -0,0 +1,85 @@
+use crate::bool::Bool;
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_formatter::{IndentWidth, LineEnding, LineWidth};
+use biome_markdown_formatter::context::{BulletListMarker, EmphasisStyle, ProseWrap};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to Markdown files
+#[derive(
+    Bpaf, Clone, Default, Debug, Deserializable, Deserialize, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MarkdownConfiguration {
+    /// Markdown formatter options
+    #[bpaf(external(markdown_formatter_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<MarkdownFormatterConfiguration>,
+}
+
+pub type MarkdownFormatterEnabled = Bool<false>; // Keep it disabled by default while experimental.
+
+/// Options that changes how the Markdown formatter behaves
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserializable, Deserialize, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MarkdownFormatterConfiguration {
+    /// Control the formatter for Markdown files.
+    #[bpaf(long("markdown-formatter-enabled"), argument("true|false"))]
+    pub enabled: Option<MarkdownFormatterEnabled>,
+
+    /// The size of the indentation applied to the code blocks of Markdown files. Default to 2.
+    #[bpaf(long("markdown-formatter-indent-width"), argument("NUMBER"))]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to Markdown files. `auto` uses CRLF on Windows and LF on other platforms.
+    #[bpaf(long("markdown-formatter-line-ending"), argument("lf|crlf|cr|auto"))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to Markdown files. Defaults to 80.
+    #[bpaf(long("markdown-formatter-line-width"), argument("NUMBER"))]
+    pub line_width: Option<LineWidth>,
+
+    /// The marker of the items of bullet lists. Defaults to dash.
+    #[bpaf(
+        long("markdown-formatter-bullet-list-marker"),
+        argument("dash|asterisk|plus")
+    )]
+    pub bullet_list_marker: Option<BulletListMarker>,
+
+    /// The delimiter of italic and bold texts. Defaults to asterisk.
+    #[bpaf(
+        long("markdown-formatter-emphasis-style"),
+        argument("asterisk|underscore")
+    )]
+    pub emphasis_style: Option<EmphasisStyle>,
+
+    /// How the text of paragraphs is wrapped. Defaults to preserve.
+    #[bpaf(
+        long("markdown-formatter-prose-wrap"),
+        argument("preserve|always|never")
+    )]
+    pub prose_wrap: Option<ProseWrap>,
+}
+
+impl MarkdownFormatterConfiguration {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or_default().into()
+    }
+}
+
+#[test]
+fn default_markdown_formatter() {
+    let markdown_configuration = MarkdownFormatterConfiguration::default();
+
+    assert!(!markdown_configuration.is_enabled());
+    assert_eq!(markdown_configuration.indent_width, None);
+    assert_eq!(markdown_configuration.line_ending, None);
+    assert_eq!(markdown_configuration.line_width, None);
+    assert_eq!(markdown_configuration.bullet_list_marker, None);
+    assert_eq!(markdown_configuration.emphasis_style, None);
+    assert_eq!(markdown_configuration.prose_wrap, None);
+}
@@ -5,7 +5,7 @@ use crate::html::HtmlConfiguration;
 use crate::max_size::MaxSize;
 use crate::{
     CssConfiguration, GraphqlConfiguration, GritConfiguration, JsConfiguration, JsonConfiguration,
-    Rules,
+    MarkdownConfiguration, Rules, YamlConfiguration,
 };
 use biome_deserialize_macros::{Deserializable, Merge};
 use biome_formatter::{
@@ -54,6 +54,14 @@ pub struct OverridePattern {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub html: Option<HtmlConfiguration>,
 
+    /// Specific configuration for the YAML language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yaml: Option<YamlConfiguration>,
+
+    /// Specific configuration for the Markdown language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<MarkdownConfiguration>,
+
     /// Specific configuration for the Json language
     #[serde(skip_serializing_if = "Option::is_none")]
     pub formatter: Option<OverrideFormatterConfiguration>,
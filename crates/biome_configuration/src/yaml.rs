@@ -0,0 +1,115 @@
+use crate::bool::Bool;
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_formatter::{IndentWidth, LineEnding, LineWidth, QuoteStyle};
+use biome_yaml_formatter::context::{IndentSequences, PreserveComments};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to YAML files
+#[derive(
+    Bpaf, Clone, Default, Debug, Deserializable, Deserialize, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct YamlConfiguration {
+    /// YAML formatter options
+    #[bpaf(external(yaml_formatter_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<YamlFormatterConfiguration>,
+
+    /// YAML linter options
+    #[bpaf(external(yaml_linter_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linter: Option<YamlLinterConfiguration>,
+}
+
+pub type YamlFormatterEnabled = Bool<true>;
+
+/// Options that changes how the YAML formatter behaves
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserializable, Deserialize, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct YamlFormatterConfiguration {
+    /// Control the formatter for YAML files.
+    #[bpaf(long("yaml-formatter-enabled"), argument("true|false"))]
+    pub enabled: Option<YamlFormatterEnabled>,
+
+    /// The size of the indentation applied to YAML files. Default to 2.
+    #[bpaf(long("yaml-formatter-indent-width"), argument("NUMBER"))]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to YAML files. `auto` uses CRLF on Windows and LF on other platforms.
+    #[bpaf(long("yaml-formatter-line-ending"), argument("lf|crlf|cr|auto"))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to YAML files. Defaults to 80.
+    #[bpaf(long("yaml-formatter-line-width"), argument("NUMBER"))]
+    pub line_width: Option<LineWidth>,
+
+    /// The type of quotes used in YAML strings. Defaults to double.
+    #[bpaf(long("yaml-formatter-quote-style"), argument("double|single"))]
+    pub quote_style: Option<QuoteStyle>,
+
+    /// Whether block sequences that are the value of a mapping entry are indented. Defaults to true.
+    #[bpaf(long("yaml-formatter-indent-sequences"), argument("true|false"))]
+    pub indent_sequences: Option<IndentSequences>,
+
+    /// Whether comments are kept in the formatted YAML files. Defaults to true.
+    #[bpaf(long("yaml-formatter-preserve-comments"), argument("true|false"))]
+    pub preserve_comments: Option<PreserveComments>,
+}
+
+impl YamlFormatterConfiguration {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or_default().into()
+    }
+}
+
+pub type YamlLinterEnabled = Bool<true>;
+
+/// Options that change how the YAML linter behaves.
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserializable, Deserialize, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct YamlLinterConfiguration {
+    /// Control the linter for YAML files.
+    #[bpaf(long("yaml-linter-enabled"), argument("true|false"))]
+    pub enabled: Option<YamlLinterEnabled>,
+
+    /// Path to a local JSON Schema that the files must satisfy, relative to the
+    /// configuration file. Usually set inside `overrides`. A schema referenced
+    /// by the file takes precedence.
+    #[bpaf(hide, pure(Default::default()))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+}
+
+impl YamlLinterConfiguration {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or_default().into()
+    }
+}
+
+#[test]
+fn default_yaml_formatter() {
+    let yaml_configuration = YamlFormatterConfiguration::default();
+
+    assert!(yaml_configuration.is_enabled());
+    assert_eq!(yaml_configuration.indent_width, None);
+    assert_eq!(yaml_configuration.line_ending, None);
+    assert_eq!(yaml_configuration.line_width, None);
+    assert_eq!(yaml_configuration.quote_style, None);
+    assert_eq!(yaml_configuration.indent_sequences, None);
+    assert_eq!(yaml_configuration.preserve_comments, None);
+}
+
+#[test]
+fn default_yaml_linter() {
+    let yaml_configuration = YamlLinterConfiguration::default();
+
+    assert!(yaml_configuration.is_enabled());
+}
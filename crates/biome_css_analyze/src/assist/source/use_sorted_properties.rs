@@ -247,6 +247,10 @@ impl RecessOrderMember {
                 AnyCssRule::CssBogusRule(_) => NodeKindOrder::UnknownKind,
                 AnyCssRule::CssNestedQualifiedRule(_) => NodeKindOrder::NestedRuleOrAtRule,
                 AnyCssRule::CssQualifiedRule(_) => NodeKindOrder::UnknownKind,
+                AnyCssRule::ScssDeclaration(_) => NodeKindOrder::UnknownKind,
+                AnyCssRule::LessDeclaration(_) => NodeKindOrder::UnknownKind,
+                AnyCssRule::LessMixinDeclaration(_) => NodeKindOrder::NestedRuleOrAtRule,
+                AnyCssRule::LessMixinCall(_) => NodeKindOrder::UnknownKind,
             },
             AnyCssDeclarationOrRule::CssEmptyDeclaration(_) => NodeKindOrder::UnknownKind,
             AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(decl_with_semicolon) => {
@@ -437,6 +441,19 @@ fn contains_shorthand_after_longhand(nodes: &[AnyCssDeclarationOrRule]) -> bool
 /// The noUnknownProperty rule will catch it instead.
 fn contains_unknown_property(nodes: &[AnyCssDeclarationOrRule]) -> bool {
     for node in nodes.iter() {
+        // Moving a SCSS or Less variable could change the value of the properties that use it,
+        // and a Less mixin call can override the properties declared before it
+        if matches!(
+            node,
+            AnyCssDeclarationOrRule::AnyCssRule(
+                AnyCssRule::ScssDeclaration(_)
+                    | AnyCssRule::LessDeclaration(_)
+                    | AnyCssRule::LessMixinCall(_)
+            )
+        ) {
+            return true;
+        }
+
         let Some(prop_text) = &node
             .as_css_declaration_with_semicolon()
             .and_then(css_declaration_to_prop_text)
@@ -4,8 +4,8 @@
 use crate::keywords::{
     BASIC_KEYWORDS, FONT_FAMILY_KEYWORDS, FONT_SIZE_KEYWORDS, FONT_STRETCH_KEYWORDS,
     FONT_STYLE_KEYWORDS, FONT_VARIANTS_KEYWORDS, FONT_WEIGHT_ABSOLUTE_KEYWORDS,
-    FONT_WEIGHT_NUMERIC_KEYWORDS, FUNCTION_KEYWORDS, LINE_HEIGHT_KEYWORDS,
-    SYSTEM_FAMILY_NAME_KEYWORDS,
+    FONT_WEIGHT_NUMERIC_KEYWORDS, FUNCTION_KEYWORDS, LESS_FUNCTION_KEYWORDS, LINE_HEIGHT_KEYWORDS,
+    SCSS_FUNCTION_KEYWORDS, SYSTEM_FAMILY_NAME_KEYWORDS,
 };
 use crate::utils::is_css_variable;
 use biome_css_syntax::{
@@ -277,6 +277,8 @@ pub fn find_font_family(value: CssGenericComponentValueList) -> Vec<CssFontValue
                 }
                 _ => {}
             },
+            AnyCssGenericComponentValue::ScssOperator(_)
+            | AnyCssGenericComponentValue::LessOperator(_) => {}
         }
     }
     font_families
@@ -311,3 +313,17 @@ pub fn is_function_keyword(value: &str) -> bool {
         .binary_search(&value.to_ascii_lowercase_cow().as_ref())
         .is_ok()
 }
+
+/// Check if the value is a global built-in function of Sass.
+pub fn is_scss_function_keyword(value: &str) -> bool {
+    SCSS_FUNCTION_KEYWORDS
+        .binary_search(&value.to_ascii_lowercase_cow().as_ref())
+        .is_ok()
+}
+
+/// Check if the value is a built-in function of Less.
+pub fn is_less_function_keyword(value: &str) -> bool {
+    LESS_FUNCTION_KEYWORDS
+        .binary_search(&value.to_ascii_lowercase_cow().as_ref())
+        .is_ok()
+}
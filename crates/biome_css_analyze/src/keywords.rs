@@ -775,6 +775,180 @@ pub const FUNCTION_KEYWORDS: &[&str] = &[
     "xywh",
 ];
 
+/// List of the global built-in functions of Sass.
+/// See the [Sass documentation](https://sass-lang.com/documentation/modules/).
+pub const SCSS_FUNCTION_KEYWORDS: &[&str] = &[
+    "adjust-color",
+    "adjust-hue",
+    "alpha",
+    "append",
+    "blackness",
+    "blue",
+    "call",
+    "change-color",
+    "comparable",
+    "complement",
+    "content-exists",
+    "darken",
+    "desaturate",
+    "fade-in",
+    "fade-out",
+    "feature-exists",
+    "function-exists",
+    "get-function",
+    "global-variable-exists",
+    "grayscale",
+    "green",
+    "hue",
+    "ie-hex-str",
+    "if",
+    "index",
+    "inspect",
+    "invert",
+    "is-bracketed",
+    "is-superselector",
+    "join",
+    "keywords",
+    "length",
+    "lighten",
+    "lightness",
+    "list-separator",
+    "map-get",
+    "map-has-key",
+    "map-keys",
+    "map-merge",
+    "map-remove",
+    "map-values",
+    "mix",
+    "mixin-exists",
+    "nth",
+    "opacify",
+    "opacity",
+    "percentage",
+    "quote",
+    "random",
+    "red",
+    "saturate",
+    "saturation",
+    "scale-color",
+    "selector-append",
+    "selector-extend",
+    "selector-nest",
+    "selector-parse",
+    "selector-replace",
+    "selector-unify",
+    "set-nth",
+    "simple-selectors",
+    "str-index",
+    "str-insert",
+    "str-length",
+    "str-slice",
+    "to-lower-case",
+    "to-upper-case",
+    "transparentize",
+    "type-of",
+    "unique-id",
+    "unit",
+    "unitless",
+    "unquote",
+    "variable-exists",
+    "whiteness",
+    "zip",
+];
+
+/// List of the built-in functions of Less.
+/// See the [Less documentation](https://lesscss.org/functions/).
+pub const LESS_FUNCTION_KEYWORDS: &[&str] = &[
+    "abs",
+    "acos",
+    "alpha",
+    "argb",
+    "asin",
+    "atan",
+    "average",
+    "blue",
+    "boolean",
+    "ceil",
+    "color",
+    "contrast",
+    "convert",
+    "cos",
+    "darken",
+    "data-uri",
+    "default",
+    "desaturate",
+    "difference",
+    "e",
+    "each",
+    "escape",
+    "exclusion",
+    "extract",
+    "fade",
+    "fadein",
+    "fadeout",
+    "floor",
+    "get-unit",
+    "green",
+    "greyscale",
+    "hardlight",
+    "hsl",
+    "hsla",
+    "hsv",
+    "hsva",
+    "hsvhue",
+    "hsvsaturation",
+    "hsvvalue",
+    "hue",
+    "if",
+    "image-height",
+    "image-size",
+    "image-width",
+    "iscolor",
+    "isdefined",
+    "isem",
+    "iskeyword",
+    "isnumber",
+    "ispercentage",
+    "ispixel",
+    "isruleset",
+    "isstring",
+    "isunit",
+    "isurl",
+    "length",
+    "lighten",
+    "lightness",
+    "luma",
+    "luminance",
+    "max",
+    "min",
+    "mix",
+    "mod",
+    "multiply",
+    "negation",
+    "overlay",
+    "percentage",
+    "pi",
+    "pow",
+    "range",
+    "red",
+    "replace",
+    "rgb",
+    "rgba",
+    "round",
+    "saturate",
+    "saturation",
+    "screen",
+    "shade",
+    "sin",
+    "softlight",
+    "spin",
+    "sqrt",
+    "svg-gradient",
+    "tan",
+    "tint",
+    "unit",
+];
+
 // These are the ones that can have single-colon notation
 pub const LEVEL_ONE_AND_TWO_PSEUDO_ELEMENTS: [&str; 5] =
     ["after", "before", "first-letter", "first-line", "slotted"];
@@ -5842,6 +6016,20 @@ mod tests {
         assert!(!has_duplicates);
     }
 
+    #[test]
+    fn test_scss_function_keywords_sorted() {
+        let sorted = SCSS_FUNCTION_KEYWORDS.to_vec();
+        let _ = sorted.is_sorted();
+        assert_eq!(SCSS_FUNCTION_KEYWORDS, sorted.as_slice());
+    }
+
+    #[test]
+    fn test_less_function_keywords_sorted() {
+        let sorted = LESS_FUNCTION_KEYWORDS.to_vec();
+        let _ = sorted.is_sorted();
+        assert_eq!(LESS_FUNCTION_KEYWORDS, sorted.as_slice());
+    }
+
     #[test]
     fn test_level_one_and_two_pseudo_elements_sorted() {
         let sorted = LEVEL_ONE_AND_TWO_PSEUDO_ELEMENTS.to_vec();
@@ -225,8 +225,20 @@ fn is_last_value_css_variable(properties: &CssGenericComponentValueList) -> bool
         .into_iter()
         .filter_map(|v| match v {
             AnyCssGenericComponentValue::AnyCssValue(_) => Some(v),
-            AnyCssGenericComponentValue::CssGenericDelimiter(_) => None,
+            AnyCssGenericComponentValue::CssGenericDelimiter(_)
+            | AnyCssGenericComponentValue::ScssOperator(_)
+            | AnyCssGenericComponentValue::LessOperator(_) => None,
         })
         .last()
-        .is_some_and(|v| is_css_variable(&v.to_trimmed_text().text().to_ascii_lowercase_cow()))
+        .is_some_and(|v| {
+            // The value of a SCSS or Less variable isn't known
+            matches!(
+                v,
+                AnyCssGenericComponentValue::AnyCssValue(
+                    AnyCssValue::ScssIdentifier(_)
+                        | AnyCssValue::ScssQualifiedName(_)
+                        | AnyCssValue::LessIdentifier(_)
+                )
+            ) || is_css_variable(&v.to_trimmed_text().text().to_ascii_lowercase_cow())
+        })
 }
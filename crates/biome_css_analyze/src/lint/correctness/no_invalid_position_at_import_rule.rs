@@ -2,7 +2,7 @@ use biome_analyze::{
     Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
 };
 use biome_console::markup;
-use biome_css_syntax::{AnyCssRule, CssRuleList};
+use biome_css_syntax::{AnyCssAtRule, AnyCssRule, CssRuleList};
 use biome_diagnostics::Severity;
 use biome_rowan::{AstNode, TextRange};
 use biome_rule_options::no_invalid_position_at_import_rule::NoInvalidPositionAtImportRuleOptions;
@@ -12,6 +12,9 @@ declare_lint_rule! {
     ///
     /// Any `@import` rules must precede all other valid at-rules and style rules in a stylesheet (ignoring `@charset` and `@layer`), or else the `@import` rule is invalid.
     ///
+    /// In SCSS files, the rules that don't output any CSS are also ignored: variables, `@use`, `@forward`, `@mixin` and `@function`.
+    /// In Less files, the variables and the mixins with parameters are ignored as well.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -50,6 +53,16 @@ impl Rule for NoInvalidPositionAtImportRule {
         let mut invalid_import_list = Vec::new();
 
         for rule in node {
+            // SCSS and Less variables, and Less mixins with parameters, don't output any CSS
+            if matches!(
+                rule,
+                AnyCssRule::ScssDeclaration(_)
+                    | AnyCssRule::LessDeclaration(_)
+                    | AnyCssRule::LessMixinDeclaration(_)
+            ) {
+                continue;
+            }
+
             let any_css_at_rule = match rule {
                 AnyCssRule::CssAtRule(item) => item.rule().ok(),
                 _ => None,
@@ -63,6 +76,16 @@ impl Rule for NoInvalidPositionAtImportRule {
                 if any_css_at_rule.as_css_layer_at_rule().is_some() {
                     continue;
                 }
+                // Ignore the SCSS at-rules that don't output any CSS
+                if matches!(
+                    any_css_at_rule,
+                    AnyCssAtRule::ScssUseAtRule(_)
+                        | AnyCssAtRule::ScssForwardAtRule(_)
+                        | AnyCssAtRule::ScssMixinAtRule(_)
+                        | AnyCssAtRule::ScssFunctionAtRule(_)
+                ) {
+                    continue;
+                }
 
                 let import_rule = any_css_at_rule.as_css_import_at_rule();
                 if let Some(import_rule) = import_rule {
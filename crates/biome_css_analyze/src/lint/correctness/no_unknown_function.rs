@@ -1,10 +1,10 @@
-use crate::fonts::is_function_keyword;
+use crate::fonts::{is_function_keyword, is_less_function_keyword, is_scss_function_keyword};
 use crate::utils::is_custom_function;
 use biome_analyze::{
     Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
 };
 use biome_console::markup;
-use biome_css_syntax::CssFunction;
+use biome_css_syntax::{CssFileSource, CssFunction, ScssFunctionAtRule, ScssQualifiedName};
 use biome_diagnostics::Severity;
 use biome_rowan::{AstNode, TextRange};
 use biome_rule_options::no_unknown_function::NoUnknownFunctionOptions;
@@ -14,6 +14,11 @@ declare_lint_rule! {
     ///
     /// This rule ignores double-dashed custom functions, e.g. `--custom-function()`.
     ///
+    /// In SCSS files, this rule also ignores the built-in functions of Sass, the functions
+    /// of modules, e.g. `math.div()`, and the functions declared with `@function` in the file.
+    ///
+    /// In Less files, this rule also ignores the built-in functions of Less, e.g. `darken()` or `e()`.
+    ///
     /// Data sources of known CSS value functions are:
     /// - MDN reference on [CSS value functions](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Functions)
     /// - MDN reference on [CSS reference](https://developer.mozilla.org/en-US/docs/Web/CSS/Reference)
@@ -69,6 +74,16 @@ impl Rule for NoUnknownFunction {
             return None;
         }
 
+        let source_type = ctx.source_type::<CssFileSource>();
+
+        if source_type.is_scss() && is_scss_function(node, function_name) {
+            return None;
+        }
+
+        if source_type.is_less() && is_less_function_keyword(function_name) {
+            return None;
+        }
+
         Some(NoUnknownFunctionState {
             function_name: function_name.into(),
             span: node.name().ok()?.range(),
@@ -93,3 +108,26 @@ impl Rule for NoUnknownFunction {
         )
     }
 }
+
+/// Returns `true` if the function is a built-in function of Sass, a function of a module
+/// or a function declared in the file.
+fn is_scss_function(node: &CssFunction, function_name: &str) -> bool {
+    if is_scss_function_keyword(function_name)
+        || node.parent::<ScssQualifiedName>().is_some()
+    {
+        return true;
+    }
+
+    node.syntax()
+        .ancestors()
+        .last()
+        .into_iter()
+        .flat_map(|root| root.descendants())
+        .filter_map(ScssFunctionAtRule::cast)
+        .any(|declaration| {
+            declaration
+                .name()
+                .and_then(|name| name.value_token())
+                .is_ok_and(|name| name.text_trimmed() == function_name)
+        })
+}
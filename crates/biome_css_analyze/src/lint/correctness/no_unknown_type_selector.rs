@@ -2,9 +2,11 @@ use biome_analyze::{
     Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
 };
 use biome_console::markup;
-use biome_css_syntax::{CssPseudoElementFunctionSelector, CssTypeSelector};
+use biome_css_syntax::{
+    CssCompoundSelector, CssFileSource, CssPseudoElementFunctionSelector, CssTypeSelector,
+};
 use biome_diagnostics::Severity;
-use biome_rowan::AstNode;
+use biome_rowan::{AstNode, AstNodeList};
 use biome_rule_options::no_unknown_type_selector::NoUnknownTypeSelectorOptions;
 
 use crate::utils::is_known_type_selector;
@@ -43,6 +45,14 @@ fn is_root_in_view_transition_pseudo_element(type_selector: &CssTypeSelector) ->
         .is_some_and(|name_text| VIEW_TRANSITION_PSEUDO_ELEMENTS.contains(&name_text.as_str()))
 }
 
+/// Checks if the type selector is a suffix appended to the parent selector in SCSS,
+/// e.g. `__title` in `&__title`.
+fn is_scss_parent_suffix(type_selector: &CssTypeSelector) -> bool {
+    type_selector
+        .parent::<CssCompoundSelector>()
+        .is_some_and(|compound_selector| !compound_selector.nesting_selectors().is_empty())
+}
+
 declare_lint_rule! {
     /// Disallow unknown type selectors.
     ///
@@ -55,6 +65,8 @@ declare_lint_rule! {
     ///
     /// This rule allows custom elements.
     ///
+    /// In SCSS files, this rule ignores the suffixes appended to the parent selector, e.g. `&__title`.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -110,6 +122,11 @@ impl Rule for NoUnknownTypeSelector {
             return None;
         }
 
+        if ctx.source_type::<CssFileSource>().is_scss() && is_scss_parent_suffix(css_type_selector)
+        {
+            return None;
+        }
+
         let type_selector = css_type_selector
             .ident()
             .ok()?
@@ -20,7 +20,7 @@ use std::ops::Deref;
 use std::sync::Arc;
 use std::{fs::read_to_string, slice};
 
-tests_macros::gen_tests! {"tests/specs/**/*.{css,json,jsonc}", crate::run_test, "module"}
+tests_macros::gen_tests! {"tests/specs/**/*.{css,scss,less,json,jsonc}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{css,json,jsonc}", crate::run_suppression_test, "module"}
 tests_macros::gen_tests! {"tests/plugin/*.grit", crate::run_plugin_test, "module"}
 
@@ -66,6 +66,18 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
             tailwind_directives: true,
             ..CssParserOptions::default()
         }
+    } else if extension == "scss" {
+        CssParserOptions {
+            scss: true,
+            allow_wrong_line_comments: true,
+            ..CssParserOptions::default()
+        }
+    } else if extension == "less" {
+        CssParserOptions {
+            less: true,
+            allow_wrong_line_comments: true,
+            ..CssParserOptions::default()
+        }
     } else {
         CssParserOptions::default()
     };
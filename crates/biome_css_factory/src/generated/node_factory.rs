@@ -2652,6 +2652,1124 @@ pub fn css_view_transition_at_rule_declarator(
         [Some(SyntaxElement::Token(view_transition_token))],
     ))
 }
+pub fn less_declaration(
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> LessDeclarationBuilder {
+    LessDeclarationBuilder {
+        name,
+        colon_token,
+        value,
+        important: None,
+        semicolon_token: None,
+    }
+}
+pub struct LessDeclarationBuilder {
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    important: Option<CssDeclarationImportant>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessDeclarationBuilder {
+    pub fn with_important(mut self, important: CssDeclarationImportant) -> Self {
+        self.important = Some(important);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessDeclaration {
+        LessDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.important
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_escaped_value(tilde_token: SyntaxToken, value: CssString) -> LessEscapedValue {
+    LessEscapedValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_ESCAPED_VALUE,
+        [
+            Some(SyntaxElement::Token(tilde_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn less_expression(items: CssGenericComponentValueList) -> LessExpression {
+    LessExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_EXPRESSION,
+        [Some(SyntaxElement::Node(items.into_syntax()))],
+    ))
+}
+pub fn less_identifier(at_token: SyntaxToken, name: CssIdentifier) -> LessIdentifier {
+    LessIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_IDENTIFIER,
+        [
+            Some(SyntaxElement::Token(at_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn less_keyword_argument(
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: LessExpression,
+) -> LessKeywordArgument {
+    LessKeywordArgument::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_KEYWORD_ARGUMENT,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn less_mixin_arguments(
+    l_paren_token: SyntaxToken,
+    items: LessMixinArgumentList,
+    r_paren_token: SyntaxToken,
+) -> LessMixinArguments {
+    LessMixinArguments::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_ARGUMENTS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_mixin_call(path: LessMixinPathSegmentList) -> LessMixinCallBuilder {
+    LessMixinCallBuilder {
+        path,
+        arguments: None,
+        important: None,
+        semicolon_token: None,
+    }
+}
+pub struct LessMixinCallBuilder {
+    path: LessMixinPathSegmentList,
+    arguments: Option<AnyLessMixinArguments>,
+    important: Option<CssDeclarationImportant>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessMixinCallBuilder {
+    pub fn with_arguments(mut self, arguments: AnyLessMixinArguments) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+    pub fn with_important(mut self, important: CssDeclarationImportant) -> Self {
+        self.important = Some(important);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessMixinCall {
+        LessMixinCall::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_CALL,
+            [
+                Some(SyntaxElement::Node(self.path.into_syntax())),
+                self.arguments
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.important
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_declaration(
+    name: AnyLessMixinSelector,
+    parameters: AnyLessMixinParameters,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> LessMixinDeclarationBuilder {
+    LessMixinDeclarationBuilder {
+        name,
+        parameters,
+        block,
+        guard: None,
+    }
+}
+pub struct LessMixinDeclarationBuilder {
+    name: AnyLessMixinSelector,
+    parameters: AnyLessMixinParameters,
+    block: AnyCssDeclarationOrRuleBlock,
+    guard: Option<LessMixinGuard>,
+}
+impl LessMixinDeclarationBuilder {
+    pub fn with_guard(mut self, guard: LessMixinGuard) -> Self {
+        self.guard = Some(guard);
+        self
+    }
+    pub fn build(self) -> LessMixinDeclaration {
+        LessMixinDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Node(self.parameters.into_syntax())),
+                self.guard
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_guard(when_token: SyntaxToken, condition: LessExpression) -> LessMixinGuard {
+    LessMixinGuard::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_GUARD,
+        [
+            Some(SyntaxElement::Token(when_token)),
+            Some(SyntaxElement::Node(condition.into_syntax())),
+        ],
+    ))
+}
+pub fn less_mixin_parameter(name: LessIdentifier) -> LessMixinParameterBuilder {
+    LessMixinParameterBuilder {
+        name,
+        default_value: None,
+        dotdotdot_token: None,
+    }
+}
+pub struct LessMixinParameterBuilder {
+    name: LessIdentifier,
+    default_value: Option<LessMixinParameterDefaultValue>,
+    dotdotdot_token: Option<SyntaxToken>,
+}
+impl LessMixinParameterBuilder {
+    pub fn with_default_value(mut self, default_value: LessMixinParameterDefaultValue) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+    pub fn with_dotdotdot_token(mut self, dotdotdot_token: SyntaxToken) -> Self {
+        self.dotdotdot_token = Some(dotdotdot_token);
+        self
+    }
+    pub fn build(self) -> LessMixinParameter {
+        LessMixinParameter::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_PARAMETER,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.default_value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.dotdotdot_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_parameter_default_value(
+    colon_token: SyntaxToken,
+    value: LessExpression,
+) -> LessMixinParameterDefaultValue {
+    LessMixinParameterDefaultValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PARAMETER_DEFAULT_VALUE,
+        [
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn less_mixin_parameters(
+    l_paren_token: SyntaxToken,
+    items: LessMixinParameterList,
+    r_paren_token: SyntaxToken,
+) -> LessMixinParameters {
+    LessMixinParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_mixin_path_segment(selector: AnyLessMixinSelector) -> LessMixinPathSegmentBuilder {
+    LessMixinPathSegmentBuilder {
+        selector,
+        r_angle_token: None,
+    }
+}
+pub struct LessMixinPathSegmentBuilder {
+    selector: AnyLessMixinSelector,
+    r_angle_token: Option<SyntaxToken>,
+}
+impl LessMixinPathSegmentBuilder {
+    pub fn with_r_angle_token(mut self, r_angle_token: SyntaxToken) -> Self {
+        self.r_angle_token = Some(r_angle_token);
+        self
+    }
+    pub fn build(self) -> LessMixinPathSegment {
+        LessMixinPathSegment::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_PATH_SEGMENT,
+            [
+                self.r_angle_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.selector.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_rest_parameter(dotdotdot_token: SyntaxToken) -> LessMixinRestParameter {
+    LessMixinRestParameter::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_REST_PARAMETER,
+        [Some(SyntaxElement::Token(dotdotdot_token))],
+    ))
+}
+pub fn less_mixin_semicolon_arguments(
+    l_paren_token: SyntaxToken,
+    items: LessMixinSemicolonArgumentList,
+    r_paren_token: SyntaxToken,
+) -> LessMixinSemicolonArguments {
+    LessMixinSemicolonArguments::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_SEMICOLON_ARGUMENTS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_mixin_semicolon_parameters(
+    l_paren_token: SyntaxToken,
+    items: LessMixinSemicolonParameterList,
+    r_paren_token: SyntaxToken,
+) -> LessMixinSemicolonParameters {
+    LessMixinSemicolonParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_SEMICOLON_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_operator(value_token: SyntaxToken) -> LessOperator {
+    LessOperator::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_OPERATOR,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn less_parenthesized_expression(
+    l_paren_token: SyntaxToken,
+    expression: LessExpression,
+    r_paren_token: SyntaxToken,
+) -> LessParenthesizedExpression {
+    LessParenthesizedExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_PARENTHESIZED_EXPRESSION,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(expression.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_arguments(
+    l_paren_token: SyntaxToken,
+    items: ScssArgumentList,
+    r_paren_token: SyntaxToken,
+) -> ScssArguments {
+    ScssArguments::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ARGUMENTS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_content_at_rule(content_token: SyntaxToken) -> ScssContentAtRuleBuilder {
+    ScssContentAtRuleBuilder {
+        content_token,
+        arguments: None,
+        semicolon_token: None,
+    }
+}
+pub struct ScssContentAtRuleBuilder {
+    content_token: SyntaxToken,
+    arguments: Option<ScssArguments>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssContentAtRuleBuilder {
+    pub fn with_arguments(mut self, arguments: ScssArguments) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssContentAtRule {
+        ScssContentAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_CONTENT_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.content_token)),
+                self.arguments
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_debug_at_rule(
+    debug_token: SyntaxToken,
+    value: ScssExpression,
+) -> ScssDebugAtRuleBuilder {
+    ScssDebugAtRuleBuilder {
+        debug_token,
+        value,
+        semicolon_token: None,
+    }
+}
+pub struct ScssDebugAtRuleBuilder {
+    debug_token: SyntaxToken,
+    value: ScssExpression,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssDebugAtRuleBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssDebugAtRule {
+        ScssDebugAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_DEBUG_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.debug_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_declaration(
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    modifiers: ScssVariableModifierList,
+) -> ScssDeclarationBuilder {
+    ScssDeclarationBuilder {
+        name,
+        colon_token,
+        value,
+        modifiers,
+        semicolon_token: None,
+    }
+}
+pub struct ScssDeclarationBuilder {
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    modifiers: ScssVariableModifierList,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssDeclarationBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssDeclaration {
+        ScssDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                Some(SyntaxElement::Node(self.modifiers.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_each_at_rule(
+    each_token: SyntaxToken,
+    bindings: ScssEachBindingList,
+    in_token: SyntaxToken,
+    iterable: ScssExpression,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssEachAtRule {
+    ScssEachAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EACH_AT_RULE,
+        [
+            Some(SyntaxElement::Token(each_token)),
+            Some(SyntaxElement::Node(bindings.into_syntax())),
+            Some(SyntaxElement::Token(in_token)),
+            Some(SyntaxElement::Node(iterable.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_else_clause(
+    at_token: SyntaxToken,
+    else_token: SyntaxToken,
+    body: AnyScssElseClauseBody,
+) -> ScssElseClause {
+    ScssElseClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ELSE_CLAUSE,
+        [
+            Some(SyntaxElement::Token(at_token)),
+            Some(SyntaxElement::Token(else_token)),
+            Some(SyntaxElement::Node(body.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_error_at_rule(
+    error_token: SyntaxToken,
+    value: ScssExpression,
+) -> ScssErrorAtRuleBuilder {
+    ScssErrorAtRuleBuilder {
+        error_token,
+        value,
+        semicolon_token: None,
+    }
+}
+pub struct ScssErrorAtRuleBuilder {
+    error_token: SyntaxToken,
+    value: ScssExpression,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssErrorAtRuleBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssErrorAtRule {
+        ScssErrorAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_ERROR_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.error_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_expression(items: CssGenericComponentValueList) -> ScssExpression {
+    ScssExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EXPRESSION,
+        [Some(SyntaxElement::Node(items.into_syntax()))],
+    ))
+}
+pub fn scss_extend_at_rule(
+    extend_token: SyntaxToken,
+    selectors: CssSelectorList,
+) -> ScssExtendAtRuleBuilder {
+    ScssExtendAtRuleBuilder {
+        extend_token,
+        selectors,
+        optional: None,
+        semicolon_token: None,
+    }
+}
+pub struct ScssExtendAtRuleBuilder {
+    extend_token: SyntaxToken,
+    selectors: CssSelectorList,
+    optional: Option<ScssExtendOptionalModifier>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssExtendAtRuleBuilder {
+    pub fn with_optional(mut self, optional: ScssExtendOptionalModifier) -> Self {
+        self.optional = Some(optional);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssExtendAtRule {
+        ScssExtendAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_EXTEND_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.extend_token)),
+                Some(SyntaxElement::Node(self.selectors.into_syntax())),
+                self.optional
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_extend_optional_modifier(
+    excl_token: SyntaxToken,
+    optional_token: SyntaxToken,
+) -> ScssExtendOptionalModifier {
+    ScssExtendOptionalModifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EXTEND_OPTIONAL_MODIFIER,
+        [
+            Some(SyntaxElement::Token(excl_token)),
+            Some(SyntaxElement::Token(optional_token)),
+        ],
+    ))
+}
+pub fn scss_for_at_rule(
+    for_token: SyntaxToken,
+    variable: ScssIdentifier,
+    from_token: SyntaxToken,
+    start: ScssExpression,
+    operator_token: SyntaxToken,
+    end: ScssExpression,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssForAtRule {
+    ScssForAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FOR_AT_RULE,
+        [
+            Some(SyntaxElement::Token(for_token)),
+            Some(SyntaxElement::Node(variable.into_syntax())),
+            Some(SyntaxElement::Token(from_token)),
+            Some(SyntaxElement::Node(start.into_syntax())),
+            Some(SyntaxElement::Token(operator_token)),
+            Some(SyntaxElement::Node(end.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_forward_as_clause(
+    as_token: SyntaxToken,
+    prefix: CssIdentifier,
+    star_token: SyntaxToken,
+) -> ScssForwardAsClause {
+    ScssForwardAsClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FORWARD_AS_CLAUSE,
+        [
+            Some(SyntaxElement::Token(as_token)),
+            Some(SyntaxElement::Node(prefix.into_syntax())),
+            Some(SyntaxElement::Token(star_token)),
+        ],
+    ))
+}
+pub fn scss_forward_at_rule(
+    forward_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+) -> ScssForwardAtRuleBuilder {
+    ScssForwardAtRuleBuilder {
+        forward_token,
+        url,
+        semicolon_token,
+        as_clause: None,
+        visibility: None,
+        with_clause: None,
+    }
+}
+pub struct ScssForwardAtRuleBuilder {
+    forward_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+    as_clause: Option<ScssForwardAsClause>,
+    visibility: Option<ScssForwardVisibilityClause>,
+    with_clause: Option<ScssWithClause>,
+}
+impl ScssForwardAtRuleBuilder {
+    pub fn with_as_clause(mut self, as_clause: ScssForwardAsClause) -> Self {
+        self.as_clause = Some(as_clause);
+        self
+    }
+    pub fn with_visibility(mut self, visibility: ScssForwardVisibilityClause) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+    pub fn with_with_clause(mut self, with_clause: ScssWithClause) -> Self {
+        self.with_clause = Some(with_clause);
+        self
+    }
+    pub fn build(self) -> ScssForwardAtRule {
+        ScssForwardAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_FORWARD_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.forward_token)),
+                Some(SyntaxElement::Node(self.url.into_syntax())),
+                self.as_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.visibility
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.with_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.semicolon_token)),
+            ],
+        ))
+    }
+}
+pub fn scss_forward_visibility_clause(
+    modifier_token: SyntaxToken,
+    members: ScssForwardMemberList,
+) -> ScssForwardVisibilityClause {
+    ScssForwardVisibilityClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FORWARD_VISIBILITY_CLAUSE,
+        [
+            Some(SyntaxElement::Token(modifier_token)),
+            Some(SyntaxElement::Node(members.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_function_at_rule(
+    function_token: SyntaxToken,
+    name: CssIdentifier,
+    parameters: ScssParameters,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssFunctionAtRule {
+    ScssFunctionAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FUNCTION_AT_RULE,
+        [
+            Some(SyntaxElement::Token(function_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Node(parameters.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_identifier(dollar_token: SyntaxToken, name: CssIdentifier) -> ScssIdentifier {
+    ScssIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_IDENTIFIER,
+        [
+            Some(SyntaxElement::Token(dollar_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_if_at_rule(
+    if_token: SyntaxToken,
+    condition: ScssExpression,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssIfAtRuleBuilder {
+    ScssIfAtRuleBuilder {
+        if_token,
+        condition,
+        block,
+        else_clause: None,
+    }
+}
+pub struct ScssIfAtRuleBuilder {
+    if_token: SyntaxToken,
+    condition: ScssExpression,
+    block: AnyCssDeclarationOrRuleBlock,
+    else_clause: Option<ScssElseClause>,
+}
+impl ScssIfAtRuleBuilder {
+    pub fn with_else_clause(mut self, else_clause: ScssElseClause) -> Self {
+        self.else_clause = Some(else_clause);
+        self
+    }
+    pub fn build(self) -> ScssIfAtRule {
+        ScssIfAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_IF_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.if_token)),
+                Some(SyntaxElement::Node(self.condition.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+                self.else_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_include_at_rule(
+    include_token: SyntaxToken,
+    name: AnyScssMixinName,
+) -> ScssIncludeAtRuleBuilder {
+    ScssIncludeAtRuleBuilder {
+        include_token,
+        name,
+        arguments: None,
+        block: None,
+        semicolon_token: None,
+    }
+}
+pub struct ScssIncludeAtRuleBuilder {
+    include_token: SyntaxToken,
+    name: AnyScssMixinName,
+    arguments: Option<ScssArguments>,
+    block: Option<AnyCssDeclarationOrRuleBlock>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssIncludeAtRuleBuilder {
+    pub fn with_arguments(mut self, arguments: ScssArguments) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+    pub fn with_block(mut self, block: AnyCssDeclarationOrRuleBlock) -> Self {
+        self.block = Some(block);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssIncludeAtRule {
+        ScssIncludeAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_INCLUDE_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.include_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.arguments
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.block
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_keyword_argument(
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: ScssExpression,
+) -> ScssKeywordArgument {
+    ScssKeywordArgument::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_KEYWORD_ARGUMENT,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_map_expression(
+    l_paren_token: SyntaxToken,
+    pairs: ScssMapExpressionPairList,
+    r_paren_token: SyntaxToken,
+) -> ScssMapExpression {
+    ScssMapExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MAP_EXPRESSION,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(pairs.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_map_expression_pair(
+    key: AnyCssValue,
+    colon_token: SyntaxToken,
+    value: ScssExpression,
+) -> ScssMapExpressionPair {
+    ScssMapExpressionPair::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR,
+        [
+            Some(SyntaxElement::Node(key.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_mixin_at_rule(
+    mixin_token: SyntaxToken,
+    name: CssIdentifier,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssMixinAtRuleBuilder {
+    ScssMixinAtRuleBuilder {
+        mixin_token,
+        name,
+        block,
+        parameters: None,
+    }
+}
+pub struct ScssMixinAtRuleBuilder {
+    mixin_token: SyntaxToken,
+    name: CssIdentifier,
+    block: AnyCssDeclarationOrRuleBlock,
+    parameters: Option<ScssParameters>,
+}
+impl ScssMixinAtRuleBuilder {
+    pub fn with_parameters(mut self, parameters: ScssParameters) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+    pub fn build(self) -> ScssMixinAtRule {
+        ScssMixinAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_MIXIN_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.mixin_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.parameters
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_operator(value_token: SyntaxToken) -> ScssOperator {
+    ScssOperator::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_OPERATOR,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn scss_parameter(name: ScssIdentifier) -> ScssParameterBuilder {
+    ScssParameterBuilder {
+        name,
+        default_value: None,
+        dotdotdot_token: None,
+    }
+}
+pub struct ScssParameterBuilder {
+    name: ScssIdentifier,
+    default_value: Option<ScssParameterDefaultValue>,
+    dotdotdot_token: Option<SyntaxToken>,
+}
+impl ScssParameterBuilder {
+    pub fn with_default_value(mut self, default_value: ScssParameterDefaultValue) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+    pub fn with_dotdotdot_token(mut self, dotdotdot_token: SyntaxToken) -> Self {
+        self.dotdotdot_token = Some(dotdotdot_token);
+        self
+    }
+    pub fn build(self) -> ScssParameter {
+        ScssParameter::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_PARAMETER,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.default_value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.dotdotdot_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_parameter_default_value(
+    colon_token: SyntaxToken,
+    value: ScssExpression,
+) -> ScssParameterDefaultValue {
+    ScssParameterDefaultValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETER_DEFAULT_VALUE,
+        [
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_parameters(
+    l_paren_token: SyntaxToken,
+    items: ScssParameterList,
+    r_paren_token: SyntaxToken,
+) -> ScssParameters {
+    ScssParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_parenthesized_expression(
+    l_paren_token: SyntaxToken,
+    expression: ScssExpression,
+    r_paren_token: SyntaxToken,
+) -> ScssParenthesizedExpression {
+    ScssParenthesizedExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARENTHESIZED_EXPRESSION,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(expression.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_placeholder_selector(
+    percent_token: SyntaxToken,
+    name: CssCustomIdentifier,
+) -> ScssPlaceholderSelector {
+    ScssPlaceholderSelector::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PLACEHOLDER_SELECTOR,
+        [
+            Some(SyntaxElement::Token(percent_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_qualified_name(
+    module: CssIdentifier,
+    dot_token: SyntaxToken,
+    member: AnyScssModuleMember,
+) -> ScssQualifiedName {
+    ScssQualifiedName::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_QUALIFIED_NAME,
+        [
+            Some(SyntaxElement::Node(module.into_syntax())),
+            Some(SyntaxElement::Token(dot_token)),
+            Some(SyntaxElement::Node(member.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_return_at_rule(
+    return_token: SyntaxToken,
+    value: ScssExpression,
+) -> ScssReturnAtRuleBuilder {
+    ScssReturnAtRuleBuilder {
+        return_token,
+        value,
+        semicolon_token: None,
+    }
+}
+pub struct ScssReturnAtRuleBuilder {
+    return_token: SyntaxToken,
+    value: ScssExpression,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssReturnAtRuleBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssReturnAtRule {
+        ScssReturnAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_RETURN_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.return_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_use_all_namespace(star_token: SyntaxToken) -> ScssUseAllNamespace {
+    ScssUseAllNamespace::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_USE_ALL_NAMESPACE,
+        [Some(SyntaxElement::Token(star_token))],
+    ))
+}
+pub fn scss_use_as_clause(
+    as_token: SyntaxToken,
+    namespace: AnyScssUseNamespace,
+) -> ScssUseAsClause {
+    ScssUseAsClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_USE_AS_CLAUSE,
+        [
+            Some(SyntaxElement::Token(as_token)),
+            Some(SyntaxElement::Node(namespace.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_use_at_rule(
+    use_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+) -> ScssUseAtRuleBuilder {
+    ScssUseAtRuleBuilder {
+        use_token,
+        url,
+        semicolon_token,
+        as_clause: None,
+        with_clause: None,
+    }
+}
+pub struct ScssUseAtRuleBuilder {
+    use_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+    as_clause: Option<ScssUseAsClause>,
+    with_clause: Option<ScssWithClause>,
+}
+impl ScssUseAtRuleBuilder {
+    pub fn with_as_clause(mut self, as_clause: ScssUseAsClause) -> Self {
+        self.as_clause = Some(as_clause);
+        self
+    }
+    pub fn with_with_clause(mut self, with_clause: ScssWithClause) -> Self {
+        self.with_clause = Some(with_clause);
+        self
+    }
+    pub fn build(self) -> ScssUseAtRule {
+        ScssUseAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_USE_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.use_token)),
+                Some(SyntaxElement::Node(self.url.into_syntax())),
+                self.as_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.with_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.semicolon_token)),
+            ],
+        ))
+    }
+}
+pub fn scss_variable_modifier(
+    excl_token: SyntaxToken,
+    value_token: SyntaxToken,
+) -> ScssVariableModifier {
+    ScssVariableModifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_VARIABLE_MODIFIER,
+        [
+            Some(SyntaxElement::Token(excl_token)),
+            Some(SyntaxElement::Token(value_token)),
+        ],
+    ))
+}
+pub fn scss_warn_at_rule(warn_token: SyntaxToken, value: ScssExpression) -> ScssWarnAtRuleBuilder {
+    ScssWarnAtRuleBuilder {
+        warn_token,
+        value,
+        semicolon_token: None,
+    }
+}
+pub struct ScssWarnAtRuleBuilder {
+    warn_token: SyntaxToken,
+    value: ScssExpression,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssWarnAtRuleBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssWarnAtRule {
+        ScssWarnAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_WARN_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.warn_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_while_at_rule(
+    while_token: SyntaxToken,
+    condition: ScssExpression,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssWhileAtRule {
+    ScssWhileAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_WHILE_AT_RULE,
+        [
+            Some(SyntaxElement::Token(while_token)),
+            Some(SyntaxElement::Node(condition.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_with_clause(
+    with_token: SyntaxToken,
+    configuration: ScssMapExpression,
+) -> ScssWithClause {
+    ScssWithClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_WITH_CLAUSE,
+        [
+            Some(SyntaxElement::Token(with_token)),
+            Some(SyntaxElement::Node(configuration.into_syntax())),
+        ],
+    ))
+}
 pub fn tw_apply_at_rule(
     apply_token: SyntaxToken,
     classes: TwApplyClassList,
@@ -3476,6 +4594,225 @@ where
         }),
     ))
 }
+pub fn less_mixin_argument_list<I, S>(items: I, separators: S) -> LessMixinArgumentList
+where
+    I: IntoIterator<Item = AnyLessMixinArgument>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    LessMixinArgumentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_ARGUMENT_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn less_mixin_parameter_list<I, S>(items: I, separators: S) -> LessMixinParameterList
+where
+    I: IntoIterator<Item = AnyLessMixinParameter>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    LessMixinParameterList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PARAMETER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn less_mixin_path_segment_list<I>(items: I) -> LessMixinPathSegmentList
+where
+    I: IntoIterator<Item = LessMixinPathSegment>,
+    I::IntoIter: ExactSizeIterator,
+{
+    LessMixinPathSegmentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PATH_SEGMENT_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn less_mixin_semicolon_argument_list<I, S>(
+    items: I,
+    separators: S,
+) -> LessMixinSemicolonArgumentList
+where
+    I: IntoIterator<Item = AnyLessMixinArgument>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    LessMixinSemicolonArgumentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_SEMICOLON_ARGUMENT_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn less_mixin_semicolon_parameter_list<I, S>(
+    items: I,
+    separators: S,
+) -> LessMixinSemicolonParameterList
+where
+    I: IntoIterator<Item = AnyLessMixinParameter>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    LessMixinSemicolonParameterList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_SEMICOLON_PARAMETER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_argument_list<I, S>(items: I, separators: S) -> ScssArgumentList
+where
+    I: IntoIterator<Item = AnyScssArgument>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssArgumentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ARGUMENT_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_each_binding_list<I, S>(items: I, separators: S) -> ScssEachBindingList
+where
+    I: IntoIterator<Item = ScssIdentifier>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssEachBindingList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EACH_BINDING_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_forward_member_list<I, S>(items: I, separators: S) -> ScssForwardMemberList
+where
+    I: IntoIterator<Item = AnyScssForwardMember>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssForwardMemberList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FORWARD_MEMBER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_map_expression_pair_list<I, S>(items: I, separators: S) -> ScssMapExpressionPairList
+where
+    I: IntoIterator<Item = AnyScssMapExpressionPair>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssMapExpressionPairList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_parameter_list<I, S>(items: I, separators: S) -> ScssParameterList
+where
+    I: IntoIterator<Item = AnyScssParameter>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssParameterList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_variable_modifier_list<I>(items: I) -> ScssVariableModifierList
+where
+    I: IntoIterator<Item = ScssVariableModifier>,
+    I::IntoIter: ExactSizeIterator,
+{
+    ScssVariableModifierList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_VARIABLE_MODIFIER_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn tw_apply_class_list<I>(items: I) -> TwApplyClassList
 where
     I: IntoIterator<Item = CssIdentifier>,
@@ -32,6 +32,21 @@ impl FormatRule<AnyCssAtRule> for FormatAnyCssAtRule {
             AnyCssAtRule::CssUnknownValueAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssValueAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssViewTransitionAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssContentAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssDebugAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssEachAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssErrorAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssExtendAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssForAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssForwardAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssFunctionAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssIfAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssIncludeAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssMixinAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssReturnAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssUseAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssWarnAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssWhileAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::TwApplyAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::TwConfigAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::TwCustomVariantAtRule(node) => node.format().fmt(f),
@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyLessMixinArgument;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyLessMixinArgument;
+impl FormatRule<AnyLessMixinArgument> for FormatAnyLessMixinArgument {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyLessMixinArgument, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyLessMixinArgument::CssBogusParameter(node) => node.format().fmt(f),
+            AnyLessMixinArgument::LessExpression(node) => node.format().fmt(f),
+            AnyLessMixinArgument::LessKeywordArgument(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyLessMixinArguments;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyLessMixinArguments;
+impl FormatRule<AnyLessMixinArguments> for FormatAnyLessMixinArguments {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyLessMixinArguments, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyLessMixinArguments::LessMixinArguments(node) => node.format().fmt(f),
+            AnyLessMixinArguments::LessMixinSemicolonArguments(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyLessMixinParameter;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyLessMixinParameter;
+impl FormatRule<AnyLessMixinParameter> for FormatAnyLessMixinParameter {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyLessMixinParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyLessMixinParameter::AnyCssValue(node) => node.format().fmt(f),
+            AnyLessMixinParameter::CssBogusParameter(node) => node.format().fmt(f),
+            AnyLessMixinParameter::LessMixinParameter(node) => node.format().fmt(f),
+            AnyLessMixinParameter::LessMixinRestParameter(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyLessMixinParameters;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyLessMixinParameters;
+impl FormatRule<AnyLessMixinParameters> for FormatAnyLessMixinParameters {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyLessMixinParameters, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyLessMixinParameters::LessMixinParameters(node) => node.format().fmt(f),
+            AnyLessMixinParameters::LessMixinSemicolonParameters(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyLessMixinSelector;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyLessMixinSelector;
+impl FormatRule<AnyLessMixinSelector> for FormatAnyLessMixinSelector {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyLessMixinSelector, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyLessMixinSelector::CssClassSelector(node) => node.format().fmt(f),
+            AnyLessMixinSelector::CssIdSelector(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod mixin_argument;
+pub(crate) mod mixin_arguments;
+pub(crate) mod mixin_parameter;
+pub(crate) mod mixin_parameters;
+pub(crate) mod mixin_selector;
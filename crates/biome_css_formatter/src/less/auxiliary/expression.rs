@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessExpression, LessExpressionFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessExpression;
+impl FormatNodeRule<LessExpression> for FormatLessExpression {
+    fn fmt_fields(&self, node: &LessExpression, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessExpressionFields { items } = node.as_fields();
+
+        write!(f, [items.format()])
+    }
+}
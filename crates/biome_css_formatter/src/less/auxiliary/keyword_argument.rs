@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessKeywordArgument, LessKeywordArgumentFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessKeywordArgument;
+impl FormatNodeRule<LessKeywordArgument> for FormatLessKeywordArgument {
+    fn fmt_fields(&self, node: &LessKeywordArgument, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessKeywordArgumentFields {
+            name,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [name.format(), colon_token.format(), space(), value.format()]
+        )
+    }
+}
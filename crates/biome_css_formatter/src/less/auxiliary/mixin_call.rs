@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinCall, LessMixinCallFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinCall;
+impl FormatNodeRule<LessMixinCall> for FormatLessMixinCall {
+    fn fmt_fields(&self, node: &LessMixinCall, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinCallFields {
+            path,
+            arguments,
+            important,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(f, [path.format(), arguments.format()])?;
+
+        if important.is_some() {
+            write!(f, [space(), important.format()])?;
+        }
+
+        if semicolon_token.is_some() {
+            write!(f, [semicolon_token.format()])
+        } else {
+            write!(f, [token(";")])
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinDeclaration, LessMixinDeclarationFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinDeclaration;
+impl FormatNodeRule<LessMixinDeclaration> for FormatLessMixinDeclaration {
+    fn fmt_fields(&self, node: &LessMixinDeclaration, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinDeclarationFields {
+            name,
+            parameters,
+            guard,
+            block,
+        } = node.as_fields();
+
+        write!(f, [name.format(), parameters.format()])?;
+
+        if guard.is_some() {
+            write!(f, [space(), guard.format()])?;
+        }
+
+        write!(f, [space(), block.format()])
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinParameter, LessMixinParameterFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinParameter;
+impl FormatNodeRule<LessMixinParameter> for FormatLessMixinParameter {
+    fn fmt_fields(&self, node: &LessMixinParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinParameterFields {
+            name,
+            default_value,
+            dotdotdot_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                name.format(),
+                default_value.format(),
+                dotdotdot_token.format()
+            ]
+        )
+    }
+}
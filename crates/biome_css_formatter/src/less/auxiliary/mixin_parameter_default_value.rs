@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinParameterDefaultValue, LessMixinParameterDefaultValueFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinParameterDefaultValue;
+impl FormatNodeRule<LessMixinParameterDefaultValue> for FormatLessMixinParameterDefaultValue {
+    fn fmt_fields(
+        &self,
+        node: &LessMixinParameterDefaultValue,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let LessMixinParameterDefaultValueFields { colon_token, value } = node.as_fields();
+
+        write!(f, [colon_token.format(), space(), value.format()])
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinParameters, LessMixinParametersFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinParameters;
+impl FormatNodeRule<LessMixinParameters> for FormatLessMixinParameters {
+    fn fmt_fields(&self, node: &LessMixinParameters, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinParametersFields {
+            l_paren_token,
+            items,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [group(&format_args![
+                l_paren_token.format(),
+                soft_block_indent(&items.format()),
+                r_paren_token.format()
+            ])]
+        )
+    }
+}
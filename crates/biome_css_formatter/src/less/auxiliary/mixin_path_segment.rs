@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinPathSegment, LessMixinPathSegmentFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinPathSegment;
+impl FormatNodeRule<LessMixinPathSegment> for FormatLessMixinPathSegment {
+    fn fmt_fields(&self, node: &LessMixinPathSegment, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinPathSegmentFields {
+            r_angle_token,
+            selector,
+        } = node.as_fields();
+
+        if r_angle_token.is_some() {
+            write!(f, [space(), r_angle_token.format(), space()])?;
+        }
+
+        write!(f, [selector.format()])
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinRestParameter, LessMixinRestParameterFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinRestParameter;
+impl FormatNodeRule<LessMixinRestParameter> for FormatLessMixinRestParameter {
+    fn fmt_fields(&self, node: &LessMixinRestParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinRestParameterFields { dotdotdot_token } = node.as_fields();
+
+        write!(f, [dotdotdot_token.format()])
+    }
+}
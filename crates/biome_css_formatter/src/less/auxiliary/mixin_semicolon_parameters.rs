@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinSemicolonParameters, LessMixinSemicolonParametersFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinSemicolonParameters;
+impl FormatNodeRule<LessMixinSemicolonParameters> for FormatLessMixinSemicolonParameters {
+    fn fmt_fields(
+        &self,
+        node: &LessMixinSemicolonParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let LessMixinSemicolonParametersFields {
+            l_paren_token,
+            items,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [group(&format_args![
+                l_paren_token.format(),
+                soft_block_indent(&items.format()),
+                r_paren_token.format()
+            ])]
+        )
+    }
+}
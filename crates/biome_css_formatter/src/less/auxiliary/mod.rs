@@ -0,0 +1,19 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod declaration;
+pub(crate) mod escaped_value;
+pub(crate) mod expression;
+pub(crate) mod keyword_argument;
+pub(crate) mod mixin_arguments;
+pub(crate) mod mixin_call;
+pub(crate) mod mixin_declaration;
+pub(crate) mod mixin_guard;
+pub(crate) mod mixin_parameter;
+pub(crate) mod mixin_parameter_default_value;
+pub(crate) mod mixin_parameters;
+pub(crate) mod mixin_path_segment;
+pub(crate) mod mixin_rest_parameter;
+pub(crate) mod mixin_semicolon_arguments;
+pub(crate) mod mixin_semicolon_parameters;
+pub(crate) mod operator;
+pub(crate) mod parenthesized_expression;
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessOperator, LessOperatorFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessOperator;
+impl FormatNodeRule<LessOperator> for FormatLessOperator {
+    fn fmt_fields(&self, node: &LessOperator, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessOperatorFields { value } = node.as_fields();
+
+        write!(f, [value.format()])
+    }
+}
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use biome_css_syntax::LessMixinArgumentList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinArgumentList;
+impl FormatRule<LessMixinArgumentList> for FormatLessMixinArgumentList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &LessMixinArgumentList, f: &mut CssFormatter) -> FormatResult<()> {
+        let separator = soft_line_break_or_space();
+        let mut joiner = f.join_with(&separator);
+
+        for formatted in node.format_separated(",") {
+            joiner.entry(&formatted);
+        }
+
+        joiner.finish()
+    }
+}
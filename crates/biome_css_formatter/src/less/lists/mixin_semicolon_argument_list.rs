@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use biome_css_syntax::LessMixinSemicolonArgumentList;
+use biome_formatter::separated::TrailingSeparator;
+use biome_rowan::AstSeparatedList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinSemicolonArgumentList;
+impl FormatRule<LessMixinSemicolonArgumentList> for FormatLessMixinSemicolonArgumentList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &LessMixinSemicolonArgumentList, f: &mut CssFormatter) -> FormatResult<()> {
+        // A trailing semicolon is what makes the commas part of the arguments
+        // when there's a single argument, e.g. `.mixin(1, 2;)`, so it's kept as is
+        let trailing_separator = if node.trailing_separator().is_some() {
+            TrailingSeparator::Mandatory
+        } else {
+            TrailingSeparator::Omit
+        };
+
+        let separator = soft_line_break_or_space();
+        let mut joiner = f.join_with(&separator);
+
+        for formatted in node
+            .format_separated(";")
+            .with_trailing_separator(trailing_separator)
+        {
+            joiner.entry(&formatted);
+        }
+
+        joiner.finish()
+    }
+}
@@ -0,0 +1,35 @@
+use crate::prelude::*;
+use biome_css_syntax::LessMixinSemicolonParameterList;
+use biome_formatter::separated::TrailingSeparator;
+use biome_rowan::AstSeparatedList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinSemicolonParameterList;
+impl FormatRule<LessMixinSemicolonParameterList> for FormatLessMixinSemicolonParameterList {
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &LessMixinSemicolonParameterList,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        // A trailing semicolon is what makes the commas part of the parameters
+        // when there's a single parameter, e.g. `.mixin(1, 2;)`, so it's kept as is
+        let trailing_separator = if node.trailing_separator().is_some() {
+            TrailingSeparator::Mandatory
+        } else {
+            TrailingSeparator::Omit
+        };
+
+        let separator = soft_line_break_or_space();
+        let mut joiner = f.join_with(&separator);
+
+        for formatted in node
+            .format_separated(";")
+            .with_trailing_separator(trailing_separator)
+        {
+            joiner.entry(&formatted);
+        }
+
+        joiner.finish()
+    }
+}
@@ -0,0 +1,7 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod mixin_argument_list;
+pub(crate) mod mixin_parameter_list;
+pub(crate) mod mixin_path_segment_list;
+pub(crate) mod mixin_semicolon_argument_list;
+pub(crate) mod mixin_semicolon_parameter_list;
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyScssMapExpressionPair;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyScssMapExpressionPair;
+impl FormatRule<AnyScssMapExpressionPair> for FormatAnyScssMapExpressionPair {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyScssMapExpressionPair, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyScssMapExpressionPair::CssBogus(node) => node.format().fmt(f),
+            AnyScssMapExpressionPair::ScssMapExpressionPair(node) => node.format().fmt(f),
+        }
+    }
+}
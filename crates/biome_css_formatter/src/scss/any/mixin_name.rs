@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyScssMixinName;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyScssMixinName;
+impl FormatRule<AnyScssMixinName> for FormatAnyScssMixinName {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyScssMixinName, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyScssMixinName::CssIdentifier(node) => node.format().fmt(f),
+            AnyScssMixinName::ScssQualifiedName(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod argument;
+pub(crate) mod else_clause_body;
+pub(crate) mod forward_member;
+pub(crate) mod map_expression_pair;
+pub(crate) mod mixin_name;
+pub(crate) mod module_member;
+pub(crate) mod parameter;
+pub(crate) mod use_namespace;
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssMapExpression, ScssMapExpressionFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssMapExpression;
+impl FormatNodeRule<ScssMapExpression> for FormatScssMapExpression {
+    fn fmt_fields(&self, node: &ScssMapExpression, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssMapExpressionFields {
+            l_paren_token,
+            pairs,
+            r_paren_token,
+        } = node.as_fields();
+
+        // Keep the maps that are written on several lines expanded, like the objects in JavaScript
+        let should_expand = pairs.syntax().has_leading_newline();
+
+        write!(
+            f,
+            [group(&format_args![
+                l_paren_token.format(),
+                soft_block_indent(&pairs.format()),
+                r_paren_token.format()
+            ])
+            .should_expand(should_expand)]
+        )
+    }
+}
@@ -0,0 +1,22 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod arguments;
+pub(crate) mod declaration;
+pub(crate) mod else_clause;
+pub(crate) mod expression;
+pub(crate) mod extend_optional_modifier;
+pub(crate) mod forward_as_clause;
+pub(crate) mod forward_visibility_clause;
+pub(crate) mod keyword_argument;
+pub(crate) mod map_expression;
+pub(crate) mod map_expression_pair;
+pub(crate) mod operator;
+pub(crate) mod parameter;
+pub(crate) mod parameter_default_value;
+pub(crate) mod parameters;
+pub(crate) mod parenthesized_expression;
+pub(crate) mod qualified_name;
+pub(crate) mod use_all_namespace;
+pub(crate) mod use_as_clause;
+pub(crate) mod variable_modifier;
+pub(crate) mod with_clause;
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::ScssForwardMemberList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssForwardMemberList;
+impl FormatRule<ScssForwardMemberList> for FormatScssForwardMemberList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &ScssForwardMemberList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.join_with(&space())
+            .entries(node.format_separated(","))
+            .finish()
+    }
+}
@@ -0,0 +1,8 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod argument_list;
+pub(crate) mod each_binding_list;
+pub(crate) mod forward_member_list;
+pub(crate) mod map_expression_pair_list;
+pub(crate) mod parameter_list;
+pub(crate) mod variable_modifier_list;
@@ -1,7 +1,9 @@
 use crate::CssFormatter;
 use crate::comments::CssComments;
 use crate::prelude::*;
-use biome_css_syntax::{CssGenericDelimiter, CssGenericProperty, CssLanguage, CssSyntaxKind};
+use biome_css_syntax::{
+    CssGenericDelimiter, CssGenericProperty, CssLanguage, CssSyntaxKind, LessOperator, ScssOperator,
+};
 use biome_formatter::{CstFormatContext, format_args, write};
 use biome_formatter::{FormatOptions, FormatResult};
 use biome_rowan::{AstNode, AstNodeList, TextSize};
@@ -24,6 +26,78 @@ where
     matches!(token_kind, Some(CssSyntaxKind::COMMA))
 }
 
+/// Returns `true` if the node is a SCSS or Less operator.
+fn is_operator<I>(node: &I) -> bool
+where
+    I: AstNode<Language = CssLanguage>,
+{
+    ScssOperator::can_cast(node.syntax().kind()) || LessOperator::can_cast(node.syntax().kind())
+}
+
+/// Returns `true` if the node is a SCSS or Less minus operator.
+fn is_minus_operator<I>(node: &I) -> bool
+where
+    I: AstNode<Language = CssLanguage>,
+{
+    let token = if let Some(node) = ScssOperator::cast_ref(node.syntax()) {
+        node.value().ok()
+    } else {
+        LessOperator::cast_ref(node.syntax()).and_then(|node| node.value().ok())
+    };
+
+    token.is_some_and(|token| token.kind() == CssSyntaxKind::MINUS)
+}
+
+/// Returns `true` if no separator can be written between `element` and the previous element
+/// without changing the meaning of a SCSS or Less expression.
+///
+/// That's the case of the operand of a unary minus, e.g. `$gutter` in `-$gutter` or `@gutter`
+/// in `-@gutter`. The minus is
+/// unary when it's glued to its operand and it either starts the expression, follows another
+/// operator or a comma, or is separated from the previous value, as in `10px -$gutter`.
+///
+/// That's also the case of a signed number glued to a SCSS or Less value, e.g. `$width+2px`,
+/// because `$width +2px` is a list of two values.
+fn is_glued_to_preprocessor_value<I>(
+    element: &I,
+    previous: Option<&I>,
+    before_previous: Option<&I>,
+) -> bool
+where
+    I: AstNode<Language = CssLanguage>,
+{
+    let Some(previous) = previous else {
+        return false;
+    };
+
+    if previous.syntax().text_trimmed_range().end() != element.syntax().text_trimmed_range().start()
+    {
+        return false;
+    }
+
+    if is_minus_operator(previous) {
+        return before_previous.is_none_or(|before_previous| {
+            is_operator(before_previous)
+                || is_comma_delimiter(before_previous)
+                || before_previous.syntax().text_trimmed_range().end()
+                    < previous.syntax().text_trimmed_range().start()
+        });
+    }
+
+    matches!(
+        previous.syntax().kind(),
+        CssSyntaxKind::SCSS_IDENTIFIER
+            | CssSyntaxKind::SCSS_QUALIFIED_NAME
+            | CssSyntaxKind::SCSS_PARENTHESIZED_EXPRESSION
+            | CssSyntaxKind::LESS_IDENTIFIER
+            | CssSyntaxKind::LESS_PARENTHESIZED_EXPRESSION
+    ) && element
+        .syntax()
+        .text_trimmed()
+        .char_at(0.into())
+        .is_some_and(|first| matches!(first, '+' | '-'))
+}
+
 /// Applies a Prettier-like wrapping strategy for comma-separated *declaration values* when using
 /// `ValueListLayout::Fill`.
 ///
@@ -110,14 +184,19 @@ where
         let content = format_with(|f: &mut Formatter<'_, CssFormatContext>| {
             let mut inner_fill = f.fill();
 
-            for element in group_items {
+            for (index, element) in group_items.iter().enumerate() {
                 let is_comma = is_comma_delimiter(element);
+                let is_glued = is_glued_to_preprocessor_value(
+                    element,
+                    index.checked_sub(1).map(|index| &group_items[index]),
+                    index.checked_sub(2).map(|index| &group_items[index]),
+                );
                 let formatted = element.clone().into_format();
 
                 inner_fill.entry(
                     &format_once(|f| {
                         // Avoid inserting a separator before commas (e.g. `value , value`).
-                        if !is_comma {
+                        if !is_comma && !is_glued {
                             write!(f, [soft_line_break_or_space()])?;
                         }
                         Ok(())
@@ -176,8 +255,16 @@ where
 
             let mut fill = f.fill();
             let mut at_group_boundary = false;
+            let mut previous: Option<I> = None;
+            let mut before_previous: Option<I> = None;
 
             for (element, formatted) in node.iter().zip(node.iter().formatted()) {
+                let is_glued = is_glued_to_preprocessor_value(
+                    &element,
+                    previous.as_ref(),
+                    before_previous.as_ref(),
+                );
+
                 fill.entry(
                     &format_once(|f| {
                         // If the current element is not a comma, insert a soft line break or a space.
@@ -186,7 +273,8 @@ where
                         // A separator should not be added before the comma because the comma acts as a `CssGenericDelimiter`.
                         let is_comma = is_comma_delimiter(&element);
 
-                        if !is_comma {
+                        // Also avoid a separator that would change the meaning of a SCSS or Less expression, e.g. `-$gutter`.
+                        if !is_comma && !is_glued {
                             if matches!(
                                 layout,
                                 ValueListLayout::PreserveInline | ValueListLayout::OnePerLine
@@ -234,6 +322,8 @@ where
                     }),
                     &formatted,
                 );
+
+                before_previous = previous.replace(element);
             }
 
             fill.finish()
@@ -10,9 +10,14 @@ use biome_service::{
     workspace::DocumentFileSource,
 };
 
-#[derive(Default)]
 pub struct CssTestFormatLanguage {
-    _source_type: CssFileSource,
+    source_type: CssFileSource,
+}
+
+impl CssTestFormatLanguage {
+    pub fn new(source_type: CssFileSource) -> Self {
+        Self { source_type }
+    }
 }
 
 impl TestFormatLanguage for CssTestFormatLanguage {
@@ -21,10 +26,20 @@ impl TestFormatLanguage for CssTestFormatLanguage {
     type FormatLanguage = CssFormatLanguage;
 
     fn parse(&self, text: &str) -> AnyParse {
-        let options = CssParserOptions::default()
-            .allow_wrong_line_comments()
-            .allow_css_modules()
-            .allow_tailwind_directives();
+        let options = if self.source_type.is_scss() {
+            CssParserOptions::default()
+                .allow_wrong_line_comments()
+                .allow_scss()
+        } else if self.source_type.is_less() {
+            CssParserOptions::default()
+                .allow_wrong_line_comments()
+                .allow_less()
+        } else {
+            CssParserOptions::default()
+                .allow_wrong_line_comments()
+                .allow_css_modules()
+                .allow_tailwind_directives()
+        };
 
         parse_css(text, options).into()
     }
@@ -1,4 +1,5 @@
 use biome_css_formatter::{CssFormatLanguage, context::CssFormatOptions};
+use biome_css_syntax::CssFileSource;
 use biome_formatter::{IndentStyle, IndentWidth};
 use biome_formatter_test::test_prettier_snapshot::{PrettierSnapshot, PrettierTestFile};
 use camino::Utf8Path;
@@ -20,7 +21,7 @@ fn test_snapshot(input: &'static str, _: &str, _: &str, _: &str) {
     let options = CssFormatOptions::default()
         .with_indent_style(IndentStyle::Space)
         .with_indent_width(IndentWidth::default());
-    let language = language::CssTestFormatLanguage::default();
+    let language = language::CssTestFormatLanguage::new(CssFileSource::css());
     let snapshot = PrettierSnapshot::new(test_file, language, CssFormatLanguage::new(options));
 
     snapshot.test()
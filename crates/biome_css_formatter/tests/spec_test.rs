@@ -1,6 +1,7 @@
 use biome_configuration::css::CssFormatterConfiguration;
 use biome_configuration::{Configuration, CssConfiguration};
 use biome_css_formatter::{CssFormatLanguage, context::CssFormatOptions};
+use biome_css_syntax::CssFileSource;
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
 use biome_service::workspace::UpdateSettingsParams;
 use camino::Utf8Path;
@@ -18,7 +19,7 @@ mod language {
 /// `cargo insta review` or `cargo insta accept`
 ///
 /// The input and the expected output are stored as dedicated files in the `tests/specs` directory where
-/// the input file name is `{spec_name}.css` and the output file name is `{spec_name}.css.snap`.
+/// the input file name is `{spec_name}.css` (or `{spec_name}.scss`, `{spec_name}.less`) and the output file name is `{spec_name}.css.snap`.
 ///
 /// Specs can be grouped in directories by specifying the directory name in the spec name. Examples:
 ///
@@ -50,8 +51,9 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
         return;
     };
 
+    let source_type: CssFileSource = test_file.input_file().as_path().try_into().unwrap();
     let options = CssFormatOptions::default();
-    let language = language::CssTestFormatLanguage::default();
+    let language = language::CssTestFormatLanguage::new(source_type);
 
     let snapshot = SpecSnapshot::new(
         test_file,
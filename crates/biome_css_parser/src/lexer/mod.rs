@@ -316,6 +316,12 @@ impl<'src> CssLexer<'src> {
             PRD => {
                 if self.is_number_start() {
                     self.consume_number(current)
+                } else if (self.options.is_scss_enabled() || self.options.is_less_enabled())
+                    && self.peek_byte() == Some(b'.')
+                    && self.byte_at(2) == Some(b'.')
+                {
+                    self.advance(2);
+                    self.consume_byte(T![...])
                 } else {
                     self.consume_byte(T![.])
                 }
@@ -327,6 +333,7 @@ impl<'src> CssLexer<'src> {
                 self.advance(1);
                 self.consume_byte(T!["$="])
             }
+            DOL if self.options.is_scss_enabled() => self.consume_byte(T![$]),
             UNI if self.options.is_metavariable_enabled() && self.is_metavariable_start() => {
                 self.consume_metavariable(GRIT_METAVARIABLE)
             }
@@ -348,7 +355,15 @@ impl<'src> CssLexer<'src> {
             MOR => self.consume_mor(),
             TLD => self.consume_tilde(),
             PIP => self.consume_pipe(),
+            EQL if self.options.is_scss_enabled() && self.peek_byte() == Some(b'=') => {
+                self.advance(1);
+                self.consume_byte(T![==])
+            }
             EQL => self.consume_byte(T![=]),
+            EXL if self.options.is_scss_enabled() && self.peek_byte() == Some(b'=') => {
+                self.advance(1);
+                self.consume_byte(T![!=])
+            }
             EXL => self.consume_byte(T![!]),
             PRC => self.consume_byte(T![%]),
             Dispatch::AMP => self.consume_byte(T![&]),
@@ -943,6 +958,28 @@ impl<'src> CssLexer<'src> {
             b"plugin" => PLUGIN_KW,
             b"slot" => SLOT_KW,
             b"inline" => INLINE_KW,
+            // SCSS keywords
+            b"mixin" => MIXIN_KW,
+            b"include" => INCLUDE_KW,
+            b"content" => CONTENT_KW,
+            b"extend" => EXTEND_KW,
+            b"use" => USE_KW,
+            b"forward" => FORWARD_KW,
+            b"function" => FUNCTION_KW,
+            b"return" => RETURN_KW,
+            b"each" => EACH_KW,
+            b"for" => FOR_KW,
+            b"while" => WHILE_KW,
+            b"through" => THROUGH_KW,
+            b"debug" => DEBUG_KW,
+            b"warn" => WARN_KW,
+            b"error" => ERROR_KW,
+            b"show" => SHOW_KW,
+            b"hide" => HIDE_KW,
+            b"with" => WITH_KW,
+            b"optional" => OPTIONAL_KW,
+            // Less keywords
+            b"when" => WHEN_KW,
             _ => IDENT,
         }
     }
@@ -37,6 +37,16 @@ pub struct CssParserOptions {
     /// Enables parsing of Tailwind CSS 4.0 directives and functions.
     /// Defaults to `false`.
     pub tailwind_directives: bool,
+
+    /// Enables parsing of the SCSS syntax: variables, mixins, modules,
+    /// placeholder selectors, maps and control directives.
+    /// Defaults to `false`.
+    pub scss: bool,
+
+    /// Enables parsing of the Less syntax: variables, mixins, guards,
+    /// operations and escaped strings.
+    /// Defaults to `false`.
+    pub less: bool,
 }
 
 impl CssParserOptions {
@@ -64,6 +74,18 @@ impl CssParserOptions {
         self
     }
 
+    /// Enables parsing of the SCSS syntax.
+    pub fn allow_scss(mut self) -> Self {
+        self.scss = true;
+        self
+    }
+
+    /// Enables parsing of the Less syntax.
+    pub fn allow_less(mut self) -> Self {
+        self.less = true;
+        self
+    }
+
     /// Checks if parsing of CSS Modules features is disabled.
     pub fn is_css_modules_disabled(&self) -> bool {
         !self.css_modules
@@ -78,6 +100,16 @@ impl CssParserOptions {
     pub fn is_tailwind_directives_enabled(&self) -> bool {
         self.tailwind_directives
     }
+
+    /// Checks if parsing of the SCSS syntax is enabled.
+    pub fn is_scss_enabled(&self) -> bool {
+        self.scss
+    }
+
+    /// Checks if parsing of the Less syntax is enabled.
+    pub fn is_less_enabled(&self) -> bool {
+        self.less
+    }
 }
 
 impl<'source> CssParser<'source> {
@@ -180,6 +212,14 @@ impl From<&CssFileSource> for CssParserOptions {
         if file_source.is_tailwind_css() {
             options.tailwind_directives = true;
         }
+        if file_source.is_scss() {
+            options.scss = true;
+            options.allow_wrong_line_comments = true;
+        }
+        if file_source.is_less() {
+            options.less = true;
+            options.allow_wrong_line_comments = true;
+        }
 
         options
     }
@@ -72,6 +72,7 @@ use crate::syntax::at_rule::view_transition::{
 
 use crate::syntax::CssSyntaxFeatures;
 use crate::syntax::parse_error::{expected_any_at_rule, tailwind_disabled};
+use crate::syntax::scss::{is_at_scss_at_rule, parse_scss_at_rule};
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::T;
 
@@ -184,6 +185,8 @@ pub(crate) fn parse_any_at_rule(p: &mut CssParser) -> ParsedSyntax {
                 tailwind_disabled(p, m.range(p))
             })
             .or_else(|| parse_unknown_at_rule(p)),
+        // SCSS at rules
+        _ if is_at_scss_at_rule(p) => parse_scss_at_rule(p),
         _ if is_at_unknown_at_rule(p) => parse_unknown_at_rule(p),
         _ => Absent,
     }
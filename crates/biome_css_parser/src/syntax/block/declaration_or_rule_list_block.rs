@@ -1,7 +1,12 @@
 use crate::parser::CssParser;
 use crate::syntax::at_rule::{is_at_at_rule, parse_at_rule};
 use crate::syntax::block::ParseBlockBody;
+use crate::syntax::less::{
+    is_at_less_declaration, is_at_less_mixin_call, is_at_less_mixin_declaration,
+    parse_less_declaration, parse_less_mixin_call, parse_less_mixin_declaration,
+};
 use crate::syntax::parse_error::expected_any_declaration_or_at_rule;
+use crate::syntax::scss::{is_at_scss_declaration, parse_scss_declaration};
 use crate::syntax::{
     is_at_any_declaration_with_semicolon, is_at_metavariable, is_at_nested_qualified_rule,
     parse_any_declaration_with_semicolon, parse_metavariable, parse_nested_qualified_rule,
@@ -36,7 +41,11 @@ impl ParseBlockBody for DeclarationOrRuleListBlock {
 
 #[inline]
 fn is_at_declaration_or_rule_item(p: &mut CssParser) -> bool {
-    is_at_at_rule(p)
+    is_at_less_declaration(p)
+        || is_at_at_rule(p)
+        || is_at_scss_declaration(p)
+        || is_at_less_mixin_declaration(p)
+        || is_at_less_mixin_call(p)
         || is_at_nested_qualified_rule(p)
         || is_at_any_declaration_with_semicolon(p)
         || is_at_metavariable(p)
@@ -60,8 +69,16 @@ impl ParseNodeList for DeclarationOrRuleList {
     const LIST_KIND: Self::Kind = CSS_DECLARATION_OR_RULE_LIST;
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        if is_at_at_rule(p) {
+        if is_at_less_declaration(p) {
+            parse_less_declaration(p)
+        } else if is_at_at_rule(p) {
             parse_at_rule(p)
+        } else if is_at_scss_declaration(p) {
+            parse_scss_declaration(p)
+        } else if is_at_less_mixin_declaration(p) {
+            parse_less_mixin_declaration(p)
+        } else if is_at_less_mixin_call(p) {
+            parse_less_mixin_call(p)
         } else if is_at_any_declaration_with_semicolon(p) {
             // if we are at a declaration,
             // we still can have a nested qualified rule or a declaration
@@ -0,0 +1,107 @@
+use crate::parser::CssParser;
+use crate::syntax::CssSyntaxFeatures;
+use crate::syntax::parse_error::{expected_component_value, expected_less_expression};
+use crate::syntax::property::{is_at_generic_component_value, parse_generic_component_value};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, SyntaxFeature, TokenSet, token_set};
+
+/// The tokens that end a Less expression, whatever its context.
+pub(crate) const LESS_EXPRESSION_END_SET: TokenSet<CssSyntaxKind> =
+    token_set![T!['{'], T!['}'], T![;], T![')'], EOF];
+
+const LESS_OPERATOR_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![+], T![-], T![*], T![=], T![<], T![>], T![<=], T![>=]];
+
+#[inline]
+pub(crate) fn is_at_less_operator(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p) && p.at_ts(LESS_OPERATOR_SET)
+}
+
+/// Parses an arithmetic operator of a Less operation, or a comparison operator of a guard.
+#[inline]
+pub(crate) fn parse_less_operator(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_operator(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump_ts(LESS_OPERATOR_SET);
+    Present(m.complete(p, LESS_OPERATOR))
+}
+
+/// Parses a Less expression, a sequence of values, operators and delimiters that
+/// ends before any token of `end_set`.
+///
+/// ```less
+/// .mixin(@a) when (@a > 10), (@a < -10) {}
+/// .mixin(@width: @base * 2) {}
+/// ```
+#[inline]
+pub(crate) fn parse_less_expression(
+    p: &mut CssParser,
+    end_set: TokenSet<CssSyntaxKind>,
+) -> ParsedSyntax {
+    if p.at_ts(end_set) || !is_at_generic_component_value(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    LessExpressionItemList { end_set }.parse_list(p);
+    Present(m.complete(p, LESS_EXPRESSION))
+}
+
+struct LessExpressionItemList {
+    end_set: TokenSet<CssSyntaxKind>,
+}
+
+impl ParseNodeList for LessExpressionItemList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = CSS_GENERIC_COMPONENT_VALUE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_generic_component_value(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_ts(self.end_set)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS_PROPERTY_VALUE, self.end_set)
+                .enable_recovery_on_line_break(),
+            expected_component_value,
+        )
+    }
+}
+
+#[inline]
+pub(crate) fn is_at_less_parenthesized_expression(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p) && p.at(T!['('])
+}
+
+/// Parses a parenthesized Less expression, e.g. `(@width + @gutter)` or `(@a > 10)`.
+#[inline]
+pub(crate) fn parse_less_parenthesized_expression(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_parenthesized_expression(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['(']);
+    parse_less_expression(p, LESS_EXPRESSION_END_SET)
+        .or_add_diagnostic(p, expected_less_expression);
+    p.expect(T![')']);
+    Present(m.complete(p, LESS_PARENTHESIZED_EXPRESSION))
+}
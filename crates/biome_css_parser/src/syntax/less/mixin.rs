@@ -0,0 +1,454 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::less::expression::{LESS_EXPRESSION_END_SET, parse_less_expression};
+use crate::syntax::less::{is_at_less_identifier, parse_less_identifier};
+use crate::syntax::parse_error::{
+    expected_identifier, expected_less_expression, expected_less_mixin_parameter,
+    expected_less_mixin_selector,
+};
+use crate::syntax::{
+    CssSyntaxFeatures, is_at_any_value, is_nth_at_identifier, parse_any_value,
+    parse_custom_identifier_with_keywords, parse_declaration_important,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{CompletedMarker, Marker, Parser, SyntaxFeature, TokenSet, token_set};
+
+const LESS_MIXIN_SELECTOR_START_SET: TokenSet<CssSyntaxKind> = token_set![T![.], T![#]];
+
+#[inline]
+fn is_nth_at_less_mixin_selector(p: &mut CssParser, n: usize) -> bool {
+    p.nth_at_ts(n, LESS_MIXIN_SELECTOR_START_SET) && is_nth_at_identifier(p, n + 1)
+}
+
+/// Returns the position of the first token after the path of a mixin,
+/// e.g. `.mixin` or `#namespace > .mixin`, or `None` if the parser isn't at a path.
+fn nth_after_less_mixin_path(p: &mut CssParser) -> Option<usize> {
+    let mut n = 0;
+
+    while is_nth_at_less_mixin_selector(p, n) {
+        n += 2;
+
+        if p.nth_at(n, T![>]) && is_nth_at_less_mixin_selector(p, n + 1) {
+            n += 1;
+        }
+    }
+
+    (n > 0).then_some(n)
+}
+
+/// Returns the position of the first token after the parentheses that start at
+/// the nth token, or `None` if they aren't closed before the end of the block.
+fn nth_after_parentheses(p: &mut CssParser, mut n: usize) -> Option<usize> {
+    let mut depth = 0usize;
+
+    loop {
+        match p.nth(n) {
+            T!['('] => depth += 1,
+            T![')'] => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(n + 1);
+                }
+            }
+            T!['{'] | T!['}'] | EOF => return None,
+            _ => {}
+        }
+        n += 1;
+    }
+}
+
+/// Checks if the parser is at the definition of a mixin that takes parameters,
+/// e.g. `.bordered(@width) {}` or `.mixin() when (default()) {}`.
+///
+/// A mixin without parentheses is a regular rule.
+#[inline]
+pub(crate) fn is_at_less_mixin_declaration(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p)
+        && is_nth_at_less_mixin_selector(p, 0)
+        && p.nth_at(2, T!['('])
+        && nth_after_parentheses(p, 2)
+            .is_some_and(|n| p.nth_at_ts(n, token_set![T!['{'], T![when]]))
+}
+
+/// Checks if the parser is at the call of a mixin, e.g. `.bordered;`, `.bordered(4px);`
+/// or `#namespace > .mixin() !important;`.
+#[inline]
+pub(crate) fn is_at_less_mixin_call(p: &mut CssParser) -> bool {
+    if !CssSyntaxFeatures::Less.is_supported(p) {
+        return false;
+    }
+
+    let Some(mut n) = nth_after_less_mixin_path(p) else {
+        return false;
+    };
+
+    if p.nth_at(n, T!['(']) {
+        let Some(after) = nth_after_parentheses(p, n) else {
+            return false;
+        };
+        n = after;
+    }
+
+    p.nth_at_ts(n, token_set![T![;], T!['}'], EOF])
+        || (p.nth_at(n, T![!]) && p.nth_at(n + 1, T![important]))
+}
+
+/// Parses the class or id selector that names a mixin.
+///
+/// The selector is lexed in the regular context, because the whitespace after it
+/// isn't a descendant combinator.
+#[inline]
+fn parse_less_mixin_selector(p: &mut CssParser) -> ParsedSyntax {
+    let kind = match p.cur() {
+        T![.] => CSS_CLASS_SELECTOR,
+        T![#] => CSS_ID_SELECTOR,
+        _ => return Absent,
+    };
+
+    let m = p.start();
+    p.bump_ts(LESS_MIXIN_SELECTOR_START_SET);
+    parse_custom_identifier_with_keywords(p, CssLexContext::Regular, true)
+        .or_add_diagnostic(p, expected_identifier);
+    Present(m.complete(p, kind))
+}
+
+/// Parses the definition of a mixin with parameters and an optional guard.
+///
+/// ```less
+/// .bordered(@width: 2px; @style: solid) {
+///     border: @width @style black;
+/// }
+///
+/// .mixin(@a) when (lightness(@a) >= 50%) {
+///     background-color: black;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_less_mixin_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_mixin_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_less_mixin_selector(p).ok();
+    parse_less_mixin_parameters(p).ok();
+    parse_less_mixin_guard(p).ok();
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, LESS_MIXIN_DECLARATION))
+}
+
+/// Returns the separator of the parameters or the arguments of a mixin, which start
+/// at the current token.
+///
+/// The semicolon is the separator when the parentheses contain at least one,
+/// so that the commas can be part of the values. Otherwise, it's the comma.
+/// The lists separated by semicolons have their own kinds, e.g. `LESS_MIXIN_SEMICOLON_PARAMETERS`.
+fn less_mixin_separator(p: &mut CssParser) -> CssSyntaxKind {
+    let mut n = 0;
+    let mut depth = 0usize;
+
+    loop {
+        match p.nth(n) {
+            T!['('] => depth += 1,
+            T![')'] => {
+                depth -= 1;
+                if depth == 0 {
+                    return T![,];
+                }
+            }
+            T![;] if depth == 1 => return T![;],
+            T!['{'] | T!['}'] | EOF => return T![,],
+            _ => {}
+        }
+        n += 1;
+    }
+}
+
+/// Returns the tokens that end a parameter or an argument of a mixin.
+fn less_mixin_argument_end_set(separator: CssSyntaxKind) -> TokenSet<CssSyntaxKind> {
+    LESS_EXPRESSION_END_SET.union(TokenSet::singleton(separator))
+}
+
+// .bordered(@width: 2px; @style: solid) {}
+//          ^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+#[inline]
+fn parse_less_mixin_parameters(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let separator = less_mixin_separator(p);
+
+    let m = p.start();
+    p.bump(T!['(']);
+    LessMixinParameterList { separator }.parse_list(p);
+    p.expect(T![')']);
+
+    let kind = if separator == T![;] {
+        LESS_MIXIN_SEMICOLON_PARAMETERS
+    } else {
+        LESS_MIXIN_PARAMETERS
+    };
+
+    Present(m.complete(p, kind))
+}
+
+struct LessMixinParameterList {
+    separator: CssSyntaxKind,
+}
+
+impl ParseSeparatedList for LessMixinParameterList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = LESS_MIXIN_PARAMETER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_less_mixin_parameter(p, less_mixin_argument_end_set(self.separator))
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                CSS_BOGUS_PARAMETER,
+                token_set![T![')'], T!['{']].union(TokenSet::singleton(self.separator)),
+            ),
+            expected_less_mixin_parameter,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        self.separator
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+
+    fn finish_list(&mut self, p: &mut Self::Parser<'_>, m: Marker) -> CompletedMarker {
+        if self.separator == T![;] {
+            m.complete(p, LESS_MIXIN_SEMICOLON_PARAMETER_LIST)
+        } else {
+            m.complete(p, Self::LIST_KIND)
+        }
+    }
+}
+
+// .bordered(@width: 2px; @rest...) {}
+//           ^^^^^^^^^^^  ^^^^^^^^
+// .mixin(dark; @color) {}
+//        ^^^^
+#[inline]
+fn parse_less_mixin_parameter(p: &mut CssParser, end_set: TokenSet<CssSyntaxKind>) -> ParsedSyntax {
+    if is_at_less_identifier(p) {
+        let m = p.start();
+        parse_less_identifier(p).ok();
+
+        if p.at(T![:]) {
+            let default_value = p.start();
+            p.bump(T![:]);
+            parse_less_expression(p, end_set).or_add_diagnostic(p, expected_less_expression);
+            default_value.complete(p, LESS_MIXIN_PARAMETER_DEFAULT_VALUE);
+        }
+
+        p.eat(T![...]);
+
+        Present(m.complete(p, LESS_MIXIN_PARAMETER))
+    } else if p.at(T![...]) {
+        let m = p.start();
+        p.bump(T![...]);
+        Present(m.complete(p, LESS_MIXIN_REST_PARAMETER))
+    } else if is_at_any_value(p) {
+        // A value that the argument must match for the mixin to be used
+        parse_any_value(p)
+    } else {
+        Absent
+    }
+}
+
+// .mixin(@a) when (@a > 10), (@a < -10) {}
+//            ^^^^^^^^^^^^^^^^^^^^^^^^^^
+#[inline]
+fn parse_less_mixin_guard(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![when]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![when]);
+    parse_less_expression(p, LESS_EXPRESSION_END_SET)
+        .or_add_diagnostic(p, expected_less_expression);
+
+    Present(m.complete(p, LESS_MIXIN_GUARD))
+}
+
+/// Parses the call of a mixin, whose semicolon is optional before a closing brace.
+///
+/// ```less
+/// .bordered;
+/// .bordered(4px; dashed);
+/// #namespace > .mixin() !important;
+/// ```
+#[inline]
+pub(crate) fn parse_less_mixin_call(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_mixin_call(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    LessMixinPathSegmentList.parse_list(p);
+    parse_less_mixin_arguments(p).ok();
+    parse_declaration_important(p).ok();
+
+    if !p.at(T!['}']) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, LESS_MIXIN_CALL))
+}
+
+struct LessMixinPathSegmentList;
+
+impl ParseNodeList for LessMixinPathSegmentList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = LESS_MIXIN_PATH_SEGMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_less_mixin_path_segment(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !p.at_ts(LESS_MIXIN_SELECTOR_START_SET.union(token_set![T![>]]))
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T!['('], T![;], T![!], T!['}']]),
+            expected_less_mixin_selector,
+        )
+    }
+}
+
+// #namespace > .mixin();
+// ^^^^^^^^^^ ^^^^^^^^
+#[inline]
+fn parse_less_mixin_path_segment(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at_ts(LESS_MIXIN_SELECTOR_START_SET.union(token_set![T![>]])) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.eat(T![>]);
+    parse_less_mixin_selector(p).or_add_diagnostic(p, expected_less_mixin_selector);
+    Present(m.complete(p, LESS_MIXIN_PATH_SEGMENT))
+}
+
+// .bordered(4px; @style: dashed);
+//          ^^^^^^^^^^^^^^^^^^^^^
+#[inline]
+fn parse_less_mixin_arguments(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let separator = less_mixin_separator(p);
+
+    let m = p.start();
+    p.bump(T!['(']);
+    LessMixinArgumentList { separator }.parse_list(p);
+    p.expect(T![')']);
+
+    let kind = if separator == T![;] {
+        LESS_MIXIN_SEMICOLON_ARGUMENTS
+    } else {
+        LESS_MIXIN_ARGUMENTS
+    };
+
+    Present(m.complete(p, kind))
+}
+
+struct LessMixinArgumentList {
+    separator: CssSyntaxKind,
+}
+
+impl ParseSeparatedList for LessMixinArgumentList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = LESS_MIXIN_ARGUMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_less_mixin_argument(p, less_mixin_argument_end_set(self.separator))
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                CSS_BOGUS_PARAMETER,
+                token_set![T![')'], T!['}']].union(TokenSet::singleton(self.separator)),
+            ),
+            expected_less_expression,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        self.separator
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+
+    fn finish_list(&mut self, p: &mut Self::Parser<'_>, m: Marker) -> CompletedMarker {
+        if self.separator == T![;] {
+            m.complete(p, LESS_MIXIN_SEMICOLON_ARGUMENT_LIST)
+        } else {
+            m.complete(p, Self::LIST_KIND)
+        }
+    }
+}
+
+/// Parses an argument of a mixin, which can be passed by name, e.g. `@style: dashed`.
+#[inline]
+fn parse_less_mixin_argument(p: &mut CssParser, end_set: TokenSet<CssSyntaxKind>) -> ParsedSyntax {
+    if is_at_less_identifier(p) && p.nth_at(2, T![:]) {
+        let m = p.start();
+        parse_less_identifier(p).ok();
+        p.bump(T![:]);
+        parse_less_expression(p, end_set).or_add_diagnostic(p, expected_less_expression);
+        Present(m.complete(p, LESS_KEYWORD_ARGUMENT))
+    } else {
+        parse_less_expression(p, end_set)
+    }
+}
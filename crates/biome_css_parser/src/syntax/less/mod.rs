@@ -0,0 +1,137 @@
+mod expression;
+mod mixin;
+
+use crate::parser::CssParser;
+use crate::syntax::parse_error::expected_string;
+use crate::syntax::property::GenericComponentValueList;
+use crate::syntax::{CssSyntaxFeatures, parse_declaration_important, parse_string};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, SyntaxFeature, token_set};
+
+pub(crate) use expression::{is_at_less_operator, parse_less_operator};
+use expression::{is_at_less_parenthesized_expression, parse_less_parenthesized_expression};
+pub(crate) use mixin::{
+    is_at_less_mixin_call, is_at_less_mixin_declaration, parse_less_mixin_call,
+    parse_less_mixin_declaration,
+};
+
+/// Checks if the current or nth token is the start of a Less variable, e.g. `@primary-color`.
+///
+/// The name of a variable can be any identifier, including the CSS-wide keywords.
+#[inline]
+pub(crate) fn is_nth_at_less_identifier(p: &mut CssParser, n: usize) -> bool {
+    p.nth_at(n, T![@]) && (p.nth_at(n + 1, T![ident]) || p.nth(n + 1).is_keyword())
+}
+
+#[inline]
+pub(crate) fn is_at_less_identifier(p: &mut CssParser) -> bool {
+    is_nth_at_less_identifier(p, 0)
+}
+
+/// Parses a Less variable, e.g. `@primary-color`.
+#[inline]
+pub(crate) fn parse_less_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![@]);
+
+    let name = p.start();
+    p.bump_remap(T![ident]);
+    name.complete(p, CSS_IDENTIFIER);
+
+    Present(m.complete(p, LESS_IDENTIFIER))
+}
+
+/// Checks if the parser is at a logical keyword of a guard, e.g. `and` in `when (@a) and (@b)`.
+///
+/// These keywords are followed by parenthesized conditions, which aren't the arguments of a function.
+#[inline]
+pub(crate) fn is_at_less_logical_keyword(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p) && p.at_ts(token_set![T![and], T![not], T![or]])
+}
+
+/// Checks if the parser is at the declaration of a Less variable, e.g. `@primary-color: #333;`.
+///
+/// It must be checked before the at-rules, which also start with `@`.
+#[inline]
+pub(crate) fn is_at_less_declaration(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p) && is_at_less_identifier(p) && p.nth_at(2, T![:])
+}
+
+/// Parses the declaration of a Less variable.
+///
+/// ```less
+/// @primary-color: #333;
+/// @font-stack: Helvetica, sans-serif;
+/// ```
+///
+/// Like the declarations of properties, the semicolon is optional before a closing brace.
+#[inline]
+pub(crate) fn parse_less_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_less_identifier(p).ok();
+    p.bump(T![:]);
+    GenericComponentValueList.parse_list(p);
+    parse_declaration_important(p).ok();
+
+    if !p.at(T!['}']) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, LESS_DECLARATION))
+}
+
+#[inline]
+fn is_at_less_escaped_value(p: &mut CssParser) -> bool {
+    p.at(T![~]) && p.nth_at(1, CSS_STRING_LITERAL)
+}
+
+/// Parses an escaped string, whose content is emitted as is, e.g. `~"calc(100% - 10px)"`.
+#[inline]
+fn parse_less_escaped_value(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_escaped_value(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![~]);
+    parse_string(p).or_add_diagnostic(p, expected_string);
+    Present(m.complete(p, LESS_ESCAPED_VALUE))
+}
+
+/// Checks if the parser is at a value that only exists in Less: a variable,
+/// an escaped string or a parenthesized operation.
+#[inline]
+pub(crate) fn is_at_less_value(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Less.is_supported(p)
+        && (is_at_less_identifier(p)
+            || is_at_less_escaped_value(p)
+            || is_at_less_parenthesized_expression(p))
+}
+
+#[inline]
+pub(crate) fn parse_less_value(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_value(p) {
+        return Absent;
+    }
+
+    if is_at_less_identifier(p) {
+        parse_less_identifier(p)
+    } else if is_at_less_escaped_value(p) {
+        parse_less_escaped_value(p)
+    } else {
+        parse_less_parenthesized_expression(p)
+    }
+}
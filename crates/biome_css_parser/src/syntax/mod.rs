@@ -1,8 +1,10 @@
 mod at_rule;
 mod block;
 mod css_modules;
+mod less;
 mod parse_error;
 mod property;
+mod scss;
 mod selector;
 mod util;
 mod value;
@@ -11,12 +13,19 @@ use crate::lexer::CssLexContext;
 use crate::parser::CssParser;
 use crate::syntax::at_rule::{is_at_at_rule, parse_at_rule};
 use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::less::{
+    is_at_less_declaration, is_at_less_mixin_call, is_at_less_mixin_declaration, is_at_less_value,
+    parse_less_declaration, parse_less_mixin_call, parse_less_mixin_declaration, parse_less_value,
+};
 use crate::syntax::parse_error::{
     expected_any_rule, expected_non_css_wide_keyword_identifier, tailwind_disabled,
 };
 use crate::syntax::property::color::{is_at_color, parse_color};
 use crate::syntax::property::unicode_range::{is_at_unicode_range, parse_unicode_range};
 use crate::syntax::property::{is_at_any_property, parse_any_property};
+use crate::syntax::scss::{
+    is_at_scss_declaration, is_at_scss_value, parse_scss_declaration, parse_scss_value,
+};
 use crate::syntax::selector::SelectorList;
 use crate::syntax::selector::is_nth_at_selector;
 use crate::syntax::selector::relative_selector::{RelativeSelectorList, is_at_relative_selector};
@@ -38,6 +47,12 @@ use self::parse_error::{expected_component_value, expected_declaration_item};
 pub(crate) enum CssSyntaxFeatures {
     /// Enable support for Tailwind CSS directives and syntax.
     Tailwind,
+
+    /// Enable support for the SCSS syntax.
+    Scss,
+
+    /// Enable support for the Less syntax.
+    Less,
 }
 
 impl SyntaxFeature for CssSyntaxFeatures {
@@ -46,6 +61,8 @@ impl SyntaxFeature for CssSyntaxFeatures {
     fn is_supported(&self, p: &Self::Parser<'_>) -> bool {
         match self {
             Self::Tailwind => p.options().is_tailwind_directives_enabled(),
+            Self::Scss => p.options().is_scss_enabled(),
+            Self::Less => p.options().is_less_enabled(),
         }
     }
 }
@@ -71,7 +88,12 @@ impl RuleList {
 
 #[inline]
 pub(crate) fn is_at_rule_list_element(p: &mut CssParser) -> bool {
-    is_at_at_rule(p) || is_at_qualified_rule(p)
+    is_at_less_declaration(p)
+        || is_at_at_rule(p)
+        || is_at_scss_declaration(p)
+        || is_at_less_mixin_declaration(p)
+        || is_at_less_mixin_call(p)
+        || is_at_qualified_rule(p)
 }
 
 struct RuleListParseRecovery {
@@ -100,8 +122,16 @@ impl ParseNodeList for RuleList {
     const LIST_KIND: Self::Kind = CSS_RULE_LIST;
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        if is_at_at_rule(p) {
+        if is_at_less_declaration(p) {
+            parse_less_declaration(p)
+        } else if is_at_at_rule(p) {
             parse_at_rule(p)
+        } else if is_at_scss_declaration(p) {
+            parse_scss_declaration(p)
+        } else if is_at_less_mixin_declaration(p) {
+            parse_less_mixin_declaration(p)
+        } else if is_at_less_mixin_call(p) {
+            parse_less_mixin_call(p)
         } else if is_at_qualified_rule(p) {
             parse_qualified_rule(p)
         } else {
@@ -284,12 +314,12 @@ pub(crate) fn parse_empty_declaration(p: &mut CssParser) -> ParsedSyntax {
 }
 
 #[inline]
-fn is_at_declaration_important(p: &mut CssParser) -> bool {
+pub(crate) fn is_at_declaration_important(p: &mut CssParser) -> bool {
     p.at(T![!]) && p.nth_at(1, T![important])
 }
 
 #[inline]
-fn parse_declaration_important(p: &mut CssParser) -> ParsedSyntax {
+pub(crate) fn parse_declaration_important(p: &mut CssParser) -> ParsedSyntax {
     if !is_at_declaration_important(p) {
         return Absent;
     }
@@ -331,11 +361,17 @@ pub(crate) fn is_at_any_value(p: &mut CssParser) -> bool {
         || is_at_color(p)
         || is_at_bracketed_value(p)
         || is_at_metavariable(p)
+        || is_at_scss_value(p)
+        || is_at_less_value(p)
 }
 
 #[inline]
 pub(crate) fn parse_any_value(p: &mut CssParser) -> ParsedSyntax {
-    if is_at_any_function(p) {
+    if is_at_scss_value(p) {
+        parse_scss_value(p)
+    } else if is_at_less_value(p) {
+        parse_less_value(p)
+    } else if is_at_any_function(p) {
         parse_any_function(p)
     } else if is_at_dashed_identifier(p) {
         if p.nth_at(1, T![-]) && p.nth_at(2, T![*]) {
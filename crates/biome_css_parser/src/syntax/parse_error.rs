@@ -240,6 +240,42 @@ pub(crate) fn tailwind_disabled(p: &CssParser, range: TextRange) -> ParseDiagnos
         })
 }
 
+pub(crate) fn expected_scss_variable(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("SCSS variable", range, p)
+}
+
+pub(crate) fn expected_scss_expression(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("SCSS expression", range, p)
+}
+
+pub(crate) fn expected_scss_map(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("SCSS map", range, p)
+}
+
+pub(crate) fn expected_scss_map_pair(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("SCSS map entry", range, p)
+}
+
+pub(crate) fn expected_scss_parameters(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("SCSS parameter list", range, p)
+}
+
+pub(crate) fn expected_scss_variable_modifier(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["!default", "!global"], range, p)
+}
+
+pub(crate) fn expected_less_expression(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("Less expression", range, p)
+}
+
+pub(crate) fn expected_less_mixin_parameter(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["Less variable", "...", "value"], range, p)
+}
+
+pub(crate) fn expected_less_mixin_selector(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["class selector", "id selector"], range, p)
+}
+
 pub(crate) fn expected_tw_source(p: &CssParser, range: TextRange) -> ParseDiagnostic {
     expected_any(&["string literal", "inline(\"...\")"], range, p)
 }
@@ -6,9 +6,11 @@ use crate::parser::CssParser;
 use crate::syntax::css_modules::{
     composes_not_allowed, expected_classes_list, expected_composes_import_source,
 };
+use crate::syntax::less::{is_at_less_operator, parse_less_operator};
 use crate::syntax::parse_error::{
     expected_component_value, expected_identifier, tailwind_disabled,
 };
+use crate::syntax::scss::{is_at_scss_operator, parse_scss_operator};
 use crate::syntax::{
     CssSyntaxFeatures, is_at_any_value, is_at_dashed_identifier, is_at_identifier, is_at_string,
     parse_any_value, parse_custom_identifier_with_keywords, parse_dashed_identifier,
@@ -240,7 +242,10 @@ impl ParseNodeList for GenericComponentValueList {
 
 #[inline]
 pub(crate) fn is_at_generic_component_value(p: &mut CssParser) -> bool {
-    is_at_any_value(p) || is_at_generic_delimiter(p)
+    is_at_any_value(p)
+        || is_at_generic_delimiter(p)
+        || is_at_scss_operator(p)
+        || is_at_less_operator(p)
 }
 
 #[inline]
@@ -251,6 +256,10 @@ pub(crate) fn parse_generic_component_value(p: &mut CssParser) -> ParsedSyntax {
 
     if is_at_generic_delimiter(p) {
         parse_generic_delimiter(p)
+    } else if is_at_scss_operator(p) {
+        parse_scss_operator(p)
+    } else if is_at_less_operator(p) {
+        parse_less_operator(p)
     } else {
         parse_any_value(p)
     }
@@ -0,0 +1,631 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::parse_error::{
+    expected_identifier, expected_scss_expression, expected_scss_map, expected_scss_parameters,
+    expected_scss_variable, expected_string,
+};
+use crate::syntax::scss::expression::{
+    SCSS_ARGUMENT_END_SET, SCSS_EXPRESSION_END_SET, is_at_scss_map_expression,
+    parse_scss_expression, parse_scss_map_expression,
+};
+use crate::syntax::scss::{
+    is_at_scss_identifier, is_at_scss_qualified_name, parse_scss_identifier,
+};
+use crate::syntax::selector::SelectorList;
+use crate::syntax::{CssSyntaxFeatures, is_at_identifier, parse_regular_identifier, parse_string};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseSeparatedList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, SyntaxFeature, TokenSet, token_set};
+
+const SCSS_AT_RULE_SET: TokenSet<CssSyntaxKind> = token_set![
+    T![use],
+    T![forward],
+    T![mixin],
+    T![function],
+    T![include],
+    T![content],
+    T![extend],
+    T![return],
+    T![if],
+    T![each],
+    T![for],
+    T![while],
+    T![debug],
+    T![warn],
+    T![error]
+];
+
+/// Checks if the parser is at the name of a SCSS at-rule, right after the `@`.
+///
+/// When SCSS is disabled, these at-rules are parsed as unknown at-rules.
+#[inline]
+pub(crate) fn is_at_scss_at_rule(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Scss.is_supported(p) && p.at_ts(SCSS_AT_RULE_SET)
+}
+
+#[inline]
+pub(crate) fn parse_scss_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_at_rule(p) {
+        return Absent;
+    }
+
+    match p.cur() {
+        T![use] => parse_scss_use_at_rule(p),
+        T![forward] => parse_scss_forward_at_rule(p),
+        T![mixin] => parse_scss_mixin_at_rule(p),
+        T![function] => parse_scss_function_at_rule(p),
+        T![include] => parse_scss_include_at_rule(p),
+        T![content] => parse_scss_content_at_rule(p),
+        T![extend] => parse_scss_extend_at_rule(p),
+        T![return] => parse_scss_return_at_rule(p),
+        T![if] => parse_scss_if_at_rule(p),
+        T![each] => parse_scss_each_at_rule(p),
+        T![for] => parse_scss_for_at_rule(p),
+        T![while] => parse_scss_while_at_rule(p),
+        T![debug] => parse_scss_message_at_rule(p, T![debug], SCSS_DEBUG_AT_RULE),
+        T![warn] => parse_scss_message_at_rule(p, T![warn], SCSS_WARN_AT_RULE),
+        T![error] => parse_scss_message_at_rule(p, T![error], SCSS_ERROR_AT_RULE),
+        _ => Absent,
+    }
+}
+
+/// Expects the semicolon that ends a statement, which is optional before a closing brace.
+#[inline]
+fn expect_statement_end(p: &mut CssParser) {
+    if !p.at(T!['}']) {
+        p.expect(T![;]);
+    }
+}
+
+// @use "sass:math" as m;
+// @use "config" with ($primary: blue);
+#[inline]
+fn parse_scss_use_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![use]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![use]);
+    parse_string(p).or_add_diagnostic(p, expected_string);
+
+    if p.at(T![as]) {
+        let clause = p.start();
+        p.bump(T![as]);
+        if p.at(T![*]) {
+            let namespace = p.start();
+            p.bump(T![*]);
+            namespace.complete(p, SCSS_USE_ALL_NAMESPACE);
+        } else {
+            parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+        }
+        clause.complete(p, SCSS_USE_AS_CLAUSE);
+    }
+
+    parse_scss_with_clause(p).ok();
+    p.expect(T![;]);
+
+    Present(m.complete(p, SCSS_USE_AT_RULE))
+}
+
+// @forward "src/list" as list-* hide list-reset, $horizontal-list-gap;
+#[inline]
+fn parse_scss_forward_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![forward]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![forward]);
+    parse_string(p).or_add_diagnostic(p, expected_string);
+
+    if p.at(T![as]) {
+        let clause = p.start();
+        p.bump(T![as]);
+        parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+        p.expect(T![*]);
+        clause.complete(p, SCSS_FORWARD_AS_CLAUSE);
+    }
+
+    if p.at_ts(token_set![T![show], T![hide]]) {
+        let clause = p.start();
+        p.bump_ts(token_set![T![show], T![hide]]);
+        ScssForwardMemberList.parse_list(p);
+        clause.complete(p, SCSS_FORWARD_VISIBILITY_CLAUSE);
+    }
+
+    parse_scss_with_clause(p).ok();
+    p.expect(T![;]);
+
+    Present(m.complete(p, SCSS_FORWARD_AT_RULE))
+}
+
+struct ScssForwardMemberList;
+
+impl ParseSeparatedList for ScssForwardMemberList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_FORWARD_MEMBER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if is_at_scss_identifier(p) {
+            parse_scss_identifier(p)
+        } else {
+            parse_regular_identifier(p)
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_ts(token_set![T![;], T![with], T!['}'], EOF])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![;], T![with]]),
+            expected_identifier,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
+
+// @use "config" with ($primary: blue);
+//               ^^^^^^^^^^^^^^^^^^^^^
+#[inline]
+fn parse_scss_with_clause(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![with]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![with]);
+
+    if is_at_scss_map_expression(p) {
+        parse_scss_map_expression(p).ok();
+    } else {
+        p.error(expected_scss_map(p, p.cur_range()));
+    }
+
+    Present(m.complete(p, SCSS_WITH_CLAUSE))
+}
+
+// @mixin theme($theme: DarkGray) { background: $theme; }
+#[inline]
+fn parse_scss_mixin_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![mixin]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![mixin]);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    parse_scss_parameters(p).ok();
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_MIXIN_AT_RULE))
+}
+
+// @function double($value) { @return $value * 2; }
+#[inline]
+fn parse_scss_function_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![function]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![function]);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    parse_scss_parameters(p).or_add_diagnostic(p, expected_scss_parameters);
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_FUNCTION_AT_RULE))
+}
+
+// @mixin theme($theme: DarkGray, $args...) {}
+//             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+#[inline]
+fn parse_scss_parameters(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['(']);
+    ScssParameterList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_PARAMETERS))
+}
+
+struct ScssParameterList;
+
+impl ParseSeparatedList for ScssParameterList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_PARAMETER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_parameter(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                CSS_BOGUS_PARAMETER,
+                token_set![T![,], T![')'], T!['{'], T![;]],
+            ),
+            expected_scss_variable,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+// @mixin theme($theme: DarkGray, $args...) {}
+//              ^^^^^^^^^^^^^^^^  ^^^^^^^^
+#[inline]
+fn parse_scss_parameter(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_scss_identifier(p).ok();
+
+    if p.at(T![:]) {
+        let default_value = p.start();
+        p.bump(T![:]);
+        parse_scss_expression(p, SCSS_ARGUMENT_END_SET)
+            .or_add_diagnostic(p, expected_scss_expression);
+        default_value.complete(p, SCSS_PARAMETER_DEFAULT_VALUE);
+    }
+
+    p.eat(T![...]);
+
+    Present(m.complete(p, SCSS_PARAMETER))
+}
+
+// @include theme($theme: DarkRed);
+// @include breakpoints.up(md) { width: 50%; }
+#[inline]
+fn parse_scss_include_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![include]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![include]);
+    parse_scss_mixin_name(p).or_add_diagnostic(p, expected_identifier);
+    parse_scss_arguments(p).ok();
+
+    if p.at(T!['{']) {
+        parse_declaration_or_rule_list_block(p);
+    } else {
+        expect_statement_end(p);
+    }
+
+    Present(m.complete(p, SCSS_INCLUDE_AT_RULE))
+}
+
+/// Parses the name of an included mixin, which can be a member of a module, e.g. `mixins.button`.
+#[inline]
+fn parse_scss_mixin_name(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_scss_qualified_name(p) {
+        let m = p.start();
+        parse_regular_identifier(p).ok();
+        p.bump(T![.]);
+        parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+        Present(m.complete(p, SCSS_QUALIFIED_NAME))
+    } else if is_at_identifier(p) {
+        parse_regular_identifier(p)
+    } else {
+        Absent
+    }
+}
+
+// @include theme($theme: DarkRed, 10px);
+//               ^^^^^^^^^^^^^^^^^^^^^^^
+#[inline]
+fn parse_scss_arguments(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['(']);
+    ScssArgumentList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_ARGUMENTS))
+}
+
+struct ScssArgumentList;
+
+impl ParseSeparatedList for ScssArgumentList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_ARGUMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_argument(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS_PARAMETER, token_set![T![,], T![')'], T![;]]),
+            expected_scss_expression,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+/// Parses an argument of a mixin, which can be passed by name, e.g. `$theme: DarkRed`.
+#[inline]
+fn parse_scss_argument(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_scss_identifier(p) && p.nth_at(2, T![:]) {
+        let m = p.start();
+        parse_scss_identifier(p).ok();
+        p.bump(T![:]);
+        parse_scss_expression(p, SCSS_ARGUMENT_END_SET)
+            .or_add_diagnostic(p, expected_scss_expression);
+        Present(m.complete(p, SCSS_KEYWORD_ARGUMENT))
+    } else {
+        parse_scss_expression(p, SCSS_ARGUMENT_END_SET)
+    }
+}
+
+// @content;
+// @content($theme);
+#[inline]
+fn parse_scss_content_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![content]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![content]);
+    parse_scss_arguments(p).ok();
+    expect_statement_end(p);
+
+    Present(m.complete(p, SCSS_CONTENT_AT_RULE))
+}
+
+// @extend %message-shared !optional;
+#[inline]
+fn parse_scss_extend_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![extend]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![extend]);
+
+    SelectorList::default()
+        .with_end_kind_ts(token_set![T![;], T![!], T!['}']])
+        .with_recovery_ts(token_set![T![;], T![!], T!['}']])
+        .parse_list(p);
+
+    if p.at(T![!]) {
+        let modifier = p.start();
+        p.bump(T![!]);
+        p.expect(T![optional]);
+        modifier.complete(p, SCSS_EXTEND_OPTIONAL_MODIFIER);
+    }
+
+    expect_statement_end(p);
+
+    Present(m.complete(p, SCSS_EXTEND_AT_RULE))
+}
+
+// @return $value * 2;
+#[inline]
+fn parse_scss_return_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![return]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![return]);
+    parse_scss_expression(p, SCSS_EXPRESSION_END_SET)
+        .or_add_diagnostic(p, expected_scss_expression);
+    expect_statement_end(p);
+
+    Present(m.complete(p, SCSS_RETURN_AT_RULE))
+}
+
+// @if $theme == dark { color: white; } @else if $theme == light { color: black; } @else {}
+#[inline]
+fn parse_scss_if_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![if]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![if]);
+    parse_scss_expression(p, SCSS_EXPRESSION_END_SET)
+        .or_add_diagnostic(p, expected_scss_expression);
+    parse_declaration_or_rule_list_block(p);
+    parse_scss_else_clause(p).ok();
+
+    Present(m.complete(p, SCSS_IF_AT_RULE))
+}
+
+// @if $theme == dark { color: white; } @else { color: black; }
+//                                      ^^^^^^^^^^^^^^^^^^^^^^^
+#[inline]
+fn parse_scss_else_clause(p: &mut CssParser) -> ParsedSyntax {
+    if !(p.at(T![@]) && p.nth_at(1, T![else])) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![@]);
+    p.bump(T![else]);
+
+    if p.at(T![if]) {
+        parse_scss_if_at_rule(p).ok();
+    } else {
+        parse_declaration_or_rule_list_block(p);
+    }
+
+    Present(m.complete(p, SCSS_ELSE_CLAUSE))
+}
+
+// @each $name, $glyph in $icons {}
+#[inline]
+fn parse_scss_each_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![each]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![each]);
+    ScssEachBindingList.parse_list(p);
+    p.expect(T![in]);
+    parse_scss_expression(p, SCSS_EXPRESSION_END_SET)
+        .or_add_diagnostic(p, expected_scss_expression);
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_EACH_AT_RULE))
+}
+
+struct ScssEachBindingList;
+
+impl ParseSeparatedList for ScssEachBindingList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_EACH_BINDING_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_identifier(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_ts(token_set![T![in], T!['{']])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![in], T!['{']]),
+            expected_scss_variable,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
+
+// @for $i from 1 through 3 {}
+#[inline]
+fn parse_scss_for_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![for]) {
+        return Absent;
+    }
+
+    let range_operators = token_set![T![through], T![to]];
+
+    let m = p.start();
+    p.bump(T![for]);
+    parse_scss_identifier(p).or_add_diagnostic(p, expected_scss_variable);
+    p.expect(T![from]);
+    parse_scss_expression(p, SCSS_EXPRESSION_END_SET.union(range_operators))
+        .or_add_diagnostic(p, expected_scss_expression);
+
+    if p.at_ts(range_operators) {
+        p.bump_ts(range_operators);
+    } else {
+        p.expect(T![through]);
+    }
+
+    parse_scss_expression(p, SCSS_EXPRESSION_END_SET)
+        .or_add_diagnostic(p, expected_scss_expression);
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_FOR_AT_RULE))
+}
+
+// @while $i > 0 {}
+#[inline]
+fn parse_scss_while_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![while]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![while]);
+    parse_scss_expression(p, SCSS_EXPRESSION_END_SET)
+        .or_add_diagnostic(p, expected_scss_expression);
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_WHILE_AT_RULE))
+}
+
+// @debug "value: #{$value}";
+// @warn "Unknown prefix.";
+// @error "Property must be either left or right.";
+#[inline]
+fn parse_scss_message_at_rule(
+    p: &mut CssParser,
+    keyword: CssSyntaxKind,
+    kind: CssSyntaxKind,
+) -> ParsedSyntax {
+    if !p.at(keyword) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(keyword);
+    parse_scss_expression(p, SCSS_EXPRESSION_END_SET)
+        .or_add_diagnostic(p, expected_scss_expression);
+    expect_statement_end(p);
+
+    Present(m.complete(p, kind))
+}
@@ -0,0 +1,201 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{
+    expected_component_value, expected_scss_expression, expected_scss_map_pair,
+};
+use crate::syntax::property::{is_at_generic_component_value, parse_generic_component_value};
+use crate::syntax::scss::is_nth_at_scss_identifier;
+use crate::syntax::{CssSyntaxFeatures, is_at_any_value, parse_any_value};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, SyntaxFeature, TokenSet, token_set};
+
+/// The tokens that end a SCSS expression, whatever its context.
+pub(crate) const SCSS_EXPRESSION_END_SET: TokenSet<CssSyntaxKind> =
+    token_set![T!['{'], T!['}'], T![;], T![')'], T![!], EOF];
+
+/// The tokens that end a SCSS expression in a list of arguments or in a map, where
+/// the commas separate the expressions.
+pub(crate) const SCSS_ARGUMENT_END_SET: TokenSet<CssSyntaxKind> =
+    SCSS_EXPRESSION_END_SET.union(token_set![T![,]]);
+
+const SCSS_OPERATOR_SET: TokenSet<CssSyntaxKind> = token_set![
+    T![+],
+    T![-],
+    T![*],
+    T![%],
+    T![==],
+    T![!=],
+    T![<],
+    T![>],
+    T![<=],
+    T![>=]
+];
+
+#[inline]
+pub(crate) fn is_at_scss_operator(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Scss.is_supported(p) && p.at_ts(SCSS_OPERATOR_SET)
+}
+
+/// Parses an arithmetic or a comparison operator of a SCSS expression.
+#[inline]
+pub(crate) fn parse_scss_operator(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_operator(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump_ts(SCSS_OPERATOR_SET);
+    Present(m.complete(p, SCSS_OPERATOR))
+}
+
+/// Parses a SCSS expression, a sequence of values, operators and delimiters that
+/// ends before any token of `end_set`.
+///
+/// ```scss
+/// @if $theme == dark and $contrast > 1 {}
+/// @return math.div($width, $columns) * 100%;
+/// ```
+#[inline]
+pub(crate) fn parse_scss_expression(
+    p: &mut CssParser,
+    end_set: TokenSet<CssSyntaxKind>,
+) -> ParsedSyntax {
+    if p.at_ts(end_set) || !is_at_generic_component_value(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    ScssExpressionItemList { end_set }.parse_list(p);
+    Present(m.complete(p, SCSS_EXPRESSION))
+}
+
+struct ScssExpressionItemList {
+    end_set: TokenSet<CssSyntaxKind>,
+}
+
+impl ParseNodeList for ScssExpressionItemList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = CSS_GENERIC_COMPONENT_VALUE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_generic_component_value(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_ts(self.end_set)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS_PROPERTY_VALUE, self.end_set)
+                .enable_recovery_on_line_break(),
+            expected_component_value,
+        )
+    }
+}
+
+/// Checks if the parser is at a SCSS map, e.g. `(small: 576px, medium: 768px)`.
+///
+/// A parenthesized value is a map when its first value is followed by a colon.
+/// The empty parentheses are an empty map.
+#[inline]
+pub(crate) fn is_at_scss_map_expression(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Scss.is_supported(p)
+        && p.at(T!['('])
+        && (p.nth_at(1, T![')'])
+            || p.nth_at(2, T![:])
+            || (is_nth_at_scss_identifier(p, 1) && p.nth_at(3, T![:])))
+}
+
+#[inline]
+pub(crate) fn parse_scss_map_expression(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_map_expression(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['(']);
+    ScssMapExpressionPairList.parse_list(p);
+    p.expect(T![')']);
+    Present(m.complete(p, SCSS_MAP_EXPRESSION))
+}
+
+struct ScssMapExpressionPairList;
+
+impl ParseSeparatedList for ScssMapExpressionPairList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_MAP_EXPRESSION_PAIR_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_map_expression_pair(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![')'], T![;], T!['}']]),
+            expected_scss_map_pair,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+/// Parses an entry of a SCSS map, e.g. `small: 576px`.
+#[inline]
+fn parse_scss_map_expression_pair(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_any_value(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_any_value(p).ok();
+    p.expect(T![:]);
+    parse_scss_expression(p, SCSS_ARGUMENT_END_SET).or_add_diagnostic(p, expected_scss_expression);
+    Present(m.complete(p, SCSS_MAP_EXPRESSION_PAIR))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_parenthesized_expression(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Scss.is_supported(p) && p.at(T!['('])
+}
+
+/// Parses a parenthesized SCSS expression, e.g. `($width + $gutter)` or `(1px, 2px)`.
+#[inline]
+pub(crate) fn parse_scss_parenthesized_expression(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_parenthesized_expression(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['(']);
+    parse_scss_expression(p, SCSS_EXPRESSION_END_SET)
+        .or_add_diagnostic(p, expected_scss_expression);
+    p.expect(T![')']);
+    Present(m.complete(p, SCSS_PARENTHESIZED_EXPRESSION))
+}
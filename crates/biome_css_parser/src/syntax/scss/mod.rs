@@ -0,0 +1,190 @@
+mod at_rule;
+mod expression;
+
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{expected_identifier, expected_scss_variable_modifier};
+use crate::syntax::property::GenericComponentValueList;
+use crate::syntax::value::function::{is_at_function, parse_function};
+use crate::syntax::{
+    CssSyntaxFeatures, is_at_identifier, is_nth_at_identifier, parse_regular_identifier,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, SyntaxFeature, token_set};
+
+pub(crate) use at_rule::{is_at_scss_at_rule, parse_scss_at_rule};
+pub(crate) use expression::{is_at_scss_map_expression, is_at_scss_operator, parse_scss_operator};
+use expression::{
+    is_at_scss_parenthesized_expression, parse_scss_map_expression,
+    parse_scss_parenthesized_expression,
+};
+
+/// Checks if the current or nth token is the start of a SCSS variable, e.g. `$primary-color`.
+///
+/// The name of a variable can be any identifier, including the CSS-wide keywords.
+#[inline]
+pub(crate) fn is_nth_at_scss_identifier(p: &mut CssParser, n: usize) -> bool {
+    p.nth_at(n, T![$]) && (p.nth_at(n + 1, T![ident]) || p.nth(n + 1).is_keyword())
+}
+
+#[inline]
+pub(crate) fn is_at_scss_identifier(p: &mut CssParser) -> bool {
+    is_nth_at_scss_identifier(p, 0)
+}
+
+/// Parses a SCSS variable, e.g. `$primary-color`.
+#[inline]
+pub(crate) fn parse_scss_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![$]);
+
+    let name = p.start();
+    p.bump_remap(T![ident]);
+    name.complete(p, CSS_IDENTIFIER);
+
+    Present(m.complete(p, SCSS_IDENTIFIER))
+}
+
+/// Checks if the parser is at the declaration of a SCSS variable, e.g. `$primary-color: #333;`.
+#[inline]
+pub(crate) fn is_at_scss_declaration(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Scss.is_supported(p) && is_at_scss_identifier(p) && p.nth_at(2, T![:])
+}
+
+/// Parses the declaration of a SCSS variable.
+///
+/// ```scss
+/// $primary-color: #333;
+/// $font-stack: Helvetica, sans-serif !default;
+/// ```
+///
+/// Like the declarations of properties, the semicolon is optional before a closing brace.
+#[inline]
+pub(crate) fn parse_scss_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_scss_identifier(p).ok();
+    p.bump(T![:]);
+    GenericComponentValueList.parse_list(p);
+    ScssVariableModifierList.parse_list(p);
+
+    if !p.at(T!['}']) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, SCSS_DECLARATION))
+}
+
+struct ScssVariableModifierList;
+
+impl ParseNodeList for ScssVariableModifierList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_VARIABLE_MODIFIER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_variable_modifier(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !p.at(T![!])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![;], T!['}']])
+                .enable_recovery_on_line_break(),
+            expected_scss_variable_modifier,
+        )
+    }
+}
+
+/// Parses the `!default` or `!global` flag of a variable declaration.
+#[inline]
+fn parse_scss_variable_modifier(p: &mut CssParser) -> ParsedSyntax {
+    if !(p.at(T![!]) && p.nth_at_ts(1, token_set![T![default], T![global]])) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![!]);
+    p.bump_ts(token_set![T![default], T![global]]);
+    Present(m.complete(p, SCSS_VARIABLE_MODIFIER))
+}
+
+/// Checks if the parser is at a member of a SCSS module, e.g. `math.div(10px, 2)` or `config.$primary`.
+#[inline]
+pub(crate) fn is_at_scss_qualified_name(p: &mut CssParser) -> bool {
+    is_at_identifier(p)
+        && p.nth_at(1, T![.])
+        && (is_nth_at_identifier(p, 2) || is_nth_at_scss_identifier(p, 2))
+}
+
+/// Parses a member of a SCSS module, which can be a variable, a function call or,
+/// in the name of an included mixin, an identifier.
+#[inline]
+fn parse_scss_qualified_name(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_qualified_name(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_regular_identifier(p).ok();
+    p.bump(T![.]);
+
+    if is_at_scss_identifier(p) {
+        parse_scss_identifier(p).ok();
+    } else if is_at_function(p) {
+        parse_function(p).ok();
+    } else {
+        parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    }
+
+    Present(m.complete(p, SCSS_QUALIFIED_NAME))
+}
+
+/// Checks if the parser is at a value that only exists in SCSS: a variable, a member of a
+/// module, a map or a parenthesized expression.
+#[inline]
+pub(crate) fn is_at_scss_value(p: &mut CssParser) -> bool {
+    CssSyntaxFeatures::Scss.is_supported(p)
+        && (is_at_scss_identifier(p)
+            || is_at_scss_qualified_name(p)
+            || is_at_scss_map_expression(p)
+            || is_at_scss_parenthesized_expression(p))
+}
+
+#[inline]
+pub(crate) fn parse_scss_value(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_value(p) {
+        return Absent;
+    }
+
+    if is_at_scss_identifier(p) {
+        parse_scss_identifier(p)
+    } else if is_at_scss_qualified_name(p) {
+        parse_scss_qualified_name(p)
+    } else if is_at_scss_map_expression(p) {
+        parse_scss_map_expression(p)
+    } else {
+        parse_scss_parenthesized_expression(p)
+    }
+}
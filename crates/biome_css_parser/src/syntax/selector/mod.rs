@@ -14,8 +14,8 @@ use crate::syntax::selector::nested_selector::NestedSelectorList;
 use crate::syntax::selector::pseudo_class::parse_pseudo_class_selector;
 use crate::syntax::selector::pseudo_element::parse_pseudo_element_selector;
 use crate::syntax::{
-    is_at_identifier, is_nth_at_identifier, parse_custom_identifier_with_keywords,
-    parse_identifier, parse_regular_identifier,
+    CssSyntaxFeatures, is_at_identifier, is_nth_at_identifier,
+    parse_custom_identifier_with_keywords, parse_identifier, parse_regular_identifier,
 };
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::{CssSyntaxKind, T, TextRange};
@@ -26,7 +26,7 @@ use biome_parser::parse_recovery::{
 };
 use biome_parser::prelude::ParsedSyntax;
 use biome_parser::prelude::ParsedSyntax::{Absent, Present};
-use biome_parser::{CompletedMarker, Parser, ParserProgress, TokenSet, token_set};
+use biome_parser::{CompletedMarker, Parser, ParserProgress, SyntaxFeature, TokenSet, token_set};
 
 use super::{is_nth_at_metavariable, parse_metavariable};
 
@@ -36,8 +36,10 @@ use super::{is_nth_at_metavariable, parse_metavariable};
 /// current context should be treated as a regular context or a selector-specific
 /// context. The distinction is important for handling whitespaces, especially
 /// around combinators in CSS selectors.
-const SELECTOR_LEX_SET: TokenSet<CssSyntaxKind> =
-    COMPLEX_SELECTOR_COMBINATOR_SET.union(token_set![T!['{'], T![,], T![')']]);
+const SELECTOR_LEX_SET: TokenSet<CssSyntaxKind> = COMPLEX_SELECTOR_COMBINATOR_SET
+    .union(token_set![T!['{'], T![,], T![')']])
+    // The end of the selectors of a SCSS `@extend` at-rule
+    .union(token_set![T![;], T![!]]);
 #[inline]
 fn selector_lex_context(p: &mut CssParser) -> CssLexContext {
     // It's an inverted logic for `is_nth_at_selector(p, 1)`.
@@ -274,9 +276,7 @@ fn parse_complex_selector(p: &mut CssParser, mut left: CompletedMarker) -> Parse
 /// including a reference combinator ('&').
 #[inline]
 fn is_nth_at_compound_selector(p: &mut CssParser, n: usize) -> bool {
-    p.nth_at(n, T![&])
-        || is_nth_at_simple_selector(p, n)
-        || p.nth_at_ts(n, SubSelectorList::START_SET)
+    p.nth_at(n, T![&]) || is_nth_at_simple_selector(p, n) || is_nth_at_sub_selector(p, n)
 }
 
 /// Parses a compound selector in CSS.
@@ -399,6 +399,14 @@ impl SubSelectorList {
     pub(crate) const START_SET: TokenSet<CssSyntaxKind> =
         token_set![T![#], T![.], T![:], T![::], T!['[']];
 }
+
+/// Checks if the current or nth token is the start of a sub-selector,
+/// including the SCSS placeholder selectors when SCSS is enabled.
+#[inline]
+fn is_nth_at_sub_selector(p: &mut CssParser, n: usize) -> bool {
+    p.nth_at_ts(n, SubSelectorList::START_SET)
+        || (CssSyntaxFeatures::Scss.is_supported(p) && p.nth_at(n, T![%]))
+}
 impl ParseNodeList for SubSelectorList {
     type Kind = CssSyntaxKind;
     type Parser<'source> = CssParser<'source>;
@@ -410,7 +418,7 @@ impl ParseNodeList for SubSelectorList {
     }
 
     fn is_at_list_end(&self, p: &mut CssParser) -> bool {
-        !p.at_ts(Self::START_SET)
+        !is_nth_at_sub_selector(p, 0)
     }
 
     fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
@@ -435,6 +443,7 @@ fn parse_sub_selector(p: &mut CssParser) -> ParsedSyntax {
         T!['['] => parse_attribute_selector(p),
         T![:] => parse_pseudo_class_selector(p),
         T![::] => parse_pseudo_element_selector(p),
+        T![%] if CssSyntaxFeatures::Scss.is_supported(p) => parse_placeholder_selector(p),
         _ => Absent,
     }
 }
@@ -477,6 +486,24 @@ pub(crate) fn parse_id_selector(p: &mut CssParser) -> ParsedSyntax {
     Present(m.complete(p, CSS_ID_SELECTOR))
 }
 
+/// Parses a SCSS placeholder selector.
+///
+/// Placeholder selectors start with a percent sign ('%') followed by an identifier.
+/// They are only emitted in the output CSS when they are extended with `@extend`.
+#[inline]
+pub(crate) fn parse_placeholder_selector(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![%]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![%]);
+    parse_selector_custom_identifier(p).or_add_diagnostic(p, expected_identifier);
+
+    Present(m.complete(p, SCSS_PLACEHOLDER_SELECTOR))
+}
+
 /// Parses a universal selector ('*') in CSS.
 ///
 /// This function attempts to parse a universal selector from the current position
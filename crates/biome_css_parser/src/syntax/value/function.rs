@@ -2,7 +2,9 @@ use super::r#if::is_at_if_function;
 use super::parse_error::expected_expression;
 use super::url::{is_at_url_function, parse_url_function};
 use crate::parser::CssParser;
+use crate::syntax::less::is_at_less_logical_keyword;
 use crate::syntax::parse_error::expected_declaration_item;
+use crate::syntax::scss::is_at_scss_map_expression;
 use crate::syntax::value::r#if::parse_if_function;
 use crate::syntax::{
     CssComponentValueList, is_at_any_value, is_at_dashed_identifier, is_nth_at_identifier,
@@ -51,7 +53,7 @@ pub(crate) fn parse_any_function(p: &mut CssParser) -> ParsedSyntax {
 /// excluding URL functions (since URL functions are also considered simple functions but are handled separately).
 #[inline]
 pub(crate) fn is_at_function(p: &mut CssParser) -> bool {
-    is_nth_at_function(p, 0) && !is_at_url_function(p)
+    is_nth_at_function(p, 0) && !is_at_url_function(p) && !is_at_less_logical_keyword(p)
 }
 
 #[inline]
@@ -192,7 +194,8 @@ pub(crate) fn parse_any_expression(p: &mut CssParser) -> ParsedSyntax {
         return Absent;
     }
 
-    let param = if is_at_parenthesized(p) {
+    // In SCSS, a map can be passed as an argument of a function
+    let param = if is_at_parenthesized(p) && !is_at_scss_map_expression(p) {
         parse_parenthesized_expression(p)
     } else {
         parse_list_of_component_values_expression(p)
@@ -2,6 +2,7 @@ use biome_css_syntax::CssSyntaxKind;
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::T;
 use biome_parser::Parser;
+use biome_parser::SyntaxFeature;
 use biome_parser::TokenSet;
 use biome_parser::parse_lists::ParseNodeList;
 use biome_parser::parse_lists::ParseSeparatedList;
@@ -13,6 +14,7 @@ use biome_parser::prelude::{CompletedMarker, ParsedSyntax};
 use biome_parser::token_set;
 
 use crate::parser::CssParser;
+use crate::syntax::CssSyntaxFeatures;
 use crate::syntax::at_rule::container::error::expected_any_container_style_query;
 use crate::syntax::at_rule::container::parse_any_container_style_query;
 use crate::syntax::at_rule::error::AnyInParensParseRecovery;
@@ -34,7 +36,8 @@ const IF_BRANCH_RECOVERY_TOKEN_SET: TokenSet<CssSyntaxKind> =
     token_set![T![;], T![')'], T!['}'], EOF];
 
 pub(crate) fn is_at_if_function(p: &mut CssParser) -> bool {
-    p.at(T![if])
+    // In SCSS, `if()` is a built-in function with a different syntax
+    p.at(T![if]) && !CssSyntaxFeatures::Scss.is_supported(p)
 }
 
 /// Parses an if function from the current position of the CSS parser.
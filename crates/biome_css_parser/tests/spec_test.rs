@@ -46,6 +46,20 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
         // TODO: find a way to make it configurable
         .allow_metavariables();
 
+    if test_case_path
+        .extension()
+        .is_some_and(|extension| extension == "scss")
+    {
+        options = options.allow_scss().allow_wrong_line_comments();
+    }
+
+    if test_case_path
+        .extension()
+        .is_some_and(|extension| extension == "less")
+    {
+        options = options.allow_less().allow_wrong_line_comments();
+    }
+
     let options_path = Utf8Path::new(test_directory).join("options.json");
 
     if options_path.exists() {
@@ -90,7 +104,11 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let formatted_ast = format!("{:#?}", parsed.tree());
 
     let mut snapshot = String::new();
-    writeln!(snapshot, "\n## Input\n\n```css\n{content}\n```\n\n").unwrap();
+    let language = test_case_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or("css");
+    writeln!(snapshot, "\n## Input\n\n```{language}\n{content}\n```\n\n").unwrap();
 
     writeln!(
         snapshot,
@@ -127,6 +127,8 @@ fn evaluate_any_subselector(selector: &AnyCssSubSelector) -> Specificity {
         AnyCssSubSelector::CssClassSelector(_) => CLASS_SPECIFICITY,
         AnyCssSubSelector::CssAttributeSelector(_) => CLASS_SPECIFICITY,
         AnyCssSubSelector::CssPseudoClassSelector(s) => evaluate_pseudo_selector(s),
+        // Placeholders are replaced by the selectors that extend them, they weigh like a class
+        AnyCssSubSelector::ScssPlaceholderSelector(_) => CLASS_SPECIFICITY,
         AnyCssSubSelector::CssPseudoElementSelector(_) => TYPE_SPECIFICITY,
         AnyCssSubSelector::CssBogusSubSelector(_) => ZERO_SPECIFICITY,
     }
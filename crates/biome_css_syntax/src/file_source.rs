@@ -16,7 +16,8 @@ pub struct CssFileSource {
 /// Currently, Biome aims to be compatible with
 /// the latest Recommendation level standards.
 ///
-/// It also supports Tailwind CSS syntax additions, when the parser option is enabled.
+/// It also supports Tailwind CSS syntax additions, when the parser option is enabled,
+/// and the SCSS and Less syntaxes.
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
@@ -29,6 +30,10 @@ pub enum CssVariant {
     CssModules,
     /// The file belongs to tailwind
     TailwindCss,
+    /// The file is a SCSS file
+    Scss,
+    /// The file is a Less file
+    Less,
 }
 
 impl CssFileSource {
@@ -44,6 +49,18 @@ impl CssFileSource {
         }
     }
 
+    pub fn scss() -> Self {
+        Self {
+            variant: CssVariant::Scss,
+        }
+    }
+
+    pub fn less() -> Self {
+        Self {
+            variant: CssVariant::Less,
+        }
+    }
+
     pub fn is_css_modules(&self) -> bool {
         self.variant == CssVariant::CssModules
     }
@@ -52,6 +69,14 @@ impl CssFileSource {
         self.variant == CssVariant::TailwindCss
     }
 
+    pub fn is_scss(&self) -> bool {
+        self.variant == CssVariant::Scss
+    }
+
+    pub fn is_less(&self) -> bool {
+        self.variant == CssVariant::Less
+    }
+
     pub fn set_variant(&mut self, variant: CssVariant) {
         self.variant = variant;
     }
@@ -67,6 +92,8 @@ impl CssFileSource {
         // We assume the file extension is normalized to lowercase
         match extension {
             "css" => Ok(Self::css()),
+            "scss" => Ok(Self::scss()),
+            "less" => Ok(Self::less()),
             _ => Err(FileSourceError::UnknownExtension),
         }
     }
@@ -81,6 +108,8 @@ impl CssFileSource {
         match language_id {
             "css" => Ok(Self::css()),
             "tailwindcss" => Ok(Self::tailwind_css()),
+            "scss" => Ok(Self::scss()),
+            "less" => Ok(Self::less()),
             _ => Err(FileSourceError::UnknownLanguageId),
         }
     }
@@ -147,7 +147,7 @@ impl Deserializable for f64 {
         name: &str,
     ) -> Option<Self> {
         let value_text = TextNumber::deserialize(ctx, value, name)?;
-        if let Ok(value) = value_text.parse::<Self>() {
+        if let Some(value) = crate::json::parse_json_number(&value_text) {
             return Some(value);
         }
         let diagnostic =
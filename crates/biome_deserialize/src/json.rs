@@ -96,7 +96,7 @@ impl DeserializableValue for AnyJsonValue {
                 let items = array.elements().iter().map(|x| x.ok());
                 visitor.visit_array(ctx, items, range, name)
             }
-            Self::JsonBogusValue(_) => {
+            Self::JsonBogusValue(_) | Self::JsonMetavariable(_) => {
                 // The parser should emit an error about this node
                 // No need to emit another diagnostic.
                 None
@@ -128,7 +128,7 @@ impl DeserializableValue for AnyJsonValue {
     fn visitable_type(&self) -> Option<DeserializableType> {
         match self {
             Self::JsonArrayValue(_) => Some(DeserializableType::Array),
-            Self::JsonBogusValue(_) => None,
+            Self::JsonBogusValue(_) | Self::JsonMetavariable(_) => None,
             Self::JsonBooleanValue(_) => Some(DeserializableType::Bool),
             Self::JsonNullValue(_) => Some(DeserializableType::Null),
             Self::JsonNumberValue(_) => Some(DeserializableType::Number),
@@ -254,6 +254,28 @@ impl DeserializableValue for JsonMemberName {
     }
 }
 
+/// Returns the value of a JSON number literal.
+///
+/// In addition to the JSON syntax, this accepts the number notations of JSON5:
+/// hexadecimal integers, a leading `+` sign, leading or trailing decimal points,
+/// `Infinity` and `NaN`.
+pub fn parse_json_number(text: &str) -> Option<f64> {
+    let (sign, unsigned) = match text.as_bytes().first() {
+        Some(b'-') => (-1.0, &text[1..]),
+        Some(b'+') => (1.0, &text[1..]),
+        _ => (1.0, text),
+    };
+    if let Some(digits) = unsigned
+        .strip_prefix("0x")
+        .or_else(|| unsigned.strip_prefix("0X"))
+    {
+        return u64::from_str_radix(digits, 16)
+            .ok()
+            .map(|value| sign * value as f64);
+    }
+    text.parse().ok()
+}
+
 /// Returns `text` with escape sequences processed.
 ///
 /// If nothing is escaped, `text` is returned without any allocation. If at
@@ -266,7 +288,11 @@ pub fn unescape_json_string(text: TokenText) -> Text {
     enum State {
         Normal,
         Escaped,
-        EscapedUnicode(u8, char),
+        /// `\u` or `\x` escape with the number of remaining hexadecimal digits
+        /// and the code point computed so far.
+        EscapedHex(u8, char),
+        /// `\r` line continuation, which can be followed by `\n`.
+        LineContinuation,
     }
 
     match text.find('\\') {
@@ -283,8 +309,23 @@ pub fn unescape_json_string(text: TokenText) -> Text {
                             'n' => '\n',
                             'r' => '\r',
                             't' => '\t',
+                            // JSON5 escapes
+                            'v' => '\u{000b}',
+                            '0' => '\0',
                             'u' => {
-                                state = State::EscapedUnicode(0, '\0');
+                                state = State::EscapedHex(4, '\0');
+                                continue;
+                            }
+                            'x' => {
+                                state = State::EscapedHex(2, '\0');
+                                continue;
+                            }
+                            '\n' | '\u{2028}' | '\u{2029}' => {
+                                state = State::Normal;
+                                continue;
+                            }
+                            '\r' => {
+                                state = State::LineContinuation;
                                 continue;
                             }
                             c => c,
@@ -292,7 +333,7 @@ pub fn unescape_json_string(text: TokenText) -> Text {
                         string.push(escaped);
                         state = State::Normal;
                     }
-                    State::EscapedUnicode(digit, char) => {
+                    State::EscapedHex(remaining, char) => {
                         let value = if c.is_ascii_digit() {
                             c as u32 - '0' as u32
                         } else if ('a'..='f').contains(&c) {
@@ -312,15 +353,19 @@ pub fn unescape_json_string(text: TokenText) -> Text {
                                 continue;
                             }
                         };
-                        if digit == 3 {
+                        if remaining == 1 {
                             string.push(char);
                             state = State::Normal;
                         } else {
-                            state = State::EscapedUnicode(digit + 1, char);
+                            state = State::EscapedHex(remaining - 1, char);
                         }
                     }
-                    State::Normal if c == '\\' => state = State::Escaped,
-                    State::Normal => string.push(c),
+                    State::LineContinuation if c == '\n' => state = State::Normal,
+                    State::Normal | State::LineContinuation if c == '\\' => state = State::Escaped,
+                    State::Normal | State::LineContinuation => {
+                        string.push(c);
+                        state = State::Normal;
+                    }
                 }
             }
             string.into()
@@ -187,6 +187,7 @@ pub(super) enum DiagnosticTag {
     UnnecessaryCode = 1 << 2,
     DeprecatedCode = 1 << 3,
     Verbose = 1 << 4,
+    Suppressed = 1 << 5,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -204,6 +205,9 @@ impl DiagnosticTags {
     pub const DEPRECATED_CODE: Self = Self(make_bitflags!(DiagnosticTag::{DeprecatedCode}));
     /// This diagnostic is verbose and should be printed only if the `--verbose` option is provided
     pub const VERBOSE: Self = Self(make_bitflags!(DiagnosticTag::{Verbose}));
+    /// This diagnostic was suppressed by a suppression comment. These diagnostics are only
+    /// emitted when explicitly requested, e.g. by reporters that track suppressed results
+    pub const SUPPRESSED: Self = Self(make_bitflags!(DiagnosticTag::{Suppressed}));
     pub const fn all() -> Self {
         Self(BitFlags::ALL)
     }
@@ -228,6 +232,9 @@ impl DiagnosticTags {
     pub fn is_internal(&self) -> bool {
         self.contains(DiagnosticTag::Internal)
     }
+    pub fn is_suppressed(&self) -> bool {
+        self.contains(DiagnosticTag::Suppressed)
+    }
 }
 
 impl BitOr for DiagnosticTags {
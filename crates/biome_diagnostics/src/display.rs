@@ -382,6 +382,10 @@ impl Visit for PrintSearch<'_, '_> {
     fn record_frame(&mut self, location: Location<'_>) -> io::Result<()> {
         frame::print_highlighted_frame(self.0, location)
     }
+
+    fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
+        diff::print_diff(self.0, diff)
+    }
 }
 
 /// Implementation of [Visitor] that prints the advices for a diagnostic.
@@ -295,6 +295,7 @@ impl From<DiagnosticTag> for DiagnosticTags {
             DiagnosticTag::UnnecessaryCode => Self::UNNECESSARY_CODE,
             DiagnosticTag::DeprecatedCode => Self::DEPRECATED_CODE,
             DiagnosticTag::Verbose => Self::VERBOSE,
+            DiagnosticTag::Suppressed => Self::SUPPRESSED,
         }
     }
 }
@@ -323,6 +324,10 @@ impl Serialize for DiagnosticTags {
             flags.push(DiagnosticTag::DeprecatedCode);
         }
 
+        if self.contains(Self::SUPPRESSED) {
+            flags.push(DiagnosticTag::Suppressed);
+        }
+
         serializer.collect_seq(flags)
     }
 }
@@ -163,6 +163,7 @@ define_categories! {
     "lint/correctness/useValidTypeof": "https://biomejs.dev/linter/rules/use-valid-typeof",
     "lint/correctness/useYield": "https://biomejs.dev/linter/rules/use-yield",
     "lint/nursery/noAmbiguousAnchorText": "https://biomejs.dev/linter/rules/no-ambiguous-anchor-text",
+    "lint/nursery/noAmbiguousBooleanValues": "https://biomejs.dev/linter/rules/no-ambiguous-boolean-values",
     "lint/nursery/noBeforeInteractiveScriptOutsideDocument": "https://biomejs.dev/linter/rules/no-before-interactive-script-outside-document",
     "lint/nursery/noColorInvalidHex": "https://biomejs.dev/linter/rules/no-color-invalid-hex",
     "lint/nursery/noContinue": "https://biomejs.dev/linter/rules/no-continue",
@@ -177,6 +178,7 @@ define_categories! {
     "lint/nursery/noDuplicateFieldDefinitionNames": "https://biomejs.dev/linter/rules/no-duplicate-field-definition-names",
     "lint/nursery/noDuplicateGraphqlOperationName": "https://biomejs.dev/linter/rules/no-duplicate-graphql-operation-name",
     "lint/nursery/noDuplicateInputFieldNames": "https://biomejs.dev/linter/rules/no-duplicate-input-field-names",
+    "lint/nursery/noDuplicateMappingKeys": "https://biomejs.dev/linter/rules/no-duplicate-mapping-keys",
     "lint/nursery/noDuplicateVariableNames": "https://biomejs.dev/linter/rules/no-duplicate-variable-names",
     "lint/nursery/noDuplicatedSpreadProps": "https://biomejs.dev/linter/rules/no-duplicated-spread-props",
     "lint/nursery/noEmptySource": "https://biomejs.dev/linter/rules/no-empty-source",
@@ -190,6 +192,7 @@ define_categories! {
     "lint/nursery/noImplicitCoercion": "https://biomejs.dev/linter/rules/no-implicit-coercion",
     "lint/nursery/noImportCycles": "https://biomejs.dev/linter/rules/no-import-cycles",
     "lint/nursery/noIncrementDecrement": "https://biomejs.dev/linter/rules/no-increment-decrement",
+    "lint/nursery/noJsonSchemaViolations": "https://biomejs.dev/linter/rules/no-json-schema-violations",
     "lint/nursery/noJsxLiterals": "https://biomejs.dev/linter/rules/no-jsx-literals",
     "lint/nursery/noJsxPropsBind": "https://biomejs.dev/linter/rules/no-jsx-props-bind",
     "lint/nursery/noLeakedRender": "https://biomejs.dev/linter/rules/no-leaked-render",
@@ -209,6 +212,7 @@ define_categories! {
     "lint/nursery/noShadow": "https://biomejs.dev/linter/rules/no-shadow",
     "lint/nursery/noSyncScripts": "https://biomejs.dev/linter/rules/no-sync-scripts",
     "lint/nursery/noTernary": "https://biomejs.dev/linter/rules/no-ternary",
+    "lint/nursery/noUndeclaredAliases": "https://biomejs.dev/linter/rules/no-undeclared-aliases",
     "lint/nursery/noUndeclaredEnvVars": "https://biomejs.dev/linter/rules/no-undeclared-env-vars",
     "lint/nursery/noUnknownAttribute": "https://biomejs.dev/linter/rules/no-unknown-attribute",
     "lint/nursery/noUnnecessaryConditions": "https://biomejs.dev/linter/rules/no-unnecessary-conditions",
@@ -233,6 +237,7 @@ define_categories! {
     "lint/nursery/useConsistentArrowReturn": "https://biomejs.dev/linter/rules/use-consistent-arrow-return",
     "lint/nursery/useConsistentEnumValueType": "https://biomejs.dev/linter/rules/use-consistent-enum-value-type",
     "lint/nursery/useConsistentGraphqlDescriptions": "https://biomejs.dev/linter/rules/use-consistent-graphql-descriptions",
+    "lint/nursery/useConsistentIndentation": "https://biomejs.dev/linter/rules/use-consistent-indentation",
     "lint/nursery/useConsistentMethodSignatures": "https://biomejs.dev/linter/rules/use-consistent-method-signatures",
     "lint/nursery/useConsistentObjectDefinition": "https://biomejs.dev/linter/rules/use-consistent-object-definition",
     "lint/nursery/useDeprecatedDate": "https://biomejs.dev/linter/rules/use-deprecated-date",
@@ -255,6 +260,7 @@ define_categories! {
     "lint/nursery/useRegexpExec": "https://biomejs.dev/linter/rules/use-regexp-exec",
     "lint/nursery/useRequiredScripts": "https://biomejs.dev/linter/rules/use-required-scripts",
     "lint/nursery/useSortedClasses": "https://biomejs.dev/linter/rules/use-sorted-classes",
+    "lint/nursery/useSortedMappingKeys": "https://biomejs.dev/linter/rules/use-sorted-mapping-keys",
     "lint/nursery/useSpread": "https://biomejs.dev/linter/rules/no-spread",
     "lint/nursery/useVueConsistentDefinePropsDeclaration": "https://biomejs.dev/linter/rules/use-vue-consistent-define-props-declaration",
     "lint/nursery/useVueConsistentVBindStyle": "https://biomejs.dev/linter/rules/use-vue-consistent-v-bind-style",
@@ -486,6 +492,7 @@ define_categories! {
     "init",
     "configuration",
     "assist",
+    "baseline",
     "migrate",
     "deserialize",
     "plugin",
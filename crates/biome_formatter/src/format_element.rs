@@ -339,7 +339,7 @@ impl BestFittingVariants {
         &self.0
     }
 
-    pub(crate) fn as_slice_mut(&mut self) -> &mut [FormatElement] {
+    pub fn as_slice_mut(&mut self) -> &mut [FormatElement] {
         &mut self.0
     }
 
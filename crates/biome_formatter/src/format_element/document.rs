@@ -10,7 +10,7 @@ use crate::{
     TransformSourceMap,
 };
 use crate::{format, write};
-use biome_rowan::TextSize;
+use biome_rowan::{TextRange, TextSize};
 use rustc_hash::FxHashMap;
 use std::ops::Deref;
 
@@ -144,50 +144,74 @@ impl Document {
         &self.elements
     }
 
-    /// Transforms the document by visiting every element, optionally replacing
-    /// them.
+    /// Replaces the content of each embedded region, delimited by [Tag::StartEmbedded]
+    /// and [Tag::EndEmbedded], with the document returned by `format_embedded` for the
+    /// range of the region, followed by a hard line break.
     ///
-    /// Accepts a `visitor` that will be called to visit each element, and which
-    /// may optionally return a replacement.
+    /// When `format_embedded` returns `None`, the region and its content, if any, are kept as they are.
     ///
-    /// Elements that contain nested elements, such as [FormatElement::Interned]
-    /// and [FormatElement::BestFitting], have the visitor called on their
-    /// nested elements, but not on the elements themselves.
-    pub(crate) fn transform(
+    /// Elements nested in [FormatElement::Interned] and [FormatElement::BestFitting] are visited too.
+    ///
+    /// The expanded groups are propagated again, because the embedded documents can contain hard line breaks.
+    pub(crate) fn format_embedded(
         &mut self,
-        mut visitor: impl FnMut(&FormatElement) -> Option<FormatElement>,
+        format_embedded: &mut impl FnMut(TextRange) -> Option<Self>,
     ) {
-        transform_elements(&mut self.elements, &mut visitor);
+        let elements = std::mem::take(&mut self.elements);
+        self.elements = format_embedded_elements(elements, format_embedded);
+        self.propagate_expand();
     }
 }
 
-/// Iterates over each of the given `elements` and optionally replaces each
-/// element with a new one.
-///
-/// Nested data structures such as [FormatElement::Interned] and
-/// [FormatElement::BestFitting] use recursion and call [transform_elements()]
-/// again. The visitor is *not* invoked on these elements.
-fn transform_elements(
-    elements: &mut [FormatElement],
-    visitor: &mut impl FnMut(&FormatElement) -> Option<FormatElement>,
-) {
-    for element in elements {
+fn format_embedded_elements(
+    elements: Vec<FormatElement>,
+    format_embedded: &mut impl FnMut(TextRange) -> Option<Document>,
+) -> Vec<FormatElement> {
+    let mut result = Vec::with_capacity(elements.len());
+    let mut elements = elements.into_iter();
+
+    while let Some(element) = elements.next() {
         match element {
             FormatElement::Interned(interned) => {
-                let mut nested_elements = interned.deref().to_vec();
-                transform_elements(&mut nested_elements, visitor);
-                *element = FormatElement::Interned(Interned::new(nested_elements));
+                let nested = format_embedded_elements(interned.deref().to_vec(), format_embedded);
+                result.push(FormatElement::Interned(Interned::new(nested)));
             }
-            FormatElement::BestFitting(best_fitting) => {
-                transform_elements(best_fitting.as_slice_mut(), visitor);
-            }
-            _ => {
-                if let Some(replacement) = visitor(element) {
-                    *element = replacement;
+            FormatElement::BestFitting(mut best_fitting) => {
+                // The variants can't change their length, only their interned elements are visited
+                for element in best_fitting.as_slice_mut() {
+                    if let FormatElement::Interned(interned) = element {
+                        let nested =
+                            format_embedded_elements(interned.deref().to_vec(), format_embedded);
+                        *element = FormatElement::Interned(Interned::new(nested));
+                    }
                 }
+                result.push(FormatElement::BestFitting(best_fitting));
             }
+            FormatElement::Tag(Tag::StartEmbedded(range)) => match format_embedded(range) {
+                Some(document) => {
+                    // Skip the content of the region, which is only printed when
+                    // the embedded content can't be formatted
+                    let mut depth = 0usize;
+                    for element in elements.by_ref() {
+                        match element {
+                            FormatElement::Tag(Tag::StartEmbedded(_)) => depth += 1,
+                            FormatElement::Tag(Tag::EndEmbedded) if depth == 0 => break,
+                            FormatElement::Tag(Tag::EndEmbedded) => depth -= 1,
+                            _ => {}
+                        }
+                    }
+                    result.push(FormatElement::Interned(Interned::new(
+                        document.into_elements(),
+                    )));
+                    result.push(FormatElement::Line(LineMode::Hard));
+                }
+                None => result.push(element),
+            },
+            element => result.push(element),
         }
     }
+
+    result
 }
 
 impl From<Vec<FormatElement>> for Document {
@@ -43,7 +43,7 @@ pub mod trivia;
 
 use crate::formatter::Formatter;
 use crate::group_id::UniqueGroupIdBuilder;
-use crate::prelude::{Tag, TagKind};
+use crate::prelude::TagKind;
 use std::fmt;
 use std::fmt::{Debug, Display};
 
@@ -51,7 +51,6 @@ use crate::builders::syntax_token_cow_slice;
 use crate::comments::{CommentStyle, Comments, SourceComment};
 pub use crate::diagnostics::{ActualStart, FormatError, InvalidDocumentError, PrintError};
 use crate::format_element::document::Document;
-use crate::format_element::{Interned, LineMode};
 #[cfg(debug_assertions)]
 use crate::printed_tokens::PrintedTokens;
 use crate::printer::{Printer, PrinterOptions};
@@ -972,22 +971,13 @@ impl<Context> Formatted<Context> {
         &self.context
     }
 
-    /// Visits each embedded element and replaces it with elements contained inside the [Document]
-    /// emitted by `fn_format_embedded`
+    /// Replaces each embedded region of the document with the [Document] emitted by `fn_format_embedded`
+    /// for the range of the region. The region is kept as is if `fn_format_embedded` returns `None`.
     pub fn format_embedded<F>(&mut self, mut fn_format_embedded: F)
     where
         F: FnMut(TextRange) -> Option<Document>,
     {
-        self.document.transform(move |element| match element {
-            FormatElement::Tag(Tag::StartEmbedded(range)) => fn_format_embedded(*range)
-                .map(|document| FormatElement::Interned(Interned::new(document.into_elements()))),
-            FormatElement::Tag(Tag::EndEmbedded) => {
-                // FIXME: this might not play well for all cases, so we need to figure out
-                // a nicer way to replace the tag
-                Some(FormatElement::Line(LineMode::Hard))
-            }
-            _ => None,
-        });
+        self.document.format_embedded(&mut fn_format_embedded);
     }
 
     /// Returns the formatted document.
@@ -1522,6 +1512,15 @@ pub trait FormatLanguage {
         true
     }
 
+    /// Returns whether the tokens with an empty range are excluded from the debug assertions
+    /// that check that every token is formatted exactly once.
+    ///
+    /// Empty tokens, such as the tokens that delimit the indented blocks of YAML, start at the
+    /// same offset as the next token, so they can't be told apart when they're tracked.
+    fn skip_tracking_empty_tokens(&self) -> bool {
+        false
+    }
+
     /// Returns the formatting options
     fn options(&self) -> &<Self::Context as FormatContext>::Options;
 
@@ -1589,10 +1588,12 @@ pub fn format_node<L: FormatLanguage>(
         None => (root.clone(), None),
     };
 
+    let skip_tracking_empty_tokens = language.skip_tracking_empty_tokens();
     let context = language.create_context(&root, source_map, delegate_fmt_embedded_nodes);
     let format_node = FormatRefWithRule::new(&root, L::FormatRule::default());
 
     let mut state = FormatState::new(context);
+    state.set_empty_token_tracking_skipped(skip_tracking_empty_tokens);
     let mut buffer = VecBuffer::new(&mut state);
 
     write!(buffer, [format_node])?;
@@ -1664,10 +1665,12 @@ pub fn format_node_with_offset<L: FormatLanguage>(
         None => (root.node.clone(), None),
     };
 
+    let skip_tracking_empty_tokens = language.skip_tracking_empty_tokens();
     let context = language.create_context(&root, source_map, delegate_fmt_embedded_nodes);
     let format_node = FormatRefWithRule::new(&root, L::FormatRule::default());
 
     let mut state = FormatState::new(context);
+    state.set_empty_token_tracking_skipped(skip_tracking_empty_tokens);
     let mut buffer = VecBuffer::new(&mut state);
 
     write!(buffer, [format_node])?;
@@ -2181,6 +2184,16 @@ impl<Context> FormatState<Context> {
         self.printed_tokens.is_disabled()
     }
 
+    #[cfg(not(debug_assertions))]
+    #[inline]
+    pub fn set_empty_token_tracking_skipped(&mut self, _: bool) {}
+
+    /// Skips or tracks the tokens with an empty range, see [FormatLanguage::skip_tracking_empty_tokens].
+    #[cfg(debug_assertions)]
+    pub fn set_empty_token_tracking_skipped(&mut self, skipped: bool) {
+        self.printed_tokens.set_skip_empty_tokens(skipped)
+    }
+
     #[cfg(not(debug_assertions))]
     #[inline]
     pub fn assert_formatted_all_tokens<L: Language>(&self, _root: &SyntaxNode<L>) {}
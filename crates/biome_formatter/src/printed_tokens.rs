@@ -10,6 +10,8 @@ pub struct PrintedTokens {
     /// Key: Start of a token's range
     offsets: IndexSet<TextSize>,
     disabled: bool,
+    /// Whether empty tokens are ignored, see [crate::FormatLanguage::skip_tracking_empty_tokens]
+    skip_empty_tokens: bool,
 }
 
 #[derive(Copy, Clone)]
@@ -30,6 +32,10 @@ impl PrintedTokens {
 
         let range = token.text_trimmed_range();
 
+        if self.skip_empty_tokens && range.is_empty() {
+            return;
+        }
+
         if !self.offsets.insert(range.start()) {
             panic!(
                 "You tried to print the token '{token:?}' twice, and this is not valid.\
@@ -47,6 +53,11 @@ impl PrintedTokens {
         self.disabled
     }
 
+    /// Ignores or tracks the tokens with an empty range
+    pub(crate) fn set_skip_empty_tokens(&mut self, skip_empty_tokens: bool) {
+        self.skip_empty_tokens = skip_empty_tokens;
+    }
+
     pub(crate) fn snapshot(&self) -> PrintedTokensSnapshot {
         PrintedTokensSnapshot {
             len: self.offsets.len(),
@@ -69,6 +80,10 @@ impl PrintedTokens {
         let mut offsets = self.offsets.clone();
 
         for token in root.descendants_tokens(Direction::Next) {
+            if self.skip_empty_tokens && token.text_trimmed_range().is_empty() {
+                continue;
+            }
+
             if !offsets.shift_remove(&token.text_trimmed_range().start()) {
                 panic!(
                     "token has not been seen by the formatter: {token:#?}.\
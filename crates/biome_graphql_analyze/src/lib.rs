@@ -7,9 +7,9 @@ mod suppression_action;
 pub use crate::registry::visit_registry;
 use crate::suppression_action::GraphqlSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression, ControlFlow,
-    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    to_analyzer_suppressions,
+    AnalysisFilter, AnalyzerOptions, AnalyzerPluginSlice, AnalyzerSignal, AnalyzerSuppression,
+    ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry, Phases, PluginTargetLanguage,
+    PluginVisitor, RuleAction, RuleRegistry, to_analyzer_suppressions,
 };
 use biome_deserialize::TextRange;
 use biome_diagnostics::Error;
@@ -33,13 +33,14 @@ pub fn analyze<'a, F, B>(
     root: &LanguageRoot<GraphqlLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    plugins: AnalyzerPluginSlice<'a>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<GraphqlLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(root, filter, |_| {}, options, plugins, emit_signal)
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -53,6 +54,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    plugins: AnalyzerPluginSlice<'a>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -108,6 +110,18 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    for plugin in plugins {
+        // SAFETY: The plugin target language is correctly checked here.
+        unsafe {
+            if plugin.language() == PluginTargetLanguage::Graphql {
+                analyzer.add_visitor(
+                    Phases::Syntax,
+                    Box::new(PluginVisitor::new_unchecked(plugin.clone())),
+                )
+            }
+        }
+    }
+
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -157,6 +171,7 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            &[],
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -12,6 +12,7 @@ impl FormatRule<AnyGraphqlSelection> for FormatAnyGraphqlSelection {
             AnyGraphqlSelection::GraphqlField(node) => node.format().fmt(f),
             AnyGraphqlSelection::GraphqlFragmentSpread(node) => node.format().fmt(f),
             AnyGraphqlSelection::GraphqlInlineFragment(node) => node.format().fmt(f),
+            AnyGraphqlSelection::GraphqlMetavariable(node) => node.format().fmt(f),
         }
     }
 }
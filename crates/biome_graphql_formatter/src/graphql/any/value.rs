@@ -14,6 +14,7 @@ impl FormatRule<AnyGraphqlValue> for FormatAnyGraphqlValue {
             AnyGraphqlValue::GraphqlFloatValue(node) => node.format().fmt(f),
             AnyGraphqlValue::GraphqlIntValue(node) => node.format().fmt(f),
             AnyGraphqlValue::GraphqlListValue(node) => node.format().fmt(f),
+            AnyGraphqlValue::GraphqlMetavariable(node) => node.format().fmt(f),
             AnyGraphqlValue::GraphqlNullValue(node) => node.format().fmt(f),
             AnyGraphqlValue::GraphqlObjectValue(node) => node.format().fmt(f),
             AnyGraphqlValue::GraphqlStringValue(node) => node.format().fmt(f),
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{GraphqlMetavariable, GraphqlMetavariableFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlMetavariable;
+impl FormatNodeRule<GraphqlMetavariable> for FormatGraphqlMetavariable {
+    fn fmt_fields(&self, node: &GraphqlMetavariable, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let GraphqlMetavariableFields { value_token } = node.as_fields();
+        write!(f, [value_token.format()])
+    }
+}
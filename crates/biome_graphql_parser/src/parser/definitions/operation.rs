@@ -2,12 +2,12 @@ use crate::parser::{
     GraphqlParser,
     argument::parse_arguments,
     directive::{DirectiveList, is_at_directive},
-    is_nth_at_name, parse_binding,
+    is_at_metavariable, is_nth_at_name, parse_binding,
     parse_error::{
         expected_any_selection, expected_name, expected_type, expected_variable,
         expected_variable_definition,
     },
-    parse_literal_name, parse_reference,
+    parse_literal_name, parse_metavariable, parse_reference,
     r#type::parse_type,
     value::parse_default_value,
     variable::{is_at_variable, parse_variable_binding},
@@ -138,7 +138,9 @@ pub(crate) fn parse_selection_set(p: &mut GraphqlParser) -> ParsedSyntax {
 
 #[inline]
 fn parse_selection(p: &mut GraphqlParser) -> ParsedSyntax {
-    if is_at_field(p) {
+    if is_at_metavariable(p) {
+        parse_metavariable(p)
+    } else if is_at_field(p) {
         parse_field(p)
     } else if is_at_fragment(p) {
         parse_fragment(p)
@@ -283,7 +285,7 @@ fn is_at_selection_set_end(p: &mut GraphqlParser) -> bool {
 
 #[inline]
 fn is_at_selection(p: &mut GraphqlParser) -> bool {
-    is_at_field(p) || is_at_fragment(p)
+    is_at_field(p) || is_at_fragment(p) || is_at_metavariable(p)
 }
 
 #[inline]
@@ -61,16 +61,32 @@ const GRAPHQL_POTENTIAL_NAME_SET: TokenSet<GraphqlSyntaxKind> = token_set![
     INPUT_FIELD_DEFINITION_KW,
 ];
 
+#[derive(Default, Debug, Clone, Copy)]
+pub struct GraphqlParserOptions {
+    /// Enables parsing of Grit metavariables.
+    /// Defaults to `false`.
+    pub grit_metavariables: bool,
+}
+
+impl GraphqlParserOptions {
+    /// Enables parsing of Grit metavariables.
+    #[must_use]
+    pub fn with_metavariables(mut self) -> Self {
+        self.grit_metavariables = true;
+        self
+    }
+}
+
 pub(crate) struct GraphqlParser<'source> {
     context: ParserContext<GraphqlSyntaxKind>,
     source: GraphqlTokenSource<'source>,
 }
 
 impl<'source> GraphqlParser<'source> {
-    pub fn new(source: &'source str) -> Self {
+    pub fn new(source: &'source str, options: GraphqlParserOptions) -> Self {
         Self {
             context: ParserContext::default(),
-            source: GraphqlTokenSource::from_str(source),
+            source: GraphqlTokenSource::from_str(source, options),
         }
     }
 
@@ -156,6 +172,22 @@ fn parse_reference(p: &mut GraphqlParser) -> ParsedSyntax {
     Present(m.complete(p, GRAPHQL_NAME_REFERENCE))
 }
 
+#[inline]
+fn is_at_metavariable(p: &GraphqlParser) -> bool {
+    p.at(GRIT_METAVARIABLE)
+}
+
+#[inline]
+fn parse_metavariable(p: &mut GraphqlParser) -> ParsedSyntax {
+    if !is_at_metavariable(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(GRIT_METAVARIABLE);
+    Present(m.complete(p, GRAPHQL_METAVARIABLE))
+}
+
 #[inline]
 fn parse_description(p: &mut GraphqlParser) -> ParsedSyntax {
     if !is_at_string(p) {
@@ -1,3 +1,4 @@
+use crate::GraphqlParserOptions;
 use crate::lexer::GraphqlLexer;
 use biome_graphql_syntax::GraphqlSyntaxKind::EOF;
 use biome_graphql_syntax::{GraphqlSyntaxKind, TextRange};
@@ -19,8 +20,8 @@ impl<'source> GraphqlTokenSource<'source> {
             trivia_list: Vec::new(),
         }
     }
-    pub fn from_str(source: &'source str) -> Self {
-        let lexer = GraphqlLexer::from_str(source);
+    pub fn from_str(source: &'source str, options: GraphqlParserOptions) -> Self {
+        let lexer = GraphqlLexer::from_str(source).with_options(options);
         let lexer = BufferedLexer::new(lexer);
 
         let mut source = GraphqlTokenSource::new(lexer);
@@ -161,6 +161,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::GraphqlLiteralName::new_unchecked(node) };
                     $body
                 }
+                $crate::GraphqlSyntaxKind::GRAPHQL_METAVARIABLE => {
+                    let $pattern = unsafe { $crate::GraphqlMetavariable::new_unchecked(node) };
+                    $body
+                }
                 $crate::GraphqlSyntaxKind::GRAPHQL_NAME_BINDING => {
                     let $pattern = unsafe { $crate::GraphqlNameBinding::new_unchecked(node) };
                     $body
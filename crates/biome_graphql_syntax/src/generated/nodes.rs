@@ -1581,6 +1581,41 @@ pub struct GraphqlLiteralNameFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GraphqlMetavariable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl GraphqlMetavariable {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> GraphqlMetavariableFields {
+        GraphqlMetavariableFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for GraphqlMetavariable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct GraphqlMetavariableFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct GraphqlNameBinding {
     pub(crate) syntax: SyntaxNode,
 }
@@ -2927,6 +2962,7 @@ pub enum AnyGraphqlSelection {
     GraphqlField(GraphqlField),
     GraphqlFragmentSpread(GraphqlFragmentSpread),
     GraphqlInlineFragment(GraphqlInlineFragment),
+    GraphqlMetavariable(GraphqlMetavariable),
 }
 impl AnyGraphqlSelection {
     pub fn as_graphql_bogus_selection(&self) -> Option<&GraphqlBogusSelection> {
@@ -2953,6 +2989,12 @@ impl AnyGraphqlSelection {
             _ => None,
         }
     }
+    pub fn as_graphql_metavariable(&self) -> Option<&GraphqlMetavariable> {
+        match &self {
+            Self::GraphqlMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyGraphqlType {
@@ -3086,6 +3128,7 @@ pub enum AnyGraphqlValue {
     GraphqlFloatValue(GraphqlFloatValue),
     GraphqlIntValue(GraphqlIntValue),
     GraphqlListValue(GraphqlListValue),
+    GraphqlMetavariable(GraphqlMetavariable),
     GraphqlNullValue(GraphqlNullValue),
     GraphqlObjectValue(GraphqlObjectValue),
     GraphqlStringValue(GraphqlStringValue),
@@ -3128,6 +3171,12 @@ impl AnyGraphqlValue {
             _ => None,
         }
     }
+    pub fn as_graphql_metavariable(&self) -> Option<&GraphqlMetavariable> {
+        match &self {
+            Self::GraphqlMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_graphql_null_value(&self) -> Option<&GraphqlNullValue> {
         match &self {
             Self::GraphqlNullValue(item) => Some(item),
@@ -4968,6 +5017,56 @@ impl From<GraphqlLiteralName> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for GraphqlMetavariable {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(GRAPHQL_METAVARIABLE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == GRAPHQL_METAVARIABLE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for GraphqlMetavariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("GraphqlMetavariable")
+                .field(
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("GraphqlMetavariable").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<GraphqlMetavariable> for SyntaxNode {
+    fn from(n: GraphqlMetavariable) -> Self {
+        n.syntax
+    }
+}
+impl From<GraphqlMetavariable> for SyntaxElement {
+    fn from(n: GraphqlMetavariable) -> Self {
+        n.syntax.into()
+    }
+}
 impl AstNode for GraphqlNameBinding {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -6723,12 +6822,18 @@ impl From<GraphqlInlineFragment> for AnyGraphqlSelection {
         Self::GraphqlInlineFragment(node)
     }
 }
+impl From<GraphqlMetavariable> for AnyGraphqlSelection {
+    fn from(node: GraphqlMetavariable) -> Self {
+        Self::GraphqlMetavariable(node)
+    }
+}
 impl AstNode for AnyGraphqlSelection {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = GraphqlBogusSelection::KIND_SET
         .union(GraphqlField::KIND_SET)
         .union(GraphqlFragmentSpread::KIND_SET)
-        .union(GraphqlInlineFragment::KIND_SET);
+        .union(GraphqlInlineFragment::KIND_SET)
+        .union(GraphqlMetavariable::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
@@ -6736,6 +6841,7 @@ impl AstNode for AnyGraphqlSelection {
                 | GRAPHQL_FIELD
                 | GRAPHQL_FRAGMENT_SPREAD
                 | GRAPHQL_INLINE_FRAGMENT
+                | GRAPHQL_METAVARIABLE
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -6750,6 +6856,7 @@ impl AstNode for AnyGraphqlSelection {
             GRAPHQL_INLINE_FRAGMENT => {
                 Self::GraphqlInlineFragment(GraphqlInlineFragment { syntax })
             }
+            GRAPHQL_METAVARIABLE => Self::GraphqlMetavariable(GraphqlMetavariable { syntax }),
             _ => return None,
         };
         Some(res)
@@ -6760,6 +6867,7 @@ impl AstNode for AnyGraphqlSelection {
             Self::GraphqlField(it) => it.syntax(),
             Self::GraphqlFragmentSpread(it) => it.syntax(),
             Self::GraphqlInlineFragment(it) => it.syntax(),
+            Self::GraphqlMetavariable(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
@@ -6768,6 +6876,7 @@ impl AstNode for AnyGraphqlSelection {
             Self::GraphqlField(it) => it.into_syntax(),
             Self::GraphqlFragmentSpread(it) => it.into_syntax(),
             Self::GraphqlInlineFragment(it) => it.into_syntax(),
+            Self::GraphqlMetavariable(it) => it.into_syntax(),
         }
     }
 }
@@ -6778,6 +6887,7 @@ impl std::fmt::Debug for AnyGraphqlSelection {
             Self::GraphqlField(it) => std::fmt::Debug::fmt(it, f),
             Self::GraphqlFragmentSpread(it) => std::fmt::Debug::fmt(it, f),
             Self::GraphqlInlineFragment(it) => std::fmt::Debug::fmt(it, f),
+            Self::GraphqlMetavariable(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -6788,6 +6898,7 @@ impl From<AnyGraphqlSelection> for SyntaxNode {
             AnyGraphqlSelection::GraphqlField(it) => it.into_syntax(),
             AnyGraphqlSelection::GraphqlFragmentSpread(it) => it.into_syntax(),
             AnyGraphqlSelection::GraphqlInlineFragment(it) => it.into_syntax(),
+            AnyGraphqlSelection::GraphqlMetavariable(it) => it.into_syntax(),
         }
     }
 }
@@ -7149,6 +7260,11 @@ impl From<GraphqlListValue> for AnyGraphqlValue {
         Self::GraphqlListValue(node)
     }
 }
+impl From<GraphqlMetavariable> for AnyGraphqlValue {
+    fn from(node: GraphqlMetavariable) -> Self {
+        Self::GraphqlMetavariable(node)
+    }
+}
 impl From<GraphqlNullValue> for AnyGraphqlValue {
     fn from(node: GraphqlNullValue) -> Self {
         Self::GraphqlNullValue(node)
@@ -7177,6 +7293,7 @@ impl AstNode for AnyGraphqlValue {
         .union(GraphqlFloatValue::KIND_SET)
         .union(GraphqlIntValue::KIND_SET)
         .union(GraphqlListValue::KIND_SET)
+        .union(GraphqlMetavariable::KIND_SET)
         .union(GraphqlNullValue::KIND_SET)
         .union(GraphqlObjectValue::KIND_SET)
         .union(GraphqlStringValue::KIND_SET)
@@ -7190,6 +7307,7 @@ impl AstNode for AnyGraphqlValue {
                 | GRAPHQL_FLOAT_VALUE
                 | GRAPHQL_INT_VALUE
                 | GRAPHQL_LIST_VALUE
+                | GRAPHQL_METAVARIABLE
                 | GRAPHQL_NULL_VALUE
                 | GRAPHQL_OBJECT_VALUE
                 | GRAPHQL_STRING_VALUE
@@ -7204,6 +7322,7 @@ impl AstNode for AnyGraphqlValue {
             GRAPHQL_FLOAT_VALUE => Self::GraphqlFloatValue(GraphqlFloatValue { syntax }),
             GRAPHQL_INT_VALUE => Self::GraphqlIntValue(GraphqlIntValue { syntax }),
             GRAPHQL_LIST_VALUE => Self::GraphqlListValue(GraphqlListValue { syntax }),
+            GRAPHQL_METAVARIABLE => Self::GraphqlMetavariable(GraphqlMetavariable { syntax }),
             GRAPHQL_NULL_VALUE => Self::GraphqlNullValue(GraphqlNullValue { syntax }),
             GRAPHQL_OBJECT_VALUE => Self::GraphqlObjectValue(GraphqlObjectValue { syntax }),
             GRAPHQL_STRING_VALUE => Self::GraphqlStringValue(GraphqlStringValue { syntax }),
@@ -7222,6 +7341,7 @@ impl AstNode for AnyGraphqlValue {
             Self::GraphqlFloatValue(it) => it.syntax(),
             Self::GraphqlIntValue(it) => it.syntax(),
             Self::GraphqlListValue(it) => it.syntax(),
+            Self::GraphqlMetavariable(it) => it.syntax(),
             Self::GraphqlNullValue(it) => it.syntax(),
             Self::GraphqlObjectValue(it) => it.syntax(),
             Self::GraphqlStringValue(it) => it.syntax(),
@@ -7236,6 +7356,7 @@ impl AstNode for AnyGraphqlValue {
             Self::GraphqlFloatValue(it) => it.into_syntax(),
             Self::GraphqlIntValue(it) => it.into_syntax(),
             Self::GraphqlListValue(it) => it.into_syntax(),
+            Self::GraphqlMetavariable(it) => it.into_syntax(),
             Self::GraphqlNullValue(it) => it.into_syntax(),
             Self::GraphqlObjectValue(it) => it.into_syntax(),
             Self::GraphqlStringValue(it) => it.into_syntax(),
@@ -7252,6 +7373,7 @@ impl std::fmt::Debug for AnyGraphqlValue {
             Self::GraphqlFloatValue(it) => std::fmt::Debug::fmt(it, f),
             Self::GraphqlIntValue(it) => std::fmt::Debug::fmt(it, f),
             Self::GraphqlListValue(it) => std::fmt::Debug::fmt(it, f),
+            Self::GraphqlMetavariable(it) => std::fmt::Debug::fmt(it, f),
             Self::GraphqlNullValue(it) => std::fmt::Debug::fmt(it, f),
             Self::GraphqlObjectValue(it) => std::fmt::Debug::fmt(it, f),
             Self::GraphqlStringValue(it) => std::fmt::Debug::fmt(it, f),
@@ -7268,6 +7390,7 @@ impl From<AnyGraphqlValue> for SyntaxNode {
             AnyGraphqlValue::GraphqlFloatValue(it) => it.into_syntax(),
             AnyGraphqlValue::GraphqlIntValue(it) => it.into_syntax(),
             AnyGraphqlValue::GraphqlListValue(it) => it.into_syntax(),
+            AnyGraphqlValue::GraphqlMetavariable(it) => it.into_syntax(),
             AnyGraphqlValue::GraphqlNullValue(it) => it.into_syntax(),
             AnyGraphqlValue::GraphqlObjectValue(it) => it.into_syntax(),
             AnyGraphqlValue::GraphqlStringValue(it) => it.into_syntax(),
@@ -7486,6 +7609,11 @@ impl std::fmt::Display for GraphqlLiteralName {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for GraphqlMetavariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for GraphqlNameBinding {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
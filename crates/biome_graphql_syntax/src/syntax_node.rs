@@ -23,3 +23,4 @@ pub type GraphqlSyntaxToken = biome_rowan::SyntaxToken<GraphqlLanguage>;
 pub type GraphqlSyntaxElement = biome_rowan::SyntaxElement<GraphqlLanguage>;
 pub type GraphqlSyntaxElementChildren = biome_rowan::SyntaxElementChildren<GraphqlLanguage>;
 pub type GraphqlSyntaxList = biome_rowan::SyntaxList<GraphqlLanguage>;
+pub type GraphqlSyntaxNodeWithOffset = biome_rowan::SyntaxNodeWithOffset<GraphqlLanguage>;
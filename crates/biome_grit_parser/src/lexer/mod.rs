@@ -795,6 +795,7 @@ impl<'src> GritLexer<'src> {
                     b"css" => T![css],
                     b"grit" => T![grit],
                     b"html" => T![html],
+                    b"graphql" => T![graphql],
                     _ => {
                         self.diagnostics.push(
                             ParseDiagnostic::new(
@@ -847,6 +848,7 @@ impl<'src> GritLexer<'src> {
             b"json" => JSON_KW,
             b"grit" => GRIT_KW,
             b"html" => HTML_KW,
+            b"graphql" => GRAPHQL_KW,
             b"typescript" => TYPESCRIPT_KW,
             b"jsx" => JSX_KW,
             b"as" => AS_KW,
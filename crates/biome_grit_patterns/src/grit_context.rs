@@ -3,23 +3,29 @@ use crate::grit_built_in_functions::BuiltIns;
 use crate::grit_code_snippet::GritCodeSnippet;
 use crate::grit_file::GritFile;
 use crate::grit_node_patterns::{GritLeafNodePattern, GritNodePattern};
+use crate::grit_query::GritQueryDiagnostic;
 use crate::grit_resolved_pattern::GritResolvedPattern;
 use crate::grit_target_language::GritTargetLanguage;
 use crate::grit_target_node::GritTargetNode;
 use crate::grit_tree::GritTargetTree;
-use biome_analyze::RuleDiagnostic;
+use biome_analyze::{FixKind, RuleDiagnostic};
 use biome_parser::AnyParse;
 use camino::Utf8PathBuf;
+use grit_pattern_matcher::binding::Binding;
 use grit_pattern_matcher::constants::{GLOBAL_VARS_SCOPE_INDEX, NEW_FILES_INDEX};
 use grit_pattern_matcher::context::{ExecContext, QueryContext};
+use grit_pattern_matcher::effects::Effect;
 use grit_pattern_matcher::file_owners::{FileOwner, FileOwners};
 use grit_pattern_matcher::pattern::{
-    CallBuiltIn, File, FilePtr, GritFunctionDefinition, Matcher, Pattern, PatternDefinition,
-    PredicateDefinition, ResolvedPattern, State,
+    CallBuiltIn, File, FilePtr, FileRegistry, GritFunctionDefinition, Matcher, Pattern,
+    PatternDefinition, PredicateDefinition, ResolvedPattern, State,
 };
 use grit_util::error::GritPatternError;
-use grit_util::{AnalysisLogs, FileOrigin, InputRanges, MatchRanges, error::GritResult};
+use grit_util::{
+    AnalysisLogs, Ast, EffectKind, FileOrigin, InputRanges, MatchRanges, error::GritResult,
+};
 use path_absolutize::Absolutize;
+use std::cmp::Reverse;
 use std::collections::BTreeSet;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
@@ -54,11 +60,11 @@ pub struct GritExecContext<'a> {
     pub patterns: &'a [PatternDefinition<GritQueryContext>],
     pub predicates: &'a [PredicateDefinition<GritQueryContext>],
 
-    pub diagnostics: Mutex<Vec<RuleDiagnostic>>,
+    pub diagnostics: Mutex<Vec<GritQueryDiagnostic>>,
 }
 
 impl GritExecContext<'_> {
-    pub fn add_diagnostic(&self, diagnostic: RuleDiagnostic) {
+    pub fn add_diagnostic(&self, diagnostic: RuleDiagnostic, fix_kind: FixKind) {
         let mut diagnostics = self.diagnostics.lock().unwrap();
         // Make sure we don't add multiple messages for the same span.
         // I think this happens when a node and its child(ren) both match the
@@ -67,13 +73,16 @@ impl GritExecContext<'_> {
         // Grit pattern matcher.
         if diagnostics
             .last()
-            .is_none_or(|last| last.span() != diagnostic.span())
+            .is_none_or(|last| last.diagnostic.span() != diagnostic.span())
         {
-            diagnostics.push(diagnostic);
+            diagnostics.push(GritQueryDiagnostic {
+                diagnostic,
+                fix_kind,
+            });
         }
     }
 
-    pub fn into_diagnostics(self) -> Vec<RuleDiagnostic> {
+    pub fn into_diagnostics(self) -> Vec<GritQueryDiagnostic> {
         self.diagnostics.into_inner().unwrap()
     }
 }
@@ -176,15 +185,37 @@ impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
             variables,
             suppressed,
         };
+        // The effects aren't tracked per file, which is fine as long as we
+        // only do single-file queries.
+        let effects = std::mem::take(&mut state.effects);
         for file_ptr in files {
             let file = state.files.get_file_owner(file_ptr);
-            let mut match_log = file.matches.borrow_mut();
+            {
+                let mut match_log = file.matches.borrow_mut();
+                if match_log.input_matches.is_none() {
+                    match_log.input_matches = Some(input_ranges.clone());
+                }
+            }
 
-            if match_log.input_matches.is_none() {
-                match_log.input_matches = Some(input_ranges.clone());
+            let source = file.tree.source();
+            let Some(rewritten) = apply_effects(&source, &effects, &state.files, &self.lang)?
+            else {
+                continue;
+            };
+            if rewritten == source {
+                continue;
             }
 
-            // TODO: Implement effect application
+            let name = file.name.clone();
+            let Some(mut owned_file) = new_file_owner(name, &rewritten, &self.lang, logs)? else {
+                continue;
+            };
+            owned_file.new = false;
+            self.files().push(owned_file);
+            // SAFETY: We just pushed to the list of files, so there must be one.
+            state
+                .files
+                .push_revision(&file_ptr, self.files().last().unwrap());
         }
 
         let new_files_binding = &mut state.bindings[GLOBAL_VARS_SCOPE_INDEX as usize]
@@ -339,6 +370,52 @@ fn new_file_owner(
     }))
 }
 
+/// Applies the rewrites and insertions of the `effects` to the `source` of a
+/// file. Returns [None] if there are no effects to apply.
+///
+/// Replacements are inserted as they're resolved, so a rewrite nested in
+/// another one is discarded in favor of the outer one.
+fn apply_effects<'a>(
+    source: &str,
+    effects: &[Effect<'a, GritQueryContext>],
+    files: &FileRegistry<'a, GritQueryContext>,
+    language: &GritTargetLanguage,
+) -> GritResult<Option<String>> {
+    let mut edits = Vec::with_capacity(effects.len());
+    for effect in effects {
+        let Some(range) = effect.binding.range(language) else {
+            continue;
+        };
+        let range = match effect.kind {
+            EffectKind::Rewrite => range.start..range.end,
+            EffectKind::Insert => range.end..range.end,
+        };
+        edits.push((range, effect.pattern.text(files, language)?));
+    }
+
+    if edits.is_empty() {
+        return Ok(None);
+    }
+
+    // Outer ranges come first, so the nested ones can be skipped.
+    edits.sort_by_key(|(range, _)| (range.start, Reverse(range.end)));
+
+    let mut rewritten = String::with_capacity(source.len());
+    let mut offset = 0;
+    for (range, text) in edits {
+        if range.start < offset || range.end > source.len() {
+            continue;
+        }
+
+        rewritten.push_str(&source[offset..range.start]);
+        rewritten.push_str(&text);
+        offset = range.end;
+    }
+    rewritten.push_str(&source[offset..]);
+
+    Ok(Some(rewritten))
+}
+
 /// Simple wrapper for target files so that we can avoid doing file I/O inside
 /// the Grit engine.
 ///
@@ -13,7 +13,7 @@ use crate::pattern_compiler::{
 };
 use crate::variables::{VarRegistry, VariableLocations};
 use crate::{BuiltInFunction, CompileError};
-use biome_analyze::RuleDiagnostic;
+use biome_analyze::{FixKind, RuleDiagnostic};
 use biome_grit_syntax::{GritRoot, GritRootExt};
 use camino::Utf8Path;
 use grit_pattern_matcher::constants::{
@@ -199,10 +199,19 @@ impl GritQuery {
 #[derive(Debug)]
 pub struct GritQueryResult {
     pub effects: Vec<GritQueryEffect>,
-    pub diagnostics: Vec<RuleDiagnostic>,
+    pub diagnostics: Vec<GritQueryDiagnostic>,
     pub logs: AnalysisLogs,
 }
 
+/// A diagnostic registered while executing a query.
+#[derive(Debug)]
+pub struct GritQueryDiagnostic {
+    pub diagnostic: RuleDiagnostic,
+
+    /// The kind of fix offered by the rewrites of the query, if any.
+    pub fix_kind: FixKind,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum GritQueryEffect {
     Match(Match),
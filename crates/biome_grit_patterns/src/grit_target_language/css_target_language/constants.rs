@@ -5,5 +5,15 @@ use biome_css_syntax::CssSyntaxKind::{self, *};
 
 pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[(CssSyntaxKind, u32, DisregardedSlotCondition)] = &[
     (CSS_DECLARATION_WITH_SEMICOLON, 1, Always),
+    (LESS_DECLARATION, 4, Always),
+    (LESS_MIXIN_CALL, 3, Always),
+    (SCSS_CONTENT_AT_RULE, 2, Always),
+    (SCSS_DEBUG_AT_RULE, 2, Always),
+    (SCSS_DECLARATION, 4, Always),
+    (SCSS_ERROR_AT_RULE, 2, Always),
+    (SCSS_EXTEND_AT_RULE, 3, Always),
+    (SCSS_INCLUDE_AT_RULE, 4, Always),
+    (SCSS_RETURN_AT_RULE, 2, Always),
+    (SCSS_WARN_AT_RULE, 2, Always),
     (TW_PLUGIN_AT_RULE, 3, Always),
 ];
@@ -246,6 +246,65 @@ pub fn kind_by_name(node_name: &str) -> Option<CssSyntaxKind> {
         "CssViewTransitionAtRuleDeclarator" => lang::CssViewTransitionAtRuleDeclarator::KIND_SET
             .iter()
             .next(),
+        "LessDeclaration" => lang::LessDeclaration::KIND_SET.iter().next(),
+        "LessEscapedValue" => lang::LessEscapedValue::KIND_SET.iter().next(),
+        "LessExpression" => lang::LessExpression::KIND_SET.iter().next(),
+        "LessIdentifier" => lang::LessIdentifier::KIND_SET.iter().next(),
+        "LessKeywordArgument" => lang::LessKeywordArgument::KIND_SET.iter().next(),
+        "LessMixinArguments" => lang::LessMixinArguments::KIND_SET.iter().next(),
+        "LessMixinCall" => lang::LessMixinCall::KIND_SET.iter().next(),
+        "LessMixinDeclaration" => lang::LessMixinDeclaration::KIND_SET.iter().next(),
+        "LessMixinGuard" => lang::LessMixinGuard::KIND_SET.iter().next(),
+        "LessMixinParameter" => lang::LessMixinParameter::KIND_SET.iter().next(),
+        "LessMixinParameterDefaultValue" => {
+            lang::LessMixinParameterDefaultValue::KIND_SET.iter().next()
+        }
+        "LessMixinParameters" => lang::LessMixinParameters::KIND_SET.iter().next(),
+        "LessMixinPathSegment" => lang::LessMixinPathSegment::KIND_SET.iter().next(),
+        "LessMixinRestParameter" => lang::LessMixinRestParameter::KIND_SET.iter().next(),
+        "LessMixinSemicolonArguments" => lang::LessMixinSemicolonArguments::KIND_SET.iter().next(),
+        "LessMixinSemicolonParameters" => {
+            lang::LessMixinSemicolonParameters::KIND_SET.iter().next()
+        }
+        "LessOperator" => lang::LessOperator::KIND_SET.iter().next(),
+        "LessParenthesizedExpression" => lang::LessParenthesizedExpression::KIND_SET.iter().next(),
+        "ScssArguments" => lang::ScssArguments::KIND_SET.iter().next(),
+        "ScssContentAtRule" => lang::ScssContentAtRule::KIND_SET.iter().next(),
+        "ScssDebugAtRule" => lang::ScssDebugAtRule::KIND_SET.iter().next(),
+        "ScssDeclaration" => lang::ScssDeclaration::KIND_SET.iter().next(),
+        "ScssEachAtRule" => lang::ScssEachAtRule::KIND_SET.iter().next(),
+        "ScssElseClause" => lang::ScssElseClause::KIND_SET.iter().next(),
+        "ScssErrorAtRule" => lang::ScssErrorAtRule::KIND_SET.iter().next(),
+        "ScssExpression" => lang::ScssExpression::KIND_SET.iter().next(),
+        "ScssExtendAtRule" => lang::ScssExtendAtRule::KIND_SET.iter().next(),
+        "ScssExtendOptionalModifier" => lang::ScssExtendOptionalModifier::KIND_SET.iter().next(),
+        "ScssForAtRule" => lang::ScssForAtRule::KIND_SET.iter().next(),
+        "ScssForwardAsClause" => lang::ScssForwardAsClause::KIND_SET.iter().next(),
+        "ScssForwardAtRule" => lang::ScssForwardAtRule::KIND_SET.iter().next(),
+        "ScssForwardVisibilityClause" => lang::ScssForwardVisibilityClause::KIND_SET.iter().next(),
+        "ScssFunctionAtRule" => lang::ScssFunctionAtRule::KIND_SET.iter().next(),
+        "ScssIdentifier" => lang::ScssIdentifier::KIND_SET.iter().next(),
+        "ScssIfAtRule" => lang::ScssIfAtRule::KIND_SET.iter().next(),
+        "ScssIncludeAtRule" => lang::ScssIncludeAtRule::KIND_SET.iter().next(),
+        "ScssKeywordArgument" => lang::ScssKeywordArgument::KIND_SET.iter().next(),
+        "ScssMapExpression" => lang::ScssMapExpression::KIND_SET.iter().next(),
+        "ScssMapExpressionPair" => lang::ScssMapExpressionPair::KIND_SET.iter().next(),
+        "ScssMixinAtRule" => lang::ScssMixinAtRule::KIND_SET.iter().next(),
+        "ScssOperator" => lang::ScssOperator::KIND_SET.iter().next(),
+        "ScssParameter" => lang::ScssParameter::KIND_SET.iter().next(),
+        "ScssParameterDefaultValue" => lang::ScssParameterDefaultValue::KIND_SET.iter().next(),
+        "ScssParameters" => lang::ScssParameters::KIND_SET.iter().next(),
+        "ScssParenthesizedExpression" => lang::ScssParenthesizedExpression::KIND_SET.iter().next(),
+        "ScssPlaceholderSelector" => lang::ScssPlaceholderSelector::KIND_SET.iter().next(),
+        "ScssQualifiedName" => lang::ScssQualifiedName::KIND_SET.iter().next(),
+        "ScssReturnAtRule" => lang::ScssReturnAtRule::KIND_SET.iter().next(),
+        "ScssUseAllNamespace" => lang::ScssUseAllNamespace::KIND_SET.iter().next(),
+        "ScssUseAsClause" => lang::ScssUseAsClause::KIND_SET.iter().next(),
+        "ScssUseAtRule" => lang::ScssUseAtRule::KIND_SET.iter().next(),
+        "ScssVariableModifier" => lang::ScssVariableModifier::KIND_SET.iter().next(),
+        "ScssWarnAtRule" => lang::ScssWarnAtRule::KIND_SET.iter().next(),
+        "ScssWhileAtRule" => lang::ScssWhileAtRule::KIND_SET.iter().next(),
+        "ScssWithClause" => lang::ScssWithClause::KIND_SET.iter().next(),
         "TwApplyAtRule" => lang::TwApplyAtRule::KIND_SET.iter().next(),
         "TwConfigAtRule" => lang::TwConfigAtRule::KIND_SET.iter().next(),
         "TwCustomVariantAtRule" => lang::TwCustomVariantAtRule::KIND_SET.iter().next(),
@@ -0,0 +1,83 @@
+mod constants;
+pub mod generated_mappings;
+
+use super::{DisregardedSlotCondition, GritTargetLanguageImpl};
+use crate::grit_target_node::{GritTargetNode, GritTargetSyntaxKind};
+use biome_graphql_syntax::GraphqlSyntaxKind;
+use constants::DISREGARDED_SNIPPET_SLOTS;
+use generated_mappings::kind_by_name;
+
+#[derive(Clone, Debug)]
+pub struct GraphqlTargetLanguage;
+
+impl GritTargetLanguageImpl for GraphqlTargetLanguage {
+    type Kind = GraphqlSyntaxKind;
+
+    /// Returns the syntax kind for a node by name.
+    ///
+    /// Supports native Biome AST patterns for full language coverage.
+    fn kind_by_name(&self, node_name: &str) -> Option<GraphqlSyntaxKind> {
+        kind_by_name(node_name)
+    }
+
+    /// Returns the node name for a given syntax kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_symbol_name()`.
+    fn name_for_kind(&self, _kind: GritTargetSyntaxKind) -> &'static str {
+        // TODO: See [super::JsTargetLanguage::name_for_kind()].
+        "(unknown node)"
+    }
+
+    /// Returns the slots with their names for the given node kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_field_name_for_id()`.
+    fn named_slots_for_kind(&self, _kind: GritTargetSyntaxKind) -> &'static [(&'static str, u32)] {
+        // TODO: See [super::JsTargetLanguage::named_slots_for_kind()].
+        &[]
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("", ""),
+            ("{ ", " }"),
+            ("{ GRIT_FIELD(", ") }"),
+            ("{ GRIT_FIELD(GRIT_ARGUMENT: ", ") }"),
+        ]
+    }
+
+    fn is_comment_kind(kind: GritTargetSyntaxKind) -> bool {
+        kind.as_graphql_kind() == Some(GraphqlSyntaxKind::COMMENT)
+    }
+
+    fn metavariable_kind() -> Self::Kind {
+        GraphqlSyntaxKind::GRAPHQL_METAVARIABLE
+    }
+
+    fn is_disregarded_snippet_field(
+        &self,
+        kind: GritTargetSyntaxKind,
+        slot_index: u32,
+        node: Option<GritTargetNode<'_>>,
+    ) -> bool {
+        DISREGARDED_SNIPPET_SLOTS.iter().any(
+            |(disregarded_kind, disregarded_slot_index, condition)| {
+                if GritTargetSyntaxKind::from(*disregarded_kind) != kind
+                    || *disregarded_slot_index != slot_index
+                {
+                    return false;
+                }
+
+                match condition {
+                    DisregardedSlotCondition::Always => true,
+                    DisregardedSlotCondition::OnlyIf(node_texts) => node_texts.iter().any(|text| {
+                        *text == node.as_ref().map(|node| node.text()).unwrap_or_default()
+                    }),
+                }
+            },
+        )
+    }
+}
@@ -0,0 +1,10 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::grit_target_language::DisregardedSlotCondition;
+use biome_graphql_syntax::GraphqlSyntaxKind;
+
+pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[(
+    GraphqlSyntaxKind,
+    u32,
+    DisregardedSlotCondition,
+)] = &[];
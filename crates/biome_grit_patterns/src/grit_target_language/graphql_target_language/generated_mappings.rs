@@ -0,0 +1,85 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+//! Maps GritQL pattern names to Biome's internal syntax kinds.
+use biome_graphql_syntax as lang;
+use biome_rowan::AstNode;
+use lang::GraphqlSyntaxKind;
+
+/// Returns the syntax kind for a legacy or native node name.
+pub fn kind_by_name(node_name: &str) -> Option<GraphqlSyntaxKind> {
+    match node_name {
+        // Native Biome AST patterns
+        "GraphqlAlias" => lang::GraphqlAlias::KIND_SET.iter().next(),
+        "GraphqlArgument" => lang::GraphqlArgument::KIND_SET.iter().next(),
+        "GraphqlArguments" => lang::GraphqlArguments::KIND_SET.iter().next(),
+        "GraphqlArgumentsDefinition" => lang::GraphqlArgumentsDefinition::KIND_SET.iter().next(),
+        "GraphqlBooleanValue" => lang::GraphqlBooleanValue::KIND_SET.iter().next(),
+        "GraphqlDefaultValue" => lang::GraphqlDefaultValue::KIND_SET.iter().next(),
+        "GraphqlDescription" => lang::GraphqlDescription::KIND_SET.iter().next(),
+        "GraphqlDirective" => lang::GraphqlDirective::KIND_SET.iter().next(),
+        "GraphqlDirectiveDefinition" => lang::GraphqlDirectiveDefinition::KIND_SET.iter().next(),
+        "GraphqlDirectiveLocation" => lang::GraphqlDirectiveLocation::KIND_SET.iter().next(),
+        "GraphqlEnumTypeDefinition" => lang::GraphqlEnumTypeDefinition::KIND_SET.iter().next(),
+        "GraphqlEnumTypeExtension" => lang::GraphqlEnumTypeExtension::KIND_SET.iter().next(),
+        "GraphqlEnumValue" => lang::GraphqlEnumValue::KIND_SET.iter().next(),
+        "GraphqlEnumValueDefinition" => lang::GraphqlEnumValueDefinition::KIND_SET.iter().next(),
+        "GraphqlEnumValuesDefinition" => lang::GraphqlEnumValuesDefinition::KIND_SET.iter().next(),
+        "GraphqlField" => lang::GraphqlField::KIND_SET.iter().next(),
+        "GraphqlFieldDefinition" => lang::GraphqlFieldDefinition::KIND_SET.iter().next(),
+        "GraphqlFieldsDefinition" => lang::GraphqlFieldsDefinition::KIND_SET.iter().next(),
+        "GraphqlFloatValue" => lang::GraphqlFloatValue::KIND_SET.iter().next(),
+        "GraphqlFragmentDefinition" => lang::GraphqlFragmentDefinition::KIND_SET.iter().next(),
+        "GraphqlFragmentSpread" => lang::GraphqlFragmentSpread::KIND_SET.iter().next(),
+        "GraphqlImplementsInterfaces" => lang::GraphqlImplementsInterfaces::KIND_SET.iter().next(),
+        "GraphqlInlineFragment" => lang::GraphqlInlineFragment::KIND_SET.iter().next(),
+        "GraphqlInputFieldsDefinition" => {
+            lang::GraphqlInputFieldsDefinition::KIND_SET.iter().next()
+        }
+        "GraphqlInputObjectTypeDefinition" => lang::GraphqlInputObjectTypeDefinition::KIND_SET
+            .iter()
+            .next(),
+        "GraphqlInputObjectTypeExtension" => lang::GraphqlInputObjectTypeExtension::KIND_SET
+            .iter()
+            .next(),
+        "GraphqlInputValueDefinition" => lang::GraphqlInputValueDefinition::KIND_SET.iter().next(),
+        "GraphqlIntValue" => lang::GraphqlIntValue::KIND_SET.iter().next(),
+        "GraphqlInterfaceTypeDefinition" => {
+            lang::GraphqlInterfaceTypeDefinition::KIND_SET.iter().next()
+        }
+        "GraphqlInterfaceTypeExtension" => {
+            lang::GraphqlInterfaceTypeExtension::KIND_SET.iter().next()
+        }
+        "GraphqlLiteralName" => lang::GraphqlLiteralName::KIND_SET.iter().next(),
+        "GraphqlMetavariable" => lang::GraphqlMetavariable::KIND_SET.iter().next(),
+        "GraphqlNameBinding" => lang::GraphqlNameBinding::KIND_SET.iter().next(),
+        "GraphqlNameReference" => lang::GraphqlNameReference::KIND_SET.iter().next(),
+        "GraphqlNonNullType" => lang::GraphqlNonNullType::KIND_SET.iter().next(),
+        "GraphqlNullValue" => lang::GraphqlNullValue::KIND_SET.iter().next(),
+        "GraphqlObjectField" => lang::GraphqlObjectField::KIND_SET.iter().next(),
+        "GraphqlObjectTypeDefinition" => lang::GraphqlObjectTypeDefinition::KIND_SET.iter().next(),
+        "GraphqlObjectTypeExtension" => lang::GraphqlObjectTypeExtension::KIND_SET.iter().next(),
+        "GraphqlObjectValue" => lang::GraphqlObjectValue::KIND_SET.iter().next(),
+        "GraphqlOperationDefinition" => lang::GraphqlOperationDefinition::KIND_SET.iter().next(),
+        "GraphqlOperationType" => lang::GraphqlOperationType::KIND_SET.iter().next(),
+        "GraphqlRoot" => lang::GraphqlRoot::KIND_SET.iter().next(),
+        "GraphqlRootOperationTypeDefinition" => lang::GraphqlRootOperationTypeDefinition::KIND_SET
+            .iter()
+            .next(),
+        "GraphqlRootOperationTypes" => lang::GraphqlRootOperationTypes::KIND_SET.iter().next(),
+        "GraphqlScalarTypeDefinition" => lang::GraphqlScalarTypeDefinition::KIND_SET.iter().next(),
+        "GraphqlScalarTypeExtension" => lang::GraphqlScalarTypeExtension::KIND_SET.iter().next(),
+        "GraphqlSchemaDefinition" => lang::GraphqlSchemaDefinition::KIND_SET.iter().next(),
+        "GraphqlSchemaExtension" => lang::GraphqlSchemaExtension::KIND_SET.iter().next(),
+        "GraphqlSelectionSet" => lang::GraphqlSelectionSet::KIND_SET.iter().next(),
+        "GraphqlStringValue" => lang::GraphqlStringValue::KIND_SET.iter().next(),
+        "GraphqlTypeCondition" => lang::GraphqlTypeCondition::KIND_SET.iter().next(),
+        "GraphqlUnionMemberTypes" => lang::GraphqlUnionMemberTypes::KIND_SET.iter().next(),
+        "GraphqlUnionTypeDefinition" => lang::GraphqlUnionTypeDefinition::KIND_SET.iter().next(),
+        "GraphqlUnionTypeExtension" => lang::GraphqlUnionTypeExtension::KIND_SET.iter().next(),
+        "GraphqlVariableBinding" => lang::GraphqlVariableBinding::KIND_SET.iter().next(),
+        "GraphqlVariableDefinition" => lang::GraphqlVariableDefinition::KIND_SET.iter().next(),
+        "GraphqlVariableDefinitions" => lang::GraphqlVariableDefinitions::KIND_SET.iter().next(),
+        "GraphqlVariableReference" => lang::GraphqlVariableReference::KIND_SET.iter().next(),
+        _ => None,
+    }
+}
@@ -0,0 +1,108 @@
+mod constants;
+pub mod generated_mappings;
+
+use super::{
+    DisregardedSlotCondition, GritTargetLanguageImpl, LeafEquivalenceClass, LeafNormalizer,
+    normalize_quoted_string,
+};
+use crate::{
+    CompileError,
+    grit_target_node::{GritTargetNode, GritTargetSyntaxKind},
+};
+use biome_html_syntax::HtmlSyntaxKind;
+use constants::DISREGARDED_SNIPPET_SLOTS;
+use generated_mappings::kind_by_name;
+
+const EQUIVALENT_LEAF_NODES: &[&[LeafNormalizer]] = &[&[LeafNormalizer::new(
+    GritTargetSyntaxKind::HtmlSyntaxKind(HtmlSyntaxKind::HTML_STRING_LITERAL),
+    normalize_quoted_string,
+)]];
+
+#[derive(Clone, Debug)]
+pub struct HtmlTargetLanguage;
+
+impl GritTargetLanguageImpl for HtmlTargetLanguage {
+    type Kind = HtmlSyntaxKind;
+
+    /// Returns the syntax kind for a node by name.
+    ///
+    /// Supports native Biome AST patterns for full language coverage.
+    fn kind_by_name(&self, node_name: &str) -> Option<HtmlSyntaxKind> {
+        kind_by_name(node_name)
+    }
+
+    /// Returns the node name for a given syntax kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_symbol_name()`.
+    fn name_for_kind(&self, _kind: GritTargetSyntaxKind) -> &'static str {
+        // TODO: See [super::JsTargetLanguage::name_for_kind()].
+        "(unknown node)"
+    }
+
+    /// Returns the slots with their names for the given node kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_field_name_for_id()`.
+    fn named_slots_for_kind(&self, _kind: GritTargetSyntaxKind) -> &'static [(&'static str, u32)] {
+        // TODO: See [super::JsTargetLanguage::named_slots_for_kind()].
+        &[]
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("", ""),
+            ("<GRIT_ELEMENT ", " />"),
+            ("<GRIT_ELEMENT GRIT_ATTRIBUTE=", " />"),
+        ]
+    }
+
+    fn is_comment_kind(kind: GritTargetSyntaxKind) -> bool {
+        kind.as_html_kind() == Some(HtmlSyntaxKind::COMMENT)
+    }
+
+    fn metavariable_kind() -> Self::Kind {
+        HtmlSyntaxKind::HTML_METAVARIABLE
+    }
+
+    fn is_disregarded_snippet_field(
+        &self,
+        kind: GritTargetSyntaxKind,
+        slot_index: u32,
+        node: Option<GritTargetNode<'_>>,
+    ) -> bool {
+        DISREGARDED_SNIPPET_SLOTS.iter().any(
+            |(disregarded_kind, disregarded_slot_index, condition)| {
+                if GritTargetSyntaxKind::from(*disregarded_kind) != kind
+                    || *disregarded_slot_index != slot_index
+                {
+                    return false;
+                }
+
+                match condition {
+                    DisregardedSlotCondition::Always => true,
+                    DisregardedSlotCondition::OnlyIf(node_texts) => node_texts.iter().any(|text| {
+                        *text == node.as_ref().map(|node| node.text()).unwrap_or_default()
+                    }),
+                }
+            },
+        )
+    }
+
+    fn get_equivalence_class(
+        &self,
+        kind: GritTargetSyntaxKind,
+        text: &str,
+    ) -> Result<Option<LeafEquivalenceClass>, CompileError> {
+        if let Some(class) = EQUIVALENT_LEAF_NODES
+            .iter()
+            .find(|v| v.iter().any(|normalizer| normalizer.kind() == kind))
+        {
+            LeafEquivalenceClass::new(text, kind, class)
+        } else {
+            Ok(None)
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::grit_target_language::DisregardedSlotCondition;
+use biome_html_syntax::HtmlSyntaxKind;
+
+pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[(HtmlSyntaxKind, u32, DisregardedSlotCondition)] =
+    &[];
@@ -0,0 +1,81 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+//! Maps GritQL pattern names to Biome's internal syntax kinds.
+use biome_html_syntax as lang;
+use biome_rowan::AstNode;
+use lang::HtmlSyntaxKind;
+
+/// Returns the syntax kind for a legacy or native node name.
+pub fn kind_by_name(node_name: &str) -> Option<HtmlSyntaxKind> {
+    match node_name {
+        // Native Biome AST patterns
+        "AstroEmbeddedContent" => lang::AstroEmbeddedContent::KIND_SET.iter().next(),
+        "AstroFrontmatterElement" => lang::AstroFrontmatterElement::KIND_SET.iter().next(),
+        "HtmlAttribute" => lang::HtmlAttribute::KIND_SET.iter().next(),
+        "HtmlAttributeDoubleTextExpression" => lang::HtmlAttributeDoubleTextExpression::KIND_SET
+            .iter()
+            .next(),
+        "HtmlAttributeInitializerClause" => {
+            lang::HtmlAttributeInitializerClause::KIND_SET.iter().next()
+        }
+        "HtmlAttributeName" => lang::HtmlAttributeName::KIND_SET.iter().next(),
+        "HtmlAttributeSingleTextExpression" => lang::HtmlAttributeSingleTextExpression::KIND_SET
+            .iter()
+            .next(),
+        "HtmlCdataSection" => lang::HtmlCdataSection::KIND_SET.iter().next(),
+        "HtmlClosingElement" => lang::HtmlClosingElement::KIND_SET.iter().next(),
+        "HtmlContent" => lang::HtmlContent::KIND_SET.iter().next(),
+        "HtmlDirective" => lang::HtmlDirective::KIND_SET.iter().next(),
+        "HtmlDoubleTextExpression" => lang::HtmlDoubleTextExpression::KIND_SET.iter().next(),
+        "HtmlElement" => lang::HtmlElement::KIND_SET.iter().next(),
+        "HtmlEmbeddedContent" => lang::HtmlEmbeddedContent::KIND_SET.iter().next(),
+        "HtmlMetavariable" => lang::HtmlMetavariable::KIND_SET.iter().next(),
+        "HtmlOpeningElement" => lang::HtmlOpeningElement::KIND_SET.iter().next(),
+        "HtmlRoot" => lang::HtmlRoot::KIND_SET.iter().next(),
+        "HtmlSelfClosingElement" => lang::HtmlSelfClosingElement::KIND_SET.iter().next(),
+        "HtmlSingleTextExpression" => lang::HtmlSingleTextExpression::KIND_SET.iter().next(),
+        "HtmlString" => lang::HtmlString::KIND_SET.iter().next(),
+        "HtmlTagName" => lang::HtmlTagName::KIND_SET.iter().next(),
+        "HtmlTextExpression" => lang::HtmlTextExpression::KIND_SET.iter().next(),
+        "SvelteAttachAttribute" => lang::SvelteAttachAttribute::KIND_SET.iter().next(),
+        "SvelteAwaitBlock" => lang::SvelteAwaitBlock::KIND_SET.iter().next(),
+        "SvelteAwaitCatchBlock" => lang::SvelteAwaitCatchBlock::KIND_SET.iter().next(),
+        "SvelteAwaitCatchClause" => lang::SvelteAwaitCatchClause::KIND_SET.iter().next(),
+        "SvelteAwaitClosingBlock" => lang::SvelteAwaitClosingBlock::KIND_SET.iter().next(),
+        "SvelteAwaitOpeningBlock" => lang::SvelteAwaitOpeningBlock::KIND_SET.iter().next(),
+        "SvelteAwaitThenBlock" => lang::SvelteAwaitThenBlock::KIND_SET.iter().next(),
+        "SvelteAwaitThenClause" => lang::SvelteAwaitThenClause::KIND_SET.iter().next(),
+        "SvelteConstBlock" => lang::SvelteConstBlock::KIND_SET.iter().next(),
+        "SvelteDebugBlock" => lang::SvelteDebugBlock::KIND_SET.iter().next(),
+        "SvelteEachAsKeyedItem" => lang::SvelteEachAsKeyedItem::KIND_SET.iter().next(),
+        "SvelteEachBlock" => lang::SvelteEachBlock::KIND_SET.iter().next(),
+        "SvelteEachClosingBlock" => lang::SvelteEachClosingBlock::KIND_SET.iter().next(),
+        "SvelteEachIndex" => lang::SvelteEachIndex::KIND_SET.iter().next(),
+        "SvelteEachKey" => lang::SvelteEachKey::KIND_SET.iter().next(),
+        "SvelteEachKeyedItem" => lang::SvelteEachKeyedItem::KIND_SET.iter().next(),
+        "SvelteEachOpeningBlock" => lang::SvelteEachOpeningBlock::KIND_SET.iter().next(),
+        "SvelteElseClause" => lang::SvelteElseClause::KIND_SET.iter().next(),
+        "SvelteElseIfClause" => lang::SvelteElseIfClause::KIND_SET.iter().next(),
+        "SvelteHtmlBlock" => lang::SvelteHtmlBlock::KIND_SET.iter().next(),
+        "SvelteIfBlock" => lang::SvelteIfBlock::KIND_SET.iter().next(),
+        "SvelteIfClosingBlock" => lang::SvelteIfClosingBlock::KIND_SET.iter().next(),
+        "SvelteIfOpeningBlock" => lang::SvelteIfOpeningBlock::KIND_SET.iter().next(),
+        "SvelteKeyBlock" => lang::SvelteKeyBlock::KIND_SET.iter().next(),
+        "SvelteKeyClosingBlock" => lang::SvelteKeyClosingBlock::KIND_SET.iter().next(),
+        "SvelteKeyOpeningBlock" => lang::SvelteKeyOpeningBlock::KIND_SET.iter().next(),
+        "SvelteName" => lang::SvelteName::KIND_SET.iter().next(),
+        "SvelteRenderBlock" => lang::SvelteRenderBlock::KIND_SET.iter().next(),
+        "SvelteSnippetBlock" => lang::SvelteSnippetBlock::KIND_SET.iter().next(),
+        "SvelteSnippetClosingBlock" => lang::SvelteSnippetClosingBlock::KIND_SET.iter().next(),
+        "SvelteSnippetOpeningBlock" => lang::SvelteSnippetOpeningBlock::KIND_SET.iter().next(),
+        "VueDirective" => lang::VueDirective::KIND_SET.iter().next(),
+        "VueDirectiveArgument" => lang::VueDirectiveArgument::KIND_SET.iter().next(),
+        "VueDynamicArgument" => lang::VueDynamicArgument::KIND_SET.iter().next(),
+        "VueModifier" => lang::VueModifier::KIND_SET.iter().next(),
+        "VueStaticArgument" => lang::VueStaticArgument::KIND_SET.iter().next(),
+        "VueVBindShorthandDirective" => lang::VueVBindShorthandDirective::KIND_SET.iter().next(),
+        "VueVOnShorthandDirective" => lang::VueVOnShorthandDirective::KIND_SET.iter().next(),
+        "VueVSlotShorthandDirective" => lang::VueVSlotShorthandDirective::KIND_SET.iter().next(),
+        _ => None,
+    }
+}
@@ -45,6 +45,7 @@ pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[(JsSyntaxKind, u32, DisregardedSlo
     (TS_MAPPED_TYPE, 10, Always),
     (TS_METHOD_SIGNATURE_CLASS_MEMBER, 1, OnlyIf(&[""])),
     (TS_METHOD_SIGNATURE_CLASS_MEMBER, 7, Always),
+    (TS_OPAQUE_TYPE_ALIAS_DECLARATION, 7, Always),
     (TS_PROPERTY_SIGNATURE_CLASS_MEMBER, 3, Always),
     (TS_SETTER_SIGNATURE_CLASS_MEMBER, 7, Always),
     (TS_TYPE_ALIAS_DECLARATION, 5, Always),
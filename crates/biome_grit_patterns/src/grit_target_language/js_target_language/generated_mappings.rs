@@ -476,6 +476,7 @@ pub fn kind_by_name(node_name: &str) -> Option<JsSyntaxKind> {
         }
         "TsEnumDeclaration" => lang::TsEnumDeclaration::KIND_SET.iter().next(),
         "TsEnumMember" => lang::TsEnumMember::KIND_SET.iter().next(),
+        "TsExactObjectType" => lang::TsExactObjectType::KIND_SET.iter().next(),
         "TsExportAsNamespaceClause" => lang::TsExportAsNamespaceClause::KIND_SET.iter().next(),
         "TsExportAssignmentClause" => lang::TsExportAssignmentClause::KIND_SET.iter().next(),
         "TsExportDeclareClause" => lang::TsExportDeclareClause::KIND_SET.iter().next(),
@@ -519,6 +520,7 @@ pub fn kind_by_name(node_name: &str) -> Option<JsSyntaxKind> {
         "TsMappedTypeReadonlyModifierClause" => lang::TsMappedTypeReadonlyModifierClause::KIND_SET
             .iter()
             .next(),
+        "TsMaybeType" => lang::TsMaybeType::KIND_SET.iter().next(),
         "TsMethodSignatureClassMember" => {
             lang::TsMethodSignatureClassMember::KIND_SET.iter().next()
         }
@@ -538,6 +540,9 @@ pub fn kind_by_name(node_name: &str) -> Option<JsSyntaxKind> {
         "TsNumberLiteralType" => lang::TsNumberLiteralType::KIND_SET.iter().next(),
         "TsNumberType" => lang::TsNumberType::KIND_SET.iter().next(),
         "TsObjectType" => lang::TsObjectType::KIND_SET.iter().next(),
+        "TsOpaqueTypeAliasDeclaration" => {
+            lang::TsOpaqueTypeAliasDeclaration::KIND_SET.iter().next()
+        }
         "TsOptionalPropertyAnnotation" => {
             lang::TsOptionalPropertyAnnotation::KIND_SET.iter().next()
         }
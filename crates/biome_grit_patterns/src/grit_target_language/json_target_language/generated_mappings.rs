@@ -0,0 +1,24 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+//! Maps GritQL pattern names to Biome's internal syntax kinds.
+use biome_json_syntax as lang;
+use biome_rowan::AstNode;
+use lang::JsonSyntaxKind;
+
+/// Returns the syntax kind for a legacy or native node name.
+pub fn kind_by_name(node_name: &str) -> Option<JsonSyntaxKind> {
+    match node_name {
+        // Native Biome AST patterns
+        "JsonArrayValue" => lang::JsonArrayValue::KIND_SET.iter().next(),
+        "JsonBooleanValue" => lang::JsonBooleanValue::KIND_SET.iter().next(),
+        "JsonMember" => lang::JsonMember::KIND_SET.iter().next(),
+        "JsonMemberName" => lang::JsonMemberName::KIND_SET.iter().next(),
+        "JsonMetavariable" => lang::JsonMetavariable::KIND_SET.iter().next(),
+        "JsonNullValue" => lang::JsonNullValue::KIND_SET.iter().next(),
+        "JsonNumberValue" => lang::JsonNumberValue::KIND_SET.iter().next(),
+        "JsonObjectValue" => lang::JsonObjectValue::KIND_SET.iter().next(),
+        "JsonRoot" => lang::JsonRoot::KIND_SET.iter().next(),
+        "JsonStringValue" => lang::JsonStringValue::KIND_SET.iter().next(),
+        _ => None,
+    }
+}
@@ -10,7 +10,10 @@ mod grit_context;
 mod grit_css_parser;
 mod grit_definitions;
 mod grit_file;
+mod grit_graphql_parser;
+mod grit_html_parser;
 mod grit_js_parser;
+mod grit_json_parser;
 mod grit_node;
 mod grit_node_patterns;
 mod grit_query;
@@ -30,10 +33,14 @@ pub use grit_built_in_functions::BuiltInFunction;
 pub use grit_context::{GritExecContext, GritQueryContext, GritTargetFile};
 pub use grit_pattern_matcher::pattern::{Pattern as GritPattern, State as GritQueryState};
 pub use grit_query::{
-    CreateFile, GritQuery, GritQueryEffect, GritQueryResult, Message, OutputFile,
+    CreateFile, GritQuery, GritQueryDiagnostic, GritQueryEffect, GritQueryResult, Message,
+    OutputFile, Rewrite,
 };
 pub use grit_resolved_pattern::GritResolvedPattern;
-pub use grit_target_language::{GritTargetLanguage, JsTargetLanguage};
+pub use grit_target_language::{
+    CssTargetLanguage, GraphqlTargetLanguage, GritTargetLanguage, HtmlTargetLanguage,
+    JsTargetLanguage, JsonTargetLanguage,
+};
 pub use grit_target_node::{GritTargetLanguageNode, GritTargetNode, GritTargetSyntaxKind};
 
 use biome_grit_parser::parse_grit;
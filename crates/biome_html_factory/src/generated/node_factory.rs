@@ -244,6 +244,12 @@ pub fn html_embedded_content(value_token: SyntaxToken) -> HtmlEmbeddedContent {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
+pub fn html_metavariable(value_token: SyntaxToken) -> HtmlMetavariable {
+    HtmlMetavariable::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::HTML_METAVARIABLE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
 pub fn html_opening_element(
     l_angle_token: SyntaxToken,
     name: HtmlTagName,
@@ -13,6 +13,7 @@ impl FormatRule<AnyHtmlAttribute> for FormatAnyHtmlAttribute {
             AnyHtmlAttribute::HtmlAttributeDoubleTextExpression(node) => node.format().fmt(f),
             AnyHtmlAttribute::HtmlAttributeSingleTextExpression(node) => node.format().fmt(f),
             AnyHtmlAttribute::HtmlBogusAttribute(node) => node.format().fmt(f),
+            AnyHtmlAttribute::HtmlMetavariable(node) => node.format().fmt(f),
             AnyHtmlAttribute::SvelteAttachAttribute(node) => node.format().fmt(f),
         }
     }
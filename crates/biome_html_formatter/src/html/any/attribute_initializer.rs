@@ -11,6 +11,7 @@ impl FormatRule<AnyHtmlAttributeInitializer> for FormatAnyHtmlAttributeInitializ
             AnyHtmlAttributeInitializer::HtmlAttributeSingleTextExpression(node) => {
                 node.format().fmt(f)
             }
+            AnyHtmlAttributeInitializer::HtmlMetavariable(node) => node.format().fmt(f),
             AnyHtmlAttributeInitializer::HtmlString(node) => node.format().fmt(f),
         }
     }
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_html_syntax::{HtmlMetavariable, HtmlMetavariableFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlMetavariable;
+impl FormatNodeRule<HtmlMetavariable> for FormatHtmlMetavariable {
+    fn fmt_fields(&self, node: &HtmlMetavariable, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let HtmlMetavariableFields { value_token } = node.as_fields();
+        write!(f, [value_token.format()])
+    }
+}
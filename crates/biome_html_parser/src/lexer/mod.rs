@@ -6,8 +6,9 @@ use crate::token_source::{
 };
 use biome_html_syntax::HtmlSyntaxKind::{
     AS_KW, ATTACH_KW, AWAIT_KW, CATCH_KW, COMMENT, CONST_KW, DEBUG_KW, DOCTYPE_KW, EACH_KW,
-    ELSE_KW, EOF, ERROR_TOKEN, HTML_KW, HTML_LITERAL, HTML_STRING_LITERAL, IDENT, IF_KW, KEY_KW,
-    NEWLINE, RENDER_KW, SNIPPET_KW, THEN_KW, TOMBSTONE, UNICODE_BOM, WHITESPACE,
+    ELSE_KW, EOF, ERROR_TOKEN, GRIT_METAVARIABLE, HTML_KW, HTML_LITERAL, HTML_STRING_LITERAL,
+    IDENT, IF_KW, KEY_KW, NEWLINE, RENDER_KW, SNIPPET_KW, THEN_KW, TOMBSTONE, UNICODE_BOM,
+    WHITESPACE,
 };
 use biome_html_syntax::{HtmlSyntaxKind, T, TextLen, TextSize};
 use biome_parser::diagnostic::ParseDiagnostic;
@@ -29,6 +30,8 @@ pub(crate) struct HtmlLexer<'src> {
     preceding_line_break: bool,
     after_newline: bool,
     unicode_bom_length: usize,
+    /// Whether Grit metavariables are lexed, which is only the case for GritQL snippets.
+    grit_metavariables: bool,
 }
 
 enum IdentifierContext {
@@ -56,9 +59,19 @@ impl<'src> HtmlLexer<'src> {
             after_newline: false,
             current_flags: TokenFlags::empty(),
             unicode_bom_length: 0,
+            grit_metavariables: false,
         }
     }
 
+    pub(crate) fn with_metavariables(mut self) -> Self {
+        self.grit_metavariables = true;
+        self
+    }
+
+    fn is_at_metavariable(&mut self) -> bool {
+        self.grit_metavariables && self.is_metavariable_start()
+    }
+
     /// Consume a token in the [HtmlLexContext::InsideTag] context.
     fn consume_token_inside_tag(&mut self, current: u8) -> HtmlSyntaxKind {
         match current {
@@ -84,6 +97,7 @@ impl<'src> HtmlLexer<'src> {
                 }
             }
             b'\'' | b'"' => self.consume_string_literal(current),
+            _ if self.is_at_metavariable() => self.consume_metavariable(GRIT_METAVARIABLE),
             _ if self.current_kind == T![<] && is_tag_name_byte(current) => {
                 // tag names must immediately follow a `<`
                 // https://html.spec.whatwg.org/multipage/syntax.html#start-tags
@@ -140,6 +154,7 @@ impl<'src> HtmlLexer<'src> {
             b'#' => self.consume_byte(T![#]),
 
             b'\'' | b'"' => self.consume_string_literal(current),
+            _ if self.is_at_metavariable() => self.consume_metavariable(GRIT_METAVARIABLE),
             _ if self.current_kind == T![<] && is_tag_name_byte(current) => {
                 // tag names must immediately follow a `<`
                 // https://html.spec.whatwg.org/multipage/syntax.html#start-tags
@@ -233,6 +248,7 @@ impl<'src> HtmlLexer<'src> {
                     self.consume_byte(HTML_LITERAL)
                 }
             }
+            _ if self.is_at_metavariable() => self.consume_metavariable(GRIT_METAVARIABLE),
             _ if is_at_start_identifier(current) => self
                 .consume_language_identifier(current)
                 .unwrap_or_else(|| self.consume_html_text(current)),
@@ -257,6 +273,7 @@ impl<'src> HtmlLexer<'src> {
             b'{' => self.consume_byte(T!['{']),
             b'}' => self.consume_byte(T!['}']),
             b'\'' | b'"' => self.consume_string_literal(current),
+            _ if self.is_at_metavariable() => self.consume_metavariable(GRIT_METAVARIABLE),
             _ => self.consume_unquoted_string_literal(),
         }
     }
@@ -24,7 +24,7 @@ impl<'source> HtmlParser<'source> {
     pub fn new(source: &'source str, options: HtmlParseOptions) -> Self {
         Self {
             context: ParserContext::default(),
-            source: HtmlTokenSource::from_str(source),
+            source: HtmlTokenSource::from_str(source, &options),
             options,
         }
     }
@@ -110,6 +110,8 @@ pub struct HtmlParseOptions {
     pub(crate) text_expression: Option<TextExpressionKind>,
     pub(crate) vue: bool,
     pub(crate) is_html: bool,
+    /// Enables parsing of Grit metavariables.
+    pub(crate) grit_metavariables: bool,
 }
 
 impl HtmlParseOptions {
@@ -145,6 +147,12 @@ impl HtmlParseOptions {
         self
     }
 
+    /// Enables parsing of Grit metavariables.
+    pub fn with_metavariables(mut self) -> Self {
+        self.grit_metavariables = true;
+        self
+    }
+
     pub fn is_html(&self) -> bool {
         self.is_html
     }
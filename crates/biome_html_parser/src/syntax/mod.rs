@@ -265,6 +265,7 @@ pub(crate) fn parse_html_element(p: &mut HtmlParser) -> ParsedSyntax {
         ),
         T!["{@"] => parse_svelte_at_block(p),
         T!["{#"] => parse_svelte_hash_block(p),
+        GRIT_METAVARIABLE => parse_metavariable(p, HtmlLexContext::Regular),
         T!['{'] => parse_single_text_expression(p, HtmlLexContext::Regular).or_else(|| {
             let m = p.start();
             p.bump_remap(HTML_LITERAL);
@@ -362,6 +363,7 @@ fn parse_attribute(p: &mut HtmlParser) -> ParsedSyntax {
     }
 
     match p.cur() {
+        GRIT_METAVARIABLE => parse_metavariable(p, inside_tag_context(p)),
         T!["{{"] => {
             let m = p.start();
             DoubleTextExpressions
@@ -436,9 +438,20 @@ fn is_at_attribute_start(p: &mut HtmlParser) -> bool {
         T![:],
         T![@],
         T![#],
+        GRIT_METAVARIABLE,
     ]) || (SingleTextExpressions.is_supported(p) && p.at(T!["{@"]))
 }
 
+fn parse_metavariable(p: &mut HtmlParser, context: HtmlLexContext) -> ParsedSyntax {
+    if !p.at(GRIT_METAVARIABLE) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump_with_context(GRIT_METAVARIABLE, context);
+    Present(m.complete(p, HTML_METAVARIABLE))
+}
+
 fn parse_literal(p: &mut HtmlParser, kind: HtmlSyntaxKind) -> ParsedSyntax {
     if !is_at_start_literal(p) {
         return Absent;
@@ -531,6 +544,8 @@ fn parse_attribute_initializer(p: &mut HtmlParser) -> ParsedSyntax {
                 expected_attribute,
             )
             .ok();
+    } else if p.at(GRIT_METAVARIABLE) {
+        parse_metavariable(p, inside_tag_context(p)).ok();
     } else {
         parse_attribute_string_literal(p).or_add_diagnostic(p, expected_initializer);
     }
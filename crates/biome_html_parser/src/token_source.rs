@@ -1,4 +1,5 @@
 use crate::lexer::HtmlLexer;
+use crate::parser::HtmlParseOptions;
 use biome_html_syntax::HtmlSyntaxKind::{AS_KW, CATCH_KW, EOF, THEN_KW};
 use biome_html_syntax::{HtmlSyntaxKind, TextRange};
 use biome_parser::diagnostic::ParseDiagnostic;
@@ -150,8 +151,11 @@ pub(crate) type HtmlTokenSourceCheckpoint = TokenSourceCheckpoint<HtmlSyntaxKind
 
 impl<'source> HtmlTokenSource<'source> {
     /// Creates a new token source for the given string
-    pub fn from_str(source: &'source str) -> Self {
-        let lexer = HtmlLexer::from_str(source);
+    pub fn from_str(source: &'source str, options: &HtmlParseOptions) -> Self {
+        let mut lexer = HtmlLexer::from_str(source);
+        if options.grit_metavariables {
+            lexer = lexer.with_metavariables();
+        }
 
         let buffered = BufferedLexer::new(lexer);
         let mut source = Self::new(buffered);
@@ -39,7 +39,9 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
         file_source = HtmlFileSource::html_with_text_expressions();
     }
 
-    let parser_options = HtmlParseOptions::from(&file_source);
+    let parser_options = HtmlParseOptions::from(&file_source)
+        // it is an internal option that cannot be configured via options.json
+        .with_metavariables();
     let parsed = parse_html(&content, parser_options);
     validate_eof_token(parsed.syntax());
 
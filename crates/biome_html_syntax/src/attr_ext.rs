@@ -8,7 +8,7 @@ impl AnyHtmlAttributeInitializer {
     /// Returns the string value of the attribute, if available, without quotes.
     pub fn string_value(&self) -> Option<Text> {
         match self {
-            Self::HtmlAttributeSingleTextExpression(_) => None,
+            Self::HtmlAttributeSingleTextExpression(_) | Self::HtmlMetavariable(_) => None,
             Self::HtmlString(string) => Some(
                 string
                     .value_token()
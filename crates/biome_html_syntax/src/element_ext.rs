@@ -29,7 +29,8 @@ impl AnyHtmlElement {
             Self::AnyHtmlContent(_)
             | Self::HtmlBogusElement(_)
             | Self::HtmlSelfClosingElement(_)
-            | Self::HtmlCdataSection(_) => false,
+            | Self::HtmlCdataSection(_)
+            | Self::HtmlMetavariable(_) => false,
             Self::HtmlElement(element) => element.is_javascript_tag(),
         }
     }
@@ -39,7 +40,8 @@ impl AnyHtmlElement {
             Self::AnyHtmlContent(_)
             | Self::HtmlBogusElement(_)
             | Self::HtmlSelfClosingElement(_)
-            | Self::HtmlCdataSection(_) => false,
+            | Self::HtmlCdataSection(_)
+            | Self::HtmlMetavariable(_) => false,
             Self::HtmlElement(element) => element.is_style_tag(),
         }
     }
@@ -49,7 +51,10 @@ impl AnyHtmlElement {
             Self::HtmlElement(element) => element.find_attribute_by_name(name_to_lookup),
             Self::HtmlSelfClosingElement(element) => element.find_attribute_by_name(name_to_lookup),
             // Other variants don't have attributes
-            Self::AnyHtmlContent(_) | Self::HtmlBogusElement(_) | Self::HtmlCdataSection(_) => None,
+            Self::AnyHtmlContent(_)
+            | Self::HtmlBogusElement(_)
+            | Self::HtmlCdataSection(_)
+            | Self::HtmlMetavariable(_) => None,
         }
     }
 
@@ -62,6 +62,7 @@ pub enum HtmlSyntaxKind {
     NEWLINE,
     WHITESPACE,
     IDENT,
+    GRIT_METAVARIABLE,
     HTML_ROOT,
     HTML_DIRECTIVE,
     HTML_SELF_CLOSING_TAG,
@@ -85,6 +86,7 @@ pub enum HtmlSyntaxKind {
     HTML_TEXT_EXPRESSION,
     HTML_ATTRIBUTE_DOUBLE_TEXT_EXPRESSION,
     HTML_ATTRIBUTE_SINGLE_TEXT_EXPRESSION,
+    HTML_METAVARIABLE,
     ASTRO_FRONTMATTER_ELEMENT,
     ASTRO_EMBEDDED_CONTENT,
     SVELTE_DEBUG_BLOCK,
@@ -75,6 +75,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::HtmlEmbeddedContent::new_unchecked(node) };
                     $body
                 }
+                $crate::HtmlSyntaxKind::HTML_METAVARIABLE => {
+                    let $pattern = unsafe { $crate::HtmlMetavariable::new_unchecked(node) };
+                    $body
+                }
                 $crate::HtmlSyntaxKind::HTML_OPENING_ELEMENT => {
                     let $pattern = unsafe { $crate::HtmlOpeningElement::new_unchecked(node) };
                     $body
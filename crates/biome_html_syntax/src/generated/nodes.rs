@@ -630,6 +630,41 @@ pub struct HtmlEmbeddedContentFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct HtmlMetavariable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl HtmlMetavariable {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> HtmlMetavariableFields {
+        HtmlMetavariableFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for HtmlMetavariable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct HtmlMetavariableFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HtmlOpeningElement {
     pub(crate) syntax: SyntaxNode,
 }
@@ -2810,6 +2845,7 @@ pub enum AnyHtmlAttribute {
     HtmlAttributeDoubleTextExpression(HtmlAttributeDoubleTextExpression),
     HtmlAttributeSingleTextExpression(HtmlAttributeSingleTextExpression),
     HtmlBogusAttribute(HtmlBogusAttribute),
+    HtmlMetavariable(HtmlMetavariable),
     SvelteAttachAttribute(SvelteAttachAttribute),
 }
 impl AnyHtmlAttribute {
@@ -2847,6 +2883,12 @@ impl AnyHtmlAttribute {
             _ => None,
         }
     }
+    pub fn as_html_metavariable(&self) -> Option<&HtmlMetavariable> {
+        match &self {
+            Self::HtmlMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_svelte_attach_attribute(&self) -> Option<&SvelteAttachAttribute> {
         match &self {
             Self::SvelteAttachAttribute(item) => Some(item),
@@ -2857,6 +2899,7 @@ impl AnyHtmlAttribute {
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyHtmlAttributeInitializer {
     HtmlAttributeSingleTextExpression(HtmlAttributeSingleTextExpression),
+    HtmlMetavariable(HtmlMetavariable),
     HtmlString(HtmlString),
 }
 impl AnyHtmlAttributeInitializer {
@@ -2868,6 +2911,12 @@ impl AnyHtmlAttributeInitializer {
             _ => None,
         }
     }
+    pub fn as_html_metavariable(&self) -> Option<&HtmlMetavariable> {
+        match &self {
+            Self::HtmlMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_html_string(&self) -> Option<&HtmlString> {
         match &self {
             Self::HtmlString(item) => Some(item),
@@ -2907,6 +2956,7 @@ pub enum AnyHtmlElement {
     HtmlBogusElement(HtmlBogusElement),
     HtmlCdataSection(HtmlCdataSection),
     HtmlElement(HtmlElement),
+    HtmlMetavariable(HtmlMetavariable),
     HtmlSelfClosingElement(HtmlSelfClosingElement),
 }
 impl AnyHtmlElement {
@@ -2934,6 +2984,12 @@ impl AnyHtmlElement {
             _ => None,
         }
     }
+    pub fn as_html_metavariable(&self) -> Option<&HtmlMetavariable> {
+        match &self {
+            Self::HtmlMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_html_self_closing_element(&self) -> Option<&HtmlSelfClosingElement> {
         match &self {
             Self::HtmlSelfClosingElement(item) => Some(item),
@@ -3926,6 +3982,56 @@ impl From<HtmlEmbeddedContent> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for HtmlMetavariable {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_METAVARIABLE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_METAVARIABLE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlMetavariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("HtmlMetavariable")
+                .field(
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("HtmlMetavariable").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<HtmlMetavariable> for SyntaxNode {
+    fn from(n: HtmlMetavariable) -> Self {
+        n.syntax
+    }
+}
+impl From<HtmlMetavariable> for SyntaxElement {
+    fn from(n: HtmlMetavariable) -> Self {
+        n.syntax.into()
+    }
+}
 impl AstNode for HtmlOpeningElement {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -6542,6 +6648,11 @@ impl From<HtmlBogusAttribute> for AnyHtmlAttribute {
         Self::HtmlBogusAttribute(node)
     }
 }
+impl From<HtmlMetavariable> for AnyHtmlAttribute {
+    fn from(node: HtmlMetavariable) -> Self {
+        Self::HtmlMetavariable(node)
+    }
+}
 impl From<SvelteAttachAttribute> for AnyHtmlAttribute {
     fn from(node: SvelteAttachAttribute) -> Self {
         Self::SvelteAttachAttribute(node)
@@ -6554,6 +6665,7 @@ impl AstNode for AnyHtmlAttribute {
         .union(HtmlAttributeDoubleTextExpression::KIND_SET)
         .union(HtmlAttributeSingleTextExpression::KIND_SET)
         .union(HtmlBogusAttribute::KIND_SET)
+        .union(HtmlMetavariable::KIND_SET)
         .union(SvelteAttachAttribute::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
@@ -6561,6 +6673,7 @@ impl AstNode for AnyHtmlAttribute {
             | HTML_ATTRIBUTE_DOUBLE_TEXT_EXPRESSION
             | HTML_ATTRIBUTE_SINGLE_TEXT_EXPRESSION
             | HTML_BOGUS_ATTRIBUTE
+            | HTML_METAVARIABLE
             | SVELTE_ATTACH_ATTRIBUTE => true,
             k if AnyVueDirective::can_cast(k) => true,
             _ => false,
@@ -6580,6 +6693,7 @@ impl AstNode for AnyHtmlAttribute {
                 })
             }
             HTML_BOGUS_ATTRIBUTE => Self::HtmlBogusAttribute(HtmlBogusAttribute { syntax }),
+            HTML_METAVARIABLE => Self::HtmlMetavariable(HtmlMetavariable { syntax }),
             SVELTE_ATTACH_ATTRIBUTE => {
                 Self::SvelteAttachAttribute(SvelteAttachAttribute { syntax })
             }
@@ -6598,6 +6712,7 @@ impl AstNode for AnyHtmlAttribute {
             Self::HtmlAttributeDoubleTextExpression(it) => it.syntax(),
             Self::HtmlAttributeSingleTextExpression(it) => it.syntax(),
             Self::HtmlBogusAttribute(it) => it.syntax(),
+            Self::HtmlMetavariable(it) => it.syntax(),
             Self::SvelteAttachAttribute(it) => it.syntax(),
             Self::AnyVueDirective(it) => it.syntax(),
         }
@@ -6608,6 +6723,7 @@ impl AstNode for AnyHtmlAttribute {
             Self::HtmlAttributeDoubleTextExpression(it) => it.into_syntax(),
             Self::HtmlAttributeSingleTextExpression(it) => it.into_syntax(),
             Self::HtmlBogusAttribute(it) => it.into_syntax(),
+            Self::HtmlMetavariable(it) => it.into_syntax(),
             Self::SvelteAttachAttribute(it) => it.into_syntax(),
             Self::AnyVueDirective(it) => it.into_syntax(),
         }
@@ -6621,6 +6737,7 @@ impl std::fmt::Debug for AnyHtmlAttribute {
             Self::HtmlAttributeDoubleTextExpression(it) => std::fmt::Debug::fmt(it, f),
             Self::HtmlAttributeSingleTextExpression(it) => std::fmt::Debug::fmt(it, f),
             Self::HtmlBogusAttribute(it) => std::fmt::Debug::fmt(it, f),
+            Self::HtmlMetavariable(it) => std::fmt::Debug::fmt(it, f),
             Self::SvelteAttachAttribute(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -6633,6 +6750,7 @@ impl From<AnyHtmlAttribute> for SyntaxNode {
             AnyHtmlAttribute::HtmlAttributeDoubleTextExpression(it) => it.into_syntax(),
             AnyHtmlAttribute::HtmlAttributeSingleTextExpression(it) => it.into_syntax(),
             AnyHtmlAttribute::HtmlBogusAttribute(it) => it.into_syntax(),
+            AnyHtmlAttribute::HtmlMetavariable(it) => it.into_syntax(),
             AnyHtmlAttribute::SvelteAttachAttribute(it) => it.into_syntax(),
         }
     }
@@ -6648,6 +6766,11 @@ impl From<HtmlAttributeSingleTextExpression> for AnyHtmlAttributeInitializer {
         Self::HtmlAttributeSingleTextExpression(node)
     }
 }
+impl From<HtmlMetavariable> for AnyHtmlAttributeInitializer {
+    fn from(node: HtmlMetavariable) -> Self {
+        Self::HtmlMetavariable(node)
+    }
+}
 impl From<HtmlString> for AnyHtmlAttributeInitializer {
     fn from(node: HtmlString) -> Self {
         Self::HtmlString(node)
@@ -6655,10 +6778,14 @@ impl From<HtmlString> for AnyHtmlAttributeInitializer {
 }
 impl AstNode for AnyHtmlAttributeInitializer {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        HtmlAttributeSingleTextExpression::KIND_SET.union(HtmlString::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = HtmlAttributeSingleTextExpression::KIND_SET
+        .union(HtmlMetavariable::KIND_SET)
+        .union(HtmlString::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, HTML_ATTRIBUTE_SINGLE_TEXT_EXPRESSION | HTML_STRING)
+        matches!(
+            kind,
+            HTML_ATTRIBUTE_SINGLE_TEXT_EXPRESSION | HTML_METAVARIABLE | HTML_STRING
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
@@ -6667,6 +6794,7 @@ impl AstNode for AnyHtmlAttributeInitializer {
                     syntax,
                 })
             }
+            HTML_METAVARIABLE => Self::HtmlMetavariable(HtmlMetavariable { syntax }),
             HTML_STRING => Self::HtmlString(HtmlString { syntax }),
             _ => return None,
         };
@@ -6675,12 +6803,14 @@ impl AstNode for AnyHtmlAttributeInitializer {
     fn syntax(&self) -> &SyntaxNode {
         match self {
             Self::HtmlAttributeSingleTextExpression(it) => it.syntax(),
+            Self::HtmlMetavariable(it) => it.syntax(),
             Self::HtmlString(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
             Self::HtmlAttributeSingleTextExpression(it) => it.into_syntax(),
+            Self::HtmlMetavariable(it) => it.into_syntax(),
             Self::HtmlString(it) => it.into_syntax(),
         }
     }
@@ -6689,6 +6819,7 @@ impl std::fmt::Debug for AnyHtmlAttributeInitializer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::HtmlAttributeSingleTextExpression(it) => std::fmt::Debug::fmt(it, f),
+            Self::HtmlMetavariable(it) => std::fmt::Debug::fmt(it, f),
             Self::HtmlString(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -6697,6 +6828,7 @@ impl From<AnyHtmlAttributeInitializer> for SyntaxNode {
     fn from(n: AnyHtmlAttributeInitializer) -> Self {
         match n {
             AnyHtmlAttributeInitializer::HtmlAttributeSingleTextExpression(it) => it.into_syntax(),
+            AnyHtmlAttributeInitializer::HtmlMetavariable(it) => it.into_syntax(),
             AnyHtmlAttributeInitializer::HtmlString(it) => it.into_syntax(),
         }
     }
@@ -6796,6 +6928,11 @@ impl From<HtmlElement> for AnyHtmlElement {
         Self::HtmlElement(node)
     }
 }
+impl From<HtmlMetavariable> for AnyHtmlElement {
+    fn from(node: HtmlMetavariable) -> Self {
+        Self::HtmlMetavariable(node)
+    }
+}
 impl From<HtmlSelfClosingElement> for AnyHtmlElement {
     fn from(node: HtmlSelfClosingElement) -> Self {
         Self::HtmlSelfClosingElement(node)
@@ -6807,12 +6944,15 @@ impl AstNode for AnyHtmlElement {
         .union(HtmlBogusElement::KIND_SET)
         .union(HtmlCdataSection::KIND_SET)
         .union(HtmlElement::KIND_SET)
+        .union(HtmlMetavariable::KIND_SET)
         .union(HtmlSelfClosingElement::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
-            HTML_BOGUS_ELEMENT | HTML_CDATA_SECTION | HTML_ELEMENT | HTML_SELF_CLOSING_ELEMENT => {
-                true
-            }
+            HTML_BOGUS_ELEMENT
+            | HTML_CDATA_SECTION
+            | HTML_ELEMENT
+            | HTML_METAVARIABLE
+            | HTML_SELF_CLOSING_ELEMENT => true,
             k if AnyHtmlContent::can_cast(k) => true,
             _ => false,
         }
@@ -6822,6 +6962,7 @@ impl AstNode for AnyHtmlElement {
             HTML_BOGUS_ELEMENT => Self::HtmlBogusElement(HtmlBogusElement { syntax }),
             HTML_CDATA_SECTION => Self::HtmlCdataSection(HtmlCdataSection { syntax }),
             HTML_ELEMENT => Self::HtmlElement(HtmlElement { syntax }),
+            HTML_METAVARIABLE => Self::HtmlMetavariable(HtmlMetavariable { syntax }),
             HTML_SELF_CLOSING_ELEMENT => {
                 Self::HtmlSelfClosingElement(HtmlSelfClosingElement { syntax })
             }
@@ -6839,6 +6980,7 @@ impl AstNode for AnyHtmlElement {
             Self::HtmlBogusElement(it) => it.syntax(),
             Self::HtmlCdataSection(it) => it.syntax(),
             Self::HtmlElement(it) => it.syntax(),
+            Self::HtmlMetavariable(it) => it.syntax(),
             Self::HtmlSelfClosingElement(it) => it.syntax(),
             Self::AnyHtmlContent(it) => it.syntax(),
         }
@@ -6848,6 +6990,7 @@ impl AstNode for AnyHtmlElement {
             Self::HtmlBogusElement(it) => it.into_syntax(),
             Self::HtmlCdataSection(it) => it.into_syntax(),
             Self::HtmlElement(it) => it.into_syntax(),
+            Self::HtmlMetavariable(it) => it.into_syntax(),
             Self::HtmlSelfClosingElement(it) => it.into_syntax(),
             Self::AnyHtmlContent(it) => it.into_syntax(),
         }
@@ -6860,6 +7003,7 @@ impl std::fmt::Debug for AnyHtmlElement {
             Self::HtmlBogusElement(it) => std::fmt::Debug::fmt(it, f),
             Self::HtmlCdataSection(it) => std::fmt::Debug::fmt(it, f),
             Self::HtmlElement(it) => std::fmt::Debug::fmt(it, f),
+            Self::HtmlMetavariable(it) => std::fmt::Debug::fmt(it, f),
             Self::HtmlSelfClosingElement(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -6871,6 +7015,7 @@ impl From<AnyHtmlElement> for SyntaxNode {
             AnyHtmlElement::HtmlBogusElement(it) => it.into_syntax(),
             AnyHtmlElement::HtmlCdataSection(it) => it.into_syntax(),
             AnyHtmlElement::HtmlElement(it) => it.into_syntax(),
+            AnyHtmlElement::HtmlMetavariable(it) => it.into_syntax(),
             AnyHtmlElement::HtmlSelfClosingElement(it) => it.into_syntax(),
         }
     }
@@ -7579,6 +7724,11 @@ impl std::fmt::Display for HtmlEmbeddedContent {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for HtmlMetavariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for HtmlOpeningElement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -21,6 +21,9 @@ declare_lint_rule! {
     /// The rule is meant to catch those dependencies that aren't declared inside the closest `package.json`, and
     /// isn't meant to detect dependencies declared in other manifest files, e.g. the root `package.json` in a monorepo setting.
     ///
+    /// Likewise, other packages of the same workspace must be declared as dependencies before they're imported.
+    /// Workspaces are discovered from the `workspaces` field of `package.json` and from `pnpm-workspace.yaml`.
+    ///
     /// The rule ignores imports that are not valid package names.
     /// This includes internal imports that start with `#` and `@/` and imports with a protocol such as `node:`, `bun:`, `jsr:`, `https:`.
     ///
@@ -262,6 +265,15 @@ impl Rule for NoUndeclaredDependencies {
             }).note(markup! {
                 "You may want to consider moving it to the "<Emphasis>"dependencies"</Emphasis>" section."
             }))
+        } else if let Some(workspace_package_path) = ctx.find_workspace_package(package_name) {
+            let workspace_package_path = workspace_package_path
+                .strip_prefix(&cwd)
+                .unwrap_or(&workspace_package_path);
+            Some(diag.note(markup! {
+                <Emphasis>{package_name}</Emphasis>" is a package of your workspace, located in "<Emphasis>{workspace_package_path.as_str()}</Emphasis>"."
+            }).note(markup! {
+                "Add the dependency in your manifest, using the "<Emphasis>"workspace:*"</Emphasis>" version if your package manager supports it."
+            }))
         } else {
             Some(
                 diag.note(markup! { "This could lead to errors." })
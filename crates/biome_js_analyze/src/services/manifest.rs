@@ -6,6 +6,7 @@ use biome_analyze::{
 };
 use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode};
 use biome_package::PackageJson;
+use biome_project_layout::ProjectLayout;
 use biome_rowan::AstNode;
 use camino::Utf8PathBuf;
 
@@ -17,6 +18,10 @@ pub struct ManifestServices {
 
     /// Contents of the `package.json` in the package.
     pub(crate) manifest: Option<Arc<PackageJson>>,
+
+    /// The project layout, used for looking up the other packages of the
+    /// workspace.
+    project_layout: Option<Arc<ProjectLayout>>,
 }
 
 impl ManifestServices {
@@ -47,6 +52,17 @@ impl ManifestServices {
             .as_deref()
             .is_some_and(|pkg| pkg.optional_dependencies.contains(specifier))
     }
+
+    /// Returns the path of the package named `package_name`, if it's another
+    /// package of the workspace that the current package belongs to.
+    pub(crate) fn find_workspace_package(&self, package_name: &str) -> Option<Utf8PathBuf> {
+        let package_path = self.package_path.as_ref()?;
+        self.project_layout
+            .as_ref()?
+            .find_workspace_package_by_name(package_path, package_name)
+            .map(|(workspace_package_path, _)| workspace_package_path)
+            .filter(|workspace_package_path| workspace_package_path != package_path)
+    }
 }
 
 impl FromServices for ManifestServices {
@@ -64,9 +80,12 @@ impl FromServices for ManifestServices {
             None => (None, None),
         };
 
+        let project_layout: Option<&Arc<ProjectLayout>> = services.get_service();
+
         Ok(Self {
             package_path,
             manifest,
+            project_layout: project_layout.cloned(),
         })
     }
 }
@@ -72,6 +72,8 @@ pub enum RenameError {
         original_range: TextRange,
         new_name: String,
     },
+    /// The module at the given path imports the renamed symbol, but it can't be parsed.
+    CannotUpdateImports(String),
 }
 
 impl std::fmt::Display for RenameError {
@@ -93,6 +95,9 @@ impl std::fmt::Display for RenameError {
                     "encountered an error finding a declaration at the specified position"
                 )
             }
+            Self::CannotUpdateImports(path) => {
+                write!(f, "encountered an error updating the imports of \"{path}\"")
+            }
         }
     }
 }
@@ -114,6 +119,11 @@ impl Diagnostic for RenameError {
                     markup! { "Can't rename from "<Emphasis>{{original_name}}</Emphasis>" to "<Emphasis>{{new_name}}</Emphasis>"" }
                 )
             }
+            Self::CannotUpdateImports(path) => {
+                fmt.write_markup(
+                    markup! { "Can't rename the symbol, because the module "<Emphasis>{{path}}</Emphasis>" that imports it can't be parsed." }
+                )
+            }
         }
     }
 
@@ -4750,6 +4750,24 @@ impl TsEnumMemberBuilder {
         ))
     }
 }
+pub fn ts_exact_object_type(
+    l_curly_token: SyntaxToken,
+    l_pipe_token: SyntaxToken,
+    members: TsTypeMemberList,
+    r_pipe_token: SyntaxToken,
+    r_curly_token: SyntaxToken,
+) -> TsExactObjectType {
+    TsExactObjectType::unwrap_cast(SyntaxNode::new_detached(
+        JsSyntaxKind::TS_EXACT_OBJECT_TYPE,
+        [
+            Some(SyntaxElement::Token(l_curly_token)),
+            Some(SyntaxElement::Token(l_pipe_token)),
+            Some(SyntaxElement::Node(members.into_syntax())),
+            Some(SyntaxElement::Token(r_pipe_token)),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
 pub fn ts_export_as_namespace_clause(
     as_token: SyntaxToken,
     namespace_token: SyntaxToken,
@@ -5683,6 +5701,15 @@ impl TsMappedTypeReadonlyModifierClauseBuilder {
         ))
     }
 }
+pub fn ts_maybe_type(question_mark_token: SyntaxToken, ty: AnyTsType) -> TsMaybeType {
+    TsMaybeType::unwrap_cast(SyntaxNode::new_detached(
+        JsSyntaxKind::TS_MAYBE_TYPE,
+        [
+            Some(SyntaxElement::Token(question_mark_token)),
+            Some(SyntaxElement::Node(ty.into_syntax())),
+        ],
+    ))
+}
 pub fn ts_method_signature_class_member(
     modifiers: TsMethodSignatureModifierList,
     name: AnyJsClassMemberName,
@@ -5970,6 +5997,66 @@ pub fn ts_object_type(
         ],
     ))
 }
+pub fn ts_opaque_type_alias_declaration(
+    opaque_token: SyntaxToken,
+    type_token: SyntaxToken,
+    binding_identifier: AnyTsIdentifierBinding,
+    eq_token: SyntaxToken,
+    ty: AnyTsType,
+) -> TsOpaqueTypeAliasDeclarationBuilder {
+    TsOpaqueTypeAliasDeclarationBuilder {
+        opaque_token,
+        type_token,
+        binding_identifier,
+        eq_token,
+        ty,
+        type_parameters: None,
+        supertype: None,
+        semicolon_token: None,
+    }
+}
+pub struct TsOpaqueTypeAliasDeclarationBuilder {
+    opaque_token: SyntaxToken,
+    type_token: SyntaxToken,
+    binding_identifier: AnyTsIdentifierBinding,
+    eq_token: SyntaxToken,
+    ty: AnyTsType,
+    type_parameters: Option<TsTypeParameters>,
+    supertype: Option<TsTypeAnnotation>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl TsOpaqueTypeAliasDeclarationBuilder {
+    pub fn with_type_parameters(mut self, type_parameters: TsTypeParameters) -> Self {
+        self.type_parameters = Some(type_parameters);
+        self
+    }
+    pub fn with_supertype(mut self, supertype: TsTypeAnnotation) -> Self {
+        self.supertype = Some(supertype);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> TsOpaqueTypeAliasDeclaration {
+        TsOpaqueTypeAliasDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            JsSyntaxKind::TS_OPAQUE_TYPE_ALIAS_DECLARATION,
+            [
+                Some(SyntaxElement::Token(self.opaque_token)),
+                Some(SyntaxElement::Token(self.type_token)),
+                Some(SyntaxElement::Node(self.binding_identifier.into_syntax())),
+                self.type_parameters
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.supertype
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.eq_token)),
+                Some(SyntaxElement::Node(self.ty.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
 pub fn ts_optional_property_annotation(
     question_mark_token: SyntaxToken,
 ) -> TsOptionalPropertyAnnotationBuilder {
@@ -48,6 +48,7 @@ fn bench_js_formatter(criterion: &mut Criterion) {
                             let formatted = biome_js_formatter::format_node(
                                 JsFormatOptions::default(),
                                 root.syntax(),
+                                false,
                             )
                             .unwrap();
                             let printed = formatted.print();
@@ -1036,13 +1036,16 @@ fn handle_variable_declarator_comment(
                 | JsSyntaxKind::JS_ARRAY_EXPRESSION
                 | JsSyntaxKind::JS_TEMPLATE_EXPRESSION
                 | JsSyntaxKind::TS_OBJECT_TYPE
+                | JsSyntaxKind::TS_EXACT_OBJECT_TYPE
                 | JsSyntaxKind::TS_UNION_TYPE
         )
     }
 
     let enclosing = comment.enclosing_node();
     match enclosing.kind() {
-        JsSyntaxKind::JS_ASSIGNMENT_EXPRESSION | JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION => {
+        JsSyntaxKind::JS_ASSIGNMENT_EXPRESSION
+        | JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION
+        | JsSyntaxKind::TS_OPAQUE_TYPE_ALIAS_DECLARATION => {
             // Makes all comments preceding objects/arrays/templates or block comments leading comments of these nodes.
             // ```javascript
             // let a = // comment
@@ -45,6 +45,10 @@ pub struct JsFormatContext {
     cached_function_body: Option<(AnyJsFunctionBody, FormatElement)>,
 
     source_map: Option<TransformSourceMap>,
+
+    /// Whether the content of the template literals that embed other languages is
+    /// formatted by the formatters of those languages.
+    should_delegate_fmt_embedded_nodes: bool,
 }
 
 impl JsFormatContext {
@@ -54,6 +58,7 @@ impl JsFormatContext {
             comments: Rc::new(comments),
             cached_function_body: None,
             source_map: None,
+            should_delegate_fmt_embedded_nodes: false,
         }
     }
 
@@ -91,6 +96,15 @@ impl JsFormatContext {
         self.source_map = source_map;
         self
     }
+
+    pub fn with_fmt_embedded_nodes(mut self) -> Self {
+        self.should_delegate_fmt_embedded_nodes = true;
+        self
+    }
+
+    pub fn should_delegate_fmt_embedded_nodes(&self) -> bool {
+        self.should_delegate_fmt_embedded_nodes
+    }
 }
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
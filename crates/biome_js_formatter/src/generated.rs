@@ -6923,6 +6923,44 @@ impl IntoFormat<JsFormatContext> for biome_js_syntax::TsEnumMember {
         )
     }
 }
+impl FormatRule<biome_js_syntax::TsExactObjectType>
+    for crate::ts::types::exact_object_type::FormatTsExactObjectType
+{
+    type Context = JsFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_js_syntax::TsExactObjectType,
+        f: &mut JsFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_js_syntax::TsExactObjectType>::fmt(self, node, f)
+    }
+}
+impl AsFormat<JsFormatContext> for biome_js_syntax::TsExactObjectType {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_js_syntax::TsExactObjectType,
+        crate::ts::types::exact_object_type::FormatTsExactObjectType,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::ts::types::exact_object_type::FormatTsExactObjectType::default(),
+        )
+    }
+}
+impl IntoFormat<JsFormatContext> for biome_js_syntax::TsExactObjectType {
+    type Format = FormatOwnedWithRule<
+        biome_js_syntax::TsExactObjectType,
+        crate::ts::types::exact_object_type::FormatTsExactObjectType,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::ts::types::exact_object_type::FormatTsExactObjectType::default(),
+        )
+    }
+}
 impl FormatRule<biome_js_syntax::TsExportAsNamespaceClause>
     for crate::ts::module::export_as_namespace_clause::FormatTsExportAsNamespaceClause
 {
@@ -8022,6 +8060,38 @@ impl IntoFormat<JsFormatContext> for biome_js_syntax::TsMappedTypeReadonlyModifi
         FormatOwnedWithRule :: new (self , crate :: ts :: auxiliary :: mapped_type_readonly_modifier_clause :: FormatTsMappedTypeReadonlyModifierClause :: default ())
     }
 }
+impl FormatRule<biome_js_syntax::TsMaybeType> for crate::ts::types::maybe_type::FormatTsMaybeType {
+    type Context = JsFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_js_syntax::TsMaybeType, f: &mut JsFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_js_syntax::TsMaybeType>::fmt(self, node, f)
+    }
+}
+impl AsFormat<JsFormatContext> for biome_js_syntax::TsMaybeType {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_js_syntax::TsMaybeType,
+        crate::ts::types::maybe_type::FormatTsMaybeType,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::ts::types::maybe_type::FormatTsMaybeType::default(),
+        )
+    }
+}
+impl IntoFormat<JsFormatContext> for biome_js_syntax::TsMaybeType {
+    type Format = FormatOwnedWithRule<
+        biome_js_syntax::TsMaybeType,
+        crate::ts::types::maybe_type::FormatTsMaybeType,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::ts::types::maybe_type::FormatTsMaybeType::default(),
+        )
+    }
+}
 impl FormatRule<biome_js_syntax::TsMethodSignatureClassMember>
     for crate::ts::classes::method_signature_class_member::FormatTsMethodSignatureClassMember
 {
@@ -8476,6 +8546,38 @@ impl IntoFormat<JsFormatContext> for biome_js_syntax::TsObjectType {
         )
     }
 }
+impl FormatRule<biome_js_syntax::TsOpaqueTypeAliasDeclaration>
+    for crate::ts::declarations::opaque_type_alias_declaration::FormatTsOpaqueTypeAliasDeclaration
+{
+    type Context = JsFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_js_syntax::TsOpaqueTypeAliasDeclaration,
+        f: &mut JsFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_js_syntax::TsOpaqueTypeAliasDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<JsFormatContext> for biome_js_syntax::TsOpaqueTypeAliasDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_js_syntax::TsOpaqueTypeAliasDeclaration,
+        crate::ts::declarations::opaque_type_alias_declaration::FormatTsOpaqueTypeAliasDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: ts :: declarations :: opaque_type_alias_declaration :: FormatTsOpaqueTypeAliasDeclaration :: default ())
+    }
+}
+impl IntoFormat<JsFormatContext> for biome_js_syntax::TsOpaqueTypeAliasDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_js_syntax::TsOpaqueTypeAliasDeclaration,
+        crate::ts::declarations::opaque_type_alias_declaration::FormatTsOpaqueTypeAliasDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: ts :: declarations :: opaque_type_alias_declaration :: FormatTsOpaqueTypeAliasDeclaration :: default ())
+    }
+}
 impl FormatRule<biome_js_syntax::TsOptionalPropertyAnnotation>
     for crate::ts::auxiliary::optional_property_annotation::FormatTsOptionalPropertyAnnotation
 {
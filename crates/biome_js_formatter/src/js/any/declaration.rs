@@ -18,6 +18,7 @@ impl FormatRule<AnyJsDeclaration> for FormatAnyJsDeclaration {
             AnyJsDeclaration::TsImportEqualsDeclaration(node) => node.format().fmt(f),
             AnyJsDeclaration::TsInterfaceDeclaration(node) => node.format().fmt(f),
             AnyJsDeclaration::TsModuleDeclaration(node) => node.format().fmt(f),
+            AnyJsDeclaration::TsOpaqueTypeAliasDeclaration(node) => node.format().fmt(f),
             AnyJsDeclaration::TsTypeAliasDeclaration(node) => node.format().fmt(f),
         }
     }
@@ -18,6 +18,7 @@ impl FormatRule<AnyJsDeclarationClause> for FormatAnyJsDeclarationClause {
             AnyJsDeclarationClause::TsImportEqualsDeclaration(node) => node.format().fmt(f),
             AnyJsDeclarationClause::TsInterfaceDeclaration(node) => node.format().fmt(f),
             AnyJsDeclarationClause::TsModuleDeclaration(node) => node.format().fmt(f),
+            AnyJsDeclarationClause::TsOpaqueTypeAliasDeclaration(node) => node.format().fmt(f),
             AnyJsDeclarationClause::TsTypeAliasDeclaration(node) => node.format().fmt(f),
         }
     }
@@ -40,6 +40,7 @@ impl FormatRule<AnyJsStatement> for FormatAnyJsStatement {
             AnyJsStatement::TsImportEqualsDeclaration(node) => node.format().fmt(f),
             AnyJsStatement::TsInterfaceDeclaration(node) => node.format().fmt(f),
             AnyJsStatement::TsModuleDeclaration(node) => node.format().fmt(f),
+            AnyJsStatement::TsOpaqueTypeAliasDeclaration(node) => node.format().fmt(f),
             AnyJsStatement::TsTypeAliasDeclaration(node) => node.format().fmt(f),
         }
     }
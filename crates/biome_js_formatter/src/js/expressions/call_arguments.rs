@@ -67,7 +67,10 @@ impl FormatNodeRule<JsCallArguments> for FormatJsCallArguments {
         };
 
         if is_commonjs_or_amd_call?
-            || is_multiline_template_only_args(node)
+            || is_multiline_template_only_args(
+                node,
+                f.context().should_delegate_fmt_embedded_nodes(),
+            )
             || is_react_hook_with_deps_array(node, f.comments())
             || (is_test_call? && is_first_arg_string_literal_or_template)
         {
@@ -1204,13 +1207,21 @@ fn is_commonjs_or_amd_call(
 }
 
 /// Returns `true` if `arguments` contains a single [multiline template literal argument that starts on its own ](is_multiline_template_starting_on_same_line).
-fn is_multiline_template_only_args(arguments: &JsCallArguments) -> bool {
+///
+/// The content of the template can become multiline when the embedded nodes are formatted
+/// by the formatter of the language they embed, so any template is considered multiline in that case.
+fn is_multiline_template_only_args(
+    arguments: &JsCallArguments,
+    delegate_fmt_embedded_nodes: bool,
+) -> bool {
     let args = arguments.args();
 
     match args.first() {
         Some(Ok(AnyJsCallArgument::AnyJsExpression(AnyJsExpression::JsTemplateExpression(
             template,
-        )))) if args.len() == 1 => is_multiline_template_starting_on_same_line(&template),
+        )))) if args.len() == 1 => {
+            delegate_fmt_embedded_nodes || is_multiline_template_starting_on_same_line(&template)
+        }
         _ => false,
     }
 }
@@ -5,7 +5,7 @@ use biome_formatter::write;
 use biome_js_syntax::parentheses::NeedsParentheses;
 use biome_js_syntax::{AnyJsExpression, JsTemplateExpression, TsTemplateLiteralType};
 use biome_js_syntax::{JsSyntaxToken, TsTypeArguments};
-use biome_rowan::{SyntaxResult, declare_node_union};
+use biome_rowan::{SyntaxResult, TextRange, declare_node_union};
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsTemplateExpression;
@@ -36,7 +36,15 @@ impl Format<JsFormatContext> for AnyJsTemplate {
             ]
         )?;
 
-        self.write_elements(f)?;
+        // The content of template literals can be formatted by the formatter of the language
+        // they embed, if any, see `Formatted::format_embedded`
+        if let Some(range) = self.embedded_range(f) {
+            f.write_element(FormatElement::Tag(Tag::StartEmbedded(range)))?;
+            self.write_elements(f)?;
+            f.write_element(FormatElement::Tag(Tag::EndEmbedded))?;
+        } else {
+            self.write_elements(f)?;
+        }
 
         write!(f, [self.r_tick_token().format()])
     }
@@ -64,6 +72,19 @@ impl AnyJsTemplate {
         }
     }
 
+    /// Returns the range of the content of a template literal expression, between its backticks.
+    fn embedded_range(&self, f: &JsFormatter) -> Option<TextRange> {
+        if !f.context().should_delegate_fmt_embedded_nodes()
+            || !matches!(self, Self::JsTemplateExpression(_))
+        {
+            return None;
+        }
+
+        let start = self.l_tick_token().ok()?.text_trimmed_range().end();
+        let end = self.r_tick_token().ok()?.text_trimmed_range().start();
+        Some(TextRange::new(start, end))
+    }
+
     fn write_elements(&self, f: &mut JsFormatter) -> FormatResult<()> {
         match self {
             Self::JsTemplateExpression(template) => {
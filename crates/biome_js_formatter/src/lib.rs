@@ -528,10 +528,15 @@ impl FormatLanguage for JsFormatLanguage {
         self,
         root: &JsSyntaxNode,
         source_map: Option<TransformSourceMap>,
-        _delegate_fmt_embedded_nodes: bool,
+        delegate_fmt_embedded_nodes: bool,
     ) -> Self::Context {
         let comments = Comments::from_node(root, &JsCommentStyle, source_map.as_ref());
-        JsFormatContext::new(self.options, comments).with_source_map(source_map)
+        let context = JsFormatContext::new(self.options, comments).with_source_map(source_map);
+        if delegate_fmt_embedded_nodes {
+            context.with_fmt_embedded_nodes()
+        } else {
+            context
+        }
     }
 }
 
@@ -556,12 +561,20 @@ pub fn format_range(
 
 /// Formats a JavaScript (and its super languages) file based on its features.
 ///
+/// When `delegate_fmt_embedded_nodes` is `true`, the content of the tagged template literals
+/// is marked as embedded, so that it can be replaced with the output of another formatter.
+///
 /// It returns a [Formatted] result, which the user can use to override a file.
 pub fn format_node(
     options: JsFormatOptions,
     root: &JsSyntaxNode,
+    delegate_fmt_embedded_nodes: bool,
 ) -> FormatResult<Formatted<JsFormatContext>> {
-    biome_formatter::format_node(root, JsFormatLanguage::new(options), false)
+    biome_formatter::format_node(
+        root,
+        JsFormatLanguage::new(options),
+        delegate_fmt_embedded_nodes,
+    )
 }
 
 /// Formats a JavaScript (and its super languages) file based on its features.
@@ -18,12 +18,14 @@ impl FormatRule<AnyTsType> for FormatAnyTsType {
             AnyTsType::TsBooleanType(node) => node.format().fmt(f),
             AnyTsType::TsConditionalType(node) => node.format().fmt(f),
             AnyTsType::TsConstructorType(node) => node.format().fmt(f),
+            AnyTsType::TsExactObjectType(node) => node.format().fmt(f),
             AnyTsType::TsFunctionType(node) => node.format().fmt(f),
             AnyTsType::TsImportType(node) => node.format().fmt(f),
             AnyTsType::TsIndexedAccessType(node) => node.format().fmt(f),
             AnyTsType::TsInferType(node) => node.format().fmt(f),
             AnyTsType::TsIntersectionType(node) => node.format().fmt(f),
             AnyTsType::TsMappedType(node) => node.format().fmt(f),
+            AnyTsType::TsMaybeType(node) => node.format().fmt(f),
             AnyTsType::TsNeverType(node) => node.format().fmt(f),
             AnyTsType::TsNonPrimitiveType(node) => node.format().fmt(f),
             AnyTsType::TsNullLiteralType(node) => node.format().fmt(f),
@@ -8,4 +8,5 @@ pub(crate) mod global_declaration;
 pub(crate) mod import_equals_declaration;
 pub(crate) mod interface_declaration;
 pub(crate) mod module_declaration;
+pub(crate) mod opaque_type_alias_declaration;
 pub(crate) mod type_alias_declaration;
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use crate::utils::{AnyJsAssignmentLike, FormatStatementSemicolon};
+use biome_formatter::write;
+use biome_js_syntax::TsOpaqueTypeAliasDeclaration;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatTsOpaqueTypeAliasDeclaration;
+
+impl FormatNodeRule<TsOpaqueTypeAliasDeclaration> for FormatTsOpaqueTypeAliasDeclaration {
+    fn fmt_fields(
+        &self,
+        node: &TsOpaqueTypeAliasDeclaration,
+        f: &mut JsFormatter,
+    ) -> FormatResult<()> {
+        let opaque_token = node.opaque_token()?;
+        let type_token = node.type_token()?;
+        let semicolon = node.semicolon_token();
+        let assignment_like = format_with(|f| write!(f, [AnyJsAssignmentLike::from(node.clone())]));
+        write!(
+            f,
+            [
+                opaque_token.format(),
+                space(),
+                type_token.format(),
+                space(),
+                group(&assignment_like),
+                FormatStatementSemicolon::new(semicolon.as_ref())
+            ]
+        )
+    }
+}
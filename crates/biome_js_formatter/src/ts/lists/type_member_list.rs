@@ -3,6 +3,7 @@ use biome_formatter::{Buffer, write};
 use biome_js_syntax::{AnyTsTypeMember, TsTypeMemberList};
 
 use crate::context::Semicolons;
+use crate::context::trailing_commas::FormatTrailingCommas;
 use biome_rowan::AstNodeList;
 
 #[derive(Debug, Clone, Default)]
@@ -55,6 +56,15 @@ impl Format<JsFormatContext> for TsTypeMemberItem<'_> {
         if !is_verbatim {
             // Children don't format the separator on purpose, so it's up to the parent - this node,
             // to decide to print their separator
+            if f.options().source_type().is_flow() {
+                // Flow object types are conventionally separated by commas
+                return if self.last {
+                    write!(f, [FormatTrailingCommas::ES5])
+                } else {
+                    token(",").fmt(f)
+                };
+            }
+
             match f.options().semicolons() {
                 Semicolons::Always => {
                     if self.last {
@@ -9,11 +9,13 @@ use crate::ts::types::boolean_literal_type::FormatTsBooleanLiteralType;
 use crate::ts::types::boolean_type::FormatTsBooleanType;
 use crate::ts::types::conditional_type::FormatTsConditionalType;
 use crate::ts::types::constructor_type::FormatTsConstructorType;
+use crate::ts::types::exact_object_type::FormatTsExactObjectType;
 use crate::ts::types::function_type::FormatTsFunctionType;
 use crate::ts::types::indexed_access_type::FormatTsIndexedAccessType;
 use crate::ts::types::infer_type::FormatTsInferType;
 use crate::ts::types::intersection_type::FormatTsIntersectionType;
 use crate::ts::types::mapped_type::FormatTsMappedType;
+use crate::ts::types::maybe_type::FormatTsMaybeType;
 use crate::ts::types::never_type::FormatTsNeverType;
 use crate::ts::types::non_primitive_type::FormatTsNonPrimitiveType;
 use crate::ts::types::null_literal_type::FormatTsNullLiteralType;
@@ -118,6 +120,8 @@ impl Format<JsFormatContext> for FormatTypeVariant<'_> {
                     AnyTsType::TsNumberLiteralType(ty) => FormatTsNumberLiteralType.fmt_node(ty, f),
                     AnyTsType::TsNumberType(ty) => FormatTsNumberType.fmt_node(ty, f),
                     AnyTsType::TsObjectType(ty) => FormatTsObjectType.fmt_node(ty, f),
+                    AnyTsType::TsExactObjectType(ty) => FormatTsExactObjectType.fmt_node(ty, f),
+                    AnyTsType::TsMaybeType(ty) => FormatTsMaybeType.fmt_node(ty, f),
                     AnyTsType::TsParenthesizedType(ty) => FormatTsParenthesizedType.fmt_node(ty, f),
                     AnyTsType::TsReferenceType(ty) => FormatTsReferenceType.fmt_node(ty, f),
                     AnyTsType::TsStringLiteralType(ty) => FormatTsStringLiteralType.fmt_node(ty, f),
@@ -183,6 +187,7 @@ impl Format<JsFormatContext> for FormatTypeVariant<'_> {
         let has_already_formatted_dangling_comments = match node {
             AnyTsType::TsMappedType(_) => true,
             AnyTsType::TsObjectType(object) => object.members().is_empty(),
+            AnyTsType::TsExactObjectType(object) => object.members().is_empty(),
             AnyTsType::TsTupleType(tuple) => tuple.elements().is_empty(),
             _ => false,
         };
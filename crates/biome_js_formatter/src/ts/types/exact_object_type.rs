@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use crate::utils::JsObjectLike;
+
+use biome_formatter::write;
+use biome_js_syntax::TsExactObjectType;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatTsExactObjectType;
+
+impl FormatNodeRule<TsExactObjectType> for FormatTsExactObjectType {
+    fn fmt_fields(&self, node: &TsExactObjectType, f: &mut JsFormatter) -> FormatResult<()> {
+        write!(f, [JsObjectLike::from(node.clone())])
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &TsExactObjectType,
+        _: &mut JsFormatter,
+    ) -> FormatResult<()> {
+        // Formatted inside of `JsObjectLike`
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use crate::prelude::*;
+
+use biome_formatter::write;
+use biome_js_syntax::parentheses::NeedsParentheses;
+use biome_js_syntax::{TsMaybeType, TsMaybeTypeFields};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatTsMaybeType;
+
+impl FormatNodeRule<TsMaybeType> for FormatTsMaybeType {
+    fn fmt_fields(&self, node: &TsMaybeType, f: &mut JsFormatter) -> FormatResult<()> {
+        let TsMaybeTypeFields {
+            question_mark_token,
+            ty,
+        } = node.as_fields();
+
+        write![f, [question_mark_token.format(), ty.format()]]
+    }
+
+    fn needs_parentheses(&self, item: &TsMaybeType) -> bool {
+        item.needs_parentheses()
+    }
+}
@@ -9,11 +9,13 @@ pub(crate) mod boolean_literal_type;
 pub(crate) mod boolean_type;
 pub(crate) mod conditional_type;
 pub(crate) mod constructor_type;
+pub(crate) mod exact_object_type;
 pub(crate) mod function_type;
 pub(crate) mod indexed_access_type;
 pub(crate) mod infer_type;
 pub(crate) mod intersection_type;
 pub(crate) mod mapped_type;
+pub(crate) mod maybe_type;
 pub(crate) mod never_type;
 pub(crate) mod non_primitive_type;
 pub(crate) mod null_literal_type;
@@ -69,7 +69,10 @@ impl FormatNodeRule<TsUnionType> for FormatTsUnionType {
 
             // These parents have indent for their content, so we don't need to indent here
             !match parent_kind {
-                Some(JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION) => has_leading_comments,
+                Some(
+                    JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION
+                    | JsSyntaxKind::TS_OPAQUE_TYPE_ALIAS_DECLARATION,
+                ) => has_leading_comments,
                 parent_kind => {
                     matches!(
                         parent_kind,
@@ -17,8 +17,9 @@ use biome_js_syntax::{
     JsObjectAssignmentPatternProperty, JsObjectBindingPattern, JsPropertyClassMember,
     JsPropertyClassMemberFields, JsPropertyObjectMember, JsSyntaxKind, JsVariableDeclarator,
     TsConditionalType, TsInitializedPropertySignatureClassMember,
-    TsInitializedPropertySignatureClassMemberFields, TsPropertySignatureClassMember,
-    TsPropertySignatureClassMemberFields, TsTypeAliasDeclaration, TsTypeArguments, TsUnionType,
+    TsInitializedPropertySignatureClassMemberFields, TsOpaqueTypeAliasDeclaration,
+    TsPropertySignatureClassMember, TsPropertySignatureClassMemberFields, TsTypeAliasDeclaration,
+    TsTypeArguments, TsUnionType,
 };
 use biome_js_syntax::{AnyJsLiteralExpression, JsUnaryExpression};
 use biome_rowan::{AstNode, SyntaxNodeOptionExt, SyntaxResult, declare_node_union};
@@ -31,6 +32,7 @@ declare_node_union! {
         JsObjectAssignmentPatternProperty |
         JsVariableDeclarator |
         TsTypeAliasDeclaration |
+        TsOpaqueTypeAliasDeclaration |
         JsPropertyClassMember |
         TsPropertySignatureClassMember |
         TsInitializedPropertySignatureClassMember
@@ -334,6 +336,9 @@ impl AnyJsAssignmentLike {
             Self::TsTypeAliasDeclaration(type_alias_declaration) => {
                 type_alias_declaration.ty()?.into()
             }
+            Self::TsOpaqueTypeAliasDeclaration(opaque_type_alias_declaration) => {
+                opaque_type_alias_declaration.ty()?.into()
+            }
             Self::JsPropertyClassMember(n) => {
                 // SAFETY: Calling `unwrap` here is safe because we check `has_only_left_hand_side` variant at the beginning of the `layout` function
                 n.value().unwrap().into()
@@ -361,6 +366,9 @@ impl AnyJsAssignmentLike {
             Self::TsTypeAliasDeclaration(type_alias_declaration) => {
                 Ok(type_alias_declaration.binding_identifier()?.into())
             }
+            Self::TsOpaqueTypeAliasDeclaration(opaque_type_alias_declaration) => {
+                Ok(opaque_type_alias_declaration.binding_identifier()?.into())
+            }
             Self::JsPropertyClassMember(property_class_member) => {
                 Ok(property_class_member.name()?.into())
             }
@@ -444,6 +452,26 @@ impl AnyJsAssignmentLike {
                 }
                 Ok(false)
             }
+            Self::TsOpaqueTypeAliasDeclaration(opaque_type_alias_declaration) => {
+                let binding_identifier = opaque_type_alias_declaration.binding_identifier()?;
+                let type_parameters = opaque_type_alias_declaration.type_parameters();
+                let supertype = opaque_type_alias_declaration.supertype();
+
+                write!(f, [binding_identifier.format()])?;
+                if let Some(type_parameters) = type_parameters {
+                    write!(
+                        f,
+                        [type_parameters
+                            .format()
+                            .with_options(FormatTsTypeParametersOptions {
+                                group_id: None,
+                                is_type_or_interface_decl: true
+                            }),]
+                    )?;
+                }
+                write!(f, [supertype.format()])?;
+                Ok(false)
+            }
             Self::JsPropertyClassMember(property_class_member) => {
                 let JsPropertyClassMemberFields {
                     modifiers,
@@ -529,6 +557,10 @@ impl AnyJsAssignmentLike {
                 let eq_token = type_alias_declaration.eq_token()?;
                 write!(f, [space(), eq_token.format()])
             }
+            Self::TsOpaqueTypeAliasDeclaration(opaque_type_alias_declaration) => {
+                let eq_token = opaque_type_alias_declaration.eq_token()?;
+                write!(f, [space(), eq_token.format()])
+            }
             Self::JsPropertyClassMember(property_class_member) => {
                 if let Some(initializer) = property_class_member.value() {
                     let eq_token = initializer.eq_token()?;
@@ -593,6 +625,10 @@ impl AnyJsAssignmentLike {
                 let ty = type_alias_declaration.ty()?;
                 write!(f, [space(), ty.format()])
             }
+            Self::TsOpaqueTypeAliasDeclaration(opaque_type_alias_declaration) => {
+                let ty = opaque_type_alias_declaration.ty()?;
+                write!(f, [space(), ty.format()])
+            }
             Self::JsPropertyClassMember(property_class_member) => {
                 if let Some(initializer) = property_class_member.value() {
                     let expression = initializer.expression()?;
@@ -638,6 +674,7 @@ impl AnyJsAssignmentLike {
             | Self::JsAssignmentExpression(_)
             | Self::JsObjectAssignmentPatternProperty(_)
             | Self::TsTypeAliasDeclaration(_)
+            | Self::TsOpaqueTypeAliasDeclaration(_)
             | Self::TsPropertySignatureClassMember(_) => {
                 unreachable!("These variants have no initializer")
             }
@@ -682,7 +719,7 @@ impl AnyJsAssignmentLike {
 
         // For type aliases, check for leading comments before checking if we should break the left-hand side.
         // This ensures comments after the `=` sign are properly indented.
-        if let Self::TsTypeAliasDeclaration(_) = self
+        if let Self::TsTypeAliasDeclaration(_) | Self::TsOpaqueTypeAliasDeclaration(_) = self
             && self.should_break_after_operator(&right, f)?
         {
             return Ok(AssignmentLikeLayout::BreakAfterOperator);
@@ -1289,6 +1326,13 @@ fn is_short_argument(
                 }
                 _ => true,
             },
+            // The content of the template can become multiline when it's formatted by the
+            // formatter of the language it embeds
+            AnyJsExpression::JsTemplateExpression(_)
+                if f.context().should_delegate_fmt_embedded_nodes() =>
+            {
+                false
+            }
             AnyJsExpression::JsTemplateExpression(template) => {
                 let elements = template.elements();
 
@@ -1337,6 +1381,7 @@ fn is_complex_type_arguments(type_arguments: TsTypeArguments) -> SyntaxResult<bo
                 AnyTsType::TsUnionType(_)
                     | AnyTsType::TsIntersectionType(_)
                     | AnyTsType::TsObjectType(_)
+                    | AnyTsType::TsExactObjectType(_)
             )
         });
 
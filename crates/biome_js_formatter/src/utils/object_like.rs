@@ -3,25 +3,35 @@ use crate::prelude::*;
 use biome_formatter::{Expand, write};
 use biome_formatter::{Format, FormatResult};
 use biome_js_syntax::{
-    JsFormalParameter, JsObjectExpression, JsParameterList, JsSyntaxToken, TsObjectType,
-    TsTypeAnnotation,
+    JsFormalParameter, JsObjectExpression, JsParameterList, JsSyntaxToken, TsExactObjectType,
+    TsObjectType, TsTypeAnnotation,
 };
 use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, SyntaxResult, declare_node_union};
 
 declare_node_union! {
-    pub (crate) JsObjectLike = JsObjectExpression | TsObjectType
+    pub (crate) JsObjectLike = JsObjectExpression | TsObjectType | TsExactObjectType
 }
 impl JsObjectLike {
     fn l_curly_token(&self) -> SyntaxResult<JsSyntaxToken> {
         match self {
             Self::JsObjectExpression(oe) => oe.l_curly_token(),
             Self::TsObjectType(ot) => ot.l_curly_token(),
+            Self::TsExactObjectType(ot) => ot.l_curly_token(),
+        }
+    }
+
+    /// Returns the pipes of the exact object types of Flow: `{| a: string |}`
+    fn pipe_tokens(&self) -> SyntaxResult<Option<(JsSyntaxToken, JsSyntaxToken)>> {
+        match self {
+            Self::JsObjectExpression(_) | Self::TsObjectType(_) => Ok(None),
+            Self::TsExactObjectType(ot) => Ok(Some((ot.l_pipe_token()?, ot.r_pipe_token()?))),
         }
     }
     fn r_curly_token(&self) -> SyntaxResult<JsSyntaxToken> {
         match self {
             Self::JsObjectExpression(oe) => oe.r_curly_token(),
             Self::TsObjectType(ot) => ot.r_curly_token(),
+            Self::TsExactObjectType(ot) => ot.r_curly_token(),
         }
     }
 
@@ -29,6 +39,7 @@ impl JsObjectLike {
         match self {
             Self::JsObjectExpression(oe) => oe.members().syntax().has_leading_newline(),
             Self::TsObjectType(ot) => ot.members().syntax().has_leading_newline(),
+            Self::TsExactObjectType(ot) => ot.members().syntax().has_leading_newline(),
         }
     }
 
@@ -36,6 +47,7 @@ impl JsObjectLike {
         match self {
             Self::JsObjectExpression(oe) => oe.members().is_empty(),
             Self::TsObjectType(ot) => ot.members().is_empty(),
+            Self::TsExactObjectType(ot) => ot.members().is_empty(),
         }
     }
 
@@ -47,6 +59,9 @@ impl JsObjectLike {
             Self::TsObjectType(ot) => {
                 write!(f, [ot.members().format()])
             }
+            Self::TsExactObjectType(ot) => {
+                write!(f, [ot.members().format()])
+            }
         }
     }
 }
@@ -54,10 +69,18 @@ impl JsObjectLike {
 impl Format<JsFormatContext> for JsObjectLike {
     fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
         let members = format_with(|f| self.write_members(f));
+        let pipe_tokens = self.pipe_tokens()?;
 
         write!(f, [self.l_curly_token().format(),])?;
+        if let Some((l_pipe_token, _)) = &pipe_tokens {
+            write!(f, [l_pipe_token.format()])?;
+        }
 
         if self.members_are_empty() {
+            // `{||}` would be lexed as `{`, `||` and `}`, keep the pipes apart
+            if pipe_tokens.is_some() && !f.comments().has_dangling_comments(self.syntax()) {
+                write!(f, [space()])?;
+            }
             write!(
                 f,
                 [format_dangling_comments(self.syntax()).with_block_indent(),]
@@ -93,6 +116,9 @@ impl Format<JsFormatContext> for JsObjectLike {
             }
         }
 
+        if let Some((_, r_pipe_token)) = &pipe_tokens {
+            write!(f, [r_pipe_token.format()])?;
+        }
         write!(f, [self.r_curly_token().format()])
     }
 }
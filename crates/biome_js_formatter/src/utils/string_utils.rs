@@ -214,7 +214,7 @@ pub(crate) enum SourceFileKind {
 
 impl From<JsFileSource> for SourceFileKind {
     fn from(st: JsFileSource) -> Self {
-        if st.language().is_typescript() {
+        if st.language().is_typescript() || st.language().is_flow() {
             Self::TypeScript
         } else {
             Self::JavaScript
@@ -11,7 +11,10 @@ use biome_rowan::AstSeparatedList;
 ///     };
 /// ```
 pub(crate) fn is_object_like_type(ty: &AnyTsType) -> bool {
-    matches!(ty, AnyTsType::TsMappedType(_) | AnyTsType::TsObjectType(_))
+    matches!(
+        ty,
+        AnyTsType::TsMappedType(_) | AnyTsType::TsObjectType(_) | AnyTsType::TsExactObjectType(_)
+    )
 }
 
 /// Utility function that checks if the current type is can categorized as "simple"
@@ -64,7 +67,9 @@ pub(crate) fn should_hug_type(ty: &AnyTsType, f: &Formatter<JsFormatContext>) ->
         let has_object_type = iter.any(|ty| {
             matches!(
                 ty,
-                Ok(AnyTsType::TsObjectType(_) | AnyTsType::TsReferenceType(_))
+                Ok(AnyTsType::TsObjectType(_)
+                    | AnyTsType::TsExactObjectType(_)
+                    | AnyTsType::TsReferenceType(_))
             )
         });
 
@@ -18,6 +18,10 @@ mod formatter {
         tests_macros::gen_tests! {"tests/specs/jsx/**/*.jsx", crate::spec_test::run, "module"}
     }
 
+    mod flow_module {
+        tests_macros::gen_tests! {"tests/specs/flow/**/*.flow", crate::spec_test::run, "module"}
+    }
+
     mod tsx_module {
         tests_macros::gen_tests! {"tests/specs/tsx/**/*.tsx", crate::spec_test::run, "module"}
     }
@@ -1037,6 +1037,7 @@ impl<'src> JsLexer<'src> {
             b"string" => STRING_KW,
             b"symbol" => SYMBOL_KW,
             b"type" => TYPE_KW,
+            b"opaque" => OPAQUE_KW,
             b"undefined" => UNDEFINED_KW,
             b"unique" => UNIQUE_KW,
             b"unknown" => UNKNOWN_KW,
@@ -90,7 +90,9 @@ pub enum JsSyntaxFeature {
     #[doc(alias = "LooseMode")]
     SloppyMode,
     StrictMode,
+    /// The type syntax, which Flow files share with TypeScript files
     TypeScript,
+    Flow,
     Jsx,
 }
 
@@ -101,7 +103,11 @@ impl SyntaxFeature for JsSyntaxFeature {
         match self {
             Self::SloppyMode => p.state().strict().is_none(),
             Self::StrictMode => p.state().strict().is_some(),
-            Self::TypeScript => p.source_type().language().is_typescript(),
+            Self::TypeScript => {
+                let language = p.source_type().language();
+                language.is_typescript() || language.is_flow()
+            }
+            Self::Flow => p.source_type().language().is_flow(),
             Self::Jsx => p.source_type().variant() == LanguageVariant::Jsx,
         }
     }
@@ -1,3 +1,4 @@
+use crate::JsSyntaxFeature::Flow;
 use crate::prelude::*;
 use crate::syntax::class::{parse_class_declaration, parse_decorators};
 use crate::syntax::function::parse_function_declaration;
@@ -7,10 +8,12 @@ use crate::syntax::stmt::{
     StatementContext, VariableDeclarationParent, is_nth_at_variable_declarations,
     parse_variable_declaration, semi,
 };
+use crate::syntax::typescript::ts_parse_error::flow_only_syntax_error;
 use crate::syntax::typescript::{
     is_nth_at_any_ts_namespace_declaration, is_nth_at_ts_interface_declaration,
-    parse_any_ts_namespace_declaration_clause, parse_ts_enum_declaration,
-    parse_ts_interface_declaration, parse_ts_type_alias_declaration,
+    is_nth_at_ts_opaque_type_alias_declaration, parse_any_ts_namespace_declaration_clause,
+    parse_ts_enum_declaration, parse_ts_interface_declaration,
+    parse_ts_opaque_type_alias_declaration, parse_ts_type_alias_declaration,
 };
 use crate::{Absent, JsParser, ParsedSyntax};
 use biome_js_syntax::JsSyntaxKind::{JS_BOGUS_STATEMENT, JS_VARIABLE_DECLARATION_CLAUSE};
@@ -64,6 +67,10 @@ pub(crate) fn is_nth_at_declaration_clause(p: &mut JsParser, n: usize) -> bool {
         return true;
     }
 
+    if is_nth_at_ts_opaque_type_alias_declaration(p, n) {
+        return true;
+    }
+
     if p.nth_at(n, T![async]) && p.nth_at(n + 1, T![function]) {
         return true;
     }
@@ -142,6 +149,11 @@ pub(crate) fn parse_declaration_clause(p: &mut JsParser, stmt_start_pos: TextSiz
             // declare type B = string | number & { a: string, b: number }
             parse_ts_type_alias_declaration(p)
         }
+        T![opaque] => Flow.parse_exclusive_syntax(
+            p,
+            parse_ts_opaque_type_alias_declaration,
+            |p, type_alias| flow_only_syntax_error(p, "opaque type alias", type_alias.range(p)),
+        ),
         T![interface] => {
             // test ts ts_ambient_interface
             // declare interface A { b: string, c: number }
@@ -25,9 +25,11 @@ use crate::syntax::function::{LineBreak, is_at_async_function, parse_function_de
 use crate::syntax::js_parse_error;
 use crate::syntax::js_parse_error::{decorators_not_allowed, expected_binding, expected_statement};
 use crate::syntax::module::parse_import_or_import_equals_declaration;
-use crate::syntax::typescript::ts_parse_error::{expected_ts_type, ts_only_syntax_error};
+use crate::syntax::typescript::ts_parse_error::{
+    expected_ts_type, flow_only_syntax_error, ts_only_syntax_error,
+};
 
-use crate::JsSyntaxFeature::{StrictMode, TypeScript};
+use crate::JsSyntaxFeature::{Flow, StrictMode, TypeScript};
 use crate::ParsedSyntax::{Absent, Present};
 use crate::span::Span;
 use crate::{JsParser, JsSyntaxFeature, ParseRecoveryTokenSet, parser};
@@ -346,6 +348,18 @@ pub(crate) fn parse_statement(p: &mut JsParser, context: StatementContext) -> Pa
                 |p, type_alias| ts_only_syntax_error(p, "type alias", type_alias.range(p)),
             )
         }
+        T![opaque] if is_nth_at_ts_opaque_type_alias_declaration(p, 0) => {
+            // test js opaque_variable
+            // let opaque;
+            // opaque = 1;
+            // opaque
+            // type;
+            Flow.parse_exclusive_syntax(
+                p,
+                parse_ts_opaque_type_alias_declaration,
+                |p, type_alias| flow_only_syntax_error(p, "opaque type alias", type_alias.range(p)),
+            )
+        }
         T![interface] if is_at_ts_interface_declaration(p) => {
             TypeScript.parse_exclusive_syntax(p, parse_ts_interface_declaration, |p, interface| {
                 ts_only_syntax_error(p, "interface", interface.range(p))
@@ -5,6 +5,7 @@ use crate::syntax::binding::{
 };
 use crate::syntax::class::parse_initializer_clause;
 use crate::syntax::expr::{ExpressionContext, is_nth_at_identifier, parse_name};
+use crate::syntax::metavariable::is_nth_at_metavariable;
 
 use super::ts_parse_error::expected_ts_enum_member;
 use crate::state::EnterAmbientContext;
@@ -17,7 +18,8 @@ use crate::syntax::stmt::{STMT_RECOVERY_SET, semi};
 use crate::syntax::typescript::ts_parse_error::expected_ts_type;
 use crate::syntax::typescript::{
     TypeContext, TypeMembers, expect_ts_type_list, parse_ts_identifier_binding,
-    parse_ts_implements_clause, parse_ts_name, parse_ts_type, parse_ts_type_parameters,
+    parse_ts_implements_clause, parse_ts_name, parse_ts_type, parse_ts_type_annotation,
+    parse_ts_type_parameters,
 };
 use crate::{Absent, JsParser, ParseRecoveryTokenSet, ParsedSyntax, Present, syntax};
 use biome_js_syntax::{JsSyntaxKind::*, *};
@@ -224,6 +226,48 @@ pub(crate) fn parse_ts_type_alias_declaration(p: &mut JsParser) -> ParsedSyntax
     Present(m.complete(p, TS_TYPE_ALIAS_DECLARATION))
 }
 
+// test flow flow_opaque_type_alias
+// opaque type A = string;
+// opaque type B<T>: A = Array<T>;
+// export opaque type C: A = string;
+//
+// test_err ts ts_opaque_type_alias
+// opaque type A = string;
+pub(crate) fn parse_ts_opaque_type_alias_declaration(p: &mut JsParser) -> ParsedSyntax {
+    if !p.at(T![opaque]) {
+        return Absent;
+    }
+
+    let start = p.cur_range().start();
+    let m = p.start();
+    p.expect(T![opaque]);
+    p.expect(T![type]);
+    parse_ts_identifier_binding(p, super::TsIdentifierContext::Type)
+        .or_add_diagnostic(p, expected_identifier);
+    parse_ts_type_parameters(
+        p,
+        TypeContext::default()
+            .and_allow_in_out_modifier(true)
+            .and_type_or_interface_declaration(true),
+    )
+    .ok();
+    parse_ts_type_annotation(p, TypeContext::default()).ok();
+    p.expect(T![=]);
+    parse_ts_type(p, TypeContext::default()).or_add_diagnostic(p, expected_ts_type);
+
+    semi(p, TextRange::new(start, p.cur_range().end()));
+
+    Present(m.complete(p, TS_OPAQUE_TYPE_ALIAS_DECLARATION))
+}
+
+pub(crate) fn is_nth_at_ts_opaque_type_alias_declaration(p: &mut JsParser, n: usize) -> bool {
+    p.nth_at(n, T![opaque])
+        && p.nth_at(n + 1, T![type])
+        && !p.has_nth_preceding_line_break(n + 1)
+        && !p.has_nth_preceding_line_break(n + 2)
+        && (is_nth_at_identifier(p, n + 2) || is_nth_at_metavariable(p, n + 2))
+}
+
 // test ts ts_declare_const_initializer
 // declare module test { const X; }
 //
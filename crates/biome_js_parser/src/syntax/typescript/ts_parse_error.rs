@@ -91,6 +91,18 @@ pub(crate) fn ts_only_syntax_error(
         ,range).with_hint( "TypeScript only syntax")
 }
 
+pub(crate) fn flow_only_syntax_error(
+    p: &JsParser,
+    syntax: &str,
+    range: TextRange,
+) -> ParseDiagnostic {
+    p.err_builder(
+        format!("{syntax} are a Flow only feature. Add the `@flow` pragma to your file or remove the syntax."),
+        range,
+    )
+    .with_hint("Flow only syntax")
+}
+
 pub(crate) fn ts_accessor_type_parameters_error(
     p: &JsParser,
     type_parameters: &CompletedMarker,
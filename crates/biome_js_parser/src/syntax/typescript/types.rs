@@ -25,7 +25,7 @@ use crate::syntax::object::{
 use crate::syntax::stmt::optional_semi;
 use crate::syntax::typescript::try_parse;
 use crate::syntax::typescript::ts_parse_error::{
-    expected_ts_type, expected_ts_type_parameter, infer_not_allowed,
+    expected_ts_type, expected_ts_type_parameter, flow_only_syntax_error, infer_not_allowed,
     ts_const_modifier_cannot_appear_on_a_type_parameter,
     ts_in_out_modifier_cannot_appear_on_a_type_parameter,
 };
@@ -33,7 +33,7 @@ use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
 use enumflags2::{BitFlags, bitflags, make_bitflags};
 use smallvec::SmallVec;
 
-use crate::JsSyntaxFeature::TypeScript;
+use crate::JsSyntaxFeature::{Flow, TypeScript};
 use crate::lexer::{JsLexContext, JsReLexContext};
 use crate::span::Span;
 use crate::syntax::class::parse_decorators;
@@ -694,7 +694,7 @@ fn parse_ts_union_or_intersection_type(
     } else {
         let first = ty_kind.parse_element(p, context);
 
-        if p.at(ty_kind.operator()) {
+        if p.at(ty_kind.operator()) && !is_at_ts_exact_object_type_end(p) {
             let list = first.precede(p);
 
             eat_ts_union_or_intersection_type_elements(p, ty_kind, context);
@@ -715,7 +715,7 @@ fn eat_ts_union_or_intersection_type_elements(
     ty_kind: IntersectionOrUnionType,
     context: TypeContext,
 ) {
-    while p.at(ty_kind.operator()) {
+    while p.at(ty_kind.operator()) && !is_at_ts_exact_object_type_end(p) {
         p.bump(ty_kind.operator());
 
         ty_kind
@@ -823,9 +823,42 @@ fn parse_ts_primary_type(p: &mut JsParser, context: TypeContext) -> ParsedSyntax
         return Present(m.complete(p, TS_TYPE_OPERATOR_TYPE));
     }
 
+    if p.at(T![?]) {
+        return Flow.parse_exclusive_syntax(
+            p,
+            |p| parse_ts_maybe_type(p, context),
+            |p, maybe_type| flow_only_syntax_error(p, "maybe types", maybe_type.range(p)),
+        );
+    }
+
     parse_postfix_type_or_higher(p, context.and_allow_conditional_types(true))
 }
 
+// test flow flow_maybe_type
+// let a: ?string;
+// let b: ?string[];
+// let c: ?() => void;
+// type D = ?A | B;
+// function e(f: ?{ g: number }): ?Array<?string> {}
+//
+// test_err ts ts_maybe_type
+// let a: ?string;
+fn parse_ts_maybe_type(p: &mut JsParser, context: TypeContext) -> ParsedSyntax {
+    if !p.at(T![?]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![?]);
+    if is_at_function_type(p) {
+        parse_ts_function_type(p, context)
+    } else {
+        parse_ts_primary_type(p, context)
+    }
+    .or_add_diagnostic(p, expected_ts_type);
+    Present(m.complete(p, TS_MAYBE_TYPE))
+}
+
 fn try_parse_constraint_of_infer_type(p: &mut JsParser, context: TypeContext) -> ParsedSyntax {
     if !p.at(T![extends]) {
         return Absent;
@@ -888,6 +921,11 @@ fn parse_ts_non_array_type(p: &mut JsParser, context: TypeContext) -> ParsedSynt
     // type K = never
     match p.cur() {
         T!['('] => parse_ts_parenthesized_type(p, context),
+        T!['{'] if p.nth_at(1, T![|]) => Flow.parse_exclusive_syntax(
+            p,
+            |p| parse_ts_exact_object_type(p, context),
+            |p, object_type| flow_only_syntax_error(p, "exact object types", object_type.range(p)),
+        ),
         T!['{'] => {
             if is_at_start_of_mapped_type(p) {
                 parse_ts_mapped_type(p, context)
@@ -1197,14 +1235,55 @@ fn parse_ts_object_type(p: &mut JsParser, context: TypeContext) -> ParsedSyntax
     Present(m.complete(p, TS_OBJECT_TYPE))
 }
 
+// test flow flow_exact_object_type
+// type A = {| a: string, b: number |};
+// type B = {| a: string; b?: number; |};
+// type C = {|
+//   a: string | number,
+//   b: {| c: boolean |}
+// |};
+//
+// test_err ts ts_exact_object_type
+// type A = {| a: string |};
+fn parse_ts_exact_object_type(p: &mut JsParser, context: TypeContext) -> ParsedSyntax {
+    if !p.at(T!['{']) || !p.nth_at(1, T![|]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['{']);
+    p.bump(T![|]);
+    TypeMembers::exact(context).parse_list(p);
+    p.expect(T![|]);
+    p.expect(T!['}']);
+    Present(m.complete(p, TS_EXACT_OBJECT_TYPE))
+}
+
+/// Returns `true` if the parser is at the closing `|}` of an exact object type.
+fn is_at_ts_exact_object_type_end(p: &mut JsParser) -> bool {
+    p.at(T![|]) && p.nth_at(1, T!['}'])
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct TypeMembers {
     context: TypeContext,
+    /// Whether the members belong to an exact object type, which ends with `|}`
+    exact: bool,
 }
 
 impl TypeMembers {
     pub fn new(context: TypeContext) -> Self {
-        Self { context }
+        Self {
+            context,
+            exact: false,
+        }
+    }
+
+    fn exact(context: TypeContext) -> Self {
+        Self {
+            context,
+            exact: true,
+        }
     }
 }
 
@@ -1219,13 +1298,17 @@ impl ParseNodeList for TypeMembers {
     }
 
     fn is_at_list_end(&self, p: &mut JsParser) -> bool {
-        p.at(T!['}'])
+        if self.exact {
+            is_at_ts_exact_object_type_end(p)
+        } else {
+            p.at(T!['}'])
+        }
     }
 
     fn recover(&mut self, p: &mut JsParser, member: ParsedSyntax) -> RecoveryResult {
         member.or_recover_with_token_set(
             p,
-            &ParseRecoveryTokenSet::new(JS_BOGUS, token_set![T!['}'], T![,], T![;]])
+            &ParseRecoveryTokenSet::new(JS_BOGUS, token_set![T!['}'], T![|], T![,], T![;]])
                 .enable_recovery_on_line_break(),
             expected_property_or_signature,
         )
@@ -2284,6 +2367,11 @@ fn parse_ts_type_member_semi(p: &mut JsParser) {
         return;
     }
 
+    // or be followed by the end of an exact object type
+    if is_at_ts_exact_object_type_end(p) {
+        return;
+    }
+
     // or a semicolon (possibly ASI)
     if !optional_semi(p) {
         let err = p
@@ -0,0 +1,4 @@
+let opaque;
+opaque = 1;
+opaque
+type;
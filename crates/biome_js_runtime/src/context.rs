@@ -8,11 +8,10 @@ use boa_engine::{
 };
 use camino::Utf8Path;
 
-use biome_analyze::RuleDiagnostic;
 use biome_resolver::FsWithResolverProxy;
 
 use crate::JsModuleLoader;
-use crate::plugin_api::JsPluginApi;
+use crate::plugin_api::{JsPluginApi, JsPluginDiagnostic};
 
 pub struct JsExecContext {
     ctx: Context,
@@ -37,7 +36,7 @@ impl JsExecContext {
     }
 
     #[inline]
-    pub fn pull_diagnostics(&mut self) -> Vec<RuleDiagnostic> {
+    pub fn pull_diagnostics(&mut self) -> Vec<JsPluginDiagnostic> {
         self.api.pull_diagnostics()
     }
 
@@ -4,3 +4,4 @@ mod plugin_api;
 
 pub use context::JsExecContext;
 pub use module_loader::JsModuleLoader;
+pub use plugin_api::{JsPluginDiagnostic, JsPluginFix};
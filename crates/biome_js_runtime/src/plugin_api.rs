@@ -3,14 +3,29 @@ use std::rc::Rc;
 
 use boa_engine::module::SyntheticModuleInitializer;
 use boa_engine::object::FunctionObjectBuilder;
-use boa_engine::{Context, JsNativeError, JsValue, Module, NativeFunction, js_string};
+use boa_engine::{Context, JsNativeError, JsResult, JsValue, Module, NativeFunction, js_string};
 
-use biome_analyze::RuleDiagnostic;
+use biome_analyze::{FixKind, RuleDiagnostic};
 use biome_diagnostics::{Severity, category};
 use biome_text_size::TextRange;
 
+/// A diagnostic registered by a JS plugin.
+#[derive(Debug)]
+pub struct JsPluginDiagnostic {
+    pub diagnostic: RuleDiagnostic,
+    pub fix: Option<JsPluginFix>,
+}
+
+/// A fix offered by a JS plugin, which replaces a range of the source text.
+#[derive(Debug)]
+pub struct JsPluginFix {
+    pub range: TextRange,
+    pub text: String,
+    pub fix_kind: FixKind,
+}
+
 pub(crate) struct JsPluginApi {
-    diagnostics: Rc<RefCell<Vec<RuleDiagnostic>>>,
+    diagnostics: Rc<RefCell<Vec<JsPluginDiagnostic>>>,
 }
 
 impl JsPluginApi {
@@ -26,10 +41,16 @@ impl JsPluginApi {
         // SAFETY: The closure doesn't capture any GC-managed values.
         let register_diagnostic = FunctionObjectBuilder::new(context.realm(), unsafe {
             NativeFunction::from_closure(move |_this, args, context| {
-                let [severity, message] = args else {
-                    return Err(JsNativeError::typ()
-                        .with_message("registerDiagnostic() expects two string arguments")
-                        .into());
+                let (severity, message, options) = match args {
+                    [severity, message] => (severity, message, &JsValue::undefined()),
+                    [severity, message, options] => (severity, message, options),
+                    _ => {
+                        return Err(JsNativeError::typ()
+                            .with_message(
+                                "registerDiagnostic() expects two string arguments, and an optional object",
+                            )
+                            .into());
+                    }
                 };
 
                 let severity =
@@ -46,14 +67,28 @@ impl JsPluginApi {
                             .into()),
                     };
 
-                let diagnostic = RuleDiagnostic::new(
-                    category!("plugin"),
-                    None::<TextRange>, // TODO: retrieve a span from the AST
-                    message.to_string(context)?.to_std_string_lossy(),
-                )
-                .with_severity(severity);
+                let message = message.to_string(context)?.to_std_string_lossy();
+
+                let (span, fix) = if options.is_undefined() {
+                    (None, None)
+                } else {
+                    let Some(options) = options.as_object() else {
+                        return Err(JsNativeError::typ()
+                            .with_message("registerDiagnostic() expects the options to be an object")
+                            .into());
+                    };
+
+                    let span = to_text_range(&options.get(js_string!("span"), context)?, context)?;
+                    let fix = to_fix(&options.get(js_string!("fix"), context)?, span, context)?;
+                    (span, fix)
+                };
+
+                let diagnostic = RuleDiagnostic::new(category!("plugin"), span, message)
+                    .with_severity(severity);
 
-                diagnostics.borrow_mut().push(diagnostic);
+                diagnostics
+                    .borrow_mut()
+                    .push(JsPluginDiagnostic { diagnostic, fix });
 
                 Ok(JsValue::undefined())
             })
@@ -79,7 +114,81 @@ impl JsPluginApi {
         )
     }
 
-    pub(crate) fn pull_diagnostics(&self) -> Vec<RuleDiagnostic> {
+    pub(crate) fn pull_diagnostics(&self) -> Vec<JsPluginDiagnostic> {
         std::mem::take(&mut self.diagnostics.borrow_mut())
     }
 }
+
+/// Converts a `[start, end]` array of offsets into a [TextRange].
+fn to_text_range(value: &JsValue, context: &mut Context) -> JsResult<Option<TextRange>> {
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    let Some(span) = value.as_object() else {
+        return Err(JsNativeError::typ()
+            .with_message("The span must be an array of two offsets: [start, end]")
+            .into());
+    };
+
+    let start = span.get(0, context)?.to_u32(context)?;
+    let end = span.get(1, context)?.to_u32(context)?;
+    if start > end {
+        return Err(JsNativeError::range()
+            .with_message("The start of the span must not be greater than its end")
+            .into());
+    }
+
+    Ok(Some(TextRange::new(start.into(), end.into())))
+}
+
+/// Converts a `{ text, span?, kind? }` object into a [JsPluginFix]. The fix
+/// replaces the span of the diagnostic, unless it has its own span.
+fn to_fix(
+    value: &JsValue,
+    span: Option<TextRange>,
+    context: &mut Context,
+) -> JsResult<Option<JsPluginFix>> {
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    let Some(fix) = value.as_object() else {
+        return Err(JsNativeError::typ()
+            .with_message("The fix must be an object")
+            .into());
+    };
+
+    let text = fix
+        .get(js_string!("text"), context)?
+        .to_string(context)?
+        .to_std_string_lossy();
+
+    let Some(range) = to_text_range(&fix.get(js_string!("span"), context)?, context)?.or(span)
+    else {
+        return Err(JsNativeError::typ()
+            .with_message("A fix requires a span, either on the diagnostic or on the fix")
+            .into());
+    };
+
+    let kind = fix.get(js_string!("kind"), context)?;
+    let fix_kind = if kind.is_undefined() {
+        FixKind::Unsafe
+    } else {
+        match kind.to_string(context)?.to_std_string_lossy().as_str() {
+            "safe" => FixKind::Safe,
+            "unsafe" => FixKind::Unsafe,
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message("Unexpected fix kind, expected one of: safe, unsafe")
+                    .into());
+            }
+        }
+    };
+
+    Ok(Some(JsPluginFix {
+        range,
+        text,
+        fix_kind,
+    }))
+}
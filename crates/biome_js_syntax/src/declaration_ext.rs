@@ -33,6 +33,9 @@ impl AnyJsDeclarationClause {
             Self::TsTypeAliasDeclaration(decl) => {
                 Some(AnyJsDeclaration::TsTypeAliasDeclaration(decl))
             }
+            Self::TsOpaqueTypeAliasDeclaration(decl) => {
+                Some(AnyJsDeclaration::TsOpaqueTypeAliasDeclaration(decl))
+            }
         }
     }
 }
@@ -96,6 +96,9 @@ pub enum Language {
     /// TypeScript source with or without JSX.
     /// `definition_file` must be true for `d.ts` files.
     TypeScript { definition_file: bool },
+
+    /// JavaScript source with [Flow](https://flow.org) type annotations, with or without JSX.
+    Flow,
 }
 
 impl Language {
@@ -105,6 +108,9 @@ impl Language {
     pub const fn is_typescript(&self) -> bool {
         matches!(self, Self::TypeScript { .. })
     }
+    pub const fn is_flow(&self) -> bool {
+        matches!(self, Self::Flow)
+    }
 
     pub const fn is_definition_file(&self) -> bool {
         matches!(
@@ -201,6 +207,14 @@ impl JsFileSource {
         Self::ts().with_variant(LanguageVariant::Jsx)
     }
 
+    /// language: Flow, variant: JSX, module_kind: Module, version: Latest
+    pub fn flow() -> Self {
+        Self {
+            language: Language::Flow,
+            ..Self::jsx()
+        }
+    }
+
     /// TypeScript definition file
     /// language: TS, ambient, variant: Standard, module_kind: Module, version: Latest
     pub fn d_ts() -> Self {
@@ -257,6 +271,11 @@ impl JsFileSource {
         self
     }
 
+    pub const fn with_language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
     pub const fn with_embedding_kind(mut self, kind: EmbeddingKind) -> Self {
         self.embedding_kind = kind;
         self
@@ -290,6 +309,10 @@ impl JsFileSource {
         self.language.is_typescript()
     }
 
+    pub const fn is_flow(&self) -> bool {
+        self.language.is_flow()
+    }
+
     pub const fn is_jsx(&self) -> bool {
         self.variant.is_jsx()
     }
@@ -322,6 +345,7 @@ impl JsFileSource {
                     LanguageVariant::Jsx => "tsx",
                 }
             }
+            Language::Flow => "flow",
         }
     }
 
@@ -357,6 +381,8 @@ impl JsFileSource {
             // Note: the extension passed to this function can contain dots,
             // this should be handled properly by the extension provider
             "d.ts" | "d.mts" | "d.cts" => Ok(Self::d_ts()),
+            // Flow declaration files, such as `index.js.flow`
+            "flow" => Ok(Self::flow()),
             // TODO: Remove once we have full support of astro files
             "astro" => Ok(Self::astro()),
             // TODO: Remove once we have full support of vue files
@@ -390,6 +416,7 @@ impl JsFileSource {
             "typescript" => Ok(Self::ts()),
             "javascriptreact" => Ok(Self::jsx()),
             "typescriptreact" => Ok(Self::tsx()),
+            "flow" => Ok(Self::flow()),
             // TODO: Remove once we have full support of astro files
             "astro" => Ok(Self::astro()),
             // TODO: Remove once we have full support of vue files
@@ -447,6 +474,7 @@ impl From<Language> for JsFileSource {
                     Self::ts()
                 }
             }
+            Language::Flow => Self::flow(),
         }
     }
 }
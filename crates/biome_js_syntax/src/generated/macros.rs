@@ -870,6 +870,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::TsEnumMember::new_unchecked(node) };
                     $body
                 }
+                $crate::JsSyntaxKind::TS_EXACT_OBJECT_TYPE => {
+                    let $pattern = unsafe { $crate::TsExactObjectType::new_unchecked(node) };
+                    $body
+                }
                 $crate::JsSyntaxKind::TS_EXPORT_AS_NAMESPACE_CLAUSE => {
                     let $pattern =
                         unsafe { $crate::TsExportAsNamespaceClause::new_unchecked(node) };
@@ -1017,6 +1021,10 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::TsMappedTypeReadonlyModifierClause::new_unchecked(node) };
                     $body
                 }
+                $crate::JsSyntaxKind::TS_MAYBE_TYPE => {
+                    let $pattern = unsafe { $crate::TsMaybeType::new_unchecked(node) };
+                    $body
+                }
                 $crate::JsSyntaxKind::TS_METHOD_SIGNATURE_CLASS_MEMBER => {
                     let $pattern =
                         unsafe { $crate::TsMethodSignatureClassMember::new_unchecked(node) };
@@ -1073,6 +1081,11 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::TsObjectType::new_unchecked(node) };
                     $body
                 }
+                $crate::JsSyntaxKind::TS_OPAQUE_TYPE_ALIAS_DECLARATION => {
+                    let $pattern =
+                        unsafe { $crate::TsOpaqueTypeAliasDeclaration::new_unchecked(node) };
+                    $body
+                }
                 $crate::JsSyntaxKind::TS_OPTIONAL_PROPERTY_ANNOTATION => {
                     let $pattern =
                         unsafe { $crate::TsOptionalPropertyAnnotation::new_unchecked(node) };
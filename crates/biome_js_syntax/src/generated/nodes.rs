@@ -9382,6 +9382,61 @@ pub struct TsEnumMemberFields {
     pub initializer: Option<JsInitializerClause>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TsExactObjectType {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TsExactObjectType {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TsExactObjectTypeFields {
+        TsExactObjectTypeFields {
+            l_curly_token: self.l_curly_token(),
+            l_pipe_token: self.l_pipe_token(),
+            members: self.members(),
+            r_pipe_token: self.r_pipe_token(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn l_pipe_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn members(&self) -> TsTypeMemberList {
+        support::list(&self.syntax, 2usize)
+    }
+    pub fn r_pipe_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 4usize)
+    }
+}
+impl Serialize for TsExactObjectType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TsExactObjectTypeFields {
+    pub l_curly_token: SyntaxResult<SyntaxToken>,
+    pub l_pipe_token: SyntaxResult<SyntaxToken>,
+    pub members: TsTypeMemberList,
+    pub r_pipe_token: SyntaxResult<SyntaxToken>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsExportAsNamespaceClause {
     pub(crate) syntax: SyntaxNode,
 }
@@ -10987,6 +11042,46 @@ pub struct TsMappedTypeReadonlyModifierClauseFields {
     pub readonly_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TsMaybeType {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TsMaybeType {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TsMaybeTypeFields {
+        TsMaybeTypeFields {
+            question_mark_token: self.question_mark_token(),
+            ty: self.ty(),
+        }
+    }
+    pub fn question_mark_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn ty(&self) -> SyntaxResult<AnyTsType> {
+        support::required_node(&self.syntax, 1usize)
+    }
+}
+impl Serialize for TsMaybeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TsMaybeTypeFields {
+    pub question_mark_token: SyntaxResult<SyntaxToken>,
+    pub ty: SyntaxResult<AnyTsType>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsMethodSignatureClassMember {
     pub(crate) syntax: SyntaxNode,
 }
@@ -11567,6 +11662,76 @@ pub struct TsObjectTypeFields {
     pub r_curly_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TsOpaqueTypeAliasDeclaration {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TsOpaqueTypeAliasDeclaration {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TsOpaqueTypeAliasDeclarationFields {
+        TsOpaqueTypeAliasDeclarationFields {
+            opaque_token: self.opaque_token(),
+            type_token: self.type_token(),
+            binding_identifier: self.binding_identifier(),
+            type_parameters: self.type_parameters(),
+            supertype: self.supertype(),
+            eq_token: self.eq_token(),
+            ty: self.ty(),
+            semicolon_token: self.semicolon_token(),
+        }
+    }
+    pub fn opaque_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn type_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn binding_identifier(&self) -> SyntaxResult<AnyTsIdentifierBinding> {
+        support::required_node(&self.syntax, 2usize)
+    }
+    pub fn type_parameters(&self) -> Option<TsTypeParameters> {
+        support::node(&self.syntax, 3usize)
+    }
+    pub fn supertype(&self) -> Option<TsTypeAnnotation> {
+        support::node(&self.syntax, 4usize)
+    }
+    pub fn eq_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 5usize)
+    }
+    pub fn ty(&self) -> SyntaxResult<AnyTsType> {
+        support::required_node(&self.syntax, 6usize)
+    }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 7usize)
+    }
+}
+impl Serialize for TsOpaqueTypeAliasDeclaration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TsOpaqueTypeAliasDeclarationFields {
+    pub opaque_token: SyntaxResult<SyntaxToken>,
+    pub type_token: SyntaxResult<SyntaxToken>,
+    pub binding_identifier: SyntaxResult<AnyTsIdentifierBinding>,
+    pub type_parameters: Option<TsTypeParameters>,
+    pub supertype: Option<TsTypeAnnotation>,
+    pub eq_token: SyntaxResult<SyntaxToken>,
+    pub ty: SyntaxResult<AnyTsType>,
+    pub semicolon_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsOptionalPropertyAnnotation {
     pub(crate) syntax: SyntaxNode,
 }
@@ -13930,6 +14095,7 @@ pub enum AnyJsDeclaration {
     TsImportEqualsDeclaration(TsImportEqualsDeclaration),
     TsInterfaceDeclaration(TsInterfaceDeclaration),
     TsModuleDeclaration(TsModuleDeclaration),
+    TsOpaqueTypeAliasDeclaration(TsOpaqueTypeAliasDeclaration),
     TsTypeAliasDeclaration(TsTypeAliasDeclaration),
 }
 impl AnyJsDeclaration {
@@ -13993,6 +14159,12 @@ impl AnyJsDeclaration {
             _ => None,
         }
     }
+    pub fn as_ts_opaque_type_alias_declaration(&self) -> Option<&TsOpaqueTypeAliasDeclaration> {
+        match &self {
+            Self::TsOpaqueTypeAliasDeclaration(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_ts_type_alias_declaration(&self) -> Option<&TsTypeAliasDeclaration> {
         match &self {
             Self::TsTypeAliasDeclaration(item) => Some(item),
@@ -14012,6 +14184,7 @@ pub enum AnyJsDeclarationClause {
     TsImportEqualsDeclaration(TsImportEqualsDeclaration),
     TsInterfaceDeclaration(TsInterfaceDeclaration),
     TsModuleDeclaration(TsModuleDeclaration),
+    TsOpaqueTypeAliasDeclaration(TsOpaqueTypeAliasDeclaration),
     TsTypeAliasDeclaration(TsTypeAliasDeclaration),
 }
 impl AnyJsDeclarationClause {
@@ -14075,6 +14248,12 @@ impl AnyJsDeclarationClause {
             _ => None,
         }
     }
+    pub fn as_ts_opaque_type_alias_declaration(&self) -> Option<&TsOpaqueTypeAliasDeclaration> {
+        match &self {
+            Self::TsOpaqueTypeAliasDeclaration(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_ts_type_alias_declaration(&self) -> Option<&TsTypeAliasDeclaration> {
         match &self {
             Self::TsTypeAliasDeclaration(item) => Some(item),
@@ -15208,6 +15387,7 @@ pub enum AnyJsStatement {
     TsImportEqualsDeclaration(TsImportEqualsDeclaration),
     TsInterfaceDeclaration(TsInterfaceDeclaration),
     TsModuleDeclaration(TsModuleDeclaration),
+    TsOpaqueTypeAliasDeclaration(TsOpaqueTypeAliasDeclaration),
     TsTypeAliasDeclaration(TsTypeAliasDeclaration),
 }
 impl AnyJsStatement {
@@ -15403,6 +15583,12 @@ impl AnyJsStatement {
             _ => None,
         }
     }
+    pub fn as_ts_opaque_type_alias_declaration(&self) -> Option<&TsOpaqueTypeAliasDeclaration> {
+        match &self {
+            Self::TsOpaqueTypeAliasDeclaration(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_ts_type_alias_declaration(&self) -> Option<&TsTypeAliasDeclaration> {
         match &self {
             Self::TsTypeAliasDeclaration(item) => Some(item),
@@ -16081,12 +16267,14 @@ pub enum AnyTsType {
     TsBooleanType(TsBooleanType),
     TsConditionalType(TsConditionalType),
     TsConstructorType(TsConstructorType),
+    TsExactObjectType(TsExactObjectType),
     TsFunctionType(TsFunctionType),
     TsImportType(TsImportType),
     TsIndexedAccessType(TsIndexedAccessType),
     TsInferType(TsInferType),
     TsIntersectionType(TsIntersectionType),
     TsMappedType(TsMappedType),
+    TsMaybeType(TsMaybeType),
     TsNeverType(TsNeverType),
     TsNonPrimitiveType(TsNonPrimitiveType),
     TsNullLiteralType(TsNullLiteralType),
@@ -16169,6 +16357,12 @@ impl AnyTsType {
             _ => None,
         }
     }
+    pub fn as_ts_exact_object_type(&self) -> Option<&TsExactObjectType> {
+        match &self {
+            Self::TsExactObjectType(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_ts_function_type(&self) -> Option<&TsFunctionType> {
         match &self {
             Self::TsFunctionType(item) => Some(item),
@@ -16205,6 +16399,12 @@ impl AnyTsType {
             _ => None,
         }
     }
+    pub fn as_ts_maybe_type(&self) -> Option<&TsMaybeType> {
+        match &self {
+            Self::TsMaybeType(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_ts_never_type(&self) -> Option<&TsNeverType> {
         match &self {
             Self::TsNeverType(item) => Some(item),
@@ -27506,6 +27706,69 @@ impl From<TsEnumMember> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for TsExactObjectType {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TS_EXACT_OBJECT_TYPE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TS_EXACT_OBJECT_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TsExactObjectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("TsExactObjectType")
+                .field(
+                    "l_curly_token",
+                    &support::DebugSyntaxResult(self.l_curly_token()),
+                )
+                .field(
+                    "l_pipe_token",
+                    &support::DebugSyntaxResult(self.l_pipe_token()),
+                )
+                .field("members", &self.members())
+                .field(
+                    "r_pipe_token",
+                    &support::DebugSyntaxResult(self.r_pipe_token()),
+                )
+                .field(
+                    "r_curly_token",
+                    &support::DebugSyntaxResult(self.r_curly_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("TsExactObjectType").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<TsExactObjectType> for SyntaxNode {
+    fn from(n: TsExactObjectType) -> Self {
+        n.syntax
+    }
+}
+impl From<TsExactObjectType> for SyntaxElement {
+    fn from(n: TsExactObjectType) -> Self {
+        n.syntax.into()
+    }
+}
 impl AstNode for TsExportAsNamespaceClause {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -29390,6 +29653,57 @@ impl From<TsMappedTypeReadonlyModifierClause> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for TsMaybeType {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TS_MAYBE_TYPE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TS_MAYBE_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TsMaybeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("TsMaybeType")
+                .field(
+                    "question_mark_token",
+                    &support::DebugSyntaxResult(self.question_mark_token()),
+                )
+                .field("ty", &support::DebugSyntaxResult(self.ty()))
+                .finish()
+        } else {
+            f.debug_struct("TsMaybeType").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<TsMaybeType> for SyntaxNode {
+    fn from(n: TsMaybeType) -> Self {
+        n.syntax
+    }
+}
+impl From<TsMaybeType> for SyntaxElement {
+    fn from(n: TsMaybeType) -> Self {
+        n.syntax.into()
+    }
+}
 impl AstNode for TsMethodSignatureClassMember {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -30095,6 +30409,75 @@ impl From<TsObjectType> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for TsOpaqueTypeAliasDeclaration {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TS_OPAQUE_TYPE_ALIAS_DECLARATION as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TS_OPAQUE_TYPE_ALIAS_DECLARATION
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TsOpaqueTypeAliasDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("TsOpaqueTypeAliasDeclaration")
+                .field(
+                    "opaque_token",
+                    &support::DebugSyntaxResult(self.opaque_token()),
+                )
+                .field("type_token", &support::DebugSyntaxResult(self.type_token()))
+                .field(
+                    "binding_identifier",
+                    &support::DebugSyntaxResult(self.binding_identifier()),
+                )
+                .field(
+                    "type_parameters",
+                    &support::DebugOptionalElement(self.type_parameters()),
+                )
+                .field(
+                    "supertype",
+                    &support::DebugOptionalElement(self.supertype()),
+                )
+                .field("eq_token", &support::DebugSyntaxResult(self.eq_token()))
+                .field("ty", &support::DebugSyntaxResult(self.ty()))
+                .field(
+                    "semicolon_token",
+                    &support::DebugOptionalElement(self.semicolon_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("TsOpaqueTypeAliasDeclaration").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<TsOpaqueTypeAliasDeclaration> for SyntaxNode {
+    fn from(n: TsOpaqueTypeAliasDeclaration) -> Self {
+        n.syntax
+    }
+}
+impl From<TsOpaqueTypeAliasDeclaration> for SyntaxElement {
+    fn from(n: TsOpaqueTypeAliasDeclaration) -> Self {
+        n.syntax.into()
+    }
+}
 impl AstNode for TsOptionalPropertyAnnotation {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -33745,6 +34128,11 @@ impl From<TsModuleDeclaration> for AnyJsDeclaration {
         Self::TsModuleDeclaration(node)
     }
 }
+impl From<TsOpaqueTypeAliasDeclaration> for AnyJsDeclaration {
+    fn from(node: TsOpaqueTypeAliasDeclaration) -> Self {
+        Self::TsOpaqueTypeAliasDeclaration(node)
+    }
+}
 impl From<TsTypeAliasDeclaration> for AnyJsDeclaration {
     fn from(node: TsTypeAliasDeclaration) -> Self {
         Self::TsTypeAliasDeclaration(node)
@@ -33762,6 +34150,7 @@ impl AstNode for AnyJsDeclaration {
         .union(TsImportEqualsDeclaration::KIND_SET)
         .union(TsInterfaceDeclaration::KIND_SET)
         .union(TsModuleDeclaration::KIND_SET)
+        .union(TsOpaqueTypeAliasDeclaration::KIND_SET)
         .union(TsTypeAliasDeclaration::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
@@ -33776,6 +34165,7 @@ impl AstNode for AnyJsDeclaration {
                 | TS_IMPORT_EQUALS_DECLARATION
                 | TS_INTERFACE_DECLARATION
                 | TS_MODULE_DECLARATION
+                | TS_OPAQUE_TYPE_ALIAS_DECLARATION
                 | TS_TYPE_ALIAS_DECLARATION
         )
     }
@@ -33803,6 +34193,9 @@ impl AstNode for AnyJsDeclaration {
                 Self::TsInterfaceDeclaration(TsInterfaceDeclaration { syntax })
             }
             TS_MODULE_DECLARATION => Self::TsModuleDeclaration(TsModuleDeclaration { syntax }),
+            TS_OPAQUE_TYPE_ALIAS_DECLARATION => {
+                Self::TsOpaqueTypeAliasDeclaration(TsOpaqueTypeAliasDeclaration { syntax })
+            }
             TS_TYPE_ALIAS_DECLARATION => {
                 Self::TsTypeAliasDeclaration(TsTypeAliasDeclaration { syntax })
             }
@@ -33822,6 +34215,7 @@ impl AstNode for AnyJsDeclaration {
             Self::TsImportEqualsDeclaration(it) => it.syntax(),
             Self::TsInterfaceDeclaration(it) => it.syntax(),
             Self::TsModuleDeclaration(it) => it.syntax(),
+            Self::TsOpaqueTypeAliasDeclaration(it) => it.syntax(),
             Self::TsTypeAliasDeclaration(it) => it.syntax(),
         }
     }
@@ -33837,6 +34231,7 @@ impl AstNode for AnyJsDeclaration {
             Self::TsImportEqualsDeclaration(it) => it.into_syntax(),
             Self::TsInterfaceDeclaration(it) => it.into_syntax(),
             Self::TsModuleDeclaration(it) => it.into_syntax(),
+            Self::TsOpaqueTypeAliasDeclaration(it) => it.into_syntax(),
             Self::TsTypeAliasDeclaration(it) => it.into_syntax(),
         }
     }
@@ -33854,6 +34249,7 @@ impl std::fmt::Debug for AnyJsDeclaration {
             Self::TsImportEqualsDeclaration(it) => std::fmt::Debug::fmt(it, f),
             Self::TsInterfaceDeclaration(it) => std::fmt::Debug::fmt(it, f),
             Self::TsModuleDeclaration(it) => std::fmt::Debug::fmt(it, f),
+            Self::TsOpaqueTypeAliasDeclaration(it) => std::fmt::Debug::fmt(it, f),
             Self::TsTypeAliasDeclaration(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -33871,6 +34267,7 @@ impl From<AnyJsDeclaration> for SyntaxNode {
             AnyJsDeclaration::TsImportEqualsDeclaration(it) => it.into_syntax(),
             AnyJsDeclaration::TsInterfaceDeclaration(it) => it.into_syntax(),
             AnyJsDeclaration::TsModuleDeclaration(it) => it.into_syntax(),
+            AnyJsDeclaration::TsOpaqueTypeAliasDeclaration(it) => it.into_syntax(),
             AnyJsDeclaration::TsTypeAliasDeclaration(it) => it.into_syntax(),
         }
     }
@@ -33931,6 +34328,11 @@ impl From<TsModuleDeclaration> for AnyJsDeclarationClause {
         Self::TsModuleDeclaration(node)
     }
 }
+impl From<TsOpaqueTypeAliasDeclaration> for AnyJsDeclarationClause {
+    fn from(node: TsOpaqueTypeAliasDeclaration) -> Self {
+        Self::TsOpaqueTypeAliasDeclaration(node)
+    }
+}
 impl From<TsTypeAliasDeclaration> for AnyJsDeclarationClause {
     fn from(node: TsTypeAliasDeclaration) -> Self {
         Self::TsTypeAliasDeclaration(node)
@@ -33948,6 +34350,7 @@ impl AstNode for AnyJsDeclarationClause {
         .union(TsImportEqualsDeclaration::KIND_SET)
         .union(TsInterfaceDeclaration::KIND_SET)
         .union(TsModuleDeclaration::KIND_SET)
+        .union(TsOpaqueTypeAliasDeclaration::KIND_SET)
         .union(TsTypeAliasDeclaration::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
@@ -33962,6 +34365,7 @@ impl AstNode for AnyJsDeclarationClause {
                 | TS_IMPORT_EQUALS_DECLARATION
                 | TS_INTERFACE_DECLARATION
                 | TS_MODULE_DECLARATION
+                | TS_OPAQUE_TYPE_ALIAS_DECLARATION
                 | TS_TYPE_ALIAS_DECLARATION
         )
     }
@@ -33989,6 +34393,9 @@ impl AstNode for AnyJsDeclarationClause {
                 Self::TsInterfaceDeclaration(TsInterfaceDeclaration { syntax })
             }
             TS_MODULE_DECLARATION => Self::TsModuleDeclaration(TsModuleDeclaration { syntax }),
+            TS_OPAQUE_TYPE_ALIAS_DECLARATION => {
+                Self::TsOpaqueTypeAliasDeclaration(TsOpaqueTypeAliasDeclaration { syntax })
+            }
             TS_TYPE_ALIAS_DECLARATION => {
                 Self::TsTypeAliasDeclaration(TsTypeAliasDeclaration { syntax })
             }
@@ -34008,6 +34415,7 @@ impl AstNode for AnyJsDeclarationClause {
             Self::TsImportEqualsDeclaration(it) => it.syntax(),
             Self::TsInterfaceDeclaration(it) => it.syntax(),
             Self::TsModuleDeclaration(it) => it.syntax(),
+            Self::TsOpaqueTypeAliasDeclaration(it) => it.syntax(),
             Self::TsTypeAliasDeclaration(it) => it.syntax(),
         }
     }
@@ -34023,6 +34431,7 @@ impl AstNode for AnyJsDeclarationClause {
             Self::TsImportEqualsDeclaration(it) => it.into_syntax(),
             Self::TsInterfaceDeclaration(it) => it.into_syntax(),
             Self::TsModuleDeclaration(it) => it.into_syntax(),
+            Self::TsOpaqueTypeAliasDeclaration(it) => it.into_syntax(),
             Self::TsTypeAliasDeclaration(it) => it.into_syntax(),
         }
     }
@@ -34040,6 +34449,7 @@ impl std::fmt::Debug for AnyJsDeclarationClause {
             Self::TsImportEqualsDeclaration(it) => std::fmt::Debug::fmt(it, f),
             Self::TsInterfaceDeclaration(it) => std::fmt::Debug::fmt(it, f),
             Self::TsModuleDeclaration(it) => std::fmt::Debug::fmt(it, f),
+            Self::TsOpaqueTypeAliasDeclaration(it) => std::fmt::Debug::fmt(it, f),
             Self::TsTypeAliasDeclaration(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -34057,6 +34467,7 @@ impl From<AnyJsDeclarationClause> for SyntaxNode {
             AnyJsDeclarationClause::TsImportEqualsDeclaration(it) => it.into_syntax(),
             AnyJsDeclarationClause::TsInterfaceDeclaration(it) => it.into_syntax(),
             AnyJsDeclarationClause::TsModuleDeclaration(it) => it.into_syntax(),
+            AnyJsDeclarationClause::TsOpaqueTypeAliasDeclaration(it) => it.into_syntax(),
             AnyJsDeclarationClause::TsTypeAliasDeclaration(it) => it.into_syntax(),
         }
     }
@@ -36990,6 +37401,11 @@ impl From<TsModuleDeclaration> for AnyJsStatement {
         Self::TsModuleDeclaration(node)
     }
 }
+impl From<TsOpaqueTypeAliasDeclaration> for AnyJsStatement {
+    fn from(node: TsOpaqueTypeAliasDeclaration) -> Self {
+        Self::TsOpaqueTypeAliasDeclaration(node)
+    }
+}
 impl From<TsTypeAliasDeclaration> for AnyJsStatement {
     fn from(node: TsTypeAliasDeclaration) -> Self {
         Self::TsTypeAliasDeclaration(node)
@@ -37029,6 +37445,7 @@ impl AstNode for AnyJsStatement {
         .union(TsImportEqualsDeclaration::KIND_SET)
         .union(TsInterfaceDeclaration::KIND_SET)
         .union(TsModuleDeclaration::KIND_SET)
+        .union(TsOpaqueTypeAliasDeclaration::KIND_SET)
         .union(TsTypeAliasDeclaration::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
@@ -37065,6 +37482,7 @@ impl AstNode for AnyJsStatement {
                 | TS_IMPORT_EQUALS_DECLARATION
                 | TS_INTERFACE_DECLARATION
                 | TS_MODULE_DECLARATION
+                | TS_OPAQUE_TYPE_ALIAS_DECLARATION
                 | TS_TYPE_ALIAS_DECLARATION
         )
     }
@@ -37116,6 +37534,9 @@ impl AstNode for AnyJsStatement {
                 Self::TsInterfaceDeclaration(TsInterfaceDeclaration { syntax })
             }
             TS_MODULE_DECLARATION => Self::TsModuleDeclaration(TsModuleDeclaration { syntax }),
+            TS_OPAQUE_TYPE_ALIAS_DECLARATION => {
+                Self::TsOpaqueTypeAliasDeclaration(TsOpaqueTypeAliasDeclaration { syntax })
+            }
             TS_TYPE_ALIAS_DECLARATION => {
                 Self::TsTypeAliasDeclaration(TsTypeAliasDeclaration { syntax })
             }
@@ -37157,6 +37578,7 @@ impl AstNode for AnyJsStatement {
             Self::TsImportEqualsDeclaration(it) => it.syntax(),
             Self::TsInterfaceDeclaration(it) => it.syntax(),
             Self::TsModuleDeclaration(it) => it.syntax(),
+            Self::TsOpaqueTypeAliasDeclaration(it) => it.syntax(),
             Self::TsTypeAliasDeclaration(it) => it.syntax(),
         }
     }
@@ -37194,6 +37616,7 @@ impl AstNode for AnyJsStatement {
             Self::TsImportEqualsDeclaration(it) => it.into_syntax(),
             Self::TsInterfaceDeclaration(it) => it.into_syntax(),
             Self::TsModuleDeclaration(it) => it.into_syntax(),
+            Self::TsOpaqueTypeAliasDeclaration(it) => it.into_syntax(),
             Self::TsTypeAliasDeclaration(it) => it.into_syntax(),
         }
     }
@@ -37233,6 +37656,7 @@ impl std::fmt::Debug for AnyJsStatement {
             Self::TsImportEqualsDeclaration(it) => std::fmt::Debug::fmt(it, f),
             Self::TsInterfaceDeclaration(it) => std::fmt::Debug::fmt(it, f),
             Self::TsModuleDeclaration(it) => std::fmt::Debug::fmt(it, f),
+            Self::TsOpaqueTypeAliasDeclaration(it) => std::fmt::Debug::fmt(it, f),
             Self::TsTypeAliasDeclaration(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -37272,6 +37696,7 @@ impl From<AnyJsStatement> for SyntaxNode {
             AnyJsStatement::TsImportEqualsDeclaration(it) => it.into_syntax(),
             AnyJsStatement::TsInterfaceDeclaration(it) => it.into_syntax(),
             AnyJsStatement::TsModuleDeclaration(it) => it.into_syntax(),
+            AnyJsStatement::TsOpaqueTypeAliasDeclaration(it) => it.into_syntax(),
             AnyJsStatement::TsTypeAliasDeclaration(it) => it.into_syntax(),
         }
     }
@@ -39226,6 +39651,11 @@ impl From<TsConstructorType> for AnyTsType {
         Self::TsConstructorType(node)
     }
 }
+impl From<TsExactObjectType> for AnyTsType {
+    fn from(node: TsExactObjectType) -> Self {
+        Self::TsExactObjectType(node)
+    }
+}
 impl From<TsFunctionType> for AnyTsType {
     fn from(node: TsFunctionType) -> Self {
         Self::TsFunctionType(node)
@@ -39256,6 +39686,11 @@ impl From<TsMappedType> for AnyTsType {
         Self::TsMappedType(node)
     }
 }
+impl From<TsMaybeType> for AnyTsType {
+    fn from(node: TsMaybeType) -> Self {
+        Self::TsMaybeType(node)
+    }
+}
 impl From<TsNeverType> for AnyTsType {
     fn from(node: TsNeverType) -> Self {
         Self::TsNeverType(node)
@@ -39368,12 +39803,14 @@ impl AstNode for AnyTsType {
         .union(TsBooleanType::KIND_SET)
         .union(TsConditionalType::KIND_SET)
         .union(TsConstructorType::KIND_SET)
+        .union(TsExactObjectType::KIND_SET)
         .union(TsFunctionType::KIND_SET)
         .union(TsImportType::KIND_SET)
         .union(TsIndexedAccessType::KIND_SET)
         .union(TsInferType::KIND_SET)
         .union(TsIntersectionType::KIND_SET)
         .union(TsMappedType::KIND_SET)
+        .union(TsMaybeType::KIND_SET)
         .union(TsNeverType::KIND_SET)
         .union(TsNonPrimitiveType::KIND_SET)
         .union(TsNullLiteralType::KIND_SET)
@@ -39407,12 +39844,14 @@ impl AstNode for AnyTsType {
                 | TS_BOOLEAN_TYPE
                 | TS_CONDITIONAL_TYPE
                 | TS_CONSTRUCTOR_TYPE
+                | TS_EXACT_OBJECT_TYPE
                 | TS_FUNCTION_TYPE
                 | TS_IMPORT_TYPE
                 | TS_INDEXED_ACCESS_TYPE
                 | TS_INFER_TYPE
                 | TS_INTERSECTION_TYPE
                 | TS_MAPPED_TYPE
+                | TS_MAYBE_TYPE
                 | TS_NEVER_TYPE
                 | TS_NON_PRIMITIVE_TYPE
                 | TS_NULL_LITERAL_TYPE
@@ -39447,12 +39886,14 @@ impl AstNode for AnyTsType {
             TS_BOOLEAN_TYPE => Self::TsBooleanType(TsBooleanType { syntax }),
             TS_CONDITIONAL_TYPE => Self::TsConditionalType(TsConditionalType { syntax }),
             TS_CONSTRUCTOR_TYPE => Self::TsConstructorType(TsConstructorType { syntax }),
+            TS_EXACT_OBJECT_TYPE => Self::TsExactObjectType(TsExactObjectType { syntax }),
             TS_FUNCTION_TYPE => Self::TsFunctionType(TsFunctionType { syntax }),
             TS_IMPORT_TYPE => Self::TsImportType(TsImportType { syntax }),
             TS_INDEXED_ACCESS_TYPE => Self::TsIndexedAccessType(TsIndexedAccessType { syntax }),
             TS_INFER_TYPE => Self::TsInferType(TsInferType { syntax }),
             TS_INTERSECTION_TYPE => Self::TsIntersectionType(TsIntersectionType { syntax }),
             TS_MAPPED_TYPE => Self::TsMappedType(TsMappedType { syntax }),
+            TS_MAYBE_TYPE => Self::TsMaybeType(TsMaybeType { syntax }),
             TS_NEVER_TYPE => Self::TsNeverType(TsNeverType { syntax }),
             TS_NON_PRIMITIVE_TYPE => Self::TsNonPrimitiveType(TsNonPrimitiveType { syntax }),
             TS_NULL_LITERAL_TYPE => Self::TsNullLiteralType(TsNullLiteralType { syntax }),
@@ -39491,12 +39932,14 @@ impl AstNode for AnyTsType {
             Self::TsBooleanType(it) => it.syntax(),
             Self::TsConditionalType(it) => it.syntax(),
             Self::TsConstructorType(it) => it.syntax(),
+            Self::TsExactObjectType(it) => it.syntax(),
             Self::TsFunctionType(it) => it.syntax(),
             Self::TsImportType(it) => it.syntax(),
             Self::TsIndexedAccessType(it) => it.syntax(),
             Self::TsInferType(it) => it.syntax(),
             Self::TsIntersectionType(it) => it.syntax(),
             Self::TsMappedType(it) => it.syntax(),
+            Self::TsMaybeType(it) => it.syntax(),
             Self::TsNeverType(it) => it.syntax(),
             Self::TsNonPrimitiveType(it) => it.syntax(),
             Self::TsNullLiteralType(it) => it.syntax(),
@@ -39531,12 +39974,14 @@ impl AstNode for AnyTsType {
             Self::TsBooleanType(it) => it.into_syntax(),
             Self::TsConditionalType(it) => it.into_syntax(),
             Self::TsConstructorType(it) => it.into_syntax(),
+            Self::TsExactObjectType(it) => it.into_syntax(),
             Self::TsFunctionType(it) => it.into_syntax(),
             Self::TsImportType(it) => it.into_syntax(),
             Self::TsIndexedAccessType(it) => it.into_syntax(),
             Self::TsInferType(it) => it.into_syntax(),
             Self::TsIntersectionType(it) => it.into_syntax(),
             Self::TsMappedType(it) => it.into_syntax(),
+            Self::TsMaybeType(it) => it.into_syntax(),
             Self::TsNeverType(it) => it.into_syntax(),
             Self::TsNonPrimitiveType(it) => it.into_syntax(),
             Self::TsNullLiteralType(it) => it.into_syntax(),
@@ -39573,12 +40018,14 @@ impl std::fmt::Debug for AnyTsType {
             Self::TsBooleanType(it) => std::fmt::Debug::fmt(it, f),
             Self::TsConditionalType(it) => std::fmt::Debug::fmt(it, f),
             Self::TsConstructorType(it) => std::fmt::Debug::fmt(it, f),
+            Self::TsExactObjectType(it) => std::fmt::Debug::fmt(it, f),
             Self::TsFunctionType(it) => std::fmt::Debug::fmt(it, f),
             Self::TsImportType(it) => std::fmt::Debug::fmt(it, f),
             Self::TsIndexedAccessType(it) => std::fmt::Debug::fmt(it, f),
             Self::TsInferType(it) => std::fmt::Debug::fmt(it, f),
             Self::TsIntersectionType(it) => std::fmt::Debug::fmt(it, f),
             Self::TsMappedType(it) => std::fmt::Debug::fmt(it, f),
+            Self::TsMaybeType(it) => std::fmt::Debug::fmt(it, f),
             Self::TsNeverType(it) => std::fmt::Debug::fmt(it, f),
             Self::TsNonPrimitiveType(it) => std::fmt::Debug::fmt(it, f),
             Self::TsNullLiteralType(it) => std::fmt::Debug::fmt(it, f),
@@ -39615,12 +40062,14 @@ impl From<AnyTsType> for SyntaxNode {
             AnyTsType::TsBooleanType(it) => it.into_syntax(),
             AnyTsType::TsConditionalType(it) => it.into_syntax(),
             AnyTsType::TsConstructorType(it) => it.into_syntax(),
+            AnyTsType::TsExactObjectType(it) => it.into_syntax(),
             AnyTsType::TsFunctionType(it) => it.into_syntax(),
             AnyTsType::TsImportType(it) => it.into_syntax(),
             AnyTsType::TsIndexedAccessType(it) => it.into_syntax(),
             AnyTsType::TsInferType(it) => it.into_syntax(),
             AnyTsType::TsIntersectionType(it) => it.into_syntax(),
             AnyTsType::TsMappedType(it) => it.into_syntax(),
+            AnyTsType::TsMaybeType(it) => it.into_syntax(),
             AnyTsType::TsNeverType(it) => it.into_syntax(),
             AnyTsType::TsNonPrimitiveType(it) => it.into_syntax(),
             AnyTsType::TsNullLiteralType(it) => it.into_syntax(),
@@ -41370,6 +41819,11 @@ impl std::fmt::Display for TsEnumMember {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for TsExactObjectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for TsExportAsNamespaceClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -41535,6 +41989,11 @@ impl std::fmt::Display for TsMappedTypeReadonlyModifierClause {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for TsMaybeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for TsMethodSignatureClassMember {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -41600,6 +42059,11 @@ impl std::fmt::Display for TsObjectType {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for TsOpaqueTypeAliasDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for TsOptionalPropertyAnnotation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
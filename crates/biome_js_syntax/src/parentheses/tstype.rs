@@ -3,7 +3,7 @@ use biome_rowan::{AstNode, AstSeparatedList};
 use crate::{
     AnyTsReturnType, AnyTsType, JsSyntaxKind, JsSyntaxNode, TsConditionalType, TsConstructorType,
     TsFunctionType, TsIndexedAccessType, TsInferType, TsIntersectionType,
-    TsIntersectionTypeElementList, TsTypeOperatorType, TsTypeofType, TsUnionType,
+    TsIntersectionTypeElementList, TsMaybeType, TsTypeOperatorType, TsTypeofType, TsUnionType,
     TsUnionTypeVariantList, parentheses::NeedsParentheses, type_ext::AnyTsFunctionType,
 };
 
@@ -91,6 +91,16 @@ impl NeedsParentheses for TsTypeOperatorType {
     }
 }
 
+impl NeedsParentheses for TsMaybeType {
+    #[inline]
+    fn needs_parentheses(&self) -> bool {
+        let Some(parent) = self.syntax().parent() else {
+            return false;
+        };
+        operator_type_or_higher_needs_parens(self.syntax(), parent)
+    }
+}
+
 impl NeedsParentheses for TsTypeofType {
     fn needs_parentheses(&self) -> bool {
         let Some(parent) = self.syntax().parent() else {
@@ -143,6 +153,8 @@ fn function_like_type_needs_parentheses(node: &JsSyntaxNode) -> bool {
         return false;
     };
     match parent.kind() {
+        // Flow doesn't require parentheses around the function type of a maybe type: `?() => void`
+        JsSyntaxKind::TS_MAYBE_TYPE => false,
         JsSyntaxKind::TS_RETURN_TYPE_ANNOTATION => parent.parent().is_some_and(|grand_parent| {
             grand_parent.kind() == JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
         }),
@@ -185,6 +197,7 @@ fn operator_type_or_higher_needs_parens(node: &JsSyntaxNode, parent: JsSyntaxNod
     match parent.kind() {
         JsSyntaxKind::TS_ARRAY_TYPE
         | JsSyntaxKind::TS_TYPE_OPERATOR_TYPE
+        | JsSyntaxKind::TS_MAYBE_TYPE
         | JsSyntaxKind::TS_REST_TUPLE_TYPE_ELEMENT
         | JsSyntaxKind::TS_OPTIONAL_TUPLE_TYPE_ELEMENT => true,
         _ => TsIndexedAccessType::cast(parent)
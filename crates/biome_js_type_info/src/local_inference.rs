@@ -296,6 +296,10 @@ impl TypeData {
             AnyJsDeclaration::TsModuleDeclaration(decl) => {
                 Self::from_ts_module_declaration(decl).unwrap_or_default()
             }
+            AnyJsDeclaration::TsOpaqueTypeAliasDeclaration(_decl) => {
+                // TODO: Handle Flow opaque type aliases.
+                Self::unknown()
+            }
             AnyJsDeclaration::TsTypeAliasDeclaration(decl) => {
                 Self::from_ts_type_alias_declaration(resolver, scope_id, decl).unwrap_or_default()
             }
@@ -684,6 +688,14 @@ impl TypeData {
                     .ok()
                     .map(|ty| TypeReference::from_any_ts_type(resolver, scope_id, &ty)),
             })),
+            AnyTsType::TsExactObjectType(ty) => Self::object_with_members(
+                ty.members()
+                    .into_iter()
+                    .filter_map(|member| {
+                        TypeMember::from_any_ts_type_member(resolver, scope_id, &member)
+                    })
+                    .collect(),
+            ),
             AnyTsType::TsFunctionType(ty) => Self::Function(Box::new(Function {
                 is_async: false,
                 type_parameters: generic_params_from_ts_type_params(
@@ -722,6 +734,18 @@ impl TypeData {
                 // TODO: Handle mapped types (`type T<U> = { [K in keyof U]: V }`).
                 Self::unknown()
             }
+            AnyTsType::TsMaybeType(ty) => {
+                // Flow's `?T` is a shorthand for `T | null | undefined`.
+                let types = Box::new([
+                    ty.ty()
+                        .map(|ty| TypeReference::from_any_ts_type(resolver, scope_id, &ty))
+                        .unwrap_or_default(),
+                    resolver.reference_to_owned_data(Self::Null),
+                    GLOBAL_UNDEFINED_ID.into(),
+                ]);
+
+                Self::union_of(resolver, types)
+            }
             AnyTsType::TsNeverType(_) => Self::NeverKeyword,
             AnyTsType::TsNonPrimitiveType(_) => Self::ObjectKeyword,
             AnyTsType::TsNullLiteralType(_) => Self::Null,
@@ -64,6 +64,7 @@ fn bench_analyzer(criterion: &mut Criterion) {
                             let json_services = JsonAnalyzeServices {
                                 file_source,
                                 configuration_provider: None,
+                                json_schema: None,
                             };
 
                             biome_json_analyze::analyze(
@@ -71,6 +72,7 @@ fn bench_analyzer(criterion: &mut Criterion) {
                                 filter,
                                 &options,
                                 json_services,
+                                &[],
                                 |event| {
                                     black_box(event.diagnostic());
                                     black_box(event.actions());
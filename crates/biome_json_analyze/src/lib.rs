@@ -10,14 +10,16 @@ pub mod utils;
 
 pub use crate::registry::visit_registry;
 use crate::services::config_source::ConfigSource;
+use crate::services::json_schema::Schema;
 use crate::suppression_action::JsonSuppressionAction;
 pub use biome_analyze::ExtendedConfigurationProvider;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression, ControlFlow,
-    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    to_analyzer_suppressions,
+    AnalysisFilter, AnalyzerOptions, AnalyzerPluginSlice, AnalyzerSignal, AnalyzerSuppression,
+    ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry, Phases, PluginTargetLanguage,
+    PluginVisitor, RuleAction, RuleRegistry, to_analyzer_suppressions,
 };
 use biome_diagnostics::Error;
+use biome_json_schema::JsonSchema;
 use biome_json_syntax::{JsonFileSource, JsonLanguage, TextRange};
 use biome_suppression::{SuppressionDiagnostic, parse_suppression_comment};
 use std::ops::Deref;
@@ -37,6 +39,9 @@ pub struct JsonAnalyzeServices {
 
     /// The source file
     pub file_source: JsonFileSource,
+
+    /// The JSON Schema the document must satisfy, if any.
+    pub json_schema: Option<Arc<JsonSchema>>,
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -47,13 +52,22 @@ pub fn analyze<'a, F, B>(
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
     json_services: JsonAnalyzeServices,
+    plugins: AnalyzerPluginSlice<'a>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<JsonLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, json_services, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        json_services,
+        plugins,
+        emit_signal,
+    )
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -68,6 +82,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
     json_services: JsonAnalyzeServices,
+    plugins: AnalyzerPluginSlice<'a>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -124,6 +139,19 @@ where
 
     services.insert_service(json_services.configuration_provider);
     services.insert_service(json_services.file_source);
+    services.insert_service(json_services.json_schema);
+
+    for plugin in plugins {
+        // SAFETY: The plugin target language is correctly checked here.
+        unsafe {
+            if plugin.language() == PluginTargetLanguage::Json {
+                analyzer.add_visitor(
+                    Phases::Syntax,
+                    Box::new(PluginVisitor::new_unchecked(plugin.clone())),
+                )
+            }
+        }
+    }
 
     (
         analyzer.run(biome_analyze::AnalyzerContext {
@@ -176,6 +204,7 @@ mod tests {
         let services = JsonAnalyzeServices {
             file_source: JsonFileSource::json(),
             configuration_provider: None,
+            json_schema: None,
         };
         analyze(
             &parsed.tree(),
@@ -185,6 +214,7 @@ mod tests {
             },
             &options,
             services,
+            &[],
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -0,0 +1,89 @@
+use crate::Schema;
+use biome_analyze::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_json_schema::SchemaViolation;
+use biome_json_syntax::JsonRoot;
+use biome_rule_options::no_json_schema_violations::NoJsonSchemaViolationsOptions;
+
+declare_lint_rule! {
+    /// Enforce that documents satisfy their JSON Schema.
+    ///
+    /// The schema is either referenced by the `$schema` property of the document,
+    /// or assigned to the file with the `json.linter.schema` option of the
+    /// configuration, usually in `overrides`. Only local schemas are supported:
+    /// the path is resolved relative to the document or to the configuration file.
+    ///
+    /// The rule reports values with an incorrect type, missing required properties,
+    /// properties that aren't allowed, and values that don't satisfy the
+    /// constraints of the schema, such as `enum`, `pattern` or `minimum`.
+    ///
+    /// ## Examples
+    ///
+    /// Given the following `package.schema.json`:
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "type": "object",
+    ///     "required": ["name"],
+    ///     "additionalProperties": false,
+    ///     "properties": {
+    ///         "name": { "type": "string" },
+    ///         "private": { "type": "boolean" }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "$schema": "./package.schema.json",
+    ///     "name": 1,
+    ///     "privte": true
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "$schema": "./package.schema.json",
+    ///     "name": "biome",
+    ///     "private": true
+    /// }
+    /// ```
+    ///
+    pub NoJsonSchemaViolations {
+        version: "next",
+        name: "noJsonSchemaViolations",
+        language: "json",
+        recommended: false,
+    }
+}
+
+impl Rule for NoJsonSchemaViolations {
+    type Query = Schema<JsonRoot>;
+    type State = SchemaViolation;
+    type Signals = Box<[Self::State]>;
+    type Options = NoJsonSchemaViolationsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(schema) = ctx.json_schema() else {
+            return Box::default();
+        };
+        let Some(instance) = biome_json_schema::json::to_instance(ctx.query()) else {
+            return Box::default();
+        };
+        schema.validate(&instance).into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            state.range,
+            markup! {
+                {state.kind.to_string()}
+            },
+        ))
+    }
+}
@@ -1 +1,2 @@
 pub(crate) mod config_source;
+pub(crate) mod json_schema;
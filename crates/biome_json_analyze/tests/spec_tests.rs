@@ -1,14 +1,18 @@
-use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
+use biome_analyze::{
+    AnalysisFilter, AnalyzerAction, AnalyzerPluginSlice, ControlFlow, Never, RuleFilter,
+};
 use biome_configuration::{ConfigurationSource, ExtendedConfigurations};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
+use biome_fs::OsFileSystem;
 use biome_json_analyze::{ExtendedConfigurationProvider, JsonAnalyzeServices};
 use biome_json_parser::{JsonParserOptions, parse_json};
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use biome_plugin_loader::AnalyzerGritPlugin;
 use biome_rowan::AstNode;
 use biome_test_utils::{
     CheckActionType, assert_diagnostics_expectation_comment, assert_errors_are_absent,
     code_fix_to_string, create_analyzer_options, diagnostic_to_string,
-    has_bogus_nodes_or_empty_slots, load_configuration_source, parse_test_path,
+    has_bogus_nodes_or_empty_slots, load_configuration_source, load_json_schema, parse_test_path,
     register_leak_checker, write_analyzer_snapshot,
 };
 use camino::Utf8Path;
@@ -18,6 +22,7 @@ use std::{fs::read_to_string, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{json,jsonc}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{json,jsonc}", crate::run_suppression_test, "module"}
+tests_macros::gen_tests! {"tests/plugin/*.grit", crate::run_plugin_test, "module"}
 
 fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     register_leak_checker();
@@ -25,9 +30,10 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     let input_file = Utf8Path::new(input);
     let file_name = input_file.file_name().unwrap();
 
-    // We should skip running test for .options.json as input_file
+    // We should skip running test for .options.json and .schema.json as input_file
     if file_name.ends_with(".options.json")
         || file_name.ends_with(".options.jsonc")
+        || file_name.ends_with(".schema.json")
         || file_name.starts_with("_ignore")
     {
         return;
@@ -84,6 +90,7 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
         input_file,
         CheckActionType::Lint,
         parser_options,
+        &[],
     );
 
     insta::with_settings!({
@@ -132,6 +139,7 @@ fn run_suppression_test(input: &'static str, _: &str, _: &str, _: &str) {
         input_file,
         CheckActionType::Suppression,
         parser_options,
+        &[],
     );
 
     insta::with_settings!({
@@ -152,6 +160,7 @@ pub(crate) fn analyze_and_snap(
     input_file: &Utf8Path,
     action_type: CheckActionType,
     parser_options: JsonParserOptions,
+    plugins: AnalyzerPluginSlice,
 ) {
     let mut diagnostics = Vec::new();
     let parsed = parse_json(input_code, parser_options);
@@ -174,34 +183,37 @@ pub(crate) fn analyze_and_snap(
                 extended_configurations: ExtendedConfigurations::from(list),
             }) as Arc<dyn ExtendedConfigurationProvider>
         }),
+        json_schema: biome_json_schema::json::schema_reference(&root)
+            .and_then(|reference| load_json_schema(input_file, &reference)),
     };
-    let (_, errors) = biome_json_analyze::analyze(&root, filter, &options, services, |event| {
-        if let Some(mut diag) = event.diagnostic() {
-            for action in event.actions() {
-                if action.is_suppression() {
-                    if action_type.is_suppression() {
+    let (_, errors) =
+        biome_json_analyze::analyze(&root, filter, &options, services, plugins, |event| {
+            if let Some(mut diag) = event.diagnostic() {
+                for action in event.actions() {
+                    if action.is_suppression() {
+                        if action_type.is_suppression() {
+                            check_code_action(input_file, input_code, &action, parser_options);
+                            diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                        }
+                    } else if !action.is_suppression() {
                         check_code_action(input_file, input_code, &action, parser_options);
                         diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                     }
-                } else if !action.is_suppression() {
-                    check_code_action(input_file, input_code, &action, parser_options);
-                    diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                 }
-            }
 
-            diagnostics.push(diagnostic_to_string(file_name, input_code, diag.into()));
-            return ControlFlow::Continue(());
-        }
+                diagnostics.push(diagnostic_to_string(file_name, input_code, diag.into()));
+                return ControlFlow::Continue(());
+            }
 
-        for action in event.actions() {
-            if !action.is_suppression() {
-                check_code_action(input_file, input_code, &action, parser_options);
-                code_fixes.push(code_fix_to_string(input_code, action));
+            for action in event.actions() {
+                if !action.is_suppression() {
+                    check_code_action(input_file, input_code, &action, parser_options);
+                    code_fixes.push(code_fix_to_string(input_code, action));
+                }
             }
-        }
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        });
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -253,3 +265,50 @@ fn check_code_action(
     let re_parse = parse_json(&output, parser_options);
     assert_errors_are_absent(re_parse.tree().syntax(), re_parse.diagnostics(), path);
 }
+
+fn run_plugin_test(input: &'static str, _: &str, _: &str, _: &str) {
+    register_leak_checker();
+
+    let plugin_path = Utf8Path::new(input);
+    let file_name = plugin_path.file_name().unwrap();
+    let input_path = plugin_path.with_extension("json");
+
+    let plugin = match AnalyzerGritPlugin::load(
+        &OsFileSystem::new(plugin_path.to_owned()),
+        Utf8Path::new(plugin_path),
+    ) {
+        Ok(plugin) => plugin,
+        Err(err) => panic!("Cannot load plugin: {err:?}"),
+    };
+
+    let filter = AnalysisFilter {
+        enabled_rules: Some(&[]),
+        ..AnalysisFilter::default()
+    };
+
+    let mut snapshot = String::new();
+
+    let input_code = read_to_string(&input_path)
+        .unwrap_or_else(|err| panic!("failed to read {input_path:?}: {err:?}"));
+    let Ok(file_source) = input_path.as_path().try_into() else {
+        return;
+    };
+    analyze_and_snap(
+        &mut snapshot,
+        &input_code,
+        file_source,
+        filter,
+        file_name,
+        &input_path,
+        CheckActionType::Lint,
+        JsonParserOptions::default(),
+        &[Arc::new(Box::new(plugin))],
+    );
+
+    insta::with_settings!({
+        prepend_module_to_snapshot => false,
+        snapshot_path => plugin_path.parent().unwrap(),
+    }, {
+        insta::assert_snapshot!(file_name, snapshot, file_name);
+    });
+}
@@ -40,9 +40,15 @@ pub fn json_member(
         ],
     ))
 }
-pub fn json_member_name(value_token: SyntaxToken) -> JsonMemberName {
+pub fn json_member_name(value_token_token: SyntaxToken) -> JsonMemberName {
     JsonMemberName::unwrap_cast(SyntaxNode::new_detached(
         JsonSyntaxKind::JSON_MEMBER_NAME,
+        [Some(SyntaxElement::Token(value_token_token))],
+    ))
+}
+pub fn json_metavariable(value_token: SyntaxToken) -> JsonMetavariable {
+    JsonMetavariable::unwrap_cast(SyntaxNode::new_detached(
+        JsonSyntaxKind::JSON_METAVARIABLE,
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
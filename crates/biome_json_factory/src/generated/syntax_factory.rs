@@ -107,7 +107,7 @@ impl SyntaxFactory for JsonSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && element.kind() == JSON_STRING_LITERAL
+                    && matches!(element.kind(), JSON_STRING_LITERAL | IDENT)
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -121,6 +121,25 @@ impl SyntaxFactory for JsonSyntaxFactory {
                 }
                 slots.into_node(JSON_MEMBER_NAME, children)
             }
+            JSON_METAVARIABLE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element
+                    && element.kind() == GRIT_METAVARIABLE
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        JSON_METAVARIABLE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(JSON_METAVARIABLE, children)
+            }
             JSON_NULL_VALUE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
@@ -68,8 +68,10 @@ pub struct JsonFormatOptions {
     trailing_commas: TrailingCommas,
     expand: Expand,
     bracket_spacing: BracketSpacing,
+    /// Whether the keys of objects should be quoted in JSON5 files. Defaults to "preserve".
+    quote_properties: QuoteProperties,
     /// The kind of file
-    _file_source: JsonFileSource,
+    file_source: JsonFileSource,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
@@ -122,10 +124,60 @@ impl schemars::JsonSchema for TrailingCommas {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum QuoteProperties {
+    #[default]
+    /// The formatter keeps the keys as they are written.
+    Preserve,
+    /// The formatter removes the quotes of the keys that are valid identifiers. Only applies to JSON5 files.
+    AsNeeded,
+}
+
+impl FromStr for QuoteProperties {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(Self::Preserve),
+            "as-needed" => Ok(Self::AsNeeded),
+            _ => Err("Value not supported for QuoteProperties"),
+        }
+    }
+}
+
+impl fmt::Display for QuoteProperties {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Preserve => std::write!(f, "Preserve"),
+            Self::AsNeeded => std::write!(f, "As needed"),
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for QuoteProperties {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("JsonQuoteProperties")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "enum": ["preserve", "asNeeded"],
+            "description": "When the keys of objects should be quoted in JSON5 files."
+        })
+    }
+}
+
 impl JsonFormatOptions {
     pub fn new(file_source: JsonFileSource) -> Self {
         Self {
-            _file_source: file_source,
+            file_source,
             ..Default::default()
         }
     }
@@ -165,6 +217,11 @@ impl JsonFormatOptions {
         self
     }
 
+    pub fn with_quote_properties(mut self, quote_properties: QuoteProperties) -> Self {
+        self.quote_properties = quote_properties;
+        self
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -189,6 +246,10 @@ impl JsonFormatOptions {
         self.bracket_spacing = bracket_spacing;
     }
 
+    pub fn set_quote_properties(&mut self, quote_properties: QuoteProperties) {
+        self.quote_properties = quote_properties;
+    }
+
     /// Set `expand_lists`
     pub fn set_expand(&mut self, expand: Expand) {
         self.expand = expand;
@@ -202,6 +263,14 @@ impl JsonFormatOptions {
         self.expand
     }
 
+    pub fn quote_properties(&self) -> QuoteProperties {
+        self.quote_properties
+    }
+
+    pub fn file_source(&self) -> &JsonFileSource {
+        &self.file_source
+    }
+
     pub(crate) fn to_trailing_separator(&self) -> TrailingSeparator {
         match self.trailing_commas {
             TrailingCommas::None => TrailingSeparator::Omit,
@@ -241,6 +310,7 @@ impl fmt::Display for JsonFormatOptions {
         writeln!(f, "Trailing commas: {}", self.trailing_commas)?;
         writeln!(f, "Expand: {}", self.expand)?;
         writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())?;
+        writeln!(f, "Quote properties: {}", self.quote_properties)?;
 
         Ok(())
     }
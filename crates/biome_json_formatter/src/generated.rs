@@ -154,6 +154,44 @@ impl IntoFormat<JsonFormatContext> for biome_json_syntax::JsonMemberName {
         )
     }
 }
+impl FormatRule<biome_json_syntax::JsonMetavariable>
+    for crate::json::auxiliary::metavariable::FormatJsonMetavariable
+{
+    type Context = JsonFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_json_syntax::JsonMetavariable,
+        f: &mut JsonFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_json_syntax::JsonMetavariable>::fmt(self, node, f)
+    }
+}
+impl AsFormat<JsonFormatContext> for biome_json_syntax::JsonMetavariable {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_json_syntax::JsonMetavariable,
+        crate::json::auxiliary::metavariable::FormatJsonMetavariable,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::json::auxiliary::metavariable::FormatJsonMetavariable::default(),
+        )
+    }
+}
+impl IntoFormat<JsonFormatContext> for biome_json_syntax::JsonMetavariable {
+    type Format = FormatOwnedWithRule<
+        biome_json_syntax::JsonMetavariable,
+        crate::json::auxiliary::metavariable::FormatJsonMetavariable,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::json::auxiliary::metavariable::FormatJsonMetavariable::default(),
+        )
+    }
+}
 impl FormatRule<biome_json_syntax::JsonNullValue>
     for crate::json::value::null_value::FormatJsonNullValue
 {
@@ -1,11 +1,34 @@
+use crate::context::QuoteProperties;
 use crate::prelude::*;
-use biome_json_syntax::JsonMemberName;
+use biome_json_syntax::{JsonMemberName, JsonSyntaxKind};
+use biome_rowan::TextSize;
+use biome_unicode_table::is_js_ident;
+use std::borrow::Cow;
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsonMemberName;
 
 impl FormatNodeRule<JsonMemberName> for FormatJsonMemberName {
     fn fmt_fields(&self, node: &JsonMemberName, f: &mut JsonFormatter) -> FormatResult<()> {
-        node.value_token()?.format().fmt(f)
+        let token = node.value_token()?;
+        let options = f.options();
+        if options.file_source().is_json5()
+            && options.quote_properties() == QuoteProperties::AsNeeded
+            && token.kind() == JsonSyntaxKind::JSON_STRING_LITERAL
+        {
+            let text = token.text_trimmed();
+            // SAFETY: string literal token have a delimiters at the start and the end of the string
+            let inner = &text[1..text.len() - 1];
+            // Escaped characters can't be written in identifiers as they are
+            if is_js_ident(inner) {
+                let start = token.text_trimmed_range().start() + TextSize::from(1);
+                return format_replaced(
+                    &token,
+                    &syntax_token_cow_slice(Cow::Borrowed(inner), &token, start),
+                )
+                .fmt(f);
+            }
+        }
+        token.format().fmt(f)
     }
 }
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_json_syntax::{JsonMetavariable, JsonMetavariableFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatJsonMetavariable;
+impl FormatNodeRule<JsonMetavariable> for FormatJsonMetavariable {
+    fn fmt_fields(&self, node: &JsonMetavariable, f: &mut JsonFormatter) -> FormatResult<()> {
+        let JsonMetavariableFields { value_token } = node.as_fields();
+        write!(f, [value_token.format()])
+    }
+}
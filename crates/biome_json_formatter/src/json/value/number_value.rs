@@ -7,8 +7,17 @@ pub(crate) struct FormatJsonNumberValue;
 
 impl FormatNodeRule<JsonNumberValue> for FormatJsonNumberValue {
     fn fmt_fields(&self, node: &JsonNumberValue, f: &mut JsonFormatter) -> FormatResult<()> {
+        let value_token = node.value_token()?;
+        // `Infinity` and `NaN` of JSON5 are printed as they are
+        if value_token
+            .text_trimmed()
+            .trim_start_matches(['+', '-'])
+            .starts_with(['I', 'N'])
+        {
+            return value_token.format().fmt(f);
+        }
         format_number_token(
-            &node.value_token()?,
+            &value_token,
             NumberFormatOptions::default().keep_one_trailing_decimal_zero(),
         )
         .fmt(f)
@@ -10,9 +10,14 @@ use biome_service::settings::{ServiceLanguage, Settings};
 use biome_service::workspace::DocumentFileSource;
 use serde::{Deserialize, Serialize};
 
-#[derive(Default)]
 pub struct JsonTestFormatLanguage {
-    _source_type: JsonFileSource,
+    source_type: JsonFileSource,
+}
+
+impl JsonTestFormatLanguage {
+    pub fn new(source_type: JsonFileSource) -> Self {
+        Self { source_type }
+    }
 }
 
 impl TestFormatLanguage for JsonTestFormatLanguage {
@@ -21,13 +26,13 @@ impl TestFormatLanguage for JsonTestFormatLanguage {
     type FormatLanguage = JsonFormatLanguage;
 
     fn parse(&self, text: &str) -> AnyParse {
-        parse_json(
-            text,
-            JsonParserOptions::default()
-                .with_allow_comments()
-                .with_allow_trailing_commas(),
-        )
-        .into()
+        let mut options = JsonParserOptions::default()
+            .with_allow_comments()
+            .with_allow_trailing_commas();
+        if self.source_type.is_json5() {
+            options = options.with_json5();
+        }
+        parse_json(text, options).into()
     }
 
     fn to_format_language(
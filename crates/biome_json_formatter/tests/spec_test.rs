@@ -1,6 +1,6 @@
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
 use biome_json_formatter::JsonFormatLanguage;
-use biome_json_syntax::JsonLanguage;
+use biome_json_syntax::{JsonFileSource, JsonLanguage};
 use biome_test_utils::create_formatting_options;
 use camino::Utf8Path;
 
@@ -36,7 +36,8 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
     let options =
         create_formatting_options::<JsonLanguage>(test_file.input_file(), &mut diagnostics);
 
-    let language = language::JsonTestFormatLanguage::default();
+    let source_type: JsonFileSource = test_file.input_file().as_path().try_into().unwrap();
+    let language = language::JsonTestFormatLanguage::new(source_type);
 
     let snapshot = SpecSnapshot::new(
         test_file,
@@ -4,6 +4,6 @@ mod spec_test;
 mod formatter {
 
     mod json_module {
-        tests_macros::gen_tests! {"tests/specs/json/**/*.{json,jsonc}", crate::spec_test::run, ""}
+        tests_macros::gen_tests! {"tests/specs/json/**/*.{json,jsonc,json5}", crate::spec_test::run, ""}
     }
 }
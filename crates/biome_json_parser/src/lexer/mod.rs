@@ -139,6 +139,8 @@ impl<'src> Lexer<'src> {
                     b'\r' | b'\n' => {
                         break;
                     }
+                    // JSON5 allows all the whitespace characters of ECMAScript
+                    _ if self.options.json5 => self.advance(1),
                     _ => {
                         let start = self.text_position();
                         self.advance(1);
@@ -308,6 +310,7 @@ impl<'src> Lexer<'src> {
             IDT | DOL => self.lex_identifier(current),
             COM => self.eat_byte(T![,]),
             MIN | DIG | ZER => self.lex_number(current),
+            PLS | PRD if self.options.json5 => self.lex_number(current),
             COL => self.eat_byte(T![:]),
             BTO => self.eat_byte(T!['[']),
             BTC => self.eat_byte(T![']']),
@@ -319,12 +322,22 @@ impl<'src> Lexer<'src> {
             UNI => {
                 let chr = self.current_char_unchecked();
 
-                if is_js_id_start(chr) {
+                if self.options.grit_metavariables && self.is_metavariable_start() {
+                    self.lex_metavariable()
+                } else if is_js_id_start(chr) {
                     self.lex_identifier(current)
                 } else if self.position == 0 && self.consume_potential_bom().is_some() {
                     // A BOM can only appear at the start of a file, so if we haven't advanced at all yet,
                     // perform the check. At any other position, the BOM is just considered plain whitespace.
                     UNICODE_BOM
+                } else if self.options.json5 && is_json5_whitespace(chr) {
+                    self.advance(chr.len_utf8());
+                    if matches!(chr, '\u{2028}' | '\u{2029}') {
+                        NEWLINE
+                    } else {
+                        self.consume_whitespaces();
+                        WHITESPACE
+                    }
                 } else {
                     self.eat_unexpected_character()
                 }
@@ -356,10 +369,16 @@ impl<'src> Lexer<'src> {
 
         let start = self.text_position();
 
-        if current == b'-' {
+        if matches!(current, b'-' | b'+') {
             self.advance(1);
         }
 
+        if self.options.json5
+            && let Some(kind) = self.lex_json5_number_prefix(start)
+        {
+            return kind;
+        }
+
         let mut state = LexNumberState::FirstDigit;
 
         loop {
@@ -401,6 +420,16 @@ impl<'src> Lexer<'src> {
                         {
                             LexNumberState::FractionalPart
                         }
+                        // JSON5 allows leading and trailing decimal points: `.5` and `5.`
+                        LexNumberState::FirstDigit
+                            if self.options.json5
+                                && matches!(self.current_byte(), Some(b'0'..=b'9')) =>
+                        {
+                            LexNumberState::FractionalPart
+                        }
+                        LexNumberState::IntegerPart if self.options.json5 => {
+                            LexNumberState::FractionalPart
+                        }
                         LexNumberState::IntegerPart => LexNumberState::Invalid {
                             position: self.text_position(),
                             reason: InvalidNumberReason::MissingFraction,
@@ -451,7 +480,11 @@ impl<'src> Lexer<'src> {
             | LexNumberState::Exponent => JSON_NUMBER_LITERAL,
             LexNumberState::FirstDigit => {
                 let err = ParseDiagnostic::new(
-                    "Minus must be followed by a digit",
+                    if current == b'+' {
+                        "Plus must be followed by a digit"
+                    } else {
+                        "Minus must be followed by a digit"
+                    },
                     start..self.text_position(),
                 );
                 self.diagnostics.push(err);
@@ -490,6 +523,48 @@ impl<'src> Lexer<'src> {
         }
     }
 
+    /// Lexes the JSON5 numbers that don't start with a decimal digit or a point after the
+    /// optional sign: `Infinity`, `NaN` and hexadecimal numbers such as `0xC0FFEE`.
+    ///
+    /// Returns `None` if the lexer isn't at one of these numbers.
+    fn lex_json5_number_prefix(&mut self, start: TextSize) -> Option<JsonSyntaxKind> {
+        let rest = &self.source[self.position..];
+        for keyword in ["Infinity", "NaN"] {
+            if rest.starts_with(keyword)
+                && !rest[keyword.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(is_js_id_continue)
+            {
+                self.advance(keyword.len());
+                return Some(JSON_NUMBER_LITERAL);
+            }
+        }
+
+        if !matches!(rest.as_bytes(), [b'0', b'x' | b'X', ..]) {
+            return None;
+        }
+
+        self.advance(2);
+        let digits_start = self.position;
+        while self
+            .current_byte()
+            .is_some_and(|byte| byte.is_ascii_hexdigit())
+        {
+            self.advance(1);
+        }
+
+        if self.position == digits_start {
+            self.diagnostics.push(
+                ParseDiagnostic::new("Missing hexadecimal digits", start..self.text_position())
+                    .with_hint("A hexadecimal number must have at least one digit: `0x1F`."),
+            );
+            return Some(ERROR_TOKEN);
+        }
+
+        Some(JSON_NUMBER_LITERAL)
+    }
+
     fn lex_string_literal(&mut self, quote: u8) -> JsonSyntaxKind {
         // Handle invalid quotes
         self.assert_at_char_boundary();
@@ -497,7 +572,7 @@ impl<'src> Lexer<'src> {
 
         self.advance(1); // Skip over the quote
         let mut state = match quote {
-            b'\'' => LexStringState::InvalidQuote,
+            b'\'' if !self.options.json5 => LexStringState::InvalidQuote,
             _ => LexStringState::InString,
         };
 
@@ -538,6 +613,17 @@ impl<'src> Lexer<'src> {
                             self.advance(1);
                         }
 
+                        Some(_) if self.options.json5 => {
+                            match (self.lex_json5_escape(escape_start), state) {
+                                (Ok(_), _) => {}
+                                (Err(err), LexStringState::InString) => {
+                                    self.diagnostics.push(err);
+                                    state = LexStringState::InvalidEscapeSequence;
+                                }
+                                (Err(_), _) => {}
+                            }
+                        }
+
                         Some(_) => {
                             if matches!(state, LexStringState::InString) {
                                 let c = self.current_char_unchecked();
@@ -582,7 +668,12 @@ impl<'src> Lexer<'src> {
                 // * quotation mark: (U+0022),
                 // * reverse solidus (U+005C),
                 // * and the **control characters U+0000 to U+001F** <- This
-                ERR | WHS if matches!(state, LexStringState::InString) && chr <= 0x1f => {
+                // JSON5 allows the control characters, except the line terminators
+                ERR | WHS
+                    if matches!(state, LexStringState::InString)
+                        && chr <= 0x1f
+                        && !self.options.json5 =>
+                {
                     self.diagnostics.push(
                         ParseDiagnostic::new(
 
@@ -675,11 +766,57 @@ impl<'src> Lexer<'src> {
         Ok(())
     }
 
+    /// Lexes the escape sequences that JSON5 allows in addition to the JSON ones.
+    /// Assumes that the lexer is positioned after the `\\`.
+    ///
+    /// JSON5 allows the escape sequences of ECMAScript 5.1: `\\v`, `\\0`, `\\xHH`,
+    /// escaped line terminators, and any other character escaping itself except digits.
+    fn lex_json5_escape(&mut self, escape_start: TextSize) -> Result<(), ParseDiagnostic> {
+        match self.current_byte() {
+            Some(b'x') => {
+                self.advance(1);
+                for _ in 0..2 {
+                    match self.current_byte() {
+                        Some(byte) if byte.is_ascii_hexdigit() => self.advance(1),
+                        _ => {
+                            return Err(ParseDiagnostic::new(
+                                "Invalid hexadecimal escape sequence",
+                                escape_start..self.text_position(),
+                            )
+                            .with_hint("A hexadecimal escape sequence must consist of 2 hexadecimal numbers: `\\xXX`, e.g. `\\x2F' for '/'."));
+                        }
+                    }
+                }
+            }
+            Some(b'0') if !matches!(self.peek_byte(), Some(b'0'..=b'9')) => self.advance(1),
+            Some(b'0'..=b'9') => {
+                self.advance(1);
+                return Err(ParseDiagnostic::new(
+                    "Invalid escape sequence",
+                    escape_start..self.text_position(),
+                )
+                .with_hint("JSON5 doesn't allow octal escape sequences."));
+            }
+            // Line continuation
+            Some(b'\r') => {
+                self.advance(1);
+                if self.current_byte() == Some(b'\n') {
+                    self.advance(1);
+                }
+            }
+            Some(chr) => self.advance_byte_or_char(chr),
+            None => {}
+        }
+
+        Ok(())
+    }
+
     /// Implements basic lexing of identifiers without support for escape sequences.
     /// This is merely for improved error recovery as identifiers are not valid in JSON.
     fn lex_identifier(&mut self, first: u8) -> JsonSyntaxKind {
         self.assert_at_char_boundary();
 
+        let start = self.position;
         let mut keyword = KeywordMatcher::from_byte(first);
 
         self.advance_byte_or_char(first);
@@ -710,10 +847,49 @@ impl<'src> Lexer<'src> {
             KeywordMatcher::Null => NULL_KW,
             KeywordMatcher::True => TRUE_KW,
             KeywordMatcher::False => FALSE_KW,
+            _ if self.options.json5
+                && matches!(&self.source[start..self.position], "Infinity" | "NaN") =>
+            {
+                JSON_NUMBER_LITERAL
+            }
             _ => IDENT,
         }
     }
 
+    /// Checks if the lexer is at the start of a Grit metavariable: `µ[a-zA-Z_]` or `µ...`
+    fn is_metavariable_start(&self) -> bool {
+        if self.current_char_unchecked() != 'µ' {
+            return false;
+        }
+
+        let offset = 'µ'.len_utf8();
+        match self.byte_at(offset) {
+            Some(b'a'..=b'z' | b'A'..=b'Z' | b'_') => true,
+            Some(b'.') => {
+                self.byte_at(offset + 1) == Some(b'.') && self.byte_at(offset + 2) == Some(b'.')
+            }
+            _ => false,
+        }
+    }
+
+    /// Lexes a Grit metavariable: `µ[a-zA-Z_][a-zA-Z0-9_]*` or `µ...`
+    fn lex_metavariable(&mut self) -> JsonSyntaxKind {
+        debug_assert!(self.is_metavariable_start());
+
+        self.advance('µ'.len_utf8());
+
+        if self.current_byte() == Some(b'.') {
+            self.advance(3);
+        } else {
+            self.advance(1);
+            while let Some(b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_') = self.current_byte() {
+                self.advance(1);
+            }
+        }
+
+        GRIT_METAVARIABLE
+    }
+
     /// Lexes a comment. Comments are not supported in JSON but it should yield better error recovery.
     fn lex_slash(&mut self) -> JsonSyntaxKind {
         let start = self.text_position();
@@ -794,6 +970,13 @@ impl<'src> Lexer<'src> {
     }
 }
 
+/// Returns `true` if `chr` is one of the whitespace characters that JSON5 allows
+/// in addition to the JSON ones.
+fn is_json5_whitespace(chr: char) -> bool {
+    matches!(chr, '\u{a0}' | '\u{feff}' | '\u{2028}' | '\u{2029}')
+        || chr.is_whitespace() && !chr.is_ascii()
+}
+
 impl Iterator for Lexer<'_> {
     type Item = Token;
 
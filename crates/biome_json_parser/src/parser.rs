@@ -16,6 +16,13 @@ pub(crate) struct JsonParser<'source> {
 pub struct JsonParserOptions {
     pub allow_comments: bool,
     pub allow_trailing_commas: bool,
+    /// Enables parsing of the [JSON5](https://spec.json5.org) syntax: unquoted keys,
+    /// single-quoted strings, hexadecimal numbers, `Infinity`, `NaN`, etc.
+    /// Defaults to `false`.
+    pub json5: bool,
+    /// Enables parsing of Grit metavariables.
+    /// Defaults to `false`.
+    pub grit_metavariables: bool,
 }
 
 impl JsonParserOptions {
@@ -30,6 +37,20 @@ impl JsonParserOptions {
         self.allow_trailing_commas = true;
         self
     }
+
+    /// Enables parsing of the JSON5 syntax.
+    #[must_use]
+    pub fn with_json5(mut self) -> Self {
+        self.json5 = true;
+        self
+    }
+
+    /// Enables parsing of Grit metavariables.
+    #[must_use]
+    pub fn with_metavariables(mut self) -> Self {
+        self.grit_metavariables = true;
+        self
+    }
 }
 
 impl From<&JsonFileSource> for JsonParserOptions {
@@ -41,6 +62,9 @@ impl From<&JsonFileSource> for JsonParserOptions {
         if file_source.allow_trailing_commas() {
             options = options.with_allow_trailing_commas();
         }
+        if file_source.is_json5() {
+            options = options.with_json5();
+        }
         options
     }
 }
@@ -114,5 +138,11 @@ mod tests {
             JsonParserOptions::from(&JsonFileSource::json_allow_comments_and_trailing_commas(""));
         assert!(p3.allow_comments);
         assert!(p3.allow_trailing_commas);
+        assert!(!p3.json5);
+
+        let p4 = JsonParserOptions::from(&JsonFileSource::json5());
+        assert!(p4.allow_comments);
+        assert!(p4.allow_trailing_commas);
+        assert!(p4.json5);
     }
 }
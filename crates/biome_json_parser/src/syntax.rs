@@ -7,6 +7,7 @@ use biome_parser::parse_recovery::ParseRecoveryTokenSet;
 use biome_parser::parsed_syntax::ParsedSyntax::Absent;
 use biome_parser::prelude::ParsedSyntax::Present;
 use biome_rowan::TextRange;
+use biome_unicode_table::is_js_ident;
 
 const VALUE_START: TokenSet<JsonSyntaxKind> = token_set![
     T![null],
@@ -16,6 +17,7 @@ const VALUE_START: TokenSet<JsonSyntaxKind> = token_set![
     JSON_NUMBER_LITERAL,
     T!['['],
     T!['{'],
+    GRIT_METAVARIABLE,
 ];
 
 const VALUE_RECOVERY_SET: TokenSet<JsonSyntaxKind> =
@@ -73,9 +75,20 @@ fn parse_value(p: &mut JsonParser) -> ParsedSyntax {
         T!['{'] => parse_sequence(p, SequenceKind::Object),
         T!['['] => parse_sequence(p, SequenceKind::Array),
 
+        GRIT_METAVARIABLE => {
+            let m = p.start();
+            p.bump(GRIT_METAVARIABLE);
+            Present(m.complete(p, JSON_METAVARIABLE))
+        }
+
         IDENT => {
             let m = p.start();
-            p.error(p.err_builder("String values must be double quoted.", p.cur_range()));
+            let message = if p.options().json5 {
+                "String values must be quoted."
+            } else {
+                "String values must be double quoted."
+            };
+            p.error(p.err_builder(message, p.cur_range()));
             p.bump(IDENT);
             Present(m.complete(p, JSON_BOGUS_VALUE))
         }
@@ -289,11 +302,22 @@ fn parse_member_name(p: &mut JsonParser) -> ParsedSyntax {
             p.bump(JSON_STRING_LITERAL);
             Present(m.complete(p, JSON_MEMBER_NAME))
         }
+        // JSON5 allows identifier names as keys, including `Infinity` and `NaN`
+        IDENT | T![null] | T![true] | T![false] if p.options().json5 => {
+            let m = p.start();
+            p.bump_remap(IDENT);
+            Present(m.complete(p, JSON_MEMBER_NAME))
+        }
+        JSON_NUMBER_LITERAL if p.options().json5 && is_js_ident(p.cur_text()) => {
+            let m = p.start();
+            p.bump_remap(IDENT);
+            Present(m.complete(p, JSON_MEMBER_NAME))
+        }
         IDENT | T![null] | T![true] | T![false] => {
             let m = p.start();
             p.error(p.err_builder("Property key must be double quoted", p.cur_range()));
             p.bump_remap(IDENT);
-            Present(m.complete(p, JSON_MEMBER_NAME))
+            Present(m.complete(p, JSON_BOGUS))
         }
         _ => Absent,
     }
@@ -36,9 +36,12 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let content = fs::read_to_string(test_case_path)
         .expect("Expected test path to be a readable file in UTF8 encoding");
 
+    let json5 = test_directory.contains("json5");
     let parse_config = JsonParserOptions {
-        allow_comments: test_directory.contains("allow_comments"),
-        allow_trailing_commas: test_directory.contains("allow_trailing_commas"),
+        allow_comments: test_directory.contains("allow_comments") || json5,
+        allow_trailing_commas: test_directory.contains("allow_trailing_commas") || json5,
+        json5,
+        grit_metavariables: test_directory.contains("grit_metavariables"),
     };
 
     let parsed = parse_json(&content, parse_config);
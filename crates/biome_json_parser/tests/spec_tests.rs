@@ -25,3 +25,14 @@ mod allow_trailing_commas {
     tests_macros::gen_tests! {"tests/json_test_suite/allow_trailing_commas/ok/*.json", crate::spec_test::run, "ok"}
     tests_macros::gen_tests! {"tests/json_test_suite/allow_trailing_commas/err/*.json", crate::spec_test::run, "error"}
 }
+
+mod grit_metavariables {
+    //! Tests with Grit metavariables, which are only enabled when parsing GritQL snippets
+    tests_macros::gen_tests! {"tests/json_test_suite/grit_metavariables/ok/*.json", crate::spec_test::run, "ok"}
+}
+
+mod json5 {
+    //! Tests with the JSON5 syntax, which also allows comments and trailing commas
+    tests_macros::gen_tests! {"tests/json_test_suite/json5/ok/*.json5", crate::spec_test::run, "ok"}
+    tests_macros::gen_tests! {"tests/json_test_suite/json5/err/*.json5", crate::spec_test::run, "error"}
+}
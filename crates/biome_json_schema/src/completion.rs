@@ -0,0 +1,211 @@
+use crate::instance::{Instance, InstanceMember, InstanceValue};
+use crate::schema::{InstanceType, JsonSchema, ROOT, SchemaId, SchemaNode, SchemaObject};
+use crate::validation::render_value;
+use biome_rowan::TextSize;
+use rustc_hash::FxHashSet;
+
+/// A suggestion of the schema for the position of the cursor.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaCompletion {
+    /// Text of the suggestion. Values are rendered as JSON.
+    pub label: String,
+    pub kind: SchemaCompletionKind,
+    /// The `description` of the schema of the suggestion, if any
+    pub documentation: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SchemaCompletionKind {
+    /// The name of a property of an object
+    Property,
+    /// A value accepted by the schema
+    Value,
+}
+
+impl JsonSchema {
+    /// Returns the suggestions of the schema at the given offset of the document.
+    ///
+    /// When the offset is inside an object, the properties of its schema that
+    /// aren't already in the object are suggested. When the offset is inside a
+    /// value, the values accepted by its schema are suggested, such as the
+    /// values of `enum`.
+    pub fn completions(&self, instance: &Instance, offset: TextSize) -> Vec<SchemaCompletion> {
+        let mut completions = Vec::new();
+        // The cursor is often after the end of a YAML mapping, on a new line
+        if instance.contains(offset) || matches!(instance.value, InstanceValue::Object(_)) {
+            self.complete(&[ROOT], instance, offset, &mut completions);
+        }
+        completions
+    }
+
+    fn complete(
+        &self,
+        ids: &[SchemaId],
+        instance: &Instance,
+        offset: TextSize,
+        completions: &mut Vec<SchemaCompletion>,
+    ) {
+        let schemas = self.applicable_schemas(ids);
+        match &instance.value {
+            InstanceValue::Object(members) => {
+                for member in members {
+                    if member.name_range.contains_inclusive(offset) {
+                        self.property_completions(&schemas, members, Some(member), completions);
+                        return;
+                    }
+                    if member.value.contains(offset) {
+                        let ids = property_schemas(&schemas, &member.name);
+                        self.complete(&ids, &member.value, offset, completions);
+                        return;
+                    }
+                }
+                self.property_completions(&schemas, members, None, completions);
+            }
+            InstanceValue::Array(items) => {
+                if let Some((index, item)) = items
+                    .iter()
+                    .enumerate()
+                    .find(|(_, item)| item.contains(offset))
+                {
+                    let ids: Vec<_> = schemas
+                        .iter()
+                        .filter_map(|schema| {
+                            schema.prefix_items.get(index).or(schema.items.as_ref())
+                        })
+                        .copied()
+                        .collect();
+                    self.complete(&ids, item, offset, completions);
+                }
+            }
+            _ => value_completions(&schemas, completions),
+        }
+    }
+
+    /// Suggests the properties that aren't in the object yet. The member under
+    /// the cursor, if any, isn't considered as present.
+    fn property_completions(
+        &self,
+        schemas: &[&SchemaObject],
+        members: &[InstanceMember],
+        current: Option<&InstanceMember>,
+        completions: &mut Vec<SchemaCompletion>,
+    ) {
+        for schema in schemas {
+            for (name, id) in &schema.properties {
+                let is_present = members.iter().any(|member| {
+                    &member.name == name
+                        && current.is_none_or(|current| !std::ptr::eq(current, member))
+                });
+                if is_present
+                    || completions.iter().any(|completion| {
+                        completion.kind == SchemaCompletionKind::Property
+                            && &completion.label == name
+                    })
+                {
+                    continue;
+                }
+                completions.push(SchemaCompletion {
+                    label: name.clone(),
+                    kind: SchemaCompletionKind::Property,
+                    documentation: self.description(*id),
+                });
+            }
+        }
+    }
+
+    /// Returns the object schemas that apply to a value, including the ones
+    /// of the references and of the combinators.
+    fn applicable_schemas(&self, ids: &[SchemaId]) -> Vec<&SchemaObject> {
+        let mut schemas = Vec::new();
+        let mut visited = FxHashSet::default();
+        let mut queue = ids.to_vec();
+        while let Some(id) = queue.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            let SchemaNode::Object(schema) = self.node(id) else {
+                continue;
+            };
+            queue.extend(schema.reference);
+            queue.extend(&schema.all_of);
+            queue.extend(&schema.any_of);
+            queue.extend(&schema.one_of);
+            if let Some((_, then, otherwise)) = schema.if_then_else {
+                queue.extend(then);
+                queue.extend(otherwise);
+            }
+            schemas.push(schema.as_ref());
+        }
+        schemas
+    }
+
+    fn description(&self, id: SchemaId) -> Option<String> {
+        self.applicable_schemas(&[id])
+            .into_iter()
+            .find_map(|schema| schema.description.clone())
+    }
+}
+
+fn property_schemas(schemas: &[&SchemaObject], name: &str) -> Vec<SchemaId> {
+    let mut ids = Vec::new();
+    for schema in schemas {
+        let mut evaluated = false;
+        if let Some((_, id)) = schema
+            .properties
+            .iter()
+            .find(|(property, _)| property == name)
+        {
+            evaluated = true;
+            ids.push(*id);
+        }
+        for (pattern, id) in &schema.pattern_properties {
+            if pattern.is_match(name) {
+                evaluated = true;
+                ids.push(*id);
+            }
+        }
+        if !evaluated {
+            ids.extend(schema.additional_properties);
+        }
+    }
+    ids
+}
+
+fn value_completions(schemas: &[&SchemaObject], completions: &mut Vec<SchemaCompletion>) {
+    let mut push = |label: String, documentation: Option<&String>| {
+        if !completions
+            .iter()
+            .any(|completion| completion.label == label)
+        {
+            completions.push(SchemaCompletion {
+                label,
+                kind: SchemaCompletionKind::Value,
+                documentation: documentation.cloned(),
+            });
+        }
+    };
+
+    for schema in schemas {
+        let documentation = schema.description.as_ref();
+        if let Some(value) = &schema.const_value {
+            push(render_value(value), documentation);
+        }
+        for value in schema.enum_values.iter().flatten() {
+            push(render_value(value), documentation);
+        }
+        for ty in schema.types.iter().flatten() {
+            match ty {
+                InstanceType::Boolean => {
+                    push("true".to_string(), documentation);
+                    push("false".to_string(), documentation);
+                }
+                InstanceType::Null => push("null".to_string(), documentation),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "completion.tests.rs"]
+mod tests;
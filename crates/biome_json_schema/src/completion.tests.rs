@@ -0,0 +1,93 @@
+use crate::{JsonSchema, SchemaCompletionKind, json, yaml};
+use biome_json_parser::{JsonParserOptions, parse_json};
+use biome_rowan::TextSize;
+use biome_yaml_parser::parse_yaml;
+
+const SCHEMA: &str = r##"{
+    "type": "object",
+    "properties": {
+        "name": { "type": "string", "description": "The name of the package" },
+        "private": { "type": "boolean" },
+        "license": { "$ref": "#/definitions/license" }
+    },
+    "definitions": {
+        "license": { "enum": ["MIT", "Apache-2.0"] }
+    }
+}"##;
+
+/// Returns the labels of the completions at the position of `|` in the document.
+fn complete_json(document: &str) -> Vec<String> {
+    let offset = document.find('|').expect("cursor in the document");
+    let document = document.replace('|', "");
+    let schema = JsonSchema::parse(SCHEMA).expect("valid schema");
+    let parsed = parse_json(
+        &document,
+        JsonParserOptions::default().with_allow_trailing_commas(),
+    );
+    let instance = json::to_instance(&parsed.tree()).expect("document with a value");
+    schema
+        .completions(&instance, TextSize::from(offset as u32))
+        .into_iter()
+        .map(|completion| completion.label)
+        .collect()
+}
+
+fn complete_yaml(document: &str) -> Vec<String> {
+    let offset = document.find('|').expect("cursor in the document");
+    let document = document.replace('|', "");
+    let schema = JsonSchema::parse(SCHEMA).expect("valid schema");
+    let parsed = parse_yaml(&document);
+    let instance = yaml::to_instances(&parsed.tree())
+        .into_iter()
+        .next()
+        .expect("document with a value");
+    schema
+        .completions(&instance, TextSize::from(offset as u32))
+        .into_iter()
+        .map(|completion| completion.label)
+        .collect()
+}
+
+#[test]
+fn suggests_missing_properties() {
+    assert_eq!(
+        complete_json(r#"{ "name": "biome", | }"#),
+        ["private", "license"]
+    );
+}
+
+#[test]
+fn suggests_values_of_properties() {
+    assert_eq!(
+        complete_json(r#"{ "license": "|" }"#),
+        ["\"MIT\"", "\"Apache-2.0\""]
+    );
+    assert_eq!(complete_json(r#"{ "private": | }"#), ["true", "false"]);
+}
+
+#[test]
+fn suggests_properties_with_their_description() {
+    let schema = JsonSchema::parse(SCHEMA).expect("valid schema");
+    let parsed = parse_json("{}", JsonParserOptions::default());
+    let instance = json::to_instance(&parsed.tree()).expect("document with a value");
+    let completion = schema
+        .completions(&instance, TextSize::from(1))
+        .into_iter()
+        .next()
+        .expect("a completion");
+    assert_eq!(completion.label, "name");
+    assert_eq!(completion.kind, SchemaCompletionKind::Property);
+    assert_eq!(
+        completion.documentation.as_deref(),
+        Some("The name of the package")
+    );
+}
+
+#[test]
+fn suggests_yaml_properties_and_values() {
+    assert_eq!(complete_yaml("name: biome\n|"), ["private", "license"]);
+    assert_eq!(
+        complete_yaml("name: biome\nprivate: |\n"),
+        ["true", "false"]
+    );
+}
@@ -0,0 +1,84 @@
+use biome_json_value::JsonValue;
+use biome_rowan::{TextRange, TextSize};
+
+/// A value of a document that is validated against a schema.
+///
+/// Instances are independent of the syntax of the document: JSON and YAML
+/// documents are both converted to instances before being validated.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instance {
+    pub value: InstanceValue,
+    /// Range of the value inside the document
+    pub range: TextRange,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InstanceValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Instance>),
+    Object(Vec<InstanceMember>),
+    /// A value whose content can't be determined, such as a bogus node or a
+    /// YAML alias. It's never reported as invalid.
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstanceMember {
+    pub name: String,
+    /// Range of the name of the member inside the document
+    pub name_range: TextRange,
+    pub value: Instance,
+}
+
+impl Instance {
+    pub fn new(value: InstanceValue, range: TextRange) -> Self {
+        Self { value, range }
+    }
+
+    pub fn unknown(range: TextRange) -> Self {
+        Self::new(InstanceValue::Unknown, range)
+    }
+
+    /// Returns the member of the object with the given name, if any.
+    pub fn member(&self, name: &str) -> Option<&InstanceMember> {
+        match &self.value {
+            InstanceValue::Object(members) => members.iter().find(|member| member.name == name),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the instance contains the given offset, including the
+    /// end of its range.
+    pub(crate) fn contains(&self, offset: TextSize) -> bool {
+        self.range.contains_inclusive(offset)
+    }
+
+    /// Returns whether the instance is equal to the given JSON value.
+    pub(crate) fn equals(&self, other: &JsonValue) -> bool {
+        match (&self.value, other) {
+            (InstanceValue::Null, JsonValue::Null) => true,
+            (InstanceValue::Bool(left), JsonValue::Bool(right)) => left == right,
+            (InstanceValue::Number(left), JsonValue::Number(right)) => left == right,
+            (InstanceValue::String(left), JsonValue::String(right)) => left == right.as_str(),
+            (InstanceValue::Array(left), JsonValue::Array(right)) => {
+                left.len() == right.len()
+                    && left
+                        .iter()
+                        .zip(right.iter())
+                        .all(|(left, right)| left.equals(right))
+            }
+            (InstanceValue::Object(left), JsonValue::Object(right)) => {
+                left.len() == right.len()
+                    && left.iter().all(|member| {
+                        right
+                            .get(member.name.as_str())
+                            .is_some_and(|value| member.value.equals(value))
+                    })
+            }
+            _ => false,
+        }
+    }
+}
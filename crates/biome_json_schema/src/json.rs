@@ -0,0 +1,78 @@
+//! Conversion of JSON documents to [Instance]s.
+
+use crate::instance::{Instance, InstanceMember, InstanceValue};
+use biome_json_syntax::{AnyJsonValue, JsonMember, JsonRoot};
+use biome_json_value::{JsonString, JsonValue};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+
+/// Converts the value of the document to an instance.
+pub fn to_instance(root: &JsonRoot) -> Option<Instance> {
+    root.value().ok().map(|value| value_to_instance(&value))
+}
+
+/// Returns the reference of the `$schema` property of the document, if any.
+pub fn schema_reference(root: &JsonRoot) -> Option<String> {
+    let AnyJsonValue::JsonObjectValue(object) = root.value().ok()? else {
+        return None;
+    };
+    let AnyJsonValue::JsonStringValue(value) = object.find_member("$schema")?.value().ok()? else {
+        return None;
+    };
+
+    Some(JsonString::from(value).as_str().to_string())
+}
+
+fn value_to_instance(value: &AnyJsonValue) -> Instance {
+    let range = value.range();
+    let value = match value {
+        AnyJsonValue::JsonArrayValue(array) => InstanceValue::Array(
+            array
+                .elements()
+                .iter()
+                .map(|element| match element {
+                    Ok(element) => value_to_instance(&element),
+                    Err(_) => Instance::unknown(range),
+                })
+                .collect(),
+        ),
+        AnyJsonValue::JsonObjectValue(object) => InstanceValue::Object(
+            object
+                .json_member_list()
+                .iter()
+                .filter_map(|member| member_to_instance(&member.ok()?))
+                .collect(),
+        ),
+        value => match JsonValue::from(value.clone()) {
+            JsonValue::Null => InstanceValue::Null,
+            JsonValue::Bool(value) => InstanceValue::Bool(value),
+            JsonValue::Number(value) => InstanceValue::Number(value),
+            JsonValue::String(value) => InstanceValue::String(value.as_str().to_string()),
+            JsonValue::Array(_) | JsonValue::Object(_) | JsonValue::Bogus => InstanceValue::Unknown,
+        },
+    };
+
+    Instance::new(value, range)
+}
+
+fn member_to_instance(member: &JsonMember) -> Option<InstanceMember> {
+    let name = member.name().ok()?;
+    let value = match member.value() {
+        Ok(value) => value_to_instance(&value),
+        // Keep members without value, so that completions can be provided for them
+        Err(_) => {
+            let colon = member.colon_token().ok()?;
+            Instance::unknown(TextRange::new(
+                colon.text_trimmed_range().end(),
+                colon.text_range().end(),
+            ))
+        }
+    };
+
+    Some(InstanceMember {
+        name: JsonString::from(name.inner_string_text().ok()?)
+            .as_str()
+            .to_string(),
+        name_range: name.range(),
+        value,
+    })
+}
@@ -0,0 +1,44 @@
+//! Validation of JSON and YAML documents against [JSON Schemas](https://json-schema.org/).
+//!
+//! A [JsonSchema] is compiled once from the text of a schema, and can then be
+//! used to validate any number of documents. Documents aren't validated
+//! directly: they are first converted to an [Instance], which keeps the text
+//! ranges of the values and of the property names, so that violations can be
+//! reported precisely. See the [json] and [yaml] modules for the conversions.
+//!
+//! The following keywords are supported:
+//!
+//! - `type`, `enum` and `const`;
+//! - `properties`, `patternProperties`, `additionalProperties`, `required`,
+//!   `minProperties` and `maxProperties`;
+//! - `items`, `prefixItems`, `additionalItems`, `minItems` and `maxItems`;
+//! - `minimum`, `maximum`, `exclusiveMinimum`, `exclusiveMaximum` and `multipleOf`;
+//! - `minLength`, `maxLength` and `pattern`;
+//! - `allOf`, `anyOf`, `oneOf`, `not` and `if`/`then`/`else`;
+//! - `$ref`, as long as the reference points inside the same schema, e.g.
+//!   `#/definitions/foo`.
+//!
+//! Unsupported keywords are ignored, and so are references to other
+//! documents: the values they apply to are always considered valid.
+
+#![deny(clippy::use_self)]
+
+mod completion;
+mod instance;
+pub mod json;
+mod schema;
+mod validation;
+pub mod yaml;
+
+pub use completion::{SchemaCompletion, SchemaCompletionKind};
+pub use instance::{Instance, InstanceMember, InstanceValue};
+pub use schema::{InstanceType, JsonSchema, JsonSchemaError};
+pub use validation::{SchemaViolation, SchemaViolationKind};
+
+/// Returns whether the given `$schema` reference points to a local file.
+///
+/// Remote schemas aren't downloaded, so references such as
+/// `https://json.schemastore.org/package.json` are ignored.
+pub fn is_local_schema_reference(reference: &str) -> bool {
+    !reference.is_empty() && !reference.contains("://")
+}
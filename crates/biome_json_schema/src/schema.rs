@@ -0,0 +1,382 @@
+use biome_json_parser::{JsonParserOptions, parse_json};
+use biome_json_value::{JsonObject, JsonValue};
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use std::fmt::{Display, Formatter};
+
+/// A compiled JSON Schema.
+///
+/// The schema is stored as a flat list of nodes that reference each other by
+/// index, so that recursive schemas can be represented without reference
+/// counting.
+#[derive(Debug)]
+pub struct JsonSchema {
+    nodes: Vec<SchemaNode>,
+}
+
+/// The root node is always the first one.
+pub(crate) const ROOT: SchemaId = SchemaId(0);
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct SchemaId(usize);
+
+#[derive(Debug)]
+pub(crate) enum SchemaNode {
+    /// `true` accepts any value, `false` rejects all of them
+    Bool(bool),
+    Object(Box<SchemaObject>),
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct SchemaObject {
+    pub(crate) reference: Option<SchemaId>,
+    pub(crate) types: Option<Vec<InstanceType>>,
+    pub(crate) enum_values: Option<Vec<JsonValue>>,
+    pub(crate) const_value: Option<JsonValue>,
+    pub(crate) description: Option<String>,
+
+    pub(crate) properties: Vec<(String, SchemaId)>,
+    pub(crate) pattern_properties: Vec<(Regex, SchemaId)>,
+    pub(crate) additional_properties: Option<SchemaId>,
+    pub(crate) required: Vec<String>,
+    pub(crate) min_properties: Option<usize>,
+    pub(crate) max_properties: Option<usize>,
+
+    pub(crate) prefix_items: Vec<SchemaId>,
+    pub(crate) items: Option<SchemaId>,
+    pub(crate) min_items: Option<usize>,
+    pub(crate) max_items: Option<usize>,
+
+    pub(crate) minimum: Option<f64>,
+    pub(crate) maximum: Option<f64>,
+    pub(crate) exclusive_minimum: Option<f64>,
+    pub(crate) exclusive_maximum: Option<f64>,
+    pub(crate) multiple_of: Option<f64>,
+
+    pub(crate) min_length: Option<usize>,
+    pub(crate) max_length: Option<usize>,
+    pub(crate) pattern: Option<(String, Regex)>,
+
+    pub(crate) all_of: Vec<SchemaId>,
+    pub(crate) any_of: Vec<SchemaId>,
+    pub(crate) one_of: Vec<SchemaId>,
+    pub(crate) not: Option<SchemaId>,
+    pub(crate) if_then_else: Option<(SchemaId, Option<SchemaId>, Option<SchemaId>)>,
+}
+
+/// The primitive types of the `type` keyword.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InstanceType {
+    Null,
+    Boolean,
+    Object,
+    Array,
+    Number,
+    Integer,
+    String,
+}
+
+impl InstanceType {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "null" => Self::Null,
+            "boolean" => Self::Boolean,
+            "object" => Self::Object,
+            "array" => Self::Array,
+            "number" => Self::Number,
+            "integer" => Self::Integer,
+            "string" => Self::String,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for InstanceType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Null => "null",
+            Self::Boolean => "boolean",
+            Self::Object => "object",
+            Self::Array => "array",
+            Self::Number => "number",
+            Self::Integer => "integer",
+            Self::String => "string",
+        })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum JsonSchemaError {
+    /// The schema isn't a valid JSON document
+    InvalidJson,
+    /// The schema is neither an object nor a boolean
+    InvalidSchema,
+}
+
+impl Display for JsonSchemaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidJson => f.write_str("the schema isn't a valid JSON document"),
+            Self::InvalidSchema => f.write_str("the schema must be an object or a boolean"),
+        }
+    }
+}
+
+impl std::error::Error for JsonSchemaError {}
+
+impl JsonSchema {
+    /// Parses and compiles the schema from its source text.
+    ///
+    /// Comments and trailing commas are allowed.
+    pub fn parse(source: &str) -> Result<Self, JsonSchemaError> {
+        let parsed = parse_json(
+            source,
+            JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas(),
+        );
+        if parsed.has_errors() {
+            return Err(JsonSchemaError::InvalidJson);
+        }
+        let value = parsed
+            .tree()
+            .value()
+            .map_err(|_| JsonSchemaError::InvalidJson)?;
+
+        Self::from_value(&value.into())
+    }
+
+    /// Compiles the schema from an already parsed value.
+    pub fn from_value(value: &JsonValue) -> Result<Self, JsonSchemaError> {
+        if !matches!(value, JsonValue::Object(_) | JsonValue::Bool(_)) {
+            return Err(JsonSchemaError::InvalidSchema);
+        }
+
+        let mut compiler = SchemaCompiler {
+            root: value,
+            nodes: Vec::new(),
+            references: FxHashMap::default(),
+        };
+        compiler.compile(value);
+
+        Ok(Self {
+            nodes: compiler.nodes,
+        })
+    }
+
+    pub(crate) fn node(&self, id: SchemaId) -> &SchemaNode {
+        &self.nodes[id.0]
+    }
+}
+
+struct SchemaCompiler<'a> {
+    root: &'a JsonValue,
+    nodes: Vec<SchemaNode>,
+    /// Nodes of the references that were already compiled, by JSON pointer
+    references: FxHashMap<String, SchemaId>,
+}
+
+impl SchemaCompiler<'_> {
+    fn reserve(&mut self) -> SchemaId {
+        self.nodes.push(SchemaNode::Bool(true));
+        SchemaId(self.nodes.len() - 1)
+    }
+
+    fn compile(&mut self, value: &JsonValue) -> SchemaId {
+        let id = self.reserve();
+        self.nodes[id.0] = self.compile_node(value);
+        id
+    }
+
+    fn compile_node(&mut self, value: &JsonValue) -> SchemaNode {
+        match value {
+            JsonValue::Bool(value) => SchemaNode::Bool(*value),
+            JsonValue::Object(object) => SchemaNode::Object(Box::new(self.compile_object(object))),
+            // Invalid schemas don't restrict anything
+            _ => SchemaNode::Bool(true),
+        }
+    }
+
+    fn compile_object(&mut self, object: &JsonObject) -> SchemaObject {
+        let mut schema = SchemaObject {
+            reference: object
+                .get("$ref")
+                .and_then(JsonValue::as_string)
+                .map(|reference| self.compile_reference(reference.as_str())),
+            description: string(object, "description"),
+            const_value: object.get("const").cloned(),
+            enum_values: object
+                .get("enum")
+                .and_then(JsonValue::as_array)
+                .map(|values| values.to_vec()),
+            ..SchemaObject::default()
+        };
+
+        schema.types = match object.get("type") {
+            Some(JsonValue::String(name)) => {
+                InstanceType::from_name(name.as_str()).map(|ty| vec![ty])
+            }
+            Some(JsonValue::Array(names)) => Some(
+                names
+                    .iter()
+                    .filter_map(JsonValue::as_string)
+                    .filter_map(|name| InstanceType::from_name(name.as_str()))
+                    .collect(),
+            ),
+            _ => None,
+        };
+
+        // Objects
+        if let Some(properties) = object.get("properties").and_then(JsonValue::as_object) {
+            for (name, value) in properties.iter() {
+                let id = self.compile(value);
+                schema.properties.push((name.as_str().to_string(), id));
+            }
+        }
+        if let Some(properties) = object
+            .get("patternProperties")
+            .and_then(JsonValue::as_object)
+        {
+            for (pattern, value) in properties.iter() {
+                // Patterns that aren't supported by the regex engine are skipped
+                if let Ok(regex) = Regex::new(pattern.as_str()) {
+                    let id = self.compile(value);
+                    schema.pattern_properties.push((regex, id));
+                }
+            }
+        }
+        schema.additional_properties = self.compile_keyword(object, "additionalProperties");
+        if let Some(required) = object.get("required").and_then(JsonValue::as_array) {
+            schema.required = required
+                .iter()
+                .filter_map(JsonValue::as_string)
+                .map(|name| name.as_str().to_string())
+                .collect();
+        }
+        schema.min_properties = count(object, "minProperties");
+        schema.max_properties = count(object, "maxProperties");
+
+        // Arrays. Before draft 2020-12, `items` could be an array of schemas
+        // that applies to the first items, which is `prefixItems` now.
+        match object.get("items") {
+            Some(JsonValue::Array(items)) => {
+                schema.prefix_items = items.iter().map(|item| self.compile(item)).collect();
+                schema.items = self.compile_keyword(object, "additionalItems");
+            }
+            Some(items) => {
+                schema.items = Some(self.compile(items));
+            }
+            None => {}
+        }
+        if let Some(items) = object.get("prefixItems").and_then(JsonValue::as_array) {
+            schema.prefix_items = items.iter().map(|item| self.compile(item)).collect();
+        }
+        schema.min_items = count(object, "minItems");
+        schema.max_items = count(object, "maxItems");
+
+        // Numbers. Before draft 6, the exclusive bounds were booleans that
+        // applied to `minimum` and `maximum`.
+        schema.minimum = number(object, "minimum");
+        schema.maximum = number(object, "maximum");
+        match object.get("exclusiveMinimum") {
+            Some(JsonValue::Number(limit)) => schema.exclusive_minimum = Some(*limit),
+            Some(JsonValue::Bool(true)) => schema.exclusive_minimum = schema.minimum.take(),
+            _ => {}
+        }
+        match object.get("exclusiveMaximum") {
+            Some(JsonValue::Number(limit)) => schema.exclusive_maximum = Some(*limit),
+            Some(JsonValue::Bool(true)) => schema.exclusive_maximum = schema.maximum.take(),
+            _ => {}
+        }
+        schema.multiple_of = number(object, "multipleOf").filter(|divisor| *divisor > 0.0);
+
+        // Strings
+        schema.min_length = count(object, "minLength");
+        schema.max_length = count(object, "maxLength");
+        schema.pattern = string(object, "pattern")
+            .and_then(|pattern| Regex::new(&pattern).ok().map(|regex| (pattern, regex)));
+
+        // Combinators
+        schema.all_of = self.compile_list(object, "allOf");
+        schema.any_of = self.compile_list(object, "anyOf");
+        schema.one_of = self.compile_list(object, "oneOf");
+        schema.not = self.compile_keyword(object, "not");
+        if let Some(condition) = self.compile_keyword(object, "if") {
+            let then = self.compile_keyword(object, "then");
+            let otherwise = self.compile_keyword(object, "else");
+            schema.if_then_else = Some((condition, then, otherwise));
+        }
+
+        schema
+    }
+
+    fn compile_keyword(&mut self, object: &JsonObject, keyword: &str) -> Option<SchemaId> {
+        object.get(keyword).map(|value| self.compile(value))
+    }
+
+    fn compile_list(&mut self, object: &JsonObject, keyword: &str) -> Vec<SchemaId> {
+        object
+            .get(keyword)
+            .and_then(JsonValue::as_array)
+            .map(|values| values.iter().map(|value| self.compile(value)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Compiles the schema the reference points to.
+    ///
+    /// The node is reserved before the target is compiled, so that recursive
+    /// references point to the node being compiled.
+    fn compile_reference(&mut self, reference: &str) -> SchemaId {
+        if let Some(id) = self.references.get(reference) {
+            return *id;
+        }
+
+        let id = self.reserve();
+        self.references.insert(reference.to_string(), id);
+        let root = self.root;
+        if let Some(target) = resolve_pointer(root, reference) {
+            self.nodes[id.0] = self.compile_node(target);
+        }
+
+        id
+    }
+}
+
+/// Resolves a reference in the form of `#/path/to/schema` inside the root schema.
+///
+/// Returns [None] for references to other documents and for anchors.
+fn resolve_pointer<'a>(root: &'a JsonValue, reference: &str) -> Option<&'a JsonValue> {
+    let pointer = reference.strip_prefix('#')?;
+    if pointer.is_empty() {
+        return Some(root);
+    }
+
+    pointer
+        .strip_prefix('/')?
+        .split('/')
+        .try_fold(root, |value, segment| {
+            let segment = segment.replace("~1", "/").replace("~0", "~");
+            match value {
+                JsonValue::Object(object) => object.get(segment.as_str()),
+                JsonValue::Array(array) => array.get(segment.parse::<usize>().ok()?),
+                _ => None,
+            }
+        })
+}
+
+fn string(object: &JsonObject, keyword: &str) -> Option<String> {
+    object
+        .get(keyword)
+        .and_then(JsonValue::as_string)
+        .map(|value| value.as_str().to_string())
+}
+
+fn number(object: &JsonObject, keyword: &str) -> Option<f64> {
+    object.get(keyword).and_then(JsonValue::as_number)
+}
+
+fn count(object: &JsonObject, keyword: &str) -> Option<usize> {
+    number(object, keyword)
+        .filter(|count| *count >= 0.0 && count.fract() == 0.0)
+        .map(|count| count as usize)
+}
@@ -0,0 +1,653 @@
+use crate::instance::{Instance, InstanceMember, InstanceValue};
+use crate::schema::{InstanceType, JsonSchema, ROOT, SchemaId, SchemaNode, SchemaObject};
+use biome_json_value::JsonValue;
+use biome_rowan::TextRange;
+use std::fmt::{Display, Formatter};
+
+/// Maximum number of nested schemas applied to the same document, which
+/// protects against references that loop without consuming the document,
+/// e.g. `{ "$ref": "#" }`.
+const MAX_DEPTH: usize = 128;
+
+/// A value of a document that doesn't satisfy the schema.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaViolation {
+    /// Range of the value, or of the property name, the violation applies to
+    pub range: TextRange,
+    pub kind: SchemaViolationKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SchemaViolationKind {
+    /// The value doesn't have any of the expected types
+    InvalidType {
+        expected: Vec<InstanceType>,
+    },
+    /// The value isn't one of the values of `enum`
+    NotInEnum {
+        allowed: Vec<String>,
+    },
+    /// The value isn't the value of `const`
+    NotConst {
+        expected: String,
+    },
+    /// A property listed in `required` is missing from the object
+    MissingProperty {
+        name: String,
+    },
+    /// A property isn't allowed by `additionalProperties`
+    UnknownProperty {
+        name: String,
+    },
+    TooFewProperties {
+        limit: usize,
+    },
+    TooManyProperties {
+        limit: usize,
+    },
+    TooFewItems {
+        limit: usize,
+    },
+    TooManyItems {
+        limit: usize,
+    },
+    BelowMinimum {
+        limit: f64,
+        exclusive: bool,
+    },
+    AboveMaximum {
+        limit: f64,
+        exclusive: bool,
+    },
+    NotMultipleOf {
+        divisor: f64,
+    },
+    TooShort {
+        limit: usize,
+    },
+    TooLong {
+        limit: usize,
+    },
+    PatternMismatch {
+        pattern: String,
+    },
+    /// The value doesn't match any schema of `anyOf` or `oneOf`
+    NoMatchingSchema,
+    /// The value matches more than one schema of `oneOf`
+    MultipleMatchingSchemas,
+    /// The value matches the schema of `not`
+    MatchesNot,
+    /// The schema is `false`
+    NotAllowed,
+}
+
+impl Display for SchemaViolationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidType { expected } => {
+                write!(f, "Incorrect type, expected ")?;
+                write_list(f, expected, "or")?;
+                write!(f, ".")
+            }
+            Self::NotInEnum { allowed } => {
+                write!(f, "Value is not accepted, expected ")?;
+                write_list(f, allowed, "or")?;
+                write!(f, ".")
+            }
+            Self::NotConst { expected } => write!(f, "Value must be {expected}."),
+            Self::MissingProperty { name } => write!(f, "Missing required property \"{name}\"."),
+            Self::UnknownProperty { name } => write!(f, "Property \"{name}\" is not allowed."),
+            Self::TooFewProperties { limit } => {
+                write!(
+                    f,
+                    "Object has fewer properties than the minimum of {limit}."
+                )
+            }
+            Self::TooManyProperties { limit } => {
+                write!(f, "Object has more properties than the maximum of {limit}.")
+            }
+            Self::TooFewItems { limit } => {
+                write!(f, "Array has fewer items than the minimum of {limit}.")
+            }
+            Self::TooManyItems { limit } => {
+                write!(f, "Array has more items than the maximum of {limit}.")
+            }
+            Self::BelowMinimum {
+                limit,
+                exclusive: false,
+            } => write!(f, "Value is below the minimum of {limit}."),
+            Self::BelowMinimum {
+                limit,
+                exclusive: true,
+            } => write!(f, "Value must be greater than {limit}."),
+            Self::AboveMaximum {
+                limit,
+                exclusive: false,
+            } => write!(f, "Value is above the maximum of {limit}."),
+            Self::AboveMaximum {
+                limit,
+                exclusive: true,
+            } => write!(f, "Value must be less than {limit}."),
+            Self::NotMultipleOf { divisor } => write!(f, "Value is not a multiple of {divisor}."),
+            Self::TooShort { limit } => {
+                write!(f, "String is shorter than the minimum length of {limit}.")
+            }
+            Self::TooLong { limit } => {
+                write!(f, "String is longer than the maximum length of {limit}.")
+            }
+            Self::PatternMismatch { pattern } => {
+                write!(f, "String does not match the pattern \"{pattern}\".")
+            }
+            Self::NoMatchingSchema => write!(f, "Value doesn't match any of the allowed schemas."),
+            Self::MultipleMatchingSchemas => write!(
+                f,
+                "Value matches more than one schema, but exactly one is expected."
+            ),
+            Self::MatchesNot => write!(f, "Value matches a schema that it must not match."),
+            Self::NotAllowed => write!(f, "Value is not allowed."),
+        }
+    }
+}
+
+fn write_list(
+    f: &mut Formatter<'_>,
+    items: &[impl Display],
+    last_separator: &str,
+) -> std::fmt::Result {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            if index == items.len() - 1 {
+                write!(f, " {last_separator} ")?;
+            } else {
+                write!(f, ", ")?;
+            }
+        }
+        write!(f, "{item}")?;
+    }
+    Ok(())
+}
+
+impl JsonSchema {
+    /// Validates the instance against the schema, and returns the violations
+    /// ordered by their position in the document.
+    pub fn validate(&self, instance: &Instance) -> Vec<SchemaViolation> {
+        let mut violations = Vec::new();
+        self.validate_node(ROOT, instance, 0, &mut violations);
+        violations.sort_by_key(|violation| violation.range.start());
+        violations.dedup();
+        violations
+    }
+
+    /// Returns whether the instance is valid against the given schema.
+    fn is_valid(&self, id: SchemaId, instance: &Instance, depth: usize) -> bool {
+        let mut violations = Vec::new();
+        self.validate_node(id, instance, depth, &mut violations);
+        violations.is_empty()
+    }
+
+    fn validate_node(
+        &self,
+        id: SchemaId,
+        instance: &Instance,
+        depth: usize,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        if depth > MAX_DEPTH || matches!(instance.value, InstanceValue::Unknown) {
+            return;
+        }
+
+        let schema = match self.node(id) {
+            SchemaNode::Bool(true) => return,
+            SchemaNode::Bool(false) => {
+                violations.push(SchemaViolation {
+                    range: instance.range,
+                    kind: SchemaViolationKind::NotAllowed,
+                });
+                return;
+            }
+            SchemaNode::Object(schema) => schema,
+        };
+
+        if let Some(reference) = schema.reference {
+            self.validate_node(reference, instance, depth + 1, violations);
+        }
+
+        if let Some(types) = &schema.types
+            && !types.iter().any(|ty| has_type(instance, *ty))
+        {
+            violations.push(SchemaViolation {
+                range: instance.range,
+                kind: SchemaViolationKind::InvalidType {
+                    expected: types.clone(),
+                },
+            });
+            // The other keywords would only report noise
+            return;
+        }
+
+        if let Some(values) = &schema.enum_values
+            && !values.iter().any(|value| instance.equals(value))
+        {
+            violations.push(SchemaViolation {
+                range: instance.range,
+                kind: SchemaViolationKind::NotInEnum {
+                    allowed: values.iter().map(render_value).collect(),
+                },
+            });
+        }
+
+        if let Some(value) = &schema.const_value
+            && !instance.equals(value)
+        {
+            violations.push(SchemaViolation {
+                range: instance.range,
+                kind: SchemaViolationKind::NotConst {
+                    expected: render_value(value),
+                },
+            });
+        }
+
+        match &instance.value {
+            InstanceValue::Object(_) => self.validate_object(schema, instance, depth, violations),
+            InstanceValue::Array(items) => {
+                self.validate_array(schema, instance, items, depth, violations)
+            }
+            InstanceValue::Number(number) => {
+                validate_number(schema, instance.range, *number, violations)
+            }
+            InstanceValue::String(string) => {
+                validate_string(schema, instance.range, string, violations)
+            }
+            InstanceValue::Null | InstanceValue::Bool(_) | InstanceValue::Unknown => {}
+        }
+
+        self.validate_combinators(schema, instance, depth, violations);
+    }
+
+    fn validate_object(
+        &self,
+        schema: &SchemaObject,
+        instance: &Instance,
+        depth: usize,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        let InstanceValue::Object(members) = &instance.value else {
+            return;
+        };
+
+        for name in &schema.required {
+            if !members.iter().any(|member| &member.name == name) {
+                violations.push(SchemaViolation {
+                    range: instance.range,
+                    kind: SchemaViolationKind::MissingProperty { name: name.clone() },
+                });
+            }
+        }
+
+        if let Some(limit) = schema.min_properties
+            && members.len() < limit
+        {
+            violations.push(SchemaViolation {
+                range: instance.range,
+                kind: SchemaViolationKind::TooFewProperties { limit },
+            });
+        }
+        if let Some(limit) = schema.max_properties
+            && members.len() > limit
+        {
+            violations.push(SchemaViolation {
+                range: instance.range,
+                kind: SchemaViolationKind::TooManyProperties { limit },
+            });
+        }
+
+        for member in members {
+            let mut evaluated = false;
+            if let Some((_, id)) = schema
+                .properties
+                .iter()
+                .find(|(name, _)| name == &member.name)
+            {
+                evaluated = true;
+                self.validate_property(*id, member, depth, violations);
+            }
+            for (pattern, id) in &schema.pattern_properties {
+                if pattern.is_match(&member.name) {
+                    evaluated = true;
+                    self.validate_property(*id, member, depth, violations);
+                }
+            }
+
+            if !evaluated && let Some(id) = schema.additional_properties {
+                if matches!(self.node(id), SchemaNode::Bool(false)) {
+                    violations.push(SchemaViolation {
+                        range: member.name_range,
+                        kind: SchemaViolationKind::UnknownProperty {
+                            name: member.name.clone(),
+                        },
+                    });
+                } else {
+                    self.validate_node(id, &member.value, depth + 1, violations);
+                }
+            }
+        }
+    }
+
+    /// Validates the value of a property. A property whose schema is `false`
+    /// is reported on its name, like unknown properties.
+    fn validate_property(
+        &self,
+        id: SchemaId,
+        member: &InstanceMember,
+        depth: usize,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        if matches!(self.node(id), SchemaNode::Bool(false)) {
+            violations.push(SchemaViolation {
+                range: member.name_range,
+                kind: SchemaViolationKind::UnknownProperty {
+                    name: member.name.clone(),
+                },
+            });
+        } else {
+            self.validate_node(id, &member.value, depth + 1, violations);
+        }
+    }
+
+    fn validate_array(
+        &self,
+        schema: &SchemaObject,
+        instance: &Instance,
+        items: &[Instance],
+        depth: usize,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        if let Some(limit) = schema.min_items
+            && items.len() < limit
+        {
+            violations.push(SchemaViolation {
+                range: instance.range,
+                kind: SchemaViolationKind::TooFewItems { limit },
+            });
+        }
+        if let Some(limit) = schema.max_items
+            && items.len() > limit
+        {
+            violations.push(SchemaViolation {
+                range: instance.range,
+                kind: SchemaViolationKind::TooManyItems { limit },
+            });
+        }
+
+        for (index, item) in items.iter().enumerate() {
+            let id = schema.prefix_items.get(index).copied().or(schema.items);
+            if let Some(id) = id {
+                self.validate_node(id, item, depth + 1, violations);
+            }
+        }
+    }
+
+    fn validate_combinators(
+        &self,
+        schema: &SchemaObject,
+        instance: &Instance,
+        depth: usize,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        for id in &schema.all_of {
+            self.validate_node(*id, instance, depth + 1, violations);
+        }
+
+        if !schema.any_of.is_empty() {
+            self.validate_alternatives(&schema.any_of, instance, depth, false, violations);
+        }
+        if !schema.one_of.is_empty() {
+            self.validate_alternatives(&schema.one_of, instance, depth, true, violations);
+        }
+
+        if let Some(id) = schema.not
+            && self.is_valid(id, instance, depth + 1)
+        {
+            violations.push(SchemaViolation {
+                range: instance.range,
+                kind: SchemaViolationKind::MatchesNot,
+            });
+        }
+
+        if let Some((condition, then, otherwise)) = schema.if_then_else {
+            let branch = if self.is_valid(condition, instance, depth + 1) {
+                then
+            } else {
+                otherwise
+            };
+            if let Some(id) = branch {
+                self.validate_node(id, instance, depth + 1, violations);
+            }
+        }
+    }
+
+    /// Validates the schemas of `anyOf` or `oneOf`.
+    ///
+    /// When none of the schemas match, the violations of a schema are only
+    /// reported if it's the only one that accepts the type of the value.
+    /// This usually points to the actual mistake, instead of the less useful
+    /// "no matching schema".
+    fn validate_alternatives(
+        &self,
+        alternatives: &[SchemaId],
+        instance: &Instance,
+        depth: usize,
+        exactly_one: bool,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        let mut matching = 0;
+        let mut candidates = Vec::new();
+        for id in alternatives {
+            let mut branch_violations = Vec::new();
+            self.validate_node(*id, instance, depth + 1, &mut branch_violations);
+            if branch_violations.is_empty() {
+                matching += 1;
+            } else if self.accepts_type(*id, instance, depth + 1) {
+                candidates.push(branch_violations);
+            }
+        }
+
+        if matching == 0 {
+            if candidates.len() == 1 {
+                violations.extend(candidates.pop().into_iter().flatten());
+            } else {
+                violations.push(SchemaViolation {
+                    range: instance.range,
+                    kind: SchemaViolationKind::NoMatchingSchema,
+                });
+            }
+        } else if exactly_one && matching > 1 {
+            violations.push(SchemaViolation {
+                range: instance.range,
+                kind: SchemaViolationKind::MultipleMatchingSchemas,
+            });
+        }
+    }
+
+    /// Returns whether the schema accepts the type of the instance, ignoring
+    /// all the other keywords.
+    fn accepts_type(&self, id: SchemaId, instance: &Instance, depth: usize) -> bool {
+        if depth > MAX_DEPTH {
+            return true;
+        }
+        let schema = match self.node(id) {
+            SchemaNode::Bool(value) => return *value,
+            SchemaNode::Object(schema) => schema,
+        };
+
+        if let Some(types) = &schema.types
+            && !types.iter().any(|ty| has_type(instance, *ty))
+        {
+            return false;
+        }
+        if let Some(value) = &schema.const_value
+            && !has_same_type(instance, value)
+        {
+            return false;
+        }
+        if let Some(values) = &schema.enum_values
+            && !values.iter().any(|value| has_same_type(instance, value))
+        {
+            return false;
+        }
+
+        schema
+            .reference
+            .iter()
+            .chain(&schema.all_of)
+            .all(|id| self.accepts_type(*id, instance, depth + 1))
+    }
+}
+
+fn validate_number(
+    schema: &SchemaObject,
+    range: TextRange,
+    number: f64,
+    violations: &mut Vec<SchemaViolation>,
+) {
+    let mut push = |kind| violations.push(SchemaViolation { range, kind });
+
+    if let Some(limit) = schema.minimum
+        && number < limit
+    {
+        push(SchemaViolationKind::BelowMinimum {
+            limit,
+            exclusive: false,
+        });
+    }
+    if let Some(limit) = schema.exclusive_minimum
+        && number <= limit
+    {
+        push(SchemaViolationKind::BelowMinimum {
+            limit,
+            exclusive: true,
+        });
+    }
+    if let Some(limit) = schema.maximum
+        && number > limit
+    {
+        push(SchemaViolationKind::AboveMaximum {
+            limit,
+            exclusive: false,
+        });
+    }
+    if let Some(limit) = schema.exclusive_maximum
+        && number >= limit
+    {
+        push(SchemaViolationKind::AboveMaximum {
+            limit,
+            exclusive: true,
+        });
+    }
+    if let Some(divisor) = schema.multiple_of {
+        let quotient = number / divisor;
+        if (quotient - quotient.round()).abs() > f64::EPSILON * quotient.abs().max(1.0) {
+            push(SchemaViolationKind::NotMultipleOf { divisor });
+        }
+    }
+}
+
+fn validate_string(
+    schema: &SchemaObject,
+    range: TextRange,
+    string: &str,
+    violations: &mut Vec<SchemaViolation>,
+) {
+    let mut push = |kind| violations.push(SchemaViolation { range, kind });
+
+    // The length of a string is its number of code points
+    let length = string.chars().count();
+    if let Some(limit) = schema.min_length
+        && length < limit
+    {
+        push(SchemaViolationKind::TooShort { limit });
+    }
+    if let Some(limit) = schema.max_length
+        && length > limit
+    {
+        push(SchemaViolationKind::TooLong { limit });
+    }
+    if let Some((pattern, regex)) = &schema.pattern
+        && !regex.is_match(string)
+    {
+        push(SchemaViolationKind::PatternMismatch {
+            pattern: pattern.clone(),
+        });
+    }
+}
+
+fn has_type(instance: &Instance, ty: InstanceType) -> bool {
+    match (&instance.value, ty) {
+        (InstanceValue::Null, InstanceType::Null)
+        | (InstanceValue::Bool(_), InstanceType::Boolean)
+        | (InstanceValue::Object(_), InstanceType::Object)
+        | (InstanceValue::Array(_), InstanceType::Array)
+        | (InstanceValue::Number(_), InstanceType::Number)
+        | (InstanceValue::String(_), InstanceType::String)
+        | (InstanceValue::Unknown, _) => true,
+        (InstanceValue::Number(number), InstanceType::Integer) => number.fract() == 0.0,
+        _ => false,
+    }
+}
+
+fn has_same_type(instance: &Instance, value: &JsonValue) -> bool {
+    matches!(
+        (&instance.value, value),
+        (InstanceValue::Null, JsonValue::Null)
+            | (InstanceValue::Bool(_), JsonValue::Bool(_))
+            | (InstanceValue::Number(_), JsonValue::Number(_))
+            | (InstanceValue::String(_), JsonValue::String(_))
+            | (InstanceValue::Array(_), JsonValue::Array(_))
+            | (InstanceValue::Object(_), JsonValue::Object(_))
+            | (InstanceValue::Unknown, _)
+    )
+}
+
+/// Renders a value of the schema as JSON, for the messages and the completions.
+pub(crate) fn render_value(value: &JsonValue) -> String {
+    match value {
+        JsonValue::Null | JsonValue::Bogus => "null".to_string(),
+        JsonValue::Bool(value) => value.to_string(),
+        JsonValue::Number(value) => value.to_string(),
+        JsonValue::String(value) => quote(value.as_str()),
+        JsonValue::Array(values) => {
+            let values: Vec<_> = values.iter().map(render_value).collect();
+            format!("[{}]", values.join(", "))
+        }
+        JsonValue::Object(members) => {
+            let members: Vec<_> = members
+                .iter()
+                .map(|(name, value)| format!("{}: {}", quote(name.as_str()), render_value(value)))
+                .collect();
+            format!("{{ {} }}", members.join(", "))
+        }
+    }
+}
+
+/// Quotes and escapes the string as a JSON string.
+pub(crate) fn quote(string: &str) -> String {
+    let mut quoted = String::with_capacity(string.len() + 2);
+    quoted.push('"');
+    for char in string.chars() {
+        match char {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            char if char.is_control() => quoted.push_str(&format!("\\u{:04x}", char as u32)),
+            char => quoted.push(char),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+#[path = "validation.tests.rs"]
+mod tests;
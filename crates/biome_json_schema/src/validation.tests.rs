@@ -0,0 +1,265 @@
+use crate::{JsonSchema, json, yaml};
+use biome_json_parser::{JsonParserOptions, parse_json};
+use biome_yaml_parser::parse_yaml;
+
+/// Returns the violations as `<text of the range>: <message>`.
+fn validate_json(schema: &str, document: &str) -> Vec<String> {
+    let schema = JsonSchema::parse(schema).expect("valid schema");
+    let parsed = parse_json(document, JsonParserOptions::default());
+    let instance = json::to_instance(&parsed.tree()).expect("document with a value");
+    schema
+        .validate(&instance)
+        .into_iter()
+        .map(|violation| format!("{}: {}", &document[violation.range], violation.kind))
+        .collect()
+}
+
+fn validate_yaml(schema: &str, document: &str) -> Vec<String> {
+    let schema = JsonSchema::parse(schema).expect("valid schema");
+    let parsed = parse_yaml(document);
+    yaml::to_instances(&parsed.tree())
+        .iter()
+        .flat_map(|instance| schema.validate(instance))
+        .map(|violation| format!("{}: {}", &document[violation.range], violation.kind))
+        .collect()
+}
+
+const PACKAGE_SCHEMA: &str = r##"{
+    "type": "object",
+    "required": ["name"],
+    "additionalProperties": false,
+    "properties": {
+        "name": { "type": "string", "minLength": 1 },
+        "private": { "type": "boolean" },
+        "version": { "$ref": "#/definitions/version" },
+        "license": { "enum": ["MIT", "Apache-2.0"] },
+        "files": { "type": "array", "items": { "type": "string" }, "maxItems": 2 }
+    },
+    "definitions": {
+        "version": { "type": "string", "pattern": "^\\d+\\.\\d+\\.\\d+$" }
+    }
+}"##;
+
+#[test]
+fn valid_document() {
+    assert!(
+        validate_json(
+            PACKAGE_SCHEMA,
+            r#"{ "name": "biome", "version": "1.0.0", "license": "MIT", "files": ["dist"] }"#
+        )
+        .is_empty()
+    );
+}
+
+#[test]
+fn reports_type_mismatches_on_values() {
+    assert_eq!(
+        validate_json(PACKAGE_SCHEMA, r#"{ "name": 1, "private": "yes" }"#),
+        [
+            "1: Incorrect type, expected string.",
+            "\"yes\": Incorrect type, expected boolean."
+        ]
+    );
+}
+
+#[test]
+fn reports_missing_properties_on_objects() {
+    assert_eq!(
+        validate_json(PACKAGE_SCHEMA, r#"{ "private": true }"#),
+        ["{ \"private\": true }: Missing required property \"name\"."]
+    );
+}
+
+#[test]
+fn reports_unknown_properties_on_names() {
+    assert_eq!(
+        validate_json(PACKAGE_SCHEMA, r#"{ "name": "biome", "nmae": "biome" }"#),
+        ["\"nmae\": Property \"nmae\" is not allowed."]
+    );
+}
+
+#[test]
+fn reports_constraints() {
+    assert_eq!(
+        validate_json(
+            PACKAGE_SCHEMA,
+            r#"{ "name": "", "version": "1.0", "license": "GPL", "files": ["a", "b", 3] }"#
+        ),
+        [
+            "\"\": String is shorter than the minimum length of 1.",
+            "\"1.0\": String does not match the pattern \"^\\d+\\.\\d+\\.\\d+$\".",
+            "\"GPL\": Value is not accepted, expected \"MIT\" or \"Apache-2.0\".",
+            "[\"a\", \"b\", 3]: Array has more items than the maximum of 2.",
+            "3: Incorrect type, expected string."
+        ]
+    );
+}
+
+#[test]
+fn reports_numeric_constraints() {
+    let schema = r#"{
+        "type": "array",
+        "items": { "type": "integer", "minimum": 1, "exclusiveMaximum": 10, "multipleOf": 2 }
+    }"#;
+    assert_eq!(
+        validate_json(schema, "[0, 4, 10, 3, 2.5]"),
+        [
+            "0: Value is below the minimum of 1.",
+            "10: Value must be less than 10.",
+            "3: Value is not a multiple of 2.",
+            "2.5: Incorrect type, expected integer."
+        ]
+    );
+}
+
+#[test]
+fn reports_the_violations_of_the_only_alternative_with_the_right_type() {
+    let schema = r#"{
+        "anyOf": [
+            { "type": "string" },
+            { "type": "object", "required": ["path"] }
+        ]
+    }"#;
+    assert!(validate_json(schema, r#""./path""#).is_empty());
+    assert_eq!(
+        validate_json(schema, r#"{ "name": "biome" }"#),
+        ["{ \"name\": \"biome\" }: Missing required property \"path\"."]
+    );
+    assert_eq!(
+        validate_json(schema, "true"),
+        ["true: Value doesn't match any of the allowed schemas."]
+    );
+}
+
+#[test]
+fn reports_values_that_match_several_alternatives() {
+    let schema = r#"{ "oneOf": [{ "type": "number" }, { "type": "integer" }] }"#;
+    assert!(validate_json(schema, "1.5").is_empty());
+    assert_eq!(
+        validate_json(schema, "1"),
+        ["1: Value matches more than one schema, but exactly one is expected."]
+    );
+}
+
+#[test]
+fn applies_conditional_schemas() {
+    let schema = r#"{
+        "if": { "properties": { "kind": { "const": "file" } } },
+        "then": { "required": ["path"] },
+        "else": { "required": ["url"] }
+    }"#;
+    assert!(validate_json(schema, r#"{ "kind": "file", "path": "a" }"#).is_empty());
+    assert_eq!(
+        validate_json(schema, r#"{ "kind": "link" }"#),
+        ["{ \"kind\": \"link\" }: Missing required property \"url\"."]
+    );
+}
+
+#[test]
+fn supports_recursive_references() {
+    let schema = r##"{
+        "$ref": "#/$defs/node",
+        "$defs": {
+            "node": {
+                "type": "object",
+                "properties": {
+                    "children": { "type": "array", "items": { "$ref": "#/$defs/node" } }
+                }
+            }
+        }
+    }"##;
+    assert_eq!(
+        validate_json(schema, r#"{ "children": [{ "children": [1] }] }"#),
+        ["1: Incorrect type, expected object."]
+    );
+}
+
+#[test]
+fn ignores_remote_references_and_self_references() {
+    let schema = r##"{
+        "properties": {
+            "remote": { "$ref": "https://example.com/schema.json" },
+            "loop": { "$ref": "#/properties/loop" }
+        }
+    }"##;
+    assert!(validate_json(schema, r#"{ "remote": 1, "loop": 2 }"#).is_empty());
+}
+
+#[test]
+fn rejects_invalid_schemas() {
+    assert!(JsonSchema::parse("{").is_err());
+    assert!(JsonSchema::parse("1").is_err());
+    assert!(JsonSchema::parse("true").is_ok());
+}
+
+#[test]
+fn validates_yaml_documents() {
+    let document = "name: biome\nprivate: yes\nversion: 1.0.0\nfiles:\n  - dist\n  - 1\n";
+    assert_eq!(
+        validate_yaml(PACKAGE_SCHEMA, document),
+        [
+            "yes: Incorrect type, expected boolean.",
+            "1: Incorrect type, expected string."
+        ]
+    );
+}
+
+#[test]
+fn resolves_yaml_scalars_with_the_core_schema() {
+    let schema = r#"{
+        "type": "object",
+        "properties": {
+            "string": { "type": "string" },
+            "number": { "type": "number" },
+            "boolean": { "type": "boolean" },
+            "null": { "type": "null" }
+        }
+    }"#;
+    let document = "string: !!str 1\nnumber: 0x1F\nboolean: True\nnull: ~\n";
+    assert!(validate_yaml(schema, document).is_empty());
+
+    let document = "string: 1.5\nnumber: '1'\nboolean: \"true\"\nnull: \n";
+    assert_eq!(
+        validate_yaml(schema, document),
+        [
+            "1.5: Incorrect type, expected string.",
+            "'1': Incorrect type, expected number.",
+            "\"true\": Incorrect type, expected boolean."
+        ]
+    );
+}
+
+#[test]
+fn skips_yaml_values_that_need_a_processor() {
+    let schema = r#"{
+        "type": "object",
+        "additionalProperties": { "type": "object", "required": ["name"] }
+    }"#;
+    let document =
+        "base: &base\n  name: biome\nalias: *base\nmerged:\n  <<: *base\ncustom: !Ref value\n";
+    assert!(validate_yaml(schema, document).is_empty());
+}
+
+#[test]
+fn finds_schema_references() {
+    let parsed = parse_json(
+        r#"{ "$schema": "./schema.json", "name": "biome" }"#,
+        JsonParserOptions::default(),
+    );
+    assert_eq!(
+        json::schema_reference(&parsed.tree()).as_deref(),
+        Some("./schema.json")
+    );
+
+    let parsed = parse_yaml("# yaml-language-server: $schema=../schema.json\nname: biome\n");
+    assert_eq!(
+        yaml::schema_reference(&parsed.tree()).as_deref(),
+        Some("../schema.json")
+    );
+
+    let parsed = parse_yaml("$schema: ./schema.json\nname: biome\n");
+    assert_eq!(
+        yaml::schema_reference(&parsed.tree()).as_deref(),
+        Some("./schema.json")
+    );
+}
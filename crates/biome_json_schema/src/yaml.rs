@@ -0,0 +1,509 @@
+//! Conversion of YAML documents to [Instance]s.
+//!
+//! Scalars are resolved with the [core schema](https://yaml.org/spec/1.2.2/#103-core-schema)
+//! of YAML 1.2. Values that can't be resolved without a YAML processor, such
+//! as aliases, merge keys and values with custom tags, are converted to
+//! [InstanceValue::Unknown] and are never reported as invalid.
+
+use crate::instance::{Instance, InstanceMember, InstanceValue};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, Direction, TextRange};
+use biome_yaml_syntax::{
+    AnyYamlBlockInBlockNode, AnyYamlBlockMapEntry, AnyYamlBlockNode, AnyYamlBlockSequenceEntry,
+    AnyYamlDocument, AnyYamlFlowMapEntry, AnyYamlFlowNode, AnyYamlFlowSequenceEntry,
+    AnyYamlJsonContent, AnyYamlMappingImplicitKey, AnyYamlPropertiesCombination, YamlBlockContent,
+    YamlBlockMapping, YamlBlockSequence, YamlDoubleQuotedScalar, YamlFlowMapping, YamlFlowSequence,
+    YamlRoot, YamlSingleQuotedScalar,
+};
+
+/// Converts each document of the stream to an instance.
+///
+/// Empty documents are skipped.
+pub fn to_instances(root: &YamlRoot) -> Vec<Instance> {
+    root.documents()
+        .iter()
+        .filter_map(|document| match document {
+            AnyYamlDocument::YamlDocument(document) => document.node(),
+            AnyYamlDocument::YamlBogus(_) => None,
+        })
+        .map(|node| block_node_to_instance(&node))
+        .collect()
+}
+
+/// Returns the schema reference of the document, if any.
+///
+/// The reference is taken from a `# yaml-language-server: $schema=<reference>`
+/// comment, the convention of the YAML language server, or from a `$schema`
+/// key at the root of the first document.
+pub fn schema_reference(root: &YamlRoot) -> Option<String> {
+    let from_comment = root
+        .syntax()
+        .descendants_tokens(Direction::Next)
+        .flat_map(|token| {
+            token
+                .leading_trivia()
+                .pieces()
+                .chain(token.trailing_trivia().pieces())
+                .collect::<Vec<_>>()
+        })
+        .filter(|piece| piece.is_comments())
+        .find_map(|piece| {
+            let reference = piece
+                .text()
+                .trim_start_matches('#')
+                .trim()
+                .strip_prefix("yaml-language-server:")?
+                .trim()
+                .strip_prefix("$schema=")?
+                .trim();
+            Some(reference.to_string())
+        });
+
+    from_comment.or_else(|| {
+        let instance = to_instances(root).into_iter().next()?;
+        match &instance.member("$schema")?.value.value {
+            InstanceValue::String(reference) => Some(reference.clone()),
+            _ => None,
+        }
+    })
+}
+
+fn block_node_to_instance(node: &AnyYamlBlockNode) -> Instance {
+    let range = node.range();
+    let value = match node {
+        AnyYamlBlockNode::AnyYamlBlockInBlockNode(node) => match node {
+            AnyYamlBlockInBlockNode::YamlBlockMapping(mapping) => {
+                if has_custom_tag(mapping.properties()) {
+                    InstanceValue::Unknown
+                } else {
+                    block_mapping_value(mapping)
+                }
+            }
+            AnyYamlBlockInBlockNode::YamlBlockSequence(sequence) => {
+                if has_custom_tag(sequence.properties()) {
+                    InstanceValue::Unknown
+                } else {
+                    block_sequence_value(sequence)
+                }
+            }
+            AnyYamlBlockInBlockNode::YamlLiteralScalar(scalar) => match scalar.content() {
+                Ok(content) if !has_custom_tag(scalar.properties()) => {
+                    InstanceValue::String(block_scalar_text(&content, false))
+                }
+                _ => InstanceValue::Unknown,
+            },
+            AnyYamlBlockInBlockNode::YamlFoldedScalar(scalar) => match scalar.content() {
+                Ok(content) if !has_custom_tag(scalar.properties()) => {
+                    InstanceValue::String(block_scalar_text(&content, true))
+                }
+                _ => InstanceValue::Unknown,
+            },
+        },
+        AnyYamlBlockNode::YamlFlowInBlockNode(node) => match node.flow() {
+            Ok(flow) => return flow_node_to_instance(&flow),
+            Err(_) => InstanceValue::Unknown,
+        },
+        AnyYamlBlockNode::YamlBogusBlockNode(_) => InstanceValue::Unknown,
+    };
+
+    Instance::new(value, range)
+}
+
+fn block_mapping_value(mapping: &YamlBlockMapping) -> InstanceValue {
+    let mut members = Vec::new();
+    for entry in mapping.entries().iter() {
+        let member = match entry {
+            AnyYamlBlockMapEntry::YamlBlockMapImplicitEntry(entry) => {
+                let Some(key) = entry.key() else {
+                    continue;
+                };
+                let value = match entry.value() {
+                    Some(value) => block_node_to_instance(&value),
+                    None => empty_value(entry.range(), entry.colon_token().ok()),
+                };
+                implicit_key_member(&key, value)
+            }
+            AnyYamlBlockMapEntry::YamlBlockMapExplicitEntry(entry) => {
+                let Some(AnyYamlBlockNode::YamlFlowInBlockNode(key)) = entry.key() else {
+                    continue;
+                };
+                let Ok(key) = key.flow() else {
+                    continue;
+                };
+                let value = match entry.value() {
+                    Some(value) => block_node_to_instance(&value),
+                    None => empty_value(entry.range(), entry.colon_token()),
+                };
+                flow_key_member(&key, value)
+            }
+            AnyYamlBlockMapEntry::YamlBogusBlockMapEntry(_) => None,
+        };
+
+        match member {
+            // Merge keys copy the entries of another mapping, usually through an alias
+            Some(member) if member.name == "<<" => return InstanceValue::Unknown,
+            Some(member) => members.push(member),
+            None => {}
+        }
+    }
+
+    InstanceValue::Object(members)
+}
+
+fn block_sequence_value(sequence: &YamlBlockSequence) -> InstanceValue {
+    InstanceValue::Array(
+        sequence
+            .entries()
+            .iter()
+            .map(|entry| match entry {
+                AnyYamlBlockSequenceEntry::YamlBlockSequenceEntry(entry) => match entry.value() {
+                    Some(value) => block_node_to_instance(&value),
+                    None => empty_value(entry.range(), entry.minus_token().ok()),
+                },
+                AnyYamlBlockSequenceEntry::YamlBogus(bogus) => Instance::unknown(bogus.range()),
+            })
+            .collect(),
+    )
+}
+
+fn flow_node_to_instance(node: &AnyYamlFlowNode) -> Instance {
+    let range = node.range();
+    let value = match node {
+        AnyYamlFlowNode::YamlFlowJsonNode(node) => {
+            if has_custom_tag(node.properties()) {
+                InstanceValue::Unknown
+            } else {
+                let is_string = has_string_tag(node.properties());
+                match node.content() {
+                    Some(AnyYamlJsonContent::YamlFlowMapping(mapping)) => {
+                        flow_mapping_value(&mapping)
+                    }
+                    Some(AnyYamlJsonContent::YamlFlowSequence(sequence)) => {
+                        flow_sequence_value(&sequence)
+                    }
+                    Some(AnyYamlJsonContent::YamlDoubleQuotedScalar(scalar)) => {
+                        double_quoted_value(&scalar)
+                    }
+                    Some(AnyYamlJsonContent::YamlSingleQuotedScalar(scalar)) => {
+                        single_quoted_value(&scalar)
+                    }
+                    None if is_string => InstanceValue::String(String::new()),
+                    None => InstanceValue::Null,
+                }
+            }
+        }
+        AnyYamlFlowNode::YamlFlowYamlNode(node) => {
+            if has_custom_tag(node.properties()) {
+                InstanceValue::Unknown
+            } else {
+                let text = node
+                    .content()
+                    .and_then(|scalar| scalar.value_token().ok())
+                    .map(|token| fold_lines(token.text_trimmed()))
+                    .unwrap_or_default();
+                if has_string_tag(node.properties()) {
+                    InstanceValue::String(text)
+                } else {
+                    resolve_plain_scalar(&text)
+                }
+            }
+        }
+        AnyYamlFlowNode::YamlAliasNode(_) | AnyYamlFlowNode::YamlBogusFlowNode(_) => {
+            InstanceValue::Unknown
+        }
+    };
+
+    Instance::new(value, range)
+}
+
+fn flow_mapping_value(mapping: &YamlFlowMapping) -> InstanceValue {
+    let mut members = Vec::new();
+    for entry in mapping.entries().iter() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        match flow_map_entry_member(&entry) {
+            Some(member) if member.name == "<<" => return InstanceValue::Unknown,
+            Some(member) => members.push(member),
+            None => {}
+        }
+    }
+
+    InstanceValue::Object(members)
+}
+
+fn flow_sequence_value(sequence: &YamlFlowSequence) -> InstanceValue {
+    InstanceValue::Array(
+        sequence
+            .entries()
+            .iter()
+            .filter_map(|entry| match entry.ok()? {
+                AnyYamlFlowSequenceEntry::AnyYamlFlowNode(node) => {
+                    Some(flow_node_to_instance(&node))
+                }
+                // A single pair, e.g. `[a: b]`, is a mapping with one entry
+                AnyYamlFlowSequenceEntry::AnyYamlFlowMapEntry(entry) => {
+                    let range = entry.range();
+                    let value = match flow_map_entry_member(&entry) {
+                        Some(member) => InstanceValue::Object(vec![member]),
+                        None => InstanceValue::Unknown,
+                    };
+                    Some(Instance::new(value, range))
+                }
+            })
+            .collect(),
+    )
+}
+
+fn flow_map_entry_member(entry: &AnyYamlFlowMapEntry) -> Option<InstanceMember> {
+    let (key, value, colon) = match entry {
+        AnyYamlFlowMapEntry::YamlFlowMapExplicitEntry(entry) => {
+            (entry.key()?, entry.value(), entry.colon_token())
+        }
+        AnyYamlFlowMapEntry::YamlFlowMapImplicitEntry(entry) => {
+            (entry.key()?, entry.value(), entry.colon_token())
+        }
+    };
+    let value = match value {
+        Some(value) => flow_node_to_instance(&value),
+        None => empty_value(entry.range(), colon),
+    };
+
+    implicit_key_member(&key, value)
+}
+
+fn implicit_key_member(key: &AnyYamlMappingImplicitKey, value: Instance) -> Option<InstanceMember> {
+    let name = match key {
+        AnyYamlMappingImplicitKey::YamlFlowJsonNode(node) => {
+            flow_node_to_instance(&node.clone().into())
+        }
+        AnyYamlMappingImplicitKey::YamlFlowYamlNode(node) => {
+            flow_node_to_instance(&node.clone().into())
+        }
+    };
+
+    member(name, value)
+}
+
+fn flow_key_member(key: &AnyYamlFlowNode, value: Instance) -> Option<InstanceMember> {
+    member(flow_node_to_instance(key), value)
+}
+
+/// Creates a member from its key. Keys that are collections are skipped,
+/// because they can't be names of JSON properties.
+fn member(key: Instance, value: Instance) -> Option<InstanceMember> {
+    let name = match key.value {
+        InstanceValue::String(name) => name,
+        InstanceValue::Null => "null".to_string(),
+        InstanceValue::Bool(value) => value.to_string(),
+        InstanceValue::Number(value) => value.to_string(),
+        InstanceValue::Array(_) | InstanceValue::Object(_) | InstanceValue::Unknown => {
+            return None;
+        }
+    };
+
+    Some(InstanceMember {
+        name,
+        name_range: key.range,
+        value,
+    })
+}
+
+/// A missing value is `null`. Its range covers the entry, up to the end of the
+/// line after the indicator, so that completions can be provided for it.
+fn empty_value(
+    entry_range: TextRange,
+    indicator: Option<biome_yaml_syntax::YamlSyntaxToken>,
+) -> Instance {
+    let end = indicator
+        .map_or(entry_range.end(), |indicator| indicator.text_range().end())
+        .max(entry_range.end());
+
+    Instance::new(
+        InstanceValue::Null,
+        TextRange::new(entry_range.start(), end),
+    )
+}
+
+fn has_string_tag(properties: Option<AnyYamlPropertiesCombination>) -> bool {
+    tag(properties).is_some_and(|tag| tag == "!!str")
+}
+
+/// Returns whether the node has a tag that isn't one of the tags of the core schema.
+fn has_custom_tag(properties: Option<AnyYamlPropertiesCombination>) -> bool {
+    tag(properties).is_some_and(|tag| {
+        !matches!(
+            tag.as_str(),
+            "!!str" | "!!int" | "!!float" | "!!bool" | "!!null" | "!!map" | "!!seq"
+        )
+    })
+}
+
+fn tag(properties: Option<AnyYamlPropertiesCombination>) -> Option<String> {
+    let tag = match properties? {
+        AnyYamlPropertiesCombination::YamlPropertiesAnchorFirst(properties) => properties.tag()?,
+        AnyYamlPropertiesCombination::YamlPropertiesTagFirst(properties) => {
+            properties.tag().ok()?
+        }
+    };
+
+    Some(tag.value_token().ok()?.text_trimmed().to_string())
+}
+
+/// Resolves a plain scalar with the core schema.
+fn resolve_plain_scalar(text: &str) -> InstanceValue {
+    match text {
+        "" | "~" | "null" | "Null" | "NULL" => return InstanceValue::Null,
+        "true" | "True" | "TRUE" => return InstanceValue::Bool(true),
+        "false" | "False" | "FALSE" => return InstanceValue::Bool(false),
+        ".nan" | ".NaN" | ".NAN" => return InstanceValue::Number(f64::NAN),
+        _ => {}
+    }
+
+    let (sign, unsigned) = match text.as_bytes()[0] {
+        b'-' => (-1.0, &text[1..]),
+        b'+' => (1.0, &text[1..]),
+        _ => (1.0, text),
+    };
+    if matches!(unsigned, ".inf" | ".Inf" | ".INF") {
+        return InstanceValue::Number(sign * f64::INFINITY);
+    }
+    if let Some(octal) = text.strip_prefix("0o")
+        && let Ok(value) = i64::from_str_radix(octal, 8)
+    {
+        return InstanceValue::Number(value as f64);
+    }
+    if let Some(hexadecimal) = text.strip_prefix("0x")
+        && let Ok(value) = i64::from_str_radix(hexadecimal, 16)
+    {
+        return InstanceValue::Number(value as f64);
+    }
+
+    let is_number = unsigned.starts_with(|char: char| char.is_ascii_digit() || char == '.')
+        && unsigned
+            .chars()
+            .all(|char| char.is_ascii_digit() || matches!(char, '.' | 'e' | 'E' | '-' | '+'));
+    match unsigned.parse::<f64>() {
+        Ok(value) if is_number => InstanceValue::Number(sign * value),
+        _ => InstanceValue::String(text.to_string()),
+    }
+}
+
+fn double_quoted_value(scalar: &YamlDoubleQuotedScalar) -> InstanceValue {
+    let Ok(text) = scalar.inner_string_text() else {
+        return InstanceValue::Unknown;
+    };
+
+    let mut value = String::with_capacity(text.text().len());
+    let mut chars = fold_lines(text.text())
+        .chars()
+        .collect::<Vec<_>>()
+        .into_iter();
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            value.push(char);
+            continue;
+        }
+        let escaped = match chars.next() {
+            Some('0') => '\0',
+            Some('a') => '\u{07}',
+            Some('b') => '\u{08}',
+            Some('t' | '\t') => '\t',
+            Some('n') => '\n',
+            Some('v') => '\u{0B}',
+            Some('f') => '\u{0C}',
+            Some('r') => '\r',
+            Some('e') => '\u{1B}',
+            Some('N') => '\u{85}',
+            Some('_') => '\u{A0}',
+            Some('L') => '\u{2028}',
+            Some('P') => '\u{2029}',
+            Some(length @ ('x' | 'u' | 'U')) => {
+                let length = match length {
+                    'x' => 2,
+                    'u' => 4,
+                    _ => 8,
+                };
+                let digits: String = chars.by_ref().take(length).collect();
+                match u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                {
+                    Some(char) => char,
+                    None => return InstanceValue::Unknown,
+                }
+            }
+            Some(char) => char,
+            None => break,
+        };
+        value.push(escaped);
+    }
+
+    InstanceValue::String(value)
+}
+
+fn single_quoted_value(scalar: &YamlSingleQuotedScalar) -> InstanceValue {
+    match scalar.inner_string_text() {
+        Ok(text) => InstanceValue::String(fold_lines(text.text()).replace("''", "'")),
+        Err(_) => InstanceValue::Unknown,
+    }
+}
+
+/// Returns the content of a literal or folded block scalar, without its indentation.
+fn block_scalar_text(content: &YamlBlockContent, folded: bool) -> String {
+    let Ok(token) = content.value_token() else {
+        return String::new();
+    };
+    let text = token.text_trimmed();
+    let lines: Vec<_> = text
+        .lines()
+        .skip_while(|line| line.trim().is_empty())
+        .collect();
+    let indentation = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start_matches(' ').len())
+        .min()
+        .unwrap_or_default();
+    let lines: Vec<_> = lines
+        .iter()
+        .map(|line| line.get(indentation..).unwrap_or_default())
+        .collect();
+
+    let mut value = if folded {
+        fold_lines(&lines.join("\n"))
+    } else {
+        lines.join("\n")
+    };
+    let trimmed_len = value.trim_end_matches('\n').len();
+    value.truncate(trimmed_len);
+    value.push('\n');
+    value
+}
+
+/// Folds the lines of a multi-line scalar: line breaks become spaces, and
+/// empty lines become line breaks.
+fn fold_lines(text: &str) -> String {
+    if !text.contains('\n') {
+        return text.to_string();
+    }
+
+    let mut value = String::with_capacity(text.len());
+    let mut empty_lines = 0;
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            empty_lines += 1;
+            continue;
+        }
+        if index > 0 {
+            if empty_lines > 0 {
+                value.extend(std::iter::repeat_n('\n', empty_lines));
+            } else {
+                value.push(' ');
+            }
+        }
+        empty_lines = 0;
+        value.push_str(line);
+    }
+    value
+}
@@ -26,6 +26,7 @@ pub enum JsonFileVariant {
     #[default]
     Standard,
     Jsonc,
+    Json5,
 }
 
 impl Display for JsonFileVariant {
@@ -33,6 +34,7 @@ impl Display for JsonFileVariant {
         match self {
             Self::Standard => write!(f, "json"),
             Self::Jsonc => write!(f, "jsonc"),
+            Self::Json5 => write!(f, "json5"),
         }
     }
 }
@@ -43,6 +45,7 @@ impl FromStr for JsonFileVariant {
         match s {
             "json" => Ok(Self::Standard),
             "jsonc" => Ok(Self::Jsonc),
+            "json5" => Ok(Self::Json5),
             _ => Err(()),
         }
     }
@@ -122,10 +125,6 @@ impl JsonFileSource {
     // This list should be SORTED!
     // Note: we shouldn't include machine generated files
     const WELL_KNOWN_JSON_ALLOW_COMMENTS_AND_TRAILING_COMMAS_FILES: &'static [&'static str] = &[
-        // Uses `json5`, we treat them as JSONC for now:
-        // https://github.com/babel/babel/blob/3956c75123e713c5fa1d3279f6f92cfeac290173/packages/babel-core/src/config/files/configuration.ts#L341
-        ".babelrc",
-        ".babelrc.json",
         // https://docs.github.com/en/codespaces/setting-up-your-project-for-codespaces/adding-a-dev-container-configuration/introduction-to-dev-containers#editing-the-devcontainerjson-file
         ".devcontainer.json",
         // Uses `jsonc-parser`:
@@ -141,14 +140,6 @@ impl JsonFileSource {
         // Uses `jsonc_parser` and allows comments and trailing commas
         // https://github.com/swc-project/swc/blob/ad932f0921411364b801b32f60eaf98f8629e812/crates/swc/src/lib.rs#L1028-L1029
         ".swcrc",
-        // Uses `jju`, default is JSON5, we treat them as JSONC for now:
-        // https://github.com/microsoft/rushstack/blob/38f0de8ba9f29d337564409eba5639287784b756/apps/api-extractor/src/api/ExtractorConfig.ts#L532
-        // https://github.com/microsoft/rushstack/blob/38f0de8ba9f29d337564409eba5639287784b756/libraries/node-core-library/src/JsonFile.ts#L218
-        // https://github.com/microsoft/rushstack/blob/38f0de8ba9f29d337564409eba5639287784b756/libraries/node-core-library/src/JsonFile.ts#L583-L585
-        "api-documenter.json",
-        "api-extractor.json",
-        // See `.babelrc`
-        "babel.config.json",
         // Uses `jsonc-parser`, and allows comments and trailing commas by default
         // https://github.com/denoland/deno/blob/5a716d1d06f73800b280259204789260774d465d/cli/tools/registry/pm.rs#L114
         "deno.json",
@@ -182,6 +173,24 @@ impl JsonFileSource {
         "typescript.json",
     ];
 
+    // Well-known JSON-like files that are parsed as JSON5
+    // This list should be SORTED!
+    // Note: we shouldn't include machine generated files
+    const WELL_KNOWN_JSON5_FILES: &'static [&'static str] = &[
+        // Uses `json5`:
+        // https://github.com/babel/babel/blob/3956c75123e713c5fa1d3279f6f92cfeac290173/packages/babel-core/src/config/files/configuration.ts#L341
+        ".babelrc",
+        ".babelrc.json",
+        // Uses `jju`, default is JSON5:
+        // https://github.com/microsoft/rushstack/blob/38f0de8ba9f29d337564409eba5639287784b756/apps/api-extractor/src/api/ExtractorConfig.ts#L532
+        // https://github.com/microsoft/rushstack/blob/38f0de8ba9f29d337564409eba5639287784b756/libraries/node-core-library/src/JsonFile.ts#L218
+        // https://github.com/microsoft/rushstack/blob/38f0de8ba9f29d337564409eba5639287784b756/libraries/node-core-library/src/JsonFile.ts#L583-L585
+        "api-documenter.json",
+        "api-extractor.json",
+        // See `.babelrc`
+        "babel.config.json",
+    ];
+
     pub fn json() -> Self {
         Self {
             allow_comments: false,
@@ -206,6 +215,16 @@ impl JsonFileSource {
         }
     }
 
+    /// [JSON5](https://json5.org) allows comments and trailing commas, as well as
+    /// unquoted keys, single-quoted strings and more number notations.
+    pub fn json5() -> Self {
+        Self {
+            allow_comments: true,
+            allow_trailing_commas: true,
+            variant: JsonFileVariant::Json5,
+        }
+    }
+
     #[must_use]
     pub fn with_allow_trailing_commas(mut self) -> Self {
         self.allow_trailing_commas = true;
@@ -231,6 +250,10 @@ impl JsonFileSource {
         self.variant
     }
 
+    pub fn is_json5(&self) -> bool {
+        self.variant == JsonFileVariant::Json5
+    }
+
     pub fn is_well_known_json_file(file_name: &str) -> bool {
         Self::WELL_KNOWN_JSON_FILES
             .binary_search(&file_name)
@@ -248,6 +271,12 @@ impl JsonFileSource {
         Self::WELL_KNOWN_JSON_ALLOW_COMMENTS_DIRECTORIES.contains(&dirname)
     }
 
+    pub fn is_well_known_json5_file(file_name: &str) -> bool {
+        Self::WELL_KNOWN_JSON5_FILES
+            .binary_search(&file_name)
+            .is_ok()
+    }
+
     pub fn is_well_known_json_allow_comments_and_trailing_commas_file(filename: &str) -> bool {
         // handle all `tsconfig.*.json` files
         // this is a common naming convention for projects that have multiple tsconfig files
@@ -266,6 +295,9 @@ impl JsonFileSource {
         let Some(extension) = path.extension() else {
             return Err(FileSourceError::MissingFileExtension);
         };
+        if Self::is_well_known_json5_file(file_name) {
+            return Ok(Self::json5());
+        }
         if Self::is_well_known_json_allow_comments_and_trailing_commas_file(file_name) {
             return Ok(Self::json_allow_comments_and_trailing_commas(extension));
         }
@@ -323,6 +355,8 @@ impl JsonFileSource {
             | "sublime-workspace"
             | "sublime_metrics"
             | "sublime_session" => Ok(Self::json_allow_comments_and_trailing_commas(extension)),
+            // https://spec.json5.org/#file-extension
+            "json5" => Ok(Self::json5()),
             _ => Err(FileSourceError::UnknownExtension),
         }
     }
@@ -340,6 +374,7 @@ impl JsonFileSource {
         match language_id {
             "json" => Ok(Self::json()),
             "jsonc" | "snippets" => Ok(Self::json_allow_comments_and_trailing_commas(language_id)),
+            "json5" => Ok(Self::json5()),
             _ => Err(FileSourceError::UnknownLanguageId),
         }
     }
@@ -376,6 +411,9 @@ mod tests {
         for items in JsonFileSource::WELL_KNOWN_JSON_ALLOW_COMMENTS_FILES.windows(2) {
             assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
         }
+        for items in JsonFileSource::WELL_KNOWN_JSON5_FILES.windows(2) {
+            assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+        }
         for items in JsonFileSource::WELL_KNOWN_JSON_FILES.windows(2) {
             assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
         }
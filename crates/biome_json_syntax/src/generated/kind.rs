@@ -31,6 +31,7 @@ pub enum JsonSyntaxKind {
     IDENT,
     COMMENT,
     MULTILINE_COMMENT,
+    GRIT_METAVARIABLE,
     JSON_ROOT,
     JSON_NUMBER_VALUE,
     JSON_STRING_VALUE,
@@ -42,6 +43,7 @@ pub enum JsonSyntaxKind {
     JSON_MEMBER,
     JSON_MEMBER_NAME,
     JSON_ARRAY_ELEMENT_LIST,
+    JSON_METAVARIABLE,
     JSON_BOGUS,
     JSON_BOGUS_MEMBER_NAME,
     JSON_BOGUS_VALUE,
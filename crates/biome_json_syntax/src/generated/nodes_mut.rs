@@ -52,6 +52,14 @@ impl JsonMember {
     }
 }
 impl JsonMemberName {
+    pub fn with_value_token_token(self, element: SyntaxToken) -> Self {
+        Self::unwrap_cast(
+            self.syntax
+                .splice_slots(0usize..=0usize, once(Some(element.into()))),
+        )
+    }
+}
+impl JsonMetavariable {
     pub fn with_value_token(self, element: SyntaxToken) -> Self {
         Self::unwrap_cast(
             self.syntax
@@ -18,7 +18,7 @@ use std::{
 
 use biome_deserialize::{
     DeserializableType, DeserializableValue, DeserializationContext, Text,
-    json::unescape_json_string,
+    json::{parse_json_number, unescape_json_string},
 };
 use biome_deserialize_macros::Deserializable;
 use biome_json_syntax::{AnyJsonValue, JsonArrayValue, JsonObjectValue, JsonStringValue};
@@ -108,16 +108,16 @@ impl From<AnyJsonValue> for JsonValue {
     fn from(value: AnyJsonValue) -> Self {
         match value {
             AnyJsonValue::JsonArrayValue(array_value) => Self::Array(array_value.into()),
-            AnyJsonValue::JsonBogusValue(_) => Self::Bogus,
+            AnyJsonValue::JsonBogusValue(_) | AnyJsonValue::JsonMetavariable(_) => Self::Bogus,
             AnyJsonValue::JsonBooleanValue(boolean_value) => match boolean_value.value_token() {
                 Ok(value) => Self::Bool(value.text_trimmed() == "true"),
                 Err(_) => Self::Bogus,
             },
             AnyJsonValue::JsonNullValue(_) => Self::Null,
             AnyJsonValue::JsonNumberValue(number_value) => match number_value.value_token() {
-                Ok(value) => match value.text_trimmed().parse() {
-                    Ok(number) => Self::Number(number),
-                    Err(_) => Self::Bogus,
+                Ok(value) => match parse_json_number(value.text_trimmed()) {
+                    Some(number) => Self::Number(number),
+                    None => Self::Bogus,
                 },
                 Err(_) => Self::Bogus,
             },
@@ -44,3 +44,38 @@ fn test_string_combined_sequences() {
         &JsonString::from("Here's some escape sequences:\n\u{1234} \\ /")
     )
 }
+
+#[test]
+fn test_json5_values() {
+    let deserialized = deserialize_from_json_str(
+        r#"{
+  unquoted: 'single \'quoted\'',
+  escapes: "\x41\v\0 line \
+continuation",
+  hex: -0x1F,
+  leading: .5,
+  trailing: 5.,
+  positive: +1,
+  infinity: -Infinity,
+}"#,
+        JsonParserOptions::default().with_json5(),
+        "test_json5",
+    );
+    let object: JsonObject = deserialized.into_deserialized().expect("should parse");
+    assert_eq!(
+        object.get("unquoted").unwrap().as_string().unwrap(),
+        &JsonString::from("single 'quoted'")
+    );
+    assert_eq!(
+        object.get("escapes").unwrap().as_string().unwrap(),
+        &JsonString::from("A\u{000b}\0 line continuation")
+    );
+    assert_eq!(object.get("hex").unwrap().as_number(), Some(-31.0));
+    assert_eq!(object.get("leading").unwrap().as_number(), Some(0.5));
+    assert_eq!(object.get("trailing").unwrap().as_number(), Some(5.0));
+    assert_eq!(object.get("positive").unwrap().as_number(), Some(1.0));
+    assert_eq!(
+        object.get("infinity").unwrap().as_number(),
+        Some(f64::NEG_INFINITY)
+    );
+}
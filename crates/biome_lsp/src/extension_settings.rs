@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use biome_service::workspace::InlayHintKind;
 use camino::Utf8PathBuf;
 use serde::{Deserialize, Serialize};
 use serde_json::{Error, Value};
@@ -27,6 +28,9 @@ pub struct WorkspaceSettings {
 
     /// Experimental settings
     pub experimental: Option<ExperimentalSettings>,
+
+    /// Inlay hints displayed by the editor
+    pub inlay_hints: Option<InlayHintsSettings>,
 }
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq)]
@@ -36,6 +40,30 @@ pub struct ExperimentalSettings {
     pub rename: Option<bool>,
 }
 
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHintsSettings {
+    /// Show the inferred return types of functions. Defaults to `true`.
+    pub function_return_types: Option<bool>,
+
+    /// Show the inferred types of `const` variables. Defaults to `true`.
+    pub variable_types: Option<bool>,
+
+    /// Show the names of the parameters at call sites. Defaults to `true`.
+    pub parameter_names: Option<bool>,
+}
+
+impl InlayHintsSettings {
+    pub(crate) fn is_enabled(&self, kind: InlayHintKind) -> bool {
+        match kind {
+            InlayHintKind::ReturnType => self.function_return_types,
+            InlayHintKind::VariableType => self.variable_types,
+            InlayHintKind::ParameterName => self.parameter_names,
+        }
+        .unwrap_or(true)
+    }
+}
+
 /// The `biome.*` extension settings
 #[derive(Debug)]
 pub(crate) struct ExtensionSettings {
@@ -63,6 +91,10 @@ impl ExtensionSettings {
         self.settings.require_configuration.unwrap_or_default()
     }
 
+    pub(crate) fn inlay_hints(&self) -> InlayHintsSettings {
+        self.settings.inlay_hints.clone().unwrap_or_default()
+    }
+
     pub(crate) fn configuration_path(&self) -> Option<Utf8PathBuf> {
         match self.settings.configuration_path.as_deref() {
             // Ignore if empty as VS Code responses an empty string even if it's not set.
@@ -1,3 +1,9 @@
 pub(crate) mod analysis;
+pub(crate) mod diagnostics;
 pub(crate) mod formatting;
+pub(crate) mod inlay_hints;
+pub(crate) mod navigation;
+pub(crate) mod rename;
+pub(crate) mod semantic_tokens;
+pub(crate) mod symbols;
 pub(crate) mod text_document;
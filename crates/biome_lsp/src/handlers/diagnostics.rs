@@ -0,0 +1,247 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use biome_fs::BiomePath;
+use biome_line_index::LineIndex;
+use biome_service::projects::ProjectKey;
+use biome_service::workspace::{GetFileContentParams, GetProjectFilesParams};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::panic::AssertUnwindSafe;
+use tower_lsp_server::ls_types::*;
+use tracing::error;
+
+/// Returns the diagnostics of a document, or an unchanged report if they're
+/// the same as the ones the client already has.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_diagnostic(
+    session: &Session,
+    params: DocumentDiagnosticParams,
+) -> Result<Option<DocumentDiagnosticReportResult>, LspError> {
+    let url = params.text_document.uri;
+    let path = session.file_path(&url)?;
+    let project_key = match session.document(&url) {
+        Some(doc) => Some(doc.project_key),
+        None => session.project_for_path(&path),
+    };
+    let diagnostics = match project_key {
+        Some(project_key) => file_diagnostics(session, &url, path, project_key)?
+            .map(|file| file.diagnostics)
+            .unwrap_or_default(),
+        None => Vec::new(),
+    };
+
+    let result_id = session.pulled_diagnostics_result_id(&url, &diagnostics);
+    let report = if params.previous_result_id.as_ref() == Some(&result_id) {
+        DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+            related_documents: None,
+            unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport { result_id },
+        })
+    } else {
+        DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
+            related_documents: None,
+            full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                result_id: Some(result_id),
+                items: diagnostics,
+            },
+        })
+    };
+
+    Ok(Some(DocumentDiagnosticReportResult::Report(report)))
+}
+
+/// The number of files reported by each partial result of the workspace
+/// diagnostics
+const PARTIAL_RESULT_SIZE: usize = 32;
+
+/// Returns the diagnostics of all the files of the projects opened in the
+/// session, including the files that are only known to the scanner.
+///
+/// The files whose diagnostics didn't change since the result the client
+/// refers to are reported as unchanged.
+///
+/// When the client provides a partial result token, the reports are streamed
+/// in batches of [PARTIAL_RESULT_SIZE] files, and the final response is empty.
+/// The handler yields between files, so that the other requests and
+/// notifications are handled while the project is processed.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) async fn workspace_diagnostic(
+    session: &Session,
+    params: WorkspaceDiagnosticParams,
+) -> Result<Option<WorkspaceDiagnosticReportResult>, LspError> {
+    let partial_result_token = params.partial_result_params.partial_result_token;
+    let previous_result_ids: FxHashMap<_, _> = params
+        .previous_result_ids
+        .into_iter()
+        .map(|previous| (previous.uri, previous.value))
+        .collect();
+
+    let mut seen = FxHashSet::default();
+    let mut items = Vec::new();
+    for project_key in session.project_keys() {
+        let result = session
+            .workspace
+            .get_project_files(GetProjectFilesParams { project_key })?;
+
+        for path in result.paths {
+            let Some(url) = Uri::from_file_path(path.as_path()) else {
+                continue;
+            };
+            if !seen.insert(url.clone()) {
+                continue;
+            }
+
+            // A file that can't be read, or that makes the analyzer panic,
+            // shouldn't prevent the other files from being reported. The session
+            // is shared with the server, which is already asserted to be unwind safe
+            let result = biome_diagnostics::panic::catch_unwind(AssertUnwindSafe(|| {
+                file_report(
+                    session,
+                    url.clone(),
+                    path,
+                    project_key,
+                    &previous_result_ids,
+                )
+            }));
+            match result {
+                Ok(Ok(Some(item))) => items.push(item),
+                Ok(Ok(None)) => {}
+                Ok(Err(err)) => {
+                    error!(
+                        "Error while pulling the diagnostics of {}: {}",
+                        url.as_str(),
+                        err
+                    );
+                }
+                Err(err) => {
+                    error!(
+                        "Panic while pulling the diagnostics of {}: {}",
+                        url.as_str(),
+                        err
+                    );
+                }
+            }
+
+            if let Some(token) = &partial_result_token
+                && items.len() >= PARTIAL_RESULT_SIZE
+            {
+                send_partial_result(session, token, std::mem::take(&mut items)).await;
+            }
+            tokio::task::yield_now().await;
+        }
+    }
+
+    if let Some(token) = &partial_result_token
+        && !items.is_empty()
+    {
+        send_partial_result(session, token, std::mem::take(&mut items)).await;
+    }
+
+    Ok(Some(WorkspaceDiagnosticReportResult::Report(
+        WorkspaceDiagnosticReport { items },
+    )))
+}
+
+/// Returns the report of a file for the workspace diagnostics, or `None` if
+/// the file isn't analyzed.
+fn file_report(
+    session: &Session,
+    url: Uri,
+    path: BiomePath,
+    project_key: ProjectKey,
+    previous_result_ids: &FxHashMap<Uri, String>,
+) -> Result<Option<WorkspaceDocumentDiagnosticReport>, LspError> {
+    let Some(FileDiagnostics {
+        version,
+        diagnostics,
+    }) = file_diagnostics(session, &url, path, project_key)?
+    else {
+        return Ok(None);
+    };
+
+    let result_id = session.pulled_diagnostics_result_id(&url, &diagnostics);
+    let report = if previous_result_ids.get(&url) == Some(&result_id) {
+        WorkspaceDocumentDiagnosticReport::Unchanged(WorkspaceUnchangedDocumentDiagnosticReport {
+            uri: url,
+            version,
+            unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport { result_id },
+        })
+    } else {
+        WorkspaceDocumentDiagnosticReport::Full(WorkspaceFullDocumentDiagnosticReport {
+            uri: url,
+            version,
+            full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                result_id: Some(result_id),
+                items: diagnostics,
+            },
+        })
+    };
+
+    Ok(Some(report))
+}
+
+/// The `$/progress` notification that carries a partial result of the
+/// workspace diagnostics. `ls_types` only models the work done progress.
+enum WorkspaceDiagnosticProgress {}
+
+impl notification::Notification for WorkspaceDiagnosticProgress {
+    type Params = WorkspaceDiagnosticProgressParams;
+    const METHOD: &'static str = "$/progress";
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct WorkspaceDiagnosticProgressParams {
+    token: ProgressToken,
+    value: WorkspaceDiagnosticReportPartialResult,
+}
+
+async fn send_partial_result(
+    session: &Session,
+    token: &ProgressToken,
+    items: Vec<WorkspaceDocumentDiagnosticReport>,
+) {
+    session
+        .client
+        .send_notification::<WorkspaceDiagnosticProgress>(WorkspaceDiagnosticProgressParams {
+            token: token.clone(),
+            value: WorkspaceDiagnosticReportPartialResult { items },
+        })
+        .await;
+}
+
+struct FileDiagnostics {
+    /// The version of the document, if it's opened by the client
+    version: Option<i64>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// Computes the diagnostics of a file, or returns `None` if the file is
+/// neither linted nor checked by the assist.
+///
+/// The content of the files that aren't opened is retrieved from the
+/// workspace, where the scanner stored it.
+fn file_diagnostics(
+    session: &Session,
+    url: &Uri,
+    path: BiomePath,
+    project_key: ProjectKey,
+) -> Result<Option<FileDiagnostics>, LspError> {
+    if let Some(doc) = session.document(url) {
+        let diagnostics =
+            session.compute_diagnostics(url, path, doc.project_key, &doc.line_index)?;
+        return Ok(diagnostics.map(|diagnostics| FileDiagnostics {
+            version: Some(i64::from(doc.version)),
+            diagnostics,
+        }));
+    }
+
+    let content = session.workspace.get_file_content(GetFileContentParams {
+        project_key,
+        path: path.clone(),
+    })?;
+    let line_index = LineIndex::new(&content);
+    let diagnostics = session.compute_diagnostics(url, path, project_key, &line_index)?;
+    Ok(diagnostics.map(|diagnostics| FileDiagnostics {
+        version: None,
+        diagnostics,
+    }))
+}
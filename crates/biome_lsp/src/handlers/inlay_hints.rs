@@ -0,0 +1,61 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_service::workspace::{self, GetInlayHintsParams};
+use tower_lsp_server::ls_types::*;
+
+/// Returns the hints of the requested range that are enabled in the
+/// extension settings.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn inlay_hint(
+    session: &Session,
+    params: InlayHintParams,
+) -> Result<Option<Vec<InlayHint>>, LspError> {
+    let url = params.text_document.uri;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let path = session.file_path(&url)?;
+    let position_encoding = session.position_encoding();
+    let range = from_proto::text_range(&doc.line_index, params.range, position_encoding)
+        .with_context(|| {
+            format!(
+                "failed to access range {:?} in document {}",
+                params.range,
+                url.as_str()
+            )
+        })?;
+
+    let settings = session.inlay_hints_settings();
+    let result = session.workspace.get_inlay_hints(GetInlayHintsParams {
+        project_key: doc.project_key,
+        path,
+        range,
+    })?;
+
+    let mut hints = Vec::with_capacity(result.hints.len());
+    for hint in result.hints {
+        if !settings.is_enabled(hint.kind) {
+            continue;
+        }
+
+        let is_parameter_name = hint.kind == workspace::InlayHintKind::ParameterName;
+        hints.push(InlayHint {
+            position: to_proto::position(&doc.line_index, hint.position, position_encoding)?,
+            label: InlayHintLabel::String(hint.label),
+            kind: Some(if is_parameter_name {
+                InlayHintKind::PARAMETER
+            } else {
+                InlayHintKind::TYPE
+            }),
+            text_edits: None,
+            tooltip: None,
+            padding_left: None,
+            padding_right: is_parameter_name.then_some(true),
+            data: None,
+        });
+    }
+
+    Ok(Some(hints))
+}
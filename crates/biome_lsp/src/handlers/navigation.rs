@@ -0,0 +1,204 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_fs::BiomePath;
+use biome_line_index::LineIndex;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_rowan::TextSize;
+use biome_service::projects::ProjectKey;
+use biome_service::workspace::{
+    self, GetCompletionsParams, GetDefinitionParams, GetFileContentParams, GetHoverParams,
+    GetReferencesParams, SymbolLocation,
+};
+use tower_lsp_server::ls_types::*;
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn hover(session: &Session, params: HoverParams) -> Result<Option<Hover>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let path = session.file_path(&url)?;
+    let offset = offset(session, &doc.line_index, &url, position)?;
+
+    let result = session.workspace.get_hover(GetHoverParams {
+        project_key: doc.project_key,
+        path,
+        offset,
+    })?;
+    let Some(content) = result.content else {
+        return Ok(None);
+    };
+    let range = result
+        .range
+        .map(|range| to_proto::range(&doc.line_index, range, session.position_encoding()))
+        .transpose()?;
+
+    Ok(Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: content,
+        }),
+        range,
+    }))
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn goto_definition(
+    session: &Session,
+    params: GotoDefinitionParams,
+) -> Result<Option<GotoDefinitionResponse>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let path = session.file_path(&url)?;
+    let offset = offset(session, &doc.line_index, &url, position)?;
+
+    let result = session.workspace.get_definition(GetDefinitionParams {
+        project_key: doc.project_key,
+        path,
+        offset,
+    })?;
+    if result.locations.is_empty() {
+        return Ok(None);
+    }
+
+    let locations = to_locations(session, doc.project_key, result.locations)?;
+
+    Ok(Some(GotoDefinitionResponse::Array(locations)))
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn references(
+    session: &Session,
+    params: ReferenceParams,
+) -> Result<Option<Vec<Location>>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let path = session.file_path(&url)?;
+    let offset = offset(session, &doc.line_index, &url, position)?;
+
+    let result = session.workspace.get_references(GetReferencesParams {
+        project_key: doc.project_key,
+        path,
+        offset,
+        include_declaration: params.context.include_declaration,
+    })?;
+    if result.locations.is_empty() {
+        return Ok(None);
+    }
+
+    let locations = to_locations(session, doc.project_key, result.locations)?;
+
+    Ok(Some(locations))
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn completion(
+    session: &Session,
+    params: CompletionParams,
+) -> Result<Option<CompletionResponse>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let path = session.file_path(&url)?;
+    let offset = offset(session, &doc.line_index, &url, position)?;
+
+    let result = session.workspace.get_completions(GetCompletionsParams {
+        project_key: doc.project_key,
+        path,
+        offset,
+    })?;
+    if result.items.is_empty() {
+        return Ok(None);
+    }
+
+    let items = result
+        .items
+        .into_iter()
+        .map(|item| CompletionItem {
+            label: item.label,
+            kind: Some(match item.kind {
+                workspace::CompletionItemKind::Property => CompletionItemKind::PROPERTY,
+                workspace::CompletionItemKind::Value => CompletionItemKind::VALUE,
+            }),
+            documentation: item.documentation.map(|documentation| {
+                Documentation::MarkupContent(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: documentation,
+                })
+            }),
+            ..Default::default()
+        })
+        .collect();
+
+    Ok(Some(CompletionResponse::Array(items)))
+}
+
+pub(crate) fn offset(
+    session: &Session,
+    line_index: &LineIndex,
+    url: &Uri,
+    position: Position,
+) -> Result<TextSize, LspError> {
+    let offset = from_proto::offset(line_index, position, session.position_encoding())
+        .with_context(|| {
+            format!(
+                "failed to access position {position:?} in document {}",
+                url.as_str()
+            )
+        })?;
+
+    Ok(offset)
+}
+
+/// Converts the locations returned by the workspace into LSP locations.
+fn to_locations(
+    session: &Session,
+    project_key: ProjectKey,
+    locations: Vec<SymbolLocation>,
+) -> Result<Vec<Location>, LspError> {
+    let position_encoding = session.position_encoding();
+    let mut result = Vec::with_capacity(locations.len());
+    for location in locations {
+        let Some(uri) = Uri::from_file_path(location.path.as_path()) else {
+            continue;
+        };
+
+        let line_index = line_index(session, project_key, &uri, &location.path)?;
+        let range = to_proto::range(&line_index, location.range, position_encoding)?;
+        result.push(Location { uri, range });
+    }
+
+    Ok(result)
+}
+
+/// Returns the line index of a file that may not be open in the client.
+///
+/// Open documents use their own line index, the other ones are computed from
+/// the content the workspace has for the file.
+pub(crate) fn line_index(
+    session: &Session,
+    project_key: ProjectKey,
+    url: &Uri,
+    path: &BiomePath,
+) -> Result<LineIndex, LspError> {
+    if let Some(doc) = session.document(url) {
+        return Ok(doc.line_index);
+    }
+
+    let content = session.workspace.get_file_content(GetFileContentParams {
+        project_key,
+        path: path.clone(),
+    })?;
+
+    Ok(LineIndex::new(&content))
+}
@@ -0,0 +1,91 @@
+use crate::diagnostics::LspError;
+use crate::handlers::navigation::{line_index, offset};
+use crate::session::Session;
+use crate::utils::text_edit;
+use biome_lsp_converters::to_proto;
+use biome_service::workspace::{self, PrepareRenameParams};
+use std::collections::HashMap;
+use tower_lsp_server::ls_types::*;
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn prepare_rename(
+    session: &Session,
+    params: TextDocumentPositionParams,
+) -> Result<Option<PrepareRenameResponse>, LspError> {
+    let url = params.text_document.uri;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let path = session.file_path(&url)?;
+    let symbol_at = offset(session, &doc.line_index, &url, params.position)?;
+
+    let result = session.workspace.prepare_rename(PrepareRenameParams {
+        project_key: doc.project_key,
+        path,
+        symbol_at,
+    })?;
+    let (Some(range), Some(placeholder)) = (result.range, result.placeholder) else {
+        return Ok(None);
+    };
+    let range = to_proto::range(&doc.line_index, range, session.position_encoding())?;
+
+    Ok(Some(PrepareRenameResponse::RangeWithPlaceholder {
+        range,
+        placeholder,
+    }))
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn rename(
+    session: &Session,
+    params: RenameParams,
+) -> Result<Option<WorkspaceEdit>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let path = session.file_path(&url)?;
+    let position_encoding = session.position_encoding();
+    let symbol_at = offset(
+        session,
+        &doc.line_index,
+        &url,
+        params.text_document_position.position,
+    )?;
+
+    let result = session.workspace.rename(workspace::RenameParams {
+        project_key: doc.project_key,
+        path,
+        symbol_at,
+        new_name: params.new_name,
+    })?;
+
+    let mut changes = HashMap::new();
+    let edits = text_edit(
+        &doc.line_index,
+        result.indels,
+        position_encoding,
+        Some(result.range.start().into()),
+    )?;
+    changes.insert(url, edits);
+
+    for file_edit in result.file_edits {
+        let Some(uri) = Uri::from_file_path(file_edit.path.as_path()) else {
+            continue;
+        };
+
+        let line_index = line_index(session, doc.project_key, &uri, &file_edit.path)?;
+        let edits = text_edit(
+            &line_index,
+            file_edit.indels,
+            position_encoding,
+            Some(file_edit.range.start().into()),
+        )?;
+        changes.entry(uri).or_insert_with(Vec::new).extend(edits);
+    }
+
+    Ok(Some(WorkspaceEdit {
+        changes: Some(changes),
+        ..Default::default()
+    }))
+}
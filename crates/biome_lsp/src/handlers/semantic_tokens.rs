@@ -0,0 +1,189 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use biome_lsp_converters::to_proto;
+use biome_service::workspace::{self, GetSemanticTokensParams};
+use tower_lsp_server::ls_types::*;
+
+/// The token types sent to the client, indexed by [to_token_type].
+const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::TYPE,
+    SemanticTokenType::CLASS,
+    SemanticTokenType::ENUM,
+    SemanticTokenType::INTERFACE,
+    SemanticTokenType::STRUCT,
+    SemanticTokenType::TYPE_PARAMETER,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::ENUM_MEMBER,
+    SemanticTokenType::FUNCTION,
+];
+
+/// The token modifiers sent to the client, indexed by [to_token_modifier].
+/// `unused` isn't part of the predefined modifiers of the protocol.
+const TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::READONLY,
+    SemanticTokenModifier::DEFAULT_LIBRARY,
+    SemanticTokenModifier::new("unused"),
+];
+
+pub(crate) fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_full(
+    session: &Session,
+    params: SemanticTokensParams,
+) -> Result<Option<SemanticTokensResult>, LspError> {
+    let url = params.text_document.uri;
+    let Some(data) = compute_semantic_tokens(session, &url)? else {
+        return Ok(None);
+    };
+
+    let result_id = session.insert_semantic_tokens(url, data.clone());
+    Ok(Some(SemanticTokensResult::Tokens(SemanticTokens {
+        result_id: Some(result_id),
+        data,
+    })))
+}
+
+/// Returns the edits to apply to the tokens previously sent to the client,
+/// or all the tokens if the client refers to tokens that aren't known
+/// anymore.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_full_delta(
+    session: &Session,
+    params: SemanticTokensDeltaParams,
+) -> Result<Option<SemanticTokensFullDeltaResult>, LspError> {
+    let url = params.text_document.uri;
+    let Some(data) = compute_semantic_tokens(session, &url)? else {
+        return Ok(None);
+    };
+
+    let previous = session.semantic_tokens(&url, &params.previous_result_id);
+    let result_id = session.insert_semantic_tokens(url, data.clone());
+    let Some(previous) = previous else {
+        return Ok(Some(SemanticTokensFullDeltaResult::Tokens(
+            SemanticTokens {
+                result_id: Some(result_id),
+                data,
+            },
+        )));
+    };
+
+    Ok(Some(SemanticTokensFullDeltaResult::TokensDelta(
+        SemanticTokensDelta {
+            result_id: Some(result_id),
+            edits: diff_tokens(&previous, &data).into_iter().collect(),
+        },
+    )))
+}
+
+/// Returns the tokens of a document, encoded relatively to each other as
+/// required by the protocol.
+fn compute_semantic_tokens(
+    session: &Session,
+    url: &Uri,
+) -> Result<Option<Vec<SemanticToken>>, LspError> {
+    let Some(doc) = session.document(url) else {
+        return Ok(None);
+    };
+    let path = session.file_path(url)?;
+
+    let result = session
+        .workspace
+        .get_semantic_tokens(GetSemanticTokensParams {
+            project_key: doc.project_key,
+            path,
+        })?;
+
+    let mut data = Vec::with_capacity(result.tokens.len());
+    let mut previous = Position::default();
+    for token in result.tokens {
+        let range = to_proto::range(&doc.line_index, token.range, session.position_encoding())?;
+        // Multi-line tokens aren't supported by all the clients
+        if range.start.line != range.end.line {
+            continue;
+        }
+
+        let delta_line = range.start.line - previous.line;
+        let delta_start = if delta_line == 0 {
+            range.start.character - previous.character
+        } else {
+            range.start.character
+        };
+        data.push(SemanticToken {
+            delta_line,
+            delta_start,
+            length: range.end.character - range.start.character,
+            token_type: to_token_type(token.kind),
+            token_modifiers_bitset: token.modifiers.into_iter().fold(0, |bitset, modifier| {
+                bitset | 1 << to_token_modifier(modifier)
+            }),
+        });
+        previous = range.start;
+    }
+
+    Ok(Some(data))
+}
+
+/// Returns a single edit that replaces the tokens between the common prefix
+/// and the common suffix of the two lists, if they differ.
+///
+/// The offsets of the edit are expressed in integers, and each token is
+/// encoded with five of them.
+fn diff_tokens(previous: &[SemanticToken], next: &[SemanticToken]) -> Option<SemanticTokensEdit> {
+    let prefix = previous
+        .iter()
+        .zip(next)
+        .take_while(|(previous, next)| previous == next)
+        .count();
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(next[prefix..].iter().rev())
+        .take_while(|(previous, next)| previous == next)
+        .count();
+
+    let deleted = previous.len() - prefix - suffix;
+    let inserted = &next[prefix..next.len() - suffix];
+    if deleted == 0 && inserted.is_empty() {
+        return None;
+    }
+
+    Some(SemanticTokensEdit {
+        start: (prefix * 5) as u32,
+        delete_count: (deleted * 5) as u32,
+        data: Some(inserted.to_vec()),
+    })
+}
+
+fn to_token_type(kind: workspace::SemanticTokenKind) -> u32 {
+    match kind {
+        workspace::SemanticTokenKind::Namespace => 0,
+        workspace::SemanticTokenKind::Type => 1,
+        workspace::SemanticTokenKind::Class => 2,
+        workspace::SemanticTokenKind::Enum => 3,
+        workspace::SemanticTokenKind::Interface => 4,
+        workspace::SemanticTokenKind::Struct => 5,
+        workspace::SemanticTokenKind::TypeParameter => 6,
+        workspace::SemanticTokenKind::Parameter => 7,
+        workspace::SemanticTokenKind::Variable => 8,
+        workspace::SemanticTokenKind::EnumMember => 9,
+        workspace::SemanticTokenKind::Function => 10,
+    }
+}
+
+fn to_token_modifier(modifier: workspace::SemanticTokenModifier) -> u32 {
+    match modifier {
+        workspace::SemanticTokenModifier::Declaration => 0,
+        workspace::SemanticTokenModifier::Readonly => 1,
+        workspace::SemanticTokenModifier::DefaultLibrary => 2,
+        workspace::SemanticTokenModifier::Unused => 3,
+    }
+}
@@ -0,0 +1,184 @@
+use crate::diagnostics::LspError;
+use crate::handlers::navigation::line_index;
+use crate::session::Session;
+use biome_line_index::LineIndex;
+use biome_lsp_converters::{PositionEncoding, to_proto};
+use biome_service::workspace::{
+    self, GetDocumentSymbolsParams, GetFoldingRangesParams, GetWorkspaceSymbolsParams,
+};
+use rustc_hash::FxHashSet;
+use tower_lsp_server::ls_types::*;
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_symbol(
+    session: &Session,
+    params: DocumentSymbolParams,
+) -> Result<Option<DocumentSymbolResponse>, LspError> {
+    let url = params.text_document.uri;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let path = session.file_path(&url)?;
+
+    let result = session
+        .workspace
+        .get_document_symbols(GetDocumentSymbolsParams {
+            project_key: doc.project_key,
+            path,
+        })?;
+    if result.symbols.is_empty() {
+        return Ok(None);
+    }
+
+    let symbols = result
+        .symbols
+        .into_iter()
+        .map(|symbol| to_document_symbol(&doc.line_index, session.position_encoding(), symbol))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+}
+
+/// Searches the symbols exported by the modules of all the projects opened in
+/// the session.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn workspace_symbol(
+    session: &Session,
+    params: WorkspaceSymbolParams,
+) -> Result<Option<WorkspaceSymbolResponse>, LspError> {
+    let position_encoding = session.position_encoding();
+    let mut seen = FxHashSet::default();
+    let mut symbols = Vec::new();
+    for project_key in session.project_keys() {
+        let result = session
+            .workspace
+            .get_workspace_symbols(GetWorkspaceSymbolsParams {
+                project_key,
+                query: params.query.clone(),
+            })?;
+
+        for symbol in result.symbols {
+            // Nested projects return the symbols of their files as well
+            if !seen.insert(symbol.location.clone()) {
+                continue;
+            }
+            let Some(uri) = Uri::from_file_path(symbol.location.path.as_path()) else {
+                continue;
+            };
+
+            let line_index = line_index(session, project_key, &uri, &symbol.location.path)?;
+            let range = to_proto::range(&line_index, symbol.location.range, position_encoding)?;
+            symbols.push(WorkspaceSymbol {
+                name: symbol.name,
+                kind: to_symbol_kind(symbol.kind),
+                tags: None,
+                container_name: None,
+                location: OneOf::Left(Location { uri, range }),
+                data: None,
+            });
+        }
+    }
+
+    Ok(Some(WorkspaceSymbolResponse::Nested(symbols)))
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn folding_range(
+    session: &Session,
+    params: FoldingRangeParams,
+) -> Result<Option<Vec<FoldingRange>>, LspError> {
+    let url = params.text_document.uri;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let path = session.file_path(&url)?;
+
+    let result = session
+        .workspace
+        .get_folding_ranges(GetFoldingRangesParams {
+            project_key: doc.project_key,
+            path,
+        })?;
+
+    let mut ranges = Vec::with_capacity(result.ranges.len());
+    for folding_range in result.ranges {
+        let range = to_proto::range(
+            &doc.line_index,
+            folding_range.range,
+            session.position_encoding(),
+        )?;
+        // The closing delimiter of a region stays visible when it's folded
+        let end_line = match folding_range.kind {
+            workspace::FoldingRangeKind::Region => range.end.line.saturating_sub(1),
+            workspace::FoldingRangeKind::Comment | workspace::FoldingRangeKind::Imports => {
+                range.end.line
+            }
+        };
+        if end_line <= range.start.line {
+            continue;
+        }
+
+        ranges.push(FoldingRange {
+            start_line: range.start.line,
+            end_line,
+            kind: Some(match folding_range.kind {
+                workspace::FoldingRangeKind::Region => FoldingRangeKind::Region,
+                workspace::FoldingRangeKind::Comment => FoldingRangeKind::Comment,
+                workspace::FoldingRangeKind::Imports => FoldingRangeKind::Imports,
+            }),
+            ..Default::default()
+        });
+    }
+
+    Ok(Some(ranges))
+}
+
+#[expect(deprecated)]
+fn to_document_symbol(
+    line_index: &LineIndex,
+    position_encoding: PositionEncoding,
+    symbol: workspace::DocumentSymbol,
+) -> Result<DocumentSymbol, LspError> {
+    let children = symbol
+        .children
+        .into_iter()
+        .map(|child| to_document_symbol(line_index, position_encoding, child))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(DocumentSymbol {
+        name: symbol.name,
+        detail: None,
+        kind: to_symbol_kind(symbol.kind),
+        tags: None,
+        deprecated: None,
+        range: to_proto::range(line_index, symbol.range, position_encoding)?,
+        selection_range: to_proto::range(line_index, symbol.selection_range, position_encoding)?,
+        children: (!children.is_empty()).then_some(children),
+    })
+}
+
+fn to_symbol_kind(kind: workspace::SymbolKind) -> SymbolKind {
+    match kind {
+        workspace::SymbolKind::Module => SymbolKind::MODULE,
+        workspace::SymbolKind::Namespace => SymbolKind::NAMESPACE,
+        workspace::SymbolKind::Class => SymbolKind::CLASS,
+        workspace::SymbolKind::Method => SymbolKind::METHOD,
+        workspace::SymbolKind::Property => SymbolKind::PROPERTY,
+        workspace::SymbolKind::Field => SymbolKind::FIELD,
+        workspace::SymbolKind::Constructor => SymbolKind::CONSTRUCTOR,
+        workspace::SymbolKind::Enum => SymbolKind::ENUM,
+        workspace::SymbolKind::Interface => SymbolKind::INTERFACE,
+        workspace::SymbolKind::Function => SymbolKind::FUNCTION,
+        workspace::SymbolKind::Variable => SymbolKind::VARIABLE,
+        workspace::SymbolKind::Constant => SymbolKind::CONSTANT,
+        workspace::SymbolKind::String => SymbolKind::STRING,
+        workspace::SymbolKind::Number => SymbolKind::NUMBER,
+        workspace::SymbolKind::Boolean => SymbolKind::BOOLEAN,
+        workspace::SymbolKind::Array => SymbolKind::ARRAY,
+        workspace::SymbolKind::Object => SymbolKind::OBJECT,
+        workspace::SymbolKind::Null => SymbolKind::NULL,
+        workspace::SymbolKind::EnumMember => SymbolKind::ENUM_MEMBER,
+        workspace::SymbolKind::Struct => SymbolKind::STRUCT,
+        workspace::SymbolKind::TypeParameter => SymbolKind::TYPE_PARAMETER,
+    }
+}
@@ -364,6 +364,12 @@ impl LanguageServer for LSPServer {
 
     #[tracing::instrument(level = "debug", skip_all)]
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        for change in &params.changes {
+            if change.typ == FileChangeType::DELETED {
+                self.session.remove_pulled_diagnostics(&change.uri);
+            }
+        }
+
         let file_paths = params
             .changes
             .iter()
@@ -452,6 +458,156 @@ impl LanguageServer for LSPServer {
         self.map_op_error(result).await
     }
 
+    async fn hover(&self, params: HoverParams) -> LspResult<Option<Hover>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::hover(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn completion(&self, params: CompletionParams) -> LspResult<Option<CompletionResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::completion(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> LspResult<Option<GotoDefinitionResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::goto_definition(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn references(&self, params: ReferenceParams) -> LspResult<Option<Vec<Location>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::references(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> LspResult<Option<DocumentSymbolResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::document_symbol(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> LspResult<Option<WorkspaceSymbolResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::workspace_symbol(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn folding_range(
+        &self,
+        params: FoldingRangeParams,
+    ) -> LspResult<Option<Vec<FoldingRange>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::folding_range(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> LspResult<DocumentDiagnosticReportResult> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::diagnostics::document_diagnostic(&self.session, params)
+        });
+
+        // The protocol doesn't allow an empty response
+        self.map_op_error(result).await.map(|report| {
+            report.unwrap_or_else(|| {
+                DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(
+                    RelatedFullDocumentDiagnosticReport::default(),
+                ))
+            })
+        })
+    }
+
+    async fn workspace_diagnostic(
+        &self,
+        params: WorkspaceDiagnosticParams,
+    ) -> LspResult<WorkspaceDiagnosticReportResult> {
+        // The handler catches the panics of each file, so that they don't
+        // prevent the other files from being reported
+        let result = handlers::diagnostics::workspace_diagnostic(&self.session, params).await;
+
+        self.map_op_error(Ok(result)).await.map(|report| {
+            report.unwrap_or_else(|| {
+                WorkspaceDiagnosticReportResult::Report(WorkspaceDiagnosticReport::default())
+            })
+        })
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> LspResult<Option<Vec<InlayHint>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::inlay_hints::inlay_hint(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> LspResult<Option<SemanticTokensResult>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_full(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn semantic_tokens_full_delta(
+        &self,
+        params: SemanticTokensDeltaParams,
+    ) -> LspResult<Option<SemanticTokensFullDeltaResult>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_full_delta(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LspResult<Option<PrepareRenameResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::rename::prepare_rename(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::rename::rename(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
     async fn formatting(
         &self,
         params: DocumentFormattingParams,
@@ -664,6 +820,7 @@ impl ServerFactory {
         workspace_method!(builder, change_file);
         workspace_method!(builder, check_file_size);
         workspace_method!(builder, get_file_content);
+        workspace_method!(builder, get_dependent_packages);
         workspace_method!(builder, close_file);
         workspace_method!(builder, pull_diagnostics);
         workspace_method!(builder, pull_actions);
@@ -672,6 +829,17 @@ impl ServerFactory {
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
+        workspace_method!(builder, prepare_rename);
+        workspace_method!(builder, get_hover);
+        workspace_method!(builder, get_completions);
+        workspace_method!(builder, get_definition);
+        workspace_method!(builder, get_references);
+        workspace_method!(builder, get_document_symbols);
+        workspace_method!(builder, get_workspace_symbols);
+        workspace_method!(builder, get_folding_ranges);
+        workspace_method!(builder, get_semantic_tokens);
+        workspace_method!(builder, get_inlay_hints);
+        workspace_method!(builder, get_project_files);
         workspace_method!(builder, parse_pattern);
         workspace_method!(builder, search_pattern);
         workspace_method!(builder, drop_pattern);
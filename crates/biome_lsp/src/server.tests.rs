@@ -386,6 +386,7 @@ const CHANNEL_BUFFER_SIZE: usize = 8;
 enum ServerNotification {
     PublishDiagnostics(PublishDiagnosticsParams),
     ShowMessage(ShowMessageParams),
+    Progress(serde_json::Value),
 }
 impl ServerNotification {
     pub fn is_publish_diagnostics(&self) -> bool {
@@ -441,6 +442,7 @@ where
             "window/showMessage" => Some(ServerNotification::ShowMessage(
                 from_value(params).expect("invalid params"),
             )),
+            "$/progress" => Some(ServerNotification::Progress(params)),
             _ => None,
         } {
             match notify.send(notification).await {
@@ -3468,6 +3470,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles").into()],
                 pull_code_actions: false,
+                pull_suppressed: false,
             },
         )
         .await?
@@ -3498,6 +3501,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles").into()],
                 pull_code_actions: false,
+                pull_suppressed: false,
             },
         )
         .await?
@@ -3524,6 +3528,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles").into()],
                 pull_code_actions: false,
+                pull_suppressed: false,
             },
         )
         .await?
@@ -3554,6 +3559,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles").into()],
                 pull_code_actions: false,
+                pull_suppressed: false,
             },
         )
         .await?
@@ -3679,6 +3685,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles").into()],
                 pull_code_actions: false,
+                pull_suppressed: false,
             },
         )
         .await?
@@ -3713,6 +3720,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles").into()],
                 pull_code_actions: false,
+                pull_suppressed: false,
             },
         )
         .await?
@@ -3744,6 +3752,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles").into()],
                 pull_code_actions: false,
+                pull_suppressed: false,
             },
         )
         .await?
@@ -3980,6 +3989,1230 @@ async fn did_save_syncs_content_from_text_parameter() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn rename_exported_symbol_across_files() -> Result<()> {
+    const FOO_CONTENT: &str = "export function foo() {}\n\nfoo();\n";
+    const INDEX_CONTENT: &str = "import { foo } from \"./foo.ts\";\n\nfoo();\n";
+    const ALIAS_CONTENT: &str = "import { foo as f } from \"./foo.ts\";\n\nf();\n";
+    const NAMESPACE_CONTENT: &str = "import * as utils from \"./foo.ts\";\n\nutils.foo();\n";
+    const REEXPORT_CONTENT: &str = "export { foo } from \"./foo.ts\";\n";
+
+    // The module graph is only populated when the project is scanned, which
+    // requires a rule of the project domain.
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noImportCycles": "error"
+      }
+    }
+  }
+}
+"#,
+    );
+    let documents = [
+        (uri!("foo.ts"), FOO_CONTENT),
+        (uri!("index.ts"), INDEX_CONTENT),
+        (uri!("alias.ts"), ALIAS_CONTENT),
+        (uri!("namespace.ts"), NAMESPACE_CONTENT),
+        (uri!("reexport.ts"), REEXPORT_CONTENT),
+    ];
+    for (uri, content) in &documents {
+        fs.insert(to_utf8_file_path_buf(uri.clone()), *content);
+    }
+
+    let factory = ServerFactory::new_with_fs(Arc::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    for (uri, content) in &documents {
+        server
+            .open_named_document(*content, uri.clone(), "typescript")
+            .await?;
+    }
+
+    let text_document_position = lsp::TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier {
+            uri: uri!("index.ts"),
+        },
+        position: Position::new(2, 1),
+    };
+
+    let prepare_rename: Option<lsp::PrepareRenameResponse> = server
+        .request(
+            "textDocument/prepareRename",
+            "prepare_rename",
+            text_document_position.clone(),
+        )
+        .await?
+        .context("prepareRename returned None")?;
+    assert_eq!(
+        prepare_rename,
+        Some(lsp::PrepareRenameResponse::RangeWithPlaceholder {
+            range: Range::new(Position::new(2, 0), Position::new(2, 3)),
+            placeholder: "foo".to_string(),
+        })
+    );
+
+    let workspace_edit: Option<lsp::WorkspaceEdit> = server
+        .request(
+            "textDocument/rename",
+            "rename",
+            lsp::RenameParams {
+                text_document_position,
+                new_name: "bar".to_string(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .context("rename returned None")?;
+    let changes = workspace_edit
+        .and_then(|workspace_edit| workspace_edit.changes)
+        .context("rename returned no changes")?;
+
+    let renamed = |uri: Uri, content: &str| {
+        changes.get(&uri).map_or_else(
+            || content.to_string(),
+            |edits| apply_text_edits(content, edits),
+        )
+    };
+    assert_eq!(
+        renamed(uri!("foo.ts"), FOO_CONTENT),
+        "export function bar() {}\n\nbar();\n"
+    );
+    assert_eq!(
+        renamed(uri!("index.ts"), INDEX_CONTENT),
+        "import { bar } from \"./foo.ts\";\n\nbar();\n"
+    );
+    assert_eq!(
+        renamed(uri!("alias.ts"), ALIAS_CONTENT),
+        "import { bar as f } from \"./foo.ts\";\n\nf();\n"
+    );
+    assert_eq!(
+        renamed(uri!("namespace.ts"), NAMESPACE_CONTENT),
+        "import * as utils from \"./foo.ts\";\n\nutils.bar();\n"
+    );
+    assert_eq!(
+        renamed(uri!("reexport.ts"), REEXPORT_CONTENT),
+        "export { bar } from \"./foo.ts\";\n"
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn completions_from_json_schema_of_overrides() -> Result<()> {
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{
+  "overrides": [
+    {
+      "includes": ["config.json"],
+      "json": { "linter": { "schema": "./config.schema.json" } }
+    }
+  ]
+}
+"#,
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("config.schema.json")),
+        r#"{
+  "type": "object",
+  "properties": {
+    "name": { "type": "string", "description": "Name of the project" },
+    "private": { "type": "boolean" }
+  }
+}
+"#,
+    );
+
+    let factory = ServerFactory::new_with_fs(Arc::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document("{\n  \"private\": \n}\n", uri!("config.json"), "json")
+        .await?;
+
+    let completion = |line, character| lsp::CompletionParams {
+        text_document_position: lsp::TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier {
+                uri: uri!("config.json"),
+            },
+            position: Position::new(line, character),
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: lsp::PartialResultParams::default(),
+        context: None,
+    };
+
+    let labels = |response: Option<lsp::CompletionResponse>| match response {
+        Some(lsp::CompletionResponse::Array(items)) => items
+            .into_iter()
+            .map(|item| (item.label, item.kind))
+            .collect::<Vec<_>>(),
+        _ => Vec::new(),
+    };
+
+    let properties: Option<lsp::CompletionResponse> = server
+        .request("textDocument/completion", "completion", completion(0, 1))
+        .await?
+        .context("completion returned None")?;
+    assert_eq!(
+        labels(properties),
+        [("name".to_string(), Some(lsp::CompletionItemKind::PROPERTY))]
+    );
+
+    let values: Option<lsp::CompletionResponse> = server
+        .request("textDocument/completion", "completion", completion(1, 13))
+        .await?
+        .context("completion returned None")?;
+    assert_eq!(
+        labels(values),
+        [
+            ("true".to_string(), Some(lsp::CompletionItemKind::VALUE)),
+            ("false".to_string(), Some(lsp::CompletionItemKind::VALUE))
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn document_symbols_of_typescript_file() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(
+            "class Foo {\n  bar = 1;\n  baz() {}\n}\n\nconst handler = () => {};\ninterface Options {\n  debug: boolean;\n}\n",
+            uri!("document.ts"),
+            "typescript",
+        )
+        .await?;
+
+    let response: Option<lsp::DocumentSymbolResponse> = server
+        .request(
+            "textDocument/documentSymbol",
+            "document_symbol",
+            lsp::DocumentSymbolParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("document.ts"),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("documentSymbol returned None")?;
+    let Some(lsp::DocumentSymbolResponse::Nested(symbols)) = response else {
+        panic!("expected nested symbols, got {response:?}");
+    };
+
+    fn outline(symbols: &[lsp::DocumentSymbol]) -> Vec<(String, lsp::SymbolKind, usize)> {
+        symbols
+            .iter()
+            .map(|symbol| {
+                let children = symbol.children.as_deref().unwrap_or_default();
+                (symbol.name.clone(), symbol.kind, children.len())
+            })
+            .collect()
+    }
+
+    assert_eq!(
+        outline(&symbols),
+        [
+            ("Foo".to_string(), lsp::SymbolKind::CLASS, 2),
+            ("handler".to_string(), lsp::SymbolKind::FUNCTION, 0),
+            ("Options".to_string(), lsp::SymbolKind::INTERFACE, 1),
+        ]
+    );
+    assert_eq!(
+        outline(symbols[0].children.as_deref().unwrap_or_default()),
+        [
+            ("bar".to_string(), lsp::SymbolKind::PROPERTY, 0),
+            ("baz".to_string(), lsp::SymbolKind::METHOD, 0),
+        ]
+    );
+    assert_eq!(
+        symbols[0].selection_range,
+        Range::new(Position::new(0, 6), Position::new(0, 9))
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+const NAVIGATION_INDEX: &str =
+    "import { formatDate, missing } from \"./utils.ts\";\nformatDate(missing);\n";
+
+/// Returns a project where `index.ts` and `other.ts` import `formatDate`
+/// from `utils.ts`. The module graph is only populated when the project is
+/// scanned, which requires a rule of the project domain.
+fn navigation_fs() -> MemoryFileSystem {
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noImportCycles": "error"
+      }
+    }
+  }
+}
+"#,
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("utils.ts")),
+        "export function formatDate(date: string): string {\n  return date;\n}\n",
+    );
+    fs.insert(to_utf8_file_path_buf(uri!("index.ts")), NAVIGATION_INDEX);
+    fs.insert(
+        to_utf8_file_path_buf(uri!("other.ts")),
+        "import { formatDate } from \"./utils.ts\";\nformatDate(\"\");\n",
+    );
+    fs
+}
+
+#[tokio::test]
+async fn hover_shows_the_type_of_an_imported_function() -> Result<()> {
+    let factory = ServerFactory::new_with_fs(Arc::new(navigation_fs()));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(NAVIGATION_INDEX, uri!("index.ts"), "typescript")
+        .await?;
+
+    let hover: lsp::Hover = server
+        .request(
+            "textDocument/hover",
+            "hover",
+            lsp::HoverParams {
+                text_document_position_params: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: uri!("index.ts"),
+                    },
+                    position: Position::new(1, 2),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .context("hover returned None")?;
+
+    let lsp::HoverContents::Markup(content) = hover.contents else {
+        panic!("expected markup content, got {:?}", hover.contents);
+    };
+    assert!(
+        content.value.starts_with("```ts\nformatDate: "),
+        "unexpected hover content: {}",
+        content.value
+    );
+    assert_eq!(
+        hover.range,
+        Some(Range {
+            start: Position::new(1, 0),
+            end: Position::new(1, 10),
+        })
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn definition_of_imported_symbols() -> Result<()> {
+    let factory = ServerFactory::new_with_fs(Arc::new(navigation_fs()));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(NAVIGATION_INDEX, uri!("index.ts"), "typescript")
+        .await?;
+
+    let response: lsp::GotoDefinitionResponse = server
+        .request(
+            "textDocument/definition",
+            "definition",
+            lsp::GotoDefinitionParams {
+                text_document_position_params: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: uri!("index.ts"),
+                    },
+                    position: Position::new(1, 2),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("definition returned None")?;
+    let locations = match response {
+        lsp::GotoDefinitionResponse::Scalar(location) => vec![location],
+        lsp::GotoDefinitionResponse::Array(locations) => locations,
+        lsp::GotoDefinitionResponse::Link(links) => panic!("unexpected links {links:?}"),
+    };
+    assert_eq!(
+        locations,
+        [lsp::Location {
+            uri: uri!("utils.ts"),
+            range: Range {
+                start: Position::new(0, 16),
+                end: Position::new(0, 26),
+            },
+        }]
+    );
+
+    // `missing` isn't exported by `utils.ts`, so the definition is the
+    // import binding itself rather than the start of the imported module
+    let response: lsp::GotoDefinitionResponse = server
+        .request(
+            "textDocument/definition",
+            "definition",
+            lsp::GotoDefinitionParams {
+                text_document_position_params: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: uri!("index.ts"),
+                    },
+                    position: Position::new(1, 13),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("definition returned None")?;
+    let locations = match response {
+        lsp::GotoDefinitionResponse::Scalar(location) => vec![location],
+        lsp::GotoDefinitionResponse::Array(locations) => locations,
+        lsp::GotoDefinitionResponse::Link(links) => panic!("unexpected links {links:?}"),
+    };
+    assert_eq!(
+        locations,
+        [lsp::Location {
+            uri: uri!("index.ts"),
+            range: Range {
+                start: Position::new(0, 21),
+                end: Position::new(0, 28),
+            },
+        }]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn references_across_modules() -> Result<()> {
+    let factory = ServerFactory::new_with_fs(Arc::new(navigation_fs()));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(NAVIGATION_INDEX, uri!("index.ts"), "typescript")
+        .await?;
+
+    let mut locations: Vec<lsp::Location> = server
+        .request(
+            "textDocument/references",
+            "references",
+            lsp::ReferenceParams {
+                text_document_position: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: uri!("index.ts"),
+                    },
+                    position: Position::new(1, 2),
+                },
+                context: lsp::ReferenceContext {
+                    include_declaration: true,
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("references returned None")?;
+    locations.sort_by_key(|location| {
+        (
+            location.uri.to_string(),
+            location.range.start.line,
+            location.range.start.character,
+        )
+    });
+
+    let location = |uri: Uri, line: u32, start: u32| lsp::Location {
+        uri,
+        range: Range {
+            start: Position::new(line, start),
+            end: Position::new(line, start + 10),
+        },
+    };
+    assert_eq!(
+        locations,
+        [
+            location(uri!("index.ts"), 0, 9),
+            location(uri!("index.ts"), 1, 0),
+            location(uri!("other.ts"), 0, 9),
+            location(uri!("other.ts"), 1, 0),
+            location(uri!("utils.ts"), 0, 16),
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn references_through_namespace_imports() -> Result<()> {
+    let fs = navigation_fs();
+    fs.insert(
+        to_utf8_file_path_buf(uri!("namespace.ts")),
+        "import * as utils from \"./utils.ts\";\nutils.formatDate(\"\");\nutils.missing();\n",
+    );
+
+    let factory = ServerFactory::new_with_fs(Arc::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(NAVIGATION_INDEX, uri!("index.ts"), "typescript")
+        .await?;
+
+    let mut locations: Vec<lsp::Location> = server
+        .request(
+            "textDocument/references",
+            "references",
+            lsp::ReferenceParams {
+                text_document_position: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: uri!("index.ts"),
+                    },
+                    position: Position::new(1, 2),
+                },
+                context: lsp::ReferenceContext {
+                    include_declaration: false,
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("references returned None")?;
+    locations.sort_by_key(|location| {
+        (
+            location.uri.to_string(),
+            location.range.start.line,
+            location.range.start.character,
+        )
+    });
+
+    let location = |uri: Uri, line: u32, start: u32| lsp::Location {
+        uri,
+        range: Range {
+            start: Position::new(line, start),
+            end: Position::new(line, start + 10),
+        },
+    };
+    assert_eq!(
+        locations,
+        [
+            location(uri!("index.ts"), 0, 9),
+            location(uri!("index.ts"), 1, 0),
+            location(uri!("namespace.ts"), 1, 6),
+            location(uri!("other.ts"), 0, 9),
+            location(uri!("other.ts"), 1, 0),
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn workspace_symbols_from_module_graph() -> Result<()> {
+    // The module graph is only populated when the project is scanned, which
+    // requires a rule of the project domain.
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noImportCycles": "error"
+      }
+    }
+  }
+}
+"#,
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("utils.ts")),
+        "export function formatDate() {}\nexport const FORMATS = [];\nfunction internal() {}\n",
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("index.ts")),
+        "export class DateFormatter {}\n",
+    );
+
+    let factory = ServerFactory::new_with_fs(Arc::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    let response: Option<lsp::WorkspaceSymbolResponse> = server
+        .request(
+            "workspace/symbol",
+            "symbol",
+            lsp::WorkspaceSymbolParams {
+                query: "date".to_string(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("symbol returned None")?;
+    // The nested and flat responses can't be told apart once serialized
+    let symbols: Vec<_> = match response {
+        Some(lsp::WorkspaceSymbolResponse::Flat(symbols)) => symbols
+            .into_iter()
+            .map(|symbol| (symbol.name, symbol.kind, symbol.location.uri))
+            .collect(),
+        Some(lsp::WorkspaceSymbolResponse::Nested(symbols)) => symbols
+            .into_iter()
+            .filter_map(|symbol| match symbol.location {
+                lsp::OneOf::Left(location) => Some((symbol.name, symbol.kind, location.uri)),
+                lsp::OneOf::Right(_) => None,
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    assert_eq!(
+        symbols,
+        [
+            (
+                "DateFormatter".to_string(),
+                lsp::SymbolKind::CLASS,
+                uri!("index.ts")
+            ),
+            (
+                "formatDate".to_string(),
+                lsp::SymbolKind::FUNCTION,
+                uri!("utils.ts")
+            ),
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn folding_ranges_of_javascript_file() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(
+            "import a from \"a\";\nimport b from \"b\";\n\n/**\n * Docs\n */\nfunction foo() {\n  return [\n    1,\n  ];\n}\n",
+            uri!("document.js"),
+            "javascript",
+        )
+        .await?;
+
+    let ranges: Option<Vec<lsp::FoldingRange>> = server
+        .request(
+            "textDocument/foldingRange",
+            "folding_range",
+            lsp::FoldingRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("foldingRange returned None")?;
+
+    let ranges: Vec<_> = ranges
+        .unwrap_or_default()
+        .into_iter()
+        .map(|range| (range.start_line, range.end_line, range.kind))
+        .collect();
+    assert_eq!(
+        ranges,
+        [
+            (0, 1, Some(lsp::FoldingRangeKind::Imports)),
+            (3, 5, Some(lsp::FoldingRangeKind::Comment)),
+            (6, 9, Some(lsp::FoldingRangeKind::Region)),
+            (7, 8, Some(lsp::FoldingRangeKind::Region)),
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn semantic_tokens_of_javascript_file() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(
+            "const x = 1;\nfunction f(a) {\n  return console.log(a, x);\n}\n",
+            uri!("document.js"),
+            "javascript",
+        )
+        .await?;
+
+    let result: lsp::SemanticTokensResult = server
+        .request(
+            "textDocument/semanticTokens/full",
+            "semantic_tokens_full",
+            lsp::SemanticTokensParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("semanticTokens/full returned None")?;
+
+    let lsp::SemanticTokensResult::Tokens(tokens) = result else {
+        panic!("expected the tokens of the document, got {result:?}");
+    };
+    let token =
+        |delta_line, delta_start, length, token_type, token_modifiers_bitset| lsp::SemanticToken {
+            delta_line,
+            delta_start,
+            length,
+            token_type,
+            token_modifiers_bitset,
+        };
+    // Declaration = 1, Readonly = 2, DefaultLibrary = 4, Unused = 8
+    assert_eq!(
+        tokens.data,
+        [
+            token(0, 6, 1, 8, 1 | 2),
+            token(1, 9, 1, 10, 1 | 8),
+            token(0, 2, 1, 7, 1),
+            token(1, 9, 7, 8, 4),
+            token(0, 12, 1, 7, 0),
+            token(0, 3, 1, 8, 2),
+        ]
+    );
+
+    server
+        .change_document(
+            1,
+            vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: "const x = 1;\nfunction f(a) {\n  return console.log(a, x);\n}\nf(x);\n"
+                    .to_string(),
+            }],
+        )
+        .await?;
+
+    let result: lsp::SemanticTokensFullDeltaResult = server
+        .request(
+            "textDocument/semanticTokens/full/delta",
+            "semantic_tokens_full_delta",
+            lsp::SemanticTokensDeltaParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("document.js"),
+                },
+                previous_result_id: tokens.result_id.context("missing result ID")?,
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("semanticTokens/full/delta returned None")?;
+
+    let lsp::SemanticTokensFullDeltaResult::TokensDelta(delta) = result else {
+        panic!("expected a delta of the tokens, got {result:?}");
+    };
+    // The function is now referenced, so the edit starts from its declaration
+    assert_eq!(
+        delta.edits,
+        [lsp::SemanticTokensEdit {
+            start: 5,
+            delete_count: 25,
+            data: Some(vec![
+                token(1, 9, 1, 10, 1),
+                token(0, 2, 1, 7, 1),
+                token(1, 9, 7, 8, 4),
+                token(0, 12, 1, 7, 0),
+                token(0, 3, 1, 8, 2),
+                token(2, 0, 1, 10, 0),
+                token(0, 2, 1, 8, 2),
+            ]),
+        }]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn workspace_diagnostics_are_streamed_as_partial_results() -> Result<()> {
+    let factory = ServerFactory::new_with_fs(Arc::new(navigation_fs()));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    let report: lsp::WorkspaceDiagnosticReportResult = server
+        .request(
+            "workspace/diagnostic",
+            "workspace_diagnostic",
+            lsp::WorkspaceDiagnosticParams {
+                identifier: None,
+                previous_result_ids: Vec::new(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: Some(lsp::ProgressToken::String(
+                        "workspace-diagnostics".to_string(),
+                    )),
+                },
+            },
+        )
+        .await?
+        .context("workspace/diagnostic returned None")?;
+
+    // The reports are sent as partial results, the response is empty
+    let lsp::WorkspaceDiagnosticReportResult::Report(report) = report else {
+        panic!("expected a report, got {report:?}");
+    };
+    assert!(
+        report.items.is_empty(),
+        "unexpected items {:?}",
+        report.items
+    );
+
+    let notification = wait_for_notification(&mut receiver, |notification| {
+        matches!(notification, ServerNotification::Progress(_))
+    })
+    .await;
+    let Some(ServerNotification::Progress(progress)) = notification else {
+        panic!("expected a progress notification, got {notification:?}");
+    };
+    assert_eq!(progress["token"], "workspace-diagnostics");
+    let partial_result: lsp::WorkspaceDiagnosticReportPartialResult =
+        from_value(progress["value"].clone())?;
+    let uris: Vec<_> = partial_result
+        .items
+        .iter()
+        .map(|item| match item {
+            lsp::WorkspaceDocumentDiagnosticReport::Full(report) => report.uri.clone(),
+            lsp::WorkspaceDocumentDiagnosticReport::Unchanged(report) => report.uri.clone(),
+        })
+        .collect();
+    for uri in [uri!("index.ts"), uri!("other.ts"), uri!("utils.ts")] {
+        assert!(uris.contains(&uri), "{uri:?} is missing from {uris:?}");
+    }
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_diagnostics_of_scanned_files() -> Result<()> {
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noImportCycles": "error"
+      }
+    }
+  }
+}
+"#,
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("foo.ts")),
+        "import { bar } from \"./bar.ts\";\nexport function foo() {\n  bar();\n}\n",
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("bar.ts")),
+        "import { foo } from \"./foo.ts\";\nexport function bar() {\n  foo();\n}\n",
+    );
+
+    let factory = ServerFactory::new_with_fs(Arc::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    // The files aren't opened, their diagnostics come from the scanner
+    let report: lsp::WorkspaceDiagnosticReportResult = server
+        .request(
+            "workspace/diagnostic",
+            "workspace_diagnostic",
+            lsp::WorkspaceDiagnosticParams {
+                identifier: None,
+                previous_result_ids: Vec::new(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("workspace/diagnostic returned None")?;
+    let lsp::WorkspaceDiagnosticReportResult::Report(report) = report else {
+        panic!("expected a full report, got {report:?}");
+    };
+
+    let mut previous_result_ids = Vec::new();
+    let mut cycles = Vec::new();
+    for item in report.items {
+        let lsp::WorkspaceDocumentDiagnosticReport::Full(item) = item else {
+            panic!("expected a full report of the document, got {item:?}");
+        };
+        let report = item.full_document_diagnostic_report;
+        let has_cycle = report.items.iter().any(|diagnostic| {
+            diagnostic.code
+                == Some(lsp::NumberOrString::String(
+                    "lint/nursery/noImportCycles".to_string(),
+                ))
+        });
+        if has_cycle {
+            cycles.push(item.uri.clone());
+        }
+        previous_result_ids.push(lsp::PreviousResultId {
+            uri: item.uri,
+            value: report.result_id.context("the report has no result ID")?,
+        });
+    }
+    assert_eq!(cycles, [uri!("bar.ts"), uri!("foo.ts")]);
+
+    // Nothing changed since the previous report
+    let report: lsp::WorkspaceDiagnosticReportResult = server
+        .request(
+            "workspace/diagnostic",
+            "workspace_diagnostic",
+            lsp::WorkspaceDiagnosticParams {
+                identifier: None,
+                previous_result_ids: previous_result_ids.clone(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("workspace/diagnostic returned None")?;
+    let lsp::WorkspaceDiagnosticReportResult::Report(report) = report else {
+        panic!("expected a full report, got {report:?}");
+    };
+    assert_eq!(report.items.len(), previous_result_ids.len());
+    assert!(
+        report
+            .items
+            .iter()
+            .all(|item| matches!(item, lsp::WorkspaceDocumentDiagnosticReport::Unchanged(_))),
+        "expected all the documents to be unchanged, got {:?}",
+        report.items
+    );
+
+    // Breaking the cycle changes the diagnostics of the opened document
+    server
+        .open_named_document("export function foo() {}\n", uri!("foo.ts"), "typescript")
+        .await?;
+    let previous_result_id = previous_result_ids
+        .iter()
+        .find(|previous| previous.uri == uri!("foo.ts"))
+        .map(|previous| previous.value.clone());
+    let report: lsp::DocumentDiagnosticReportResult = server
+        .request(
+            "textDocument/diagnostic",
+            "document_diagnostic",
+            lsp::DocumentDiagnosticParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("foo.ts"),
+                },
+                identifier: None,
+                previous_result_id,
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("textDocument/diagnostic returned None")?;
+    let lsp::DocumentDiagnosticReportResult::Report(lsp::DocumentDiagnosticReport::Full(report)) =
+        report
+    else {
+        panic!("expected a full report of the document, got {report:?}");
+    };
+    assert_eq!(report.full_document_diagnostic_report.items, []);
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn inlay_hints_of_typescript_file() -> Result<()> {
+    // The types are inferred from the module graph, which is only populated
+    // when the project is scanned.
+    const CONTENT: &str = "function add(left: number, right: number) {
+  return left + right;
+}
+const total = add(1, 2);
+const right = 3;
+add(total, right);
+";
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noImportCycles": "error"
+      }
+    }
+  }
+}
+"#,
+    );
+    fs.insert(to_utf8_file_path_buf(uri!("index.ts")), CONTENT);
+
+    let factory = ServerFactory::new_with_fs(Arc::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(CONTENT, uri!("index.ts"), "typescript")
+        .await?;
+
+    let hints: Vec<lsp::InlayHint> = server
+        .request(
+            "textDocument/inlayHint",
+            "inlay_hint",
+            lsp::InlayHintParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("index.ts"),
+                },
+                range: Range {
+                    start: Position::new(0, 0),
+                    end: Position::new(6, 0),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .context("inlayHint returned None")?;
+
+    let hints: Vec<_> = hints
+        .into_iter()
+        .map(|hint| {
+            let lsp::InlayHintLabel::String(label) = hint.label else {
+                panic!("expected a string label, got {:?}", hint.label);
+            };
+            (
+                hint.position.line,
+                hint.position.character,
+                label,
+                hint.kind,
+            )
+        })
+        .collect();
+    assert_eq!(
+        hints,
+        [
+            (
+                0,
+                41,
+                ": number".to_string(),
+                Some(lsp::InlayHintKind::TYPE)
+            ),
+            (
+                3,
+                11,
+                ": number".to_string(),
+                Some(lsp::InlayHintKind::TYPE)
+            ),
+            (
+                3,
+                18,
+                "left:".to_string(),
+                Some(lsp::InlayHintKind::PARAMETER)
+            ),
+            (
+                3,
+                21,
+                "right:".to_string(),
+                Some(lsp::InlayHintKind::PARAMETER)
+            ),
+            (
+                5,
+                4,
+                "left:".to_string(),
+                Some(lsp::InlayHintKind::PARAMETER)
+            ),
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 // #region MONOREPO TESTS
 
 #[tokio::test]
@@ -4327,7 +5560,7 @@ fn assert_diagnostic_code(server_notification: &ServerNotification, code: &str)
                     .is_some_and(|c| &NumberOrString::String(code.to_string()) == c)
             }));
         }
-        ServerNotification::ShowMessage(_) => {
+        ServerNotification::ShowMessage(_) | ServerNotification::Progress(_) => {
             panic!("Unexpected notification: {server_notification:?}",);
         }
     }
@@ -4338,10 +5571,34 @@ fn assert_diagnostics_count(server_notification: &ServerNotification, expected_c
         ServerNotification::PublishDiagnostics(publish) => {
             assert_eq!(publish.diagnostics.len(), expected_count)
         }
-        ServerNotification::ShowMessage(_) => {
+        ServerNotification::ShowMessage(_) | ServerNotification::Progress(_) => {
             panic!("Unexpected notification: {server_notification:?}",);
         }
     }
 }
 
+/// Applies the `edits` returned by the server to `text`.
+fn apply_text_edits(text: &str, edits: &[TextEdit]) -> String {
+    let line_index = biome_line_index::LineIndex::new(text);
+    let mut edits = edits
+        .iter()
+        .map(|edit| {
+            let range = biome_lsp_converters::from_proto::text_range(
+                &line_index,
+                edit.range,
+                biome_lsp_converters::PositionEncoding::Utf8,
+            )
+            .unwrap();
+            (range, edit.new_text.as_str())
+        })
+        .collect::<Vec<_>>();
+    edits.sort_by_key(|(range, _)| std::cmp::Reverse(range.start()));
+
+    let mut text = text.to_string();
+    for (range, new_text) in edits {
+        text.replace_range(std::ops::Range::<usize>::from(range), new_text);
+    }
+    text
+}
+
 // #endregion
@@ -1,6 +1,6 @@
 use crate::diagnostics::LspError;
 use crate::documents::Document;
-use crate::extension_settings::{CONFIGURATION_SECTION, ExtensionSettings};
+use crate::extension_settings::{CONFIGURATION_SECTION, ExtensionSettings, InlayHintsSettings};
 use crate::utils;
 use anyhow::Result;
 use biome_analyze::RuleCategoriesBuilder;
@@ -9,7 +9,7 @@ use biome_console::markup;
 use biome_deserialize::Merge;
 use biome_diagnostics::PrintDescription;
 use biome_fs::BiomePath;
-use biome_line_index::WideEncoding;
+use biome_line_index::{LineIndex, WideEncoding};
 use biome_lsp_converters::{PositionEncoding, negotiated_encoding};
 use biome_service::Workspace;
 use biome_service::WorkspaceError;
@@ -38,7 +38,7 @@ use serde_json::Value;
 use std::sync::Arc;
 use std::sync::RwLock;
 use std::sync::atomic::Ordering;
-use std::sync::atomic::{AtomicBool, AtomicU8};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU64};
 use tokio::spawn;
 use tokio::sync::Notify;
 use tokio::sync::OnceCell;
@@ -47,8 +47,8 @@ use tokio::sync::watch;
 use tokio::task::spawn_blocking;
 use tower_lsp_server::Client;
 use tower_lsp_server::ls_types::{
-    self as lsp, ClientCapabilities, Diagnostic, MessageType, Registration, Unregistration, Uri,
-    WorkspaceFolder,
+    self as lsp, ClientCapabilities, Diagnostic, MessageType, Registration, SemanticToken,
+    Unregistration, Uri, WorkspaceFolder,
 };
 use tracing::{debug, error, info, instrument, warn};
 
@@ -99,6 +99,20 @@ pub(crate) struct Session {
     /// Documents opened in this session.
     documents: HashMap<Uri, Document, FxBuildHasher>,
 
+    /// The last semantic tokens sent for each document, with their result
+    /// ID, so that the next request can be answered with a delta.
+    semantic_tokens: HashMap<Uri, (String, Vec<SemanticToken>), FxBuildHasher>,
+
+    /// Used to generate the result IDs of the semantic tokens.
+    next_semantic_tokens_id: AtomicU64,
+
+    /// The last diagnostics pulled by the client for each file, with their
+    /// result ID, so that unchanged diagnostics aren't sent again.
+    pulled_diagnostics: HashMap<Uri, (String, Vec<Diagnostic>), FxBuildHasher>,
+
+    /// Used to generate the result IDs of the pulled diagnostics.
+    next_pulled_diagnostics_id: AtomicU64,
+
     pub(crate) cancellation: Arc<Notify>,
 
     /// Receiver for service notifications.
@@ -216,6 +230,10 @@ impl Session {
             configuration_status: AtomicU8::new(ConfigurationStatus::Missing as u8),
             projects: Default::default(),
             documents: Default::default(),
+            semantic_tokens: Default::default(),
+            next_semantic_tokens_id: AtomicU64::new(0),
+            pulled_diagnostics: Default::default(),
+            next_pulled_diagnostics_id: AtomicU64::new(0),
             extension_settings: config,
             cancellation,
             notified_broken_configuration: AtomicBool::new(false),
@@ -329,6 +347,11 @@ impl Session {
             .map(|(_project_path, project_key)| *project_key)
     }
 
+    /// Returns the keys of the projects opened in this session.
+    pub(crate) fn project_keys(&self) -> Vec<ProjectKey> {
+        self.projects.pin().values().copied().collect()
+    }
+
     /// Registers an open project with its root path and scans the folder.
     #[tracing::instrument(level = "debug", skip(self))]
     pub(crate) async fn insert_and_scan_project(
@@ -363,9 +386,62 @@ impl Session {
 
     /// Remove the [`Document`] matching the provided [`Uri`]
     pub(crate) fn remove_document(&self, url: &Uri) -> Option<ProjectKey> {
+        self.semantic_tokens.pin().remove(url);
+        self.remove_pulled_diagnostics(url);
         self.documents.pin().remove(url).map(|doc| doc.project_key)
     }
 
+    /// Stores the semantic tokens sent for a document and returns their
+    /// result ID.
+    pub(crate) fn insert_semantic_tokens(&self, url: Uri, tokens: Vec<SemanticToken>) -> String {
+        let result_id = self
+            .next_semantic_tokens_id
+            .fetch_add(1, Ordering::Relaxed)
+            .to_string();
+        self.semantic_tokens
+            .pin()
+            .insert(url, (result_id.clone(), tokens));
+        result_id
+    }
+
+    /// Returns the semantic tokens last sent for a document, if they match
+    /// the provided result ID.
+    pub(crate) fn semantic_tokens(&self, url: &Uri, result_id: &str) -> Option<Vec<SemanticToken>> {
+        self.semantic_tokens
+            .pin()
+            .get(url)
+            .filter(|(id, _)| id == result_id)
+            .map(|(_, tokens)| tokens.clone())
+    }
+
+    /// Returns the result ID of the diagnostics pulled for a file. The ID
+    /// only changes when the diagnostics differ from the last ones pulled.
+    pub(crate) fn pulled_diagnostics_result_id(
+        &self,
+        url: &Uri,
+        diagnostics: &[Diagnostic],
+    ) -> String {
+        let pulled_diagnostics = self.pulled_diagnostics.pin();
+        if let Some((result_id, previous)) = pulled_diagnostics.get(url)
+            && previous.as_slice() == diagnostics
+        {
+            return result_id.clone();
+        }
+
+        let result_id = self
+            .next_pulled_diagnostics_id
+            .fetch_add(1, Ordering::Relaxed)
+            .to_string();
+        pulled_diagnostics.insert(url.clone(), (result_id.clone(), diagnostics.to_vec()));
+        result_id
+    }
+
+    /// Forgets the diagnostics pulled for a file, e.g. when it's closed or
+    /// deleted.
+    pub(crate) fn remove_pulled_diagnostics(&self, url: &Uri) {
+        self.pulled_diagnostics.pin().remove(url);
+    }
+
     pub(crate) fn file_path(&self, url: &Uri) -> Result<BiomePath> {
         let path_to_file = match url.to_file_path() {
             None => {
@@ -400,8 +476,6 @@ impl Session {
         url: Uri,
         doc: Document,
     ) -> Result<(), LspError> {
-        let biome_path = self.file_path(&url)?;
-
         if !self.notified_broken_configuration() {
             if self.configuration_status().is_editorconfig_error() {
                 self.set_notified_broken_configuration();
@@ -419,20 +493,45 @@ impl Session {
             }
         }
 
+        // Clients that pull the diagnostics request them when they need them
+        if self.supports_pull_diagnostics() {
+            return Ok(());
+        }
+
+        let biome_path = self.file_path(&url)?;
+        let diagnostics = self
+            .compute_diagnostics(&url, biome_path, doc.project_key, &doc.line_index)?
+            .unwrap_or_default();
+
+        info!("Diagnostics sent to the client {}", diagnostics.len());
+
+        self.client
+            .publish_diagnostics(url, diagnostics, Some(doc.version))
+            .await;
+
+        Ok(())
+    }
+
+    /// Computes the diagnostics of the file matching the provided url, or
+    /// returns `None` if the file is neither linted nor checked by the assist.
+    pub(crate) fn compute_diagnostics(
+        &self,
+        url: &Uri,
+        biome_path: BiomePath,
+        project_key: ProjectKey,
+        line_index: &LineIndex,
+    ) -> Result<Option<Vec<Diagnostic>>, LspError> {
         let FileFeaturesResult {
             features_supported: file_features,
         } = self.workspace.file_features(SupportsFeatureParams {
-            project_key: doc.project_key,
+            project_key,
             features: FeaturesBuilder::new().with_linter().with_assist().build(),
             path: biome_path.clone(),
             skip_ignore_check: false,
         })?;
 
         if !file_features.supports_lint() && !file_features.supports_assist() {
-            self.client
-                .publish_diagnostics(url, vec![], Some(doc.version))
-                .await;
-            return Ok(());
+            return Ok(None);
         }
 
         let diagnostics: Vec<Diagnostic> = {
@@ -446,13 +545,14 @@ impl Session {
                 }
             }
             let result = self.workspace.pull_diagnostics(PullDiagnosticsParams {
-                project_key: doc.project_key,
+                project_key,
                 path: biome_path.clone(),
                 categories: categories.build(),
                 only: Vec::new(),
                 skip: Vec::new(),
                 enabled_rules: Vec::new(),
                 pull_code_actions: false,
+                pull_suppressed: false,
             })?;
 
             let offset = if file_features.supports_full_html_support() {
@@ -468,7 +568,7 @@ impl Session {
                     let content = self
                         .workspace
                         .get_file_content(GetFileContentParams {
-                            project_key: doc.project_key,
+                            project_key,
                             path: biome_path.clone(),
                         })
                         .ok()?;
@@ -482,8 +582,8 @@ impl Session {
                 .filter_map(|d| {
                     match utils::diagnostic_to_lsp(
                         d,
-                        &url,
-                        &doc.line_index,
+                        url,
+                        line_index,
                         self.position_encoding(),
                         offset,
                     ) {
@@ -497,18 +597,21 @@ impl Session {
                 .collect()
         };
 
-        info!("Diagnostics sent to the client {}", diagnostics.len());
-
-        self.client
-            .publish_diagnostics(url, diagnostics, Some(doc.version))
-            .await;
-
-        Ok(())
+        Ok(Some(diagnostics))
     }
 
     /// Updates diagnostics for every [`Document`] in this [`Session`]
     #[tracing::instrument(level = "debug", skip(self))]
     pub(crate) async fn update_all_diagnostics(&self) {
+        if self.supports_pull_diagnostics() {
+            if self.can_refresh_workspace_diagnostics()
+                && let Err(err) = self.client.workspace_diagnostic_refresh().await
+            {
+                error!("Error while refreshing diagnostics: {}", err);
+            }
+            return;
+        }
+
         let mut futures: FuturesUnordered<_> = self
             .documents
             .pin()
@@ -608,6 +711,35 @@ impl Session {
         result
     }
 
+    /// True if the client pulls the diagnostics instead of waiting for the
+    /// server to publish them
+    #[instrument(level = "info", skip(self))]
+    pub(crate) fn supports_pull_diagnostics(&self) -> bool {
+        let result = self
+            .initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.text_document.as_ref())
+            .is_some_and(|c| c.diagnostic.is_some());
+
+        info!("Supports pull diagnostics: {result}");
+        result
+    }
+
+    /// True if the client supports the "workspace/diagnostic/refresh" request
+    #[instrument(level = "info", skip(self))]
+    pub(crate) fn can_refresh_workspace_diagnostics(&self) -> bool {
+        let result = self
+            .initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.workspace.as_ref())
+            .and_then(|c| c.diagnostics.as_ref())
+            .and_then(|c| c.refresh_support)
+            == Some(true);
+
+        info!("Can refresh workspace diagnostics: {result}");
+        result
+    }
+
     /// Get the current workspace folders
     pub(crate) fn get_workspace_folders(&self) -> Option<Vec<WorkspaceFolder>> {
         self.workspace_folders.read().unwrap().clone()
@@ -1046,6 +1178,10 @@ impl Session {
             .requires_configuration()
     }
 
+    pub(crate) fn inlay_hints_settings(&self) -> InlayHintsSettings {
+        self.extension_settings.read().unwrap().inlay_hints()
+    }
+
     pub(crate) fn is_linting_and_formatting_disabled(&self) -> bool {
         debug!("configuration status {:?}", self.configuration_status());
         match self.configuration_status() {
@@ -15,26 +15,59 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
     ) -> RawSyntaxNode<Self::Kind> {
         match kind {
             MD_BOGUS => RawSyntaxNode::new(kind, children.into_iter().map(Some)),
+            MD_AUTOLINK => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element
+                    && element.kind() == T ! [<]
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && MdInlineItemList::can_cast(element.kind())
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && element.kind() == T ! [>]
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_AUTOLINK.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_AUTOLINK, children)
+            }
             MD_BULLET => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && matches!(element.kind(), T ! [-] | T ! [*])
+                    && matches!(element.kind(), T ! [-] | T ! [*] | T ! [+])
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && element.kind() == MD_TEXTUAL_LITERAL
+                    && MdTaskListCheckbox::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && MdInlineItemList::can_cast(element.kind())
+                    && MdBlockList::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -102,24 +135,10 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_FENCED_CODE_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && element.kind() == T!["```"]
-                {
-                    slots.mark_present();
-                    current_element = elements.next();
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element
-                    && MdCodeNameList::can_cast(element.kind())
-                {
-                    slots.mark_present();
-                    current_element = elements.next();
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element
-                    && MdHardLine::can_cast(element.kind())
+                    && matches!(element.kind(), MD_BACKTICKS_LITERAL | MD_TILDES_LITERAL)
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -133,14 +152,14 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && MdHardLine::can_cast(element.kind())
+                    && MdInlineItemList::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && element.kind() == T!["```"]
+                    && matches!(element.kind(), MD_BACKTICKS_LITERAL | MD_TILDES_LITERAL)
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -227,7 +246,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && MdTextual::can_cast(element.kind())
+                    && MdInlineItemList::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -241,12 +260,12 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_HTML_BLOCK, children)
             }
-            MD_INDENT => {
+            MD_INDENT_CODE_BLOCK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && element.kind() == MD_INDENT_CHUNK_LITERAL
+                    && MdInlineItemList::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -254,18 +273,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INDENT.to_bogus(),
+                        MD_INDENT_CODE_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INDENT, children)
+                slots.into_node(MD_INDENT_CODE_BLOCK, children)
             }
-            MD_INDENT_CODE_BLOCK => {
+            MD_INLINE_CODE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && MdIndentedCodeLineList::can_cast(element.kind())
+                    && element.kind() == MD_BACKTICKS_LITERAL
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && MdInlineItemList::can_cast(element.kind())
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && element.kind() == MD_BACKTICKS_LITERAL
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -273,25 +306,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INDENT_CODE_BLOCK.to_bogus(),
+                        MD_INLINE_CODE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INDENT_CODE_BLOCK, children)
+                slots.into_node(MD_INLINE_CODE, children)
             }
-            MD_INDENTED_CODE_LINE => {
+            MD_INLINE_EMPHASIS => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && MdIndent::can_cast(element.kind())
+                    && matches!(element.kind(), T ! [**] | T!["__"])
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && MdTextual::can_cast(element.kind())
+                    && MdInlineItemList::can_cast(element.kind())
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && matches!(element.kind(), T ! [**] | T!["__"])
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -299,18 +339,65 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INDENTED_CODE_LINE.to_bogus(),
+                        MD_INLINE_EMPHASIS.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INDENTED_CODE_LINE, children)
+                slots.into_node(MD_INLINE_EMPHASIS, children)
             }
-            MD_INLINE_CODE => {
+            MD_INLINE_HTML => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element
+                    && MdInlineItemList::can_cast(element.kind())
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_INLINE_HTML.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_INLINE_HTML, children)
+            }
+            MD_INLINE_IMAGE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<7usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && element.kind() == T!['`']
+                    && element.kind() == T![!]
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && element.kind() == T!['[']
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && MdInlineItemList::can_cast(element.kind())
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && element.kind() == T![']']
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && element.kind() == T!['(']
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -324,7 +411,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && element.kind() == T!['`']
+                    && element.kind() == T![')']
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -332,18 +419,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_CODE.to_bogus(),
+                        MD_INLINE_IMAGE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_CODE, children)
+                slots.into_node(MD_INLINE_IMAGE, children)
             }
-            MD_INLINE_EMPHASIS => {
+            MD_INLINE_ITALIC => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && matches!(element.kind(), T ! [**] | T!["__"])
+                    && matches!(element.kind(), T ! [*] | T!["_"])
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -357,7 +444,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && matches!(element.kind(), T ! [**] | T!["__"])
+                    && matches!(element.kind(), T ! [*] | T!["_"])
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -365,13 +452,13 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_EMPHASIS.to_bogus(),
+                        MD_INLINE_ITALIC.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_EMPHASIS, children)
+                slots.into_node(MD_INLINE_ITALIC, children)
             }
-            MD_INLINE_IMAGE => {
+            MD_INLINE_LINK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
@@ -383,35 +470,35 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && element.kind() == T![!]
+                    && MdInlineItemList::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && MdInlineImageAlt::can_cast(element.kind())
+                    && element.kind() == T![']']
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && MdInlineImageSource::can_cast(element.kind())
+                    && element.kind() == T!['(']
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && element.kind() == T![']']
+                    && MdInlineItemList::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && MdInlineImageLink::can_cast(element.kind())
+                    && element.kind() == T![')']
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -419,18 +506,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE.to_bogus(),
+                        MD_INLINE_LINK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE, children)
+                slots.into_node(MD_INLINE_LINK, children)
             }
-            MD_INLINE_IMAGE_ALT => {
+            MD_INLINE_STRIKETHROUGH => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && element.kind() == T!['[']
+                    && element.kind() == MD_TILDES_LITERAL
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -444,7 +531,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && element.kind() == T![']']
+                    && element.kind() == MD_TILDES_LITERAL
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -452,18 +539,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE_ALT.to_bogus(),
+                        MD_INLINE_STRIKETHROUGH.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE_ALT, children)
+                slots.into_node(MD_INLINE_STRIKETHROUGH, children)
             }
-            MD_INLINE_IMAGE_LINK => {
+            MD_LINK_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<5usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && element.kind() == T!['(']
+                    && element.kind() == T!['[']
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -477,7 +564,21 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && element.kind() == T![')']
+                    && element.kind() == T![']']
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && element.kind() == T ! [:]
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && MdInlineItemList::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -485,32 +586,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE_LINK.to_bogus(),
+                        MD_LINK_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE_LINK, children)
+                slots.into_node(MD_LINK_BLOCK, children)
             }
-            MD_INLINE_IMAGE_SOURCE => {
+            MD_ORDER_BULLET => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && element.kind() == T!['(']
+                    && element.kind() == MD_ORDERED_LIST_MARKER_LITERAL
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && MdInlineItemList::can_cast(element.kind())
+                    && MdTaskListCheckbox::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && element.kind() == T![')']
+                    && MdBlockList::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -518,23 +619,35 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE_SOURCE.to_bogus(),
+                        MD_ORDER_BULLET.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE_SOURCE, children)
+                slots.into_node(MD_ORDER_BULLET, children)
             }
-            MD_INLINE_ITALIC => {
+            MD_ORDER_LIST_ITEM => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && matches!(element.kind(), T ! [*] | T!["_"])
+                    && MdOrderList::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_ORDER_LIST_ITEM.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_ORDER_LIST_ITEM, children)
+            }
+            MD_PARAGRAPH => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element
                     && MdInlineItemList::can_cast(element.kind())
                 {
@@ -542,8 +655,43 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                     current_element = elements.next();
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_PARAGRAPH.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_PARAGRAPH, children)
+            }
+            MD_QUOTE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && matches!(element.kind(), T ! [*] | T!["_"])
+                    && MdQuotePrefix::can_cast(element.kind())
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && MdBlockList::can_cast(element.kind())
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(MD_QUOTE.to_bogus(), children.into_iter().map(Some));
+                }
+                slots.into_node(MD_QUOTE, children)
+            }
+            MD_QUOTE_PREFIX => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element
+                    && element.kind() == T ! [>]
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -551,16 +699,23 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_ITALIC.to_bogus(),
+                        MD_QUOTE_PREFIX.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_ITALIC, children)
+                slots.into_node(MD_QUOTE_PREFIX, children)
             }
-            MD_INLINE_LINK => {
+            MD_REFERENCE_IMAGE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<5usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
+                if let Some(element) = &current_element
+                    && element.kind() == T![!]
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
                 if let Some(element) = &current_element
                     && element.kind() == T!['[']
                 {
@@ -583,7 +738,26 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && element.kind() == T!['(']
+                    && MdReferenceLinkLabel::can_cast(element.kind())
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_REFERENCE_IMAGE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_REFERENCE_IMAGE, children)
+            }
+            MD_REFERENCE_LINK => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element
+                    && element.kind() == T!['[']
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -597,7 +771,14 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && element.kind() == T![')']
+                    && element.kind() == T![']']
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && MdReferenceLinkLabel::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -605,32 +786,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_LINK.to_bogus(),
+                        MD_REFERENCE_LINK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_LINK, children)
+                slots.into_node(MD_REFERENCE_LINK, children)
             }
-            MD_LINK_BLOCK => {
+            MD_REFERENCE_LINK_LABEL => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && MdTextual::can_cast(element.kind())
+                    && element.kind() == T!['[']
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && MdTextual::can_cast(element.kind())
+                    && MdInlineItemList::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && MdTextual::can_cast(element.kind())
+                    && element.kind() == T![']']
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -638,18 +819,25 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_LINK_BLOCK.to_bogus(),
+                        MD_REFERENCE_LINK_LABEL.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_LINK_BLOCK, children)
+                slots.into_node(MD_REFERENCE_LINK_LABEL, children)
             }
-            MD_ORDER_LIST_ITEM => {
+            MD_SETEXT_HEADER => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && MdBulletList::can_cast(element.kind())
+                    && MdParagraph::can_cast(element.kind())
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && element.kind() == MD_SETEXT_UNDERLINE_LITERAL
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -657,60 +845,81 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_ORDER_LIST_ITEM.to_bogus(),
+                        MD_SETEXT_HEADER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_ORDER_LIST_ITEM, children)
+                slots.into_node(MD_SETEXT_HEADER, children)
             }
-            MD_PARAGRAPH => {
+            MD_TABLE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && MdInlineItemList::can_cast(element.kind())
+                    && MdTableRow::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element
-                    && MdHardLine::can_cast(element.kind())
+                    && MdTableRow::can_cast(element.kind())
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && MdTableRowList::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        MD_PARAGRAPH.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
+                    return RawSyntaxNode::new(MD_TABLE.to_bogus(), children.into_iter().map(Some));
                 }
-                slots.into_node(MD_PARAGRAPH, children)
+                slots.into_node(MD_TABLE, children)
             }
-            MD_QUOTE => {
+            MD_TABLE_CELL => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && AnyMdBlock::can_cast(element.kind())
+                    && MdInlineItemList::can_cast(element.kind())
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && element.kind() == T ! [|]
                 {
                     slots.mark_present();
                     current_element = elements.next();
                 }
                 slots.next_slot();
                 if current_element.is_some() {
-                    return RawSyntaxNode::new(MD_QUOTE.to_bogus(), children.into_iter().map(Some));
+                    return RawSyntaxNode::new(
+                        MD_TABLE_CELL.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
                 }
-                slots.into_node(MD_QUOTE, children)
+                slots.into_node(MD_TABLE_CELL, children)
             }
-            MD_SETEXT_HEADER => {
+            MD_TABLE_ROW => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && MdParagraph::can_cast(element.kind())
+                    && element.kind() == T ! [|]
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && MdTableCellList::can_cast(element.kind())
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -718,18 +927,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_SETEXT_HEADER.to_bogus(),
+                        MD_TABLE_ROW.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_SETEXT_HEADER, children)
+                slots.into_node(MD_TABLE_ROW, children)
             }
-            MD_SOFT_BREAK => {
+            MD_TASK_LIST_CHECKBOX => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element
-                    && element.kind() == MD_SOFT_BREAK_LITERAL
+                    && element.kind() == T!['[']
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && MdTextual::can_cast(element.kind())
+                {
+                    slots.mark_present();
+                    current_element = elements.next();
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element
+                    && element.kind() == T![']']
                 {
                     slots.mark_present();
                     current_element = elements.next();
@@ -737,11 +960,11 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_SOFT_BREAK.to_bogus(),
+                        MD_TASK_LIST_CHECKBOX.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_SOFT_BREAK, children)
+                slots.into_node(MD_TASK_LIST_CHECKBOX, children)
             }
             MD_TEXTUAL => {
                 let mut elements = (&children).into_iter();
@@ -783,21 +1006,15 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_BLOCK_LIST => Self::make_node_list_syntax(kind, children, AnyMdBlock::can_cast),
             MD_BULLET_LIST => Self::make_node_list_syntax(kind, children, MdBullet::can_cast),
-            MD_CODE_NAME_LIST => Self::make_separated_list_syntax(
-                kind,
-                children,
-                MdTextual::can_cast,
-                T ! [,],
-                false,
-            ),
             MD_HASH_LIST => Self::make_node_list_syntax(kind, children, MdHash::can_cast),
-            MD_INDENTED_CODE_LINE_LIST => {
-                Self::make_node_list_syntax(kind, children, MdIndentedCodeLine::can_cast)
-            }
             MD_INLINE_ITEM_LIST => {
                 Self::make_node_list_syntax(kind, children, AnyMdInline::can_cast)
             }
-            MD_ORDER_LIST => Self::make_node_list_syntax(kind, children, AnyCodeBlock::can_cast),
+            MD_ORDER_LIST => Self::make_node_list_syntax(kind, children, MdOrderBullet::can_cast),
+            MD_TABLE_CELL_LIST => {
+                Self::make_node_list_syntax(kind, children, MdTableCell::can_cast)
+            }
+            MD_TABLE_ROW_LIST => Self::make_node_list_syntax(kind, children, MdTableRow::can_cast),
             _ => unreachable!("Is {:?} a token?", kind),
         }
     }
@@ -0,0 +1,359 @@
+use crate::comments::{FormatMarkdownLeadingComment, MarkdownCommentStyle, MarkdownComments};
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_formatter::printer::PrinterOptions;
+use biome_formatter::{
+    CstFormatContext, FormatContext, FormatOptions, IndentStyle, IndentWidth, LineEnding,
+    LineWidth, TransformSourceMap,
+};
+use biome_markdown_syntax::{MarkdownFileSource, MarkdownLanguage};
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct MarkdownFormatContext {
+    options: MarkdownFormatOptions,
+    /// Markdown has no comments, but the formatter infrastructure requires them.
+    comments: Rc<MarkdownComments>,
+    source_map: Option<TransformSourceMap>,
+    /// Whether the code of fenced code blocks is formatted by the formatters of their languages.
+    should_delegate_fmt_embedded_nodes: bool,
+}
+
+impl MarkdownFormatContext {
+    pub fn new(options: MarkdownFormatOptions, comments: MarkdownComments) -> Self {
+        Self {
+            options,
+            comments: Rc::new(comments),
+            source_map: None,
+            should_delegate_fmt_embedded_nodes: false,
+        }
+    }
+
+    pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
+    pub fn with_fmt_embedded_nodes(mut self) -> Self {
+        self.should_delegate_fmt_embedded_nodes = true;
+        self
+    }
+
+    pub fn should_delegate_fmt_embedded_nodes(&self) -> bool {
+        self.should_delegate_fmt_embedded_nodes
+    }
+}
+
+impl FormatContext for MarkdownFormatContext {
+    type Options = MarkdownFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_map(&self) -> Option<&TransformSourceMap> {
+        None
+    }
+}
+
+impl CstFormatContext for MarkdownFormatContext {
+    type Language = MarkdownLanguage;
+    type Style = MarkdownCommentStyle;
+    type CommentRule = FormatMarkdownLeadingComment;
+
+    fn comments(&self) -> &MarkdownComments {
+        &self.comments
+    }
+}
+
+/// The options of the Markdown formatter.
+///
+/// The indentation of Markdown is meaningful, so there's no indent style: the
+/// formatter always indents with spaces.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MarkdownFormatOptions {
+    indent_width: IndentWidth,
+    line_ending: LineEnding,
+    line_width: LineWidth,
+    /// The marker of the items of bullet lists. Default is `-`.
+    bullet_list_marker: BulletListMarker,
+    /// The delimiter of italic and bold texts. Default is `*`.
+    emphasis_style: EmphasisStyle,
+    /// How the text of paragraphs is wrapped. Default is `preserve`.
+    prose_wrap: ProseWrap,
+    _file_source: MarkdownFileSource,
+}
+
+impl MarkdownFormatOptions {
+    pub fn new(file_source: MarkdownFileSource) -> Self {
+        Self {
+            _file_source: file_source,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_indent_width(mut self, indent_width: IndentWidth) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn with_bullet_list_marker(mut self, bullet_list_marker: BulletListMarker) -> Self {
+        self.bullet_list_marker = bullet_list_marker;
+        self
+    }
+
+    pub fn with_emphasis_style(mut self, emphasis_style: EmphasisStyle) -> Self {
+        self.emphasis_style = emphasis_style;
+        self
+    }
+
+    pub fn with_prose_wrap(mut self, prose_wrap: ProseWrap) -> Self {
+        self.prose_wrap = prose_wrap;
+        self
+    }
+
+    pub fn set_indent_width(&mut self, indent_width: IndentWidth) {
+        self.indent_width = indent_width;
+    }
+
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    pub fn set_line_width(&mut self, line_width: LineWidth) {
+        self.line_width = line_width;
+    }
+
+    pub fn set_bullet_list_marker(&mut self, bullet_list_marker: BulletListMarker) {
+        self.bullet_list_marker = bullet_list_marker;
+    }
+
+    pub fn set_emphasis_style(&mut self, emphasis_style: EmphasisStyle) {
+        self.emphasis_style = emphasis_style;
+    }
+
+    pub fn set_prose_wrap(&mut self, prose_wrap: ProseWrap) {
+        self.prose_wrap = prose_wrap;
+    }
+
+    pub fn bullet_list_marker(&self) -> BulletListMarker {
+        self.bullet_list_marker
+    }
+
+    pub fn emphasis_style(&self) -> EmphasisStyle {
+        self.emphasis_style
+    }
+
+    pub fn prose_wrap(&self) -> ProseWrap {
+        self.prose_wrap
+    }
+}
+
+impl FormatOptions for MarkdownFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        IndentStyle::Space
+    }
+
+    fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::from(self)
+    }
+}
+
+impl fmt::Display for MarkdownFormatOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Indent width: {}", self.indent_width.value())?;
+        writeln!(f, "Line ending: {}", self.line_ending)?;
+        writeln!(f, "Line width: {}", self.line_width.value())?;
+        writeln!(f, "Bullet list marker: {}", self.bullet_list_marker)?;
+        writeln!(f, "Emphasis style: {}", self.emphasis_style)?;
+        writeln!(f, "Prose wrap: {}", self.prose_wrap)
+    }
+}
+
+/// The marker of the items of bullet lists.
+///
+/// When two bullet lists follow each other, the second list uses another marker,
+/// otherwise both lists would be merged into one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserializable, Merge)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum BulletListMarker {
+    /// `- item`
+    #[default]
+    Dash,
+    /// `* item`
+    Asterisk,
+    /// `+ item`
+    Plus,
+}
+
+impl BulletListMarker {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Dash => "-",
+            Self::Asterisk => "*",
+            Self::Plus => "+",
+        }
+    }
+
+    /// Returns the marker used by a bullet list that directly follows a list with this marker.
+    pub const fn alternate(&self) -> Self {
+        match self {
+            Self::Dash | Self::Plus => Self::Asterisk,
+            Self::Asterisk => Self::Dash,
+        }
+    }
+}
+
+impl fmt::Display for BulletListMarker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Dash => std::write!(f, "dash"),
+            Self::Asterisk => std::write!(f, "asterisk"),
+            Self::Plus => std::write!(f, "plus"),
+        }
+    }
+}
+
+impl FromStr for BulletListMarker {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dash" => Ok(Self::Dash),
+            "asterisk" => Ok(Self::Asterisk),
+            "plus" => Ok(Self::Plus),
+            _ => Err(
+                "Value not supported for BulletListMarker. Supported values are 'dash', 'asterisk' and 'plus'.",
+            ),
+        }
+    }
+}
+
+/// The delimiter of italic and bold texts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserializable, Merge)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum EmphasisStyle {
+    /// `*italic*` and `**bold**`
+    #[default]
+    Asterisk,
+    /// `_italic_` and `__bold__`
+    Underscore,
+}
+
+impl EmphasisStyle {
+    pub const fn as_char(&self) -> char {
+        match self {
+            Self::Asterisk => '*',
+            Self::Underscore => '_',
+        }
+    }
+}
+
+impl fmt::Display for EmphasisStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Asterisk => std::write!(f, "asterisk"),
+            Self::Underscore => std::write!(f, "underscore"),
+        }
+    }
+}
+
+impl FromStr for EmphasisStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "asterisk" => Ok(Self::Asterisk),
+            "underscore" => Ok(Self::Underscore),
+            _ => Err(
+                "Value not supported for EmphasisStyle. Supported values are 'asterisk' and 'underscore'.",
+            ),
+        }
+    }
+}
+
+/// How the text of paragraphs is wrapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserializable, Merge)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ProseWrap {
+    /// The line breaks of the text are kept as they are.
+    #[default]
+    Preserve,
+    /// The text is wrapped when it exceeds the line width.
+    Always,
+    /// Each paragraph is printed on a single line.
+    Never,
+}
+
+impl ProseWrap {
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+
+    pub const fn is_always(&self) -> bool {
+        matches!(self, Self::Always)
+    }
+}
+
+impl fmt::Display for ProseWrap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Preserve => std::write!(f, "preserve"),
+            Self::Always => std::write!(f, "always"),
+            Self::Never => std::write!(f, "never"),
+        }
+    }
+}
+
+impl FromStr for ProseWrap {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(Self::Preserve),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(
+                "Value not supported for ProseWrap. Supported values are 'preserve', 'always' and 'never'.",
+            ),
+        }
+    }
+}
@@ -0,0 +1,1599 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+#![allow(clippy::use_self)]
+#![expect(clippy::default_constructed_unit_structs)]
+use crate::{
+    AsFormat, FormatBogusNodeRule, FormatNodeRule, IntoFormat, MarkdownFormatContext,
+    MarkdownFormatter,
+};
+use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<biome_markdown_syntax::MdAutolink>
+    for crate::markdown::auxiliary::autolink::FormatMdAutolink
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdAutolink,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdAutolink>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdAutolink {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdAutolink,
+        crate::markdown::auxiliary::autolink::FormatMdAutolink,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::autolink::FormatMdAutolink::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdAutolink {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdAutolink,
+        crate::markdown::auxiliary::autolink::FormatMdAutolink,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::autolink::FormatMdAutolink::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdBullet>
+    for crate::markdown::auxiliary::bullet::FormatMdBullet
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdBullet,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdBullet>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBullet {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdBullet,
+        crate::markdown::auxiliary::bullet::FormatMdBullet,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::bullet::FormatMdBullet::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBullet {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdBullet,
+        crate::markdown::auxiliary::bullet::FormatMdBullet,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::bullet::FormatMdBullet::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdBulletListItem>
+    for crate::markdown::auxiliary::bullet_list_item::FormatMdBulletListItem
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdBulletListItem,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdBulletListItem>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBulletListItem {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdBulletListItem,
+        crate::markdown::auxiliary::bullet_list_item::FormatMdBulletListItem,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::bullet_list_item::FormatMdBulletListItem::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBulletListItem {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdBulletListItem,
+        crate::markdown::auxiliary::bullet_list_item::FormatMdBulletListItem,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::bullet_list_item::FormatMdBulletListItem::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdDocument>
+    for crate::markdown::auxiliary::document::FormatMdDocument
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdDocument,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdDocument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdDocument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdDocument,
+        crate::markdown::auxiliary::document::FormatMdDocument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::document::FormatMdDocument::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdDocument {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdDocument,
+        crate::markdown::auxiliary::document::FormatMdDocument,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::document::FormatMdDocument::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdFencedCodeBlock>
+    for crate::markdown::auxiliary::fenced_code_block::FormatMdFencedCodeBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdFencedCodeBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdFencedCodeBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdFencedCodeBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdFencedCodeBlock,
+        crate::markdown::auxiliary::fenced_code_block::FormatMdFencedCodeBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::fenced_code_block::FormatMdFencedCodeBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdFencedCodeBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdFencedCodeBlock,
+        crate::markdown::auxiliary::fenced_code_block::FormatMdFencedCodeBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::fenced_code_block::FormatMdFencedCodeBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdHardLine>
+    for crate::markdown::auxiliary::hard_line::FormatMdHardLine
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdHardLine,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdHardLine>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHardLine {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdHardLine,
+        crate::markdown::auxiliary::hard_line::FormatMdHardLine,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::hard_line::FormatMdHardLine::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHardLine {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdHardLine,
+        crate::markdown::auxiliary::hard_line::FormatMdHardLine,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::hard_line::FormatMdHardLine::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdHash> for crate::markdown::auxiliary::hash::FormatMdHash {
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdHash,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdHash>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHash {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdHash,
+        crate::markdown::auxiliary::hash::FormatMdHash,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::hash::FormatMdHash::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHash {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdHash,
+        crate::markdown::auxiliary::hash::FormatMdHash,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::hash::FormatMdHash::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdHeader>
+    for crate::markdown::auxiliary::header::FormatMdHeader
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdHeader,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdHeader>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHeader {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdHeader,
+        crate::markdown::auxiliary::header::FormatMdHeader,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::header::FormatMdHeader::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHeader {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdHeader,
+        crate::markdown::auxiliary::header::FormatMdHeader,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::header::FormatMdHeader::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdHtmlBlock>
+    for crate::markdown::auxiliary::html_block::FormatMdHtmlBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdHtmlBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdHtmlBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHtmlBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdHtmlBlock,
+        crate::markdown::auxiliary::html_block::FormatMdHtmlBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::html_block::FormatMdHtmlBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHtmlBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdHtmlBlock,
+        crate::markdown::auxiliary::html_block::FormatMdHtmlBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::html_block::FormatMdHtmlBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdIndentCodeBlock>
+    for crate::markdown::auxiliary::indent_code_block::FormatMdIndentCodeBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdIndentCodeBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdIndentCodeBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdIndentCodeBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdIndentCodeBlock,
+        crate::markdown::auxiliary::indent_code_block::FormatMdIndentCodeBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::indent_code_block::FormatMdIndentCodeBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdIndentCodeBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdIndentCodeBlock,
+        crate::markdown::auxiliary::indent_code_block::FormatMdIndentCodeBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::indent_code_block::FormatMdIndentCodeBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdInlineCode>
+    for crate::markdown::auxiliary::inline_code::FormatMdInlineCode
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdInlineCode,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdInlineCode>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineCode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdInlineCode,
+        crate::markdown::auxiliary::inline_code::FormatMdInlineCode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_code::FormatMdInlineCode::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineCode {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdInlineCode,
+        crate::markdown::auxiliary::inline_code::FormatMdInlineCode,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_code::FormatMdInlineCode::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdInlineEmphasis>
+    for crate::markdown::auxiliary::inline_emphasis::FormatMdInlineEmphasis
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdInlineEmphasis,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdInlineEmphasis>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineEmphasis {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdInlineEmphasis,
+        crate::markdown::auxiliary::inline_emphasis::FormatMdInlineEmphasis,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_emphasis::FormatMdInlineEmphasis::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineEmphasis {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdInlineEmphasis,
+        crate::markdown::auxiliary::inline_emphasis::FormatMdInlineEmphasis,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_emphasis::FormatMdInlineEmphasis::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdInlineHtml>
+    for crate::markdown::auxiliary::inline_html::FormatMdInlineHtml
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdInlineHtml,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdInlineHtml>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineHtml {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdInlineHtml,
+        crate::markdown::auxiliary::inline_html::FormatMdInlineHtml,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_html::FormatMdInlineHtml::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineHtml {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdInlineHtml,
+        crate::markdown::auxiliary::inline_html::FormatMdInlineHtml,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_html::FormatMdInlineHtml::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdInlineImage>
+    for crate::markdown::auxiliary::inline_image::FormatMdInlineImage
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdInlineImage,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdInlineImage>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineImage {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdInlineImage,
+        crate::markdown::auxiliary::inline_image::FormatMdInlineImage,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_image::FormatMdInlineImage::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineImage {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdInlineImage,
+        crate::markdown::auxiliary::inline_image::FormatMdInlineImage,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_image::FormatMdInlineImage::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdInlineItalic>
+    for crate::markdown::auxiliary::inline_italic::FormatMdInlineItalic
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdInlineItalic,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdInlineItalic>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineItalic {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdInlineItalic,
+        crate::markdown::auxiliary::inline_italic::FormatMdInlineItalic,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_italic::FormatMdInlineItalic::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineItalic {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdInlineItalic,
+        crate::markdown::auxiliary::inline_italic::FormatMdInlineItalic,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_italic::FormatMdInlineItalic::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdInlineLink>
+    for crate::markdown::auxiliary::inline_link::FormatMdInlineLink
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdInlineLink,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdInlineLink>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineLink {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdInlineLink,
+        crate::markdown::auxiliary::inline_link::FormatMdInlineLink,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_link::FormatMdInlineLink::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineLink {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdInlineLink,
+        crate::markdown::auxiliary::inline_link::FormatMdInlineLink,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_link::FormatMdInlineLink::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdInlineStrikethrough>
+    for crate::markdown::auxiliary::inline_strikethrough::FormatMdInlineStrikethrough
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdInlineStrikethrough,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdInlineStrikethrough>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineStrikethrough {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdInlineStrikethrough,
+        crate::markdown::auxiliary::inline_strikethrough::FormatMdInlineStrikethrough,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_strikethrough::FormatMdInlineStrikethrough::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineStrikethrough {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdInlineStrikethrough,
+        crate::markdown::auxiliary::inline_strikethrough::FormatMdInlineStrikethrough,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::inline_strikethrough::FormatMdInlineStrikethrough::default(
+            ),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdLinkBlock>
+    for crate::markdown::auxiliary::link_block::FormatMdLinkBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdLinkBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdLinkBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdLinkBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdLinkBlock,
+        crate::markdown::auxiliary::link_block::FormatMdLinkBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::link_block::FormatMdLinkBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdLinkBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdLinkBlock,
+        crate::markdown::auxiliary::link_block::FormatMdLinkBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::link_block::FormatMdLinkBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdOrderBullet>
+    for crate::markdown::auxiliary::order_bullet::FormatMdOrderBullet
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdOrderBullet,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdOrderBullet>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdOrderBullet {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdOrderBullet,
+        crate::markdown::auxiliary::order_bullet::FormatMdOrderBullet,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::order_bullet::FormatMdOrderBullet::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdOrderBullet {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdOrderBullet,
+        crate::markdown::auxiliary::order_bullet::FormatMdOrderBullet,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::order_bullet::FormatMdOrderBullet::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdOrderListItem>
+    for crate::markdown::auxiliary::order_list_item::FormatMdOrderListItem
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdOrderListItem,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdOrderListItem>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdOrderListItem {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdOrderListItem,
+        crate::markdown::auxiliary::order_list_item::FormatMdOrderListItem,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::order_list_item::FormatMdOrderListItem::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdOrderListItem {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdOrderListItem,
+        crate::markdown::auxiliary::order_list_item::FormatMdOrderListItem,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::order_list_item::FormatMdOrderListItem::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdParagraph>
+    for crate::markdown::auxiliary::paragraph::FormatMdParagraph
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdParagraph,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdParagraph>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdParagraph {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdParagraph,
+        crate::markdown::auxiliary::paragraph::FormatMdParagraph,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::paragraph::FormatMdParagraph::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdParagraph {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdParagraph,
+        crate::markdown::auxiliary::paragraph::FormatMdParagraph,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::paragraph::FormatMdParagraph::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdQuote>
+    for crate::markdown::auxiliary::quote::FormatMdQuote
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdQuote,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdQuote>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdQuote {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdQuote,
+        crate::markdown::auxiliary::quote::FormatMdQuote,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::quote::FormatMdQuote::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdQuote {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdQuote,
+        crate::markdown::auxiliary::quote::FormatMdQuote,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::quote::FormatMdQuote::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdQuotePrefix>
+    for crate::markdown::auxiliary::quote_prefix::FormatMdQuotePrefix
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdQuotePrefix,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdQuotePrefix>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdQuotePrefix {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdQuotePrefix,
+        crate::markdown::auxiliary::quote_prefix::FormatMdQuotePrefix,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::quote_prefix::FormatMdQuotePrefix::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdQuotePrefix {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdQuotePrefix,
+        crate::markdown::auxiliary::quote_prefix::FormatMdQuotePrefix,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::quote_prefix::FormatMdQuotePrefix::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdReferenceImage>
+    for crate::markdown::auxiliary::reference_image::FormatMdReferenceImage
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdReferenceImage,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdReferenceImage>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdReferenceImage {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdReferenceImage,
+        crate::markdown::auxiliary::reference_image::FormatMdReferenceImage,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::reference_image::FormatMdReferenceImage::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdReferenceImage {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdReferenceImage,
+        crate::markdown::auxiliary::reference_image::FormatMdReferenceImage,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::reference_image::FormatMdReferenceImage::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdReferenceLink>
+    for crate::markdown::auxiliary::reference_link::FormatMdReferenceLink
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdReferenceLink,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdReferenceLink>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdReferenceLink {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdReferenceLink,
+        crate::markdown::auxiliary::reference_link::FormatMdReferenceLink,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::reference_link::FormatMdReferenceLink::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdReferenceLink {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdReferenceLink,
+        crate::markdown::auxiliary::reference_link::FormatMdReferenceLink,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::reference_link::FormatMdReferenceLink::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdReferenceLinkLabel>
+    for crate::markdown::auxiliary::reference_link_label::FormatMdReferenceLinkLabel
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdReferenceLinkLabel,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdReferenceLinkLabel>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdReferenceLinkLabel {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdReferenceLinkLabel,
+        crate::markdown::auxiliary::reference_link_label::FormatMdReferenceLinkLabel,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::reference_link_label::FormatMdReferenceLinkLabel::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdReferenceLinkLabel {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdReferenceLinkLabel,
+        crate::markdown::auxiliary::reference_link_label::FormatMdReferenceLinkLabel,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::reference_link_label::FormatMdReferenceLinkLabel::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdSetextHeader>
+    for crate::markdown::auxiliary::setext_header::FormatMdSetextHeader
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdSetextHeader,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdSetextHeader>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdSetextHeader {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdSetextHeader,
+        crate::markdown::auxiliary::setext_header::FormatMdSetextHeader,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::setext_header::FormatMdSetextHeader::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdSetextHeader {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdSetextHeader,
+        crate::markdown::auxiliary::setext_header::FormatMdSetextHeader,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::setext_header::FormatMdSetextHeader::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdTable>
+    for crate::markdown::auxiliary::table::FormatMdTable
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdTable,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdTable>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTable {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTable,
+        crate::markdown::auxiliary::table::FormatMdTable,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::table::FormatMdTable::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTable {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTable,
+        crate::markdown::auxiliary::table::FormatMdTable,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::table::FormatMdTable::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdTableCell>
+    for crate::markdown::auxiliary::table_cell::FormatMdTableCell
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdTableCell,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdTableCell>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableCell {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTableCell,
+        crate::markdown::auxiliary::table_cell::FormatMdTableCell,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::table_cell::FormatMdTableCell::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableCell {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTableCell,
+        crate::markdown::auxiliary::table_cell::FormatMdTableCell,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::table_cell::FormatMdTableCell::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdTableRow>
+    for crate::markdown::auxiliary::table_row::FormatMdTableRow
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdTableRow,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdTableRow>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableRow {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTableRow,
+        crate::markdown::auxiliary::table_row::FormatMdTableRow,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::table_row::FormatMdTableRow::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableRow {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTableRow,
+        crate::markdown::auxiliary::table_row::FormatMdTableRow,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::table_row::FormatMdTableRow::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdTaskListCheckbox>
+    for crate::markdown::auxiliary::task_list_checkbox::FormatMdTaskListCheckbox
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdTaskListCheckbox,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdTaskListCheckbox>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTaskListCheckbox {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTaskListCheckbox,
+        crate::markdown::auxiliary::task_list_checkbox::FormatMdTaskListCheckbox,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::task_list_checkbox::FormatMdTaskListCheckbox::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTaskListCheckbox {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTaskListCheckbox,
+        crate::markdown::auxiliary::task_list_checkbox::FormatMdTaskListCheckbox,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::task_list_checkbox::FormatMdTaskListCheckbox::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdTextual>
+    for crate::markdown::auxiliary::textual::FormatMdTextual
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdTextual,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdTextual>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTextual {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTextual,
+        crate::markdown::auxiliary::textual::FormatMdTextual,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::textual::FormatMdTextual::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTextual {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTextual,
+        crate::markdown::auxiliary::textual::FormatMdTextual,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::textual::FormatMdTextual::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdThematicBreakBlock>
+    for crate::markdown::auxiliary::thematic_break_block::FormatMdThematicBreakBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdThematicBreakBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdThematicBreakBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdThematicBreakBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdThematicBreakBlock,
+        crate::markdown::auxiliary::thematic_break_block::FormatMdThematicBreakBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::thematic_break_block::FormatMdThematicBreakBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdThematicBreakBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdThematicBreakBlock,
+        crate::markdown::auxiliary::thematic_break_block::FormatMdThematicBreakBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::thematic_break_block::FormatMdThematicBreakBlock::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBlockList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdBlockList,
+        crate::markdown::lists::block_list::FormatMdBlockList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::lists::block_list::FormatMdBlockList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBlockList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdBlockList,
+        crate::markdown::lists::block_list::FormatMdBlockList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::lists::block_list::FormatMdBlockList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBulletList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdBulletList,
+        crate::markdown::lists::bullet_list::FormatMdBulletList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::lists::bullet_list::FormatMdBulletList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBulletList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdBulletList,
+        crate::markdown::lists::bullet_list::FormatMdBulletList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::lists::bullet_list::FormatMdBulletList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHashList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdHashList,
+        crate::markdown::lists::hash_list::FormatMdHashList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::lists::hash_list::FormatMdHashList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHashList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdHashList,
+        crate::markdown::lists::hash_list::FormatMdHashList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::lists::hash_list::FormatMdHashList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineItemList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdInlineItemList,
+        crate::markdown::lists::inline_item_list::FormatMdInlineItemList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::lists::inline_item_list::FormatMdInlineItemList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineItemList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdInlineItemList,
+        crate::markdown::lists::inline_item_list::FormatMdInlineItemList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::lists::inline_item_list::FormatMdInlineItemList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdOrderList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdOrderList,
+        crate::markdown::lists::order_list::FormatMdOrderList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::lists::order_list::FormatMdOrderList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdOrderList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdOrderList,
+        crate::markdown::lists::order_list::FormatMdOrderList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::lists::order_list::FormatMdOrderList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableCellList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTableCellList,
+        crate::markdown::lists::table_cell_list::FormatMdTableCellList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::lists::table_cell_list::FormatMdTableCellList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableCellList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTableCellList,
+        crate::markdown::lists::table_cell_list::FormatMdTableCellList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::lists::table_cell_list::FormatMdTableCellList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableRowList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTableRowList,
+        crate::markdown::lists::table_row_list::FormatMdTableRowList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::lists::table_row_list::FormatMdTableRowList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableRowList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTableRowList,
+        crate::markdown::lists::table_row_list::FormatMdTableRowList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::lists::table_row_list::FormatMdTableRowList::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdBogus> for crate::markdown::bogus::bogus::FormatMdBogus {
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdBogus,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_markdown_syntax::MdBogus>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBogus {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdBogus,
+        crate::markdown::bogus::bogus::FormatMdBogus,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::bogus::bogus::FormatMdBogus::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBogus {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdBogus,
+        crate::markdown::bogus::bogus::FormatMdBogus,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::bogus::bogus::FormatMdBogus::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyCodeBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::AnyCodeBlock,
+        crate::markdown::any::code_block::FormatAnyCodeBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::any::code_block::FormatAnyCodeBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyCodeBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::AnyCodeBlock,
+        crate::markdown::any::code_block::FormatAnyCodeBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::any::code_block::FormatAnyCodeBlock::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyContainerBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::AnyContainerBlock,
+        crate::markdown::any::container_block::FormatAnyContainerBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::any::container_block::FormatAnyContainerBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyContainerBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::AnyContainerBlock,
+        crate::markdown::any::container_block::FormatAnyContainerBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::any::container_block::FormatAnyContainerBlock::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyLeafBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::AnyLeafBlock,
+        crate::markdown::any::leaf_block::FormatAnyLeafBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::any::leaf_block::FormatAnyLeafBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyLeafBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::AnyLeafBlock,
+        crate::markdown::any::leaf_block::FormatAnyLeafBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::any::leaf_block::FormatAnyLeafBlock::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::AnyMdBlock,
+        crate::markdown::any::block::FormatAnyMdBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::any::block::FormatAnyMdBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::AnyMdBlock,
+        crate::markdown::any::block::FormatAnyMdBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::any::block::FormatAnyMdBlock::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdInline {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::AnyMdInline,
+        crate::markdown::any::inline::FormatAnyMdInline,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::any::inline::FormatAnyMdInline::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdInline {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::AnyMdInline,
+        crate::markdown::any::inline::FormatAnyMdInline,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::any::inline::FormatAnyMdInline::default(),
+        )
+    }
+}
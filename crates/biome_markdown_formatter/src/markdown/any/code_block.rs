@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_markdown_syntax::AnyCodeBlock;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCodeBlock;
+impl FormatRule<AnyCodeBlock> for FormatAnyCodeBlock {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &AnyCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        match node {
+            AnyCodeBlock::MdFencedCodeBlock(node) => node.format().fmt(f),
+            AnyCodeBlock::MdIndentCodeBlock(node) => node.format().fmt(f),
+        }
+    }
+}
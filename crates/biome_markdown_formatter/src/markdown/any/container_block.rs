@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_markdown_syntax::AnyContainerBlock;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyContainerBlock;
+impl FormatRule<AnyContainerBlock> for FormatAnyContainerBlock {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &AnyContainerBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        match node {
+            AnyContainerBlock::MdBulletListItem(node) => node.format().fmt(f),
+            AnyContainerBlock::MdOrderListItem(node) => node.format().fmt(f),
+            AnyContainerBlock::MdQuote(node) => node.format().fmt(f),
+        }
+    }
+}
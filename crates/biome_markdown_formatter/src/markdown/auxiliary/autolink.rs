@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use crate::utils::inline::format_inline_verbatim;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdAutolink, MdAutolinkFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdAutolink;
+impl FormatNodeRule<MdAutolink> for FormatMdAutolink {
+    fn fmt_fields(&self, node: &MdAutolink, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdAutolinkFields {
+            l_angle_token,
+            r_angle_token,
+            ..
+        } = node.as_fields();
+
+        write!(
+            f,
+            [format_inline_verbatim(&l_angle_token?, &r_angle_token?)]
+        )
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use crate::utils::blocks::{bullet_marker, format_list_item};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdBullet, MdBulletFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBullet;
+impl FormatNodeRule<MdBullet> for FormatMdBullet {
+    fn fmt_fields(&self, node: &MdBullet, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdBulletFields {
+            bullet,
+            checkbox,
+            content,
+        } = node.as_fields();
+        let marker = bullet_marker(node, f);
+
+        write!(
+            f,
+            [format_list_item(
+                &bullet?,
+                marker.as_str(),
+                checkbox.as_ref(),
+                &content
+            )]
+        )
+    }
+}
@@ -0,0 +1,86 @@
+use crate::prelude::*;
+use crate::utils::blocks::format_removed_node;
+use crate::utils::lines::{
+    FirstLine, block_lines, containers, format_block_lines, indent_in_container,
+};
+use biome_formatter::format_element::tag::Tag;
+use biome_formatter::{CstFormatContext, FormatElement, write};
+use biome_markdown_syntax::{MdFencedCodeBlock, MdFencedCodeBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdFencedCodeBlock;
+impl FormatNodeRule<MdFencedCodeBlock> for FormatMdFencedCodeBlock {
+    fn fmt_fields(&self, node: &MdFencedCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdFencedCodeBlockFields {
+            l_fence,
+            info,
+            content,
+            r_fence,
+        } = node.as_fields();
+        let l_fence = l_fence?;
+
+        // The lines of the code are indented like the opening fence
+        let indent = indent_in_container(&l_fence, &containers(node.syntax()));
+        let lines = block_lines(&content, FirstLine::AfterFirstLineBreak, indent);
+
+        write!(f, [l_fence.format()])?;
+        if let Some(info) = info {
+            // The info string is trimmed here instead of by the rule of its node
+            f.context()
+                .comments()
+                .mark_suppression_checked(info.syntax());
+            let info_token = info.value_token()?;
+            write!(
+                f,
+                [format_replaced(
+                    &info_token,
+                    &text(
+                        info_token.text_trimmed().trim(),
+                        info_token.text_trimmed_range().start()
+                    )
+                )]
+            )?;
+        }
+
+        write!(f, [format_removed_node(content.syntax())])?;
+        let position = content.syntax().text_trimmed_range().start();
+
+        let embedded_range =
+            if f.context().should_delegate_fmt_embedded_nodes() && !node.is_in_quote() {
+                node.code_range()
+            } else {
+                None
+            };
+
+        // The code is formatted by the formatter of its language, if any, see `Formatted::format_embedded`
+        if let Some(range) = embedded_range {
+            f.write_element(FormatElement::Tag(Tag::StartEmbedded(range)))?;
+            write!(f, [format_block_lines(&lines, position, true)])?;
+            f.write_element(FormatElement::Tag(Tag::EndEmbedded))?;
+        } else {
+            write!(f, [format_block_lines(&lines, position, true)])?;
+        }
+
+        write!(f, [hard_line_break()])?;
+
+        // The closing fence is the same as the opening fence. It's added when it's missing
+        // because the code block is closed by the end of its container
+        let closing_fence = l_fence.text_trimmed().to_string();
+        match r_fence {
+            Some(r_fence) => write!(
+                f,
+                [format_replaced(
+                    &r_fence,
+                    &text(&closing_fence, r_fence.text_trimmed_range().start())
+                )]
+            ),
+            None => write!(
+                f,
+                [text(
+                    &closing_fence,
+                    node.syntax().text_trimmed_range().end()
+                )]
+            ),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use crate::utils::inline::format_inline_separator;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHardLine, MdHardLineFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHardLine;
+impl FormatNodeRule<MdHardLine> for FormatMdHardLine {
+    fn fmt_fields(&self, node: &MdHardLine, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHardLineFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        // The token contains the line break, and the spaces or the backslash that precede it
+        let marker = if value_token.text_trimmed().starts_with('\\') {
+            "\\"
+        } else {
+            "  "
+        };
+
+        write!(
+            f,
+            [
+                format_inline_separator(&value_token),
+                format_replaced(&value_token, &token(marker)),
+                hard_line_break()
+            ]
+        )
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHash, MdHashFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHash;
+impl FormatNodeRule<MdHash> for FormatMdHash {
+    fn fmt_fields(&self, node: &MdHash, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHashFields { hash_token } = node.as_fields();
+
+        write!(f, [hash_token.format()])
+    }
+}
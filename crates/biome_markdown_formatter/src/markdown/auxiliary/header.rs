@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use crate::utils::blocks::format_removed_node;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHeader, MdHeaderFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHeader;
+impl FormatNodeRule<MdHeader> for FormatMdHeader {
+    fn fmt_fields(&self, node: &MdHeader, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHeaderFields {
+            before,
+            content,
+            after,
+        } = node.as_fields();
+
+        write!(f, [before.format()])?;
+
+        if let Some(content) = content
+            && !content.list().is_empty()
+        {
+            write!(f, [space(), content.format()])?;
+        }
+
+        // The closing sequence of `#` is optional
+        write!(f, [format_removed_node(after.syntax())])
+    }
+}
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use crate::utils::blocks::format_removed_node;
+use crate::utils::lines::{FirstLine, block_lines, format_block_lines};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHtmlBlock, MdHtmlBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHtmlBlock;
+impl FormatNodeRule<MdHtmlBlock> for FormatMdHtmlBlock {
+    fn fmt_fields(&self, node: &MdHtmlBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHtmlBlockFields { content } = node.as_fields();
+
+        let lines: Vec<_> = block_lines(&content, FirstLine::AfterLastLineBreak, 0)
+            .into_iter()
+            .map(|line| line.trim_end().to_string())
+            .collect();
+
+        write!(
+            f,
+            [
+                format_removed_node(content.syntax()),
+                format_block_lines(&lines, content.syntax().text_trimmed_range().start(), false)
+            ]
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use crate::utils::blocks::format_removed_node;
+use crate::utils::lines::{FirstLine, block_lines, format_block_lines};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdIndentCodeBlock, MdIndentCodeBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdIndentCodeBlock;
+impl FormatNodeRule<MdIndentCodeBlock> for FormatMdIndentCodeBlock {
+    fn fmt_fields(&self, node: &MdIndentCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdIndentCodeBlockFields { content } = node.as_fields();
+
+        let lines: Vec<_> = block_lines(&content, FirstLine::AfterLastLineBreak, 4)
+            .into_iter()
+            .map(|line| {
+                if line.trim().is_empty() {
+                    line
+                } else {
+                    std::format!("    {line}")
+                }
+            })
+            .collect();
+
+        write!(
+            f,
+            [
+                format_removed_node(content.syntax()),
+                format_block_lines(&lines, content.syntax().text_trimmed_range().start(), false)
+            ]
+        )
+    }
+}
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use crate::utils::inline::format_inline_verbatim;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineCode, MdInlineCodeFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineCode;
+impl FormatNodeRule<MdInlineCode> for FormatMdInlineCode {
+    fn fmt_fields(&self, node: &MdInlineCode, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineCodeFields {
+            l_tick_token,
+            r_tick_token,
+            ..
+        } = node.as_fields();
+
+        write!(f, [format_inline_verbatim(&l_tick_token?, &r_tick_token?)])
+    }
+}
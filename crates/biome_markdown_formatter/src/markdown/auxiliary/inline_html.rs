@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use crate::utils::inline::format_inline_verbatim;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineHtml, MdInlineHtmlFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineHtml;
+impl FormatNodeRule<MdInlineHtml> for FormatMdInlineHtml {
+    fn fmt_fields(&self, node: &MdInlineHtml, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineHtmlFields { value } = node.as_fields();
+
+        match (value.syntax().first_token(), value.syntax().last_token()) {
+            (Some(first), Some(last)) => write!(f, [format_inline_verbatim(&first, &last)]),
+            _ => Ok(()),
+        }
+    }
+}
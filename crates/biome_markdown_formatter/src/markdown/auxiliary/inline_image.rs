@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use crate::utils::inline::{format_inline_token, format_inline_verbatim};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineImage, MdInlineImageFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineImage;
+impl FormatNodeRule<MdInlineImage> for FormatMdInlineImage {
+    fn fmt_fields(&self, node: &MdInlineImage, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineImageFields {
+            excl_token,
+            l_brack_token,
+            alt,
+            r_brack_token,
+            l_paren_token,
+            r_paren_token,
+            ..
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                format_inline_token(&excl_token?),
+                format_inline_token(&l_brack_token?),
+                alt.format(),
+                format_inline_token(&r_brack_token?),
+                format_inline_verbatim(&l_paren_token?, &r_paren_token?)
+            ]
+        )
+    }
+}
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use crate::utils::inline::format_emphasis;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineItalic, MdInlineItalicFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineItalic;
+impl FormatNodeRule<MdInlineItalic> for FormatMdInlineItalic {
+    fn fmt_fields(&self, node: &MdInlineItalic, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineItalicFields {
+            l_fence,
+            content,
+            r_fence,
+        } = node.as_fields();
+
+        write!(f, [format_emphasis(&l_fence?, &content, &r_fence?)])
+    }
+}
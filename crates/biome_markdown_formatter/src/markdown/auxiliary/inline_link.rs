@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use crate::utils::inline::{format_inline_token, format_inline_verbatim};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineLink, MdInlineLinkFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineLink;
+impl FormatNodeRule<MdInlineLink> for FormatMdInlineLink {
+    fn fmt_fields(&self, node: &MdInlineLink, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineLinkFields {
+            l_brack_token,
+            text,
+            r_brack_token,
+            l_paren_token,
+            r_paren_token,
+            ..
+        } = node.as_fields();
+
+        // The destination and the title are kept as they are
+        write!(
+            f,
+            [
+                format_inline_token(&l_brack_token?),
+                text.format(),
+                format_inline_token(&r_brack_token?),
+                format_inline_verbatim(&l_paren_token?, &r_paren_token?)
+            ]
+        )
+    }
+}
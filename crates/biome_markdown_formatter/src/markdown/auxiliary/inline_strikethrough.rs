@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use crate::utils::inline::format_inline_token;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineStrikethrough, MdInlineStrikethroughFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineStrikethrough;
+impl FormatNodeRule<MdInlineStrikethrough> for FormatMdInlineStrikethrough {
+    fn fmt_fields(
+        &self,
+        node: &MdInlineStrikethrough,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdInlineStrikethroughFields {
+            l_fence_token,
+            content,
+            r_fence_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                format_inline_token(&l_fence_token?),
+                content.format(),
+                format_inline_token(&r_fence_token?)
+            ]
+        )
+    }
+}
@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use crate::utils::blocks::format_removed_node;
+use crate::utils::inline::inline_source_text;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineItemList, MdLinkBlock, MdLinkBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdLinkBlock;
+impl FormatNodeRule<MdLinkBlock> for FormatMdLinkBlock {
+    fn fmt_fields(&self, node: &MdLinkBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdLinkBlockFields {
+            l_brack_token,
+            label,
+            destination,
+            ..
+        } = node.as_fields();
+        let l_brack_token = l_brack_token?;
+
+        let source_text = |list: &MdInlineItemList| {
+            list.syntax()
+                .first_token()
+                .zip(list.syntax().last_token())
+                .map(|(first, last)| inline_source_text(&first, &last))
+                .unwrap_or_default()
+        };
+
+        // The destination and the title can be on the next lines
+        let mut definition = std::format!("[{}]:", source_text(&label));
+        let destination_text = source_text(&destination);
+        if !destination_text.is_empty() {
+            definition.push(' ');
+            definition.push_str(&destination_text);
+        }
+
+        write!(
+            f,
+            [
+                format_removed_node(node.syntax()),
+                text(&definition, l_brack_token.text_trimmed_range().start())
+            ]
+        )
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use crate::utils::blocks::{format_list_item, order_marker};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdOrderBullet, MdOrderBulletFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderBullet;
+impl FormatNodeRule<MdOrderBullet> for FormatMdOrderBullet {
+    fn fmt_fields(&self, node: &MdOrderBullet, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdOrderBulletFields {
+            marker_token,
+            checkbox,
+            content,
+        } = node.as_fields();
+        let marker = order_marker(node);
+
+        write!(
+            f,
+            [format_list_item(
+                &marker_token?,
+                &marker,
+                checkbox.as_ref(),
+                &content
+            )]
+        )
+    }
+}
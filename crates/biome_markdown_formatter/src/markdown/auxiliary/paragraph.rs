@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use crate::utils::inline::paragraph_wrap;
+use biome_formatter::format_element::tag::Tag;
+use biome_formatter::{FormatElement, write};
+use biome_markdown_syntax::{MdParagraph, MdParagraphFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdParagraph;
+impl FormatNodeRule<MdParagraph> for FormatMdParagraph {
+    fn fmt_fields(&self, node: &MdParagraph, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdParagraphFields { list } = node.as_fields();
+
+        if !paragraph_wrap(node, f).is_always() {
+            return write!(f, [list.format()]);
+        }
+
+        // The words are the entries of a fill, whose separators are written between the tokens
+        // of the words, see `FormatInlineSeparator`
+        f.write_elements([
+            FormatElement::Tag(Tag::StartFill),
+            FormatElement::Tag(Tag::StartEntry),
+        ])?;
+        write!(f, [list.format()])?;
+        f.write_elements([
+            FormatElement::Tag(Tag::EndEntry),
+            FormatElement::Tag(Tag::EndFill),
+        ])
+    }
+}
@@ -0,0 +1,50 @@
+use crate::prelude::*;
+use crate::utils::blocks::formatted_column;
+use crate::utils::print::print_to_string;
+use biome_formatter::{FormatOptions, write};
+use biome_markdown_syntax::{MdQuote, MdQuoteFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdQuote;
+impl FormatNodeRule<MdQuote> for FormatMdQuote {
+    fn fmt_fields(&self, node: &MdQuote, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdQuoteFields { prefix, content } = node.as_fields();
+        let prefix = prefix?;
+        let position = prefix.syntax().text_trimmed_range().start();
+
+        write!(f, [prefix.format()])?;
+
+        // The content is printed on its own, then each of its lines is prefixed with the marker
+        let width = usize::from(f.options().line_width().value())
+            .saturating_sub(formatted_column(node.syntax()));
+        let printed = print_to_string(
+            &content.format(),
+            u32::try_from(width).unwrap_or(u32::MAX),
+            f,
+        )?;
+
+        let lines: Vec<String> = printed
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    ">".to_string()
+                } else {
+                    std::format!("> {line}")
+                }
+            })
+            .collect();
+
+        if lines.is_empty() {
+            return write!(f, [token(">")]);
+        }
+
+        for (index, line) in lines.iter().enumerate() {
+            if index > 0 {
+                write!(f, [hard_line_break()])?;
+            }
+            write!(f, [text(line, position)])?;
+        }
+
+        Ok(())
+    }
+}
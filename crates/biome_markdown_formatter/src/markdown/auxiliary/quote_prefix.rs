@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdQuotePrefix, MdQuotePrefixFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdQuotePrefix;
+impl FormatNodeRule<MdQuotePrefix> for FormatMdQuotePrefix {
+    fn fmt_fields(&self, node: &MdQuotePrefix, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdQuotePrefixFields { marker_token } = node.as_fields();
+
+        // The quote prints its own marker at the start of each of its lines
+        write!(f, [format_removed(&marker_token?)])
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use crate::utils::inline::format_inline_token;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdReferenceImage, MdReferenceImageFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdReferenceImage;
+impl FormatNodeRule<MdReferenceImage> for FormatMdReferenceImage {
+    fn fmt_fields(&self, node: &MdReferenceImage, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdReferenceImageFields {
+            excl_token,
+            l_brack_token,
+            alt,
+            r_brack_token,
+            label,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                format_inline_token(&excl_token?),
+                format_inline_token(&l_brack_token?),
+                alt.format(),
+                format_inline_token(&r_brack_token?),
+                label.format()
+            ]
+        )
+    }
+}
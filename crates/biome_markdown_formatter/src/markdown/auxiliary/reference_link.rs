@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use crate::utils::inline::format_inline_token;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdReferenceLink, MdReferenceLinkFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdReferenceLink;
+impl FormatNodeRule<MdReferenceLink> for FormatMdReferenceLink {
+    fn fmt_fields(&self, node: &MdReferenceLink, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdReferenceLinkFields {
+            l_brack_token,
+            text,
+            r_brack_token,
+            label,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                format_inline_token(&l_brack_token?),
+                text.format(),
+                format_inline_token(&r_brack_token?),
+                label.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use crate::utils::inline::format_inline_verbatim;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdReferenceLinkLabel, MdReferenceLinkLabelFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdReferenceLinkLabel;
+impl FormatNodeRule<MdReferenceLinkLabel> for FormatMdReferenceLinkLabel {
+    fn fmt_fields(
+        &self,
+        node: &MdReferenceLinkLabel,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdReferenceLinkLabelFields {
+            l_brack_token,
+            r_brack_token,
+            ..
+        } = node.as_fields();
+
+        // The label identifies a link reference definition: it's kept as it is
+        write!(
+            f,
+            [format_inline_verbatim(&l_brack_token?, &r_brack_token?)]
+        )
+    }
+}
@@ -0,0 +1,46 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownSyntaxKind, MdSetextHeader, MdSetextHeaderFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdSetextHeader;
+impl FormatNodeRule<MdSetextHeader> for FormatMdSetextHeader {
+    fn fmt_fields(&self, node: &MdSetextHeader, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdSetextHeaderFields {
+            content,
+            underline_token,
+        } = node.as_fields();
+        let content = content?;
+        let underline_token = underline_token?;
+        let is_level_one = underline_token.text_trimmed().starts_with('=');
+
+        // An ATX heading can't contain hard line breaks
+        let has_hard_line = content
+            .syntax()
+            .descendants()
+            .any(|node| node.kind() == MarkdownSyntaxKind::MD_HARD_LINE);
+
+        if has_hard_line {
+            let underline = if is_level_one { "===" } else { "---" };
+            return write!(
+                f,
+                [
+                    content.format(),
+                    hard_line_break(),
+                    format_replaced(&underline_token, &token(underline))
+                ]
+            );
+        }
+
+        let hashes = if is_level_one { "#" } else { "##" };
+        write!(
+            f,
+            [
+                token(hashes),
+                space(),
+                content.format(),
+                format_removed(&underline_token)
+            ]
+        )
+    }
+}
@@ -0,0 +1,173 @@
+use crate::prelude::*;
+use crate::utils::blocks::format_removed_node;
+use crate::utils::print::print_to_string;
+use biome_formatter::{CstFormatContext, write};
+use biome_markdown_syntax::{MdTable, MdTableFields, MdTableRow};
+use std::iter;
+use unicode_width::UnicodeWidthStr;
+
+/// The minimal width of a column, which is the width of the delimiter `---`.
+const MIN_COLUMN_WIDTH: usize = 3;
+
+/// The alignment of a column of a table, defined by the position of the colons in its delimiter.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+enum ColumnAlignment {
+    #[default]
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+impl ColumnAlignment {
+    fn from_delimiter(delimiter: &str) -> Self {
+        match (delimiter.starts_with(':'), delimiter.ends_with(':')) {
+            (true, true) if delimiter.len() > 1 => Self::Center,
+            (true, _) => Self::Left,
+            (false, true) => Self::Right,
+            (false, false) => Self::None,
+        }
+    }
+
+    fn delimiter(self, width: usize) -> String {
+        match self {
+            Self::None => "-".repeat(width),
+            Self::Left => std::format!(":{}", "-".repeat(width - 1)),
+            Self::Center => std::format!(":{}:", "-".repeat(width - 2)),
+            Self::Right => std::format!("{}:", "-".repeat(width - 1)),
+        }
+    }
+
+    fn pad(self, cell: &str, width: usize) -> String {
+        let padding = width.saturating_sub(cell.width());
+        match self {
+            Self::None | Self::Left => std::format!("{cell}{}", " ".repeat(padding)),
+            Self::Center => {
+                let before = padding / 2;
+                std::format!(
+                    "{}{cell}{}",
+                    " ".repeat(before),
+                    " ".repeat(padding - before)
+                )
+            }
+            Self::Right => std::format!("{}{cell}", " ".repeat(padding)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTable;
+impl FormatNodeRule<MdTable> for FormatMdTable {
+    fn fmt_fields(&self, node: &MdTable, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableFields {
+            header,
+            delimiter,
+            rows,
+        } = node.as_fields();
+        let header = header?;
+        let delimiter = delimiter?;
+
+        let alignments: Vec<_> = delimiter
+            .cells()
+            .iter()
+            .map(|cell| {
+                let delimiter = cell.content().syntax().text_trimmed().to_string();
+                ColumnAlignment::from_delimiter(delimiter.trim())
+            })
+            .collect();
+        write!(f, [format_removed_node(delimiter.syntax())])?;
+
+        // The cells are printed on their own to align the columns
+        let mut table: Vec<(MdTableRow, Vec<String>)> = Vec::new();
+        for row in iter::once(header.clone()).chain(rows.iter()) {
+            // The rows and the cells are printed by the table
+            let comments = f.context().comments();
+            comments.mark_suppression_checked(row.syntax());
+            for cell in row.cells().iter() {
+                comments.mark_suppression_checked(cell.syntax());
+            }
+
+            if let Some(l_pipe_token) = row.l_pipe_token() {
+                write!(f, [format_removed(&l_pipe_token)])?;
+            }
+
+            let mut cells = Vec::new();
+            for cell in row.cells().iter() {
+                cells.push(print_to_string(
+                    &cell.content().format(),
+                    u32::from(u16::MAX),
+                    f,
+                )?);
+                if let Some(r_pipe_token) = cell.r_pipe_token() {
+                    write!(f, [format_removed(&r_pipe_token)])?;
+                }
+            }
+            table.push((row, cells));
+        }
+
+        let header_columns = header.cells().len();
+        let columns = table
+            .iter()
+            .map(|(_, cells)| cells.len())
+            .max()
+            .unwrap_or_default()
+            .max(header_columns);
+        let widths: Vec<usize> = (0..columns)
+            .map(|column| {
+                table
+                    .iter()
+                    .filter_map(|(_, cells)| cells.get(column))
+                    .map(|cell| cell.width())
+                    .max()
+                    .unwrap_or_default()
+                    .max(MIN_COLUMN_WIDTH)
+            })
+            .collect();
+
+        let alignment = |column: usize| alignments.get(column).copied().unwrap_or_default();
+        let format_row = |cells: Vec<String>| {
+            let cells: Vec<_> = cells
+                .iter()
+                .enumerate()
+                .map(|(column, cell)| alignment(column).pad(cell, widths[column]))
+                .collect();
+            std::format!("| {} |", cells.join(" | "))
+        };
+
+        for (index, (row, mut cells)) in table.into_iter().enumerate() {
+            // The missing cells of a row are empty
+            if cells.len() < header_columns {
+                cells.resize(header_columns, String::new());
+            }
+
+            if index > 0 {
+                write!(f, [hard_line_break()])?;
+            }
+            write!(
+                f,
+                [text(
+                    &format_row(cells),
+                    row.syntax().text_trimmed_range().start()
+                )]
+            )?;
+
+            if index == 0 {
+                let delimiters = (0..header_columns)
+                    .map(|column| alignment(column).delimiter(widths[column]))
+                    .collect();
+                write!(
+                    f,
+                    [
+                        hard_line_break(),
+                        text(
+                            &format_row(delimiters),
+                            delimiter.syntax().text_trimmed_range().start()
+                        )
+                    ]
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdTableCell, MdTableCellFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableCell;
+impl FormatNodeRule<MdTableCell> for FormatMdTableCell {
+    fn fmt_fields(&self, node: &MdTableCell, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableCellFields {
+            content,
+            r_pipe_token,
+        } = node.as_fields();
+
+        write!(f, [content.format(), r_pipe_token.format()])
+    }
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdTableRow, MdTableRowFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableRow;
+impl FormatNodeRule<MdTableRow> for FormatMdTableRow {
+    fn fmt_fields(&self, node: &MdTableRow, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableRowFields {
+            l_pipe_token,
+            cells,
+        } = node.as_fields();
+
+        write!(f, [l_pipe_token.format(), cells.format()])
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use crate::utils::blocks::format_removed_node;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdTaskListCheckbox, MdTaskListCheckboxFields};
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTaskListCheckbox;
+impl FormatNodeRule<MdTaskListCheckbox> for FormatMdTaskListCheckbox {
+    fn fmt_fields(&self, node: &MdTaskListCheckbox, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTaskListCheckboxFields {
+            l_brack_token,
+            value,
+            r_brack_token,
+        } = node.as_fields();
+
+        let is_checked = value.is_some();
+        if let Some(value) = value {
+            write!(f, [format_removed_node(value.syntax())])?;
+        }
+
+        let checkbox = if is_checked { "[x]" } else { "[ ]" };
+        write!(
+            f,
+            [
+                format_replaced(&l_brack_token?, &token(checkbox)),
+                format_removed(&r_brack_token?)
+            ]
+        )
+    }
+}
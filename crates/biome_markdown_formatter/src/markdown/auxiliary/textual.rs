@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use crate::utils::inline::format_inline_token;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdTextual, MdTextualFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTextual;
+impl FormatNodeRule<MdTextual> for FormatMdTextual {
+    fn fmt_fields(&self, node: &MdTextual, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTextualFields { value_token } = node.as_fields();
+
+        write!(f, [format_inline_token(&value_token?)])
+    }
+}
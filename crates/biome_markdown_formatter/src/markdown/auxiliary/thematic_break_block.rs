@@ -0,0 +1,61 @@
+use crate::context::BulletListMarker;
+use crate::prelude::*;
+use crate::utils::blocks::{bullet_marker, needs_empty_line_between};
+use biome_formatter::write;
+use biome_markdown_syntax::{
+    AnyMdBlock, MarkdownSyntaxKind, MdBullet, MdThematicBreakBlock, MdThematicBreakBlockFields,
+};
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdThematicBreakBlock;
+impl FormatNodeRule<MdThematicBreakBlock> for FormatMdThematicBreakBlock {
+    fn fmt_fields(
+        &self,
+        node: &MdThematicBreakBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdThematicBreakBlockFields { value_token } = node.as_fields();
+
+        // `---` under a paragraph is the underline of a setext heading
+        let follows_paragraph = node
+            .syntax()
+            .prev_sibling()
+            .and_then(AnyMdBlock::cast)
+            .zip(AnyMdBlock::cast(node.syntax().clone()))
+            .is_some_and(|(previous, block)| {
+                previous.syntax().kind() == MarkdownSyntaxKind::MD_PARAGRAPH
+                    && !needs_empty_line_between(&previous, &block)
+            });
+
+        // The markers of the list items that start on the same line as the break
+        let mut markers = Vec::new();
+        let mut block = node.syntax().clone();
+        while block.prev_sibling().is_none()
+            && let Some(bullet) = block.grand_parent().and_then(MdBullet::cast)
+        {
+            markers.push(bullet_marker(&bullet, f));
+
+            // The marker of the parent item is on the same line only for the first item
+            let list_item = bullet.syntax().parent().and_then(|list| list.parent());
+            match list_item {
+                Some(list_item) if bullet.syntax().prev_sibling().is_none() => block = list_item,
+                _ => break,
+            }
+        }
+
+        // A break made of the same character as a list marker on its line would be parsed as a
+        // thematic break that contains the marker
+        let break_text = if follows_paragraph || markers.contains(&BulletListMarker::Dash) {
+            if markers.contains(&BulletListMarker::Asterisk) {
+                "___"
+            } else {
+                "***"
+            }
+        } else {
+            "---"
+        };
+
+        write!(f, [format_replaced(&value_token?, &token(break_text))])
+    }
+}
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use crate::utils::blocks::needs_empty_line_between;
+use biome_formatter::write;
+use biome_markdown_syntax::{AnyMdBlock, MdBlockList};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBlockList;
+impl FormatRule<MdBlockList> for FormatMdBlockList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdBlockList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let mut previous: Option<AnyMdBlock> = None;
+
+        for block in node.iter() {
+            // The markers of the lines of block quotes are printed by the quotes
+            if let AnyMdBlock::MdQuotePrefix(prefix) = &block {
+                write!(f, [prefix.format()])?;
+                continue;
+            }
+
+            if let Some(previous) = &previous {
+                if needs_empty_line_between(previous, &block) {
+                    write!(f, [empty_line()])?;
+                } else {
+                    write!(f, [hard_line_break()])?;
+                }
+            }
+
+            write!(f, [format_or_verbatim(block.format())])?;
+            previous = Some(block);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use crate::utils::blocks::is_loose_list;
+use biome_formatter::write;
+use biome_markdown_syntax::MdBulletList;
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBulletList;
+impl FormatRule<MdBulletList> for FormatMdBulletList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdBulletList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let is_loose = is_loose_list(node.iter().map(|item| item.syntax().clone()));
+
+        for (index, item) in node.iter().enumerate() {
+            if index > 0 {
+                if is_loose {
+                    write!(f, [empty_line()])?;
+                } else {
+                    write!(f, [hard_line_break()])?;
+                }
+            }
+            write!(f, [item.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use crate::utils::blocks::is_loose_list;
+use biome_formatter::write;
+use biome_markdown_syntax::MdOrderList;
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderList;
+impl FormatRule<MdOrderList> for FormatMdOrderList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdOrderList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let is_loose = is_loose_list(node.iter().map(|item| item.syntax().clone()));
+
+        for (index, item) in node.iter().enumerate() {
+            if index > 0 {
+                if is_loose {
+                    write!(f, [empty_line()])?;
+                } else {
+                    write!(f, [hard_line_break()])?;
+                }
+            }
+            write!(f, [item.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+use crate::context::BulletListMarker;
+use crate::prelude::*;
+use crate::utils::inline::is_quote_marker;
+use biome_formatter::{CstFormatContext, write};
+use biome_markdown_syntax::MarkdownSyntaxKind::*;
+use biome_markdown_syntax::{
+    AnyMdBlock, MarkdownSyntaxNode, MarkdownSyntaxToken, MdBlockList, MdBullet, MdBulletListItem,
+    MdOrderBullet, MdOrderList, MdTaskListCheckbox,
+};
+use biome_rowan::{AstNode, Direction};
+
+/// Returns `true` if there's a blank line between `node` and the previous block.
+pub(crate) fn has_blank_line_before(node: &MarkdownSyntaxNode) -> bool {
+    let Some(token) = node.first_token() else {
+        return false;
+    };
+
+    let count_newlines = |token: &MarkdownSyntaxToken| {
+        token
+            .leading_trivia()
+            .pieces()
+            .filter(|piece| piece.is_newline())
+            .count()
+    };
+
+    let mut newlines = count_newlines(&token);
+    let mut previous = token.prev_token();
+    // The markers of the block quotes are between the line breaks of the blank lines
+    while let Some(token) = previous.filter(is_quote_marker) {
+        newlines += count_newlines(&token);
+        previous = token.prev_token();
+    }
+
+    newlines > 1
+}
+
+/// Returns `true` if a blank line separates `next` from `previous`, the block printed before it.
+pub(crate) fn needs_empty_line_between(previous: &AnyMdBlock, next: &AnyMdBlock) -> bool {
+    let in_list_item = next
+        .syntax()
+        .grand_parent()
+        .is_some_and(|parent| matches!(parent.kind(), MD_BULLET | MD_ORDER_BULLET));
+
+    // The blank lines inside a list item make the list loose
+    if in_list_item {
+        return has_blank_line_before(next.syntax());
+    }
+
+    // Keep the consecutive link reference definitions together
+    let is_link_block = |block: &AnyMdBlock| block.syntax().kind() == MD_LINK_BLOCK;
+    if is_link_block(previous) && is_link_block(next) {
+        return has_blank_line_before(next.syntax());
+    }
+
+    true
+}
+
+/// Returns the previous sibling block of `node`, skipping the markers of block quotes.
+fn previous_block(node: &MarkdownSyntaxNode) -> Option<MarkdownSyntaxNode> {
+    node.siblings(Direction::Prev)
+        .skip(1)
+        .find(|sibling| sibling.kind() != MD_QUOTE_PREFIX)
+}
+
+/// Returns the marker of the items of `list`.
+///
+/// When bullet lists follow each other, their markers alternate, otherwise they
+/// would be parsed as a single list.
+pub(crate) fn bullet_list_marker(
+    list: &MdBulletListItem,
+    f: &MarkdownFormatter,
+) -> BulletListMarker {
+    let marker = f.options().bullet_list_marker();
+    let mut preceding_lists = 0;
+    let mut previous = previous_block(list.syntax());
+    while let Some(node) = previous.filter(|node| node.kind() == MD_BULLET_LIST_ITEM) {
+        preceding_lists += 1;
+        previous = previous_block(&node);
+    }
+
+    if preceding_lists % 2 == 0 {
+        marker
+    } else {
+        marker.alternate()
+    }
+}
+
+/// Returns the marker of `bullet`, the item of a bullet list.
+pub(crate) fn bullet_marker(bullet: &MdBullet, f: &MarkdownFormatter) -> BulletListMarker {
+    bullet
+        .syntax()
+        .grand_parent()
+        .and_then(MdBulletListItem::cast)
+        .map_or(f.options().bullet_list_marker(), |list| {
+            bullet_list_marker(&list, f)
+        })
+}
+
+/// Splits the marker of the item of an ordered list into its number and its delimiter.
+fn split_order_marker(marker: &str) -> (u64, &str) {
+    let digits = marker.bytes().take_while(u8::is_ascii_digit).count();
+    let number = marker[..digits].parse().unwrap_or_default();
+    (number, &marker[digits..])
+}
+
+/// Returns the marker of `bullet`, the item of an ordered list.
+///
+/// The items are numbered from the number of the first item. When the first two
+/// items have the same number, all the items use this number.
+pub(crate) fn order_marker(bullet: &MdOrderBullet) -> String {
+    let marker = bullet
+        .marker_token()
+        .map(|token| token.text_trimmed().to_string())
+        .unwrap_or_default();
+    let (number, delimiter) = split_order_marker(&marker);
+
+    let Some(list) = bullet.syntax().parent().and_then(MdOrderList::cast) else {
+        return std::format!("{number}{delimiter}");
+    };
+
+    let numbers: Vec<u64> = list
+        .iter()
+        .take(2)
+        .filter_map(|item| item.marker_token().ok())
+        .map(|token| split_order_marker(token.text_trimmed()).0)
+        .collect();
+    let start = numbers.first().copied().unwrap_or(number);
+    let increment = match numbers.as_slice() {
+        [first, second] if first == second => 0,
+        _ => 1,
+    };
+    let index = list
+        .iter()
+        .position(|item| &item == bullet)
+        .unwrap_or_default() as u64;
+
+    std::format!("{}{delimiter}", start + index * increment)
+}
+
+/// Returns the column of the content of `node` in the formatted document, which is the sum of
+/// the widths of the markers of its containers.
+pub(crate) fn formatted_column(node: &MarkdownSyntaxNode) -> usize {
+    node.ancestors()
+        .map(|ancestor| {
+            if let Some(bullet) = MdOrderBullet::cast_ref(&ancestor) {
+                order_marker(&bullet).len() + 1
+            } else if matches!(ancestor.kind(), MD_BULLET | MD_QUOTE) {
+                2
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// Formats all the tokens of `node` as removed: the node is printed from its source text.
+///
+/// The nodes aren't formatted by their rules, so they're marked as checked for suppressions.
+pub(crate) struct FormatRemovedNode<'a> {
+    node: &'a MarkdownSyntaxNode,
+}
+
+pub(crate) fn format_removed_node(node: &MarkdownSyntaxNode) -> FormatRemovedNode<'_> {
+    FormatRemovedNode { node }
+}
+
+impl Format<MarkdownFormatContext> for FormatRemovedNode<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let comments = f.context().comments();
+        for node in self.node.descendants() {
+            comments.mark_suppression_checked(&node);
+        }
+
+        for token in self.node.descendants_tokens(Direction::Next) {
+            format_removed(&token).fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if the list whose items are `items` is loose: its items, or the blocks of
+/// its items, are separated by blank lines.
+pub(crate) fn is_loose_list(items: impl Iterator<Item = MarkdownSyntaxNode>) -> bool {
+    items.enumerate().any(|(index, item)| {
+        if index > 0 && has_blank_line_before(&item) {
+            return true;
+        }
+
+        item.children()
+            .filter(|child| child.kind() == MD_BLOCK_LIST)
+            .flat_map(|content| content.children())
+            .filter(|block| block.kind() != MD_QUOTE_PREFIX)
+            .skip(1)
+            .any(|block| has_blank_line_before(&block))
+    })
+}
+
+/// Formats an item of a list: its marker, followed by its content aligned after the marker.
+pub(crate) struct FormatListItem<'a> {
+    marker_token: &'a MarkdownSyntaxToken,
+    marker: &'a str,
+    checkbox: Option<&'a MdTaskListCheckbox>,
+    content: &'a MdBlockList,
+}
+
+pub(crate) fn format_list_item<'a>(
+    marker_token: &'a MarkdownSyntaxToken,
+    marker: &'a str,
+    checkbox: Option<&'a MdTaskListCheckbox>,
+    content: &'a MdBlockList,
+) -> FormatListItem<'a> {
+    FormatListItem {
+        marker_token,
+        marker,
+        checkbox,
+        content,
+    }
+}
+
+impl Format<MarkdownFormatContext> for FormatListItem<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        write!(
+            f,
+            [format_replaced(
+                self.marker_token,
+                &text(self.marker, self.marker_token.text_trimmed_range().start())
+            )]
+        )?;
+
+        if self.checkbox.is_none() && self.content.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, [space()])?;
+
+        let width = u8::try_from(self.marker.len() + 1).unwrap_or(u8::MAX);
+        let checkbox = self.checkbox;
+        let content = self.content;
+        write!(
+            f,
+            [align(
+                width,
+                &format_with(|f| {
+                    if let Some(checkbox) = checkbox {
+                        write!(f, [checkbox.format()])?;
+                        if !content.is_empty() {
+                            write!(f, [space()])?;
+                        }
+                    }
+                    write!(f, [content.format()])
+                })
+            )]
+        )
+    }
+}
@@ -0,0 +1,376 @@
+use crate::context::ProseWrap;
+use crate::prelude::*;
+use biome_formatter::format_element::tag::Tag;
+use biome_formatter::{CstFormatContext, FormatElement, write};
+use biome_markdown_syntax::MarkdownSyntaxKind::*;
+use biome_markdown_syntax::{
+    MarkdownSyntaxKind, MarkdownSyntaxToken, MdInlineItemList, MdParagraph, MdTableCell,
+};
+use biome_rowan::AstNode;
+
+/// Returns `true` if `token` is the `>` marker that continues a block quote on a new line.
+pub(crate) fn is_quote_marker(token: &MarkdownSyntaxToken) -> bool {
+    token.kind() == R_ANGLE
+        && token
+            .parent()
+            .is_some_and(|parent| parent.kind() == MD_QUOTE_PREFIX)
+}
+
+/// Returns how the text of `paragraph` is wrapped.
+///
+/// The content of headings must stay on a single line, whatever the option.
+pub(crate) fn paragraph_wrap(paragraph: &MdParagraph, f: &MarkdownFormatter) -> ProseWrap {
+    let is_heading = paragraph
+        .syntax()
+        .parent()
+        .is_some_and(|parent| matches!(parent.kind(), MD_HEADER | MD_SETEXT_HEADER));
+
+    if is_heading {
+        ProseWrap::Never
+    } else {
+        f.options().prose_wrap()
+    }
+}
+
+/// Returns `true` if nodes of this kind can be part of the text of a paragraph or of a table cell.
+///
+/// The tokens of the other inline nodes, e.g. inline code or autolinks, are printed as they are.
+const fn is_wrappable_inline(kind: MarkdownSyntaxKind) -> bool {
+    matches!(
+        kind,
+        MD_INLINE_ITEM_LIST
+            | MD_TEXTUAL
+            | MD_HARD_LINE
+            | MD_INLINE_EMPHASIS
+            | MD_INLINE_ITALIC
+            | MD_INLINE_STRIKETHROUGH
+            | MD_INLINE_LINK
+            | MD_INLINE_IMAGE
+            | MD_REFERENCE_LINK
+            | MD_REFERENCE_IMAGE
+            | MD_QUOTE_PREFIX
+    )
+}
+
+/// Returns the inline content, the text of a paragraph or of a table cell, that contains `token`,
+/// and how this content is wrapped.
+///
+/// The first token of an inline node that isn't wrappable, e.g. the opening backtick of an inline
+/// code, is part of the content, but the other tokens of this node aren't.
+fn inline_root(
+    token: &MarkdownSyntaxToken,
+    f: &MarkdownFormatter,
+) -> Option<(MdInlineItemList, ProseWrap)> {
+    for ancestor in token.parent()?.ancestors() {
+        if let Some(paragraph) = MdParagraph::cast_ref(&ancestor) {
+            return Some((paragraph.list(), paragraph_wrap(&paragraph, f)));
+        }
+        if let Some(cell) = MdTableCell::cast_ref(&ancestor) {
+            return Some((cell.content(), ProseWrap::Never));
+        }
+        if !is_wrappable_inline(ancestor.kind()) && ancestor.first_token().as_ref() != Some(token) {
+            return None;
+        }
+    }
+
+    None
+}
+
+/// The whitespace between a token and the previous token of the same inline content.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Whitespace {
+    None,
+    Space,
+    LineBreak,
+}
+
+fn whitespace_before(token: &MarkdownSyntaxToken) -> Whitespace {
+    let mut has_space = token.leading_trivia().pieces().next().is_some();
+    let mut has_line_break = token.has_leading_newline();
+
+    let mut previous = token.prev_token();
+    while let Some(token) = previous {
+        if is_quote_marker(&token) {
+            has_line_break |= token.has_leading_newline();
+            previous = token.prev_token();
+            continue;
+        }
+
+        // The line break of a hard line is part of its token
+        if token.kind() == MD_HARD_LINE_LITERAL {
+            return Whitespace::None;
+        }
+
+        has_space |= token.trailing_trivia().pieces().next().is_some();
+        break;
+    }
+
+    if has_line_break {
+        Whitespace::LineBreak
+    } else if has_space {
+        Whitespace::Space
+    } else {
+        Whitespace::None
+    }
+}
+
+/// Returns `true` if a line that starts with `text` could be parsed as the start of a block,
+/// e.g. a list item or a heading, instead of the continuation of a paragraph.
+fn could_start_block(text: &str) -> bool {
+    if text.starts_with(['#', '>', '-', '+', '*', '=', '|', '`', '~', '<']) {
+        return true;
+    }
+
+    let digits = text.bytes().take_while(u8::is_ascii_digit).count();
+    digits > 0 && matches!(text.as_bytes().get(digits), Some(b'.' | b')'))
+}
+
+/// Formats the whitespace before `token` in the text of a paragraph or of a table cell.
+///
+/// The spaces and line breaks between the words are replaced by:
+/// - a line break or a space, depending on the source, when the prose wrap is `preserve`;
+/// - a space when the prose wrap is `never`;
+/// - a separator of the fill of the paragraph when the prose wrap is `always`, unless the
+///   token would start a block at the beginning of a line.
+pub(crate) struct FormatInlineSeparator<'a> {
+    token: &'a MarkdownSyntaxToken,
+}
+
+pub(crate) fn format_inline_separator(token: &MarkdownSyntaxToken) -> FormatInlineSeparator<'_> {
+    FormatInlineSeparator { token }
+}
+
+impl Format<MarkdownFormatContext> for FormatInlineSeparator<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let Some((root, wrap)) = inline_root(self.token, f) else {
+            return Ok(());
+        };
+
+        if root.syntax().first_token().as_ref() == Some(self.token) {
+            return Ok(());
+        }
+
+        match (whitespace_before(self.token), wrap) {
+            (Whitespace::None, _) => Ok(()),
+            (_, ProseWrap::Always) if !could_start_block(self.token.text_trimmed()) => {
+                f.write_elements([
+                    FormatElement::Tag(Tag::EndEntry),
+                    FormatElement::Tag(Tag::StartEntry),
+                ])?;
+                write!(f, [soft_line_break_or_space()])?;
+                f.write_elements([
+                    FormatElement::Tag(Tag::EndEntry),
+                    FormatElement::Tag(Tag::StartEntry),
+                ])
+            }
+            (Whitespace::LineBreak, ProseWrap::Preserve) => write!(f, [hard_line_break()]),
+            _ => write!(f, [space()]),
+        }
+    }
+}
+
+/// Formats a token of an inline content, preceded by its separator.
+pub(crate) struct FormatInlineToken<'a> {
+    token: &'a MarkdownSyntaxToken,
+}
+
+pub(crate) fn format_inline_token(token: &MarkdownSyntaxToken) -> FormatInlineToken<'_> {
+    FormatInlineToken { token }
+}
+
+impl Format<MarkdownFormatContext> for FormatInlineToken<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        write!(
+            f,
+            [format_inline_separator(self.token), self.token.format()]
+        )
+    }
+}
+
+/// Returns the source text from `first` to `last`, in which the line breaks, the
+/// indentation of the lines and the markers of block quotes are replaced by a single space.
+pub(crate) fn inline_source_text(
+    first: &MarkdownSyntaxToken,
+    last: &MarkdownSyntaxToken,
+) -> String {
+    let mut text = String::new();
+    let mut after_line_break = false;
+    let mut current = Some(first.clone());
+
+    while let Some(token) = current {
+        if is_quote_marker(&token) {
+            after_line_break = true;
+        } else {
+            if &token != first {
+                for piece in token.leading_trivia().pieces() {
+                    if piece.is_newline() {
+                        after_line_break = true;
+                    } else if !after_line_break {
+                        text.push_str(piece.text());
+                    }
+                }
+            }
+
+            if after_line_break {
+                text.push(' ');
+                after_line_break = false;
+            }
+
+            text.push_str(&token.text_trimmed().replace(['\r', '\n'], " "));
+
+            if &token != last {
+                text.push_str(token.trailing_trivia().text());
+            }
+        }
+
+        if &token == last {
+            break;
+        }
+        current = token.next_token();
+    }
+
+    text
+}
+
+/// Formats the tokens from `first` to `last` as they are in the source, on a single line.
+///
+/// Used for the inline nodes whose content must not be changed, e.g. inline code or link destinations.
+pub(crate) struct FormatInlineVerbatim<'a> {
+    first: &'a MarkdownSyntaxToken,
+    last: &'a MarkdownSyntaxToken,
+}
+
+pub(crate) fn format_inline_verbatim<'a>(
+    first: &'a MarkdownSyntaxToken,
+    last: &'a MarkdownSyntaxToken,
+) -> FormatInlineVerbatim<'a> {
+    FormatInlineVerbatim { first, last }
+}
+
+impl Format<MarkdownFormatContext> for FormatInlineVerbatim<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        write!(f, [format_inline_separator(self.first)])?;
+
+        let range = self
+            .first
+            .text_trimmed_range()
+            .cover(self.last.text_trimmed_range());
+        let mut current = Some(self.first.clone());
+        while let Some(token) = current {
+            // The nodes between the tokens aren't formatted by their rules
+            let mut parent = token.parent();
+            while let Some(node) =
+                parent.filter(|node| !node.text_trimmed_range().contains_range(range))
+            {
+                f.context().comments().mark_suppression_checked(&node);
+                parent = node.parent();
+            }
+
+            write!(f, [format_removed(&token)])?;
+            if &token == self.last {
+                break;
+            }
+            current = token.next_token();
+        }
+
+        let source = inline_source_text(self.first, self.last);
+        write!(f, [text(&source, self.first.text_trimmed_range().start())])
+    }
+}
+
+/// Formats an italic or a bold text, whose delimiters are `l_fence` and `r_fence`.
+///
+/// The delimiters are replaced by the character of the emphasis style, unless the replacement
+/// would change how the text is parsed: `_` doesn't delimit an emphasis inside a word, and
+/// the new delimiters could be merged with the same character around or inside the text.
+pub(crate) struct FormatEmphasis<'a> {
+    l_fence: &'a MarkdownSyntaxToken,
+    content: &'a MdInlineItemList,
+    r_fence: &'a MarkdownSyntaxToken,
+}
+
+pub(crate) fn format_emphasis<'a>(
+    l_fence: &'a MarkdownSyntaxToken,
+    content: &'a MdInlineItemList,
+    r_fence: &'a MarkdownSyntaxToken,
+) -> FormatEmphasis<'a> {
+    FormatEmphasis {
+        l_fence,
+        content,
+        r_fence,
+    }
+}
+
+impl FormatEmphasis<'_> {
+    fn can_use_style(&self, style: char) -> bool {
+        if self.l_fence.text_trimmed().starts_with(style) {
+            return false;
+        }
+
+        if self
+            .content
+            .syntax()
+            .text_with_trivia()
+            .contains_char(style)
+        {
+            return false;
+        }
+
+        let before = self
+            .l_fence
+            .prev_token()
+            .filter(|token| {
+                self.l_fence.leading_trivia().is_empty() && token.trailing_trivia().is_empty()
+            })
+            .and_then(|token| token.text_trimmed().chars().last());
+        let after = self
+            .r_fence
+            .next_token()
+            .filter(|token| {
+                self.r_fence.trailing_trivia().is_empty() && token.leading_trivia().is_empty()
+            })
+            .and_then(|token| token.text_trimmed().chars().next());
+
+        [before, after]
+            .into_iter()
+            .flatten()
+            .all(|neighbor| neighbor != style && !(style == '_' && neighbor.is_alphanumeric()))
+    }
+}
+
+impl Format<MarkdownFormatContext> for FormatEmphasis<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let style = f.options().emphasis_style().as_char();
+
+        if !self.can_use_style(style) {
+            return write!(
+                f,
+                [
+                    format_inline_token(self.l_fence),
+                    self.content.format(),
+                    format_inline_token(self.r_fence)
+                ]
+            );
+        }
+
+        let fence = style
+            .to_string()
+            .repeat(self.l_fence.text_trimmed().chars().count());
+        write!(
+            f,
+            [
+                format_inline_separator(self.l_fence),
+                format_replaced(
+                    self.l_fence,
+                    &text(&fence, self.l_fence.text_trimmed_range().start())
+                ),
+                self.content.format(),
+                format_inline_separator(self.r_fence),
+                format_replaced(
+                    self.r_fence,
+                    &text(&fence, self.r_fence.text_trimmed_range().start())
+                )
+            ]
+        )
+    }
+}
@@ -0,0 +1,298 @@
+use crate::prelude::*;
+use biome_formatter::{FormatElement, write};
+use biome_markdown_syntax::{
+    MarkdownSyntaxNode, MarkdownSyntaxToken, MdBullet, MdInlineItemList, MdOrderBullet, MdQuote,
+};
+use biome_rowan::{AstNode, TextSize};
+
+/// The width of a tab when computing the columns of the lines, as defined by CommonMark.
+const TAB_WIDTH: usize = 4;
+
+/// Returns the column that follows `character` when it's at `column`.
+fn next_column(column: usize, character: char) -> usize {
+    if character == '\t' {
+        column + TAB_WIDTH - column % TAB_WIDTH
+    } else {
+        column + 1
+    }
+}
+
+fn width_of(text: &str, start_column: usize) -> usize {
+    text.chars().fold(start_column, next_column) - start_column
+}
+
+/// Returns the source text from the start of the line of `token` to the start of its trimmed text.
+fn line_text_before(token: &MarkdownSyntaxToken) -> String {
+    let leading = token.leading_trivia();
+    let leading = leading.text();
+    if let Some(index) = leading.rfind('\n') {
+        return leading[index + 1..].to_string();
+    }
+
+    let mut text = leading.to_string();
+    let mut previous = token.prev_token();
+    while let Some(token) = previous {
+        let token_text = token.text();
+        if let Some(index) = token_text.rfind('\n') {
+            text.insert_str(0, &token_text[index + 1..]);
+            break;
+        }
+        text.insert_str(0, token_text);
+        previous = token.prev_token();
+    }
+
+    text
+}
+
+/// Returns the column of the start of the trimmed text of `token` in the source.
+fn token_column(token: &MarkdownSyntaxToken) -> usize {
+    width_of(&line_text_before(token), 0)
+}
+
+/// A container block that repeats its marker or its indentation on each line of its content.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Container {
+    /// A block quote, whose lines start with `>`.
+    Quote,
+    /// A list item, whose lines are indented up to the column of its content.
+    ListItem { content_column: usize },
+}
+
+/// Returns the column of the content of the list item whose marker is `marker`.
+fn list_item_content_column(marker: &MarkdownSyntaxToken) -> usize {
+    let marker_end = token_column(marker) + marker.text_trimmed().chars().count();
+    let spacing = width_of(marker.trailing_trivia().text(), marker_end);
+
+    // When the content starts with an indented code block, or on the next line, the
+    // content is indented by one space after the marker
+    if spacing == 0 || spacing > TAB_WIDTH {
+        marker_end + 1
+    } else {
+        marker_end + spacing
+    }
+}
+
+/// Returns the containers of `node`, from the outermost to the innermost.
+pub(crate) fn containers(node: &MarkdownSyntaxNode) -> Vec<Container> {
+    let mut containers: Vec<_> = node
+        .ancestors()
+        .skip(1)
+        .filter_map(|ancestor| {
+            if MdQuote::can_cast(ancestor.kind()) {
+                return Some(Container::Quote);
+            }
+
+            let marker = if let Some(bullet) = MdBullet::cast_ref(&ancestor) {
+                bullet.bullet().ok()?
+            } else {
+                MdOrderBullet::cast_ref(&ancestor)?.marker_token().ok()?
+            };
+
+            Some(Container::ListItem {
+                content_column: list_item_content_column(&marker),
+            })
+        })
+        .collect();
+
+    containers.reverse();
+    containers
+}
+
+/// Skips the spaces and tabs at the start of `text` until `column` reaches `until`.
+///
+/// Returns the rest of the text and its column.
+fn skip_whitespace(text: &str, mut column: usize, until: usize) -> (&str, usize) {
+    let mut offset = 0;
+    for character in text.chars() {
+        if column >= until || !matches!(character, ' ' | '\t') {
+            break;
+        }
+        let next = next_column(column, character);
+        if next > until {
+            break;
+        }
+        column = next;
+        offset += 1;
+    }
+
+    (&text[offset..], column)
+}
+
+/// Removes the markers and the indentation of `containers` at the start of `line`.
+///
+/// Returns the rest of the line and its column.
+pub(crate) fn strip_containers<'a>(line: &'a str, containers: &[Container]) -> (&'a str, usize) {
+    let mut rest = line;
+    let mut column = 0;
+
+    for container in containers {
+        match container {
+            Container::Quote => {
+                let (after_indent, indent_column) = skip_whitespace(rest, column, column + 3);
+                let Some(after_marker) = after_indent.strip_prefix('>') else {
+                    // A line that doesn't continue the quote, e.g. a blank line
+                    break;
+                };
+                rest = after_marker;
+                column = indent_column + 1;
+                if let Some(after_space) = rest.strip_prefix(' ') {
+                    rest = after_space;
+                    column += 1;
+                }
+            }
+            Container::ListItem { content_column } => {
+                (rest, column) = skip_whitespace(rest, column, *content_column);
+            }
+        }
+    }
+
+    (rest, column)
+}
+
+/// Returns the indentation of `token` from the column of the content of its innermost container.
+pub(crate) fn indent_in_container(token: &MarkdownSyntaxToken, containers: &[Container]) -> usize {
+    let line = line_text_before(token);
+    let column = width_of(&line, 0);
+
+    let content_column = match containers.last() {
+        Some(Container::ListItem { content_column }) => *content_column,
+        Some(Container::Quote) => match line.rfind('>') {
+            Some(index) => {
+                let after_marker = width_of(&line[..=index], 0);
+                if line[index + 1..].starts_with(' ') {
+                    after_marker + 1
+                } else {
+                    after_marker
+                }
+            }
+            None => 0,
+        },
+        None => 0,
+    };
+
+    column.saturating_sub(content_column)
+}
+
+/// Where the first line of a code or HTML block starts in the text of its content.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum FirstLine {
+    /// After the first line break: the content starts with the line break of the opening fence.
+    AfterFirstLineBreak,
+    /// After the last line break of the leading trivia: the previous line breaks are the blank
+    /// lines that separate the block from the previous one.
+    AfterLastLineBreak,
+}
+
+/// Returns the lines of `content`, the content of a code or HTML block, without the markers and
+/// the indentation of the containers of the block, and without their first `indent` columns.
+///
+/// The blank lines at the end of the block are removed.
+pub(crate) fn block_lines(
+    content: &MdInlineItemList,
+    first_line: FirstLine,
+    indent: usize,
+) -> Vec<String> {
+    let Some(first_token) = content.syntax().first_token() else {
+        return Vec::new();
+    };
+    let containers = containers(content.syntax());
+    let text = content.syntax().text_with_trivia().to_string();
+
+    let (start, first_line_column) = match first_line {
+        FirstLine::AfterFirstLineBreak => match text.find('\n') {
+            Some(index) => (index + 1, None),
+            None => return Vec::new(),
+        },
+        FirstLine::AfterLastLineBreak => match first_token.leading_trivia().text().rfind('\n') {
+            Some(index) => (index + 1, None),
+            // The block starts on the line of the marker of its container
+            None => {
+                let before = line_text_before(&first_token);
+                let leading = first_token.leading_trivia();
+                let before = &before[..before.len() - leading.text().len()];
+                (0, Some(width_of(before, 0)))
+            }
+        },
+    };
+
+    let mut lines: Vec<String> = text[start..]
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let (rest, _) = match first_line_column {
+                Some(column) if index == 0 => {
+                    let base = match containers.last() {
+                        Some(Container::ListItem { content_column }) => *content_column,
+                        _ => column,
+                    };
+                    skip_whitespace(line, column, base + indent)
+                }
+                _ => {
+                    let (rest, column) = strip_containers(line, &containers);
+                    skip_whitespace(rest, column, column + indent)
+                }
+            };
+            rest.to_string()
+        })
+        .collect();
+
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    lines
+}
+
+/// Formats the lines of a code or HTML block, each on its own line.
+///
+/// The blank lines are printed without the indentation of the containers of the block.
+pub(crate) struct FormatBlockLines<'a> {
+    lines: &'a [String],
+    position: TextSize,
+    /// Whether the first line is preceded by a line break.
+    leading_line_break: bool,
+}
+
+pub(crate) fn format_block_lines(
+    lines: &[String],
+    position: TextSize,
+    leading_line_break: bool,
+) -> FormatBlockLines<'_> {
+    FormatBlockLines {
+        lines,
+        position,
+        leading_line_break,
+    }
+}
+
+impl Format<MarkdownFormatContext> for FormatBlockLines<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let mut blank_lines = 0;
+        let mut is_first = !self.leading_line_break;
+
+        for line in self.lines {
+            if line.trim().is_empty() {
+                blank_lines += 1;
+                continue;
+            }
+
+            if blank_lines > 0 && !is_first {
+                // A line break in a text isn't followed by the indentation, so the blank lines
+                // don't have trailing whitespace
+                f.write_element(FormatElement::Text {
+                    text: "\n".repeat(blank_lines + 1).into_boxed_str(),
+                    source_position: self.position,
+                })?;
+            } else if !is_first {
+                write!(f, [hard_line_break()])?;
+            }
+
+            write!(f, [text(line, self.position)])?;
+            blank_lines = 0;
+            is_first = false;
+        }
+
+        Ok(())
+    }
+}
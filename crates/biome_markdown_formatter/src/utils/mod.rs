@@ -0,0 +1,4 @@
+pub(crate) mod blocks;
+pub(crate) mod inline;
+pub(crate) mod lines;
+pub(crate) mod print;
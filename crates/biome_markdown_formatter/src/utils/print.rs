@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_formatter::format_element::document::Document;
+use biome_formatter::printer::{PrintWidth, Printer};
+use biome_formatter::{FormatOptions, VecBuffer, write};
+
+/// Prints `content` to a string that fits in `width` columns.
+///
+/// Used by the blocks that must transform or measure the lines of their content:
+/// block quotes prefix each line with `>` and tables pad their cells.
+pub(crate) fn print_to_string(
+    content: &dyn Format<MarkdownFormatContext>,
+    width: u32,
+    f: &mut MarkdownFormatter,
+) -> FormatResult<String> {
+    let print_options = f
+        .options()
+        .as_print_options()
+        .with_print_width(PrintWidth::new(width.max(1)));
+
+    let mut buffer = VecBuffer::new(f.state_mut());
+    write!(buffer, [content])?;
+    let document = Document::from(buffer.into_vec());
+
+    Ok(Printer::new(print_options).print(&document)?.into_code())
+}
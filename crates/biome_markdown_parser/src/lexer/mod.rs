@@ -1,16 +1,15 @@
-//! An extremely fast, lookup table based, JSON lexer which yields SyntaxKind tokens used by the rome-json parser.
+//! An extremely fast, lossless Markdown lexer which yields SyntaxKind tokens used by the Biome Markdown parser.
 
 #[rustfmt::skip]
 mod tests;
 
-use biome_markdown_syntax::MarkdownSyntaxKind;
 use biome_markdown_syntax::MarkdownSyntaxKind::*;
+use biome_markdown_syntax::{MarkdownSyntaxKind, T};
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_parser::lexer::{
     LexContext, Lexer, LexerCheckpoint, LexerWithCheckpoint, ReLexer, TokenFlags,
 };
 use biome_rowan::{SyntaxKind, TextSize};
-use biome_unicode_table::{Dispatch::*, lookup_byte};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
 pub enum MarkdownLexContext {
@@ -28,9 +27,10 @@ impl LexContext for MarkdownLexContext {
 /// Context in which the [MarkdownLexContext]'s current should be re-lexed.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum MarkdownReLexContext {
-    #[expect(dead_code)]
-    Regular,
-    // UnicodeRange,
+    /// Lexes the rest of the line as a single [MD_TEXTUAL_LITERAL], e.g. the content of a code block
+    Line,
+    /// Lexes a single `*` or `_`, e.g. to split `**` when it opens both an italic and a bold text
+    SingleDelimiter,
 }
 
 /// An extremely fast, lookup table based, lossless Markdown lexer
@@ -43,6 +43,8 @@ pub(crate) struct MarkdownLexer<'src> {
     position: usize,
 
     /// `true` if there has been a line break between the last non-trivia token and the next non-trivia token.
+    ///
+    /// It's also `true` at the start of the source.
     after_newline: bool,
 
     /// If the source starts with a Unicode BOM, this is the number of bytes for that token.
@@ -103,7 +105,10 @@ impl<'src> Lexer<'src> for MarkdownLexer<'src> {
             .set(TokenFlags::PRECEDING_LINE_BREAK, self.after_newline);
         self.current_kind = kind;
 
-        if !kind.is_trivia() {
+        if kind == MD_HARD_LINE_LITERAL {
+            // A hard line break ends with the line break
+            self.after_newline = true;
+        } else if !kind.is_trivia() {
             self.after_newline = false;
         }
 
@@ -166,7 +171,7 @@ impl<'src> MarkdownLexer<'src> {
     pub fn from_str(source: &'src str) -> Self {
         Self {
             source,
-            after_newline: false,
+            after_newline: true,
             unicode_bom_length: 0,
             current_kind: TOMBSTONE,
             current_start: TextSize::from(0),
@@ -177,10 +182,46 @@ impl<'src> MarkdownLexer<'src> {
     }
 
     pub(crate) fn consume_token(&mut self, current: u8) -> MarkdownSyntaxKind {
-        let dispatched = lookup_byte(current);
-        match dispatched {
-            WHS => self.consume_newline_or_whitespace(),
-            MUL | MIN | IDT => self.consume_thematic_break_literal(),
+        match current {
+            b'\n' | b'\r' => self.consume_newline(),
+            b' ' => self.consume_whitespace(),
+            b'\t' => self.consume_tab(),
+            b'\\' => self.consume_backslash(),
+            b'*' | b'_' => {
+                if self.is_at_thematic_break() {
+                    self.consume_thematic_break_literal()
+                } else {
+                    self.consume_delimiter(current)
+                }
+            }
+            b'-' => {
+                if self.is_at_thematic_break() {
+                    self.consume_thematic_break_literal()
+                } else if self.is_at_setext_underline(b'-') {
+                    self.consume_setext_underline(b'-')
+                } else if self.is_whitespace_or_eol_at(1) {
+                    self.consume_byte(T![-])
+                } else {
+                    self.consume_textual()
+                }
+            }
+            b'=' if self.is_at_setext_underline(b'=') => self.consume_setext_underline(b'='),
+            b'+' if self.is_whitespace_or_eol_at(1) => self.consume_byte(T![+]),
+            b'#' => self.consume_byte(T![#]),
+            b'>' => self.consume_byte(T![>]),
+            b'<' => self.consume_byte(T![<]),
+            b'[' => self.consume_byte(T!['[']),
+            b']' => self.consume_byte(T![']']),
+            b'(' => self.consume_byte(T!['(']),
+            b')' => self.consume_byte(T![')']),
+            b'|' => self.consume_byte(T![|]),
+            b'!' if self.peek_byte() == Some(b'[') => self.consume_byte(T![!]),
+            b':' if self.position > 0 && self.byte_at_offset(-1) == Some(b']') => {
+                self.consume_byte(T![:])
+            }
+            b'`' => self.consume_run(b'`', MD_BACKTICKS_LITERAL),
+            b'~' => self.consume_run(b'~', MD_TILDES_LITERAL),
+            b'0'..=b'9' => self.consume_ordered_list_marker_or_textual(),
             _ => self.consume_textual(),
         }
     }
@@ -189,12 +230,6 @@ impl<'src> MarkdownLexer<'src> {
         TextSize::try_from(self.position).expect("Input to be smaller than 4 GB")
     }
 
-    /// Bumps the current byte and creates a lexed token of the passed in kind
-    #[expect(dead_code)]
-    fn eat_byte(&mut self, tok: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
-        self.advance(1);
-        tok
-    }
     /// Returns the byte at position `self.position + offset` or `None` if it is out of bounds.
     #[inline]
     fn byte_at(&self, offset: usize) -> Option<u8> {
@@ -204,23 +239,33 @@ impl<'src> MarkdownLexer<'src> {
             .copied()
     }
 
+    /// Returns the byte at position `self.position + offset` where the offset can be negative.
+    #[inline]
+    fn byte_at_offset(&self, offset: isize) -> Option<u8> {
+        let position = self.position.checked_add_signed(offset)?;
+        self.source.as_bytes().get(position).copied()
+    }
+
     /// Peeks at the next byte
     #[inline]
     fn peek_byte(&self) -> Option<u8> {
         self.byte_at(1)
     }
 
-    /// Consume one newline or all whitespace until a non-whitespace or a newline is found.
-    ///
-    /// ## Safety
-    /// Must be called at a valid UT8 char boundary
-    fn consume_newline_or_whitespace(&mut self) -> MarkdownSyntaxKind {
-        match self.current_byte() {
-            Some(b'\n' | b'\r') => self.consume_newline(),
-            Some(b' ') => self.consume_whitespace(),
-            Some(b'\t') => self.consume_tab(),
-            _ => self.consume_textual(),
-        }
+    /// Returns `true` if the byte at `self.position + offset` is a space, a tab, a line break, or the end of the file.
+    fn is_whitespace_or_eol_at(&self, offset: usize) -> bool {
+        matches!(
+            self.byte_at(offset),
+            None | Some(b' ' | b'\t' | b'\n' | b'\r')
+        )
+    }
+
+    /// Returns the number of bytes from the current position until the next line break or the end of the file.
+    fn rest_of_line_len(&self) -> usize {
+        self.source.as_bytes()[self.position..]
+            .iter()
+            .position(|byte| matches!(byte, b'\n' | b'\r'))
+            .unwrap_or(self.source.len() - self.position)
     }
 
     /// Consume just one newline/line break.
@@ -249,17 +294,119 @@ impl<'src> MarkdownLexer<'src> {
 
     /// Consumes all whitespace until a non-whitespace or a newline is found.
     ///
+    /// Two or more spaces followed by a line break are a [hard line break](https://spec.commonmark.org/0.31.2/#hard-line-breaks),
+    /// unless they are the only characters of the line.
+    ///
     /// ## Safety
     /// Must be called at a valid UT8 char boundary
     fn consume_whitespace(&mut self) -> MarkdownSyntaxKind {
         self.assert_at_char_boundary();
+        let start = self.position;
         while let Some(b' ') = self.current_byte() {
             self.advance(1);
         }
 
+        if self.position - start >= 2
+            && matches!(self.current_byte(), Some(b'\n' | b'\r'))
+            && !self.is_after_block_marker(start)
+            && self.can_break_line(start, self.position)
+        {
+            self.consume_newline();
+            return MD_HARD_LINE_LITERAL;
+        }
+
         WHITESPACE
     }
 
+    /// Returns `true` if the token that ends at `end` only starts a block, e.g. the hashes of a heading or the fence of a code block.
+    /// Trailing spaces after these tokens aren't hard line breaks.
+    fn is_after_block_marker(&self, end: usize) -> bool {
+        match self.current_kind {
+            T![#] | T![|] => true,
+            MD_BACKTICKS_LITERAL | MD_TILDES_LITERAL => {
+                let bytes = self.source.as_bytes();
+                let fence_char = bytes[end - 1];
+                let start = bytes[..end]
+                    .iter()
+                    .rposition(|byte| *byte != fence_char)
+                    .map_or(0, |index| index + 1);
+                self.is_block_start(start)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if a hard line break starting at `start` and ending with the line break at `line_break` is valid.
+    ///
+    /// Hard line breaks can't end a block, so they aren't allowed on a line of its own, in an ATX heading,
+    /// or before a blank line.
+    fn can_break_line(&self, start: usize, line_break: usize) -> bool {
+        let bytes = self.source.as_bytes();
+        let line_start = self.line_start(start);
+        let is_heading = bytes[line_start..start]
+            .iter()
+            .position(|byte| *byte == b'#')
+            .is_some_and(|index| {
+                let hashes_start = line_start + index;
+                let hashes = bytes[hashes_start..]
+                    .iter()
+                    .take_while(|byte| **byte == b'#')
+                    .count();
+                hashes <= 6
+                    && matches!(bytes.get(hashes_start + hashes), Some(b' ' | b'\t'))
+                    && self.is_block_start(hashes_start)
+            });
+
+        let next_line_start = if bytes[line_break..].starts_with(b"\r\n") {
+            line_break + 2
+        } else {
+            line_break + 1
+        };
+        let is_before_blank_line = bytes[next_line_start.min(bytes.len())..]
+            .iter()
+            .take_while(|byte| !matches!(byte, b'\n' | b'\r'))
+            .all(|byte| matches!(byte, b' ' | b'\t'));
+
+        !self.after_newline && !is_heading && !is_before_blank_line
+    }
+
+    /// Returns the offset of the start of the line that contains `position`
+    fn line_start(&self, position: usize) -> usize {
+        self.source.as_bytes()[..position]
+            .iter()
+            .rposition(|byte| matches!(byte, b'\n' | b'\r'))
+            .map_or(0, |index| index + 1)
+    }
+
+    /// Returns `true` if only indentation and the markers of block quotes and list items precede `position` on its line.
+    fn is_block_start(&self, position: usize) -> bool {
+        let bytes = self.source.as_bytes();
+        let prefix = &bytes[self.line_start(position)..position];
+        let is_whitespace = |index: usize| matches!(prefix.get(index), Some(b' ' | b'\t'));
+
+        let mut index = 0;
+        while index < prefix.len() {
+            match prefix[index] {
+                b' ' | b'\t' | b'>' => index += 1,
+                b'-' | b'+' | b'*' if is_whitespace(index + 1) => index += 2,
+                b'0'..=b'9' => {
+                    let digits = prefix[index..]
+                        .iter()
+                        .take_while(|byte| byte.is_ascii_digit())
+                        .count();
+                    index += digits;
+                    if !matches!(prefix.get(index), Some(b'.' | b')')) || !is_whitespace(index + 1)
+                    {
+                        return false;
+                    }
+                    index += 2;
+                }
+                _ => return false,
+            }
+        }
+        true
+    }
+
     fn consume_tab(&mut self) -> MarkdownSyntaxKind {
         self.assert_at_char_boundary();
 
@@ -269,31 +416,117 @@ impl<'src> MarkdownLexer<'src> {
         TAB
     }
 
-    fn consume_thematic_break_literal(&mut self) -> MarkdownSyntaxKind {
+    /// Consumes a [backslash escape](https://spec.commonmark.org/0.31.2/#backslash-escapes)
+    /// or a hard line break made of a backslash followed by a line break.
+    ///
+    /// Escaped backticks aren't consumed as part of the escape: backslash escapes don't work in code spans.
+    fn consume_backslash(&mut self) -> MarkdownSyntaxKind {
         self.assert_at_char_boundary();
+        match self.peek_byte() {
+            Some(b'\n' | b'\r') if self.can_break_line(self.position, self.position + 1) => {
+                self.advance(1);
+                self.consume_newline();
+                MD_HARD_LINE_LITERAL
+            }
+            Some(byte) if byte.is_ascii_punctuation() && byte != b'`' => {
+                self.advance(2);
+                MD_TEXTUAL_LITERAL
+            }
+            _ => self.consume_byte(MD_TEXTUAL_LITERAL),
+        }
+    }
 
-        let start_char = match self.current_byte() {
-            Some(b'-') => b'-',
-            Some(b'*') => b'*',
-            Some(b'_') => b'_',
-            _ => return self.consume_textual(),
+    /// Returns `true` if the rest of the line is a [thematic break](https://spec.commonmark.org/0.31.2/#thematic-breaks):
+    /// three or more `-`, `*` or `_` characters, optionally separated by spaces or tabs.
+    fn is_at_thematic_break(&self) -> bool {
+        let Some(start_char) = self.current_byte() else {
+            return false;
         };
-
+        if !self.is_block_start(self.position) {
+            return false;
+        }
         let mut count = 0;
-        loop {
-            self.consume_whitespace();
-            if matches!(self.current_byte(), Some(ch) if ch == start_char) {
-                self.advance(1);
-                count += 1;
-            } else {
-                break;
+        for byte in &self.source.as_bytes()[self.position..self.position + self.rest_of_line_len()]
+        {
+            match byte {
+                b' ' | b'\t' => {}
+                byte if *byte == start_char => count += 1,
+                _ => return false,
+            }
+        }
+        count >= 3
+    }
+
+    fn consume_thematic_break_literal(&mut self) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+        self.advance(self.rest_of_line_len());
+        MD_THEMATIC_BREAK_LITERAL
+    }
+
+    /// Returns `true` if the rest of the line is a [setext heading underline](https://spec.commonmark.org/0.31.2/#setext-heading-underline):
+    /// a sequence of `=` or of at least two `-`, optionally followed by spaces or tabs.
+    fn is_at_setext_underline(&self, char: u8) -> bool {
+        let line = &self.source.as_bytes()[self.position..self.position + self.rest_of_line_len()];
+        let count = line.iter().take_while(|byte| **byte == char).count();
+        let min_count = if char == b'-' { 2 } else { 1 };
+        count >= min_count
+            && self.is_block_start(self.position)
+            && line[count..]
+                .iter()
+                .all(|byte| matches!(byte, b' ' | b'\t'))
+    }
+
+    fn consume_setext_underline(&mut self, char: u8) -> MarkdownSyntaxKind {
+        while self.current_byte() == Some(char) {
+            self.advance(1);
+        }
+        // The trailing whitespace is part of the underline, so that it's never a hard line break
+        while let Some(b' ' | b'\t') = self.current_byte() {
+            self.advance(1);
+        }
+        MD_SETEXT_UNDERLINE_LITERAL
+    }
+
+    /// Consumes `*`, `**`, `_` or `__`
+    fn consume_delimiter(&mut self, char: u8) -> MarkdownSyntaxKind {
+        let double = self.peek_byte() == Some(char);
+        match (char, double) {
+            (b'*', false) => self.consume_byte(T![*]),
+            (b'*', true) => {
+                self.advance(2);
+                T![**]
+            }
+            (_, false) => self.consume_byte(T!["_"]),
+            (_, true) => {
+                self.advance(2);
+                T!["__"]
             }
         }
-        // until next newline or eof
-        if matches!(self.current_byte(), Some(b'\n' | b'\r') | None) && count >= 3 {
-            return MD_THEMATIC_BREAK_LITERAL;
+    }
+
+    /// Consumes a run of the given character, e.g. the backticks of a code span or of a code fence.
+    fn consume_run(&mut self, char: u8, kind: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
+        while self.current_byte() == Some(char) {
+            self.advance(1);
         }
-        ERROR_TOKEN
+        kind
+    }
+
+    /// Consumes an [ordered list marker](https://spec.commonmark.org/0.31.2/#ordered-list-marker)
+    /// such as `1.` or `2)` when it's followed by a whitespace, otherwise consumes a textual token.
+    fn consume_ordered_list_marker_or_textual(&mut self) -> MarkdownSyntaxKind {
+        let digits = self.source.as_bytes()[self.position..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        if digits <= 9
+            && matches!(self.byte_at(digits), Some(b'.' | b')'))
+            && self.is_whitespace_or_eol_at(digits + 1)
+        {
+            self.advance(digits + 1);
+            return MD_ORDERED_LIST_MARKER_LITERAL;
+        }
+        self.consume_textual()
     }
 
     /// Get the UTF8 char which starts at the current byte
@@ -350,18 +583,40 @@ impl<'src> MarkdownLexer<'src> {
         self.position >= self.source.len()
     }
 
-    #[inline]
+    /// Consumes a run of text until a whitespace or a character that may start a Markdown construct.
     fn consume_textual(&mut self) -> MarkdownSyntaxKind {
         self.assert_at_char_boundary();
 
         let char = self.current_char_unchecked();
         self.advance(char.len_utf8());
 
+        while let Some(byte) = self.current_byte() {
+            match byte {
+                b' ' | b'\t' | b'\n' | b'\r' | b'\\' | b'*' | b'_' | b'`' | b'~' | b'[' | b']'
+                | b'(' | b')' | b'<' | b'>' | b'|' => break,
+                b'!' if self.peek_byte() == Some(b'[') => break,
+                _ => {
+                    let char = self.current_char_unchecked();
+                    self.advance(char.len_utf8());
+                }
+            }
+        }
+
+        MD_TEXTUAL_LITERAL
+    }
+
+    /// Consumes everything until the end of the line
+    fn consume_line(&mut self) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+        let len = self.rest_of_line_len();
+        if len == 0 {
+            return self.consume_token(self.current_byte().unwrap_or_default());
+        }
+        self.advance(len);
         MD_TEXTUAL_LITERAL
     }
 
     /// Bumps the current byte and creates a lexed token of the passed in kind
-    #[expect(dead_code)]
     fn consume_byte(&mut self, tok: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
         self.advance(1);
         tok
@@ -370,23 +625,21 @@ impl<'src> MarkdownLexer<'src> {
 
 impl<'src> ReLexer<'src> for MarkdownLexer<'src> {
     fn re_lex(&mut self, context: Self::ReLexContext) -> Self::Kind {
-        let old_position = self.position;
         self.position = u32::from(self.current_start) as usize;
 
         let re_lexed_kind = match self.current_byte() {
             Some(current) => match context {
-                MarkdownReLexContext::Regular => self.consume_token(current),
-                // MarkdownReLexContext::UnicodeRange => self.consume_unicode_range_token(current),
+                MarkdownReLexContext::Line => self.consume_line(),
+                MarkdownReLexContext::SingleDelimiter => match current {
+                    b'*' => self.consume_byte(T![*]),
+                    b'_' => self.consume_byte(T!["_"]),
+                    _ => self.consume_token(current),
+                },
             },
             None => EOF,
         };
 
-        if self.current() == re_lexed_kind {
-            // Didn't re-lex anything. Return existing token again
-            self.position = old_position;
-        } else {
-            self.current_kind = re_lexed_kind;
-        }
+        self.current_kind = re_lexed_kind;
 
         re_lexed_kind
     }
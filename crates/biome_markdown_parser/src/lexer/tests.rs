@@ -109,8 +109,8 @@ fn empty() {
 #[test]
 fn textual() {
     assert_lex! {
-        "+",
-       MD_TEXTUAL_LITERAL:1,
+        "+a",
+       MD_TEXTUAL_LITERAL:2,
     }
 }
 
@@ -162,3 +162,165 @@ _ _ _ _  _ "#,
         MD_THEMATIC_BREAK_LITERAL:11,
     }
 }
+
+#[test]
+fn thematic_break_after_text() {
+    assert_lex! {
+        "a ***",
+        MD_TEXTUAL_LITERAL:1,
+        WHITESPACE:1,
+        DOUBLE_STAR:2,
+        STAR:1,
+    }
+}
+
+#[test]
+fn thematic_break_in_container() {
+    assert_lex! {
+        "> - ***",
+        R_ANGLE:1,
+        WHITESPACE:1,
+        MINUS:1,
+        WHITESPACE:1,
+        MD_THEMATIC_BREAK_LITERAL:3,
+    }
+}
+
+#[test]
+fn setext_underline() {
+    assert_lex! {
+        "a\n===  \n--",
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        MD_SETEXT_UNDERLINE_LITERAL:5,
+        NEWLINE:1,
+        MD_SETEXT_UNDERLINE_LITERAL:2,
+    }
+}
+
+#[test]
+fn hard_line() {
+    assert_lex! {
+        "a  \nb\\\nc",
+        MD_TEXTUAL_LITERAL:1,
+        MD_HARD_LINE_LITERAL:3,
+        MD_TEXTUAL_LITERAL:1,
+        MD_HARD_LINE_LITERAL:2,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn no_hard_line_in_heading() {
+    assert_lex! {
+        "# a  \nb",
+        HASH:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        WHITESPACE:2,
+        NEWLINE:1,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn no_hard_line_before_blank_line() {
+    assert_lex! {
+        "a\\\n\nb",
+        MD_TEXTUAL_LITERAL:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        NEWLINE:1,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn no_hard_line_after_fence() {
+    assert_lex! {
+        "```  \n",
+        MD_BACKTICKS_LITERAL:3,
+        WHITESPACE:2,
+        NEWLINE:1,
+    }
+}
+
+#[test]
+fn list_markers() {
+    assert_lex! {
+        "- a\n+ b\n10. c\n2) d",
+        MINUS:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        PLUS:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        MD_ORDERED_LIST_MARKER_LITERAL:3,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        MD_ORDERED_LIST_MARKER_LITERAL:2,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn delimiters() {
+    assert_lex! {
+        "**a** _b_ __c__ ~~d~~ `e`",
+        DOUBLE_STAR:2,
+        MD_TEXTUAL_LITERAL:1,
+        DOUBLE_STAR:2,
+        WHITESPACE:1,
+        UNDERSCORE:1,
+        MD_TEXTUAL_LITERAL:1,
+        UNDERSCORE:1,
+        WHITESPACE:1,
+        DOUBLE_UNDERSCORE:2,
+        MD_TEXTUAL_LITERAL:1,
+        DOUBLE_UNDERSCORE:2,
+        WHITESPACE:1,
+        MD_TILDES_LITERAL:2,
+        MD_TEXTUAL_LITERAL:1,
+        MD_TILDES_LITERAL:2,
+        WHITESPACE:1,
+        MD_BACKTICKS_LITERAL:1,
+        MD_TEXTUAL_LITERAL:1,
+        MD_BACKTICKS_LITERAL:1,
+    }
+}
+
+#[test]
+fn link() {
+    assert_lex! {
+        "![a](b) [c]: d",
+        BANG:1,
+        L_BRACK:1,
+        MD_TEXTUAL_LITERAL:1,
+        R_BRACK:1,
+        L_PAREN:1,
+        MD_TEXTUAL_LITERAL:1,
+        R_PAREN:1,
+        WHITESPACE:1,
+        L_BRACK:1,
+        MD_TEXTUAL_LITERAL:1,
+        R_BRACK:1,
+        COLON:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn escape() {
+    assert_lex! {
+        "\\*a\\`",
+        MD_TEXTUAL_LITERAL:2,
+        MD_TEXTUAL_LITERAL:1,
+        MD_TEXTUAL_LITERAL:1,
+        MD_BACKTICKS_LITERAL:1,
+    }
+}
@@ -4,12 +4,62 @@ use biome_parser::event::Event;
 use biome_parser::prelude::*;
 use biome_parser::token_source::Trivia;
 use biome_parser::{ParserContextCheckpoint, diagnostic::merge_diagnostics};
+use biome_string_case::StrOnlyExtension;
+use std::collections::HashSet;
 
+use crate::lexer::MarkdownReLexContext;
+use crate::syntax::inline::{EmphasisMatch, EmphasisMatches};
+use crate::syntax::link_block::label_end;
 use crate::token_source::{MarkdownTokenSource, MarkdownTokenSourceCheckpoint};
 
 pub(crate) struct MarkdownParser<'source> {
     context: ParserContext<MarkdownSyntaxKind>,
     source: MarkdownTokenSource<'source>,
+    text: &'source str,
+    state: MarkdownParserState,
+    /// The normalized labels of the link reference definitions of the document
+    link_labels: HashSet<String>,
+    /// The emphasis delimiters matched in the last inline content that contained an emphasis
+    emphasis: Option<EmphasisMatches>,
+}
+
+/// A block that contains other blocks and whose markers must be repeated,
+/// or whose indentation must be respected, on every line.
+///
+/// See <https://spec.commonmark.org/0.31.2/#container-blocks>
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum Container {
+    /// A block quote, whose lines start with `>`
+    Quote { content_column: usize },
+    /// A list item, whose lines are indented at least up to the content of the item
+    ListItem { content_column: usize },
+}
+
+impl Container {
+    pub(crate) fn content_column(&self) -> usize {
+        match self {
+            Self::Quote { content_column } | Self::ListItem { content_column } => *content_column,
+        }
+    }
+}
+
+/// The containers matched by the markers at the start of a line.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct LinePrefixes {
+    /// The offset of the start of the line
+    pub(crate) line_start: usize,
+    /// The number of open containers that continue on this line
+    pub(crate) matched: usize,
+    /// The column where the content of the innermost matched container starts
+    pub(crate) content_column: usize,
+}
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct MarkdownParserState {
+    /// The containers that are open, from the outermost to the innermost
+    pub(crate) containers: Vec<Container>,
+    /// The prefixes of the last line whose container markers were parsed
+    pub(crate) line: Option<LinePrefixes>,
 }
 
 impl<'source> MarkdownParser<'source> {
@@ -17,6 +67,10 @@ impl<'source> MarkdownParser<'source> {
         Self {
             context: ParserContext::default(),
             source: MarkdownTokenSource::from_str(source),
+            text: source,
+            state: MarkdownParserState::default(),
+            link_labels: collect_link_labels(source),
+            emphasis: None,
         }
     }
 
@@ -24,18 +78,140 @@ impl<'source> MarkdownParser<'source> {
         MarkdownParserCheckpoint {
             context: self.context.checkpoint(),
             source: self.source.checkpoint(),
+            state: self.state.clone(),
         }
     }
 
-    pub fn before_whitespace_count(&self) -> usize {
-        self.source.before_whitespace_count()
-    }
-
     pub fn rewind(&mut self, checkpoint: MarkdownParserCheckpoint) {
-        let MarkdownParserCheckpoint { context, source } = checkpoint;
+        let MarkdownParserCheckpoint {
+            context,
+            source,
+            state,
+        } = checkpoint;
 
         self.context.rewind(context);
         self.source.rewind(source);
+        self.state = state;
+    }
+
+    pub fn state(&self) -> &MarkdownParserState {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut MarkdownParserState {
+        &mut self.state
+    }
+
+    /// Re-lexes the current token in the given context
+    pub fn re_lex(&mut self, context: MarkdownReLexContext) -> MarkdownSyntaxKind {
+        self.source.re_lex(context)
+    }
+
+    /// Returns the source text of the document
+    pub fn text(&self) -> &'source str {
+        self.text
+    }
+
+    /// Returns the offset of the start of the current token
+    pub fn cur_offset(&self) -> usize {
+        usize::from(self.cur_range().start())
+    }
+
+    /// Returns the offset of the end of the current token
+    pub fn cur_end(&self) -> usize {
+        usize::from(self.cur_range().end())
+    }
+
+    /// Returns the offset of the start of the line that contains `offset`
+    pub fn line_start_at(&self, offset: usize) -> usize {
+        self.text[..offset]
+            .rfind(['\n', '\r'])
+            .map_or(0, |index| index + 1)
+    }
+
+    /// Returns the offset of the line break, or of the end of the file, that ends the line containing `offset`
+    pub fn line_end_at(&self, offset: usize) -> usize {
+        self.text[offset..]
+            .find(['\n', '\r'])
+            .map_or(self.text.len(), |index| offset + index)
+    }
+
+    /// Returns the column of `offset`. Tabs are expanded to the next multiple of 4.
+    ///
+    /// See <https://spec.commonmark.org/0.31.2/#tabs>
+    pub fn column_at(&self, offset: usize) -> usize {
+        let line_start = self.line_start_at(offset);
+        self.text[line_start..offset]
+            .chars()
+            .fold(0, |column, char| {
+                if char == '\t' {
+                    column + 4 - column % 4
+                } else {
+                    column + 1
+                }
+            })
+    }
+
+    /// Returns the column of the current token
+    pub fn cur_column(&self) -> usize {
+        self.column_at(self.cur_offset())
+    }
+
+    /// Returns the text between the start of the current token and the end of its line
+    pub fn rest_of_line(&self) -> &'source str {
+        let start = self.cur_offset();
+        &self.text[start..self.line_end_at(start)]
+    }
+
+    /// Returns `true` if the current token is on the line that starts at `line_start`
+    pub fn is_on_line(&self, line_start: usize) -> bool {
+        !self.at(MarkdownSyntaxKind::EOF) && self.line_start_at(self.cur_offset()) == line_start
+    }
+
+    /// Returns `true` if the line before the line of the current token only contains whitespace
+    pub fn has_blank_line_before(&self) -> bool {
+        let line_start = self.line_start_at(self.cur_offset());
+        if line_start == 0 {
+            return false;
+        }
+        let mut previous_line_end = line_start - 1;
+        if self.text.as_bytes()[previous_line_end] == b'\n'
+            && previous_line_end > 0
+            && self.text.as_bytes()[previous_line_end - 1] == b'\r'
+        {
+            previous_line_end -= 1;
+        }
+        let previous_line_start = self.line_start_at(previous_line_end);
+        self.text[previous_line_start..previous_line_end]
+            .trim_matches([' ', '\t'])
+            .is_empty()
+    }
+
+    /// Returns `true` if a link reference definition with the given label exists in the document
+    pub fn is_link_label_defined(&self, label: &str) -> bool {
+        self.link_labels.contains(&normalize_link_label(label))
+    }
+
+    /// Returns the emphasis delimiters that start at `offset` and the delimiters that close them,
+    /// in the inline content between `start` and `end`.
+    ///
+    /// The delimiters of an inline content are only matched once.
+    pub fn emphasis_match(
+        &mut self,
+        start: usize,
+        end: usize,
+        offset: usize,
+    ) -> Option<EmphasisMatch> {
+        if self
+            .emphasis
+            .as_ref()
+            .is_some_and(|emphasis| !emphasis.is_for(start, end))
+        {
+            self.emphasis = None;
+        }
+        self.emphasis
+            .get_or_insert_with(|| EmphasisMatches::new(self.text, start, end))
+            .at(offset)
     }
 
     pub fn finish(
@@ -78,4 +254,58 @@ impl<'source> Parser for MarkdownParser<'source> {
 pub struct MarkdownParserCheckpoint {
     pub(super) context: ParserContextCheckpoint,
     pub(super) source: MarkdownTokenSourceCheckpoint,
+    pub(super) state: MarkdownParserState,
+}
+
+/// Normalizes a link label: labels are matched case-insensitively, and consecutive whitespace is collapsed.
+///
+/// See <https://spec.commonmark.org/0.31.2/#matches>
+pub(crate) fn normalize_link_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase_cow()
+        .into_owned()
+}
+
+/// Collects the labels of the link reference definitions of the document, e.g. `foo` in `[foo]: /url`.
+///
+/// The labels must be known before the inline content is parsed, because `[foo]` is only a link
+/// when a definition for `foo` exists, even if the definition comes after the link.
+fn collect_link_labels(source: &str) -> HashSet<String> {
+    let mut labels = HashSet::new();
+    for line in source.lines() {
+        let line = strip_container_markers(line);
+        if !line.starts_with('[') {
+            continue;
+        }
+        if let Some(end) = label_end(line) {
+            labels.insert(normalize_link_label(&line[1..end]));
+        }
+    }
+    labels
+}
+
+/// Removes the indentation and the markers of block quotes and list items at the start of a line
+fn strip_container_markers(mut line: &str) -> &str {
+    loop {
+        let trimmed = line.trim_start_matches([' ', '\t', '>']);
+        let rest = if let Some(rest) = trimmed.strip_prefix(['-', '*', '+']) {
+            rest
+        } else {
+            let digits = trimmed.bytes().take_while(u8::is_ascii_digit).count();
+            if digits == 0 {
+                return trimmed;
+            }
+            match trimmed[digits..].strip_prefix(['.', ')']) {
+                Some(rest) => rest,
+                None => return trimmed,
+            }
+        };
+        if !rest.starts_with([' ', '\t']) {
+            return trimmed;
+        }
+        line = rest;
+    }
 }
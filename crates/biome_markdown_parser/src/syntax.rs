@@ -1,56 +1,238 @@
+pub mod code_block;
+pub mod header;
+pub mod html_block;
+pub mod inline;
+pub mod link_block;
+pub mod list;
+pub mod paragraph;
+pub mod quote;
+pub mod table;
 pub mod thematic_break_block;
 
 use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
-use biome_parser::{
-    Parser,
-    prelude::ParsedSyntax::{self, *},
+use biome_parser::Parser;
+use code_block::{
+    at_fenced_code_block, at_indent_code_block, parse_fenced_code_block, parse_indent_code_block,
 };
+use header::{at_header, parse_header};
+use html_block::{HtmlBlockKind, parse_html_block};
+use link_block::{at_link_block, parse_link_block};
+use list::{at_bullet_list_item, at_order_list_item, parse_bullet_list, parse_order_list};
+use paragraph::parse_paragraph;
+use quote::{at_quote, parse_quote, parse_quote_prefix};
+use table::{at_table, parse_table};
 use thematic_break_block::{at_thematic_break_block, parse_thematic_break_block};
 
 use crate::MarkdownParser;
+use crate::parser::{Container, LinePrefixes};
 
 pub(crate) fn parse_document(p: &mut MarkdownParser) {
     let m = p.start();
-    let _ = parse_block_list(p);
+    parse_block_list(p);
     m.complete(p, MD_DOCUMENT);
 }
 
-pub(crate) fn parse_block_list(p: &mut MarkdownParser) -> ParsedSyntax {
+/// Parses the blocks of the innermost open container, or of the document.
+///
+/// The list ends when a line doesn't continue all the open containers.
+pub(crate) fn parse_block_list(p: &mut MarkdownParser) {
+    let depth = p.state().containers.len();
     let m = p.start();
 
     while !p.at(T![EOF]) {
+        if p.has_preceding_line_break() {
+            let prefixes = parse_line_prefixes(p);
+            if prefixes.matched < depth {
+                break;
+            }
+            if !p.is_on_line(prefixes.line_start) {
+                // The rest of the line is blank
+                continue;
+            }
+        }
+
         parse_any_block(p);
     }
-    Present(m.complete(p, MD_BLOCK_LIST))
+
+    m.complete(p, MD_BLOCK_LIST);
 }
 
 pub(crate) fn parse_any_block(p: &mut MarkdownParser) {
     if at_indent_code_block(p) {
         parse_indent_code_block(p);
     } else if at_thematic_break_block(p) {
-        let break_block = try_parse(p, |p| {
-            let break_block = parse_thematic_break_block(p);
-            if break_block.is_absent() {
-                return Err(());
+        parse_thematic_break_block(p).ok();
+    } else if at_header(p) {
+        parse_header(p);
+    } else if at_fenced_code_block(p) {
+        parse_fenced_code_block(p);
+    } else if at_quote(p) {
+        parse_quote(p);
+    } else if at_bullet_list_item(p, false) {
+        parse_bullet_list(p);
+    } else if at_order_list_item(p, false) {
+        parse_order_list(p);
+    } else if let Some(kind) = HtmlBlockKind::at_html_block(p) {
+        parse_html_block(p, kind);
+    } else if at_link_block(p) {
+        parse_link_block(p);
+    } else if at_table(p) {
+        parse_table(p);
+    } else {
+        parse_paragraph(p);
+    }
+}
+
+/// Parses the markers of the open containers at the start of the current line,
+/// and returns how many of them continue on the line.
+///
+/// The markers are only parsed once per line: the result is cached until the parser moves to another line.
+///
+/// See <https://spec.commonmark.org/0.31.2/#phase-1-block-structure>
+pub(crate) fn parse_line_prefixes(p: &mut MarkdownParser) -> LinePrefixes {
+    let line_start = p.line_start_at(p.cur_offset());
+    if let Some(line) = p.state().line.filter(|line| line.line_start == line_start) {
+        return line;
+    }
+
+    let blank_line_before = p.has_blank_line_before();
+    let mut prefixes = LinePrefixes {
+        line_start,
+        matched: 0,
+        content_column: 0,
+    };
+
+    for container in p.state().containers.clone() {
+        let rest_is_blank = !p.is_on_line(line_start);
+        match container {
+            Container::ListItem { content_column } => {
+                if !rest_is_blank && p.cur_column() < content_column {
+                    break;
+                }
+                prefixes.content_column = content_column;
+            }
+            Container::Quote { .. } => {
+                if rest_is_blank
+                    || blank_line_before
+                    || !p.at(T![>])
+                    || p.cur_column() > prefixes.content_column + 3
+                {
+                    break;
+                }
+                prefixes.content_column = parse_quote_prefix(p);
             }
-            Ok(break_block)
-        });
-        if break_block.is_err() {
-            parse_paragraph(p);
         }
+        prefixes.matched += 1;
     }
+
+    p.state_mut().line = Some(prefixes);
+    prefixes
+}
+
+/// Returns the prefixes of the current line if they have already been parsed
+pub(crate) fn current_line_prefixes(p: &MarkdownParser) -> Option<LinePrefixes> {
+    let line_start = p.line_start_at(p.cur_offset());
+    p.state().line.filter(|line| line.line_start == line_start)
 }
 
-pub(crate) fn at_indent_code_block(p: &mut MarkdownParser) -> bool {
-    p.before_whitespace_count() > 4
+/// Returns `true` if the current token is the first token of a line whose container markers haven't been parsed yet
+pub(crate) fn is_at_new_line(p: &MarkdownParser) -> bool {
+    !p.at(T![EOF]) && p.has_preceding_line_break() && current_line_prefixes(p).is_none()
+}
+
+/// Returns the indentation of the current token, relative to the content of the innermost container
+pub(crate) fn block_indent(p: &MarkdownParser) -> usize {
+    let depth = p.state().containers.len();
+    let content_column = match current_line_prefixes(p) {
+        Some(prefixes) if prefixes.matched == depth => prefixes.content_column,
+        _ => p
+            .state()
+            .containers
+            .last()
+            .map_or(0, Container::content_column),
+    };
+    p.cur_column().saturating_sub(content_column)
+}
+
+/// How a paragraph ends at the start of a line
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum ParagraphLine {
+    /// The line continues the paragraph, possibly as a [lazy continuation line](https://spec.commonmark.org/0.31.2/#lazy-continuation-line)
+    Continue,
+    /// The line is a setext heading underline, which turns the paragraph into a heading
+    SetextUnderline,
+    /// The paragraph ends before the line
+    End,
+}
+
+/// Checks how the current line, whose container markers haven't been parsed yet, relates to the paragraph before it.
+pub(crate) fn paragraph_line(p: &mut MarkdownParser) -> ParagraphLine {
+    if p.has_blank_line_before() {
+        return ParagraphLine::End;
+    }
+
+    let checkpoint = p.checkpoint();
+    let depth = p.state().containers.len();
+    let prefixes = parse_line_prefixes(p);
+
+    let result = if !p.is_on_line(prefixes.line_start) {
+        ParagraphLine::End
+    } else if prefixes.matched == depth && at_setext_underline(p) {
+        ParagraphLine::SetextUnderline
+    } else if block_indent(p) >= 4 {
+        ParagraphLine::Continue
+    } else if prefixes.matched == depth && at_paragraph_interrupt(p) {
+        ParagraphLine::End
+    } else if prefixes.matched < depth && at_block_start(p) {
+        // A line that doesn't continue all the containers is only a lazy continuation line
+        // if it doesn't start another block
+        ParagraphLine::End
+    } else {
+        ParagraphLine::Continue
+    };
+
+    p.rewind(checkpoint);
+    result
+}
+
+/// Returns `true` if the current token is a [setext heading underline](https://spec.commonmark.org/0.31.2/#setext-heading-underline)
+fn at_setext_underline(p: &MarkdownParser) -> bool {
+    if block_indent(p) >= 4 {
+        return false;
+    }
+    match p.cur() {
+        MD_SETEXT_UNDERLINE_LITERAL => true,
+        // `---` is lexed as a thematic break, but it's an underline after a paragraph
+        MD_THEMATIC_BREAK_LITERAL => {
+            let text = p.cur_text().trim_end();
+            text.starts_with('-') && text.bytes().all(|byte| byte == b'-')
+        }
+        _ => false,
+    }
 }
 
-pub(crate) fn parse_indent_code_block(_p: &mut MarkdownParser) {
-    todo!()
+/// Returns `true` if the current line starts a block that can interrupt a paragraph
+pub(crate) fn at_paragraph_interrupt(p: &mut MarkdownParser) -> bool {
+    at_thematic_break_block(p)
+        || at_header(p)
+        || at_fenced_code_block(p)
+        || at_quote(p)
+        || at_bullet_list_item(p, true)
+        || at_order_list_item(p, true)
+        || HtmlBlockKind::at_html_block(p).is_some_and(|kind| kind.can_interrupt_paragraph())
+        || at_table(p)
 }
 
-pub(crate) fn parse_paragraph(_p: &mut MarkdownParser) {
-    todo!()
+/// Returns `true` if the current line starts a block other than a paragraph or an indented code block
+fn at_block_start(p: &mut MarkdownParser) -> bool {
+    at_thematic_break_block(p)
+        || at_header(p)
+        || at_fenced_code_block(p)
+        || at_quote(p)
+        || at_bullet_list_item(p, false)
+        || at_order_list_item(p, false)
+        || HtmlBlockKind::at_html_block(p).is_some()
+        || at_table(p)
 }
 
 /// Attempt to parse some input with the given parsing function. If parsing
@@ -0,0 +1,118 @@
+use crate::lexer::MarkdownReLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::{block_indent, parse_line_prefixes};
+use biome_markdown_syntax::{MarkdownSyntaxKind, T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+
+pub(crate) fn at_indent_code_block(p: &MarkdownParser) -> bool {
+    !p.at(T![EOF]) && block_indent(p) >= 4
+}
+
+/// Parses an [indented code block](https://spec.commonmark.org/0.31.2/#indented-code-blocks):
+/// lines indented by at least four spaces.
+pub(crate) fn parse_indent_code_block(p: &mut MarkdownParser) {
+    let m = p.start();
+    let content = p.start();
+
+    parse_code_line(p);
+    while at_indent_code_line(p) {
+        parse_code_line(p);
+    }
+
+    content.complete(p, MD_INLINE_ITEM_LIST);
+    m.complete(p, MD_INDENT_CODE_BLOCK);
+}
+
+/// Returns `true` if the next line with some content continues the indented code block.
+///
+/// The container markers of the lines are parsed when the block continues, and rewound otherwise.
+fn at_indent_code_line(p: &mut MarkdownParser) -> bool {
+    let checkpoint = p.checkpoint();
+    let depth = p.state().containers.len();
+
+    let continues = loop {
+        if p.at(T![EOF]) {
+            break false;
+        }
+        let prefixes = parse_line_prefixes(p);
+        if prefixes.matched < depth {
+            break false;
+        }
+        if p.is_on_line(prefixes.line_start) {
+            break block_indent(p) >= 4;
+        }
+    };
+
+    if !continues {
+        p.rewind(checkpoint);
+    }
+    continues
+}
+
+/// Returns `true` if the current line opens a [fenced code block](https://spec.commonmark.org/0.31.2/#fenced-code-blocks):
+/// at least three backticks or tildes. The info string after backticks can't contain backticks.
+pub(crate) fn at_fenced_code_block(p: &MarkdownParser) -> bool {
+    if !p.at(MD_BACKTICKS_LITERAL) && !p.at(MD_TILDES_LITERAL) || block_indent(p) >= 4 {
+        return false;
+    }
+    let fence = p.cur_text();
+    fence.len() >= 3 && !(fence.starts_with('`') && p.rest_of_line()[fence.len()..].contains('`'))
+}
+
+pub(crate) fn parse_fenced_code_block(p: &mut MarkdownParser) {
+    let fence_kind = p.cur();
+    let fence_len = p.cur_text().len();
+    let line_start = p.line_start_at(p.cur_offset());
+    let depth = p.state().containers.len();
+
+    let m = p.start();
+    p.bump(fence_kind);
+
+    if p.is_on_line(line_start) {
+        parse_code_line(p);
+    }
+
+    let content = p.start();
+    let is_closed = loop {
+        if p.at(T![EOF]) {
+            break false;
+        }
+        let prefixes = parse_line_prefixes(p);
+        if prefixes.matched < depth {
+            break false;
+        }
+        if !p.is_on_line(prefixes.line_start) {
+            continue;
+        }
+        if at_closing_fence(p, fence_kind, fence_len) {
+            break true;
+        }
+        parse_code_line(p);
+    };
+    content.complete(p, MD_INLINE_ITEM_LIST);
+
+    if is_closed {
+        p.bump(fence_kind);
+    }
+
+    m.complete(p, MD_FENCED_CODE_BLOCK);
+}
+
+/// Returns `true` if the current line closes a fenced code block: a fence of the same kind,
+/// at least as long as the opening fence, followed only by whitespace.
+fn at_closing_fence(p: &MarkdownParser, fence_kind: MarkdownSyntaxKind, fence_len: usize) -> bool {
+    p.at(fence_kind)
+        && p.cur_text().len() >= fence_len
+        && block_indent(p) < 4
+        && p.rest_of_line()[p.cur_text().len()..]
+            .trim_matches([' ', '\t'])
+            .is_empty()
+}
+
+/// Parses the rest of the line as text
+pub(crate) fn parse_code_line(p: &mut MarkdownParser) {
+    let m = p.start();
+    p.re_lex(MarkdownReLexContext::Line);
+    p.bump_remap(MD_TEXTUAL_LITERAL);
+    m.complete(p, MD_TEXTUAL);
+}
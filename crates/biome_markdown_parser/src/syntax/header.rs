@@ -0,0 +1,66 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::block_indent;
+use crate::syntax::inline::{InlineContext, parse_inline_item_list};
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+
+/// Returns `true` if the current line is an [ATX heading](https://spec.commonmark.org/0.31.2/#atx-headings):
+/// one to six `#` followed by a space, a tab or the end of the line.
+pub(crate) fn at_header(p: &MarkdownParser) -> bool {
+    if !p.at(T![#]) || block_indent(p) >= 4 {
+        return false;
+    }
+    let line = p.rest_of_line();
+    let level = line.bytes().take_while(|byte| *byte == b'#').count();
+    (1..=6).contains(&level) && matches!(line.as_bytes().get(level), None | Some(b' ' | b'\t'))
+}
+
+pub(crate) fn parse_header(p: &mut MarkdownParser) {
+    let m = p.start();
+    let line_start = p.line_start_at(p.cur_offset());
+
+    let level = p
+        .rest_of_line()
+        .bytes()
+        .take_while(|byte| *byte == b'#')
+        .count();
+    let before = p.start();
+    for _ in 0..level {
+        let hash = p.start();
+        p.bump(T![#]);
+        hash.complete(p, MD_HASH);
+    }
+    before.complete(p, MD_HASH_LIST);
+
+    let content_end = closing_sequence_start(p);
+    if p.is_on_line(line_start) && p.cur_offset() < content_end {
+        let paragraph = p.start();
+        parse_inline_item_list(p, InlineContext::single_line(p).with_end(content_end));
+        paragraph.complete(p, MD_PARAGRAPH);
+    }
+
+    let after = p.start();
+    while p.at(T![#]) && p.is_on_line(line_start) {
+        let hash = p.start();
+        p.bump(T![#]);
+        hash.complete(p, MD_HASH);
+    }
+    after.complete(p, MD_HASH_LIST);
+
+    m.complete(p, MD_HEADER);
+}
+
+/// Returns the offset of the optional closing sequence of `#` of the heading, or the end of its line.
+fn closing_sequence_start(p: &MarkdownParser) -> usize {
+    let start = p.cur_offset();
+    let line = p.rest_of_line().trim_end_matches([' ', '\t']);
+    let without_hashes = line.trim_end_matches('#');
+    if without_hashes.len() == line.len() {
+        return start + line.len();
+    }
+    if without_hashes.is_empty() || without_hashes.ends_with([' ', '\t']) {
+        start + without_hashes.len()
+    } else {
+        start + line.len()
+    }
+}
@@ -0,0 +1,218 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::code_block::parse_code_line;
+use crate::syntax::inline::tag_len;
+use crate::syntax::{block_indent, parse_line_prefixes};
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+use biome_string_case::StrLikeExtension;
+
+/// The [HTML block](https://spec.commonmark.org/0.31.2/#html-blocks) start conditions.
+/// Each kind of HTML block ends differently.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum HtmlBlockKind {
+    /// `<script`, `<pre`, `<style` or `<textarea`, ends at the line containing the closing tag
+    Raw,
+    /// `<!--`, ends at the line containing `-->`
+    Comment,
+    /// `<?`, ends at the line containing `?>`
+    ProcessingInstruction,
+    /// `<!` followed by a letter, ends at the line containing `>`
+    Declaration,
+    /// `<![CDATA[`, ends at the line containing `]]>`
+    CData,
+    /// An opening or closing tag of a block element, such as `<div>`, ends at a blank line
+    Block,
+    /// Any other complete opening or closing tag alone on its line, ends at a blank line
+    Tag,
+}
+
+const RAW_TAGS: [&str; 4] = ["pre", "script", "style", "textarea"];
+
+const BLOCK_TAGS: [&str; 62] = [
+    "address",
+    "article",
+    "aside",
+    "base",
+    "basefont",
+    "blockquote",
+    "body",
+    "caption",
+    "center",
+    "col",
+    "colgroup",
+    "dd",
+    "details",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "frame",
+    "frameset",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hr",
+    "html",
+    "iframe",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "menu",
+    "menuitem",
+    "nav",
+    "noframes",
+    "ol",
+    "optgroup",
+    "option",
+    "p",
+    "param",
+    "search",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "track",
+    "ul",
+];
+
+impl HtmlBlockKind {
+    /// Returns the kind of the HTML block that starts on the current line, if any
+    pub(crate) fn at_html_block(p: &MarkdownParser) -> Option<Self> {
+        if !p.at(T![<]) || block_indent(p) >= 4 {
+            return None;
+        }
+        let line = p.rest_of_line();
+
+        if line.starts_with("<!--") {
+            return Some(Self::Comment);
+        }
+        if line.starts_with("<?") {
+            return Some(Self::ProcessingInstruction);
+        }
+        if line.starts_with("<![CDATA[") {
+            return Some(Self::CData);
+        }
+        if line
+            .strip_prefix("<!")
+            .is_some_and(|rest| rest.starts_with(|char: char| char.is_ascii_alphabetic()))
+        {
+            return Some(Self::Declaration);
+        }
+
+        let (is_closing, rest) = match line.strip_prefix("</") {
+            Some(rest) => (true, rest),
+            None => (false, &line[1..]),
+        };
+        let name_len = rest
+            .bytes()
+            .take_while(|byte| byte.is_ascii_alphanumeric() || *byte == b'-')
+            .count();
+        if name_len == 0 || !rest.starts_with(|char: char| char.is_ascii_alphabetic()) {
+            return None;
+        }
+        let name = &rest[..name_len];
+        let is_raw_tag = RAW_TAGS.iter().any(|tag| tag.eq_ignore_ascii_case(name));
+        let after_name = &rest[name_len..];
+
+        if !is_closing
+            && is_raw_tag
+            && (after_name.is_empty() || after_name.starts_with([' ', '\t', '>']))
+        {
+            return Some(Self::Raw);
+        }
+        if BLOCK_TAGS.iter().any(|tag| tag.eq_ignore_ascii_case(name))
+            && (after_name.is_empty()
+                || after_name.starts_with([' ', '\t', '>'])
+                || after_name.starts_with("/>"))
+        {
+            return Some(Self::Block);
+        }
+        if !is_raw_tag
+            && tag_len(line).is_some_and(|len| line[len..].trim_matches([' ', '\t']).is_empty())
+        {
+            return Some(Self::Tag);
+        }
+        None
+    }
+
+    /// A complete tag alone on its line can't interrupt a paragraph
+    pub(crate) fn can_interrupt_paragraph(self) -> bool {
+        self != Self::Tag
+    }
+
+    /// Blocks that start with a tag end at a blank line, the other ones end with a specific sequence of characters
+    fn ends_at_blank_line(self) -> bool {
+        matches!(self, Self::Block | Self::Tag)
+    }
+
+    /// Returns `true` if the given line ends the HTML block
+    fn is_end(self, line: &str) -> bool {
+        match self {
+            Self::Raw => {
+                let line = line.to_ascii_lowercase_cow();
+                RAW_TAGS
+                    .iter()
+                    .any(|tag| line.contains(&format!("</{tag}>")))
+            }
+            Self::Comment => line.contains("-->"),
+            Self::ProcessingInstruction => line.contains("?>"),
+            Self::Declaration => line.contains('>'),
+            Self::CData => line.contains("]]>"),
+            Self::Block | Self::Tag => false,
+        }
+    }
+}
+
+/// Parses an HTML block, whose lines are kept as text
+pub(crate) fn parse_html_block(p: &mut MarkdownParser, kind: HtmlBlockKind) {
+    let depth = p.state().containers.len();
+    let m = p.start();
+    let content = p.start();
+
+    loop {
+        let is_end = kind.is_end(p.rest_of_line());
+        parse_code_line(p);
+        if is_end || p.at(T![EOF]) || (kind.ends_at_blank_line() && p.has_blank_line_before()) {
+            break;
+        }
+
+        let checkpoint = p.checkpoint();
+        let continues = loop {
+            let prefixes = parse_line_prefixes(p);
+            if prefixes.matched < depth {
+                break false;
+            }
+            if p.is_on_line(prefixes.line_start) {
+                break true;
+            }
+            if kind.ends_at_blank_line() || p.at(T![EOF]) {
+                break false;
+            }
+        };
+        if !continues {
+            p.rewind(checkpoint);
+            break;
+        }
+    }
+
+    content.complete(p, MD_INLINE_ITEM_LIST);
+    m.complete(p, MD_HTML_BLOCK);
+}
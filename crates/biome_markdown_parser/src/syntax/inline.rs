@@ -0,0 +1,223 @@
+mod code;
+mod emphasis;
+mod html;
+mod link;
+
+use crate::lexer::MarkdownReLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::{ParagraphLine, is_at_new_line, paragraph_line, parse_line_prefixes};
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+use biome_parser::prelude::ParsedSyntax;
+use code::parse_inline_code;
+pub(crate) use emphasis::{EmphasisMatch, EmphasisMatches};
+use emphasis::{parse_emphasis, parse_strikethrough};
+use html::parse_autolink_or_inline_html;
+pub(crate) use html::tag_len;
+use link::parse_link;
+
+/// Describes where a list of inline elements ends.
+///
+/// Markdown has no syntax errors: an inline construct whose closing delimiter can't be found
+/// is parsed as text. The closing delimiters are searched in the source text before the construct is parsed,
+/// and `scan_end` bounds that search, e.g. to the end of the paragraph.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct InlineContext {
+    /// The offset where the inline content starts, e.g. the start of the paragraph
+    content_start: usize,
+    /// The offset where the inline content ends. Unlike `scan_end`, it isn't reduced by nested constructs
+    content_end: usize,
+    /// The offset where the list ends, e.g. the offset of the closing delimiter of an emphasis
+    end: Option<usize>,
+    /// The offset after which closing delimiters aren't searched
+    scan_end: usize,
+    /// The list ends at the end of the line, e.g. in headings and table cells
+    single_line: bool,
+    /// The tokens of the list are text, e.g. in code spans and link destinations
+    verbatim: bool,
+    /// The list ends at a `|`, e.g. in table cells
+    in_table: bool,
+}
+
+impl InlineContext {
+    /// The content of a paragraph, which ends at the first blank line
+    pub(crate) fn paragraph(p: &MarkdownParser) -> Self {
+        let scan_end = paragraph_scan_end(p.text(), p.cur_offset());
+        Self {
+            content_start: p.cur_offset(),
+            content_end: scan_end,
+            end: None,
+            scan_end,
+            single_line: false,
+            verbatim: false,
+            in_table: false,
+        }
+    }
+
+    /// The content of a construct that ends with its line, e.g. a heading
+    pub(crate) fn single_line(p: &MarkdownParser) -> Self {
+        let scan_end = p.line_end_at(p.cur_offset());
+        Self {
+            content_start: p.cur_offset(),
+            content_end: scan_end,
+            end: None,
+            scan_end,
+            single_line: true,
+            verbatim: false,
+            in_table: false,
+        }
+    }
+
+    /// The content of a cell of a table
+    pub(crate) fn table_cell(p: &MarkdownParser) -> Self {
+        Self {
+            in_table: true,
+            ..Self::single_line(p)
+        }
+    }
+
+    /// Ends the list at `end`, or before if the current context ends before
+    pub(crate) fn with_end(self, end: usize) -> Self {
+        let end = self.end.map_or(end, |current| current.min(end));
+        Self {
+            end: Some(end),
+            scan_end: self.scan_end.min(end),
+            ..self
+        }
+    }
+
+    /// Parses all the tokens as text
+    pub(crate) fn verbatim(self) -> Self {
+        Self {
+            verbatim: true,
+            ..self
+        }
+    }
+}
+
+/// The reason why a list of inline elements ended
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum InlineListEnd {
+    Eof,
+    /// The list reached the end of its context
+    End,
+    /// A single line list reached the end of its line
+    LineEnd,
+    /// A table cell reached a `|`
+    Pipe,
+    /// The next line doesn't continue the paragraph
+    ParagraphEnd,
+    /// The next line is a setext heading underline, its container markers have been parsed
+    SetextUnderline,
+}
+
+pub(crate) fn parse_inline_item_list(
+    p: &mut MarkdownParser,
+    context: InlineContext,
+) -> InlineListEnd {
+    let m = p.start();
+
+    let end = loop {
+        if p.at(T![EOF]) {
+            break InlineListEnd::Eof;
+        }
+        if context.end.is_some_and(|end| p.cur_offset() >= end) {
+            break InlineListEnd::End;
+        }
+        if is_at_new_line(p) {
+            if context.single_line {
+                break InlineListEnd::LineEnd;
+            }
+            match paragraph_line(p) {
+                ParagraphLine::Continue => {
+                    parse_line_prefixes(p);
+                }
+                ParagraphLine::SetextUnderline => {
+                    parse_line_prefixes(p);
+                    break InlineListEnd::SetextUnderline;
+                }
+                ParagraphLine::End => break InlineListEnd::ParagraphEnd,
+            }
+        }
+        if context.in_table && p.at(T![|]) {
+            break InlineListEnd::Pipe;
+        }
+
+        parse_any_inline(p, context);
+    };
+
+    m.complete(p, MD_INLINE_ITEM_LIST);
+    end
+}
+
+fn parse_any_inline(p: &mut MarkdownParser, context: InlineContext) {
+    if context.verbatim {
+        parse_textual(p);
+        return;
+    }
+
+    let parsed = match p.cur() {
+        MD_HARD_LINE_LITERAL => {
+            let m = p.start();
+            p.bump(MD_HARD_LINE_LITERAL);
+            m.complete(p, MD_HARD_LINE);
+            return;
+        }
+        T![*] | T![**] | T!["_"] | T!["__"] => parse_emphasis(p, context),
+        MD_TILDES_LITERAL => parse_strikethrough(p, context),
+        MD_BACKTICKS_LITERAL => parse_inline_code(p, context),
+        T!['['] => parse_link(p, context, false),
+        T![!] => parse_link(p, context, true),
+        T![<] => parse_autolink_or_inline_html(p, context),
+        _ => ParsedSyntax::Absent,
+    };
+
+    if parsed.is_absent() {
+        if matches!(p.cur(), T![**] | T!["__"]) {
+            // Only the first delimiter is text, the second may still open or close an emphasis
+            p.re_lex(MarkdownReLexContext::SingleDelimiter);
+        }
+        parse_textual(p);
+    }
+}
+
+/// Parses the current token as text
+pub(crate) fn parse_textual(p: &mut MarkdownParser) {
+    let m = p.start();
+    p.bump_remap(MD_TEXTUAL_LITERAL);
+    m.complete(p, MD_TEXTUAL);
+}
+
+/// Returns the offset of the line break before the first blank line after `offset`, or the end of the text.
+fn paragraph_scan_end(text: &str, offset: usize) -> usize {
+    let mut position = offset;
+    while let Some(index) = text[position..].find(['\n', '\r']) {
+        let line_break = position + index;
+        let line_start = if text[line_break..].starts_with("\r\n") {
+            line_break + 2
+        } else {
+            line_break + 1
+        };
+        let line_end = text[line_start..]
+            .find(['\n', '\r'])
+            .map_or(text.len(), |index| line_start + index);
+        if text[line_start..line_end]
+            .trim_matches([' ', '\t'])
+            .is_empty()
+        {
+            return line_break;
+        }
+        position = line_end;
+    }
+    text.len()
+}
+
+/// Returns the number of bytes of the backslash escape at `offset`, if any.
+///
+/// See <https://spec.commonmark.org/0.31.2/#backslash-escapes>
+fn escape_len(bytes: &[u8], offset: usize) -> usize {
+    match bytes.get(offset + 1) {
+        Some(byte) if byte.is_ascii_punctuation() && *byte != b'`' => 2,
+        _ => 1,
+    }
+}
@@ -0,0 +1,51 @@
+use super::{InlineContext, parse_inline_item_list};
+use crate::parser::MarkdownParser;
+use crate::syntax::try_parse;
+use biome_markdown_syntax::kind::MarkdownSyntaxKind::*;
+use biome_parser::Parser;
+use biome_parser::prelude::ParsedSyntax::{self, *};
+
+/// Parses a [code span](https://spec.commonmark.org/0.31.2/#code-spans), which ends with
+/// a run of backticks of the same length as the opening one.
+pub(super) fn parse_inline_code(p: &mut MarkdownParser, context: InlineContext) -> ParsedSyntax {
+    let start = p.cur_offset();
+    let len = p.cur_end() - start;
+    let Some(close_start) = code_span_end(p.text(), p.cur_end(), len, context.scan_end) else {
+        return Absent;
+    };
+
+    try_parse(p, |p| {
+        let m = p.start();
+        p.bump(MD_BACKTICKS_LITERAL);
+        parse_inline_item_list(p, context.with_end(close_start).verbatim());
+
+        if p.cur_offset() != close_start || !p.at(MD_BACKTICKS_LITERAL) {
+            m.abandon(p);
+            return Err(());
+        }
+        p.bump(MD_BACKTICKS_LITERAL);
+        Ok(m.complete(p, MD_INLINE_CODE))
+    })
+    .map_or(Absent, Present)
+}
+
+/// Returns the offset of the first run of exactly `len` backticks between `from` and `scan_end`
+pub(super) fn code_span_end(text: &str, from: usize, len: usize, scan_end: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut position = from;
+    while position < scan_end {
+        if bytes[position] == b'`' {
+            let run_len = bytes[position..]
+                .iter()
+                .take_while(|byte| **byte == b'`')
+                .count();
+            if run_len == len {
+                return Some(position);
+            }
+            position += run_len;
+        } else {
+            position += 1;
+        }
+    }
+    None
+}
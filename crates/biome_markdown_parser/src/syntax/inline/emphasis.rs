@@ -0,0 +1,306 @@
+use super::code::code_span_end;
+use super::{InlineContext, escape_len, parse_inline_item_list};
+use crate::lexer::MarkdownReLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::try_parse;
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+use biome_parser::prelude::ParsedSyntax::{self, *};
+
+/// Parses an italic or a bold text delimited by `*` or `_`.
+///
+/// The delimiters are matched before parsing, see [EmphasisMatches]. For example, in `***text***`, the first
+/// delimiter opens the italic text, and the remaining ones open the bold text nested in it.
+///
+/// See <https://spec.commonmark.org/0.31.2/#emphasis-and-strong-emphasis>
+pub(super) fn parse_emphasis(p: &mut MarkdownParser, context: InlineContext) -> ParsedSyntax {
+    let start = p.cur_offset();
+    let Some(EmphasisMatch {
+        closing_start, len, ..
+    }) = p.emphasis_match(context.content_start, context.content_end, start)
+    else {
+        return Absent;
+    };
+    let kind = if len == 1 {
+        MD_INLINE_ITALIC
+    } else {
+        MD_INLINE_EMPHASIS
+    };
+
+    try_parse(p, |p| {
+        let m = p.start();
+        bump_delimiter(p, len);
+        parse_inline_item_list(p, context.with_end(closing_start));
+
+        if p.cur_offset() != closing_start
+            || !matches!(p.cur(), T![*] | T![**] | T!["_"] | T!["__"])
+            || p.cur_end() - p.cur_offset() < len
+        {
+            m.abandon(p);
+            return Err(());
+        }
+        bump_delimiter(p, len);
+        Ok(m.complete(p, kind))
+    })
+    .map_or(Absent, Present)
+}
+
+/// An opening and a closing emphasis delimiter run that match
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct EmphasisMatch {
+    opening_start: usize,
+    closing_start: usize,
+    /// The number of delimiters used on each side: 1 for an italic text, 2 for a bold text
+    len: usize,
+}
+
+/// The emphasis delimiters of an inline content, such as a paragraph, that match each other.
+///
+/// Whether a delimiter opens or closes an emphasis depends on all the delimiters of the content,
+/// so they're matched with the [process emphasis](https://spec.commonmark.org/0.31.2/#phase-2-inline-structure)
+/// algorithm of the specification once per content.
+#[derive(Debug)]
+pub(crate) struct EmphasisMatches {
+    start: usize,
+    end: usize,
+    /// The matches, sorted by the offset of their opening delimiters
+    matches: Vec<EmphasisMatch>,
+}
+
+/// A delimiter run of `*` or `_` found in the inline content
+struct DelimiterRun {
+    delimiter: u8,
+    /// The delimiters that haven't been matched yet. Opening delimiters are matched from the end of the run,
+    /// closing delimiters from its start.
+    start: usize,
+    end: usize,
+    original_len: usize,
+    can_open: bool,
+    can_close: bool,
+}
+
+impl EmphasisMatches {
+    pub(crate) fn new(text: &str, start: usize, end: usize) -> Self {
+        let mut runs = delimiter_runs(text, start, end);
+        let mut matches = Vec::new();
+
+        for closer in 0..runs.len() {
+            if !runs[closer].can_close {
+                continue;
+            }
+            while runs[closer].start < runs[closer].end {
+                let Some(opener) = (0..closer)
+                    .rev()
+                    .find(|opener| can_match(&runs[*opener], &runs[closer]))
+                else {
+                    break;
+                };
+
+                let opener_len = runs[opener].end - runs[opener].start;
+                let closer_len = runs[closer].end - runs[closer].start;
+                let len = if opener_len >= 2 && closer_len >= 2 {
+                    2
+                } else {
+                    1
+                };
+                runs[opener].end -= len;
+                matches.push(EmphasisMatch {
+                    opening_start: runs[opener].end,
+                    closing_start: runs[closer].start,
+                    len,
+                });
+                runs[closer].start += len;
+
+                // The delimiters between the opener and the closer can't be matched anymore
+                for run in &mut runs[opener + 1..closer] {
+                    run.end = run.start;
+                }
+            }
+        }
+
+        matches.sort_unstable_by_key(|emphasis| emphasis.opening_start);
+        Self {
+            start,
+            end,
+            matches,
+        }
+    }
+
+    /// Returns `true` if the matches were computed for the inline content between `start` and `end`
+    pub(crate) fn is_for(&self, start: usize, end: usize) -> bool {
+        self.start == start && self.end == end
+    }
+
+    /// Returns the match whose opening delimiters start at `offset`
+    pub(crate) fn at(&self, offset: usize) -> Option<EmphasisMatch> {
+        self.matches
+            .binary_search_by_key(&offset, |emphasis| emphasis.opening_start)
+            .ok()
+            .map(|index| self.matches[index])
+    }
+}
+
+/// Returns `true` if the remaining delimiters of `opener` can be closed by `closer`.
+///
+/// When one of the runs can both open and close, the sum of their lengths can't be a multiple of 3,
+/// unless both lengths are multiples of 3.
+fn can_match(opener: &DelimiterRun, closer: &DelimiterRun) -> bool {
+    opener.delimiter == closer.delimiter
+        && opener.can_open
+        && opener.start < opener.end
+        && (!(opener.can_close || closer.can_open)
+            || !(opener.original_len + closer.original_len).is_multiple_of(3)
+            || (opener.original_len.is_multiple_of(3) && closer.original_len.is_multiple_of(3)))
+}
+
+/// Collects the delimiter runs of `*` and `_` between `start` and `end`, skipping escaped delimiters and code spans
+fn delimiter_runs(text: &str, start: usize, end: usize) -> Vec<DelimiterRun> {
+    let bytes = text.as_bytes();
+    let mut runs = Vec::new();
+    let mut position = start;
+    while position < end {
+        match bytes[position] {
+            b'\\' => position += escape_len(bytes, position),
+            b'`' => {
+                let run_len = run_len(bytes, position, b'`');
+                position = code_span_end(text, position + run_len, run_len, end)
+                    .map_or(position + run_len, |close| close + run_len);
+            }
+            delimiter @ (b'*' | b'_') => {
+                let run_end = (position + run_len(bytes, position, delimiter)).min(end);
+                runs.push(DelimiterRun {
+                    delimiter,
+                    start: position,
+                    end: run_end,
+                    original_len: run_end - position,
+                    can_open: can_open(text, position, run_end, delimiter),
+                    can_close: can_close(text, position, run_end, delimiter),
+                });
+                position = run_end;
+            }
+            _ => position += 1,
+        }
+    }
+    runs
+}
+
+/// Bumps `len` delimiters of the current token, splitting `**` or `__` if only one is needed.
+fn bump_delimiter(p: &mut MarkdownParser, len: usize) {
+    if p.cur_end() - p.cur_offset() > len {
+        p.re_lex(MarkdownReLexContext::SingleDelimiter);
+    }
+    p.bump_any();
+}
+
+/// Parses a [strikethrough](https://github.github.com/gfm/#strikethrough-extension-) text delimited by `~` or `~~`.
+pub(super) fn parse_strikethrough(p: &mut MarkdownParser, context: InlineContext) -> ParsedSyntax {
+    let text = p.text();
+    let start = p.cur_offset();
+    let end = p.cur_end();
+    let len = end - start;
+    if len > 2 || !can_open(text, start, end, b'~') {
+        return Absent;
+    }
+    let Some(close_start) = find_closing_tildes(text, end, context.scan_end, len) else {
+        return Absent;
+    };
+
+    try_parse(p, |p| {
+        let m = p.start();
+        p.bump(MD_TILDES_LITERAL);
+        parse_inline_item_list(p, context.with_end(close_start));
+
+        if p.cur_offset() != close_start || !p.at(MD_TILDES_LITERAL) {
+            m.abandon(p);
+            return Err(());
+        }
+        p.bump(MD_TILDES_LITERAL);
+        Ok(m.complete(p, MD_INLINE_STRIKETHROUGH))
+    })
+    .map_or(Absent, Present)
+}
+
+/// Finds the first run of `len` tildes after `from` that can close a strikethrough, and returns its start.
+///
+/// Code spans are skipped, because they take precedence over strikethroughs.
+fn find_closing_tildes(text: &str, from: usize, scan_end: usize, len: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut position = from;
+    while position < scan_end {
+        match bytes[position] {
+            b'\\' => position += escape_len(bytes, position),
+            b'`' => {
+                let run_len = run_len(bytes, position, b'`');
+                position = code_span_end(text, position + run_len, run_len, scan_end)
+                    .map_or(position + run_len, |close| close + run_len);
+            }
+            b'~' => {
+                let run_end = position + run_len(bytes, position, b'~');
+                if run_end - position == len && can_close(text, position, run_end, b'~') {
+                    return Some(position);
+                }
+                position = run_end;
+            }
+            _ => position += 1,
+        }
+    }
+    None
+}
+
+fn run_len(bytes: &[u8], offset: usize, byte: u8) -> usize {
+    bytes[offset..]
+        .iter()
+        .take_while(|current| **current == byte)
+        .count()
+}
+
+/// See <https://spec.commonmark.org/0.31.2/#left-flanking-delimiter-run>
+fn is_left_flanking(before: Option<char>, after: Option<char>) -> bool {
+    !is_whitespace(after)
+        && (!is_punctuation(after) || is_whitespace(before) || is_punctuation(before))
+}
+
+/// See <https://spec.commonmark.org/0.31.2/#right-flanking-delimiter-run>
+fn is_right_flanking(before: Option<char>, after: Option<char>) -> bool {
+    !is_whitespace(before)
+        && (!is_punctuation(before) || is_whitespace(after) || is_punctuation(after))
+}
+
+/// Returns `true` if the delimiter run between `start` and `end` can open an emphasis.
+/// An `_` can't open an emphasis inside a word.
+fn can_open(text: &str, start: usize, end: usize, delimiter: u8) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    let left_flanking = is_left_flanking(before, after);
+    if delimiter == b'_' {
+        left_flanking && (!is_right_flanking(before, after) || is_punctuation(before))
+    } else {
+        left_flanking
+    }
+}
+
+/// Returns `true` if the delimiter run between `start` and `end` can close an emphasis.
+/// An `_` can't close an emphasis inside a word.
+fn can_close(text: &str, start: usize, end: usize, delimiter: u8) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    let right_flanking = is_right_flanking(before, after);
+    if delimiter == b'_' {
+        right_flanking && (!is_left_flanking(before, after) || is_punctuation(after))
+    } else {
+        right_flanking
+    }
+}
+
+/// The start and the end of the text count as whitespace
+fn is_whitespace(char: Option<char>) -> bool {
+    char.is_none_or(char::is_whitespace)
+}
+
+/// See <https://spec.commonmark.org/0.31.2/#unicode-punctuation-character>
+fn is_punctuation(char: Option<char>) -> bool {
+    char.is_some_and(|char| {
+        char.is_ascii_punctuation()
+            || (!char.is_alphanumeric() && !char.is_whitespace() && !char.is_control())
+    })
+}
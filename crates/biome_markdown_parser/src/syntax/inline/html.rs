@@ -0,0 +1,194 @@
+use super::{InlineContext, parse_inline_item_list};
+use crate::parser::MarkdownParser;
+use crate::syntax::try_parse;
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+use biome_parser::prelude::ParsedSyntax::{self, *};
+
+/// Parses an [autolink](https://spec.commonmark.org/0.31.2/#autolinks) such as `<https://biomejs.dev>`,
+/// or [raw HTML](https://spec.commonmark.org/0.31.2/#raw-html) such as `<span class="name">`.
+pub(super) fn parse_autolink_or_inline_html(
+    p: &mut MarkdownParser,
+    context: InlineContext,
+) -> ParsedSyntax {
+    let start = p.cur_offset();
+    let end = p.line_end_at(start).min(context.scan_end);
+    let source = &p.text()[start..end.max(start)];
+
+    if let Some(len) = autolink_len(source) {
+        let close_angle = start + len - 1;
+        try_parse(p, |p| {
+            let m = p.start();
+            p.bump(T![<]);
+            parse_inline_item_list(p, context.with_end(close_angle).verbatim());
+            if p.cur_offset() != close_angle || !p.at(T![>]) {
+                m.abandon(p);
+                return Err(());
+            }
+            p.bump(T![>]);
+            Ok(m.complete(p, MD_AUTOLINK))
+        })
+        .map_or(Absent, Present)
+    } else if let Some(len) = inline_html_len(source) {
+        let end = start + len;
+        try_parse(p, |p| {
+            let m = p.start();
+            parse_inline_item_list(p, context.with_end(end).verbatim());
+            if p.last_end().map(usize::from) != Some(end) {
+                m.abandon(p);
+                return Err(());
+            }
+            Ok(m.complete(p, MD_INLINE_HTML))
+        })
+        .map_or(Absent, Present)
+    } else {
+        Absent
+    }
+}
+
+/// Returns the length of the autolink at the start of `source`, including the angle brackets
+fn autolink_len(source: &str) -> Option<usize> {
+    let inner = source.strip_prefix('<')?;
+    let end = inner.find('>')?;
+    let value = &inner[..end];
+    if value.is_empty()
+        || value
+            .chars()
+            .any(|char| char.is_whitespace() || char.is_control() || char == '<')
+    {
+        return None;
+    }
+
+    let is_uri = value.split_once(':').is_some_and(|(scheme, _)| {
+        (2..=32).contains(&scheme.len())
+            && scheme.starts_with(|char: char| char.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || matches!(char, '+' | '.' | '-'))
+    });
+    let is_email = value.split_once('@').is_some_and(|(local, domain)| {
+        !local.is_empty()
+            && local
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || ".!#$%&'*+/=?^_`{|}~-".contains(char))
+            && domain.split('.').all(|label| {
+                !label.is_empty()
+                    && label.len() <= 63
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+                    && label
+                        .chars()
+                        .all(|char| char.is_ascii_alphanumeric() || char == '-')
+            })
+    });
+
+    (is_uri || is_email).then_some(end + 2)
+}
+
+/// Returns the length of the HTML tag, comment, processing instruction, declaration or
+/// CDATA section at the start of `source`. Only constructs that fit on a single line are recognized.
+fn inline_html_len(source: &str) -> Option<usize> {
+    let delimited = [("<!--", "-->"), ("<?", "?>"), ("<![CDATA[", "]]>")];
+    for (open, close) in delimited {
+        if let Some(rest) = source.strip_prefix(open) {
+            return rest.find(close).map(|end| open.len() + end + close.len());
+        }
+    }
+
+    if let Some(rest) = source.strip_prefix("<!") {
+        if rest.starts_with(|char: char| char.is_ascii_alphabetic()) {
+            return rest.find('>').map(|end| end + 3);
+        }
+        return None;
+    }
+
+    tag_len(source)
+}
+
+/// Returns the length of the [open tag](https://spec.commonmark.org/0.31.2/#open-tag) or of the
+/// [closing tag](https://spec.commonmark.org/0.31.2/#closing-tag) at the start of `source`
+pub(crate) fn tag_len(source: &str) -> Option<usize> {
+    if let Some(rest) = source.strip_prefix("</") {
+        let name_len = tag_name_len(rest)?;
+        let after_name = &rest[name_len..];
+        let trimmed = after_name.trim_start();
+        return trimmed
+            .starts_with('>')
+            .then(|| source.len() - trimmed.len() + 1);
+    }
+
+    let rest = source.strip_prefix('<')?;
+    let name_len = tag_name_len(rest)?;
+    let bytes = source.as_bytes();
+    let mut position = 1 + name_len;
+    loop {
+        let whitespace = bytes[position..]
+            .iter()
+            .take_while(|byte| matches!(byte, b' ' | b'\t'))
+            .count();
+        position += whitespace;
+        match bytes.get(position)? {
+            b'>' => return Some(position + 1),
+            b'/' => return (bytes.get(position + 1) == Some(&b'>')).then_some(position + 2),
+            byte if whitespace > 0
+                && (byte.is_ascii_alphabetic() || matches!(byte, b'_' | b':')) =>
+            {
+                position += bytes[position..]
+                    .iter()
+                    .take_while(|byte| {
+                        byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'.' | b':' | b'-')
+                    })
+                    .count();
+                let before_value = position;
+                position += bytes[position..]
+                    .iter()
+                    .take_while(|byte| matches!(byte, b' ' | b'\t'))
+                    .count();
+                if bytes.get(position) != Some(&b'=') {
+                    position = before_value;
+                    continue;
+                }
+                position += 1;
+                position += bytes[position..]
+                    .iter()
+                    .take_while(|byte| matches!(byte, b' ' | b'\t'))
+                    .count();
+                match bytes.get(position)? {
+                    quote @ (b'"' | b'\'') => {
+                        let end = bytes[position + 1..]
+                            .iter()
+                            .position(|byte| byte == quote)?;
+                        position += end + 2;
+                    }
+                    _ => {
+                        let len = bytes[position..]
+                            .iter()
+                            .take_while(|byte| {
+                                !byte.is_ascii_whitespace()
+                                    && !matches!(byte, b'"' | b'\'' | b'=' | b'<' | b'>' | b'`')
+                            })
+                            .count();
+                        if len == 0 {
+                            return None;
+                        }
+                        position += len;
+                    }
+                }
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Returns the length of the [tag name](https://spec.commonmark.org/0.31.2/#tag-name) at the start of `source`
+fn tag_name_len(source: &str) -> Option<usize> {
+    if !source.starts_with(|char: char| char.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some(
+        source
+            .bytes()
+            .take_while(|byte| byte.is_ascii_alphanumeric() || *byte == b'-')
+            .count(),
+    )
+}
@@ -0,0 +1,241 @@
+use super::code::code_span_end;
+use super::{InlineContext, escape_len, parse_inline_item_list};
+use crate::parser::MarkdownParser;
+use crate::syntax::try_parse;
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+use biome_parser::prelude::ParsedSyntax::{self, *};
+
+/// The form of a link, which is known before the link is parsed
+#[derive(Debug, Copy, Clone)]
+enum LinkKind {
+    /// `[text](destination "title")`, the offset is the one of the `)`
+    Inline { close_paren: usize },
+    /// `[text][label]`, `[text][]` or `[text]`, the offset is the one of the `]` of the label
+    Reference { label_end: Option<usize> },
+}
+
+/// Parses a link or, when `image` is `true`, an image.
+///
+/// A reference link is only parsed when its label matches a link reference definition of the document.
+///
+/// See <https://spec.commonmark.org/0.31.2/#links> and <https://spec.commonmark.org/0.31.2/#images>
+pub(super) fn parse_link(
+    p: &mut MarkdownParser,
+    context: InlineContext,
+    image: bool,
+) -> ParsedSyntax {
+    let text = p.text();
+    let open_bracket = if image {
+        p.cur_offset() + 1
+    } else {
+        p.cur_offset()
+    };
+    let Some(close_bracket) = bracket_end(text, open_bracket + 1, context.scan_end) else {
+        return Absent;
+    };
+    let Some(kind) = link_kind(p, open_bracket + 1, close_bracket, context.scan_end) else {
+        return Absent;
+    };
+
+    try_parse(p, |p| {
+        let m = p.start();
+        if image {
+            p.bump(T![!]);
+        }
+        p.bump(T!['[']);
+        parse_inline_item_list(p, context.with_end(close_bracket));
+        if p.cur_offset() != close_bracket || !p.at(T![']']) {
+            m.abandon(p);
+            return Err(());
+        }
+        p.bump(T![']']);
+
+        let kind = match kind {
+            LinkKind::Inline { close_paren } => {
+                if !p.at(T!['(']) {
+                    m.abandon(p);
+                    return Err(());
+                }
+                p.bump(T!['(']);
+                parse_inline_item_list(p, context.with_end(close_paren).verbatim());
+                if p.cur_offset() != close_paren || !p.at(T![')']) {
+                    m.abandon(p);
+                    return Err(());
+                }
+                p.bump(T![')']);
+                if image {
+                    MD_INLINE_IMAGE
+                } else {
+                    MD_INLINE_LINK
+                }
+            }
+            LinkKind::Reference { label_end } => {
+                if let Some(label_end) = label_end {
+                    let label = p.start();
+                    p.bump(T!['[']);
+                    parse_inline_item_list(p, context.with_end(label_end).verbatim());
+                    if p.cur_offset() != label_end || !p.at(T![']']) {
+                        label.abandon(p);
+                        m.abandon(p);
+                        return Err(());
+                    }
+                    p.bump(T![']']);
+                    label.complete(p, MD_REFERENCE_LINK_LABEL);
+                }
+                if image {
+                    MD_REFERENCE_IMAGE
+                } else {
+                    MD_REFERENCE_LINK
+                }
+            }
+        };
+        Ok(m.complete(p, kind))
+    })
+    .map_or(Absent, Present)
+}
+
+/// Returns the form of the link whose text is between `text_start` and the `]` at `close_bracket`
+fn link_kind(
+    p: &MarkdownParser,
+    text_start: usize,
+    close_bracket: usize,
+    scan_end: usize,
+) -> Option<LinkKind> {
+    let text = p.text();
+    let after = close_bracket + 1;
+    if after < scan_end
+        && text.as_bytes()[after] == b'('
+        && let Some(close_paren) = destination_end(text, after + 1, scan_end)
+    {
+        return Some(LinkKind::Inline { close_paren });
+    }
+
+    let link_text = &text[text_start..close_bracket];
+    if after < scan_end
+        && text.as_bytes()[after] == b'['
+        && let Some(label_end) = bracket_end(text, after + 1, scan_end)
+    {
+        let label = &text[after + 1..label_end];
+        let is_defined = if label.trim().is_empty() {
+            p.is_link_label_defined(link_text)
+        } else {
+            p.is_link_label_defined(label)
+        };
+        if is_defined {
+            return Some(LinkKind::Reference {
+                label_end: Some(label_end),
+            });
+        }
+    }
+
+    if p.is_link_label_defined(link_text) {
+        return Some(LinkKind::Reference { label_end: None });
+    }
+
+    None
+}
+
+/// Returns the offset of the `]` that matches a `[` before `from`.
+///
+/// Brackets can be nested, and the ones that are escaped or in a code span are skipped.
+fn bracket_end(text: &str, from: usize, scan_end: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    let mut position = from;
+    while position < scan_end {
+        match bytes[position] {
+            b'\\' => {
+                position += escape_len(bytes, position);
+                continue;
+            }
+            b'`' => {
+                let run_len = bytes[position..]
+                    .iter()
+                    .take_while(|byte| **byte == b'`')
+                    .count();
+                position = code_span_end(text, position + run_len, run_len, scan_end)
+                    .map_or(position + run_len, |close| close + run_len);
+                continue;
+            }
+            b'[' => depth += 1,
+            b']' => {
+                if depth == 0 {
+                    return Some(position);
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+        position += 1;
+    }
+    None
+}
+
+/// Returns the offset of the `)` that ends the destination and the optional title of an inline link
+///
+/// See <https://spec.commonmark.org/0.31.2/#link-destination> and <https://spec.commonmark.org/0.31.2/#link-title>
+fn destination_end(text: &str, from: usize, scan_end: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut position = skip_whitespace(bytes, from, scan_end);
+
+    if bytes.get(position) == Some(&b'<') {
+        position += 1;
+        loop {
+            match bytes.get(position)? {
+                b'>' => break,
+                b'<' | b'\n' | b'\r' => return None,
+                b'\\' => position += escape_len(bytes, position),
+                _ => position += 1,
+            }
+        }
+        position += 1;
+    } else {
+        let mut depth = 0usize;
+        while position < scan_end {
+            match bytes[position] {
+                b'\\' => {
+                    position += escape_len(bytes, position);
+                    continue;
+                }
+                b'(' => depth += 1,
+                b')' if depth == 0 => break,
+                b')' => depth -= 1,
+                byte if byte.is_ascii_whitespace() || byte.is_ascii_control() => break,
+                _ => {}
+            }
+            position += 1;
+        }
+    }
+
+    let before_title = position;
+    position = skip_whitespace(bytes, position, scan_end);
+    if position > before_title {
+        let closing_quote = match bytes.get(position) {
+            Some(b'"') => Some(b'"'),
+            Some(b'\'') => Some(b'\''),
+            Some(b'(') => Some(b')'),
+            _ => None,
+        };
+        if let Some(closing_quote) = closing_quote {
+            position += 1;
+            while position < scan_end && bytes[position] != closing_quote {
+                position += if bytes[position] == b'\\' {
+                    escape_len(bytes, position)
+                } else {
+                    1
+                };
+            }
+            position = skip_whitespace(bytes, position + 1, scan_end);
+        }
+    }
+
+    (position < scan_end && bytes[position] == b')').then_some(position)
+}
+
+fn skip_whitespace(bytes: &[u8], from: usize, scan_end: usize) -> usize {
+    from + bytes[from..scan_end.max(from)]
+        .iter()
+        .take_while(|byte| byte.is_ascii_whitespace())
+        .count()
+}
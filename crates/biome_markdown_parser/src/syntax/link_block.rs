@@ -0,0 +1,47 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::block_indent;
+use crate::syntax::inline::{InlineContext, parse_inline_item_list};
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+
+/// Returns `true` if the current line is a [link reference definition](https://spec.commonmark.org/0.31.2/#link-reference-definitions)
+/// such as `[label]: /url "title"`. Only definitions that fit on a single line are recognized.
+pub(crate) fn at_link_block(p: &MarkdownParser) -> bool {
+    p.at(T!['[']) && block_indent(p) < 4 && label_end(p.rest_of_line()).is_some()
+}
+
+/// Returns the offset of the `]` of the label, relative to the start of the line,
+/// when the label is followed by a `:` and a destination.
+pub(crate) fn label_end(line: &str) -> Option<usize> {
+    let bytes = line.as_bytes();
+    let mut position = 1;
+    while position < bytes.len() {
+        match bytes[position] {
+            b'\\' => position += 1,
+            b'[' => return None,
+            b']' => break,
+            _ => {}
+        }
+        position += 1;
+    }
+    let label = line.get(1..position)?;
+    let destination = line.get(position + 1..)?.strip_prefix(':')?;
+    (!label.trim().is_empty() && label.len() <= 999 && !destination.trim().is_empty())
+        .then_some(position)
+}
+
+pub(crate) fn parse_link_block(p: &mut MarkdownParser) {
+    let label_end = p.cur_offset() + label_end(p.rest_of_line()).unwrap_or_default();
+    let m = p.start();
+
+    p.bump(T!['[']);
+    parse_inline_item_list(
+        p,
+        InlineContext::single_line(p).with_end(label_end).verbatim(),
+    );
+    p.expect(T![']']);
+    p.expect(T![:]);
+    parse_inline_item_list(p, InlineContext::single_line(p).verbatim());
+
+    m.complete(p, MD_LINK_BLOCK);
+}
@@ -0,0 +1,143 @@
+use crate::parser::{Container, LinePrefixes, MarkdownParser};
+use crate::syntax::inline::parse_textual;
+use crate::syntax::{block_indent, current_line_prefixes, parse_block_list};
+use biome_markdown_syntax::{MarkdownSyntaxKind, T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+
+/// Returns `true` if the current token is a [bullet list marker](https://spec.commonmark.org/0.31.2/#bullet-list-marker)
+/// followed by a whitespace or the end of the line.
+///
+/// An empty list item can't interrupt a paragraph.
+pub(crate) fn at_bullet_list_item(p: &MarkdownParser, interrupts_paragraph: bool) -> bool {
+    if !matches!(p.cur(), T![-] | T![+] | T![*]) || block_indent(p) >= 4 {
+        return false;
+    }
+    let rest = &p.rest_of_line()[1..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return false;
+    }
+    !interrupts_paragraph || !rest.trim().is_empty()
+}
+
+/// Returns `true` if the current token is an [ordered list marker](https://spec.commonmark.org/0.31.2/#ordered-list-marker).
+///
+/// Only a non-empty list item that starts with `1` can interrupt a paragraph.
+pub(crate) fn at_order_list_item(p: &MarkdownParser, interrupts_paragraph: bool) -> bool {
+    if !p.at(MD_ORDERED_LIST_MARKER_LITERAL) || block_indent(p) >= 4 {
+        return false;
+    }
+    if !interrupts_paragraph {
+        return true;
+    }
+    let marker = p.cur_text();
+    marker[..marker.len() - 1].parse::<u32>() == Ok(1)
+        && !p.rest_of_line()[marker.len()..].trim().is_empty()
+}
+
+pub(crate) fn parse_bullet_list(p: &mut MarkdownParser) {
+    let marker = p.cur();
+    let m = p.start();
+    let list = p.start();
+
+    loop {
+        parse_list_item(p, MD_BULLET);
+        if !at_next_list_item(p) || !p.at(marker) || !at_bullet_list_item(p, false) {
+            break;
+        }
+    }
+
+    list.complete(p, MD_BULLET_LIST);
+    m.complete(p, MD_BULLET_LIST_ITEM);
+}
+
+pub(crate) fn parse_order_list(p: &mut MarkdownParser) {
+    let delimiter = ordered_list_delimiter(p);
+    let m = p.start();
+    let list = p.start();
+
+    loop {
+        parse_list_item(p, MD_ORDER_BULLET);
+        if !at_next_list_item(p)
+            || !at_order_list_item(p, false)
+            || ordered_list_delimiter(p) != delimiter
+        {
+            break;
+        }
+    }
+
+    list.complete(p, MD_ORDER_LIST);
+    m.complete(p, MD_ORDER_LIST_ITEM);
+}
+
+/// Returns the `.` or `)` that ends an ordered list marker
+fn ordered_list_delimiter(p: &MarkdownParser) -> Option<char> {
+    p.cur_text().chars().next_back()
+}
+
+/// Returns `true` if the previous list item ended at the start of a line that continues the containers of the list
+fn at_next_list_item(p: &MarkdownParser) -> bool {
+    let depth = p.state().containers.len();
+    current_line_prefixes(p)
+        .is_some_and(|prefixes| prefixes.matched >= depth && p.is_on_line(prefixes.line_start))
+}
+
+/// Parses a [list item](https://spec.commonmark.org/0.31.2/#list-items). The content of the item
+/// starts at the column of its first character, unless it's an indented code block.
+fn parse_list_item(p: &mut MarkdownParser, kind: MarkdownSyntaxKind) {
+    let line_start = p.line_start_at(p.cur_offset());
+    let marker_column = p.column_at(p.cur_end());
+    let m = p.start();
+    p.bump_any();
+
+    let content_column = if p.is_on_line(line_start) {
+        let column = p.cur_column();
+        if column - marker_column <= 4 {
+            column
+        } else {
+            marker_column + 1
+        }
+    } else {
+        marker_column + 1
+    };
+
+    let has_checkbox = p.is_on_line(line_start) && at_task_list_checkbox(p);
+    if has_checkbox {
+        parse_task_list_checkbox(p);
+    }
+
+    p.state_mut()
+        .containers
+        .push(Container::ListItem { content_column });
+    if has_checkbox {
+        // The content on the line of the checkbox starts after the checkbox
+        let prefixes = LinePrefixes {
+            line_start,
+            matched: p.state().containers.len(),
+            content_column: p.cur_column(),
+        };
+        p.state_mut().line = Some(prefixes);
+    }
+    parse_block_list(p);
+    p.state_mut().containers.pop();
+
+    m.complete(p, kind);
+}
+
+/// Returns `true` if the current token starts a [task list item marker](https://github.github.com/gfm/#task-list-items-extension-):
+/// `[ ]`, `[x]` or `[X]` followed by a whitespace.
+fn at_task_list_checkbox(p: &MarkdownParser) -> bool {
+    let line = p.rest_of_line();
+    p.at(T!['['])
+        && (line.starts_with("[ ]") || line.starts_with("[x]") || line.starts_with("[X]"))
+        && line[3..].starts_with([' ', '\t'])
+}
+
+fn parse_task_list_checkbox(p: &mut MarkdownParser) {
+    let m = p.start();
+    p.bump(T!['[']);
+    if !p.at(T![']']) {
+        parse_textual(p);
+    }
+    p.expect(T![']']);
+    m.complete(p, MD_TASK_LIST_CHECKBOX);
+}
@@ -0,0 +1,18 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::inline::{InlineContext, InlineListEnd, parse_inline_item_list};
+use biome_markdown_syntax::kind::MarkdownSyntaxKind::*;
+use biome_parser::Parser;
+
+/// Parses a [paragraph](https://spec.commonmark.org/0.31.2/#paragraphs), or a
+/// [setext heading](https://spec.commonmark.org/0.31.2/#setext-headings) when the paragraph is followed by an underline.
+pub(crate) fn parse_paragraph(p: &mut MarkdownParser) {
+    let m = p.start();
+    let end = parse_inline_item_list(p, InlineContext::paragraph(p));
+    let paragraph = m.complete(p, MD_PARAGRAPH);
+
+    if end == InlineListEnd::SetextUnderline {
+        let header = paragraph.precede(p);
+        p.bump_remap(MD_SETEXT_UNDERLINE_LITERAL);
+        header.complete(p, MD_SETEXT_HEADER);
+    }
+}
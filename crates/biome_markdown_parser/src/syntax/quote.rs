@@ -0,0 +1,38 @@
+use crate::parser::{Container, MarkdownParser};
+use crate::syntax::{block_indent, parse_block_list};
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+
+pub(crate) fn at_quote(p: &MarkdownParser) -> bool {
+    p.at(T![>]) && block_indent(p) < 4
+}
+
+/// Parses a [block quote](https://spec.commonmark.org/0.31.2/#block-quotes)
+pub(crate) fn parse_quote(p: &mut MarkdownParser) {
+    let m = p.start();
+    let content_column = parse_quote_prefix(p);
+
+    p.state_mut()
+        .containers
+        .push(Container::Quote { content_column });
+    parse_block_list(p);
+    p.state_mut().containers.pop();
+
+    m.complete(p, MD_QUOTE);
+}
+
+/// Parses the `>` marker of a block quote and returns the column where the content of the quote starts.
+///
+/// The content starts after the space that optionally follows the marker.
+pub(crate) fn parse_quote_prefix(p: &mut MarkdownParser) -> usize {
+    let marker_end = p.cur_end();
+    let m = p.start();
+    p.bump(T![>]);
+    m.complete(p, MD_QUOTE_PREFIX);
+
+    let column = p.column_at(marker_end);
+    match p.text().as_bytes().get(marker_end) {
+        Some(b' ' | b'\t') => column + 1,
+        _ => column,
+    }
+}
@@ -0,0 +1,135 @@
+use crate::parser::{Container, MarkdownParser};
+use crate::syntax::inline::{InlineContext, parse_inline_item_list};
+use crate::syntax::{at_paragraph_interrupt, block_indent, is_at_new_line, parse_line_prefixes};
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+
+/// Returns `true` if the current line is the header row of a [table](https://github.github.com/gfm/#tables-extension-):
+/// a line with pipes, followed by a delimiter row with the same number of cells.
+///
+/// Tables aren't supported in block quotes.
+pub(crate) fn at_table(p: &MarkdownParser) -> bool {
+    if block_indent(p) >= 4
+        || p.state()
+            .containers
+            .iter()
+            .any(|container| matches!(container, Container::Quote { .. }))
+    {
+        return false;
+    }
+
+    let line = p.rest_of_line();
+    if !line.contains('|') {
+        return false;
+    }
+
+    let line_end = p.line_end_at(p.cur_offset());
+    let text = p.text();
+    let next_line_start = if text[line_end..].starts_with("\r\n") {
+        line_end + 2
+    } else {
+        line_end + 1
+    };
+    if next_line_start > text.len() {
+        return false;
+    }
+    let next_line = &text[next_line_start..p.line_end_at(next_line_start)];
+
+    is_delimiter_row(next_line) && split_cells(next_line).len() == split_cells(line).len()
+}
+
+/// Returns `true` if the line only contains cells made of `-`, optionally surrounded by `:`
+fn is_delimiter_row(line: &str) -> bool {
+    line.contains('|')
+        && split_cells(line).iter().all(|cell| {
+            let cell = cell.trim();
+            let dashes = cell.strip_prefix(':').unwrap_or(cell);
+            let dashes = dashes.strip_suffix(':').unwrap_or(dashes);
+            !dashes.is_empty() && dashes.bytes().all(|byte| byte == b'-')
+        })
+}
+
+/// Splits a row into cells at the pipes that aren't escaped. The leading and the trailing pipes are optional.
+fn split_cells(line: &str) -> Vec<&str> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = if line.ends_with('|') && !line.ends_with("\\|") {
+        &line[..line.len() - 1]
+    } else {
+        line
+    };
+
+    let bytes = line.as_bytes();
+    let mut cells = Vec::new();
+    let mut cell_start = 0;
+    let mut position = 0;
+    while position < bytes.len() {
+        match bytes[position] {
+            b'\\' => position += 1,
+            b'|' => {
+                cells.push(&line[cell_start..position]);
+                cell_start = position + 1;
+            }
+            _ => {}
+        }
+        position += 1;
+    }
+    cells.push(&line[cell_start.min(line.len())..]);
+    cells
+}
+
+pub(crate) fn parse_table(p: &mut MarkdownParser) {
+    let m = p.start();
+
+    parse_table_row(p);
+    parse_line_prefixes(p);
+    parse_table_row(p);
+
+    let rows = p.start();
+    while at_table_row(p) {
+        parse_line_prefixes(p);
+        parse_table_row(p);
+    }
+    rows.complete(p, MD_TABLE_ROW_LIST);
+
+    m.complete(p, MD_TABLE);
+}
+
+/// Returns `true` if the current line continues the table: the table ends at a blank line,
+/// or at the start of another block.
+fn at_table_row(p: &mut MarkdownParser) -> bool {
+    if !is_at_new_line(p) || p.has_blank_line_before() {
+        return false;
+    }
+
+    let checkpoint = p.checkpoint();
+    let depth = p.state().containers.len();
+    let prefixes = parse_line_prefixes(p);
+    let result = prefixes.matched == depth
+        && p.is_on_line(prefixes.line_start)
+        && (block_indent(p) >= 4 || !at_paragraph_interrupt(p));
+    p.rewind(checkpoint);
+    result
+}
+
+fn parse_table_row(p: &mut MarkdownParser) {
+    let line_start = p.line_start_at(p.cur_offset());
+    let m = p.start();
+
+    if p.at(T![|]) {
+        p.bump(T![|]);
+    }
+
+    let cells = p.start();
+    while p.is_on_line(line_start) {
+        let cell = p.start();
+        parse_inline_item_list(p, InlineContext::table_cell(p));
+        if p.at(T![|]) && p.is_on_line(line_start) {
+            p.bump(T![|]);
+        }
+        cell.complete(p, MD_TABLE_CELL);
+    }
+    cells.complete(p, MD_TABLE_CELL_LIST);
+
+    m.complete(p, MD_TABLE_ROW);
+}
@@ -0,0 +1,49 @@
+use crate::{MdFencedCodeBlock, MdQuote};
+use biome_rowan::{AstNode, AstNodeList, TextRange};
+
+impl MdFencedCodeBlock {
+    /// Returns the language of the code block, which is the first word of its info string.
+    ///
+    /// ````markdown
+    /// ```js title="example.js"
+    /// ```
+    /// ````
+    ///
+    /// The language of this code block is `js`.
+    pub fn language(&self) -> Option<String> {
+        let info = self.info()?.value_token().ok()?;
+        let language = info.text_trimmed().split_whitespace().next()?;
+        Some(language.to_string())
+    }
+
+    /// Returns the range of the code of the block: from the start of the line that follows
+    /// the opening fence, to the end of the last line of code.
+    ///
+    /// Returns `None` if the code block is empty.
+    pub fn code_range(&self) -> Option<TextRange> {
+        let content = self.content();
+        let first = content.first()?.syntax().first_token()?;
+        let last = content.last()?.syntax().last_token()?;
+
+        // The line break that ends the line of the opening fence is the first piece
+        // of the leading trivia of the first token of the code
+        let mut start = first.text_range().start();
+        for piece in first.leading_trivia().pieces() {
+            start += piece.text_len();
+            if piece.is_newline() {
+                break;
+            }
+        }
+        let end = last.text_trimmed_range().end();
+        (start < end).then(|| TextRange::new(start, end))
+    }
+
+    /// Returns `true` if the code block is inside a block quote, whose `>` markers
+    /// are repeated on every line of the code.
+    pub fn is_in_quote(&self) -> bool {
+        self.syntax()
+            .ancestors()
+            .skip(1)
+            .any(|node| MdQuote::can_cast(node.kind()))
+    }
+}
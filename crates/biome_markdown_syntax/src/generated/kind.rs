@@ -18,28 +18,24 @@ pub enum MarkdownSyntaxKind {
     R_PAREN,
     L_BRACK,
     R_BRACK,
-    SLASH,
-    EQ,
     BANG,
     MINUS,
+    PLUS,
     STAR,
     DOUBLE_STAR,
-    BACKTICK,
-    TRIPLE_BACKTICK,
-    TILDE,
-    WHITESPACE3,
     UNDERSCORE,
     DOUBLE_UNDERSCORE,
     HASH,
-    COMMA,
+    PIPE,
+    COLON,
     NULL_KW,
     MD_HARD_LINE_LITERAL,
-    MD_SOFT_BREAK_LITERAL,
     MD_TEXTUAL_LITERAL,
-    MD_STRING_LITERAL,
-    MD_INDENT_CHUNK_LITERAL,
     MD_THEMATIC_BREAK_LITERAL,
-    MD_ERROR_LITERAL,
+    MD_SETEXT_UNDERLINE_LITERAL,
+    MD_ORDERED_LIST_MARKER_LITERAL,
+    MD_BACKTICKS_LITERAL,
+    MD_TILDES_LITERAL,
     ERROR_TOKEN,
     NEWLINE,
     WHITESPACE,
@@ -48,39 +44,44 @@ pub enum MarkdownSyntaxKind {
     MD_BOGUS,
     MD_DOCUMENT,
     MD_BLOCK_LIST,
+    MD_THEMATIC_BREAK_BLOCK,
     MD_HASH_LIST,
     MD_HASH,
     MD_HEADER,
+    MD_SETEXT_HEADER,
     MD_INDENT_CODE_BLOCK,
     MD_FENCED_CODE_BLOCK,
-    MD_CODE_NAME_LIST,
     MD_HTML_BLOCK,
     MD_LINK_BLOCK,
+    MD_TABLE,
+    MD_TABLE_ROW_LIST,
+    MD_TABLE_ROW,
+    MD_TABLE_CELL_LIST,
+    MD_TABLE_CELL,
     MD_QUOTE,
-    MD_ORDER_LIST_ITEM,
+    MD_QUOTE_PREFIX,
     MD_BULLET_LIST_ITEM,
     MD_BULLET_LIST,
+    MD_BULLET,
+    MD_ORDER_LIST_ITEM,
     MD_ORDER_LIST,
+    MD_ORDER_BULLET,
+    MD_TASK_LIST_CHECKBOX,
     MD_PARAGRAPH,
     MD_INLINE_ITEM_LIST,
     MD_INLINE_EMPHASIS,
     MD_INLINE_ITALIC,
+    MD_INLINE_STRIKETHROUGH,
     MD_INLINE_CODE,
-    MD_BULLET,
     MD_INLINE_LINK,
+    MD_REFERENCE_LINK,
+    MD_REFERENCE_LINK_LABEL,
     MD_INLINE_IMAGE,
-    MD_INLINE_IMAGE_ALT,
-    MD_INDENTED_CODE_LINE,
-    MD_INLINE_IMAGE_LINK,
-    MD_INLINE_IMAGE_SOURCE,
-    MD_INDENTED_CODE_LINE_LIST,
+    MD_REFERENCE_IMAGE,
+    MD_AUTOLINK,
+    MD_INLINE_HTML,
     MD_HARD_LINE,
-    MD_SOFT_BREAK,
     MD_TEXTUAL,
-    MD_SETEXT_HEADER,
-    MD_STRING,
-    MD_INDENT,
-    MD_THEMATIC_BREAK_BLOCK,
     #[doc(hidden)]
     __LAST,
 }
@@ -95,32 +96,28 @@ impl MarkdownSyntaxKind {
                 | R_PAREN
                 | L_BRACK
                 | R_BRACK
-                | SLASH
-                | EQ
                 | BANG
                 | MINUS
+                | PLUS
                 | STAR
                 | DOUBLE_STAR
-                | BACKTICK
-                | TRIPLE_BACKTICK
-                | TILDE
-                | WHITESPACE3
                 | UNDERSCORE
                 | DOUBLE_UNDERSCORE
                 | HASH
-                | COMMA
+                | PIPE
+                | COLON
         )
     }
     pub const fn is_literal(self) -> bool {
         matches!(
             self,
             MD_HARD_LINE_LITERAL
-                | MD_SOFT_BREAK_LITERAL
                 | MD_TEXTUAL_LITERAL
-                | MD_STRING_LITERAL
-                | MD_INDENT_CHUNK_LITERAL
                 | MD_THEMATIC_BREAK_LITERAL
-                | MD_ERROR_LITERAL
+                | MD_SETEXT_UNDERLINE_LITERAL
+                | MD_ORDERED_LIST_MARKER_LITERAL
+                | MD_BACKTICKS_LITERAL
+                | MD_TILDES_LITERAL
         )
     }
     pub const fn is_list(self) -> bool {
@@ -128,11 +125,11 @@ impl MarkdownSyntaxKind {
             self,
             MD_BLOCK_LIST
                 | MD_HASH_LIST
-                | MD_CODE_NAME_LIST
+                | MD_TABLE_ROW_LIST
+                | MD_TABLE_CELL_LIST
                 | MD_BULLET_LIST
                 | MD_ORDER_LIST
                 | MD_INLINE_ITEM_LIST
-                | MD_INDENTED_CODE_LINE_LIST
         )
     }
     pub fn from_keyword(ident: &str) -> Option<Self> {
@@ -150,20 +147,16 @@ impl MarkdownSyntaxKind {
             R_PAREN => ")",
             L_BRACK => "[",
             R_BRACK => "]",
-            SLASH => "/",
-            EQ => "=",
             BANG => "!",
             MINUS => "-",
+            PLUS => "+",
             STAR => "*",
             DOUBLE_STAR => "**",
-            BACKTICK => "`",
-            TRIPLE_BACKTICK => "```",
-            TILDE => "~",
-            WHITESPACE3 => "   ",
             UNDERSCORE => "_",
             DOUBLE_UNDERSCORE => "__",
             HASH => "#",
-            COMMA => ",",
+            PIPE => "|",
+            COLON => ":",
             NULL_KW => "null",
             EOF => "EOF",
             _ => return None,
@@ -173,4 +166,4 @@ impl MarkdownSyntaxKind {
 }
 #[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
 #[macro_export]
-macro_rules ! T { [<] => { $ crate :: MarkdownSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: MarkdownSyntaxKind :: R_ANGLE } ; ['('] => { $ crate :: MarkdownSyntaxKind :: L_PAREN } ; [')'] => { $ crate :: MarkdownSyntaxKind :: R_PAREN } ; ['['] => { $ crate :: MarkdownSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: MarkdownSyntaxKind :: R_BRACK } ; [/] => { $ crate :: MarkdownSyntaxKind :: SLASH } ; [=] => { $ crate :: MarkdownSyntaxKind :: EQ } ; [!] => { $ crate :: MarkdownSyntaxKind :: BANG } ; [-] => { $ crate :: MarkdownSyntaxKind :: MINUS } ; [*] => { $ crate :: MarkdownSyntaxKind :: STAR } ; [**] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_STAR } ; ['`'] => { $ crate :: MarkdownSyntaxKind :: BACKTICK } ; ["```"] => { $ crate :: MarkdownSyntaxKind :: TRIPLE_BACKTICK } ; [~] => { $ crate :: MarkdownSyntaxKind :: TILDE } ; ["   "] => { $ crate :: MarkdownSyntaxKind :: WHITESPACE3 } ; ["_"] => { $ crate :: MarkdownSyntaxKind :: UNDERSCORE } ; ["__"] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_UNDERSCORE } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; [,] => { $ crate :: MarkdownSyntaxKind :: COMMA } ; [null] => { $ crate :: MarkdownSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: MarkdownSyntaxKind :: IDENT } ; [EOF] => { $ crate :: MarkdownSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: MarkdownSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; }
+macro_rules ! T { [<] => { $ crate :: MarkdownSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: MarkdownSyntaxKind :: R_ANGLE } ; ['('] => { $ crate :: MarkdownSyntaxKind :: L_PAREN } ; [')'] => { $ crate :: MarkdownSyntaxKind :: R_PAREN } ; ['['] => { $ crate :: MarkdownSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: MarkdownSyntaxKind :: R_BRACK } ; [!] => { $ crate :: MarkdownSyntaxKind :: BANG } ; [-] => { $ crate :: MarkdownSyntaxKind :: MINUS } ; [+] => { $ crate :: MarkdownSyntaxKind :: PLUS } ; [*] => { $ crate :: MarkdownSyntaxKind :: STAR } ; [**] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_STAR } ; ["_"] => { $ crate :: MarkdownSyntaxKind :: UNDERSCORE } ; ["__"] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_UNDERSCORE } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; [|] => { $ crate :: MarkdownSyntaxKind :: PIPE } ; [:] => { $ crate :: MarkdownSyntaxKind :: COLON } ; [null] => { $ crate :: MarkdownSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: MarkdownSyntaxKind :: IDENT } ; [EOF] => { $ crate :: MarkdownSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: MarkdownSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; }
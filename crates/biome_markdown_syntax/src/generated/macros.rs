@@ -16,6 +16,10 @@ macro_rules! map_syntax_node {
     ($ node : expr , $ pattern : pat => $ body : expr) => {
         match $node {
             node => match $crate::MarkdownSyntaxNode::kind(&node) {
+                $crate::MarkdownSyntaxKind::MD_AUTOLINK => {
+                    let $pattern = unsafe { $crate::MdAutolink::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_BULLET => {
                     let $pattern = unsafe { $crate::MdBullet::new_unchecked(node) };
                     $body
@@ -48,18 +52,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdHtmlBlock::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INDENT => {
-                    let $pattern = unsafe { $crate::MdIndent::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_INDENT_CODE_BLOCK => {
                     let $pattern = unsafe { $crate::MdIndentCodeBlock::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INDENTED_CODE_LINE => {
-                    let $pattern = unsafe { $crate::MdIndentedCodeLine::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_INLINE_CODE => {
                     let $pattern = unsafe { $crate::MdInlineCode::new_unchecked(node) };
                     $body
@@ -68,20 +64,12 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdInlineEmphasis::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE => {
-                    let $pattern = unsafe { $crate::MdInlineImage::new_unchecked(node) };
-                    $body
-                }
-                $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE_ALT => {
-                    let $pattern = unsafe { $crate::MdInlineImageAlt::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_INLINE_HTML => {
+                    let $pattern = unsafe { $crate::MdInlineHtml::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE_LINK => {
-                    let $pattern = unsafe { $crate::MdInlineImageLink::new_unchecked(node) };
-                    $body
-                }
-                $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE_SOURCE => {
-                    let $pattern = unsafe { $crate::MdInlineImageSource::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE => {
+                    let $pattern = unsafe { $crate::MdInlineImage::new_unchecked(node) };
                     $body
                 }
                 $crate::MarkdownSyntaxKind::MD_INLINE_ITALIC => {
@@ -92,10 +80,18 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdInlineLink::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_INLINE_STRIKETHROUGH => {
+                    let $pattern = unsafe { $crate::MdInlineStrikethrough::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_LINK_BLOCK => {
                     let $pattern = unsafe { $crate::MdLinkBlock::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_ORDER_BULLET => {
+                    let $pattern = unsafe { $crate::MdOrderBullet::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_ORDER_LIST_ITEM => {
                     let $pattern = unsafe { $crate::MdOrderListItem::new_unchecked(node) };
                     $body
@@ -108,12 +104,40 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdQuote::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_QUOTE_PREFIX => {
+                    let $pattern = unsafe { $crate::MdQuotePrefix::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_REFERENCE_IMAGE => {
+                    let $pattern = unsafe { $crate::MdReferenceImage::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_REFERENCE_LINK => {
+                    let $pattern = unsafe { $crate::MdReferenceLink::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_REFERENCE_LINK_LABEL => {
+                    let $pattern = unsafe { $crate::MdReferenceLinkLabel::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_SETEXT_HEADER => {
                     let $pattern = unsafe { $crate::MdSetextHeader::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_SOFT_BREAK => {
-                    let $pattern = unsafe { $crate::MdSoftBreak::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_TABLE => {
+                    let $pattern = unsafe { $crate::MdTable::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_CELL => {
+                    let $pattern = unsafe { $crate::MdTableCell::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_ROW => {
+                    let $pattern = unsafe { $crate::MdTableRow::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TASK_LIST_CHECKBOX => {
+                    let $pattern = unsafe { $crate::MdTaskListCheckbox::new_unchecked(node) };
                     $body
                 }
                 $crate::MarkdownSyntaxKind::MD_TEXTUAL => {
@@ -136,18 +160,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdBulletList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_CODE_NAME_LIST => {
-                    let $pattern = unsafe { $crate::MdCodeNameList::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_HASH_LIST => {
                     let $pattern = unsafe { $crate::MdHashList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INDENTED_CODE_LINE_LIST => {
-                    let $pattern = unsafe { $crate::MdIndentedCodeLineList::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_INLINE_ITEM_LIST => {
                     let $pattern = unsafe { $crate::MdInlineItemList::new_unchecked(node) };
                     $body
@@ -156,6 +172,14 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdOrderList::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_TABLE_CELL_LIST => {
+                    let $pattern = unsafe { $crate::MdTableCellList::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_ROW_LIST => {
+                    let $pattern = unsafe { $crate::MdTableRowList::new_unchecked(node) };
+                    $body
+                }
                 _ => unreachable!(),
             },
         }
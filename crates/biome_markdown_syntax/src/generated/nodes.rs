@@ -21,6 +21,51 @@ use std::fmt::{Debug, Formatter};
 #[doc = r" the slots are not statically known."]
 pub(crate) const SLOT_MAP_EMPTY_VALUE: u8 = u8::MAX;
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdAutolink {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdAutolink {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdAutolinkFields {
+        MdAutolinkFields {
+            l_angle_token: self.l_angle_token(),
+            value: self.value(),
+            r_angle_token: self.r_angle_token(),
+        }
+    }
+    pub fn l_angle_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn value(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_angle_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for MdAutolink {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdAutolinkFields {
+    pub l_angle_token: SyntaxResult<SyntaxToken>,
+    pub value: MdInlineItemList,
+    pub r_angle_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdBullet {
     pub(crate) syntax: SyntaxNode,
 }
@@ -37,17 +82,17 @@ impl MdBullet {
     pub fn as_fields(&self) -> MdBulletFields {
         MdBulletFields {
             bullet: self.bullet(),
-            space_token: self.space_token(),
+            checkbox: self.checkbox(),
             content: self.content(),
         }
     }
     pub fn bullet(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn space_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
+    pub fn checkbox(&self) -> Option<MdTaskListCheckbox> {
+        support::node(&self.syntax, 1usize)
     }
-    pub fn content(&self) -> MdInlineItemList {
+    pub fn content(&self) -> MdBlockList {
         support::list(&self.syntax, 2usize)
     }
 }
@@ -62,8 +107,8 @@ impl Serialize for MdBullet {
 #[derive(Serialize)]
 pub struct MdBulletFields {
     pub bullet: SyntaxResult<SyntaxToken>,
-    pub space_token: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
+    pub checkbox: Option<MdTaskListCheckbox>,
+    pub content: MdBlockList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdBulletListItem {
@@ -161,31 +206,23 @@ impl MdFencedCodeBlock {
     }
     pub fn as_fields(&self) -> MdFencedCodeBlockFields {
         MdFencedCodeBlockFields {
-            l_fence_token: self.l_fence_token(),
-            code_list: self.code_list(),
-            l_hard_line: self.l_hard_line(),
+            l_fence: self.l_fence(),
+            info: self.info(),
             content: self.content(),
-            r_hard_line: self.r_hard_line(),
-            r_fence_token: self.r_fence_token(),
+            r_fence: self.r_fence(),
         }
     }
-    pub fn l_fence_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_fence(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn code_list(&self) -> MdCodeNameList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn l_hard_line(&self) -> SyntaxResult<MdHardLine> {
-        support::required_node(&self.syntax, 2usize)
-    }
-    pub fn content(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 3usize)
+    pub fn info(&self) -> Option<MdTextual> {
+        support::node(&self.syntax, 1usize)
     }
-    pub fn r_hard_line(&self) -> SyntaxResult<MdHardLine> {
-        support::required_node(&self.syntax, 4usize)
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 2usize)
     }
-    pub fn r_fence_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 5usize)
+    pub fn r_fence(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
     }
 }
 impl Serialize for MdFencedCodeBlock {
@@ -198,12 +235,10 @@ impl Serialize for MdFencedCodeBlock {
 }
 #[derive(Serialize)]
 pub struct MdFencedCodeBlockFields {
-    pub l_fence_token: SyntaxResult<SyntaxToken>,
-    pub code_list: MdCodeNameList,
-    pub l_hard_line: SyntaxResult<MdHardLine>,
-    pub content: SyntaxResult<MdTextual>,
-    pub r_hard_line: SyntaxResult<MdHardLine>,
-    pub r_fence_token: SyntaxResult<SyntaxToken>,
+    pub l_fence: SyntaxResult<SyntaxToken>,
+    pub info: Option<MdTextual>,
+    pub content: MdInlineItemList,
+    pub r_fence: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdHardLine {
@@ -336,11 +371,11 @@ impl MdHtmlBlock {
     }
     pub fn as_fields(&self) -> MdHtmlBlockFields {
         MdHtmlBlockFields {
-            md_textual: self.md_textual(),
+            content: self.content(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 0usize)
     }
 }
 impl Serialize for MdHtmlBlock {
@@ -353,42 +388,7 @@ impl Serialize for MdHtmlBlock {
 }
 #[derive(Serialize)]
 pub struct MdHtmlBlockFields {
-    pub md_textual: SyntaxResult<MdTextual>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdIndent {
-    pub(crate) syntax: SyntaxNode,
-}
-impl MdIndent {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn as_fields(&self) -> MdIndentFields {
-        MdIndentFields {
-            value_token: self.value_token(),
-        }
-    }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-}
-impl Serialize for MdIndent {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[derive(Serialize)]
-pub struct MdIndentFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+    pub content: MdInlineItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdIndentCodeBlock {
@@ -406,10 +406,10 @@ impl MdIndentCodeBlock {
     }
     pub fn as_fields(&self) -> MdIndentCodeBlockFields {
         MdIndentCodeBlockFields {
-            lines: self.lines(),
+            content: self.content(),
         }
     }
-    pub fn lines(&self) -> MdIndentedCodeLineList {
+    pub fn content(&self) -> MdInlineItemList {
         support::list(&self.syntax, 0usize)
     }
 }
@@ -423,47 +423,7 @@ impl Serialize for MdIndentCodeBlock {
 }
 #[derive(Serialize)]
 pub struct MdIndentCodeBlockFields {
-    pub lines: MdIndentedCodeLineList,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdIndentedCodeLine {
-    pub(crate) syntax: SyntaxNode,
-}
-impl MdIndentedCodeLine {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn as_fields(&self) -> MdIndentedCodeLineFields {
-        MdIndentedCodeLineFields {
-            indentation: self.indentation(),
-            content: self.content(),
-        }
-    }
-    pub fn indentation(&self) -> SyntaxResult<MdIndent> {
-        support::required_node(&self.syntax, 0usize)
-    }
-    pub fn content(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
-    }
-}
-impl Serialize for MdIndentedCodeLine {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[derive(Serialize)]
-pub struct MdIndentedCodeLineFields {
-    pub indentation: SyntaxResult<MdIndent>,
-    pub content: SyntaxResult<MdTextual>,
+    pub content: MdInlineItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdInlineCode {
@@ -556,10 +516,10 @@ pub struct MdInlineEmphasisFields {
     pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImage {
+pub struct MdInlineHtml {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineImage {
+impl MdInlineHtml {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -569,36 +529,16 @@ impl MdInlineImage {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineImageFields {
-        MdInlineImageFields {
-            l_brack_token: self.l_brack_token(),
-            excl_token: self.excl_token(),
-            alt: self.alt(),
-            source: self.source(),
-            r_brack_token: self.r_brack_token(),
-            link: self.link(),
+    pub fn as_fields(&self) -> MdInlineHtmlFields {
+        MdInlineHtmlFields {
+            value: self.value(),
         }
     }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn alt(&self) -> SyntaxResult<MdInlineImageAlt> {
-        support::required_node(&self.syntax, 2usize)
-    }
-    pub fn source(&self) -> SyntaxResult<MdInlineImageSource> {
-        support::required_node(&self.syntax, 3usize)
-    }
-    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 4usize)
-    }
-    pub fn link(&self) -> Option<MdInlineImageLink> {
-        support::node(&self.syntax, 5usize)
+    pub fn value(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 0usize)
     }
 }
-impl Serialize for MdInlineImage {
+impl Serialize for MdInlineHtml {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -607,19 +547,14 @@ impl Serialize for MdInlineImage {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineImageFields {
-    pub l_brack_token: SyntaxResult<SyntaxToken>,
-    pub excl_token: SyntaxResult<SyntaxToken>,
-    pub alt: SyntaxResult<MdInlineImageAlt>,
-    pub source: SyntaxResult<MdInlineImageSource>,
-    pub r_brack_token: SyntaxResult<SyntaxToken>,
-    pub link: Option<MdInlineImageLink>,
+pub struct MdInlineHtmlFields {
+    pub value: MdInlineItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImageAlt {
+pub struct MdInlineImage {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineImageAlt {
+impl MdInlineImage {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -629,114 +564,40 @@ impl MdInlineImageAlt {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineImageAltFields {
-        MdInlineImageAltFields {
+    pub fn as_fields(&self) -> MdInlineImageFields {
+        MdInlineImageFields {
+            excl_token: self.excl_token(),
             l_brack_token: self.l_brack_token(),
-            content: self.content(),
+            alt: self.alt(),
             r_brack_token: self.r_brack_token(),
-        }
-    }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-    pub fn content(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
-    }
-}
-impl Serialize for MdInlineImageAlt {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[derive(Serialize)]
-pub struct MdInlineImageAltFields {
-    pub l_brack_token: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
-    pub r_brack_token: SyntaxResult<SyntaxToken>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImageLink {
-    pub(crate) syntax: SyntaxNode,
-}
-impl MdInlineImageLink {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn as_fields(&self) -> MdInlineImageLinkFields {
-        MdInlineImageLinkFields {
             l_paren_token: self.l_paren_token(),
-            content: self.content(),
+            source: self.source(),
             r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn content(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
-    }
-}
-impl Serialize for MdInlineImageLink {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
     }
-}
-#[derive(Serialize)]
-pub struct MdInlineImageLinkFields {
-    pub l_paren_token: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
-    pub r_paren_token: SyntaxResult<SyntaxToken>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImageSource {
-    pub(crate) syntax: SyntaxNode,
-}
-impl MdInlineImageSource {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
+    pub fn alt(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 2usize)
     }
-    pub fn as_fields(&self) -> MdInlineImageSourceFields {
-        MdInlineImageSourceFields {
-            l_paren_token: self.l_paren_token(),
-            content: self.content(),
-            r_paren_token: self.r_paren_token(),
-        }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
     }
     pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
+        support::required_token(&self.syntax, 4usize)
     }
-    pub fn content(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 1usize)
+    pub fn source(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 5usize)
     }
     pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
+        support::required_token(&self.syntax, 6usize)
     }
 }
-impl Serialize for MdInlineImageSource {
+impl Serialize for MdInlineImage {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -745,9 +606,13 @@ impl Serialize for MdInlineImageSource {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineImageSourceFields {
+pub struct MdInlineImageFields {
+    pub excl_token: SyntaxResult<SyntaxToken>,
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub alt: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
     pub l_paren_token: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
+    pub source: MdInlineItemList,
     pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -856,10 +721,10 @@ pub struct MdInlineLinkFields {
     pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdLinkBlock {
+pub struct MdInlineStrikethrough {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdLinkBlock {
+impl MdInlineStrikethrough {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -869,21 +734,74 @@ impl MdLinkBlock {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdLinkBlockFields {
-        MdLinkBlockFields {
-            label: self.label(),
-            url: self.url(),
-            title: self.title(),
+    pub fn as_fields(&self) -> MdInlineStrikethroughFields {
+        MdInlineStrikethroughFields {
+            l_fence_token: self.l_fence_token(),
+            content: self.content(),
+            r_fence_token: self.r_fence_token(),
         }
     }
-    pub fn label(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn l_fence_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn url(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_fence_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for MdInlineStrikethrough {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdInlineStrikethroughFields {
+    pub l_fence_token: SyntaxResult<SyntaxToken>,
+    pub content: MdInlineItemList,
+    pub r_fence_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdLinkBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdLinkBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdLinkBlockFields {
+        MdLinkBlockFields {
+            l_brack_token: self.l_brack_token(),
+            label: self.label(),
+            r_brack_token: self.r_brack_token(),
+            colon_token: self.colon_token(),
+            destination: self.destination(),
+        }
     }
-    pub fn title(&self) -> Option<MdTextual> {
-        support::node(&self.syntax, 2usize)
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn label(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn destination(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 4usize)
     }
 }
 impl Serialize for MdLinkBlock {
@@ -896,9 +814,56 @@ impl Serialize for MdLinkBlock {
 }
 #[derive(Serialize)]
 pub struct MdLinkBlockFields {
-    pub label: SyntaxResult<MdTextual>,
-    pub url: SyntaxResult<MdTextual>,
-    pub title: Option<MdTextual>,
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub label: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub destination: MdInlineItemList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdOrderBullet {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdOrderBullet {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdOrderBulletFields {
+        MdOrderBulletFields {
+            marker_token: self.marker_token(),
+            checkbox: self.checkbox(),
+            content: self.content(),
+        }
+    }
+    pub fn marker_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn checkbox(&self) -> Option<MdTaskListCheckbox> {
+        support::node(&self.syntax, 1usize)
+    }
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 2usize)
+    }
+}
+impl Serialize for MdOrderBullet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdOrderBulletFields {
+    pub marker_token: SyntaxResult<SyntaxToken>,
+    pub checkbox: Option<MdTaskListCheckbox>,
+    pub content: MdBlockList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdOrderListItem {
@@ -916,10 +881,10 @@ impl MdOrderListItem {
     }
     pub fn as_fields(&self) -> MdOrderListItemFields {
         MdOrderListItemFields {
-            md_bullet_list: self.md_bullet_list(),
+            md_order_list: self.md_order_list(),
         }
     }
-    pub fn md_bullet_list(&self) -> MdBulletList {
+    pub fn md_order_list(&self) -> MdOrderList {
         support::list(&self.syntax, 0usize)
     }
 }
@@ -933,7 +898,7 @@ impl Serialize for MdOrderListItem {
 }
 #[derive(Serialize)]
 pub struct MdOrderListItemFields {
-    pub md_bullet_list: MdBulletList,
+    pub md_order_list: MdOrderList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdParagraph {
@@ -950,17 +915,11 @@ impl MdParagraph {
         Self { syntax }
     }
     pub fn as_fields(&self) -> MdParagraphFields {
-        MdParagraphFields {
-            list: self.list(),
-            hard_line: self.hard_line(),
-        }
+        MdParagraphFields { list: self.list() }
     }
     pub fn list(&self) -> MdInlineItemList {
         support::list(&self.syntax, 0usize)
     }
-    pub fn hard_line(&self) -> SyntaxResult<MdHardLine> {
-        support::required_node(&self.syntax, 1usize)
-    }
 }
 impl Serialize for MdParagraph {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -973,7 +932,6 @@ impl Serialize for MdParagraph {
 #[derive(Serialize)]
 pub struct MdParagraphFields {
     pub list: MdInlineItemList,
-    pub hard_line: SyntaxResult<MdHardLine>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdQuote {
@@ -991,12 +949,16 @@ impl MdQuote {
     }
     pub fn as_fields(&self) -> MdQuoteFields {
         MdQuoteFields {
-            any_md_block: self.any_md_block(),
+            prefix: self.prefix(),
+            content: self.content(),
         }
     }
-    pub fn any_md_block(&self) -> SyntaxResult<AnyMdBlock> {
+    pub fn prefix(&self) -> SyntaxResult<MdQuotePrefix> {
         support::required_node(&self.syntax, 0usize)
     }
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 1usize)
+    }
 }
 impl Serialize for MdQuote {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -1008,7 +970,193 @@ impl Serialize for MdQuote {
 }
 #[derive(Serialize)]
 pub struct MdQuoteFields {
-    pub any_md_block: SyntaxResult<AnyMdBlock>,
+    pub prefix: SyntaxResult<MdQuotePrefix>,
+    pub content: MdBlockList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdQuotePrefix {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdQuotePrefix {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdQuotePrefixFields {
+        MdQuotePrefixFields {
+            marker_token: self.marker_token(),
+        }
+    }
+    pub fn marker_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for MdQuotePrefix {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdQuotePrefixFields {
+    pub marker_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdReferenceImage {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdReferenceImage {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdReferenceImageFields {
+        MdReferenceImageFields {
+            excl_token: self.excl_token(),
+            l_brack_token: self.l_brack_token(),
+            alt: self.alt(),
+            r_brack_token: self.r_brack_token(),
+            label: self.label(),
+        }
+    }
+    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn alt(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 2usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn label(&self) -> Option<MdReferenceLinkLabel> {
+        support::node(&self.syntax, 4usize)
+    }
+}
+impl Serialize for MdReferenceImage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdReferenceImageFields {
+    pub excl_token: SyntaxResult<SyntaxToken>,
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub alt: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub label: Option<MdReferenceLinkLabel>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdReferenceLink {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdReferenceLink {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdReferenceLinkFields {
+        MdReferenceLinkFields {
+            l_brack_token: self.l_brack_token(),
+            text: self.text(),
+            r_brack_token: self.r_brack_token(),
+            label: self.label(),
+        }
+    }
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn text(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+    pub fn label(&self) -> Option<MdReferenceLinkLabel> {
+        support::node(&self.syntax, 3usize)
+    }
+}
+impl Serialize for MdReferenceLink {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdReferenceLinkFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub text: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub label: Option<MdReferenceLinkLabel>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdReferenceLinkLabel {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdReferenceLinkLabel {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdReferenceLinkLabelFields {
+        MdReferenceLinkLabelFields {
+            l_brack_token: self.l_brack_token(),
+            label: self.label(),
+            r_brack_token: self.r_brack_token(),
+        }
+    }
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn label(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for MdReferenceLinkLabel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdReferenceLinkLabelFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub label: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdSetextHeader {
@@ -1026,12 +1174,16 @@ impl MdSetextHeader {
     }
     pub fn as_fields(&self) -> MdSetextHeaderFields {
         MdSetextHeaderFields {
-            md_paragraph: self.md_paragraph(),
+            content: self.content(),
+            underline_token: self.underline_token(),
         }
     }
-    pub fn md_paragraph(&self) -> SyntaxResult<MdParagraph> {
+    pub fn content(&self) -> SyntaxResult<MdParagraph> {
         support::required_node(&self.syntax, 0usize)
     }
+    pub fn underline_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
 }
 impl Serialize for MdSetextHeader {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -1043,13 +1195,14 @@ impl Serialize for MdSetextHeader {
 }
 #[derive(Serialize)]
 pub struct MdSetextHeaderFields {
-    pub md_paragraph: SyntaxResult<MdParagraph>,
+    pub content: SyntaxResult<MdParagraph>,
+    pub underline_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdSoftBreak {
+pub struct MdTable {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdSoftBreak {
+impl MdTable {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1059,16 +1212,149 @@ impl MdSoftBreak {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdSoftBreakFields {
-        MdSoftBreakFields {
-            value_token: self.value_token(),
+    pub fn as_fields(&self) -> MdTableFields {
+        MdTableFields {
+            header: self.header(),
+            delimiter: self.delimiter(),
+            rows: self.rows(),
         }
     }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn header(&self) -> SyntaxResult<MdTableRow> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn delimiter(&self) -> SyntaxResult<MdTableRow> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn rows(&self) -> MdTableRowList {
+        support::list(&self.syntax, 2usize)
+    }
+}
+impl Serialize for MdTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableFields {
+    pub header: SyntaxResult<MdTableRow>,
+    pub delimiter: SyntaxResult<MdTableRow>,
+    pub rows: MdTableRowList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableCell {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableCell {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableCellFields {
+        MdTableCellFields {
+            content: self.content(),
+            r_pipe_token: self.r_pipe_token(),
+        }
+    }
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 0usize)
+    }
+    pub fn r_pipe_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
+    }
+}
+impl Serialize for MdTableCell {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableCellFields {
+    pub content: MdInlineItemList,
+    pub r_pipe_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableRow {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableRow {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableRowFields {
+        MdTableRowFields {
+            l_pipe_token: self.l_pipe_token(),
+            cells: self.cells(),
+        }
+    }
+    pub fn l_pipe_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 0usize)
+    }
+    pub fn cells(&self) -> MdTableCellList {
+        support::list(&self.syntax, 1usize)
+    }
+}
+impl Serialize for MdTableRow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableRowFields {
+    pub l_pipe_token: Option<SyntaxToken>,
+    pub cells: MdTableCellList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTaskListCheckbox {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTaskListCheckbox {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTaskListCheckboxFields {
+        MdTaskListCheckboxFields {
+            l_brack_token: self.l_brack_token(),
+            value: self.value(),
+            r_brack_token: self.r_brack_token(),
+        }
+    }
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
+    pub fn value(&self) -> Option<MdTextual> {
+        support::node(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
 }
-impl Serialize for MdSoftBreak {
+impl Serialize for MdTaskListCheckbox {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1077,8 +1363,10 @@ impl Serialize for MdSoftBreak {
     }
 }
 #[derive(Serialize)]
-pub struct MdSoftBreakFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+pub struct MdTaskListCheckboxFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub value: Option<MdTextual>,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdTextual {
@@ -1203,6 +1491,7 @@ pub enum AnyLeafBlock {
     MdLinkBlock(MdLinkBlock),
     MdParagraph(MdParagraph),
     MdSetextHeader(MdSetextHeader),
+    MdTable(MdTable),
     MdThematicBreakBlock(MdThematicBreakBlock),
 }
 impl AnyLeafBlock {
@@ -1242,6 +1531,12 @@ impl AnyLeafBlock {
             _ => None,
         }
     }
+    pub fn as_md_table(&self) -> Option<&MdTable> {
+        match &self {
+            Self::MdTable(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_md_thematic_break_block(&self) -> Option<&MdThematicBreakBlock> {
         match &self {
             Self::MdThematicBreakBlock(item) => Some(item),
@@ -1253,6 +1548,7 @@ impl AnyLeafBlock {
 pub enum AnyMdBlock {
     AnyContainerBlock(AnyContainerBlock),
     AnyLeafBlock(AnyLeafBlock),
+    MdQuotePrefix(MdQuotePrefix),
 }
 impl AnyMdBlock {
     pub fn as_any_container_block(&self) -> Option<&AnyContainerBlock> {
@@ -1267,29 +1563,39 @@ impl AnyMdBlock {
             _ => None,
         }
     }
+    pub fn as_md_quote_prefix(&self) -> Option<&MdQuotePrefix> {
+        match &self {
+            Self::MdQuotePrefix(item) => Some(item),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyMdInline {
+    MdAutolink(MdAutolink),
     MdHardLine(MdHardLine),
-    MdHtmlBlock(MdHtmlBlock),
     MdInlineCode(MdInlineCode),
     MdInlineEmphasis(MdInlineEmphasis),
+    MdInlineHtml(MdInlineHtml),
     MdInlineImage(MdInlineImage),
     MdInlineItalic(MdInlineItalic),
     MdInlineLink(MdInlineLink),
-    MdSoftBreak(MdSoftBreak),
+    MdInlineStrikethrough(MdInlineStrikethrough),
+    MdQuotePrefix(MdQuotePrefix),
+    MdReferenceImage(MdReferenceImage),
+    MdReferenceLink(MdReferenceLink),
     MdTextual(MdTextual),
 }
 impl AnyMdInline {
-    pub fn as_md_hard_line(&self) -> Option<&MdHardLine> {
+    pub fn as_md_autolink(&self) -> Option<&MdAutolink> {
         match &self {
-            Self::MdHardLine(item) => Some(item),
+            Self::MdAutolink(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
+    pub fn as_md_hard_line(&self) -> Option<&MdHardLine> {
         match &self {
-            Self::MdHtmlBlock(item) => Some(item),
+            Self::MdHardLine(item) => Some(item),
             _ => None,
         }
     }
@@ -1305,6 +1611,12 @@ impl AnyMdInline {
             _ => None,
         }
     }
+    pub fn as_md_inline_html(&self) -> Option<&MdInlineHtml> {
+        match &self {
+            Self::MdInlineHtml(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_md_inline_image(&self) -> Option<&MdInlineImage> {
         match &self {
             Self::MdInlineImage(item) => Some(item),
@@ -1323,9 +1635,27 @@ impl AnyMdInline {
             _ => None,
         }
     }
-    pub fn as_md_soft_break(&self) -> Option<&MdSoftBreak> {
+    pub fn as_md_inline_strikethrough(&self) -> Option<&MdInlineStrikethrough> {
+        match &self {
+            Self::MdInlineStrikethrough(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_quote_prefix(&self) -> Option<&MdQuotePrefix> {
+        match &self {
+            Self::MdQuotePrefix(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_reference_image(&self) -> Option<&MdReferenceImage> {
+        match &self {
+            Self::MdReferenceImage(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_reference_link(&self) -> Option<&MdReferenceLink> {
         match &self {
-            Self::MdSoftBreak(item) => Some(item),
+            Self::MdReferenceLink(item) => Some(item),
             _ => None,
         }
     }
@@ -1336,6 +1666,61 @@ impl AnyMdInline {
         }
     }
 }
+impl AstNode for MdAutolink {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_AUTOLINK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_AUTOLINK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdAutolink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdAutolink")
+                .field(
+                    "l_angle_token",
+                    &support::DebugSyntaxResult(self.l_angle_token()),
+                )
+                .field("value", &self.value())
+                .field(
+                    "r_angle_token",
+                    &support::DebugSyntaxResult(self.r_angle_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("MdAutolink").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdAutolink> for SyntaxNode {
+    fn from(n: MdAutolink) -> Self {
+        n.syntax
+    }
+}
+impl From<MdAutolink> for SyntaxElement {
+    fn from(n: MdAutolink) -> Self {
+        n.syntax.into()
+    }
+}
 impl AstNode for MdBullet {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -1365,10 +1750,7 @@ impl std::fmt::Debug for MdBullet {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdBullet")
                 .field("bullet", &support::DebugSyntaxResult(self.bullet()))
-                .field(
-                    "space_token",
-                    &support::DebugSyntaxResult(self.space_token()),
-                )
+                .field("checkbox", &support::DebugOptionalElement(self.checkbox()))
                 .field("content", &self.content())
                 .finish()
         } else {
@@ -1515,24 +1897,10 @@ impl std::fmt::Debug for MdFencedCodeBlock {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdFencedCodeBlock")
-                .field(
-                    "l_fence_token",
-                    &support::DebugSyntaxResult(self.l_fence_token()),
-                )
-                .field("code_list", &self.code_list())
-                .field(
-                    "l_hard_line",
-                    &support::DebugSyntaxResult(self.l_hard_line()),
-                )
-                .field("content", &support::DebugSyntaxResult(self.content()))
-                .field(
-                    "r_hard_line",
-                    &support::DebugSyntaxResult(self.r_hard_line()),
-                )
-                .field(
-                    "r_fence_token",
-                    &support::DebugSyntaxResult(self.r_fence_token()),
-                )
+                .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
+                .field("info", &support::DebugOptionalElement(self.info()))
+                .field("content", &self.content())
+                .field("r_fence", &support::DebugOptionalElement(self.r_fence()))
                 .finish()
         } else {
             f.debug_struct("MdFencedCodeBlock").finish()
@@ -1700,9 +2068,254 @@ impl From<MdHeader> for SyntaxElement {
 impl AstNode for MdHtmlBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HTML_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HTML_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_HTML_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdHtmlBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdHtmlBlock")
+                .field("content", &self.content())
+                .finish()
+        } else {
+            f.debug_struct("MdHtmlBlock").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdHtmlBlock> for SyntaxNode {
+    fn from(n: MdHtmlBlock) -> Self {
+        n.syntax
+    }
+}
+impl From<MdHtmlBlock> for SyntaxElement {
+    fn from(n: MdHtmlBlock) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdIndentCodeBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT_CODE_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_INDENT_CODE_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdIndentCodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdIndentCodeBlock")
+                .field("content", &self.content())
+                .finish()
+        } else {
+            f.debug_struct("MdIndentCodeBlock").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdIndentCodeBlock> for SyntaxNode {
+    fn from(n: MdIndentCodeBlock) -> Self {
+        n.syntax
+    }
+}
+impl From<MdIndentCodeBlock> for SyntaxElement {
+    fn from(n: MdIndentCodeBlock) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdInlineCode {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_CODE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_INLINE_CODE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdInlineCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdInlineCode")
+                .field(
+                    "l_tick_token",
+                    &support::DebugSyntaxResult(self.l_tick_token()),
+                )
+                .field("content", &self.content())
+                .field(
+                    "r_tick_token",
+                    &support::DebugSyntaxResult(self.r_tick_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("MdInlineCode").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdInlineCode> for SyntaxNode {
+    fn from(n: MdInlineCode) -> Self {
+        n.syntax
+    }
+}
+impl From<MdInlineCode> for SyntaxElement {
+    fn from(n: MdInlineCode) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdInlineEmphasis {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_EMPHASIS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_INLINE_EMPHASIS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdInlineEmphasis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdInlineEmphasis")
+                .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
+                .field("content", &self.content())
+                .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
+                .finish()
+        } else {
+            f.debug_struct("MdInlineEmphasis").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdInlineEmphasis> for SyntaxNode {
+    fn from(n: MdInlineEmphasis) -> Self {
+        n.syntax
+    }
+}
+impl From<MdInlineEmphasis> for SyntaxElement {
+    fn from(n: MdInlineEmphasis) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdInlineHtml {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_HTML as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_INLINE_HTML
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdInlineHtml {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdInlineHtml")
+                .field("value", &self.value())
+                .finish()
+        } else {
+            f.debug_struct("MdInlineHtml").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdInlineHtml> for SyntaxNode {
+    fn from(n: MdInlineHtml) -> Self {
+        n.syntax
+    }
+}
+impl From<MdInlineHtml> for SyntaxElement {
+    fn from(n: MdInlineHtml) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdInlineImage {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HTML_BLOCK
+        kind == MD_INLINE_IMAGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1718,38 +2331,56 @@ impl AstNode for MdHtmlBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdHtmlBlock {
+impl std::fmt::Debug for MdInlineImage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdHtmlBlock")
-                .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+            f.debug_struct("MdInlineImage")
+                .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
+                .field(
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
+                )
+                .field("alt", &self.alt())
+                .field(
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
+                )
+                .field(
+                    "l_paren_token",
+                    &support::DebugSyntaxResult(self.l_paren_token()),
+                )
+                .field("source", &self.source())
+                .field(
+                    "r_paren_token",
+                    &support::DebugSyntaxResult(self.r_paren_token()),
+                )
                 .finish()
         } else {
-            f.debug_struct("MdHtmlBlock").finish()
+            f.debug_struct("MdInlineImage").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdHtmlBlock> for SyntaxNode {
-    fn from(n: MdHtmlBlock) -> Self {
+impl From<MdInlineImage> for SyntaxNode {
+    fn from(n: MdInlineImage) -> Self {
         n.syntax
     }
 }
-impl From<MdHtmlBlock> for SyntaxElement {
-    fn from(n: MdHtmlBlock) -> Self {
+impl From<MdInlineImage> for SyntaxElement {
+    fn from(n: MdInlineImage) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdIndent {
+impl AstNode for MdInlineItalic {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITALIC as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENT
+        kind == MD_INLINE_ITALIC
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1765,41 +2396,40 @@ impl AstNode for MdIndent {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdIndent {
+impl std::fmt::Debug for MdInlineItalic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdIndent")
-                .field(
-                    "value_token",
-                    &support::DebugSyntaxResult(self.value_token()),
-                )
+            f.debug_struct("MdInlineItalic")
+                .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
+                .field("content", &self.content())
+                .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
                 .finish()
         } else {
-            f.debug_struct("MdIndent").finish()
+            f.debug_struct("MdInlineItalic").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdIndent> for SyntaxNode {
-    fn from(n: MdIndent) -> Self {
+impl From<MdInlineItalic> for SyntaxNode {
+    fn from(n: MdInlineItalic) -> Self {
         n.syntax
     }
 }
-impl From<MdIndent> for SyntaxElement {
-    fn from(n: MdIndent) -> Self {
+impl From<MdInlineItalic> for SyntaxElement {
+    fn from(n: MdInlineItalic) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdIndentCodeBlock {
+impl AstNode for MdInlineLink {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT_CODE_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_LINK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENT_CODE_BLOCK
+        kind == MD_INLINE_LINK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1815,38 +2445,55 @@ impl AstNode for MdIndentCodeBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdIndentCodeBlock {
+impl std::fmt::Debug for MdInlineLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdIndentCodeBlock")
-                .field("lines", &self.lines())
+            f.debug_struct("MdInlineLink")
+                .field(
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
+                )
+                .field("text", &self.text())
+                .field(
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
+                )
+                .field(
+                    "l_paren_token",
+                    &support::DebugSyntaxResult(self.l_paren_token()),
+                )
+                .field("source", &self.source())
+                .field(
+                    "r_paren_token",
+                    &support::DebugSyntaxResult(self.r_paren_token()),
+                )
                 .finish()
         } else {
-            f.debug_struct("MdIndentCodeBlock").finish()
+            f.debug_struct("MdInlineLink").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdIndentCodeBlock> for SyntaxNode {
-    fn from(n: MdIndentCodeBlock) -> Self {
+impl From<MdInlineLink> for SyntaxNode {
+    fn from(n: MdInlineLink) -> Self {
         n.syntax
     }
 }
-impl From<MdIndentCodeBlock> for SyntaxElement {
-    fn from(n: MdIndentCodeBlock) -> Self {
+impl From<MdInlineLink> for SyntaxElement {
+    fn from(n: MdInlineLink) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdIndentedCodeLine {
+impl AstNode for MdInlineStrikethrough {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENTED_CODE_LINE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_STRIKETHROUGH as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENTED_CODE_LINE
+        kind == MD_INLINE_STRIKETHROUGH
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1862,42 +2509,46 @@ impl AstNode for MdIndentedCodeLine {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdIndentedCodeLine {
+impl std::fmt::Debug for MdInlineStrikethrough {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdIndentedCodeLine")
+            f.debug_struct("MdInlineStrikethrough")
                 .field(
-                    "indentation",
-                    &support::DebugSyntaxResult(self.indentation()),
+                    "l_fence_token",
+                    &support::DebugSyntaxResult(self.l_fence_token()),
+                )
+                .field("content", &self.content())
+                .field(
+                    "r_fence_token",
+                    &support::DebugSyntaxResult(self.r_fence_token()),
                 )
-                .field("content", &support::DebugSyntaxResult(self.content()))
                 .finish()
         } else {
-            f.debug_struct("MdIndentedCodeLine").finish()
+            f.debug_struct("MdInlineStrikethrough").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdIndentedCodeLine> for SyntaxNode {
-    fn from(n: MdIndentedCodeLine) -> Self {
+impl From<MdInlineStrikethrough> for SyntaxNode {
+    fn from(n: MdInlineStrikethrough) -> Self {
         n.syntax
     }
 }
-impl From<MdIndentedCodeLine> for SyntaxElement {
-    fn from(n: MdIndentedCodeLine) -> Self {
+impl From<MdInlineStrikethrough> for SyntaxElement {
+    fn from(n: MdInlineStrikethrough) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineCode {
+impl AstNode for MdLinkBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_CODE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LINK_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_CODE
+        kind == MD_LINK_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1913,46 +2564,51 @@ impl AstNode for MdInlineCode {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineCode {
+impl std::fmt::Debug for MdLinkBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineCode")
+            f.debug_struct("MdLinkBlock")
                 .field(
-                    "l_tick_token",
-                    &support::DebugSyntaxResult(self.l_tick_token()),
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
                 )
-                .field("content", &self.content())
+                .field("label", &self.label())
                 .field(
-                    "r_tick_token",
-                    &support::DebugSyntaxResult(self.r_tick_token()),
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
+                )
+                .field(
+                    "colon_token",
+                    &support::DebugSyntaxResult(self.colon_token()),
                 )
+                .field("destination", &self.destination())
                 .finish()
         } else {
-            f.debug_struct("MdInlineCode").finish()
+            f.debug_struct("MdLinkBlock").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineCode> for SyntaxNode {
-    fn from(n: MdInlineCode) -> Self {
+impl From<MdLinkBlock> for SyntaxNode {
+    fn from(n: MdLinkBlock) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineCode> for SyntaxElement {
-    fn from(n: MdInlineCode) -> Self {
+impl From<MdLinkBlock> for SyntaxElement {
+    fn from(n: MdLinkBlock) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineEmphasis {
+impl AstNode for MdOrderBullet {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_EMPHASIS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_BULLET as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_EMPHASIS
+        kind == MD_ORDER_BULLET
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1968,40 +2624,43 @@ impl AstNode for MdInlineEmphasis {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineEmphasis {
+impl std::fmt::Debug for MdOrderBullet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineEmphasis")
-                .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
+            f.debug_struct("MdOrderBullet")
+                .field(
+                    "marker_token",
+                    &support::DebugSyntaxResult(self.marker_token()),
+                )
+                .field("checkbox", &support::DebugOptionalElement(self.checkbox()))
                 .field("content", &self.content())
-                .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
                 .finish()
         } else {
-            f.debug_struct("MdInlineEmphasis").finish()
+            f.debug_struct("MdOrderBullet").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineEmphasis> for SyntaxNode {
-    fn from(n: MdInlineEmphasis) -> Self {
+impl From<MdOrderBullet> for SyntaxNode {
+    fn from(n: MdOrderBullet) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineEmphasis> for SyntaxElement {
-    fn from(n: MdInlineEmphasis) -> Self {
+impl From<MdOrderBullet> for SyntaxElement {
+    fn from(n: MdOrderBullet) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImage {
+impl AstNode for MdOrderListItem {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_LIST_ITEM as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE
+        kind == MD_ORDER_LIST_ITEM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2017,49 +2676,38 @@ impl AstNode for MdInlineImage {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImage {
+impl std::fmt::Debug for MdOrderListItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImage")
-                .field(
-                    "l_brack_token",
-                    &support::DebugSyntaxResult(self.l_brack_token()),
-                )
-                .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
-                .field("alt", &support::DebugSyntaxResult(self.alt()))
-                .field("source", &support::DebugSyntaxResult(self.source()))
-                .field(
-                    "r_brack_token",
-                    &support::DebugSyntaxResult(self.r_brack_token()),
-                )
-                .field("link", &support::DebugOptionalElement(self.link()))
+            f.debug_struct("MdOrderListItem")
+                .field("md_order_list", &self.md_order_list())
                 .finish()
         } else {
-            f.debug_struct("MdInlineImage").finish()
+            f.debug_struct("MdOrderListItem").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImage> for SyntaxNode {
-    fn from(n: MdInlineImage) -> Self {
+impl From<MdOrderListItem> for SyntaxNode {
+    fn from(n: MdOrderListItem) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImage> for SyntaxElement {
-    fn from(n: MdInlineImage) -> Self {
+impl From<MdOrderListItem> for SyntaxElement {
+    fn from(n: MdOrderListItem) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImageAlt {
+impl AstNode for MdParagraph {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE_ALT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_PARAGRAPH as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE_ALT
+        kind == MD_PARAGRAPH
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2075,46 +2723,38 @@ impl AstNode for MdInlineImageAlt {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImageAlt {
+impl std::fmt::Debug for MdParagraph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImageAlt")
-                .field(
-                    "l_brack_token",
-                    &support::DebugSyntaxResult(self.l_brack_token()),
-                )
-                .field("content", &self.content())
-                .field(
-                    "r_brack_token",
-                    &support::DebugSyntaxResult(self.r_brack_token()),
-                )
+            f.debug_struct("MdParagraph")
+                .field("list", &self.list())
                 .finish()
         } else {
-            f.debug_struct("MdInlineImageAlt").finish()
+            f.debug_struct("MdParagraph").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImageAlt> for SyntaxNode {
-    fn from(n: MdInlineImageAlt) -> Self {
+impl From<MdParagraph> for SyntaxNode {
+    fn from(n: MdParagraph) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImageAlt> for SyntaxElement {
-    fn from(n: MdInlineImageAlt) -> Self {
+impl From<MdParagraph> for SyntaxElement {
+    fn from(n: MdParagraph) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImageLink {
+impl AstNode for MdQuote {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE_LINK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_QUOTE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE_LINK
+        kind == MD_QUOTE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2130,46 +2770,39 @@ impl AstNode for MdInlineImageLink {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImageLink {
+impl std::fmt::Debug for MdQuote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImageLink")
-                .field(
-                    "l_paren_token",
-                    &support::DebugSyntaxResult(self.l_paren_token()),
-                )
+            f.debug_struct("MdQuote")
+                .field("prefix", &support::DebugSyntaxResult(self.prefix()))
                 .field("content", &self.content())
-                .field(
-                    "r_paren_token",
-                    &support::DebugSyntaxResult(self.r_paren_token()),
-                )
                 .finish()
         } else {
-            f.debug_struct("MdInlineImageLink").finish()
+            f.debug_struct("MdQuote").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImageLink> for SyntaxNode {
-    fn from(n: MdInlineImageLink) -> Self {
+impl From<MdQuote> for SyntaxNode {
+    fn from(n: MdQuote) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImageLink> for SyntaxElement {
-    fn from(n: MdInlineImageLink) -> Self {
+impl From<MdQuote> for SyntaxElement {
+    fn from(n: MdQuote) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImageSource {
+impl AstNode for MdQuotePrefix {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE_SOURCE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_QUOTE_PREFIX as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE_SOURCE
+        kind == MD_QUOTE_PREFIX
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2185,46 +2818,41 @@ impl AstNode for MdInlineImageSource {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImageSource {
+impl std::fmt::Debug for MdQuotePrefix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImageSource")
-                .field(
-                    "l_paren_token",
-                    &support::DebugSyntaxResult(self.l_paren_token()),
-                )
-                .field("content", &self.content())
+            f.debug_struct("MdQuotePrefix")
                 .field(
-                    "r_paren_token",
-                    &support::DebugSyntaxResult(self.r_paren_token()),
+                    "marker_token",
+                    &support::DebugSyntaxResult(self.marker_token()),
                 )
                 .finish()
         } else {
-            f.debug_struct("MdInlineImageSource").finish()
+            f.debug_struct("MdQuotePrefix").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImageSource> for SyntaxNode {
-    fn from(n: MdInlineImageSource) -> Self {
+impl From<MdQuotePrefix> for SyntaxNode {
+    fn from(n: MdQuotePrefix) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImageSource> for SyntaxElement {
-    fn from(n: MdInlineImageSource) -> Self {
+impl From<MdQuotePrefix> for SyntaxElement {
+    fn from(n: MdQuotePrefix) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineItalic {
+impl AstNode for MdReferenceImage {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITALIC as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_REFERENCE_IMAGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_ITALIC
+        kind == MD_REFERENCE_IMAGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2240,40 +2868,48 @@ impl AstNode for MdInlineItalic {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineItalic {
+impl std::fmt::Debug for MdReferenceImage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineItalic")
-                .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
-                .field("content", &self.content())
-                .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
+            f.debug_struct("MdReferenceImage")
+                .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
+                .field(
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
+                )
+                .field("alt", &self.alt())
+                .field(
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
+                )
+                .field("label", &support::DebugOptionalElement(self.label()))
                 .finish()
         } else {
-            f.debug_struct("MdInlineItalic").finish()
+            f.debug_struct("MdReferenceImage").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineItalic> for SyntaxNode {
-    fn from(n: MdInlineItalic) -> Self {
+impl From<MdReferenceImage> for SyntaxNode {
+    fn from(n: MdReferenceImage) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineItalic> for SyntaxElement {
-    fn from(n: MdInlineItalic) -> Self {
+impl From<MdReferenceImage> for SyntaxElement {
+    fn from(n: MdReferenceImage) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineLink {
+impl AstNode for MdReferenceLink {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_LINK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_REFERENCE_LINK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_LINK
+        kind == MD_REFERENCE_LINK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2289,55 +2925,47 @@ impl AstNode for MdInlineLink {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineLink {
+impl std::fmt::Debug for MdReferenceLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineLink")
+            f.debug_struct("MdReferenceLink")
                 .field(
                     "l_brack_token",
-                    &support::DebugSyntaxResult(self.l_brack_token()),
-                )
-                .field("text", &self.text())
-                .field(
-                    "r_brack_token",
-                    &support::DebugSyntaxResult(self.r_brack_token()),
-                )
-                .field(
-                    "l_paren_token",
-                    &support::DebugSyntaxResult(self.l_paren_token()),
+                    &support::DebugSyntaxResult(self.l_brack_token()),
                 )
-                .field("source", &self.source())
+                .field("text", &self.text())
                 .field(
-                    "r_paren_token",
-                    &support::DebugSyntaxResult(self.r_paren_token()),
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
                 )
+                .field("label", &support::DebugOptionalElement(self.label()))
                 .finish()
         } else {
-            f.debug_struct("MdInlineLink").finish()
+            f.debug_struct("MdReferenceLink").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineLink> for SyntaxNode {
-    fn from(n: MdInlineLink) -> Self {
+impl From<MdReferenceLink> for SyntaxNode {
+    fn from(n: MdReferenceLink) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineLink> for SyntaxElement {
-    fn from(n: MdInlineLink) -> Self {
+impl From<MdReferenceLink> for SyntaxElement {
+    fn from(n: MdReferenceLink) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdLinkBlock {
+impl AstNode for MdReferenceLinkLabel {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LINK_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_REFERENCE_LINK_LABEL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_LINK_BLOCK
+        kind == MD_REFERENCE_LINK_LABEL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2353,40 +2981,46 @@ impl AstNode for MdLinkBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdLinkBlock {
+impl std::fmt::Debug for MdReferenceLinkLabel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdLinkBlock")
-                .field("label", &support::DebugSyntaxResult(self.label()))
-                .field("url", &support::DebugSyntaxResult(self.url()))
-                .field("title", &support::DebugOptionalElement(self.title()))
+            f.debug_struct("MdReferenceLinkLabel")
+                .field(
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
+                )
+                .field("label", &self.label())
+                .field(
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
+                )
                 .finish()
         } else {
-            f.debug_struct("MdLinkBlock").finish()
+            f.debug_struct("MdReferenceLinkLabel").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdLinkBlock> for SyntaxNode {
-    fn from(n: MdLinkBlock) -> Self {
+impl From<MdReferenceLinkLabel> for SyntaxNode {
+    fn from(n: MdReferenceLinkLabel) -> Self {
         n.syntax
     }
 }
-impl From<MdLinkBlock> for SyntaxElement {
-    fn from(n: MdLinkBlock) -> Self {
+impl From<MdReferenceLinkLabel> for SyntaxElement {
+    fn from(n: MdReferenceLinkLabel) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdOrderListItem {
+impl AstNode for MdSetextHeader {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_LIST_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_SETEXT_HEADER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_ORDER_LIST_ITEM
+        kind == MD_SETEXT_HEADER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2402,38 +3036,42 @@ impl AstNode for MdOrderListItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdOrderListItem {
+impl std::fmt::Debug for MdSetextHeader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdOrderListItem")
-                .field("md_bullet_list", &self.md_bullet_list())
+            f.debug_struct("MdSetextHeader")
+                .field("content", &support::DebugSyntaxResult(self.content()))
+                .field(
+                    "underline_token",
+                    &support::DebugSyntaxResult(self.underline_token()),
+                )
                 .finish()
         } else {
-            f.debug_struct("MdOrderListItem").finish()
+            f.debug_struct("MdSetextHeader").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdOrderListItem> for SyntaxNode {
-    fn from(n: MdOrderListItem) -> Self {
+impl From<MdSetextHeader> for SyntaxNode {
+    fn from(n: MdSetextHeader) -> Self {
         n.syntax
     }
 }
-impl From<MdOrderListItem> for SyntaxElement {
-    fn from(n: MdOrderListItem) -> Self {
+impl From<MdSetextHeader> for SyntaxElement {
+    fn from(n: MdSetextHeader) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdParagraph {
+impl AstNode for MdTable {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_PARAGRAPH as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_PARAGRAPH
+        kind == MD_TABLE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2449,39 +3087,40 @@ impl AstNode for MdParagraph {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdParagraph {
+impl std::fmt::Debug for MdTable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdParagraph")
-                .field("list", &self.list())
-                .field("hard_line", &support::DebugSyntaxResult(self.hard_line()))
+            f.debug_struct("MdTable")
+                .field("header", &support::DebugSyntaxResult(self.header()))
+                .field("delimiter", &support::DebugSyntaxResult(self.delimiter()))
+                .field("rows", &self.rows())
                 .finish()
         } else {
-            f.debug_struct("MdParagraph").finish()
+            f.debug_struct("MdTable").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdParagraph> for SyntaxNode {
-    fn from(n: MdParagraph) -> Self {
+impl From<MdTable> for SyntaxNode {
+    fn from(n: MdTable) -> Self {
         n.syntax
     }
 }
-impl From<MdParagraph> for SyntaxElement {
-    fn from(n: MdParagraph) -> Self {
+impl From<MdTable> for SyntaxElement {
+    fn from(n: MdTable) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdQuote {
+impl AstNode for MdTableCell {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_QUOTE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_CELL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_QUOTE
+        kind == MD_TABLE_CELL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2497,41 +3136,42 @@ impl AstNode for MdQuote {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdQuote {
+impl std::fmt::Debug for MdTableCell {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdQuote")
+            f.debug_struct("MdTableCell")
+                .field("content", &self.content())
                 .field(
-                    "any_md_block",
-                    &support::DebugSyntaxResult(self.any_md_block()),
+                    "r_pipe_token",
+                    &support::DebugOptionalElement(self.r_pipe_token()),
                 )
                 .finish()
         } else {
-            f.debug_struct("MdQuote").finish()
+            f.debug_struct("MdTableCell").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdQuote> for SyntaxNode {
-    fn from(n: MdQuote) -> Self {
+impl From<MdTableCell> for SyntaxNode {
+    fn from(n: MdTableCell) -> Self {
         n.syntax
     }
 }
-impl From<MdQuote> for SyntaxElement {
-    fn from(n: MdQuote) -> Self {
+impl From<MdTableCell> for SyntaxElement {
+    fn from(n: MdTableCell) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdSetextHeader {
+impl AstNode for MdTableRow {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_SETEXT_HEADER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_ROW as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_SETEXT_HEADER
+        kind == MD_TABLE_ROW
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2547,41 +3187,42 @@ impl AstNode for MdSetextHeader {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdSetextHeader {
+impl std::fmt::Debug for MdTableRow {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdSetextHeader")
+            f.debug_struct("MdTableRow")
                 .field(
-                    "md_paragraph",
-                    &support::DebugSyntaxResult(self.md_paragraph()),
+                    "l_pipe_token",
+                    &support::DebugOptionalElement(self.l_pipe_token()),
                 )
+                .field("cells", &self.cells())
                 .finish()
         } else {
-            f.debug_struct("MdSetextHeader").finish()
+            f.debug_struct("MdTableRow").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdSetextHeader> for SyntaxNode {
-    fn from(n: MdSetextHeader) -> Self {
+impl From<MdTableRow> for SyntaxNode {
+    fn from(n: MdTableRow) -> Self {
         n.syntax
     }
 }
-impl From<MdSetextHeader> for SyntaxElement {
-    fn from(n: MdSetextHeader) -> Self {
+impl From<MdTableRow> for SyntaxElement {
+    fn from(n: MdTableRow) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdSoftBreak {
+impl AstNode for MdTaskListCheckbox {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_SOFT_BREAK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TASK_LIST_CHECKBOX as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_SOFT_BREAK
+        kind == MD_TASK_LIST_CHECKBOX
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2597,32 +3238,37 @@ impl AstNode for MdSoftBreak {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdSoftBreak {
+impl std::fmt::Debug for MdTaskListCheckbox {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdSoftBreak")
+            f.debug_struct("MdTaskListCheckbox")
                 .field(
-                    "value_token",
-                    &support::DebugSyntaxResult(self.value_token()),
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
+                )
+                .field("value", &support::DebugOptionalElement(self.value()))
+                .field(
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
                 )
                 .finish()
         } else {
-            f.debug_struct("MdSoftBreak").finish()
+            f.debug_struct("MdTaskListCheckbox").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdSoftBreak> for SyntaxNode {
-    fn from(n: MdSoftBreak) -> Self {
+impl From<MdTaskListCheckbox> for SyntaxNode {
+    fn from(n: MdTaskListCheckbox) -> Self {
         n.syntax
     }
 }
-impl From<MdSoftBreak> for SyntaxElement {
-    fn from(n: MdSoftBreak) -> Self {
+impl From<MdTaskListCheckbox> for SyntaxElement {
+    fn from(n: MdTaskListCheckbox) -> Self {
         n.syntax.into()
     }
 }
@@ -2882,6 +3528,11 @@ impl From<MdSetextHeader> for AnyLeafBlock {
         Self::MdSetextHeader(node)
     }
 }
+impl From<MdTable> for AnyLeafBlock {
+    fn from(node: MdTable) -> Self {
+        Self::MdTable(node)
+    }
+}
 impl From<MdThematicBreakBlock> for AnyLeafBlock {
     fn from(node: MdThematicBreakBlock) -> Self {
         Self::MdThematicBreakBlock(node)
@@ -2895,6 +3546,7 @@ impl AstNode for AnyLeafBlock {
         .union(MdLinkBlock::KIND_SET)
         .union(MdParagraph::KIND_SET)
         .union(MdSetextHeader::KIND_SET)
+        .union(MdTable::KIND_SET)
         .union(MdThematicBreakBlock::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
@@ -2903,6 +3555,7 @@ impl AstNode for AnyLeafBlock {
             | MD_LINK_BLOCK
             | MD_PARAGRAPH
             | MD_SETEXT_HEADER
+            | MD_TABLE
             | MD_THEMATIC_BREAK_BLOCK => true,
             k if AnyCodeBlock::can_cast(k) => true,
             _ => false,
@@ -2915,6 +3568,7 @@ impl AstNode for AnyLeafBlock {
             MD_LINK_BLOCK => Self::MdLinkBlock(MdLinkBlock { syntax }),
             MD_PARAGRAPH => Self::MdParagraph(MdParagraph { syntax }),
             MD_SETEXT_HEADER => Self::MdSetextHeader(MdSetextHeader { syntax }),
+            MD_TABLE => Self::MdTable(MdTable { syntax }),
             MD_THEMATIC_BREAK_BLOCK => Self::MdThematicBreakBlock(MdThematicBreakBlock { syntax }),
             _ => {
                 if let Some(any_code_block) = AnyCodeBlock::cast(syntax) {
@@ -2932,6 +3586,7 @@ impl AstNode for AnyLeafBlock {
             Self::MdLinkBlock(it) => it.syntax(),
             Self::MdParagraph(it) => it.syntax(),
             Self::MdSetextHeader(it) => it.syntax(),
+            Self::MdTable(it) => it.syntax(),
             Self::MdThematicBreakBlock(it) => it.syntax(),
             Self::AnyCodeBlock(it) => it.syntax(),
         }
@@ -2943,6 +3598,7 @@ impl AstNode for AnyLeafBlock {
             Self::MdLinkBlock(it) => it.into_syntax(),
             Self::MdParagraph(it) => it.into_syntax(),
             Self::MdSetextHeader(it) => it.into_syntax(),
+            Self::MdTable(it) => it.into_syntax(),
             Self::MdThematicBreakBlock(it) => it.into_syntax(),
             Self::AnyCodeBlock(it) => it.into_syntax(),
         }
@@ -2957,6 +3613,7 @@ impl std::fmt::Debug for AnyLeafBlock {
             Self::MdLinkBlock(it) => std::fmt::Debug::fmt(it, f),
             Self::MdParagraph(it) => std::fmt::Debug::fmt(it, f),
             Self::MdSetextHeader(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdTable(it) => std::fmt::Debug::fmt(it, f),
             Self::MdThematicBreakBlock(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -2970,6 +3627,7 @@ impl From<AnyLeafBlock> for SyntaxNode {
             AnyLeafBlock::MdLinkBlock(it) => it.into_syntax(),
             AnyLeafBlock::MdParagraph(it) => it.into_syntax(),
             AnyLeafBlock::MdSetextHeader(it) => it.into_syntax(),
+            AnyLeafBlock::MdTable(it) => it.into_syntax(),
             AnyLeafBlock::MdThematicBreakBlock(it) => it.into_syntax(),
         }
     }
@@ -2980,37 +3638,52 @@ impl From<AnyLeafBlock> for SyntaxElement {
         node.into()
     }
 }
+impl From<MdQuotePrefix> for AnyMdBlock {
+    fn from(node: MdQuotePrefix) -> Self {
+        Self::MdQuotePrefix(node)
+    }
+}
 impl AstNode for AnyMdBlock {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        AnyContainerBlock::KIND_SET.union(AnyLeafBlock::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = AnyContainerBlock::KIND_SET
+        .union(AnyLeafBlock::KIND_SET)
+        .union(MdQuotePrefix::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
+            MD_QUOTE_PREFIX => true,
             k if AnyContainerBlock::can_cast(k) => true,
             k if AnyLeafBlock::can_cast(k) => true,
             _ => false,
         }
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let syntax = match AnyContainerBlock::try_cast(syntax) {
-            Ok(any_container_block) => {
-                return Some(Self::AnyContainerBlock(any_container_block));
+        let res = match syntax.kind() {
+            MD_QUOTE_PREFIX => Self::MdQuotePrefix(MdQuotePrefix { syntax }),
+            _ => {
+                let syntax = match AnyContainerBlock::try_cast(syntax) {
+                    Ok(any_container_block) => {
+                        return Some(Self::AnyContainerBlock(any_container_block));
+                    }
+                    Err(syntax) => syntax,
+                };
+                if let Some(any_leaf_block) = AnyLeafBlock::cast(syntax) {
+                    return Some(Self::AnyLeafBlock(any_leaf_block));
+                }
+                return None;
             }
-            Err(syntax) => syntax,
         };
-        if let Some(any_leaf_block) = AnyLeafBlock::cast(syntax) {
-            return Some(Self::AnyLeafBlock(any_leaf_block));
-        }
-        None
+        Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
+            Self::MdQuotePrefix(it) => it.syntax(),
             Self::AnyContainerBlock(it) => it.syntax(),
             Self::AnyLeafBlock(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
+            Self::MdQuotePrefix(it) => it.into_syntax(),
             Self::AnyContainerBlock(it) => it.into_syntax(),
             Self::AnyLeafBlock(it) => it.into_syntax(),
         }
@@ -3021,6 +3694,7 @@ impl std::fmt::Debug for AnyMdBlock {
         match self {
             Self::AnyContainerBlock(it) => std::fmt::Debug::fmt(it, f),
             Self::AnyLeafBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdQuotePrefix(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -3029,6 +3703,7 @@ impl From<AnyMdBlock> for SyntaxNode {
         match n {
             AnyMdBlock::AnyContainerBlock(it) => it.into_syntax(),
             AnyMdBlock::AnyLeafBlock(it) => it.into_syntax(),
+            AnyMdBlock::MdQuotePrefix(it) => it.into_syntax(),
         }
     }
 }
@@ -3038,16 +3713,16 @@ impl From<AnyMdBlock> for SyntaxElement {
         node.into()
     }
 }
+impl From<MdAutolink> for AnyMdInline {
+    fn from(node: MdAutolink) -> Self {
+        Self::MdAutolink(node)
+    }
+}
 impl From<MdHardLine> for AnyMdInline {
     fn from(node: MdHardLine) -> Self {
         Self::MdHardLine(node)
     }
 }
-impl From<MdHtmlBlock> for AnyMdInline {
-    fn from(node: MdHtmlBlock) -> Self {
-        Self::MdHtmlBlock(node)
-    }
-}
 impl From<MdInlineCode> for AnyMdInline {
     fn from(node: MdInlineCode) -> Self {
         Self::MdInlineCode(node)
@@ -3058,6 +3733,11 @@ impl From<MdInlineEmphasis> for AnyMdInline {
         Self::MdInlineEmphasis(node)
     }
 }
+impl From<MdInlineHtml> for AnyMdInline {
+    fn from(node: MdInlineHtml) -> Self {
+        Self::MdInlineHtml(node)
+    }
+}
 impl From<MdInlineImage> for AnyMdInline {
     fn from(node: MdInlineImage) -> Self {
         Self::MdInlineImage(node)
@@ -3073,9 +3753,24 @@ impl From<MdInlineLink> for AnyMdInline {
         Self::MdInlineLink(node)
     }
 }
-impl From<MdSoftBreak> for AnyMdInline {
-    fn from(node: MdSoftBreak) -> Self {
-        Self::MdSoftBreak(node)
+impl From<MdInlineStrikethrough> for AnyMdInline {
+    fn from(node: MdInlineStrikethrough) -> Self {
+        Self::MdInlineStrikethrough(node)
+    }
+}
+impl From<MdQuotePrefix> for AnyMdInline {
+    fn from(node: MdQuotePrefix) -> Self {
+        Self::MdQuotePrefix(node)
+    }
+}
+impl From<MdReferenceImage> for AnyMdInline {
+    fn from(node: MdReferenceImage) -> Self {
+        Self::MdReferenceImage(node)
+    }
+}
+impl From<MdReferenceLink> for AnyMdInline {
+    fn from(node: MdReferenceLink) -> Self {
+        Self::MdReferenceLink(node)
     }
 }
 impl From<MdTextual> for AnyMdInline {
@@ -3085,39 +3780,53 @@ impl From<MdTextual> for AnyMdInline {
 }
 impl AstNode for AnyMdInline {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = MdHardLine::KIND_SET
-        .union(MdHtmlBlock::KIND_SET)
+    const KIND_SET: SyntaxKindSet<Language> = MdAutolink::KIND_SET
+        .union(MdHardLine::KIND_SET)
         .union(MdInlineCode::KIND_SET)
         .union(MdInlineEmphasis::KIND_SET)
+        .union(MdInlineHtml::KIND_SET)
         .union(MdInlineImage::KIND_SET)
         .union(MdInlineItalic::KIND_SET)
         .union(MdInlineLink::KIND_SET)
-        .union(MdSoftBreak::KIND_SET)
+        .union(MdInlineStrikethrough::KIND_SET)
+        .union(MdQuotePrefix::KIND_SET)
+        .union(MdReferenceImage::KIND_SET)
+        .union(MdReferenceLink::KIND_SET)
         .union(MdTextual::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
-            MD_HARD_LINE
-                | MD_HTML_BLOCK
+            MD_AUTOLINK
+                | MD_HARD_LINE
                 | MD_INLINE_CODE
                 | MD_INLINE_EMPHASIS
+                | MD_INLINE_HTML
                 | MD_INLINE_IMAGE
                 | MD_INLINE_ITALIC
                 | MD_INLINE_LINK
-                | MD_SOFT_BREAK
+                | MD_INLINE_STRIKETHROUGH
+                | MD_QUOTE_PREFIX
+                | MD_REFERENCE_IMAGE
+                | MD_REFERENCE_LINK
                 | MD_TEXTUAL
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
+            MD_AUTOLINK => Self::MdAutolink(MdAutolink { syntax }),
             MD_HARD_LINE => Self::MdHardLine(MdHardLine { syntax }),
-            MD_HTML_BLOCK => Self::MdHtmlBlock(MdHtmlBlock { syntax }),
             MD_INLINE_CODE => Self::MdInlineCode(MdInlineCode { syntax }),
             MD_INLINE_EMPHASIS => Self::MdInlineEmphasis(MdInlineEmphasis { syntax }),
+            MD_INLINE_HTML => Self::MdInlineHtml(MdInlineHtml { syntax }),
             MD_INLINE_IMAGE => Self::MdInlineImage(MdInlineImage { syntax }),
             MD_INLINE_ITALIC => Self::MdInlineItalic(MdInlineItalic { syntax }),
             MD_INLINE_LINK => Self::MdInlineLink(MdInlineLink { syntax }),
-            MD_SOFT_BREAK => Self::MdSoftBreak(MdSoftBreak { syntax }),
+            MD_INLINE_STRIKETHROUGH => {
+                Self::MdInlineStrikethrough(MdInlineStrikethrough { syntax })
+            }
+            MD_QUOTE_PREFIX => Self::MdQuotePrefix(MdQuotePrefix { syntax }),
+            MD_REFERENCE_IMAGE => Self::MdReferenceImage(MdReferenceImage { syntax }),
+            MD_REFERENCE_LINK => Self::MdReferenceLink(MdReferenceLink { syntax }),
             MD_TEXTUAL => Self::MdTextual(MdTextual { syntax }),
             _ => return None,
         };
@@ -3125,27 +3834,35 @@ impl AstNode for AnyMdInline {
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
+            Self::MdAutolink(it) => it.syntax(),
             Self::MdHardLine(it) => it.syntax(),
-            Self::MdHtmlBlock(it) => it.syntax(),
             Self::MdInlineCode(it) => it.syntax(),
             Self::MdInlineEmphasis(it) => it.syntax(),
+            Self::MdInlineHtml(it) => it.syntax(),
             Self::MdInlineImage(it) => it.syntax(),
             Self::MdInlineItalic(it) => it.syntax(),
             Self::MdInlineLink(it) => it.syntax(),
-            Self::MdSoftBreak(it) => it.syntax(),
+            Self::MdInlineStrikethrough(it) => it.syntax(),
+            Self::MdQuotePrefix(it) => it.syntax(),
+            Self::MdReferenceImage(it) => it.syntax(),
+            Self::MdReferenceLink(it) => it.syntax(),
             Self::MdTextual(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
+            Self::MdAutolink(it) => it.into_syntax(),
             Self::MdHardLine(it) => it.into_syntax(),
-            Self::MdHtmlBlock(it) => it.into_syntax(),
             Self::MdInlineCode(it) => it.into_syntax(),
             Self::MdInlineEmphasis(it) => it.into_syntax(),
+            Self::MdInlineHtml(it) => it.into_syntax(),
             Self::MdInlineImage(it) => it.into_syntax(),
             Self::MdInlineItalic(it) => it.into_syntax(),
             Self::MdInlineLink(it) => it.into_syntax(),
-            Self::MdSoftBreak(it) => it.into_syntax(),
+            Self::MdInlineStrikethrough(it) => it.into_syntax(),
+            Self::MdQuotePrefix(it) => it.into_syntax(),
+            Self::MdReferenceImage(it) => it.into_syntax(),
+            Self::MdReferenceLink(it) => it.into_syntax(),
             Self::MdTextual(it) => it.into_syntax(),
         }
     }
@@ -3153,14 +3870,18 @@ impl AstNode for AnyMdInline {
 impl std::fmt::Debug for AnyMdInline {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::MdAutolink(it) => std::fmt::Debug::fmt(it, f),
             Self::MdHardLine(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
             Self::MdInlineCode(it) => std::fmt::Debug::fmt(it, f),
             Self::MdInlineEmphasis(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineHtml(it) => std::fmt::Debug::fmt(it, f),
             Self::MdInlineImage(it) => std::fmt::Debug::fmt(it, f),
             Self::MdInlineItalic(it) => std::fmt::Debug::fmt(it, f),
             Self::MdInlineLink(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdSoftBreak(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineStrikethrough(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdQuotePrefix(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdReferenceImage(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdReferenceLink(it) => std::fmt::Debug::fmt(it, f),
             Self::MdTextual(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -3168,14 +3889,18 @@ impl std::fmt::Debug for AnyMdInline {
 impl From<AnyMdInline> for SyntaxNode {
     fn from(n: AnyMdInline) -> Self {
         match n {
+            AnyMdInline::MdAutolink(it) => it.into_syntax(),
             AnyMdInline::MdHardLine(it) => it.into_syntax(),
-            AnyMdInline::MdHtmlBlock(it) => it.into_syntax(),
             AnyMdInline::MdInlineCode(it) => it.into_syntax(),
             AnyMdInline::MdInlineEmphasis(it) => it.into_syntax(),
+            AnyMdInline::MdInlineHtml(it) => it.into_syntax(),
             AnyMdInline::MdInlineImage(it) => it.into_syntax(),
             AnyMdInline::MdInlineItalic(it) => it.into_syntax(),
             AnyMdInline::MdInlineLink(it) => it.into_syntax(),
-            AnyMdInline::MdSoftBreak(it) => it.into_syntax(),
+            AnyMdInline::MdInlineStrikethrough(it) => it.into_syntax(),
+            AnyMdInline::MdQuotePrefix(it) => it.into_syntax(),
+            AnyMdInline::MdReferenceImage(it) => it.into_syntax(),
+            AnyMdInline::MdReferenceLink(it) => it.into_syntax(),
             AnyMdInline::MdTextual(it) => it.into_syntax(),
         }
     }
@@ -3211,6 +3936,11 @@ impl std::fmt::Display for AnyMdInline {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for MdAutolink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for MdBullet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -3251,77 +3981,87 @@ impl std::fmt::Display for MdHtmlBlock {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdIndent {
+impl std::fmt::Display for MdIndentCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdIndentCodeBlock {
+impl std::fmt::Display for MdInlineCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdIndentedCodeLine {
+impl std::fmt::Display for MdInlineEmphasis {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineCode {
+impl std::fmt::Display for MdInlineHtml {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineEmphasis {
+impl std::fmt::Display for MdInlineImage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImage {
+impl std::fmt::Display for MdInlineItalic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImageAlt {
+impl std::fmt::Display for MdInlineLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImageLink {
+impl std::fmt::Display for MdInlineStrikethrough {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImageSource {
+impl std::fmt::Display for MdLinkBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineItalic {
+impl std::fmt::Display for MdOrderBullet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineLink {
+impl std::fmt::Display for MdOrderListItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdLinkBlock {
+impl std::fmt::Display for MdParagraph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdOrderListItem {
+impl std::fmt::Display for MdQuote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdParagraph {
+impl std::fmt::Display for MdQuotePrefix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdQuote {
+impl std::fmt::Display for MdReferenceImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdReferenceLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdReferenceLinkLabel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -3331,7 +4071,22 @@ impl std::fmt::Display for MdSetextHeader {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdSoftBreak {
+impl std::fmt::Display for MdTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTaskListCheckbox {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -3568,10 +4323,10 @@ impl IntoIterator for MdBulletList {
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdCodeNameList {
+pub struct MdHashList {
     syntax_list: SyntaxList,
 }
-impl MdCodeNameList {
+impl MdHashList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3584,12 +4339,12 @@ impl MdCodeNameList {
         }
     }
 }
-impl AstNode for MdCodeNameList {
+impl AstNode for MdHashList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_CODE_NAME_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HASH_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_CODE_NAME_LIST
+        kind == MD_HASH_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3607,7 +4362,7 @@ impl AstNode for MdCodeNameList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdCodeNameList {
+impl Serialize for MdHashList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3619,9 +4374,9 @@ impl Serialize for MdCodeNameList {
         seq.end()
     }
 }
-impl AstSeparatedList for MdCodeNameList {
+impl AstNodeList for MdHashList {
     type Language = Language;
-    type Node = MdTextual;
+    type Node = MdHash;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3629,31 +4384,31 @@ impl AstSeparatedList for MdCodeNameList {
         self.syntax_list
     }
 }
-impl Debug for MdCodeNameList {
+impl Debug for MdHashList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdCodeNameList ")?;
-        f.debug_list().entries(self.elements()).finish()
+        f.write_str("MdHashList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for MdCodeNameList {
-    type Item = SyntaxResult<MdTextual>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, MdTextual>;
+impl IntoIterator for &MdHashList {
+    type Item = MdHash;
+    type IntoIter = AstNodeListIterator<Language, MdHash>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &MdCodeNameList {
-    type Item = SyntaxResult<MdTextual>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, MdTextual>;
+impl IntoIterator for MdHashList {
+    type Item = MdHash;
+    type IntoIter = AstNodeListIterator<Language, MdHash>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdHashList {
+pub struct MdInlineItemList {
     syntax_list: SyntaxList,
 }
-impl MdHashList {
+impl MdInlineItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3666,12 +4421,12 @@ impl MdHashList {
         }
     }
 }
-impl AstNode for MdHashList {
+impl AstNode for MdInlineItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HASH_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HASH_LIST
+        kind == MD_INLINE_ITEM_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3689,7 +4444,7 @@ impl AstNode for MdHashList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdHashList {
+impl Serialize for MdInlineItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3701,9 +4456,9 @@ impl Serialize for MdHashList {
         seq.end()
     }
 }
-impl AstNodeList for MdHashList {
+impl AstNodeList for MdInlineItemList {
     type Language = Language;
-    type Node = MdHash;
+    type Node = AnyMdInline;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3711,31 +4466,31 @@ impl AstNodeList for MdHashList {
         self.syntax_list
     }
 }
-impl Debug for MdHashList {
+impl Debug for MdInlineItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdHashList ")?;
+        f.write_str("MdInlineItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdHashList {
-    type Item = MdHash;
-    type IntoIter = AstNodeListIterator<Language, MdHash>;
+impl IntoIterator for &MdInlineItemList {
+    type Item = AnyMdInline;
+    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdHashList {
-    type Item = MdHash;
-    type IntoIter = AstNodeListIterator<Language, MdHash>;
+impl IntoIterator for MdInlineItemList {
+    type Item = AnyMdInline;
+    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdIndentedCodeLineList {
+pub struct MdOrderList {
     syntax_list: SyntaxList,
 }
-impl MdIndentedCodeLineList {
+impl MdOrderList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3748,12 +4503,12 @@ impl MdIndentedCodeLineList {
         }
     }
 }
-impl AstNode for MdIndentedCodeLineList {
+impl AstNode for MdOrderList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENTED_CODE_LINE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENTED_CODE_LINE_LIST
+        kind == MD_ORDER_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3771,7 +4526,7 @@ impl AstNode for MdIndentedCodeLineList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdIndentedCodeLineList {
+impl Serialize for MdOrderList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3783,9 +4538,9 @@ impl Serialize for MdIndentedCodeLineList {
         seq.end()
     }
 }
-impl AstNodeList for MdIndentedCodeLineList {
+impl AstNodeList for MdOrderList {
     type Language = Language;
-    type Node = MdIndentedCodeLine;
+    type Node = MdOrderBullet;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3793,31 +4548,31 @@ impl AstNodeList for MdIndentedCodeLineList {
         self.syntax_list
     }
 }
-impl Debug for MdIndentedCodeLineList {
+impl Debug for MdOrderList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdIndentedCodeLineList ")?;
+        f.write_str("MdOrderList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdIndentedCodeLineList {
-    type Item = MdIndentedCodeLine;
-    type IntoIter = AstNodeListIterator<Language, MdIndentedCodeLine>;
+impl IntoIterator for &MdOrderList {
+    type Item = MdOrderBullet;
+    type IntoIter = AstNodeListIterator<Language, MdOrderBullet>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdIndentedCodeLineList {
-    type Item = MdIndentedCodeLine;
-    type IntoIter = AstNodeListIterator<Language, MdIndentedCodeLine>;
+impl IntoIterator for MdOrderList {
+    type Item = MdOrderBullet;
+    type IntoIter = AstNodeListIterator<Language, MdOrderBullet>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdInlineItemList {
+pub struct MdTableCellList {
     syntax_list: SyntaxList,
 }
-impl MdInlineItemList {
+impl MdTableCellList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3830,12 +4585,12 @@ impl MdInlineItemList {
         }
     }
 }
-impl AstNode for MdInlineItemList {
+impl AstNode for MdTableCellList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITEM_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_CELL_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_ITEM_LIST
+        kind == MD_TABLE_CELL_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3853,7 +4608,7 @@ impl AstNode for MdInlineItemList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdInlineItemList {
+impl Serialize for MdTableCellList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3865,9 +4620,9 @@ impl Serialize for MdInlineItemList {
         seq.end()
     }
 }
-impl AstNodeList for MdInlineItemList {
+impl AstNodeList for MdTableCellList {
     type Language = Language;
-    type Node = AnyMdInline;
+    type Node = MdTableCell;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3875,31 +4630,31 @@ impl AstNodeList for MdInlineItemList {
         self.syntax_list
     }
 }
-impl Debug for MdInlineItemList {
+impl Debug for MdTableCellList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdInlineItemList ")?;
+        f.write_str("MdTableCellList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdInlineItemList {
-    type Item = AnyMdInline;
-    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+impl IntoIterator for &MdTableCellList {
+    type Item = MdTableCell;
+    type IntoIter = AstNodeListIterator<Language, MdTableCell>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdInlineItemList {
-    type Item = AnyMdInline;
-    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+impl IntoIterator for MdTableCellList {
+    type Item = MdTableCell;
+    type IntoIter = AstNodeListIterator<Language, MdTableCell>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdOrderList {
+pub struct MdTableRowList {
     syntax_list: SyntaxList,
 }
-impl MdOrderList {
+impl MdTableRowList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3912,12 +4667,12 @@ impl MdOrderList {
         }
     }
 }
-impl AstNode for MdOrderList {
+impl AstNode for MdTableRowList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_ROW_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_ORDER_LIST
+        kind == MD_TABLE_ROW_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3935,7 +4690,7 @@ impl AstNode for MdOrderList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdOrderList {
+impl Serialize for MdTableRowList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3947,9 +4702,9 @@ impl Serialize for MdOrderList {
         seq.end()
     }
 }
-impl AstNodeList for MdOrderList {
+impl AstNodeList for MdTableRowList {
     type Language = Language;
-    type Node = AnyCodeBlock;
+    type Node = MdTableRow;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3957,22 +4712,22 @@ impl AstNodeList for MdOrderList {
         self.syntax_list
     }
 }
-impl Debug for MdOrderList {
+impl Debug for MdTableRowList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdOrderList ")?;
+        f.write_str("MdTableRowList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdOrderList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+impl IntoIterator for &MdTableRowList {
+    type Item = MdTableRow;
+    type IntoIter = AstNodeListIterator<Language, MdTableRow>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdOrderList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+impl IntoIterator for MdTableRowList {
+    type Item = MdTableRow;
+    type IntoIter = AstNodeListIterator<Language, MdTableRow>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
@@ -10,7 +10,7 @@ use biome_js_syntax::AnyJsImportLike;
 use biome_js_type_info::{BindingId, ImportSymbol, ResolvedTypeId, ScopeId, TypeData};
 use biome_jsdoc_comment::JsdocComment;
 use biome_resolver::ResolvedPath;
-use biome_rowan::{Text, TextRange};
+use biome_rowan::{Text, TextRange, TextSize};
 use camino::Utf8Path;
 use indexmap::IndexMap;
 use rust_lapper::Lapper;
@@ -24,6 +24,7 @@ use scope::{JsScope, JsScopeData, TsBindingReference};
 
 use crate::diagnostics::ModuleDiagnostic;
 pub(super) use binding::JsBindingData;
+pub use binding::JsDeclarationKind;
 pub use diagnostics::JsModuleInfoDiagnostic;
 pub use module_resolver::ModuleResolver;
 pub(crate) use visitor::JsModuleVisitor;
@@ -297,6 +298,37 @@ impl JsModuleInfoInner {
         }
     }
 
+    /// Returns the ID of the binding declared at the given `range`.
+    pub(crate) fn find_binding_by_range(&self, range: TextRange) -> Option<BindingId> {
+        self.bindings
+            .iter()
+            .position(|binding| binding.range == range)
+            .map(BindingId::new)
+    }
+
+    /// Returns the ID of the binding with the given `name` in the global
+    /// scope.
+    pub(crate) fn find_global_binding(&self, name: &str) -> Option<BindingId> {
+        self.scopes[0]
+            .bindings_by_name
+            .get(name)
+            .map(|binding_ref| binding_ref.value_ty_or_ty())
+    }
+
+    /// Returns the ranges of the declaration and all references of a binding.
+    pub(crate) fn binding_ranges(&self, binding_id: BindingId) -> Vec<TextRange> {
+        let binding = self.binding(binding_id);
+        let name_len = TextSize::of(binding.name.text());
+        std::iter::once(binding.range)
+            .chain(
+                binding
+                    .references
+                    .iter()
+                    .map(|reference| TextRange::at(reference.range_start, name_len)),
+            )
+            .collect()
+    }
+
     pub fn types(&self) -> Vec<&TypeData> {
         self.types.iter().map(Arc::as_ref).collect()
     }
@@ -208,7 +208,8 @@ impl JsDeclarationKind {
                             Self::Module
                         }
                     }
-                    AnyJsDeclaration::TsTypeAliasDeclaration(_) => Self::Type,
+                    AnyJsDeclaration::TsOpaqueTypeAliasDeclaration(_)
+                    | AnyJsDeclaration::TsTypeAliasDeclaration(_) => Self::Type,
                     AnyJsDeclaration::TsGlobalDeclaration(_)
                     | AnyJsDeclaration::TsImportEqualsDeclaration(_) => Self::Unknown,
                 };
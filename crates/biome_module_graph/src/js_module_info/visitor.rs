@@ -197,6 +197,9 @@ impl<'a> JsModuleVisitor<'a> {
                 AnyTsModuleName::AnyTsIdentifierBinding(node) => get_ts_name(node)?,
                 AnyTsModuleName::TsQualifiedModuleName(_) => return None,
             },
+            AnyJsDeclarationClause::TsOpaqueTypeAliasDeclaration(node) => {
+                node.binding_identifier().ok().and_then(get_ts_name)?
+            }
             AnyJsDeclarationClause::TsTypeAliasDeclaration(node) => {
                 node.binding_identifier().ok().and_then(get_ts_name)?
             }
@@ -12,9 +12,10 @@ pub use biome_resolver::ResolvedPath;
 pub use css_module_info::{CssImport, CssImports, CssModuleInfo};
 pub use diagnostics::ModuleDiagnostic;
 pub use js_module_info::{
-    JsExport, JsImport, JsImportPath, JsImportPhase, JsModuleInfo, JsModuleInfoDiagnostic,
-    JsOwnExport, JsReexport, ModuleResolver, SerializedJsModuleInfo,
+    JsDeclarationKind, JsExport, JsImport, JsImportPath, JsImportPhase, JsModuleInfo,
+    JsModuleInfoDiagnostic, JsOwnExport, JsReexport, ModuleResolver, SerializedJsModuleInfo,
 };
 pub use module_graph::{
-    ModuleDependencies, ModuleGraph, ModuleInfo, SUPPORTED_EXTENSIONS, SerializedModuleInfo,
+    ExportedDeclaration, ModuleDependencies, ModuleGraph, ModuleInfo, NamespaceImport,
+    SUPPORTED_EXTENSIONS, SerializedModuleInfo,
 };
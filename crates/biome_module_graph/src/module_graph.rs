@@ -12,8 +12,8 @@ use std::{collections::BTreeSet, ops::Deref};
 
 use crate::css_module_info::{CssModuleInfo, CssModuleVisitor, SerializedCssModuleInfo};
 use crate::{
-    JsExport, JsModuleInfo, JsOwnExport, ModuleDiagnostic, SerializedJsModuleInfo,
-    js_module_info::JsModuleVisitor,
+    JsDeclarationKind, JsExport, JsImport, JsModuleInfo, JsOwnExport, ModuleDiagnostic,
+    SerializedJsModuleInfo, js_module_info::JsModuleVisitor,
 };
 use biome_css_syntax::CssRoot;
 use biome_fs::BiomePath;
@@ -22,6 +22,7 @@ use biome_js_type_info::ImportSymbol;
 use biome_jsdoc_comment::JsdocComment;
 use biome_project_layout::ProjectLayout;
 use biome_resolver::{FsWithResolverProxy, PathInfo};
+use biome_rowan::{Text, TextRange};
 use camino::{Utf8Path, Utf8PathBuf};
 pub(crate) use fs_proxy::ModuleGraphFsProxy;
 use papaya::{HashMap, HashMapRef, LocalGuard};
@@ -31,6 +32,31 @@ pub const SUPPORTED_EXTENSIONS: &[&str] = &[
     "ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs", "json", "node",
 ];
 
+/// A declaration exported by a module, see [ModuleGraph::exported_declarations].
+#[derive(Clone, Debug)]
+pub struct ExportedDeclaration {
+    /// Path of the module that declares the symbol
+    pub path: Utf8PathBuf,
+    /// The name the symbol is exported with
+    pub name: Text,
+    /// Range of the binding of the symbol
+    pub range: TextRange,
+    pub kind: JsDeclarationKind,
+}
+
+/// A namespace import through which a declaration can be accessed, see
+/// [ModuleGraph::find_namespace_imports_of_declaration].
+#[derive(Clone, Debug)]
+pub struct NamespaceImport {
+    /// Path of the importing module
+    pub path: Utf8PathBuf,
+    /// The local name of the namespace, e.g. `ns` in `import * as ns from "./mod"`
+    pub namespace: Text,
+    /// The name the declaration is exported with, and accessed through the
+    /// namespace, e.g. `ns.name`
+    pub name: Text,
+}
+
 /// Data structure for tracking imports and exports across files.
 ///
 /// The module graph is also augmented with type information, allowing types
@@ -225,7 +251,239 @@ impl ModuleGraph {
         let mut seen_paths = BTreeSet::new();
 
         find_exported_symbol_with_seen_paths(&data, module, symbol_name, &mut seen_paths)
-            .map(|(_, export)| export.clone())
+            .map(|(_, _, export)| export.clone())
+    }
+
+    /// Finds the declaration of the symbol exported by `symbol_name` from the
+    /// module at `path`.
+    ///
+    /// Follows re-exports if necessary. Returns the path of the module that
+    /// declares the symbol, together with the range of its binding.
+    pub fn find_exported_symbol_declaration(
+        &self,
+        path: &Utf8Path,
+        symbol_name: &str,
+    ) -> Option<(Utf8PathBuf, TextRange)> {
+        let data = self.data.pin();
+        let module = data.get(path)?.as_js_module_info()?;
+        let mut seen_paths = BTreeSet::new();
+
+        let (declaring_path, module, export) =
+            find_exported_symbol_with_seen_paths(&data, module, symbol_name, &mut seen_paths)?;
+        match export {
+            JsOwnExport::Binding(binding_id) => Some((
+                declaring_path.unwrap_or(path).to_path_buf(),
+                module.binding(*binding_id).range,
+            )),
+            JsOwnExport::Type(_) => None,
+        }
+    }
+
+    /// Returns the declarations exported by the modules of the graph.
+    ///
+    /// Re-exports are skipped, so every declaration is returned once, by the
+    /// module that declares it. Default exports are named after their binding.
+    pub fn exported_declarations(&self) -> Vec<ExportedDeclaration> {
+        let data = self.data.pin();
+        let mut declarations = Vec::new();
+        for (path, module) in data.iter() {
+            let Some(module) = module.as_js_module_info() else {
+                continue;
+            };
+
+            for (name, export) in module.exports.iter() {
+                let Some(JsOwnExport::Binding(binding_id)) = export.as_own_export() else {
+                    continue;
+                };
+                let binding = module.binding(*binding_id);
+                let name = if name.text() == "default" && !binding.name.text().is_empty() {
+                    binding.name.clone()
+                } else {
+                    name.clone()
+                };
+                declarations.push(ExportedDeclaration {
+                    path: path.clone(),
+                    name,
+                    range: binding.range,
+                    kind: binding.declaration_kind,
+                });
+            }
+        }
+
+        declarations
+    }
+
+    /// Finds all references to the binding declared at `range` in the module
+    /// at `path`, across the declaring module and all the modules that import
+    /// it.
+    ///
+    /// Imports are matched by the declaration they resolve to, so references
+    /// that go through re-exports are included as well. The result includes
+    /// the declaration itself, as well as the local bindings created by
+    /// importing the symbol.
+    ///
+    /// The members accessed through namespace imports, e.g. `ns.name`, aren't
+    /// tracked by the module graph. Use
+    /// [Self::find_namespace_imports_of_declaration] to find the namespaces
+    /// whose members should be looked up.
+    pub fn find_references_to_declaration(
+        &self,
+        path: &Utf8Path,
+        range: TextRange,
+    ) -> Vec<(Utf8PathBuf, TextRange)> {
+        let data = self.data.pin();
+        let Some(declaring_module) = data.get(path).and_then(ModuleInfo::as_js_module_info) else {
+            return Vec::new();
+        };
+        let Some(binding_id) = declaring_module.find_binding_by_range(range) else {
+            return Vec::new();
+        };
+
+        let mut references: Vec<_> = declaring_module
+            .binding_ranges(binding_id)
+            .into_iter()
+            .map(|range| (path.to_path_buf(), range))
+            .collect();
+
+        for (module_path, module) in data.iter() {
+            let Some(module) = module.as_js_module_info() else {
+                continue;
+            };
+
+            for (local_name, import) in module.static_imports.iter() {
+                let imported_name = match &import.symbol {
+                    ImportSymbol::Default => "default",
+                    ImportSymbol::Named(name) => name.text(),
+                    ImportSymbol::All => continue,
+                };
+                if !self.import_resolves_to_declaration(import, imported_name, path, range) {
+                    continue;
+                }
+
+                if let Some(local_binding_id) = module.find_global_binding(local_name) {
+                    references.extend(
+                        module
+                            .binding_ranges(local_binding_id)
+                            .into_iter()
+                            .map(|range| (module_path.clone(), range)),
+                    );
+                }
+            }
+        }
+
+        references
+    }
+
+    /// Finds the namespace imports through which the binding declared at
+    /// `range` in the module at `path` can be accessed, such as
+    /// `import * as ns from "./mod"` followed by `ns.name`.
+    ///
+    /// The binding is looked up by all the names it's exported with, and
+    /// imports are matched by the declaration they resolve to, so namespaces
+    /// of modules that re-export the binding are included as well.
+    pub fn find_namespace_imports_of_declaration(
+        &self,
+        path: &Utf8Path,
+        range: TextRange,
+    ) -> Vec<NamespaceImport> {
+        let data = self.data.pin();
+        let Some(declaring_module) = data.get(path).and_then(ModuleInfo::as_js_module_info) else {
+            return Vec::new();
+        };
+        let Some(binding_id) = declaring_module.find_binding_by_range(range) else {
+            return Vec::new();
+        };
+        let exported_names: Vec<_> = declaring_module
+            .exports
+            .iter()
+            .filter(|(_, export)| export.as_own_export() == Some(&JsOwnExport::Binding(binding_id)))
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        let mut namespace_imports = Vec::new();
+        for (module_path, module) in data.iter() {
+            let Some(module) = module.as_js_module_info() else {
+                continue;
+            };
+
+            for (local_name, import) in module.static_imports.iter() {
+                if import.symbol != ImportSymbol::All {
+                    continue;
+                }
+
+                for name in &exported_names {
+                    if self.import_resolves_to_declaration(import, name.text(), path, range) {
+                        namespace_imports.push(NamespaceImport {
+                            path: module_path.clone(),
+                            namespace: local_name.clone(),
+                            name: name.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        namespace_imports
+    }
+
+    /// Finds the modules that refer to the binding declared at `range` in the
+    /// module at `path` by its exported `name`.
+    ///
+    /// These are the modules that import the symbol by name or through a
+    /// namespace import, as well as the modules that re-export it by name.
+    /// The declaring module itself is not included.
+    pub fn find_modules_importing_declaration(
+        &self,
+        path: &Utf8Path,
+        range: TextRange,
+        name: &str,
+    ) -> Vec<Utf8PathBuf> {
+        let refers_to_declaration = |import: &JsImport| match &import.symbol {
+            ImportSymbol::Named(imported_name) => {
+                imported_name.text() == name
+                    && self.import_resolves_to_declaration(import, name, path, range)
+            }
+            ImportSymbol::All => self.import_resolves_to_declaration(import, name, path, range),
+            ImportSymbol::Default => false,
+        };
+
+        let data = self.data.pin();
+        data.iter()
+            .filter(|(module_path, _)| module_path.as_path() != path)
+            .filter_map(|(module_path, module)| {
+                let module = module.as_js_module_info()?;
+                let imports_declaration = module.static_imports.values().any(refers_to_declaration);
+                let reexports_declaration = module.exports.values().any(|export| match export {
+                    JsExport::Reexport(reexport) | JsExport::ReexportType(reexport) => {
+                        reexport.import.symbol != ImportSymbol::All
+                            && refers_to_declaration(&reexport.import)
+                    }
+                    _ => false,
+                });
+
+                (imports_declaration || reexports_declaration).then(|| module_path.clone())
+            })
+            .collect()
+    }
+
+    /// Returns whether the symbol `imported_name` imported through `import`
+    /// resolves to the binding declared at `range` in the module at `path`.
+    pub fn import_resolves_to_declaration(
+        &self,
+        import: &JsImport,
+        imported_name: &str,
+        path: &Utf8Path,
+        range: TextRange,
+    ) -> bool {
+        import
+            .resolved_path
+            .as_path()
+            .and_then(|import_path| {
+                self.find_exported_symbol_declaration(import_path, imported_name)
+            })
+            .is_some_and(|(declaring_path, declaring_range)| {
+                declaring_path == path && declaring_range == range
+            })
     }
 
     /// Finds an exported symbol by `symbol_name` as exported by `module`.
@@ -240,7 +498,7 @@ impl ModuleGraph {
         let mut seen_paths = BTreeSet::new();
 
         find_exported_symbol_with_seen_paths(&data, module, symbol_name, &mut seen_paths).and_then(
-            |(module, export)| match export {
+            |(_, module, export)| match export {
                 JsOwnExport::Binding(binding_id) => {
                     module.bindings[binding_id.index()].jsdoc.clone()
                 }
@@ -316,15 +574,20 @@ impl ModuleInfo {
     }
 }
 
+/// Finds an exported symbol by `symbol_name` as exported by `module`.
+///
+/// Returns the module that owns the export, together with its path if it was
+/// reached through a re-export. If the path is `None`, the export is owned by
+/// `module` itself.
 fn find_exported_symbol_with_seen_paths<'a>(
     data: &'a HashMapRef<Utf8PathBuf, ModuleInfo, FxBuildHasher, LocalGuard>,
     module: &'a JsModuleInfo,
     symbol_name: &str,
     seen_paths: &mut BTreeSet<&'a Utf8Path>,
-) -> Option<(&'a JsModuleInfo, &'a JsOwnExport)> {
+) -> Option<(Option<&'a Utf8Path>, &'a JsModuleInfo, &'a JsOwnExport)> {
     match module.exports.get(symbol_name) {
         Some(JsExport::Own(own_export) | JsExport::OwnType(own_export)) => {
-            Some((module, own_export))
+            Some((None, module, own_export))
         }
         Some(JsExport::Reexport(reexport) | JsExport::ReexportType(reexport)) => {
             if reexport.import.symbol == ImportSymbol::All {
@@ -332,37 +595,36 @@ fn find_exported_symbol_with_seen_paths<'a>(
                 None
             } else {
                 match reexport.import.resolved_path.as_deref() {
-                    Ok(path) if seen_paths.insert(path) => data.get(path).and_then(|module| {
-                        if let ModuleInfo::Js(module) = module {
-                            find_exported_symbol_with_seen_paths(
-                                data,
-                                module,
-                                symbol_name,
-                                seen_paths,
-                            )
-                        } else {
-                            None
-                        }
-                    }),
+                    Ok(path) if seen_paths.insert(path) => {
+                        find_exported_symbol_in_path(data, path, symbol_name, seen_paths)
+                    }
                     _ => None,
                 }
             }
         }
         None => module.blanket_reexports.iter().find_map(|reexport| {
             match reexport.import.resolved_path.as_deref() {
-                Ok(path) if seen_paths.insert(path) => data.get(path).and_then(|module| {
-                    if let ModuleInfo::Js(module) = module {
-                        find_exported_symbol_with_seen_paths(data, module, symbol_name, seen_paths)
-                    } else {
-                        None
-                    }
-                }),
+                Ok(path) if seen_paths.insert(path) => {
+                    find_exported_symbol_in_path(data, path, symbol_name, seen_paths)
+                }
                 _ => None,
             }
         }),
     }
 }
 
+fn find_exported_symbol_in_path<'a>(
+    data: &'a HashMapRef<Utf8PathBuf, ModuleInfo, FxBuildHasher, LocalGuard>,
+    path: &'a Utf8Path,
+    symbol_name: &str,
+    seen_paths: &mut BTreeSet<&'a Utf8Path>,
+) -> Option<(Option<&'a Utf8Path>, &'a JsModuleInfo, &'a JsOwnExport)> {
+    let module = data.get(path)?.as_js_module_info()?;
+    let (owner_path, module, export) =
+        find_exported_symbol_with_seen_paths(data, module, symbol_name, seen_paths)?;
+    Some((Some(owner_path.unwrap_or(path)), module, export))
+}
+
 /// Represents all the files that are imported/depended on by a module.
 #[derive(Debug, Default)]
 pub struct ModuleDependencies(FxHashSet<Utf8PathBuf>);
@@ -35,6 +35,17 @@ impl ResolverFsProxy for ModuleGraphFsProxy<'_> {
             .ok_or(ResolveError::ManifestNotFound)
     }
 
+    fn find_workspace_package(
+        &self,
+        search_dir: &Utf8Path,
+        package_name: &str,
+    ) -> Result<Utf8PathBuf, ResolveError> {
+        self.project_layout
+            .find_workspace_package_by_name(search_dir, package_name)
+            .map(|(package_path, _)| package_path)
+            .ok_or(ResolveError::NotFound)
+    }
+
     fn path_info(&self, path: &Utf8Path) -> Result<PathInfo, ResolveError> {
         self.module_graph
             .get_or_insert_path_info(path, self.fs)
@@ -18,9 +18,9 @@ use biome_module_graph::{
     JsModuleInfoDiagnostic, JsReexport, ModuleDiagnostic, ModuleGraph, ModuleResolver,
     ResolvedPath,
 };
-use biome_package::{Dependencies, PackageJson};
+use biome_package::{Dependencies, PackageJson, Workspaces};
 use biome_project_layout::ProjectLayout;
-use biome_rowan::Text;
+use biome_rowan::{Text, TextRange, TextSize};
 use biome_test_utils::{get_added_paths, get_css_added_paths};
 use camino::{Utf8Path, Utf8PathBuf};
 use walkdir::WalkDir;
@@ -324,6 +324,86 @@ fn test_resolve_package_import_in_monorepo_fixtures() {
     );
 }
 
+#[test]
+fn test_resolve_workspace_dependency_to_sources() {
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        "/packages/app/src/index.ts".into(),
+        r#"
+            import { greet } from "@monorepo/shared";
+            import { format } from "@monorepo/shared/format";
+        "#,
+    );
+    fs.insert(
+        "/packages/shared/src/index.ts".into(),
+        r#"
+            export function greet() {}
+        "#,
+    );
+    fs.insert(
+        "/packages/shared/src/format.ts".into(),
+        r#"
+            export function format() {}
+        "#,
+    );
+
+    let project_layout = ProjectLayout::default();
+    project_layout.insert_node_manifest(
+        "/".into(),
+        PackageJson::new("monorepo").with_workspaces(Workspaces(Box::new(["packages/*".into()]))),
+    );
+    project_layout.insert_node_manifest(
+        "/packages/app".into(),
+        PackageJson::new("@monorepo/app").with_dependencies(Dependencies(Box::new([(
+            "@monorepo/shared".into(),
+            "workspace:*".into(),
+        )]))),
+    );
+    project_layout.insert_node_manifest(
+        "/packages/shared".into(),
+        PackageJson::new("@monorepo/shared").with_exports(JsonObject::from([
+            (".".into(), JsonString::from("./src/index.ts").into()),
+            (
+                "./format".into(),
+                JsonString::from("./src/format.ts").into(),
+            ),
+        ])),
+    );
+
+    let added_paths = [
+        BiomePath::new("/packages/app/src/index.ts"),
+        BiomePath::new("/packages/shared/src/index.ts"),
+        BiomePath::new("/packages/shared/src/format.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &project_layout, &added_paths);
+
+    let imports = module_graph.data();
+    let file_imports = imports
+        .get(Utf8Path::new("/packages/app/src/index.ts"))
+        .unwrap();
+    let file_imports = file_imports.as_js_module_info().unwrap();
+
+    assert_eq!(
+        file_imports.static_imports.get("greet"),
+        Some(&JsImport {
+            specifier: "@monorepo/shared".into(),
+            resolved_path: ResolvedPath::from_path("/packages/shared/src/index.ts"),
+            symbol: "greet".into()
+        })
+    );
+    assert_eq!(
+        file_imports.static_imports.get("format"),
+        Some(&JsImport {
+            specifier: "@monorepo/shared/format".into(),
+            resolved_path: ResolvedPath::from_path("/packages/shared/src/format.ts"),
+            symbol: "format".into()
+        })
+    );
+}
+
 #[test]
 fn test_export_referenced_function() {
     let fs = MemoryFileSystem::default();
@@ -2289,6 +2369,61 @@ fn resolves_css_imports_correctly() {
     );
 }
 
+#[test]
+fn test_find_references_to_declaration() {
+    let fs = MemoryFileSystem::default();
+    let foo = "export function foo() {}\nfoo();\n";
+    let reexport = "export { foo } from \"./foo.ts\";\n";
+    let index = "import { foo as bar } from \"./reexport.ts\";\nbar();\nbar();\n";
+    fs.insert("/src/foo.ts".into(), foo);
+    fs.insert("/src/reexport.ts".into(), reexport);
+    fs.insert("/src/index.ts".into(), index);
+
+    let added_paths = [
+        BiomePath::new("/src/foo.ts"),
+        BiomePath::new("/src/reexport.ts"),
+        BiomePath::new("/src/index.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths);
+
+    let range_of = |source: &str, name: &str, nth: usize| {
+        let start = source.match_indices(name).nth(nth).unwrap().0;
+        TextRange::at(TextSize::from(start as u32), TextSize::of(name))
+    };
+
+    let declaration = range_of(foo, "foo", 0);
+    assert_eq!(
+        module_graph.find_exported_symbol_declaration(Utf8Path::new("/src/reexport.ts"), "foo"),
+        Some((Utf8PathBuf::from("/src/foo.ts"), declaration))
+    );
+
+    let mut references =
+        module_graph.find_references_to_declaration(Utf8Path::new("/src/foo.ts"), declaration);
+    references.sort();
+    assert_eq!(
+        references,
+        [
+            (Utf8PathBuf::from("/src/foo.ts"), declaration),
+            (Utf8PathBuf::from("/src/foo.ts"), range_of(foo, "foo", 1)),
+            (
+                Utf8PathBuf::from("/src/index.ts"),
+                range_of(index, "bar", 0)
+            ),
+            (
+                Utf8PathBuf::from("/src/index.ts"),
+                range_of(index, "bar", 1)
+            ),
+            (
+                Utf8PathBuf::from("/src/index.ts"),
+                range_of(index, "bar", 2)
+            ),
+        ]
+    );
+}
+
 fn find_files_recursively_in_directory(
     directory: &Utf8Path,
     predicate: impl Fn(&Utf8Path) -> bool,
@@ -10,8 +10,8 @@ use biome_fs::FileSystem;
 use camino::Utf8Path;
 pub use license::generated::*;
 pub use node_js_package::{
-    CompilerOptions, Dependencies, NodeJsPackage, PackageJson, PackageType, TsConfigJson,
-    TurboJson, Version,
+    CompilerOptions, Dependencies, NodeJsPackage, PackageJson, PackageType, PnpmWorkspace,
+    TsConfigJson, TurboJson, Version, Workspaces,
 };
 
 use std::any::TypeId;
@@ -1,9 +1,11 @@
 mod package_json;
+mod pnpm_workspace;
 mod tsconfig_json;
 mod turbo_json;
 
 use camino::Utf8Path;
-pub use package_json::{Dependencies, PackageJson, PackageType, Version};
+pub use package_json::{Dependencies, PackageJson, PackageType, Version, Workspaces};
+pub use pnpm_workspace::PnpmWorkspace;
 pub use tsconfig_json::{CompilerOptions, TsConfigJson};
 pub use turbo_json::TurboJson;
 
@@ -26,6 +28,9 @@ pub struct NodeJsPackage {
 
     /// The `turbo.json` manifest for Turborepo projects.
     pub turbo_json: Option<Arc<TurboJson>>,
+
+    /// The `pnpm-workspace.yaml` manifest for pnpm workspaces.
+    pub pnpm_workspace: Option<Arc<PnpmWorkspace>>,
 }
 
 impl NodeJsPackage {
@@ -57,12 +62,43 @@ impl NodeJsPackage {
             .collect();
     }
 
+    pub fn insert_serialized_pnpm_workspace(
+        &mut self,
+        content: &ProjectLanguageRoot<PnpmWorkspace>,
+        path: &Utf8Path,
+    ) {
+        let pnpm_workspace = PnpmWorkspace::deserialize_manifest(content, path);
+        let (pnpm_workspace, deserialize_diagnostics) = pnpm_workspace.consume();
+        self.pnpm_workspace = Some(Arc::new(pnpm_workspace.unwrap_or_default()));
+        self.diagnostics = deserialize_diagnostics
+            .into_iter()
+            .map(biome_diagnostics::serde::Diagnostic::new)
+            .collect();
+    }
+
+    /// Returns the glob patterns of the packages of the workspace, if this
+    /// package is the root of a workspace.
+    ///
+    /// Patterns from `pnpm-workspace.yaml` take precedence over the
+    /// `workspaces` field of `package.json`, like pnpm does.
+    pub fn workspace_patterns(&self) -> Option<&[Box<str>]> {
+        if let Some(pnpm_workspace) = &self.pnpm_workspace {
+            return Some(&pnpm_workspace.packages);
+        }
+
+        self.manifest
+            .as_ref()
+            .map(|manifest| manifest.workspaces.as_ref())
+            .filter(|patterns| !patterns.is_empty())
+    }
+
     pub fn without_tsconfig(&self) -> Self {
         Self {
             manifest: self.manifest.clone(),
             diagnostics: self.diagnostics.clone(),
             tsconfig: None,
             turbo_json: self.turbo_json.clone(),
+            pnpm_workspace: self.pnpm_workspace.clone(),
         }
     }
 
@@ -72,6 +108,17 @@ impl NodeJsPackage {
             diagnostics: self.diagnostics.clone(),
             tsconfig: self.tsconfig.clone(),
             turbo_json: None,
+            pnpm_workspace: self.pnpm_workspace.clone(),
+        }
+    }
+
+    pub fn without_pnpm_workspace(&self) -> Self {
+        Self {
+            manifest: self.manifest.clone(),
+            diagnostics: self.diagnostics.clone(),
+            tsconfig: self.tsconfig.clone(),
+            turbo_json: self.turbo_json.clone(),
+            pnpm_workspace: None,
         }
     }
 }
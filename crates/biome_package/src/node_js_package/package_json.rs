@@ -40,6 +40,12 @@ pub struct PackageJson {
     pub imports: Option<JsonValue>,
     pub main: Option<Box<str>>,
     pub types: Option<Box<str>>,
+
+    /// The glob patterns of the packages of the workspace, if this package is
+    /// the root of an npm, Yarn, or Bun workspace.
+    ///
+    /// <https://docs.npmjs.com/cli/using-npm/workspaces>
+    pub workspaces: Workspaces,
 }
 
 static_assertions::assert_impl_all!(PackageJson: Send, Sync);
@@ -74,6 +80,33 @@ impl PackageJson {
         }
     }
 
+    pub fn with_workspaces(self, workspaces: Workspaces) -> Self {
+        Self { workspaces, ..self }
+    }
+
+    /// Returns the version range with which the dependency `specifier` is
+    /// declared in `dependencies`, `dev_dependencies`, `peer_dependencies` or
+    /// `optional_dependencies`.
+    pub fn dependency_range(&self, specifier: &str) -> Option<&str> {
+        self.dependencies
+            .iter()
+            .chain(self.dev_dependencies.iter())
+            .chain(self.peer_dependencies.iter())
+            .chain(self.optional_dependencies.iter())
+            .find(|(dependency_name, _)| dependency_name.as_ref() == specifier)
+            .map(|(_, range)| range.as_ref())
+    }
+
+    /// Checks whether the `specifier` is declared using the `workspace:`
+    /// protocol, meaning it must be resolved to a package of the same
+    /// workspace.
+    ///
+    /// <https://pnpm.io/workspaces#workspace-protocol-workspace>
+    pub fn is_workspace_dependency(&self, specifier: &str) -> bool {
+        self.dependency_range(specifier)
+            .is_some_and(|range| range.starts_with("workspace:"))
+    }
+
     /// Checks whether the `specifier` is defined in `dependencies`,
     /// `dev_dependencies` or `peer_dependencies`
     pub fn contains_dependency(&self, specifier: &str) -> bool {
@@ -191,6 +224,77 @@ impl Dependencies {
     }
 }
 
+/// The `workspaces` field of a `package.json`.
+///
+/// Both the array form and the object form with a `packages` field, as used
+/// by Yarn, are supported.
+#[derive(Debug, Default, Clone)]
+pub struct Workspaces(pub Box<[Box<str>]>);
+
+impl Deserializable for Workspaces {
+    fn deserialize(
+        ctx: &mut impl DeserializationContext,
+        value: &impl DeserializableValue,
+        name: &str,
+    ) -> Option<Self> {
+        struct Visitor;
+        impl DeserializationVisitor for Visitor {
+            type Output = Workspaces;
+            const EXPECTED_TYPE: DeserializableTypes =
+                DeserializableTypes::ARRAY.union(DeserializableTypes::MAP);
+
+            fn visit_array(
+                self,
+                ctx: &mut impl DeserializationContext,
+                items: impl ExactSizeIterator<Item = Option<impl DeserializableValue>>,
+                _range: TextRange,
+                name: &str,
+            ) -> Option<Self::Output> {
+                let result = items
+                    .flatten()
+                    .filter_map(|item| Deserializable::deserialize(ctx, &item, name))
+                    .collect::<Vec<_>>();
+
+                Some(Workspaces(result.into_boxed_slice()))
+            }
+
+            fn visit_map(
+                self,
+                ctx: &mut impl DeserializationContext,
+                members: impl Iterator<
+                    Item = Option<(impl DeserializableValue, impl DeserializableValue)>,
+                >,
+                _range: TextRange,
+                _name: &str,
+            ) -> Option<Self::Output> {
+                let mut result = Workspaces::default();
+                for (key, value) in members.flatten() {
+                    let Some(key_text) = Text::deserialize(ctx, &key, "") else {
+                        continue;
+                    };
+                    if key_text.text() == "packages"
+                        && let Some(packages) = Deserializable::deserialize(ctx, &value, &key_text)
+                    {
+                        result = packages;
+                    }
+                }
+
+                Some(result)
+            }
+        }
+
+        value.deserialize(ctx, Visitor, name)
+    }
+}
+
+impl Deref for Workspaces {
+    type Target = Box<[Box<str>]>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Version {
     SemVer(Range),
@@ -315,6 +419,11 @@ impl DeserializationVisitor for PackageJsonVisitor {
                         result.main = Some(value);
                     }
                 }
+                "workspaces" => {
+                    if let Some(workspaces) = Deserializable::deserialize(ctx, &value, &key_text) {
+                        result.workspaces = workspaces;
+                    }
+                }
                 _ => {}
             }
         }
@@ -386,6 +495,46 @@ mod tests {
         assert_eq!(package_json.author, Some("Biome Team".into()));
     }
 
+    #[test]
+    fn parse_package_json_workspaces_field() {
+        let deserialized = deserialize_from_json_str::<PackageJson>(
+            r#"{
+    "name": "monorepo",
+    "workspaces": ["packages/*", "apps/*"]
+}"#,
+            JsonParserOptions::default(),
+            "",
+        );
+        let (package_json, errors) = deserialized.consume();
+        assert!(errors.is_empty());
+
+        let package_json = package_json.expect("parsing must have succeeded");
+        assert_eq!(
+            package_json.workspaces.as_ref(),
+            &["packages/*".into(), "apps/*".into()]
+        );
+    }
+
+    #[test]
+    fn parse_package_json_workspaces_field_with_packages() {
+        let deserialized = deserialize_from_json_str::<PackageJson>(
+            r#"{
+    "name": "monorepo",
+    "workspaces": {
+        "packages": ["packages/*"],
+        "nohoist": ["**/react-native"]
+    }
+}"#,
+            JsonParserOptions::default(),
+            "",
+        );
+        let (package_json, errors) = deserialized.consume();
+        assert!(errors.is_empty());
+
+        let package_json = package_json.expect("parsing must have succeeded");
+        assert_eq!(package_json.workspaces.as_ref(), &["packages/*".into()]);
+    }
+
     #[test]
     fn should_not_panic_on_invalid_semver_range() {
         let result = parse_range("~0.x.0");
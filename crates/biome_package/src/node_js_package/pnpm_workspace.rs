@@ -0,0 +1,191 @@
+use crate::{LanguageRoot, Manifest};
+use biome_deserialize::Deserialized;
+use biome_diagnostics::Error;
+use biome_rowan::{AstNodeList, AstSeparatedList};
+use biome_yaml_parser::parse_yaml;
+use biome_yaml_syntax::{
+    AnyYamlBlockInBlockNode, AnyYamlBlockMapEntry, AnyYamlBlockNode, AnyYamlBlockSequenceEntry,
+    AnyYamlDocument, AnyYamlFlowNode, AnyYamlFlowSequenceEntry, AnyYamlJsonContent,
+    AnyYamlMappingImplicitKey, YamlLanguage,
+};
+use camino::Utf8Path;
+
+/// Deserialized `pnpm-workspace.yaml`.
+///
+/// <https://pnpm.io/pnpm-workspace_yaml>
+#[derive(Debug, Default, Clone)]
+pub struct PnpmWorkspace {
+    /// The glob patterns of the packages of the workspace.
+    pub packages: Box<[Box<str>]>,
+}
+
+impl Manifest for PnpmWorkspace {
+    type Language = YamlLanguage;
+
+    fn deserialize_manifest(
+        root: &LanguageRoot<Self::Language>,
+        _path: &Utf8Path,
+    ) -> Deserialized<Self> {
+        let packages = root
+            .documents()
+            .iter()
+            .find_map(|document| match document {
+                AnyYamlDocument::YamlDocument(document) => document.node(),
+                AnyYamlDocument::YamlBogus(_) => None,
+            })
+            .and_then(|node| packages_from_document(&node))
+            .unwrap_or_default();
+
+        Deserialized::new(
+            Some(Self {
+                packages: packages.into_boxed_slice(),
+            }),
+            Vec::new(),
+        )
+    }
+
+    fn read_manifest(fs: &dyn biome_fs::FileSystem, path: &Utf8Path) -> Deserialized<Self> {
+        match fs.read_file_from_path(path) {
+            Ok(content) => Self::deserialize_manifest(&parse_yaml(&content).tree(), path),
+            Err(error) => Deserialized::new(None, vec![Error::from(error)]),
+        }
+    }
+}
+
+/// Returns the items of the `packages` sequence of the given document.
+fn packages_from_document(node: &AnyYamlBlockNode) -> Option<Vec<Box<str>>> {
+    let AnyYamlBlockNode::AnyYamlBlockInBlockNode(AnyYamlBlockInBlockNode::YamlBlockMapping(
+        mapping,
+    )) = node
+    else {
+        return None;
+    };
+
+    let value = mapping.entries().iter().find_map(|entry| match entry {
+        AnyYamlBlockMapEntry::YamlBlockMapImplicitEntry(entry) => {
+            let key = match entry.key()? {
+                AnyYamlMappingImplicitKey::YamlFlowJsonNode(node) => scalar_text(&node.into())?,
+                AnyYamlMappingImplicitKey::YamlFlowYamlNode(node) => scalar_text(&node.into())?,
+            };
+            (key.as_ref() == "packages")
+                .then(|| entry.value())
+                .flatten()
+        }
+        _ => None,
+    })?;
+
+    match value {
+        AnyYamlBlockNode::AnyYamlBlockInBlockNode(AnyYamlBlockInBlockNode::YamlBlockSequence(
+            sequence,
+        )) => Some(
+            sequence
+                .entries()
+                .iter()
+                .filter_map(|entry| match entry {
+                    AnyYamlBlockSequenceEntry::YamlBlockSequenceEntry(entry) => {
+                        match entry.value()? {
+                            AnyYamlBlockNode::YamlFlowInBlockNode(node) => {
+                                scalar_text(&node.flow().ok()?)
+                            }
+                            _ => None,
+                        }
+                    }
+                    AnyYamlBlockSequenceEntry::YamlBogus(_) => None,
+                })
+                .collect(),
+        ),
+        AnyYamlBlockNode::YamlFlowInBlockNode(node) => match node.flow().ok()? {
+            AnyYamlFlowNode::YamlFlowJsonNode(node) => match node.content()? {
+                AnyYamlJsonContent::YamlFlowSequence(sequence) => Some(
+                    sequence
+                        .entries()
+                        .iter()
+                        .filter_map(|entry| match entry.ok()? {
+                            AnyYamlFlowSequenceEntry::AnyYamlFlowNode(node) => scalar_text(&node),
+                            AnyYamlFlowSequenceEntry::AnyYamlFlowMapEntry(_) => None,
+                        })
+                        .collect(),
+                ),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the text of a plain or quoted scalar.
+fn scalar_text(node: &AnyYamlFlowNode) -> Option<Box<str>> {
+    match node {
+        AnyYamlFlowNode::YamlFlowJsonNode(node) => match node.content()? {
+            AnyYamlJsonContent::YamlDoubleQuotedScalar(scalar) => {
+                Some(scalar.inner_string_text().ok()?.text().into())
+            }
+            AnyYamlJsonContent::YamlSingleQuotedScalar(scalar) => {
+                Some(scalar.inner_string_text().ok()?.text().into())
+            }
+            _ => None,
+        },
+        AnyYamlFlowNode::YamlFlowYamlNode(node) => Some(
+            node.content()?
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                .trim()
+                .into(),
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packages(source: &str) -> Vec<Box<str>> {
+        let parsed = parse_yaml(source);
+        let (manifest, errors) =
+            PnpmWorkspace::deserialize_manifest(&parsed.tree(), Utf8Path::new("")).consume();
+        assert!(errors.is_empty());
+
+        manifest
+            .expect("deserializing must have succeeded")
+            .packages
+            .into_vec()
+    }
+
+    #[test]
+    fn parse_pnpm_workspace_packages() {
+        assert_eq!(
+            packages(
+                r#"packages:
+  - "packages/*"
+  - 'apps/*'
+  - "!**/test/**"
+  - tools
+catalog:
+  react: ^19.0.0
+"#
+            ),
+            [
+                "packages/*".into(),
+                "apps/*".into(),
+                "!**/test/**".into(),
+                "tools".into()
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_pnpm_workspace_flow_packages() {
+        assert_eq!(
+            packages(r#"packages: ["packages/*", apps/*]"#),
+            ["packages/*".into(), "apps/*".into()]
+        );
+    }
+
+    #[test]
+    fn parse_pnpm_workspace_without_packages() {
+        assert!(packages("catalog:\n  react: ^19.0.0\n").is_empty());
+    }
+}
@@ -1,15 +1,25 @@
 use crate::{AnalyzerPlugin, PluginDiagnostic};
-use biome_analyze::{PluginTargetLanguage, RuleDiagnostic};
+use biome_analyze::{
+    FixKind, PluginAction, PluginEvaluationResult, PluginTargetLanguage, RuleDiagnostic,
+};
 use biome_console::markup;
-use biome_css_syntax::{CssRoot, CssSyntaxNode};
-use biome_diagnostics::{Severity, category};
+use biome_css_parser::{CssParserOptions, parse_css};
+use biome_css_syntax::{CssFileSource, CssLanguage, CssRoot, CssSyntaxNode};
+use biome_diagnostics::{Applicability, Severity, category};
 use biome_fs::FileSystem;
+use biome_graphql_parser::parse_graphql;
+use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode};
 use biome_grit_patterns::{
     BuiltInFunction, CompilePatternOptions, GritBinding, GritExecContext, GritPattern, GritQuery,
-    GritQueryContext, GritQueryState, GritResolvedPattern, GritTargetFile, GritTargetLanguage,
-    compile_pattern_with_options,
+    GritQueryContext, GritQueryDiagnostic, GritQueryEffect, GritQueryState, GritResolvedPattern,
+    GritTargetFile, GritTargetLanguage, compile_pattern_with_options,
 };
-use biome_js_syntax::{AnyJsRoot, JsSyntaxNode};
+use biome_html_parser::{HtmlParseOptions, parse_html};
+use biome_html_syntax::{HtmlFileSource, HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
+use biome_js_parser::{JsParserOptions, parse};
+use biome_js_syntax::{AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode};
+use biome_json_parser::{JsonParserOptions, parse_json};
+use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
 use biome_parser::{AnyParse, NodeParse};
 use biome_rowan::{AnySyntaxNode, AstNode, RawSyntaxKind, SyntaxKind, TextRange};
 use camino::{Utf8Path, Utf8PathBuf};
@@ -30,7 +40,7 @@ impl AnalyzerGritPlugin {
             .with_extra_built_ins(vec![
                 BuiltInFunction::new(
                     "register_diagnostic",
-                    &["span", "message", "severity"],
+                    &["span", "message", "severity", "fix_kind"],
                     Box::new(register_diagnostic),
                 )
                 .as_predicate(),
@@ -47,6 +57,9 @@ impl AnalyzerPlugin for AnalyzerGritPlugin {
         match &self.grit_query.language {
             GritTargetLanguage::JsTargetLanguage(_) => PluginTargetLanguage::JavaScript,
             GritTargetLanguage::CssTargetLanguage(_) => PluginTargetLanguage::Css,
+            GritTargetLanguage::JsonTargetLanguage(_) => PluginTargetLanguage::Json,
+            GritTargetLanguage::HtmlTargetLanguage(_) => PluginTargetLanguage::Html,
+            GritTargetLanguage::GraphqlTargetLanguage(_) => PluginTargetLanguage::Graphql,
         }
     }
 
@@ -59,10 +72,22 @@ impl AnalyzerPlugin for AnalyzerGritPlugin {
             PluginTargetLanguage::Css => {
                 CssRoot::KIND_SET.iter().map(|kind| kind.to_raw()).collect()
             }
+            PluginTargetLanguage::Json => JsonRoot::KIND_SET
+                .iter()
+                .map(|kind| kind.to_raw())
+                .collect(),
+            PluginTargetLanguage::Html => HtmlRoot::KIND_SET
+                .iter()
+                .map(|kind| kind.to_raw())
+                .collect(),
+            PluginTargetLanguage::Graphql => GraphqlRoot::KIND_SET
+                .iter()
+                .map(|kind| kind.to_raw())
+                .collect(),
         }
     }
 
-    fn evaluate(&self, node: AnySyntaxNode, path: Arc<Utf8PathBuf>) -> Vec<RuleDiagnostic> {
+    fn evaluate(&self, node: AnySyntaxNode, path: Arc<Utf8PathBuf>) -> Vec<PluginEvaluationResult> {
         let name: &str = self.grit_query.name.as_deref().unwrap_or("anonymous");
 
         let root = match self.language() {
@@ -72,50 +97,145 @@ impl AnalyzerPlugin for AnalyzerGritPlugin {
             PluginTargetLanguage::Css => node
                 .downcast_ref::<CssSyntaxNode>()
                 .and_then(|node| node.as_send()),
+            PluginTargetLanguage::Json => node
+                .downcast_ref::<JsonSyntaxNode>()
+                .and_then(|node| node.as_send()),
+            PluginTargetLanguage::Html => node
+                .downcast_ref::<HtmlSyntaxNode>()
+                .and_then(|node| node.as_send()),
+            PluginTargetLanguage::Graphql => node
+                .downcast_ref::<GraphqlSyntaxNode>()
+                .and_then(|node| node.as_send()),
         };
 
         let parse = AnyParse::Node(NodeParse::new(root.unwrap(), vec![]));
-        let file = GritTargetFile { parse, path };
+        let file = GritTargetFile {
+            parse,
+            path: path.clone(),
+        };
 
         match self.grit_query.execute(file) {
             Ok(result) => {
-                let mut diagnostics: Vec<_> = result
-                    .logs
+                let rewritten = result
+                    .effects
                     .iter()
-                    .map(|log| {
-                        RuleDiagnostic::new(
+                    .find_map(|effect| match effect {
+                        GritQueryEffect::Rewrite(rewrite) => Some(&rewrite.rewritten.content),
+                        _ => None,
+                    })
+                    .and_then(|content| self.parse_rewritten(content, &path));
+
+                let logs = result.logs.iter().map(|log| {
+                    RuleDiagnostic::new(
                         category!("plugin"),
                         log.range.map(from_grit_range),
                         markup!(<Emphasis>{name}</Emphasis>" logged: "<Info>{log.message}</Info>),
                     )
                     .verbose()
+                    .into()
+                });
+
+                let diagnostics = result.diagnostics.into_iter().map(
+                    |GritQueryDiagnostic {
+                         diagnostic,
+                         fix_kind,
+                     }| {
+                        let result = PluginEvaluationResult::from(diagnostic);
+                        let action = Applicability::try_from(fix_kind)
+                            .ok()
+                            .zip(rewritten.as_ref())
+                            .map(|(applicability, rewritten)| PluginAction {
+                                applicability,
+                                message: markup!("Apply the rewrite of the plugin "<Emphasis>{name}</Emphasis>".")
+                                    .to_owned(),
+                                fixed_node: self.rewritten_root(rewritten),
+                            });
+
+                        match action {
+                            Some(action) => result.with_action(action),
+                            None => result,
+                        }
+                    },
+                );
+
+                let mut results: Vec<PluginEvaluationResult> = logs
+                    .chain(diagnostics)
+                    .map(|mut result| {
+                        result.diagnostic = result.diagnostic.subcategory(name.to_string());
+                        result
                     })
-                    .chain(result.diagnostics)
-                    .map(|diagnostics| diagnostics.subcategory(name.to_string()))
                     .collect();
 
-                if diagnostics
+                if results
                     .iter()
-                    .any(|diagnostic| diagnostic.span().is_none())
+                    .any(|result| result.diagnostic.span().is_none())
                 {
-                    diagnostics.push(RuleDiagnostic::new(
-                        category!("plugin"),
-                        None::<TextRange>,
-                        markup!(
-                            "Plugin "<Emphasis>{name}</Emphasis>" reported one or more diagnostics, "
-                            "but it didn't specify a valid "<Emphasis>"span"</Emphasis>". "
-                            "Diagnostics have been shown without context."
-                        ),
-                    ));
+                    results.push(
+                        RuleDiagnostic::new(
+                            category!("plugin"),
+                            None::<TextRange>,
+                            markup!(
+                                "Plugin "<Emphasis>{name}</Emphasis>" reported one or more diagnostics, "
+                                "but it didn't specify a valid "<Emphasis>"span"</Emphasis>". "
+                                "Diagnostics have been shown without context."
+                            ),
+                        )
+                        .into(),
+                    );
                 }
 
-                diagnostics
+                results
+            }
+            Err(error) => vec![
+                RuleDiagnostic::new(
+                    category!("plugin"),
+                    None::<TextRange>,
+                    markup!(<Emphasis>{name}</Emphasis>" errored: "<Error>{error.to_string()}</Error>),
+                )
+                .into(),
+            ],
+        }
+    }
+}
+
+impl AnalyzerGritPlugin {
+    /// Parses the content of a file rewritten by the query.
+    ///
+    /// Returns [None] if the rewritten content contains syntax errors.
+    fn parse_rewritten(&self, content: &str, path: &Utf8Path) -> Option<AnyParse> {
+        let parse: AnyParse = match self.language() {
+            PluginTargetLanguage::JavaScript => {
+                let file_source = JsFileSource::try_from(path).unwrap_or_default();
+                parse(content, file_source, JsParserOptions::default()).into()
+            }
+            PluginTargetLanguage::Css => {
+                let file_source = CssFileSource::try_from(path).unwrap_or_default();
+                let options = CssParserOptions::from(&file_source)
+                    .allow_css_modules()
+                    .allow_tailwind_directives();
+                parse_css(content, options).into()
             }
-            Err(error) => vec![RuleDiagnostic::new(
-                category!("plugin"),
-                None::<TextRange>,
-                markup!(<Emphasis>{name}</Emphasis>" errored: "<Error>{error.to_string()}</Error>),
-            )],
+            PluginTargetLanguage::Json => {
+                let file_source = JsonFileSource::try_from(path).unwrap_or_default();
+                parse_json(content, JsonParserOptions::from(&file_source)).into()
+            }
+            PluginTargetLanguage::Html => {
+                let file_source = HtmlFileSource::try_from(path).unwrap_or_default();
+                parse_html(content, HtmlParseOptions::from(&file_source)).into()
+            }
+            PluginTargetLanguage::Graphql => parse_graphql(content).into(),
+        };
+
+        (!parse.has_errors()).then_some(parse)
+    }
+
+    fn rewritten_root(&self, parse: &AnyParse) -> AnySyntaxNode {
+        match self.language() {
+            PluginTargetLanguage::JavaScript => parse.syntax::<JsLanguage>().into(),
+            PluginTargetLanguage::Css => parse.syntax::<CssLanguage>().into(),
+            PluginTargetLanguage::Json => parse.syntax::<JsonLanguage>().into(),
+            PluginTargetLanguage::Html => parse.syntax::<HtmlLanguage>().into(),
+            PluginTargetLanguage::Graphql => parse.syntax::<GraphqlLanguage>().into(),
         }
     }
 }
@@ -132,11 +252,11 @@ fn register_diagnostic<'a>(
 ) -> Result<GritResolvedPattern<'a>, GritPatternError> {
     let args = GritResolvedPattern::from_patterns(args, state, context, logs)?;
 
-    let (span_node, message, severity) = match args.as_slice() {
-        [Some(span), Some(message), severity] => (span, message, severity),
+    let (span_node, message, severity, fix_kind) = match args.as_slice() {
+        [Some(span), Some(message), severity, fix_kind] => (span, message, severity, fix_kind),
         _ => {
             return Err(GritPatternError::new(
-                "register_diagnostic() takes 2 required arguments: span and message, and an optional severity",
+                "register_diagnostic() takes 2 required arguments: span and message, and optional severity and fix_kind",
             ));
         }
     };
@@ -171,8 +291,24 @@ fn register_diagnostic<'a>(
         .and_then(|severity| Severity::from_str(severity.as_ref()).ok())
         .unwrap_or(Severity::Error);
 
+    // Rewrites are offered as unsafe fixes, unless the plugin says otherwise
+    let fix_kind = match fix_kind {
+        Some(fix_kind) => match fix_kind.text(&state.files, &context.lang)?.as_ref() {
+            "none" => FixKind::None,
+            "safe" => FixKind::Safe,
+            "unsafe" => FixKind::Unsafe,
+            _ => {
+                return Err(GritPatternError::new(
+                    "register_diagnostic() expects fix_kind to be one of: none, safe, unsafe",
+                ));
+            }
+        },
+        None => FixKind::Unsafe,
+    };
+
     context.add_diagnostic(
         RuleDiagnostic::new(category!("plugin"), span, message).with_severity(severity),
+        fix_kind,
     );
 
     Ok(span_node.clone())
@@ -6,11 +6,14 @@ use boa_engine::object::builtins::JsFunction;
 use boa_engine::{JsNativeError, JsResult, JsString, JsValue};
 use camino::{Utf8Path, Utf8PathBuf};
 
-use biome_analyze::{AnalyzerPlugin, PluginTargetLanguage, RuleDiagnostic};
+use biome_analyze::{
+    AnalyzerPlugin, PluginAction, PluginEvaluationResult, PluginTargetLanguage, RuleDiagnostic,
+};
 use biome_console::markup;
-use biome_diagnostics::category;
-use biome_js_runtime::JsExecContext;
-use biome_js_syntax::AnyJsRoot;
+use biome_diagnostics::{Applicability, category};
+use biome_js_parser::{JsParserOptions, parse};
+use biome_js_runtime::{JsExecContext, JsPluginDiagnostic, JsPluginFix};
+use biome_js_syntax::{AnyJsRoot, JsFileSource, JsSyntaxNode};
 use biome_resolver::FsWithResolverProxy;
 use biome_rowan::{AnySyntaxNode, AstNode, RawSyntaxKind, SyntaxKind};
 use biome_text_size::TextRange;
@@ -85,42 +88,91 @@ impl AnalyzerPlugin for AnalyzerJsPlugin {
             .collect()
     }
 
-    fn evaluate(&self, _node: AnySyntaxNode, path: Arc<Utf8PathBuf>) -> Vec<RuleDiagnostic> {
+    fn evaluate(&self, node: AnySyntaxNode, path: Arc<Utf8PathBuf>) -> Vec<PluginEvaluationResult> {
         let mut plugin = match self
             .loaded
             .get_mut_or_try_init(|| load_plugin(self.fs.clone(), &self.path))
         {
             Ok(plugin) => plugin,
             Err(err) => {
-                return vec![RuleDiagnostic::new(
-                    category!("plugin"),
-                    None::<TextRange>,
-                    markup!("Could not load the plugin: "<Error>{err.to_string()}</Error>),
-                )];
+                return vec![
+                    RuleDiagnostic::new(
+                        category!("plugin"),
+                        None::<TextRange>,
+                        markup!("Could not load the plugin: "<Error>{err.to_string()}</Error>),
+                    )
+                    .into(),
+                ];
             }
         };
 
         let plugin = plugin.deref_mut();
+        let source = node
+            .downcast_ref::<JsSyntaxNode>()
+            .map(JsSyntaxNode::to_string)
+            .unwrap_or_default();
 
         // TODO: pass the AST to the plugin
-        plugin
-            .ctx
-            .call_function(
-                &plugin.entrypoint,
-                &JsValue::undefined(),
-                &[JsValue::from(JsString::from(path.as_str()))],
-            )
-            .map_or_else(
-                |err| {
-                    vec![RuleDiagnostic::new(
-                        category!("plugin"),
-                        None::<TextRange>,
-                        markup!("Plugin errored: "<Error>{err.to_string()}</Error>),
-                    )]
-                },
-                |_| plugin.ctx.pull_diagnostics(),
-            )
+        let result = plugin.ctx.call_function(
+            &plugin.entrypoint,
+            &JsValue::undefined(),
+            &[
+                JsValue::from(JsString::from(path.as_str())),
+                JsValue::from(JsString::from(source.as_str())),
+            ],
+        );
+
+        match result {
+            Ok(_) => plugin
+                .ctx
+                .pull_diagnostics()
+                .into_iter()
+                .map(|JsPluginDiagnostic { diagnostic, fix }| {
+                    let result = PluginEvaluationResult::from(diagnostic);
+                    match fix.and_then(|fix| apply_fix(&source, &path, fix)) {
+                        Some(action) => result.with_action(action),
+                        None => result,
+                    }
+                })
+                .collect(),
+            Err(err) => vec![
+                RuleDiagnostic::new(
+                    category!("plugin"),
+                    None::<TextRange>,
+                    markup!("Plugin errored: "<Error>{err.to_string()}</Error>),
+                )
+                .into(),
+            ],
+        }
+    }
+}
+
+/// Applies the text edit of a fix to the source, and parses the result.
+///
+/// Returns [None] if the fix is out of bounds, or if it introduces syntax errors.
+fn apply_fix(source: &str, path: &Utf8Path, fix: JsPluginFix) -> Option<PluginAction> {
+    let JsPluginFix {
+        range,
+        text,
+        fix_kind,
+    } = fix;
+
+    let range = std::ops::Range::<usize>::from(range);
+    source.get(range.clone())?;
+    let mut fixed_source = source.to_string();
+    fixed_source.replace_range(range, &text);
+
+    let file_source = JsFileSource::try_from(path).unwrap_or_default();
+    let parse = parse(&fixed_source, file_source, JsParserOptions::default());
+    if parse.has_errors() {
+        return None;
     }
+
+    Some(PluginAction {
+        applicability: Applicability::try_from(fix_kind).ok()?,
+        message: markup!("Apply the fix of the plugin.").to_owned(),
+        fixed_node: parse.syntax().into(),
+    })
 }
 
 #[cfg(test)]
@@ -172,7 +224,11 @@ mod tests {
                     JsParserOptions::default(),
                 );
 
-                plugin.evaluate(parse.syntax().into(), Arc::new("/foo.js".into()))
+                plugin
+                    .evaluate(parse.syntax().into(), Arc::new("/foo.js".into()))
+                    .into_iter()
+                    .map(|result| result.diagnostic)
+                    .collect::<Vec<_>>()
             })
         };
 
@@ -186,7 +242,11 @@ mod tests {
                     JsParserOptions::default(),
                 );
 
-                plugin.evaluate(parse.syntax().into(), Arc::new("/bar.js".into()))
+                plugin
+                    .evaluate(parse.syntax().into(), Arc::new("/bar.js".into()))
+                    .into_iter()
+                    .map(|result| result.diagnostic)
+                    .collect::<Vec<_>>()
             })
         };
 
@@ -199,4 +259,47 @@ mod tests {
             diagnostics.into_iter().map(|diag| diag.into()).collect(),
         );
     }
+
+    #[test]
+    fn evaluate_with_fix() {
+        let fs = MemoryFileSystem::default();
+        fs.insert(
+            "/plugin.js".into(),
+            r#"import { registerDiagnostic } from "@biomejs/plugin-api";
+            export default function useMyPlugin(path, source) {
+                const start = source.indexOf("foo");
+                registerDiagnostic("warning", "Rename foo.", {
+                    span: [start, start + 3],
+                    fix: { text: "bar", kind: "safe" },
+                });
+            }"#,
+        );
+
+        let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
+        let plugin = AnalyzerJsPlugin::load(fs, "/plugin.js".into()).unwrap();
+
+        let parse = biome_js_parser::parse(
+            "let foo = 1;",
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+        let results = plugin.evaluate(parse.syntax().into(), Arc::new("/foo.js".into()));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].diagnostic.span(),
+            Some(TextRange::new(4.into(), 7.into()))
+        );
+
+        let action = results[0].action.as_ref().unwrap();
+        assert_eq!(action.applicability, Applicability::Always);
+        assert_eq!(
+            action
+                .fixed_node
+                .downcast_ref::<JsSyntaxNode>()
+                .unwrap()
+                .to_string(),
+            "let bar = 1;"
+        );
+    }
 }
@@ -1,8 +1,9 @@
+use biome_glob::{CandidatePath, NormalizedGlob};
 use biome_package::{NodeJsPackage, Package, PackageJson, TsConfigJson, TurboJson};
 use biome_rowan::SendNode;
 use camino::{Utf8Path, Utf8PathBuf};
 use papaya::HashMap;
-use rustc_hash::FxBuildHasher;
+use rustc_hash::{FxBuildHasher, FxHashSet};
 use std::sync::Arc;
 
 /// The layout used across all open projects.
@@ -139,6 +140,126 @@ impl ProjectLayout {
             .map(Arc::clone)
     }
 
+    /// Returns the root of the workspace that the given `path` belongs to.
+    ///
+    /// The root of a workspace is the closest package in the ancestors of
+    /// `path` that has a `pnpm-workspace.yaml` file or a `package.json` with a
+    /// `workspaces` field. Whether `path` is actually matched by the patterns
+    /// of the workspace is not verified.
+    pub fn find_workspace_root_for_path(&self, path: &Utf8Path) -> Option<Utf8PathBuf> {
+        let packages = self.0.pin();
+        path.ancestors().find_map(|package_path| {
+            packages
+                .get(package_path)
+                .and_then(|data| data.node_package.as_ref())
+                .and_then(NodeJsPackage::workspace_patterns)
+                .map(|_| package_path.to_path_buf())
+        })
+    }
+
+    /// Returns the packages of the workspace with the given `workspace_root`,
+    /// together with their `package.json`, sorted by path.
+    ///
+    /// The workspace root itself is not included. Patterns that aren't valid
+    /// globs are ignored.
+    pub fn get_workspace_packages(
+        &self,
+        workspace_root: &Utf8Path,
+    ) -> Vec<(Utf8PathBuf, PackageJson)> {
+        let packages = self.0.pin();
+        let Some(patterns) = packages
+            .get(workspace_root)
+            .and_then(|data| data.node_package.as_ref())
+            .and_then(NodeJsPackage::workspace_patterns)
+        else {
+            return Vec::new();
+        };
+
+        let globs: Vec<NormalizedGlob> = patterns
+            .iter()
+            .filter_map(|pattern| pattern.trim_end_matches('/').parse().ok())
+            .collect();
+
+        let mut workspace_packages: Vec<_> = packages
+            .iter()
+            .filter_map(|(package_path, data)| {
+                let relative_path = package_path.strip_prefix(workspace_root).ok()?;
+                if relative_path.as_str().is_empty()
+                    || relative_path
+                        .components()
+                        .any(|component| component.as_str() == "node_modules")
+                    || !CandidatePath::new(relative_path).matches_with_exceptions(&globs)
+                {
+                    return None;
+                }
+
+                let manifest = data.node_package.as_ref()?.manifest.as_ref()?;
+                Some((package_path.clone(), manifest.clone()))
+            })
+            .collect();
+        workspace_packages.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        workspace_packages
+    }
+
+    /// Returns the package with the given `package_name` from the workspace
+    /// that the given `path` belongs to, together with its `package.json`.
+    pub fn find_workspace_package_by_name(
+        &self,
+        path: &Utf8Path,
+        package_name: &str,
+    ) -> Option<(Utf8PathBuf, PackageJson)> {
+        let workspace_root = self.find_workspace_root_for_path(path)?;
+        self.get_workspace_packages(&workspace_root)
+            .into_iter()
+            .find(|(_, manifest)| manifest.name.as_deref() == Some(package_name))
+    }
+
+    /// Returns the paths of the packages of the same workspace that depend on
+    /// the package at the given `package_path`, either directly or through
+    /// other packages of the workspace. The result is sorted by path.
+    pub fn find_dependent_packages(&self, package_path: &Utf8Path) -> Vec<Utf8PathBuf> {
+        let Some(workspace_root) = self.find_workspace_root_for_path(package_path) else {
+            return Vec::new();
+        };
+        let workspace_packages = self.get_workspace_packages(&workspace_root);
+        let Some(package_name) = workspace_packages
+            .iter()
+            .find(|(path, _)| path == package_path)
+            .and_then(|(_, manifest)| manifest.name.clone())
+        else {
+            return Vec::new();
+        };
+
+        let mut dependency_names = FxHashSet::from_iter([package_name]);
+        let mut dependents = Vec::new();
+        loop {
+            let new_dependents: Vec<_> = workspace_packages
+                .iter()
+                .filter(|(path, manifest)| {
+                    path != package_path
+                        && !dependents.contains(path)
+                        && dependency_names.iter().any(|name| {
+                            manifest.contains_dependency(name)
+                                || manifest.optional_dependencies.contains(name)
+                        })
+                })
+                .collect();
+            if new_dependents.is_empty() {
+                break;
+            }
+
+            for (path, manifest) in new_dependents {
+                dependents.push(path.clone());
+                if let Some(name) = &manifest.name {
+                    dependency_names.insert(name.clone());
+                }
+            }
+        }
+
+        dependents.sort_unstable();
+        dependents
+    }
+
     /// Inserts a `package.json` manifest for the package at the given `path`.
     ///
     /// `path` refers to the package directory, not the `package.json` file
@@ -158,6 +279,10 @@ impl ProjectLayout {
                         .node_package
                         .as_ref()
                         .and_then(|package| package.turbo_json.clone()),
+                    pnpm_workspace: data
+                        .node_package
+                        .as_ref()
+                        .and_then(|package| package.pnpm_workspace.clone()),
                 };
 
                 PackageData {
@@ -196,6 +321,10 @@ impl ProjectLayout {
                         .node_package
                         .as_ref()
                         .and_then(|package| package.turbo_json.clone()),
+                    pnpm_workspace: data
+                        .node_package
+                        .as_ref()
+                        .and_then(|package| package.pnpm_workspace.clone()),
                 };
 
                 PackageData {
@@ -235,6 +364,10 @@ impl ProjectLayout {
                         .as_ref()
                         .and_then(|package| package.tsconfig.clone()),
                     turbo_json: Some(Arc::clone(&turbo_json)),
+                    pnpm_workspace: data
+                        .node_package
+                        .as_ref()
+                        .and_then(|package| package.pnpm_workspace.clone()),
                 };
 
                 PackageData {
@@ -273,6 +406,10 @@ impl ProjectLayout {
                         .node_package
                         .as_ref()
                         .and_then(|package| package.turbo_json.clone()),
+                    pnpm_workspace: data
+                        .node_package
+                        .as_ref()
+                        .and_then(|package| package.pnpm_workspace.clone()),
                 };
                 node_js_package.insert_serialized_manifest(
                     &manifest.to_language_root(),
@@ -314,6 +451,10 @@ impl ProjectLayout {
                         .node_package
                         .as_ref()
                         .and_then(|package| package.turbo_json.clone()),
+                    pnpm_workspace: data
+                        .node_package
+                        .as_ref()
+                        .and_then(|package| package.pnpm_workspace.clone()),
                 };
                 node_js_package.insert_serialized_tsconfig(
                     &manifest.to_language_root(),
@@ -362,6 +503,10 @@ impl ProjectLayout {
                         .as_ref()
                         .and_then(|package| package.tsconfig.clone()),
                     turbo_json: Default::default(),
+                    pnpm_workspace: data
+                        .node_package
+                        .as_ref()
+                        .and_then(|package| package.pnpm_workspace.clone()),
                 };
                 node_js_package.insert_serialized_turbo_json(
                     &manifest.to_language_root(),
@@ -386,10 +531,59 @@ impl ProjectLayout {
         );
     }
 
+    /// Inserts a `pnpm-workspace.yaml` manifest for the package at the given
+    /// `path`, parsing the manifest on demand.
+    ///
+    /// `path` refers to the package directory, not the `pnpm-workspace.yaml`
+    /// file itself.
+    pub fn insert_serialized_pnpm_workspace(&self, path: Utf8PathBuf, manifest: &SendNode) {
+        self.0.pin().update_or_insert_with(
+            path.clone(),
+            |data| {
+                let mut node_js_package = NodeJsPackage {
+                    manifest: data
+                        .node_package
+                        .as_ref()
+                        .and_then(|package| package.manifest.clone()),
+                    diagnostics: Default::default(),
+                    tsconfig: data
+                        .node_package
+                        .as_ref()
+                        .and_then(|package| package.tsconfig.clone()),
+                    turbo_json: data
+                        .node_package
+                        .as_ref()
+                        .and_then(|package| package.turbo_json.clone()),
+                    pnpm_workspace: Default::default(),
+                };
+                node_js_package.insert_serialized_pnpm_workspace(
+                    &manifest.to_language_root(),
+                    &path.join("pnpm-workspace.yaml"),
+                );
+
+                PackageData {
+                    node_package: Some(node_js_package),
+                }
+            },
+            || {
+                let mut node_js_package = NodeJsPackage::default();
+                node_js_package.insert_serialized_pnpm_workspace(
+                    &manifest.to_language_root(),
+                    &path.join("pnpm-workspace.yaml"),
+                );
+
+                PackageData {
+                    node_package: Some(node_js_package),
+                }
+            },
+        );
+    }
+
     /// Returns whether the manifest with the given `path` is indexed in the
     /// project layout.
     ///
-    /// Only returns `true` for `package.json`, `tsconfig.json`, and `turbo.json` manifests.
+    /// Only returns `true` for `package.json`, `tsconfig.json`, `turbo.json`,
+    /// and `pnpm-workspace.yaml` manifests.
     pub fn is_indexed(&self, path: &Utf8Path) -> bool {
         path.parent()
             .and_then(|package_path| {
@@ -401,6 +595,7 @@ impl ProjectLayout {
                         Some("package.json") => package.manifest.is_some(),
                         Some("tsconfig.json") => package.tsconfig.is_some(),
                         Some("turbo.json" | "turbo.jsonc") => package.turbo_json.is_some(),
+                        Some("pnpm-workspace.yaml") => package.pnpm_workspace.is_some(),
                         _ => false,
                     })
             })
@@ -467,6 +662,17 @@ impl ProjectLayout {
         });
     }
 
+    /// Removes a `pnpm-workspace.yaml` manifest from the package with the
+    /// given `path`.
+    pub fn remove_pnpm_workspace_from_package(&self, path: &Utf8Path) {
+        self.0.pin().update(path.to_path_buf(), |data| PackageData {
+            node_package: data
+                .node_package
+                .as_ref()
+                .map(NodeJsPackage::without_pnpm_workspace),
+        });
+    }
+
     /// Removes a package and its metadata from the project layout.
     pub fn remove_package(&self, path: &Utf8Path) {
         self.0.pin().remove(path);
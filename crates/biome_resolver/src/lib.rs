@@ -275,6 +275,19 @@ fn resolve_module_with_package_json(
         }
     }
 
+    // Dependencies using the `workspace:` protocol are resolved to the sources
+    // of the package inside the workspace, so that we don't depend on them
+    // being linked inside `node_modules/`.
+    if let Ok((package_name, subpath)) = parse_package_specifier(specifier)
+        && package_json.is_workspace_dependency(package_name)
+        && let Ok(workspace_package_path) = fs.find_workspace_package(package_path, package_name)
+    {
+        match resolve_package_path(&workspace_package_path, subpath, fs, options) {
+            Err(ResolveError::NotFound) => { /* continue below */ }
+            result => return result,
+        }
+    }
+
     resolve_dependency(specifier, package_path, fs, options)
 }
 
@@ -38,6 +38,16 @@ pub trait ResolverFsProxy {
         search_dir: &Utf8Path,
     ) -> Result<(Utf8PathBuf, PackageJson), ResolveError>;
 
+    /// Finds the package with the given `package_name` in the workspace that
+    /// `search_dir` belongs to.
+    ///
+    /// Returns the path of the package in which it was found.
+    fn find_workspace_package(
+        &self,
+        search_dir: &Utf8Path,
+        package_name: &str,
+    ) -> Result<Utf8PathBuf, ResolveError>;
+
     /// Returns information about the path.
     ///
     /// This method does not follow symlinks.
@@ -74,6 +84,16 @@ impl<Fs: FileSystem> ResolverFsProxy for Fs {
             })
     }
 
+    fn find_workspace_package(
+        &self,
+        _search_dir: &Utf8Path,
+        _package_name: &str,
+    ) -> Result<Utf8PathBuf, ResolveError> {
+        // Discovering the packages of a workspace requires the project layout,
+        // so without it, we rely on them being linked inside `node_modules/`.
+        Err(ResolveError::NotFound)
+    }
+
     fn path_info(&self, path: &Utf8Path) -> Result<PathInfo, ResolveError> {
         match self.symlink_path_kind(path) {
             Ok(PathKind::Directory { .. }) => Ok(PathInfo::Directory),
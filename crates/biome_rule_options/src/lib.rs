@@ -7,6 +7,7 @@ pub mod no_accumulating_spread;
 pub mod no_adjacent_spaces_in_regex;
 pub mod no_alert;
 pub mod no_ambiguous_anchor_text;
+pub mod no_ambiguous_boolean_values;
 pub mod no_approximative_numeric_constant;
 pub mod no_arguments;
 pub mod no_aria_hidden_on_focusable;
@@ -70,6 +71,7 @@ pub mod no_duplicate_font_names;
 pub mod no_duplicate_graphql_operation_name;
 pub mod no_duplicate_input_field_names;
 pub mod no_duplicate_jsx_props;
+pub mod no_duplicate_mapping_keys;
 pub mod no_duplicate_object_keys;
 pub mod no_duplicate_parameters;
 pub mod no_duplicate_properties;
@@ -135,6 +137,7 @@ pub mod no_invalid_grid_areas;
 pub mod no_invalid_position_at_import_rule;
 pub mod no_invalid_use_before_declaration;
 pub mod no_irregular_whitespace;
+pub mod no_json_schema_violations;
 pub mod no_jsx_literals;
 pub mod no_jsx_props_bind;
 pub mod no_label_var;
@@ -218,6 +221,7 @@ pub mod no_then_property;
 pub mod no_this_in_static;
 pub mod no_ts_ignore;
 pub mod no_unassigned_variables;
+pub mod no_undeclared_aliases;
 pub mod no_undeclared_dependencies;
 pub mod no_undeclared_env_vars;
 pub mod no_undeclared_variables;
@@ -310,6 +314,7 @@ pub mod use_consistent_builtin_instantiation;
 pub mod use_consistent_curly_braces;
 pub mod use_consistent_enum_value_type;
 pub mod use_consistent_graphql_descriptions;
+pub mod use_consistent_indentation;
 pub mod use_consistent_member_accessibility;
 pub mod use_consistent_method_signatures;
 pub mod use_consistent_object_definitions;
@@ -401,6 +406,7 @@ pub mod use_solid_for_component;
 pub mod use_sorted_attributes;
 pub mod use_sorted_classes;
 pub mod use_sorted_keys;
+pub mod use_sorted_mapping_keys;
 pub mod use_sorted_properties;
 pub mod use_spread;
 pub mod use_static_response_methods;
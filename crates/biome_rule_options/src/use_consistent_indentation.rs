@@ -0,0 +1,21 @@
+use biome_deserialize_macros::{Deserializable, Merge};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Debug, Deserialize, Deserializable, Merge, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseConsistentIndentationOptions {
+    /// The number of spaces a nested block is indented by. Defaults to `2`.
+    #[serde(skip_serializing_if = "Option::<_>::is_none")]
+    pub indent_width: Option<u8>,
+}
+
+impl UseConsistentIndentationOptions {
+    pub const DEFAULT_INDENT_WIDTH: u8 = 2;
+
+    /// Returns [`Self::indent_width`] if it is set.
+    /// Otherwise, returns [`Self::DEFAULT_INDENT_WIDTH`].
+    pub fn indent_width(&self) -> u8 {
+        self.indent_width.unwrap_or(Self::DEFAULT_INDENT_WIDTH)
+    }
+}
@@ -0,0 +1,12 @@
+pub use crate::shared::sort_order::SortOrder;
+use biome_deserialize_macros::{Deserializable, Merge};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Debug, Deserialize, Deserializable, Merge, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseSortedMappingKeysOptions {
+    /// The order used to compare the keys. Defaults to `natural`.
+    #[serde(skip_serializing_if = "Option::<_>::is_none")]
+    pub sort_order: Option<SortOrder>,
+}
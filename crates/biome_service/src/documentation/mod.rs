@@ -10,6 +10,7 @@ use biome_html_syntax::HtmlLanguage;
 use biome_js_syntax::JsLanguage;
 use biome_json_syntax::JsonLanguage;
 use biome_rowan::Language;
+use biome_yaml_syntax::YamlLanguage;
 use std::{collections::BTreeMap, str::FromStr};
 
 #[derive(Debug, Clone)]
@@ -55,6 +56,7 @@ impl RulesVisitor {
 
         biome_graphql_analyze::visit_registry(&mut visitor);
         biome_html_analyze::visit_registry(&mut visitor);
+        biome_yaml_analyze::visit_registry(&mut visitor);
         biome_css_analyze::visit_registry(&mut visitor);
         biome_json_analyze::visit_registry(&mut visitor);
         biome_js_analyze::visit_registry(&mut visitor);
@@ -133,6 +135,16 @@ impl RegistryVisitor<HtmlLanguage> for RulesVisitor {
     }
 }
 
+impl RegistryVisitor<YamlLanguage> for RulesVisitor {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.store_rule::<R, YamlLanguage>();
+    }
+}
+
 impl biome_console::fmt::Display for ExplainRule {
     fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
         let metadata = &self.metadata;
@@ -15,7 +15,7 @@ use biome_rowan::NodeCache;
 use regex::{Matches, Regex, RegexBuilder};
 use std::sync::LazyLock;
 
-use super::SearchCapabilities;
+use super::{NavigationCapabilities, SearchCapabilities};
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct AstroFileHandler;
@@ -91,6 +91,8 @@ impl ExtensionHandler for AstroFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                prepare_rename: None,
+                rename_imports: None,
                 fix_all: Some(fix_all),
                 update_snippets: None,
                 pull_diagnostics_and_actions: None,
@@ -103,6 +105,17 @@ impl ExtensionHandler for AstroFileHandler {
             },
             // TODO: We should be able to search JS portions already
             search: SearchCapabilities { search: None },
+            navigation: NavigationCapabilities {
+                hover: None,
+                definition: None,
+                references: None,
+                namespace_member_references: None,
+                completions: None,
+                document_symbols: None,
+                folding_ranges: None,
+                semantic_tokens: None,
+                inlay_hints: None,
+            },
         }
     }
 }
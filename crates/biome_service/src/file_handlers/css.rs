@@ -1,6 +1,7 @@
 use super::{
     AnalyzerVisitorBuilder, CodeActionsParams, EnabledForPath, ExtensionHandler, FixAllParams,
-    LintParams, LintResults, ParseResult, ProcessFixAll, ProcessLint, SearchCapabilities, search,
+    LintParams, LintResults, NavigationCapabilities, ParseResult, ProcessFixAll, ProcessLint,
+    SearchCapabilities, collect_document_symbols, folding_ranges_of_brackets, search,
 };
 use crate::WorkspaceError;
 use crate::configuration::to_analyzer_rules;
@@ -13,7 +14,9 @@ use crate::settings::{
     Settings, check_feature_activity, check_override_feature_activity,
 };
 use crate::workspace::{
-    CodeAction, DocumentFileSource, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
+    CodeAction, DocumentFileSource, DocumentSymbol, FixFileResult, FoldingRange,
+    GetSyntaxTreeResult, PullActionsResult, SemanticToken, SemanticTokenKind,
+    SemanticTokenModifier, SymbolKind,
 };
 use biome_analyze::options::PreferredQuote;
 use biome_analyze::{AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never};
@@ -26,8 +29,12 @@ use biome_css_analyze::{CssAnalyzerServices, analyze};
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_formatter::format_node;
 use biome_css_parser::CssParserOptions;
+use biome_css_semantic::model::{CssGlobalCustomVariable, CssProperty, Rule};
 use biome_css_semantic::semantic_model;
-use biome_css_syntax::{CssLanguage, CssRoot, CssSyntaxNode};
+use biome_css_syntax::{
+    AnyCssDeclarationName, CssClassSelector, CssGenericProperty, CssIdSelector, CssLanguage,
+    CssRoot, CssSyntaxKind, CssSyntaxNode, CssTypeSelector,
+};
 use biome_formatter::{
     FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
 };
@@ -154,16 +161,24 @@ impl ServiceLanguage for CssLanguage {
         overrides: &OverrideSettings,
         language: &Self::ParserSettings,
         path: &BiomePath,
-        _file_source: &DocumentFileSource,
+        file_source: &DocumentFileSource,
     ) -> Self::ParserOptions {
+        let css_file_source = file_source.to_css_file_source();
+        let is_scss = css_file_source.is_some_and(|file_source| file_source.is_scss());
+        let is_less = css_file_source.is_some_and(|file_source| file_source.is_less());
         let mut options = CssParserOptions {
-            allow_wrong_line_comments: language
-                .allow_wrong_line_comments
-                .unwrap_or_default()
-                .into(),
+            // Line comments are valid in SCSS and Less
+            allow_wrong_line_comments: is_scss
+                || is_less
+                || language
+                    .allow_wrong_line_comments
+                    .unwrap_or_default()
+                    .into(),
             css_modules: language.css_modules_enabled.unwrap_or_default().into(),
             grit_metavariables: false,
             tailwind_directives: language.tailwind_directives_enabled(),
+            scss: is_scss,
+            less: is_less,
         };
 
         overrides.apply_override_css_parser_options(path, &mut options);
@@ -356,6 +371,8 @@ impl ExtensionHandler for CssFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                prepare_rename: None,
+                rename_imports: None,
                 fix_all: Some(fix_all),
                 update_snippets: None,
                 pull_diagnostics_and_actions: None,
@@ -369,6 +386,17 @@ impl ExtensionHandler for CssFileHandler {
             search: SearchCapabilities {
                 search: Some(search),
             },
+            navigation: NavigationCapabilities {
+                hover: None,
+                definition: None,
+                references: None,
+                namespace_member_references: None,
+                completions: None,
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(folding_ranges),
+                semantic_tokens: Some(semantic_tokens),
+                inlay_hints: None,
+            },
             enabled_for_path: EnabledForPath {
                 formatter: Some(formatter_enabled),
                 linter: Some(linter_enabled),
@@ -397,19 +425,25 @@ fn search_enabled(_path: &Utf8Path, _settings: &Settings) -> bool {
 
 fn parse(
     biome_path: &BiomePath,
-    _file_source: DocumentFileSource,
+    file_source: DocumentFileSource,
     text: &str,
     settings: &Settings,
     cache: &mut NodeCache,
 ) -> ParseResult {
+    let css_file_source = file_source.to_css_file_source();
+    let is_scss = css_file_source.is_some_and(|file_source| file_source.is_scss());
+    let is_less = css_file_source.is_some_and(|file_source| file_source.is_less());
     let mut options = CssParserOptions {
-        allow_wrong_line_comments: settings
-            .languages
-            .css
-            .parser
-            .allow_wrong_line_comments
-            .unwrap_or_default()
-            .into(),
+        // Line comments are valid in SCSS and Less
+        allow_wrong_line_comments: is_scss
+            || is_less
+            || settings
+                .languages
+                .css
+                .parser
+                .allow_wrong_line_comments
+                .unwrap_or_default()
+                .into(),
         css_modules: settings
             .languages
             .css
@@ -425,6 +459,8 @@ fn parse(
             .tailwind_directives
             .unwrap_or_default()
             .into(),
+        scss: is_scss,
+        less: is_less,
     };
 
     settings
@@ -547,11 +583,13 @@ fn lint(params: LintParams) -> LintResults {
     };
 
     let settings = &params.settings;
-    let analyzer_options = settings.analyzer_options::<CssLanguage>(
-        params.path,
-        &params.language,
-        params.suppression_reason.as_deref(),
-    );
+    let analyzer_options = settings
+        .analyzer_options::<CssLanguage>(
+            params.path,
+            &params.language,
+            params.suppression_reason.as_deref(),
+        )
+        .with_suppressed_signals(params.pull_suppressed);
     let tree = params.parse.tree();
 
     let (enabled_rules, disabled_rules, analyzer_options) =
@@ -754,6 +792,156 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     }
 }
 
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: CssRoot = parse.tree();
+    collect_document_symbols(root.syntax(), document_symbol)
+}
+
+/// Returns the symbol declared by a node, if any.
+///
+/// Rules are named after their selectors, at-rules after their prelude, and
+/// custom properties after their name.
+fn document_symbol(node: &CssSyntaxNode) -> Option<DocumentSymbol> {
+    match node.kind() {
+        CssSyntaxKind::CSS_QUALIFIED_RULE | CssSyntaxKind::CSS_NESTED_QUALIFIED_RULE => {
+            prelude_symbol(node, SymbolKind::Class)
+        }
+        CssSyntaxKind::CSS_AT_RULE => prelude_symbol(node, SymbolKind::Namespace),
+        CssSyntaxKind::CSS_GENERIC_PROPERTY => {
+            let name = CssGenericProperty::cast_ref(node)?.name().ok()?;
+            let AnyCssDeclarationName::CssDashedIdentifier(name) = name else {
+                return None;
+            };
+            Some(DocumentSymbol {
+                name: name.syntax().text_trimmed().to_string(),
+                kind: SymbolKind::Variable,
+                range: node.text_trimmed_range(),
+                selection_range: name.syntax().text_trimmed_range(),
+                children: Vec::new(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Creates a symbol named after the text that precedes the block of a rule,
+/// e.g. its selectors. The whitespace of the name is collapsed, because the
+/// selectors can span multiple lines.
+fn prelude_symbol(node: &CssSyntaxNode, kind: SymbolKind) -> Option<DocumentSymbol> {
+    let text = node.text_trimmed().to_string();
+    let prelude = text.split(['{', ';']).next()?.trim_end();
+    if prelude.is_empty() {
+        return None;
+    }
+
+    let range = node.text_trimmed_range();
+    Some(DocumentSymbol {
+        name: prelude.split_whitespace().collect::<Vec<_>>().join(" "),
+        kind,
+        range,
+        selection_range: TextRange::at(range.start(), TextSize::of(prelude)),
+        children: Vec::new(),
+    })
+}
+
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let root: CssRoot = parse.tree();
+    folding_ranges_of_brackets(root.syntax())
+}
+
+/// Returns the selectors of the rules and the custom properties of the file.
+///
+/// Type selectors are classified as types, class selectors as classes and ID
+/// selectors as variables, because they select a single element. Custom
+/// properties are variables, declared where the semantic model finds them.
+fn semantic_tokens(parse: AnyParse) -> Vec<SemanticToken> {
+    let root: CssRoot = parse.tree();
+    let model = semantic_model(&root);
+    let mut tokens = Vec::new();
+
+    let mut rules: Vec<&Rule> = model.rules().iter().collect();
+    while let Some(rule) = rules.pop() {
+        for selector in rule.selectors() {
+            for node in selector.node(&root).syntax().descendants() {
+                let (name, kind) = match node.kind() {
+                    CssSyntaxKind::CSS_TYPE_SELECTOR => (
+                        CssTypeSelector::unwrap_cast(node)
+                            .ident()
+                            .map(|name| name.into_syntax()),
+                        SemanticTokenKind::Type,
+                    ),
+                    CssSyntaxKind::CSS_CLASS_SELECTOR => (
+                        CssClassSelector::unwrap_cast(node)
+                            .name()
+                            .map(|name| name.into_syntax()),
+                        SemanticTokenKind::Class,
+                    ),
+                    CssSyntaxKind::CSS_ID_SELECTOR => (
+                        CssIdSelector::unwrap_cast(node)
+                            .name()
+                            .map(|name| name.into_syntax()),
+                        SemanticTokenKind::Variable,
+                    ),
+                    _ => continue,
+                };
+                if let Ok(name) = name {
+                    tokens.push(SemanticToken {
+                        range: name.text_trimmed_range(),
+                        kind,
+                        modifiers: Vec::new(),
+                    });
+                }
+            }
+        }
+
+        for declaration in rule.declarations() {
+            if let CssProperty::CssDashedIdentifier(property) = declaration.property(&root) {
+                tokens.push(SemanticToken {
+                    range: property.syntax().text_trimmed_range(),
+                    kind: SemanticTokenKind::Variable,
+                    modifiers: vec![SemanticTokenModifier::Declaration],
+                });
+            }
+        }
+
+        rules.extend(
+            rule.child_ids()
+                .iter()
+                .filter_map(|id| model.get_rule_by_id(id)),
+        );
+    }
+
+    for variable in model.global_custom_variables().values() {
+        if let CssGlobalCustomVariable::AtProperty { property, .. } = variable {
+            tokens.push(SemanticToken {
+                range: property
+                    .to_node(root.syntax())
+                    .syntax()
+                    .text_trimmed_range(),
+                kind: SemanticTokenKind::Variable,
+                modifiers: vec![SemanticTokenModifier::Declaration],
+            });
+        }
+    }
+
+    // The remaining custom properties are references, e.g. in `var(--name)`
+    tokens.extend(
+        root.syntax()
+            .descendants()
+            .filter(|node| node.kind() == CssSyntaxKind::CSS_DASHED_IDENTIFIER)
+            .map(|node| SemanticToken {
+                range: node.text_trimmed_range(),
+                kind: SemanticTokenKind::Variable,
+                modifiers: Vec::new(),
+            }),
+    );
+
+    // The declarations come first among the tokens with the same range
+    tokens.sort_by_key(|token| token.range.start());
+    tokens.dedup_by_key(|token| token.range);
+    tokens
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
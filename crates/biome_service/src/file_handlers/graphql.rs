@@ -1,7 +1,8 @@
 use super::{
     AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource, EnabledForPath,
-    ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult, ProcessFixAll,
-    ProcessLint, SearchCapabilities,
+    ExtensionHandler, FixAllParams, LintParams, LintResults, NavigationCapabilities, ParseResult,
+    ProcessFixAll, ProcessLint, SearchCapabilities, collect_document_symbols,
+    folding_ranges_of_brackets, search,
 };
 use crate::WorkspaceError;
 use crate::configuration::to_analyzer_rules;
@@ -13,7 +14,10 @@ use crate::settings::{
     FormatSettings, LanguageListSettings, LanguageSettings, OverrideSettings, ServiceLanguage,
     Settings, check_feature_activity, check_override_feature_activity,
 };
-use crate::workspace::{CodeAction, FixFileResult, GetSyntaxTreeResult, PullActionsResult};
+use crate::workspace::{
+    CodeAction, DocumentSymbol, FixFileResult, FoldingRange, GetSyntaxTreeResult,
+    PullActionsResult, SemanticToken, SemanticTokenKind, SemanticTokenModifier, SymbolKind,
+};
 use biome_analyze::{AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never};
 use biome_configuration::graphql::{
     GraphqlAssistConfiguration, GraphqlAssistEnabled, GraphqlFormatterConfiguration,
@@ -28,11 +32,20 @@ use biome_graphql_analyze::analyze;
 use biome_graphql_formatter::context::GraphqlFormatOptions;
 use biome_graphql_formatter::format_node;
 use biome_graphql_parser::parse_graphql_with_cache;
-use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode, TextRange, TextSize};
+use biome_graphql_semantic::semantic_model;
+use biome_graphql_syntax::{
+    GraphqlEnumTypeDefinition, GraphqlEnumValueDefinition, GraphqlFieldDefinition,
+    GraphqlFragmentDefinition, GraphqlInputObjectTypeDefinition, GraphqlInputValueDefinition,
+    GraphqlInterfaceTypeDefinition, GraphqlLanguage, GraphqlNameReference,
+    GraphqlObjectTypeDefinition, GraphqlOperationDefinition, GraphqlRoot,
+    GraphqlScalarTypeDefinition, GraphqlSchemaDefinition, GraphqlSyntaxKind, GraphqlSyntaxNode,
+    GraphqlUnionTypeDefinition, TextRange, TextSize,
+};
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache, TokenAtOffset};
 use camino::Utf8Path;
 use either::Either;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::borrow::Cow;
 use tracing::{debug_span, error, info, trace_span};
 
@@ -289,6 +302,8 @@ impl ExtensionHandler for GraphqlFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                prepare_rename: None,
+                rename_imports: None,
                 fix_all: Some(fix_all),
                 update_snippets: None,
                 pull_diagnostics_and_actions: None,
@@ -299,7 +314,20 @@ impl ExtensionHandler for GraphqlFileHandler {
                 format_on_type: Some(format_on_type),
                 format_embedded: None,
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
+            navigation: NavigationCapabilities {
+                hover: None,
+                definition: None,
+                references: None,
+                namespace_member_references: None,
+                completions: None,
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(folding_ranges),
+                semantic_tokens: Some(semantic_tokens),
+                inlay_hints: None,
+            },
         }
     }
 }
@@ -432,11 +460,13 @@ fn lint(params: LintParams) -> LintResults {
     let _ = debug_span!("Linting GraphQL file", path =? params.path, language =? params.language)
         .entered();
     let workspace_settings = &params.settings;
-    let analyzer_options = workspace_settings.analyzer_options::<GraphqlLanguage>(
-        params.path,
-        &params.language,
-        params.suppression_reason.as_deref(),
-    );
+    let analyzer_options = workspace_settings
+        .analyzer_options::<GraphqlLanguage>(
+            params.path,
+            &params.language,
+            params.suppression_reason.as_deref(),
+        )
+        .with_suppressed_signals(params.pull_suppressed);
     let tree = params.parse.tree();
 
     let (enabled_rules, disabled_rules, analyzer_options) =
@@ -457,9 +487,13 @@ fn lint(params: LintParams) -> LintResults {
 
     let mut process_lint = ProcessLint::new(&params);
 
-    let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-        process_lint.process_signal(signal)
-    });
+    let (_, analyze_diagnostics) = analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        &params.plugins,
+        |signal| process_lint.process_signal(signal),
+    );
 
     process_lint.into_result(
         params
@@ -483,7 +517,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         skip,
         suppression_reason,
         enabled_rules: rules,
-        plugins: _,
+        plugins,
         categories,
         action_offset,
         document_services: _,
@@ -522,7 +556,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
     info!("GraphQL runs the analyzer");
 
-    analyze(&tree, filter, &analyzer_options, |signal| {
+    analyze(&tree, filter, &analyzer_options, &plugins, |signal| {
         actions.extend(signal.actions().into_code_action_iter().map(|item| {
             CodeAction {
                 category: item.category.clone(),
@@ -573,9 +607,13 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         tree.syntax().text_range_with_trivia().len().into(),
     );
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-            process_fix_all.process_signal(signal)
-        });
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            &params.plugins,
+            |signal| process_fix_all.process_signal(signal),
+        );
 
         let result = process_fix_all.process_action(action, |root| {
             tree = match GraphqlRoot::cast(root) {
@@ -602,3 +640,248 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         }
     }
 }
+
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: GraphqlRoot = parse.tree();
+    collect_document_symbols(root.syntax(), document_symbol)
+}
+
+/// Returns the symbol declared by a node, if any.
+///
+/// The definitions without a name, such as the schema or anonymous
+/// operations, are named after their keyword.
+fn document_symbol(node: &GraphqlSyntaxNode) -> Option<DocumentSymbol> {
+    let (kind, name) = match node.kind() {
+        GraphqlSyntaxKind::GRAPHQL_SCHEMA_DEFINITION => {
+            let keyword = GraphqlSchemaDefinition::cast_ref(node)?
+                .schema_token()
+                .ok()?;
+            return Some(DocumentSymbol {
+                name: keyword.text_trimmed().to_string(),
+                kind: SymbolKind::Module,
+                range: node.text_trimmed_range(),
+                selection_range: keyword.text_trimmed_range(),
+                children: Vec::new(),
+            });
+        }
+        GraphqlSyntaxKind::GRAPHQL_OBJECT_TYPE_DEFINITION => (
+            SymbolKind::Class,
+            GraphqlObjectTypeDefinition::cast_ref(node)?
+                .name()
+                .ok()?
+                .into_syntax(),
+        ),
+        GraphqlSyntaxKind::GRAPHQL_INTERFACE_TYPE_DEFINITION => (
+            SymbolKind::Interface,
+            GraphqlInterfaceTypeDefinition::cast_ref(node)?
+                .name()
+                .ok()?
+                .into_syntax(),
+        ),
+        GraphqlSyntaxKind::GRAPHQL_ENUM_TYPE_DEFINITION => (
+            SymbolKind::Enum,
+            GraphqlEnumTypeDefinition::cast_ref(node)?
+                .name()
+                .ok()?
+                .into_syntax(),
+        ),
+        GraphqlSyntaxKind::GRAPHQL_ENUM_VALUE_DEFINITION => (
+            SymbolKind::EnumMember,
+            GraphqlEnumValueDefinition::cast_ref(node)?
+                .value()
+                .ok()?
+                .into_syntax(),
+        ),
+        GraphqlSyntaxKind::GRAPHQL_INPUT_OBJECT_TYPE_DEFINITION => (
+            SymbolKind::Struct,
+            GraphqlInputObjectTypeDefinition::cast_ref(node)?
+                .name()
+                .ok()?
+                .into_syntax(),
+        ),
+        GraphqlSyntaxKind::GRAPHQL_SCALAR_TYPE_DEFINITION => (
+            SymbolKind::TypeParameter,
+            GraphqlScalarTypeDefinition::cast_ref(node)?
+                .name()
+                .ok()?
+                .into_syntax(),
+        ),
+        GraphqlSyntaxKind::GRAPHQL_UNION_TYPE_DEFINITION => (
+            SymbolKind::Enum,
+            GraphqlUnionTypeDefinition::cast_ref(node)?
+                .name()
+                .ok()?
+                .into_syntax(),
+        ),
+        GraphqlSyntaxKind::GRAPHQL_OPERATION_DEFINITION => (SymbolKind::Function, {
+            let operation = GraphqlOperationDefinition::cast_ref(node)?;
+            match operation.name() {
+                Some(name) => name.into_syntax(),
+                None => operation.ty().ok()?.into_syntax(),
+            }
+        }),
+        GraphqlSyntaxKind::GRAPHQL_FRAGMENT_DEFINITION => (
+            SymbolKind::Function,
+            GraphqlFragmentDefinition::cast_ref(node)?
+                .name()
+                .ok()?
+                .into_syntax(),
+        ),
+        GraphqlSyntaxKind::GRAPHQL_FIELD_DEFINITION => (
+            SymbolKind::Field,
+            GraphqlFieldDefinition::cast_ref(node)?
+                .name()
+                .ok()?
+                .into_syntax(),
+        ),
+        // The arguments of the fields aren't symbols, only the fields of the
+        // input types are
+        GraphqlSyntaxKind::GRAPHQL_INPUT_VALUE_DEFINITION
+            if node.parent().is_some_and(|parent| {
+                parent.kind() == GraphqlSyntaxKind::GRAPHQL_INPUT_FIELD_LIST
+            }) =>
+        {
+            (
+                SymbolKind::Field,
+                GraphqlInputValueDefinition::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            )
+        }
+        _ => return None,
+    };
+
+    Some(DocumentSymbol {
+        name: name.text_trimmed().to_string(),
+        kind,
+        range: node.text_trimmed_range(),
+        selection_range: name.text_trimmed_range(),
+        children: Vec::new(),
+    })
+}
+
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let root: GraphqlRoot = parse.tree();
+    folding_ranges_of_brackets(root.syntax())
+}
+
+/// Returns the names of the definitions, the references to them and the
+/// variables of the file, classified using the semantic model.
+///
+/// References that can't be resolved but aren't reported by the semantic
+/// model are the built-in scalars and directives.
+fn semantic_tokens(parse: AnyParse) -> Vec<SemanticToken> {
+    let root: GraphqlRoot = parse.tree();
+    let model = semantic_model(&root);
+    let referenced_bindings: FxHashMap<TextSize, bool> = model
+        .all_bindings()
+        .map(|binding| {
+            let start = binding.syntax().text_range_with_trivia().start();
+            (start, !binding.all_references().is_empty())
+        })
+        .collect();
+    let unresolved_references: FxHashSet<TextRange> = model
+        .all_unresolved_references()
+        .map(|reference| *reference.range())
+        .collect();
+
+    let mut tokens = Vec::new();
+    for node in root.syntax().descendants() {
+        let range = node.text_trimmed_range();
+        match node.kind() {
+            GraphqlSyntaxKind::GRAPHQL_NAME_BINDING => {
+                let Some(kind) = node
+                    .parent()
+                    .and_then(|parent| definition_kind(parent.kind()))
+                else {
+                    continue;
+                };
+                let mut modifiers = vec![SemanticTokenModifier::Declaration];
+                // Types are used by the clients of a schema, so only fragments can be unused
+                let is_fragment = node.parent().is_some_and(|parent| {
+                    parent.kind() == GraphqlSyntaxKind::GRAPHQL_FRAGMENT_DEFINITION
+                });
+                let is_referenced = referenced_bindings.get(&node.text_range_with_trivia().start());
+                if is_fragment && is_referenced == Some(&false) {
+                    modifiers.push(SemanticTokenModifier::Unused);
+                }
+                tokens.push(SemanticToken {
+                    range,
+                    kind,
+                    modifiers,
+                });
+            }
+            GraphqlSyntaxKind::GRAPHQL_NAME_REFERENCE => {
+                let reference = GraphqlNameReference::unwrap_cast(node.clone());
+                let is_directive = node
+                    .parent()
+                    .is_some_and(|parent| parent.kind() == GraphqlSyntaxKind::GRAPHQL_DIRECTIVE);
+                if let Some(binding) = model.binding(&reference) {
+                    let Some(kind) = binding
+                        .syntax()
+                        .parent()
+                        .and_then(|parent| definition_kind(parent.kind()))
+                    else {
+                        continue;
+                    };
+                    tokens.push(SemanticToken {
+                        range,
+                        kind,
+                        modifiers: Vec::new(),
+                    });
+                } else if !unresolved_references.contains(&node.text_range_with_trivia()) {
+                    tokens.push(SemanticToken {
+                        range,
+                        kind: if is_directive {
+                            SemanticTokenKind::Function
+                        } else {
+                            SemanticTokenKind::Type
+                        },
+                        modifiers: vec![SemanticTokenModifier::DefaultLibrary],
+                    });
+                }
+            }
+            GraphqlSyntaxKind::GRAPHQL_VARIABLE_BINDING => {
+                let mut modifiers = vec![SemanticTokenModifier::Declaration];
+                let is_referenced = referenced_bindings.get(&node.text_range_with_trivia().start());
+                if is_referenced == Some(&false) {
+                    modifiers.push(SemanticTokenModifier::Unused);
+                }
+                tokens.push(SemanticToken {
+                    range,
+                    kind: SemanticTokenKind::Parameter,
+                    modifiers,
+                });
+            }
+            GraphqlSyntaxKind::GRAPHQL_VARIABLE_REFERENCE => {
+                tokens.push(SemanticToken {
+                    range,
+                    kind: SemanticTokenKind::Parameter,
+                    modifiers: Vec::new(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    tokens
+}
+
+/// Returns the kind of token of the name of a definition.
+fn definition_kind(kind: GraphqlSyntaxKind) -> Option<SemanticTokenKind> {
+    let kind = match kind {
+        GraphqlSyntaxKind::GRAPHQL_OBJECT_TYPE_DEFINITION => SemanticTokenKind::Class,
+        GraphqlSyntaxKind::GRAPHQL_INTERFACE_TYPE_DEFINITION => SemanticTokenKind::Interface,
+        GraphqlSyntaxKind::GRAPHQL_ENUM_TYPE_DEFINITION => SemanticTokenKind::Enum,
+        GraphqlSyntaxKind::GRAPHQL_INPUT_OBJECT_TYPE_DEFINITION => SemanticTokenKind::Struct,
+        GraphqlSyntaxKind::GRAPHQL_SCALAR_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_UNION_TYPE_DEFINITION => SemanticTokenKind::Type,
+        GraphqlSyntaxKind::GRAPHQL_OPERATION_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_FRAGMENT_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_DIRECTIVE_DEFINITION => SemanticTokenKind::Function,
+        _ => return None,
+    };
+
+    Some(kind)
+}
@@ -1,7 +1,7 @@
 use super::{
     AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, EnabledForPath,
-    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, SearchCapabilities,
+    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults,
+    NavigationCapabilities, ParseResult, ParserCapabilities, SearchCapabilities,
 };
 use crate::settings::{OverrideSettings, check_feature_activity, check_override_feature_activity};
 use crate::workspace::{FixFileResult, GetSyntaxTreeResult};
@@ -262,6 +262,8 @@ impl ExtensionHandler for GritFileHandler {
                 lint: Some(lint),
                 code_actions: None,
                 rename: None,
+                prepare_rename: None,
+                rename_imports: None,
                 fix_all: Some(fix_all),
                 update_snippets: None,
                 pull_diagnostics_and_actions: None,
@@ -273,6 +275,17 @@ impl ExtensionHandler for GritFileHandler {
                 format_embedded: None,
             },
             search: SearchCapabilities { search: None },
+            navigation: NavigationCapabilities {
+                hover: None,
+                definition: None,
+                references: None,
+                namespace_member_references: None,
+                completions: None,
+                document_symbols: None,
+                folding_ranges: None,
+                semantic_tokens: None,
+                inlay_hints: None,
+            },
         }
     }
 }
@@ -1,13 +1,14 @@
 use super::{
     AnalyzerCapabilities, AnalyzerVisitorBuilder, Capabilities, CodeActionsParams,
     DebugCapabilities, DocumentFileSource, EnabledForPath, ExtensionHandler, FixAllParams,
-    FormatEmbedNode, FormatterCapabilities, LintParams, LintResults, ParseEmbedResult, ParseResult,
-    ParserCapabilities, ProcessFixAll, ProcessLint, SearchCapabilities, UpdateSnippetsNodes,
+    FormatEmbedNode, FormatterCapabilities, LintParams, LintResults, NavigationCapabilities,
+    ParseEmbedResult, ParseResult, ParserCapabilities, ProcessFixAll, ProcessLint,
+    SearchCapabilities, UpdateSnippetsNodes, folding_ranges_of_brackets, search,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::settings::{OverrideSettings, check_feature_activity, check_override_feature_activity};
 use crate::workspace::{CodeAction, CssDocumentServices, DocumentServices, EmbeddedSnippet};
-use crate::workspace::{FixFileResult, PullActionsResult};
+use crate::workspace::{FixFileResult, FoldingRange, FoldingRangeKind, PullActionsResult};
 use crate::{
     WorkspaceError,
     settings::{ServiceLanguage, Settings},
@@ -38,7 +39,7 @@ use biome_html_formatter::{
 use biome_html_parser::{HtmlParseOptions, parse_html_with_cache};
 use biome_html_syntax::element_ext::AnyEmbeddedContent;
 use biome_html_syntax::{
-    AstroEmbeddedContent, HtmlElement, HtmlLanguage, HtmlRoot, HtmlSyntaxNode,
+    AstroEmbeddedContent, HtmlElement, HtmlLanguage, HtmlRoot, HtmlSyntaxKind, HtmlSyntaxNode,
 };
 use biome_js_parser::parse_js_with_offset_and_cache;
 use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage};
@@ -341,6 +342,8 @@ impl ExtensionHandler for HtmlFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                prepare_rename: None,
+                rename_imports: None,
                 fix_all: Some(fix_all),
                 update_snippets: Some(update_snippets),
                 pull_diagnostics_and_actions: None,
@@ -351,7 +354,20 @@ impl ExtensionHandler for HtmlFileHandler {
                 format_on_type: None,
                 format_embedded: Some(format_embedded),
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
+            navigation: NavigationCapabilities {
+                hover: None,
+                definition: None,
+                references: None,
+                namespace_member_references: None,
+                completions: None,
+                document_symbols: None,
+                folding_ranges: Some(folding_ranges),
+                semantic_tokens: None,
+                inlay_hints: None,
+            },
         }
     }
 }
@@ -752,11 +768,13 @@ fn format_embedded(
 #[tracing::instrument(level = "debug", skip(params))]
 fn lint(params: LintParams) -> LintResults {
     let workspace_settings = &params.settings;
-    let analyzer_options = workspace_settings.analyzer_options::<HtmlLanguage>(
-        params.path,
-        &params.language,
-        params.suppression_reason.as_deref(),
-    );
+    let analyzer_options = workspace_settings
+        .analyzer_options::<HtmlLanguage>(
+            params.path,
+            &params.language,
+            params.suppression_reason.as_deref(),
+        )
+        .with_suppressed_signals(params.pull_suppressed);
     let tree = params.parse.tree();
 
     let (enabled_rules, disabled_rules, analyzer_options) =
@@ -777,9 +795,13 @@ fn lint(params: LintParams) -> LintResults {
 
     let mut process_lint = ProcessLint::new(&params);
 
-    let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-        process_lint.process_signal(signal)
-    });
+    let (_, analyze_diagnostics) = analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        &params.plugins,
+        |signal| process_lint.process_signal(signal),
+    );
 
     process_lint.into_result(
         params
@@ -802,7 +824,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         skip,
         suppression_reason,
         enabled_rules: rules,
-        plugins: _,
+        plugins,
         categories,
         action_offset,
         document_services: _,
@@ -835,7 +857,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         range,
     };
 
-    analyze(&tree, filter, &analyzer_options, |signal| {
+    analyze(&tree, filter, &analyzer_options, &plugins, |signal| {
         actions.extend(signal.actions().into_code_action_iter().map(|item| {
             CodeAction {
                 category: item.category.clone(),
@@ -887,9 +909,13 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     );
 
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-            process_fix_all.process_signal(signal)
-        });
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            &params.plugins,
+            |signal| process_fix_all.process_signal(signal),
+        );
 
         let result = process_fix_all.process_action(action, |root| {
             tree = match HtmlRoot::cast(root) {
@@ -948,3 +974,22 @@ pub(crate) fn update_snippets(
 
     Ok(root.as_send().unwrap())
 }
+
+/// Returns the elements and the comments that span multiple lines.
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let root: HtmlRoot = parse.tree();
+    let mut ranges = folding_ranges_of_brackets(root.syntax());
+    ranges.extend(
+        root.syntax()
+            .descendants()
+            .filter(|node| node.kind() == HtmlSyntaxKind::HTML_ELEMENT)
+            .filter(|node| node.text_trimmed().contains_char('\n'))
+            .map(|node| FoldingRange {
+                range: node.text_trimmed_range(),
+                kind: FoldingRangeKind::Region,
+            }),
+    );
+
+    ranges.sort_by_key(|range| (range.range.start(), range.range.end()));
+    ranges
+}
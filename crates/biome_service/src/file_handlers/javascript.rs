@@ -1,8 +1,9 @@
 use super::{
     AnalyzerCapabilities, AnalyzerVisitorBuilder, CodeActionsParams, DebugCapabilities,
-    DiagnosticsAndActionsParams, EnabledForPath, ExtensionHandler, FormatterCapabilities,
-    LintParams, LintResults, ParseResult, ParserCapabilities, ProcessDiagnosticsAndActions,
-    ProcessFixAll, ProcessLint, SearchCapabilities, search,
+    DiagnosticsAndActionsParams, EnabledForPath, ExtensionHandler, FormatEmbedNode,
+    FormatterCapabilities, LintParams, LintResults, NavigationCapabilities, ParseResult,
+    ParserCapabilities, ProcessDiagnosticsAndActions, ProcessFixAll, ProcessLint, RenamedExport,
+    SearchCapabilities, collect_document_symbols, folding_ranges_of_brackets, search,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
@@ -10,7 +11,12 @@ use crate::file_handlers::FixAllParams;
 use crate::settings::{
     OverrideSettings, Settings, check_feature_activity, check_override_feature_activity,
 };
-use crate::workspace::{DocumentFileSource, PullDiagnosticsAndActionsResult};
+use crate::workspace::{
+    DocumentFileSource, DocumentSymbol, FoldingRange, FoldingRangeKind, GetDefinitionResult,
+    GetHoverResult, GetReferencesResult, InlayHint, InlayHintKind, PrepareRenameResult,
+    PullDiagnosticsAndActionsResult, SemanticToken, SemanticTokenKind, SemanticTokenModifier,
+    SymbolKind, SymbolLocation,
+};
 use crate::{
     WorkspaceError,
     settings::{FormatSettings, LanguageListSettings, LanguageSettings, ServiceLanguage},
@@ -22,42 +28,75 @@ use biome_analyze::{
     RuleCategoriesBuilder, RuleFilter,
 };
 use biome_configuration::javascript::{
-    JsAssistConfiguration, JsAssistEnabled, JsFormatterConfiguration, JsFormatterEnabled,
-    JsGritMetavariable, JsLinterConfiguration, JsLinterEnabled, JsParserConfiguration,
-    JsxEverywhere, JsxRuntime, UnsafeParameterDecoratorsEnabled,
+    DEFAULT_EMBEDDED_CSS_TAGS, DEFAULT_EMBEDDED_GRAPHQL_TAGS, JsAssistConfiguration,
+    JsAssistEnabled, JsEmbeddedLanguagesConfiguration, JsEmbeddedLanguagesEnabled,
+    JsFormatterConfiguration, JsFormatterEnabled, JsGritMetavariable, JsLinterConfiguration,
+    JsLinterEnabled, JsParserConfiguration, JsxEverywhere, JsxRuntime,
+    UnsafeParameterDecoratorsEnabled,
 };
+use biome_css_syntax::CssLanguage;
+use biome_formatter::format_element::{Interned, LineMode};
+use biome_formatter::prelude::{Document, Tag};
 use biome_formatter::{
-    AttributePosition, BracketSameLine, BracketSpacing, Expand, FormatError, IndentStyle,
-    IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
+    AttributePosition, BracketSameLine, BracketSpacing, Expand, FormatElement, FormatError,
+    IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
 };
 use biome_fs::BiomePath;
-use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
+use biome_graphql_syntax::GraphqlLanguage;
+use biome_js_analyze::globals::{is_js_global, is_ts_global};
+use biome_js_analyze::utils::rename::{
+    AnyJsRenamableDeclaration, RenamableNode, RenameError, RenameSymbolExtensions,
+};
 use biome_js_analyze::{
     ControlFlowGraph, JsAnalyzerServices, analyze, analyze_with_inspect_matcher,
 };
+use biome_js_factory::make;
 use biome_js_formatter::context::trailing_commas::TrailingCommas;
 use biome_js_formatter::context::{
     ArrowParentheses, JsFormatOptions, OperatorLinebreak, QuoteProperties, Semicolons,
 };
 use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
-use biome_js_semantic::{SemanticModelOptions, semantic_model};
+use biome_js_semantic::{SemanticModel, SemanticModelOptions, semantic_model};
+use biome_js_syntax::binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding};
 use biome_js_syntax::{
-    AnyJsRoot, JsClassDeclaration, JsClassExpression, JsFileSource, JsFunctionDeclaration,
-    JsLanguage, JsSyntaxNode, JsVariableDeclarator, TextRange, TextSize, TokenAtOffset,
+    AnyJsArrowFunctionParameters, AnyJsBindingPattern, AnyJsCallArgument, AnyJsExpression,
+    AnyJsFunction, AnyJsModuleItem, AnyJsRoot, JsCallExpression, JsClassDeclaration,
+    JsClassExportDefaultDeclaration, JsClassExpression, JsConstructorClassMember,
+    JsExportNamedFromClause, JsFileSource, JsFunctionDeclaration,
+    JsFunctionExportDefaultDeclaration, JsGetterClassMember, JsGetterObjectMember,
+    JsIdentifierExpression, JsImport, JsLanguage, JsLiteralExportName, JsMethodClassMember,
+    JsMethodObjectMember, JsNamedImportSpecifier, JsNamespaceImportSpecifier,
+    JsPropertyClassMember, JsPropertyObjectMember, JsSetterClassMember, JsSetterObjectMember,
+    JsShorthandNamedImportSpecifier, JsShorthandPropertyObjectMember, JsStaticMemberExpression,
+    JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, JsVariableDeclarator, Language, TextRange, TextSize,
+    TokenAtOffset, TsDeclareFunctionDeclaration, TsEnumDeclaration, TsEnumMember,
+    TsExternalModuleDeclaration, TsInterfaceDeclaration, TsMethodSignatureClassMember,
+    TsMethodSignatureTypeMember, TsModuleDeclaration, TsPropertySignatureTypeMember,
+    TsQualifiedName, TsTypeAliasDeclaration,
+};
+use biome_js_type_info::{
+    FunctionParameter, GlobalsResolver, Literal, ScopeId, Type, TypeData, TypeReference,
+    TypeResolver,
 };
-use biome_js_type_info::{GlobalsResolver, ScopeId, TypeData, TypeResolver};
-use biome_module_graph::ModuleGraph;
+use biome_module_graph::{ImportSymbol, JsExport, ModuleGraph, ModuleResolver, NamespaceImport};
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, BatchMutationExt, Direction, NodeCache, WalkEvent};
+use biome_rowan::{
+    AstNode, AstSeparatedList, BatchMutation, BatchMutationExt, Direction, NodeCache, SyntaxResult,
+    WalkEvent,
+};
+use biome_text_edit::TextEdit;
 use camino::Utf8Path;
 use either::Either;
+use rustc_hash::FxHashSet;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::Debug;
 use std::sync::Arc;
 use tracing::{debug, debug_span, error, trace_span};
 
+mod embedded;
+
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JsFormatterSettings {
@@ -108,6 +147,7 @@ pub struct JsParserSettings {
     pub parse_class_parameter_decorators: Option<UnsafeParameterDecoratorsEnabled>,
     pub grit_metavariables: Option<JsGritMetavariable>,
     pub jsx_everywhere: Option<JsxEverywhere>,
+    pub embedded_languages: JsEmbeddedLanguagesSettings,
 }
 
 impl From<JsParserConfiguration> for JsParserSettings {
@@ -116,6 +156,47 @@ impl From<JsParserConfiguration> for JsParserSettings {
             parse_class_parameter_decorators: value.unsafe_parameter_decorators_enabled,
             grit_metavariables: value.grit_metavariables,
             jsx_everywhere: value.jsx_everywhere,
+            embedded_languages: JsEmbeddedLanguagesSettings::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JsEmbeddedLanguagesSettings {
+    pub enabled: Option<JsEmbeddedLanguagesEnabled>,
+    pub css_tags: Option<Vec<Box<str>>>,
+    pub graphql_tags: Option<Vec<Box<str>>>,
+}
+
+impl From<JsEmbeddedLanguagesConfiguration> for JsEmbeddedLanguagesSettings {
+    fn from(value: JsEmbeddedLanguagesConfiguration) -> Self {
+        Self {
+            enabled: value.enabled,
+            css_tags: value.css_tags,
+            graphql_tags: value.graphql_tags,
+        }
+    }
+}
+
+impl JsEmbeddedLanguagesSettings {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or_default().into()
+    }
+
+    /// Whether the template literals tagged with `name` contain CSS.
+    pub fn is_css_tag(&self, name: &str) -> bool {
+        match &self.css_tags {
+            Some(tags) => tags.iter().any(|tag| tag.as_ref() == name),
+            None => DEFAULT_EMBEDDED_CSS_TAGS.contains(&name),
+        }
+    }
+
+    /// Whether the template literals tagged with `name` contain GraphQL.
+    pub fn is_graphql_tag(&self, name: &str) -> bool {
+        match &self.graphql_tags {
+            Some(tags) => tags.iter().any(|tag| tag.as_ref() == name),
+            None => DEFAULT_EMBEDDED_GRAPHQL_TAGS.contains(&name),
         }
     }
 }
@@ -486,7 +567,7 @@ impl ExtensionHandler for JsFileHandler {
             },
             parser: ParserCapabilities {
                 parse: Some(parse),
-                parse_embedded_nodes: None,
+                parse_embedded_nodes: Some(embedded::parse_embedded_nodes),
             },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
@@ -501,6 +582,8 @@ impl ExtensionHandler for JsFileHandler {
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
                 rename: Some(rename),
+                prepare_rename: Some(prepare_rename),
+                rename_imports: Some(rename_imports),
                 update_snippets: None,
                 pull_diagnostics_and_actions: Some(pull_diagnostics_and_actions),
             },
@@ -508,11 +591,22 @@ impl ExtensionHandler for JsFileHandler {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
-                format_embedded: None,
+                format_embedded: Some(format_embedded),
             },
             search: SearchCapabilities {
                 search: Some(search),
             },
+            navigation: NavigationCapabilities {
+                hover: Some(hover),
+                definition: Some(definition),
+                references: Some(references),
+                namespace_member_references: Some(namespace_member_references),
+                completions: None,
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(folding_ranges),
+                semantic_tokens: Some(semantic_tokens),
+                inlay_hints: Some(inlay_hints),
+            },
         }
     }
 }
@@ -542,11 +636,51 @@ fn parse(
 ) -> ParseResult {
     let options = settings.parse_options::<JsLanguage>(biome_path, &file_source);
 
-    let file_source = file_source.to_js_file_source().unwrap_or_default();
+    let mut file_source = file_source.to_js_file_source().unwrap_or_default();
+    // Plain JavaScript files that opt into Flow with the `@flow` pragma
+    // are parsed with the Flow type annotations enabled
+    let language = if file_source.language().is_javascript() && has_flow_pragma(text) {
+        file_source = file_source.with_language(Language::Flow);
+        Some(DocumentFileSource::Js(file_source))
+    } else {
+        None
+    };
     let parse = biome_js_parser::parse_js_with_cache(text, file_source, options, cache);
     ParseResult {
         any_parse: parse.into(),
-        language: None,
+        language,
+    }
+}
+
+/// Returns `true` if one of the comments at the top of the file contains the
+/// `@flow` pragma, e.g. `// @flow` or `/* @flow strict */`.
+fn has_flow_pragma(text: &str) -> bool {
+    let mut rest = text.strip_prefix('\u{feff}').unwrap_or(text);
+    if rest.starts_with("#!") {
+        rest = rest.find('\n').map_or("", |index| &rest[index..]);
+    }
+
+    loop {
+        rest = rest.trim_start();
+        let comment = if let Some(line_comment) = rest.strip_prefix("//") {
+            let end = line_comment.find('\n').unwrap_or(line_comment.len());
+            rest = &line_comment[end..];
+            &line_comment[..end]
+        } else if let Some(block_comment) = rest.strip_prefix("/*") {
+            let end = block_comment.find("*/").unwrap_or(block_comment.len());
+            rest = block_comment.get(end + 2..).unwrap_or_default();
+            &block_comment[..end]
+        } else {
+            return false;
+        };
+
+        let has_pragma = comment.match_indices("@flow").any(|(index, pragma)| {
+            !comment[index + pragma.len()..]
+                .starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        });
+        if has_pragma {
+            return true;
+        }
     }
 }
 
@@ -612,7 +746,7 @@ fn debug_formatter_ir(
     let options = settings.format_options::<JsLanguage>(path, document_file_source);
 
     let tree = parse.syntax();
-    let formatted = format_node(options, &tree)?;
+    let formatted = format_node(options, &tree, false)?;
 
     let root_element = formatted.into_document();
     Ok(root_element.to_string())
@@ -735,11 +869,14 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
         };
     };
     let tree = params.parse.tree();
-    let analyzer_options = params.settings.analyzer_options::<JsLanguage>(
-        params.path,
-        &params.language,
-        params.suppression_reason.as_deref(),
-    );
+    let analyzer_options = params
+        .settings
+        .analyzer_options::<JsLanguage>(
+            params.path,
+            &params.language,
+            params.suppression_reason.as_deref(),
+        )
+        .with_suppressed_signals(params.pull_suppressed);
     let (enabled_rules, disabled_rules, analyzer_options) =
         AnalyzerVisitorBuilder::new(params.settings, analyzer_options)
             .with_only(params.only)
@@ -926,6 +1063,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                             &params.document_file_source,
                         ),
                         tree.syntax(),
+                        false,
                     ))
                 } else {
                     Either::Right(tree.syntax().to_string())
@@ -944,7 +1082,7 @@ pub(crate) fn format(
     let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
     debug!("{:?}", &options);
     let tree = parse.syntax();
-    let formatted = format_node(options, &tree)?;
+    let formatted = format_node(options, &tree, false)?;
     match formatted.print() {
         Ok(printed) => Ok(printed),
         Err(error) => {
@@ -969,6 +1107,51 @@ pub(crate) fn format_range(
     Ok(printed)
 }
 
+/// Formats the JavaScript file and the content of its tagged template literals that embed
+/// other languages, each with the formatter of its language.
+fn format_embedded(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: &Settings,
+    embedded_nodes: Vec<FormatEmbedNode>,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+
+    let tree: AnyJsRoot = parse.tree();
+    let mut formatted = format_node(options, tree.syntax(), true)?;
+    formatted.format_embedded(|range| {
+        let node = embedded_nodes.iter().find(|node| node.range == range)?;
+        let template = embedded::find_template(&tree, range)?;
+
+        let document = match node.source {
+            DocumentFileSource::Css(_) => {
+                let css_options = settings.format_options::<CssLanguage>(biome_path, &node.source);
+                embedded::format_embedded_css(&template, &node.node, css_options)?
+            }
+            DocumentFileSource::Graphql(_) => {
+                let graphql_options =
+                    settings.format_options::<GraphqlLanguage>(biome_path, &node.source);
+                embedded::format_embedded_graphql(&template, &node.node, graphql_options)?
+            }
+            _ => return None,
+        };
+
+        // The content starts on the line that follows the opening backtick, and it's indented
+        Some(Document::new(vec![
+            FormatElement::Tag(Tag::StartIndent),
+            FormatElement::Line(LineMode::Hard),
+            FormatElement::Interned(Interned::new(document.into_elements())),
+            FormatElement::Tag(Tag::EndIndent),
+        ]))
+    });
+
+    match formatted.print() {
+        Ok(printed) => Ok(printed),
+        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    }
+}
+
 #[tracing::instrument(level = "debug", skip(parse, settings, document_file_source))]
 pub(crate) fn format_on_type(
     path: &BiomePath,
@@ -1095,7 +1278,11 @@ fn rename(
                     }))
                 } else {
                     let (range, indels) = batch.to_text_range_and_edit().unwrap_or_default();
-                    Ok(RenameResult { range, indels })
+                    Ok(RenameResult {
+                        range,
+                        indels,
+                        file_edits: Vec::new(),
+                    })
                 }
             }
             Err(err) => Err(WorkspaceError::RenameError(err)),
@@ -1106,3 +1293,1114 @@ fn rename(
         ))
     }
 }
+
+fn prepare_rename(
+    _path: &BiomePath,
+    parse: AnyParse,
+    symbol_at: TextSize,
+) -> Result<PrepareRenameResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let Some((node, identifier)) = identifier_at_offset(&root, symbol_at) else {
+        return Ok(PrepareRenameResult::default());
+    };
+
+    // Globals and unresolved references can't be renamed.
+    if identifier.binding(&model).is_none() {
+        return Ok(PrepareRenameResult::default());
+    }
+
+    Ok(PrepareRenameResult {
+        range: Some(node.text_trimmed_range()),
+        placeholder: Some(node.text_trimmed().to_string()),
+    })
+}
+
+/// Updates the imports and re-exports of a module that refer to an exported
+/// symbol renamed in another module.
+///
+/// Named imports and re-exports are updated to use the new name, as well as
+/// the members accessed through namespace imports. When a named import has
+/// no alias, the references to the local binding are renamed too.
+fn rename_imports(
+    path: &BiomePath,
+    parse: AnyParse,
+    export: &RenamedExport,
+    module_graph: Arc<ModuleGraph>,
+) -> Result<Option<(TextRange, TextEdit)>, WorkspaceError> {
+    let Some(module_info) = module_graph.js_module_info_for_path(path) else {
+        return Ok(None);
+    };
+
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let mut batch = root.clone().begin();
+    let new_name = export.new_name;
+    let refers_to_export = |import| {
+        module_graph.import_resolves_to_declaration(import, export.name, export.path, export.range)
+    };
+
+    let mut named_imports = FxHashSet::default();
+    let mut namespace_imports = FxHashSet::default();
+    for (local_name, import) in module_info.static_imports.iter() {
+        match &import.symbol {
+            ImportSymbol::Named(name) if name.text() == export.name && refers_to_export(import) => {
+                named_imports.insert(local_name.text());
+            }
+            ImportSymbol::All if refers_to_export(import) => {
+                namespace_imports.insert(local_name.text());
+            }
+            _ => {}
+        }
+    }
+    let reexport_specifiers: FxHashSet<_> = module_info
+        .exports
+        .values()
+        .filter_map(|export| match export {
+            JsExport::Reexport(reexport) | JsExport::ReexportType(reexport) => {
+                Some(&reexport.import)
+            }
+            _ => None,
+        })
+        .filter(|import| {
+            matches!(&import.symbol, ImportSymbol::Named(name) if name.text() == export.name)
+                && refers_to_export(import)
+        })
+        .map(|import| import.specifier.text())
+        .collect();
+
+    for node in root.syntax().descendants() {
+        if let Some(specifier) = JsShorthandNamedImportSpecifier::cast_ref(&node) {
+            let Some(binding) = specifier
+                .local_name()
+                .ok()
+                .and_then(|binding| binding.as_js_identifier_binding().cloned())
+            else {
+                continue;
+            };
+            let is_renamed_import = binding
+                .name_token()
+                .is_ok_and(|name| named_imports.contains(name.text_trimmed()));
+            if is_renamed_import {
+                let declaration = AnyJsRenamableDeclaration::JsIdentifierBinding(binding);
+                batch.rename_any_renamable_node(&model, &declaration, new_name);
+            }
+        } else if let Some(specifier) = JsNamedImportSpecifier::cast_ref(&node) {
+            let is_renamed_import = specifier
+                .local_name()
+                .ok()
+                .and_then(|binding| binding.as_js_identifier_binding()?.name_token().ok())
+                .is_some_and(|name| named_imports.contains(name.text_trimmed()));
+            if is_renamed_import {
+                rename_export_name(&mut batch, specifier.name(), new_name);
+            }
+        } else if let Some(specifier) = JsNamespaceImportSpecifier::cast_ref(&node) {
+            let Some(binding) = specifier
+                .local_name()
+                .ok()
+                .and_then(|binding| binding.as_js_identifier_binding().cloned())
+            else {
+                continue;
+            };
+            let is_renamed_import = binding
+                .name_token()
+                .is_ok_and(|name| namespace_imports.contains(name.text_trimmed()));
+            if is_renamed_import {
+                let binding = model.as_binding(&binding);
+                for reference in binding.all_references() {
+                    rename_namespace_member(&mut batch, reference.syntax(), export.name, new_name);
+                }
+            }
+        } else if let Some(clause) = JsExportNamedFromClause::cast_ref(&node) {
+            let is_renamed_reexport = clause
+                .source()
+                .ok()
+                .and_then(|source| source.as_js_module_source()?.inner_string_text().ok())
+                .is_some_and(|source| reexport_specifiers.contains(source.text()));
+            if !is_renamed_reexport {
+                continue;
+            }
+
+            for specifier in clause.specifiers().iter().flatten() {
+                let source_name = specifier.source_name();
+                let is_renamed_symbol = source_name
+                    .as_ref()
+                    .ok()
+                    .and_then(|name| name.value().ok())
+                    .is_some_and(|name| name.text_trimmed() == export.name);
+                if is_renamed_symbol {
+                    rename_export_name(&mut batch, source_name, new_name);
+                }
+            }
+        }
+    }
+
+    Ok(batch.to_text_range_and_edit())
+}
+
+/// Replaces the name of an imported or re-exported symbol, unless it's written
+/// as a string literal.
+fn rename_export_name(
+    batch: &mut BatchMutation<JsLanguage>,
+    name: SyntaxResult<JsLiteralExportName>,
+    new_name: &str,
+) {
+    if let Some(token) = name
+        .ok()
+        .and_then(|name| name.value().ok())
+        .filter(|token| token.kind() == JsSyntaxKind::IDENT)
+    {
+        batch.replace_token_transfer_trivia(token, make::ident(new_name));
+    }
+}
+
+/// Replaces `name` in a member accessed through the namespace `reference`,
+/// such as `ns.name` or `ns.Name` in type positions.
+fn rename_namespace_member(
+    batch: &mut BatchMutation<JsLanguage>,
+    reference: &JsSyntaxNode,
+    name: &str,
+    new_name: &str,
+) {
+    if let Some(token) = namespace_member(reference, name) {
+        batch.replace_token_transfer_trivia(token, make::ident(new_name));
+    }
+}
+
+/// Returns the token of the member `name` accessed through the namespace
+/// `reference`, such as `name` in `ns.name` or `Name` in `ns.Name` in type
+/// positions.
+fn namespace_member(reference: &JsSyntaxNode, name: &str) -> Option<JsSyntaxToken> {
+    let parent = reference.parent()?;
+    let member = if let Some(expression) = JsIdentifierExpression::cast_ref(&parent) {
+        expression
+            .syntax()
+            .parent()
+            .and_then(JsStaticMemberExpression::cast)
+            .and_then(|member| member.member().ok())
+            .and_then(|member| member.as_js_name().cloned())
+    } else {
+        TsQualifiedName::cast(parent).and_then(|qualified_name| qualified_name.right().ok())
+    };
+
+    member?
+        .value_token()
+        .ok()
+        .filter(|token| token.text_trimmed() == name)
+}
+
+/// Returns the identifier at the given `offset`, if it refers to a binding.
+fn identifier_at_offset(
+    root: &AnyJsRoot,
+    offset: TextSize,
+) -> Option<(JsSyntaxNode, AnyJsRenamableDeclaration)> {
+    root.syntax()
+        .token_at_offset(offset)
+        .filter_map(|token| token.parent())
+        .find_map(|node| {
+            let identifier = AnyJsRenamableDeclaration::try_from(node.clone()).ok()?;
+            Some((node, identifier))
+        })
+}
+
+/// Returns the binding declared by the identifier at the given `offset`, or
+/// the binding it refers to.
+fn binding_at_offset(
+    root: &AnyJsRoot,
+    model: &SemanticModel,
+    offset: TextSize,
+) -> Option<AnyJsIdentifierBinding> {
+    let (_, identifier) = identifier_at_offset(root, offset)?;
+    identifier
+        .binding(model)
+        .and_then(AnyJsIdentifierBinding::cast)
+}
+
+/// Resolves the declaration imported by `binding`, if it is an import binding.
+///
+/// Returns `None` if the module graph can't resolve the imported symbol, so
+/// that callers fall back to the local import binding.
+fn resolve_imported_declaration(
+    path: &BiomePath,
+    binding: &AnyJsIdentifierBinding,
+    module_graph: &ModuleGraph,
+) -> Option<SymbolLocation> {
+    if !binding
+        .syntax()
+        .ancestors()
+        .any(|node| JsImport::can_cast(node.kind()))
+    {
+        return None;
+    }
+
+    let module_info = module_graph.js_module_info_for_path(path)?;
+    let name = binding.name_token().ok()?;
+    let import = module_info.static_imports.get(name.text_trimmed())?;
+    let resolved_path = import.resolved_path.as_path()?;
+    let declaration = match &import.symbol {
+        ImportSymbol::Default => {
+            module_graph.find_exported_symbol_declaration(resolved_path, "default")
+        }
+        ImportSymbol::Named(name) => {
+            module_graph.find_exported_symbol_declaration(resolved_path, name.text())
+        }
+        ImportSymbol::All => None,
+    };
+    let (path, range) = declaration?;
+
+    Some(SymbolLocation {
+        path: path.into(),
+        range,
+    })
+}
+
+fn hover(
+    path: &BiomePath,
+    parse: AnyParse,
+    offset: TextSize,
+    module_graph: Arc<ModuleGraph>,
+) -> Result<GetHoverResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    let Some((node, _)) = identifier_at_offset(&root, offset) else {
+        return Ok(GetHoverResult::default());
+    };
+
+    let range = node.text_trimmed_range();
+    let name = node.text_trimmed().to_string();
+    let content = match module_graph.js_module_info_for_path(path) {
+        Some(module_info) => {
+            let resolver = Arc::new(ModuleResolver::for_module(
+                module_info,
+                module_graph.clone(),
+            ));
+            let ty = resolver.resolved_type_of_named_value(range, &name);
+            format!("```ts\n{name}: {ty}\n```")
+        }
+        None => format!("```ts\n{name}\n```"),
+    };
+
+    Ok(GetHoverResult {
+        range: Some(range),
+        content: Some(content),
+    })
+}
+
+fn definition(
+    path: &BiomePath,
+    parse: AnyParse,
+    offset: TextSize,
+    module_graph: Arc<ModuleGraph>,
+) -> Result<GetDefinitionResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let Some(binding) = binding_at_offset(&root, &model, offset) else {
+        return Ok(GetDefinitionResult::default());
+    };
+
+    let location =
+        resolve_imported_declaration(path, &binding, &module_graph).unwrap_or_else(|| {
+            SymbolLocation {
+                path: path.clone(),
+                range: binding.syntax().text_trimmed_range(),
+            }
+        });
+
+    Ok(GetDefinitionResult {
+        locations: vec![location],
+    })
+}
+
+fn references(
+    path: &BiomePath,
+    parse: AnyParse,
+    offset: TextSize,
+    include_declaration: bool,
+    module_graph: Arc<ModuleGraph>,
+) -> Result<GetReferencesResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let Some(binding) = binding_at_offset(&root, &model, offset) else {
+        return Ok(GetReferencesResult::default());
+    };
+
+    let local_declaration = SymbolLocation {
+        path: path.clone(),
+        range: binding.syntax().text_trimmed_range(),
+    };
+    let imported_declaration = resolve_imported_declaration(path, &binding, &module_graph);
+    let binding = model.as_binding(&binding);
+
+    let mut locations = vec![local_declaration.clone()];
+    locations.extend(binding.all_references().map(|reference| SymbolLocation {
+        path: path.clone(),
+        range: reference.syntax().text_trimmed_range(),
+    }));
+
+    // Symbols that cross module boundaries are also looked up in the module
+    // graph. The current module is skipped, because the semantic model is
+    // always up to date with the document.
+    let declaration = imported_declaration.unwrap_or(local_declaration);
+    if binding.is_imported() || binding.exports().next().is_some() {
+        locations.extend(
+            module_graph
+                .find_references_to_declaration(&declaration.path, declaration.range)
+                .into_iter()
+                .filter(|(reference_path, _)| reference_path != path.as_path())
+                .map(|(path, range)| SymbolLocation {
+                    path: path.into(),
+                    range,
+                }),
+        );
+    }
+
+    if !include_declaration {
+        locations.retain(|location| *location != declaration);
+    }
+
+    let mut seen = FxHashSet::default();
+    locations.retain(|location| seen.insert(location.clone()));
+
+    Ok(GetReferencesResult { locations })
+}
+
+fn namespace_member_references(
+    parse: AnyParse,
+    namespace_import: &NamespaceImport,
+) -> Vec<TextRange> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let namespace = namespace_import.namespace.text();
+    let name = namespace_import.name.text();
+
+    root.syntax()
+        .descendants()
+        .filter_map(JsNamespaceImportSpecifier::cast)
+        .filter_map(|specifier| {
+            specifier
+                .local_name()
+                .ok()?
+                .as_js_identifier_binding()
+                .cloned()
+        })
+        .filter(|binding| {
+            binding
+                .name_token()
+                .is_ok_and(|token| token.text_trimmed() == namespace)
+        })
+        .flat_map(|binding| {
+            model
+                .as_binding(&binding)
+                .all_references()
+                .filter_map(|reference| namespace_member(reference.syntax(), name))
+                .map(|token| token.text_trimmed_range())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: AnyJsRoot = parse.tree();
+    collect_document_symbols(root.syntax(), document_symbol)
+}
+
+/// Returns the symbol declared by a node, if any.
+///
+/// The variables whose initializer is a function or a class are reported
+/// with the kind of their initializer.
+fn document_symbol(node: &JsSyntaxNode) -> Option<DocumentSymbol> {
+    let (kind, name) = match node.kind() {
+        JsSyntaxKind::JS_FUNCTION_DECLARATION => (
+            SymbolKind::Function,
+            Some(
+                JsFunctionDeclaration::cast_ref(node)?
+                    .id()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION => (
+            SymbolKind::Function,
+            Some(
+                TsDeclareFunctionDeclaration::cast_ref(node)?
+                    .id()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION => (
+            SymbolKind::Function,
+            JsFunctionExportDefaultDeclaration::cast_ref(node)?
+                .id()
+                .map(AstNode::into_syntax),
+        ),
+        JsSyntaxKind::JS_CLASS_DECLARATION => (
+            SymbolKind::Class,
+            Some(JsClassDeclaration::cast_ref(node)?.id().ok()?.into_syntax()),
+        ),
+        JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION => (
+            SymbolKind::Class,
+            JsClassExportDefaultDeclaration::cast_ref(node)?
+                .id()
+                .map(AstNode::into_syntax),
+        ),
+        JsSyntaxKind::JS_VARIABLE_DECLARATOR => {
+            let declarator = JsVariableDeclarator::cast_ref(node)?;
+            let AnyJsBindingPattern::AnyJsBinding(binding) = declarator.id().ok()? else {
+                return None;
+            };
+            let initializer = declarator
+                .initializer()
+                .and_then(|initializer| initializer.expression().ok());
+            let kind = match initializer {
+                Some(
+                    AnyJsExpression::JsArrowFunctionExpression(_)
+                    | AnyJsExpression::JsFunctionExpression(_),
+                ) => SymbolKind::Function,
+                Some(AnyJsExpression::JsClassExpression(_)) => SymbolKind::Class,
+                _ if declarator
+                    .declaration()
+                    .is_some_and(|declaration| declaration.is_const()) =>
+                {
+                    SymbolKind::Constant
+                }
+                _ => SymbolKind::Variable,
+            };
+            (kind, Some(binding.into_syntax()))
+        }
+        JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER => (
+            SymbolKind::Constructor,
+            Some(
+                JsConstructorClassMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::JS_METHOD_CLASS_MEMBER => (
+            SymbolKind::Method,
+            Some(
+                JsMethodClassMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::TS_METHOD_SIGNATURE_CLASS_MEMBER => (
+            SymbolKind::Method,
+            Some(
+                TsMethodSignatureClassMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER => (
+            SymbolKind::Property,
+            Some(
+                JsPropertyClassMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::JS_GETTER_CLASS_MEMBER => (
+            SymbolKind::Property,
+            Some(
+                JsGetterClassMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::JS_SETTER_CLASS_MEMBER => (
+            SymbolKind::Property,
+            Some(
+                JsSetterClassMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::TS_INTERFACE_DECLARATION => (
+            SymbolKind::Interface,
+            Some(
+                TsInterfaceDeclaration::cast_ref(node)?
+                    .id()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::TS_PROPERTY_SIGNATURE_TYPE_MEMBER => (
+            SymbolKind::Property,
+            Some(
+                TsPropertySignatureTypeMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::TS_METHOD_SIGNATURE_TYPE_MEMBER => (
+            SymbolKind::Method,
+            Some(
+                TsMethodSignatureTypeMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION => (
+            SymbolKind::TypeParameter,
+            Some(
+                TsTypeAliasDeclaration::cast_ref(node)?
+                    .binding_identifier()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::TS_ENUM_DECLARATION => (
+            SymbolKind::Enum,
+            Some(TsEnumDeclaration::cast_ref(node)?.id().ok()?.into_syntax()),
+        ),
+        JsSyntaxKind::TS_ENUM_MEMBER => (
+            SymbolKind::EnumMember,
+            Some(TsEnumMember::cast_ref(node)?.name().ok()?.into_syntax()),
+        ),
+        JsSyntaxKind::TS_MODULE_DECLARATION => {
+            let declaration = TsModuleDeclaration::cast_ref(node)?;
+            let kind = if declaration.is_namespace().ok()? {
+                SymbolKind::Namespace
+            } else {
+                SymbolKind::Module
+            };
+            (kind, Some(declaration.name().ok()?.into_syntax()))
+        }
+        JsSyntaxKind::TS_EXTERNAL_MODULE_DECLARATION => (
+            SymbolKind::Module,
+            Some(
+                TsExternalModuleDeclaration::cast_ref(node)?
+                    .source()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::JS_PROPERTY_OBJECT_MEMBER => (
+            SymbolKind::Property,
+            Some(
+                JsPropertyObjectMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::JS_SHORTHAND_PROPERTY_OBJECT_MEMBER => (
+            SymbolKind::Property,
+            Some(
+                JsShorthandPropertyObjectMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::JS_METHOD_OBJECT_MEMBER => (
+            SymbolKind::Method,
+            Some(
+                JsMethodObjectMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::JS_GETTER_OBJECT_MEMBER => (
+            SymbolKind::Property,
+            Some(
+                JsGetterObjectMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        JsSyntaxKind::JS_SETTER_OBJECT_MEMBER => (
+            SymbolKind::Property,
+            Some(
+                JsSetterObjectMember::cast_ref(node)?
+                    .name()
+                    .ok()?
+                    .into_syntax(),
+            ),
+        ),
+        _ => return None,
+    };
+
+    // Anonymous default exports are named after the export
+    let (name, selection_range) = match name {
+        Some(name) => (name.text_trimmed().to_string(), name.text_trimmed_range()),
+        None => (
+            "default".to_string(),
+            node.first_token()?.text_trimmed_range(),
+        ),
+    };
+
+    Some(DocumentSymbol {
+        name,
+        kind,
+        range: node.text_trimmed_range(),
+        selection_range,
+        children: Vec::new(),
+    })
+}
+
+/// Returns the regions delimited by brackets, the multi-line JSX elements
+/// and the groups of consecutive imports.
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let root: AnyJsRoot = parse.tree();
+    let mut ranges = folding_ranges_of_brackets(root.syntax());
+
+    ranges.extend(
+        root.syntax()
+            .descendants()
+            .filter(|node| node.kind() == JsSyntaxKind::JSX_ELEMENT)
+            .filter(|node| node.text_trimmed().contains_char('\n'))
+            .map(|node| FoldingRange {
+                range: node.text_trimmed_range(),
+                kind: FoldingRangeKind::Region,
+            }),
+    );
+
+    if let AnyJsRoot::JsModule(module) = &root {
+        let mut imports: Vec<TextRange> = Vec::new();
+        let mut flush = |imports: &mut Vec<TextRange>| {
+            if let [first, .., last] = imports.as_slice() {
+                ranges.push(FoldingRange {
+                    range: first.cover(*last),
+                    kind: FoldingRangeKind::Imports,
+                });
+            }
+            imports.clear();
+        };
+        for item in module.items() {
+            if matches!(item, AnyJsModuleItem::JsImport(_)) {
+                imports.push(item.syntax().text_trimmed_range());
+            } else {
+                flush(&mut imports);
+            }
+        }
+        flush(&mut imports);
+    }
+
+    ranges.sort_by_key(|range| (range.range.start(), range.range.end()));
+    ranges
+}
+
+/// Returns the bindings of the file, their references and the references to
+/// the globals of the environment, classified using the semantic model.
+fn semantic_tokens(parse: AnyParse) -> Vec<SemanticToken> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let mut tokens = Vec::new();
+
+    for binding in model.all_bindings() {
+        let declaration = binding.tree().declaration();
+        let Some(kind) = declaration.as_ref().and_then(semantic_token_kind) else {
+            continue;
+        };
+        let readonly = declaration.as_ref().is_some_and(is_readonly_declaration);
+        let mut references = binding.all_references().peekable();
+        let is_unused = references.peek().is_none() && binding.exports().next().is_none();
+
+        let mut modifiers = vec![SemanticTokenModifier::Declaration];
+        if readonly {
+            modifiers.push(SemanticTokenModifier::Readonly);
+        }
+        if is_unused {
+            modifiers.push(SemanticTokenModifier::Unused);
+        }
+        tokens.push(SemanticToken {
+            range: binding.syntax().text_trimmed_range(),
+            kind,
+            modifiers,
+        });
+
+        for reference in references {
+            tokens.push(SemanticToken {
+                range: reference.syntax().text_trimmed_range(),
+                kind,
+                modifiers: if readonly {
+                    vec![SemanticTokenModifier::Readonly]
+                } else {
+                    Vec::new()
+                },
+            });
+        }
+    }
+
+    for reference in model.all_unresolved_references() {
+        let node = reference.syntax();
+        let name = node.text_trimmed().to_string();
+        if is_js_global(&name) || is_ts_global(&name) {
+            tokens.push(SemanticToken {
+                range: node.text_trimmed_range(),
+                kind: SemanticTokenKind::Variable,
+                modifiers: vec![SemanticTokenModifier::DefaultLibrary],
+            });
+        }
+    }
+
+    tokens.sort_by_key(|token| token.range.start());
+    tokens.dedup_by_key(|token| token.range);
+    tokens
+}
+
+fn semantic_token_kind(declaration: &AnyJsBindingDeclaration) -> Option<SemanticTokenKind> {
+    if let Some(declaration) = declaration.parent_binding_pattern_declaration() {
+        return semantic_token_kind(&declaration).map(|kind| match kind {
+            SemanticTokenKind::Function | SemanticTokenKind::Class => SemanticTokenKind::Variable,
+            kind => kind,
+        });
+    }
+
+    let kind = match declaration {
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            match declarator
+                .initializer()
+                .and_then(|initializer| initializer.expression().ok())
+                .map(AnyJsExpression::omit_parentheses)
+            {
+                Some(
+                    AnyJsExpression::JsArrowFunctionExpression(_)
+                    | AnyJsExpression::JsFunctionExpression(_),
+                ) => SemanticTokenKind::Function,
+                Some(AnyJsExpression::JsClassExpression(_)) => SemanticTokenKind::Class,
+                _ => SemanticTokenKind::Variable,
+            }
+        }
+        AnyJsBindingDeclaration::JsArrowFunctionExpression(_)
+        | AnyJsBindingDeclaration::JsFormalParameter(_)
+        | AnyJsBindingDeclaration::JsRestParameter(_)
+        | AnyJsBindingDeclaration::JsBogusParameter(_)
+        | AnyJsBindingDeclaration::TsIndexSignatureParameter(_)
+        | AnyJsBindingDeclaration::TsPropertyParameter(_) => SemanticTokenKind::Parameter,
+        AnyJsBindingDeclaration::TsInferType(_)
+        | AnyJsBindingDeclaration::TsMappedType(_)
+        | AnyJsBindingDeclaration::TsTypeParameter(_) => SemanticTokenKind::TypeParameter,
+        AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExpression(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionExportDefaultDeclaration(_) => {
+            SemanticTokenKind::Function
+        }
+        AnyJsBindingDeclaration::TsEnumMember(_) => SemanticTokenKind::EnumMember,
+        AnyJsBindingDeclaration::JsClassDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassExpression(_)
+        | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_) => SemanticTokenKind::Class,
+        AnyJsBindingDeclaration::TsInterfaceDeclaration(_) => SemanticTokenKind::Interface,
+        AnyJsBindingDeclaration::TsTypeAliasDeclaration(_) => SemanticTokenKind::Type,
+        AnyJsBindingDeclaration::TsEnumDeclaration(_) => SemanticTokenKind::Enum,
+        AnyJsBindingDeclaration::TsExternalModuleDeclaration(_)
+        | AnyJsBindingDeclaration::TsModuleDeclaration(_)
+        | AnyJsBindingDeclaration::JsNamespaceImportSpecifier(_) => SemanticTokenKind::Namespace,
+        AnyJsBindingDeclaration::JsShorthandNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsDefaultImportSpecifier(_)
+        | AnyJsBindingDeclaration::TsImportEqualsDeclaration(_)
+        | AnyJsBindingDeclaration::JsCatchDeclaration(_) => SemanticTokenKind::Variable,
+        AnyJsBindingDeclaration::JsBogusNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsArrayBindingPatternElement(_)
+        | AnyJsBindingDeclaration::JsArrayBindingPatternRestElement(_)
+        | AnyJsBindingDeclaration::JsObjectBindingPatternProperty(_)
+        | AnyJsBindingDeclaration::JsObjectBindingPatternRest(_)
+        | AnyJsBindingDeclaration::JsObjectBindingPatternShorthandProperty(_) => return None,
+    };
+
+    Some(kind)
+}
+
+/// Returns `true` if the bindings of the declaration can't be reassigned.
+fn is_readonly_declaration(declaration: &AnyJsBindingDeclaration) -> bool {
+    if let Some(declaration) = declaration.parent_binding_pattern_declaration() {
+        return is_readonly_declaration(&declaration);
+    }
+
+    match declaration {
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => declarator
+            .declaration()
+            .is_some_and(|declaration| declaration.is_const()),
+        AnyJsBindingDeclaration::JsShorthandNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsDefaultImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsNamespaceImportSpecifier(_)
+        | AnyJsBindingDeclaration::TsImportEqualsDeclaration(_)
+        | AnyJsBindingDeclaration::TsEnumMember(_) => true,
+        _ => false,
+    }
+}
+
+/// Types nested deeper than this aren't rendered in inlay hints.
+const MAX_INLAY_HINT_TYPE_DEPTH: usize = 4;
+
+fn inlay_hints(
+    path: &BiomePath,
+    parse: AnyParse,
+    range: TextRange,
+    module_graph: Arc<ModuleGraph>,
+) -> Vec<InlayHint> {
+    let Some(module_info) = module_graph.js_module_info_for_path(path) else {
+        return Vec::new();
+    };
+
+    let resolver = Arc::new(ModuleResolver::for_module(
+        module_info,
+        module_graph.clone(),
+    ));
+    let root: AnyJsRoot = parse.tree();
+    let mut hints = Vec::new();
+    for node in root.syntax().descendants() {
+        if range.intersect(node.text_trimmed_range()).is_none() {
+            continue;
+        }
+
+        if let Some(function) = AnyJsFunction::cast_ref(&node) {
+            hints.extend(return_type_hint(&resolver, &function));
+        } else if let Some(declarator) = JsVariableDeclarator::cast_ref(&node) {
+            hints.extend(variable_type_hint(&resolver, &declarator));
+        } else if let Some(call) = JsCallExpression::cast_ref(&node) {
+            hints.extend(parameter_name_hints(&resolver, &call));
+        }
+    }
+
+    hints.retain(|hint| range.contains(hint.position) || range.end() == hint.position);
+    hints.sort_by_key(|hint| hint.position);
+    hints
+}
+
+/// Returns the inferred return type of a function without annotation,
+/// displayed after its parameters.
+fn return_type_hint(resolver: &Arc<ModuleResolver>, function: &AnyJsFunction) -> Option<InlayHint> {
+    if function.return_type_annotation().is_some() || function.is_generator() {
+        return None;
+    }
+
+    // A hint after a parameter without parentheses, like `a => a`, would read
+    // as an annotation of the parameter
+    let AnyJsArrowFunctionParameters::JsParameters(parameters) = function.parameters().ok()? else {
+        return None;
+    };
+
+    let ty = match function {
+        AnyJsFunction::JsArrowFunctionExpression(expression) => {
+            resolver.resolved_type_of_expression(&AnyJsExpression::from(expression.clone()))
+        }
+        AnyJsFunction::JsFunctionExpression(expression) => {
+            resolver.resolved_type_of_expression(&AnyJsExpression::from(expression.clone()))
+        }
+        AnyJsFunction::JsFunctionDeclaration(_)
+        | AnyJsFunction::JsFunctionExportDefaultDeclaration(_) => {
+            let id = function.id().ok()??;
+            let name = id.as_js_identifier_binding()?.name_token().ok()?;
+            resolver.resolved_type_of_named_value(id.range(), name.text_trimmed())
+        }
+    };
+
+    let TypeData::Function(function_type) = &*ty else {
+        return None;
+    };
+    let return_type = ty.resolve(function_type.return_type.as_type()?)?;
+
+    Some(InlayHint {
+        position: parameters.range().end(),
+        label: format!(": {}", type_annotation(&return_type, 0)?),
+        kind: InlayHintKind::ReturnType,
+    })
+}
+
+/// Returns the inferred type of a `const` variable without annotation,
+/// displayed after its name.
+///
+/// Variables initialized with a literal, a function, a class or a `new`
+/// expression are skipped, because their type is obvious from the
+/// initializer.
+fn variable_type_hint(
+    resolver: &Arc<ModuleResolver>,
+    declarator: &JsVariableDeclarator,
+) -> Option<InlayHint> {
+    if declarator.variable_annotation().is_some() || !declarator.declaration()?.is_const() {
+        return None;
+    }
+
+    let initializer = declarator.initializer()?.expression().ok()?;
+    if matches!(
+        initializer,
+        AnyJsExpression::AnyJsLiteralExpression(_)
+            | AnyJsExpression::JsArrowFunctionExpression(_)
+            | AnyJsExpression::JsFunctionExpression(_)
+            | AnyJsExpression::JsClassExpression(_)
+            | AnyJsExpression::JsNewExpression(_)
+    ) {
+        return None;
+    }
+
+    let AnyJsBindingPattern::AnyJsBinding(binding) = declarator.id().ok()? else {
+        return None;
+    };
+    let name = binding.as_js_identifier_binding()?.name_token().ok()?;
+    let ty = resolver.resolved_type_of_named_value(binding.range(), name.text_trimmed());
+
+    Some(InlayHint {
+        position: binding.range().end(),
+        label: format!(": {}", type_annotation(&ty, 0)?),
+        kind: InlayHintKind::VariableType,
+    })
+}
+
+/// Returns the names of the parameters the arguments of a call are passed
+/// to, displayed before each argument.
+///
+/// Arguments that are identifiers with the same name as their parameter are
+/// skipped.
+fn parameter_name_hints(resolver: &Arc<ModuleResolver>, call: &JsCallExpression) -> Vec<InlayHint> {
+    let Ok(callee) = call.callee() else {
+        return Vec::new();
+    };
+    let ty = resolver.resolved_type_of_expression(&callee);
+    let TypeData::Function(function) = &*ty else {
+        return Vec::new();
+    };
+    let Ok(arguments) = call.arguments() else {
+        return Vec::new();
+    };
+
+    let mut hints = Vec::new();
+    for (argument, parameter) in arguments.args().iter().zip(&function.parameters) {
+        // The parameters of the arguments following a spread aren't known
+        let Ok(AnyJsCallArgument::AnyJsExpression(argument)) = argument else {
+            break;
+        };
+        let FunctionParameter::Named(parameter) = parameter else {
+            continue;
+        };
+        if parameter.is_rest {
+            break;
+        }
+
+        let is_same_name = argument
+            .as_js_identifier_expression()
+            .and_then(|identifier| identifier.name().ok())
+            .and_then(|name| name.value_token().ok())
+            .is_some_and(|name| name.text_trimmed() == parameter.name.text());
+        if !is_same_name {
+            hints.push(InlayHint {
+                position: argument.range().start(),
+                label: format!("{}:", parameter.name),
+                kind: InlayHintKind::ParameterName,
+            });
+        }
+    }
+
+    hints
+}
+
+/// Renders a type the way it would be written in an annotation.
+///
+/// Returns `None` if the type, or one of the types it's made of, isn't known
+/// or would be too verbose to be useful in a hint.
+fn type_annotation(ty: &Type, depth: usize) -> Option<String> {
+    if depth > MAX_INLAY_HINT_TYPE_DEPTH {
+        return None;
+    }
+
+    let nested = |reference: &TypeReference| {
+        ty.resolve(reference)
+            .and_then(|nested| type_annotation(&nested, depth + 1))
+    };
+
+    let annotation = match &**ty {
+        TypeData::BigInt => "bigint".to_string(),
+        TypeData::Boolean => "boolean".to_string(),
+        TypeData::Null => "null".to_string(),
+        TypeData::Number => "number".to_string(),
+        TypeData::String => "string".to_string(),
+        TypeData::Symbol => "symbol".to_string(),
+        TypeData::Undefined => "undefined".to_string(),
+        TypeData::AnyKeyword => "any".to_string(),
+        TypeData::NeverKeyword => "never".to_string(),
+        TypeData::ObjectKeyword => "object".to_string(),
+        TypeData::ThisKeyword => "this".to_string(),
+        TypeData::UnknownKeyword => "unknown".to_string(),
+        TypeData::VoidKeyword => "void".to_string(),
+        TypeData::Literal(literal) => match literal.as_ref() {
+            Literal::BigInt(value) => value.to_string(),
+            Literal::Boolean(value) => value.as_bool().to_string(),
+            Literal::Number(value) => value.as_str().to_string(),
+            Literal::String(value) => format!("\"{}\"", value.as_str()),
+            Literal::Template(_) => "string".to_string(),
+            Literal::RegExp(_) => "RegExp".to_string(),
+            Literal::Object(_) => return None,
+        },
+        TypeData::Class(class) => format!("typeof {}", class.name.as_ref()?),
+        TypeData::Interface(interface) => interface.name.to_string(),
+        TypeData::Generic(generic) => generic.name.to_string(),
+        TypeData::InstanceOf(instance) => {
+            let target = ty.resolve(&instance.ty)?;
+            let name = match &*target {
+                TypeData::Class(class) => class.name.as_ref()?.to_string(),
+                TypeData::Interface(interface) => interface.name.to_string(),
+                _ => return type_annotation(&target, depth + 1),
+            };
+            let type_parameters = instance
+                .type_parameters
+                .iter()
+                .map(nested)
+                .collect::<Option<Vec<_>>>()?;
+            match type_parameters.as_slice() {
+                [] => name,
+                [element] if name == "Array" => match ty.resolve(&instance.type_parameters[0]) {
+                    Some(element_type) if element_type.is_union() => format!("({element})[]"),
+                    _ => format!("{element}[]"),
+                },
+                _ => format!("{name}<{}>", type_parameters.join(", ")),
+            }
+        }
+        TypeData::Union(_) => ty
+            .flattened_union_variants()
+            .map(|variant| type_annotation(&variant, depth + 1))
+            .collect::<Option<Vec<_>>>()?
+            .join(" | "),
+        TypeData::Tuple(tuple) => {
+            let elements = tuple
+                .elements()
+                .iter()
+                .map(|element| {
+                    let element_type = nested(&element.ty)?;
+                    Some(match (element.is_rest, element.is_optional) {
+                        (true, _) => format!("...{element_type}[]"),
+                        (false, true) => format!("{element_type}?"),
+                        (false, false) => element_type,
+                    })
+                })
+                .collect::<Option<Vec<_>>>()?;
+            format!("[{}]", elements.join(", "))
+        }
+        TypeData::Function(function) => {
+            let parameters = function
+                .parameters
+                .iter()
+                .map(|parameter| {
+                    let FunctionParameter::Named(parameter) = parameter else {
+                        return None;
+                    };
+                    let parameter_type = nested(&parameter.ty)?;
+                    Some(match (parameter.is_rest, parameter.is_optional) {
+                        (true, _) => format!("...{}: {parameter_type}", parameter.name),
+                        (false, true) => format!("{}?: {parameter_type}", parameter.name),
+                        (false, false) => format!("{}: {parameter_type}", parameter.name),
+                    })
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let return_type = nested(function.return_type.as_type()?)?;
+            format!("({}) => {return_type}", parameters.join(", "))
+        }
+        _ => return None,
+    };
+
+    Some(annotation)
+}
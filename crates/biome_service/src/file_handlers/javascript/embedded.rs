@@ -0,0 +1,604 @@
+//! Snippets of other languages embedded in the tagged template literals of JavaScript files,
+//! such as ``styled.div`color: red;` `` and ``gql`query { user }` ``.
+//!
+//! The interpolations `${...}` of a template literal are replaced by placeholders that have the
+//! same length, so that the ranges of the embedded snippet match the ranges of the JavaScript file.
+//! The placeholders are restored when the snippet is formatted.
+
+use super::JsEmbeddedLanguagesSettings;
+use crate::file_handlers::ParseEmbedResult;
+use crate::settings::Settings;
+use crate::workspace::{
+    CssDocumentServices, DocumentFileSource, DocumentServices, EmbeddedSnippet,
+};
+use biome_css_formatter::context::CssFormatOptions;
+use biome_css_parser::parse_css_with_offset_and_cache;
+use biome_css_syntax::{
+    AnyCssRule, CssDeclarationOrRuleList, CssFileSource, CssLanguage, CssRoot, CssSyntaxNode,
+};
+use biome_formatter::FormatElement;
+use biome_formatter::format_element::Interned;
+use biome_formatter::prelude::Document;
+use biome_fs::BiomePath;
+use biome_graphql_formatter::context::GraphqlFormatOptions;
+use biome_graphql_parser::parse_graphql_with_offset_and_cache;
+use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsRoot, AnyJsTemplateElement, JsCallArgumentList, JsCallArguments,
+    JsCallExpression, JsTemplateExpression,
+};
+use biome_parser::AnyParse;
+use biome_rowan::{
+    AstNode, AstNodeList, AstSeparatedList, NodeCache, SyntaxNodeWithOffset, TextRange, TextSize,
+    TokenText,
+};
+use std::ops::Deref;
+
+/// The code that is parsed around the content of a CSS template literal, so that its
+/// declarations and nested rules belong to the block of a rule.
+///
+/// The trailing `;` keeps the comments at the end of the content inside the block.
+const CSS_PREFIX: &str = "&{";
+const CSS_SUFFIX: &str = ";}";
+
+/// Parses the content of the template literals that are tagged with one of the tags
+/// configured in `javascript.embeddedLanguages`.
+///
+/// The content that has syntax errors is skipped, so that it's printed as it is.
+pub(super) fn parse_embedded_nodes(
+    root: &AnyParse,
+    biome_path: &BiomePath,
+    _file_source: &DocumentFileSource,
+    settings: &Settings,
+    cache: &mut NodeCache,
+) -> ParseEmbedResult {
+    let mut nodes = Vec::new();
+    let embedded_languages = embedded_languages_settings(biome_path, settings);
+    if !embedded_languages.is_enabled() {
+        return ParseEmbedResult { nodes };
+    }
+
+    let tree: AnyJsRoot = root.tree();
+    for template in tree
+        .syntax()
+        .descendants()
+        .filter_map(JsTemplateExpression::cast)
+    {
+        let Some(tag) = template_tag_name(&template) else {
+            continue;
+        };
+
+        if embedded_languages.is_css_tag(&tag) {
+            let result = parse_embedded_css(&template, cache, biome_path, settings);
+            if let Some((snippet, services, file_source)) = result {
+                nodes.push(((snippet, services).into(), file_source));
+            }
+        } else if embedded_languages.is_graphql_tag(&tag)
+            && let Some((snippet, file_source)) = parse_embedded_graphql(&template, cache)
+        {
+            nodes.push((snippet.into(), file_source));
+        }
+    }
+
+    ParseEmbedResult { nodes }
+}
+
+/// Returns the template literal whose content is in `range`.
+pub(super) fn find_template(root: &AnyJsRoot, range: TextRange) -> Option<JsTemplateExpression> {
+    root.syntax()
+        .covering_element(range)
+        .ancestors()
+        .filter_map(JsTemplateExpression::cast)
+        .find(|template| template_content_range(template) == Some(range))
+}
+
+/// Formats the CSS snippet of `template` and restores its interpolations.
+///
+/// Returns `None` when the snippet can't be formatted without losing some of its code.
+pub(super) fn format_embedded_css(
+    template: &JsTemplateExpression,
+    node: &AnyParse,
+    options: CssFormatOptions,
+) -> Option<Document> {
+    let root = node.clone().embedded_syntax::<CssLanguage>();
+    let items = css_template_items(&root.node)?;
+    let items = SyntaxNodeWithOffset::new(items.into_syntax(), root.offset);
+    let formatted = biome_css_formatter::format_node_with_offset(options, &items).ok()?;
+
+    restore_document(
+        template,
+        TemplateLanguage::Css,
+        formatted.into_document(),
+        root.offset,
+    )
+}
+
+/// Formats the GraphQL snippet of `template` and restores its interpolations.
+///
+/// Returns `None` when the snippet can't be formatted without losing some of its code.
+pub(super) fn format_embedded_graphql(
+    template: &JsTemplateExpression,
+    node: &AnyParse,
+    options: GraphqlFormatOptions,
+) -> Option<Document> {
+    let root = node.clone().embedded_syntax::<GraphqlLanguage>();
+    let formatted = biome_graphql_formatter::format_node_with_offset(options, &root).ok()?;
+
+    restore_document(
+        template,
+        TemplateLanguage::Graphql,
+        formatted.into_document(),
+        root.offset,
+    )
+}
+
+/// Restores the interpolations of `template` in the formatted `document` of its snippet,
+/// which starts at `offset` in the JavaScript file.
+///
+/// Returns `None` if some placeholders aren't printed as they are.
+fn restore_document(
+    template: &JsTemplateExpression,
+    language: TemplateLanguage,
+    document: Document,
+    offset: TextSize,
+) -> Option<Document> {
+    let interpolations = template_interpolations(template, language)?;
+    let mut restored = vec![false; interpolations.len()];
+    let elements = restore_interpolations(
+        document.into_elements(),
+        &interpolations,
+        offset,
+        &mut restored,
+    );
+
+    restored
+        .iter()
+        .all(|restored| *restored)
+        .then(|| Document::new(elements))
+}
+
+fn embedded_languages_settings(
+    biome_path: &BiomePath,
+    settings: &Settings,
+) -> JsEmbeddedLanguagesSettings {
+    settings.override_settings.override_js_embedded_languages(
+        biome_path,
+        &settings.languages.javascript.parser.embedded_languages,
+    )
+}
+
+/// Returns the name of the leftmost identifier of the tag of `template`, so that `styled` is
+/// returned for ``styled.div`...` ``, ``styled(Button)`...` `` and ``styled.div.attrs({})`...` ``.
+///
+/// The callee of an untagged template literal that is the only argument of a call is used as its
+/// tag, so that `graphql` is returned for ``graphql(`...`)``.
+fn template_tag_name(template: &JsTemplateExpression) -> Option<TokenText> {
+    let mut expression = match template.tag() {
+        Some(tag) => tag,
+        None => template_callee(template)?,
+    };
+    loop {
+        expression = match expression {
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                let name = identifier.name().ok()?.value_token().ok()?;
+                return Some(name.token_text_trimmed());
+            }
+            AnyJsExpression::JsStaticMemberExpression(member) => member.object().ok()?,
+            AnyJsExpression::JsComputedMemberExpression(member) => member.object().ok()?,
+            AnyJsExpression::JsCallExpression(call) => call.callee().ok()?,
+            _ => return None,
+        };
+    }
+}
+
+/// Returns the callee of the call whose only argument is `template`.
+fn template_callee(template: &JsTemplateExpression) -> Option<AnyJsExpression> {
+    let arguments = template
+        .syntax()
+        .parent()
+        .and_then(JsCallArgumentList::cast)?;
+    if arguments.len() != 1 {
+        return None;
+    }
+
+    let call = arguments
+        .syntax()
+        .parent()
+        .and_then(JsCallArguments::cast)?
+        .syntax()
+        .parent()
+        .and_then(JsCallExpression::cast)?;
+    call.callee().ok()
+}
+
+/// Returns the range of the content of `template`, between its backticks.
+fn template_content_range(template: &JsTemplateExpression) -> Option<TextRange> {
+    let start = template.l_tick_token().ok()?.text_trimmed_range().end();
+    let end = template.r_tick_token().ok()?.text_trimmed_range().start();
+    Some(TextRange::new(start, end))
+}
+
+fn parse_embedded_css(
+    template: &JsTemplateExpression,
+    cache: &mut NodeCache,
+    biome_path: &BiomePath,
+    settings: &Settings,
+) -> Option<(
+    EmbeddedSnippet<CssLanguage>,
+    DocumentServices,
+    DocumentFileSource,
+)> {
+    let content_range = template_content_range(template)?;
+    // The prefix overlaps the tag and the opening backtick, so there's always room for it
+    let offset = content_range
+        .start()
+        .checked_sub(TextSize::of(CSS_PREFIX))?;
+    let content = template_content_with_placeholders(template, TemplateLanguage::Css)?;
+    if content.trim().is_empty() {
+        return None;
+    }
+
+    let file_source = DocumentFileSource::Css(CssFileSource::css());
+    let options = settings
+        .parse_options::<CssLanguage>(biome_path, &file_source)
+        .allow_wrong_line_comments();
+    let code = format!("{CSS_PREFIX}{content}{CSS_SUFFIX}");
+    let parse = parse_css_with_offset_and_cache(&code, offset, cache, options);
+    if parse.has_errors() || css_template_items(&parse.syntax().node).is_none() {
+        return None;
+    }
+
+    let mut services = CssDocumentServices::default();
+    if settings.is_linter_enabled() || settings.is_assist_enabled() {
+        services = services.with_css_semantic_model(&parse.tree());
+    }
+
+    let snippet = EmbeddedSnippet::new(parse.into(), content_range, content_range, offset);
+    Some((snippet, services.into(), file_source))
+}
+
+fn parse_embedded_graphql(
+    template: &JsTemplateExpression,
+    cache: &mut NodeCache,
+) -> Option<(EmbeddedSnippet<GraphqlLanguage>, DocumentFileSource)> {
+    let content_range = template_content_range(template)?;
+    let content = template_content_with_placeholders(template, TemplateLanguage::Graphql)?;
+    if content.trim().is_empty() {
+        return None;
+    }
+
+    let offset = content_range.start();
+    let parse = parse_graphql_with_offset_and_cache(&content, offset, cache);
+    if parse.has_errors() {
+        return None;
+    }
+
+    let snippet = EmbeddedSnippet::new(parse.into(), content_range, content_range, offset);
+    Some((
+        snippet,
+        DocumentFileSource::Graphql(GraphqlFileSource::graphql()),
+    ))
+}
+
+/// Returns the declarations and the rules of the content of a CSS template literal, when the
+/// comments around them belong to them.
+fn css_template_items(root: &CssSyntaxNode) -> Option<CssDeclarationOrRuleList> {
+    let root = CssRoot::cast_ref(root)?;
+    let rules = root.rules();
+    if rules.len() != 1 {
+        return None;
+    }
+    let AnyCssRule::CssQualifiedRule(rule) = rules.first()? else {
+        return None;
+    };
+    let block = rule.block().ok()?;
+    let block = block.as_css_declaration_or_rule_block()?;
+    if block.l_curly_token().ok()?.has_trailing_comments()
+        || block.r_curly_token().ok()?.has_leading_comments()
+    {
+        return None;
+    }
+
+    Some(block.items())
+}
+
+/// The language of the snippet of a template literal, which decides the syntax of the
+/// placeholders of its interpolations.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TemplateLanguage {
+    Css,
+    Graphql,
+}
+
+/// An interpolation `${...}` of a template literal.
+struct Interpolation {
+    /// The range of the interpolation in the JavaScript file
+    range: TextRange,
+    /// The code of the interpolation
+    text: String,
+    /// The placeholder that replaces the interpolation in the embedded code
+    placeholder: String,
+}
+
+/// Returns the content of `template`, with its interpolations replaced by their placeholders.
+fn template_content_with_placeholders(
+    template: &JsTemplateExpression,
+    language: TemplateLanguage,
+) -> Option<String> {
+    let interpolations = template_interpolations(template, language)?;
+    let mut content = String::new();
+    let mut interpolations = interpolations.iter();
+
+    for element in template.elements() {
+        match element {
+            AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                content.push_str(chunk.template_chunk_token().ok()?.text_trimmed());
+            }
+            AnyJsTemplateElement::JsTemplateElement(_) => {
+                content.push_str(&interpolations.next()?.placeholder);
+            }
+        }
+    }
+
+    Some(content)
+}
+
+/// Returns the interpolations of `template` with their placeholders, or `None` when a
+/// placeholder doesn't fit in its interpolation.
+fn template_interpolations(
+    template: &JsTemplateExpression,
+    language: TemplateLanguage,
+) -> Option<Vec<Interpolation>> {
+    let elements: Vec<_> = template.elements().iter().collect();
+    let mut interpolations = Vec::new();
+    // The content that precedes the current element, where the interpolations are
+    // replaced by their placeholders
+    let mut content = String::new();
+
+    for (index, element) in elements.iter().enumerate() {
+        match element {
+            AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                content.push_str(chunk.template_chunk_token().ok()?.text_trimmed());
+            }
+            AnyJsTemplateElement::JsTemplateElement(element) => {
+                let following = match elements.get(index + 1) {
+                    Some(AnyJsTemplateElement::JsTemplateChunkElement(chunk)) => chunk
+                        .template_chunk_token()
+                        .ok()?
+                        .text_trimmed()
+                        .to_string(),
+                    _ => String::new(),
+                };
+                let range = element.syntax().text_trimmed_range();
+                let kind = match language {
+                    TemplateLanguage::Css => {
+                        PlaceholderKind::from_css_context(&content, &following)
+                    }
+                    TemplateLanguage::Graphql => {
+                        PlaceholderKind::from_graphql_context(&content, &following)
+                    }
+                };
+                let placeholder =
+                    kind.placeholder(language, interpolations.len(), range.len().into())?;
+                content.push_str(&placeholder);
+                interpolations.push(Interpolation {
+                    range,
+                    text: element.syntax().text_trimmed().to_string(),
+                    placeholder,
+                });
+            }
+        }
+    }
+
+    Some(interpolations)
+}
+
+/// The syntax that replaces an interpolation, so that the code is still valid.
+#[derive(Debug, Eq, PartialEq)]
+enum PlaceholderKind {
+    /// A comment, for the interpolations that take the place of whole CSS declarations,
+    /// `${mixin};`, or of whole GraphQL definitions, such as the fragments that follow a query.
+    Comment,
+    /// A custom property, for the interpolations that take the place of a CSS property name:
+    /// `${property}: red;`
+    Property,
+    /// An identifier, for the values and the selectors: `color: ${color};`, `${Button}:hover {}`.
+    ///
+    /// In CSS, it contains a hyphen, so that it's a valid custom element as a type selector.
+    Identifier,
+}
+
+impl PlaceholderKind {
+    /// Infers the kind of the CSS placeholder from the code that precedes and follows the
+    /// interpolation.
+    fn from_css_context(preceding: &str, following: &str) -> Self {
+        let statement = preceding
+            .rfind(['{', '}', ';'])
+            .map_or(preceding, |index| &preceding[index + 1..])
+            .trim_start();
+        if !statement.is_empty() {
+            return Self::Identifier;
+        }
+
+        let is_selector = following
+            .find(['{', '}', ';'])
+            .is_some_and(|index| following.as_bytes()[index] == b'{');
+        let next = following.trim_start();
+        if is_selector {
+            Self::Identifier
+        } else if next.starts_with(':') {
+            Self::Property
+        } else if next.is_empty()
+            || next.starts_with([';', '}'])
+            || following[..following.len() - next.len()].contains('\n')
+        {
+            Self::Comment
+        } else {
+            Self::Identifier
+        }
+    }
+
+    /// Infers the kind of the GraphQL placeholder from the code that precedes and follows the
+    /// interpolation.
+    ///
+    /// The interpolations outside of the definitions are comments, which end with their line.
+    fn from_graphql_context(preceding: &str, following: &str) -> Self {
+        let depth = preceding.chars().fold(0isize, |depth, char| match char {
+            '{' | '(' | '[' => depth + 1,
+            '}' | ')' | ']' => depth - 1,
+            _ => depth,
+        });
+        let rest_of_line = following.lines().next().unwrap_or_default();
+        if depth <= 0 && rest_of_line.trim().is_empty() {
+            Self::Comment
+        } else {
+            Self::Identifier
+        }
+    }
+
+    /// Returns the placeholder of the interpolation at `index`, with a length of `len`.
+    fn placeholder(&self, language: TemplateLanguage, index: usize, len: usize) -> Option<String> {
+        let index = to_base36(index);
+        let (prefix, suffix) = match (language, self) {
+            (TemplateLanguage::Css, Self::Comment) => ("/*", "*/"),
+            (TemplateLanguage::Css, Self::Property) => ("--", ""),
+            (TemplateLanguage::Css, Self::Identifier) => ("_-", ""),
+            (TemplateLanguage::Graphql, Self::Comment) => ("#", ""),
+            (TemplateLanguage::Graphql, Self::Property | Self::Identifier) => ("_", ""),
+        };
+        let padding = len.checked_sub(prefix.len() + index.len() + suffix.len())?;
+        Some(format!("{prefix}{index}{}{suffix}", "_".repeat(padding)))
+    }
+}
+
+fn to_base36(mut value: usize) -> String {
+    let mut digits = Vec::new();
+    loop {
+        let digit = (value % 36) as u32;
+        digits.push(char::from_digit(digit, 36).unwrap_or('0'));
+        value /= 36;
+        if value == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+/// Replaces the placeholders in the text of `elements` with the code of their interpolations.
+///
+/// `snippet_start` is the position of the JavaScript file where the code of the snippet starts.
+fn restore_interpolations(
+    elements: Vec<FormatElement>,
+    interpolations: &[Interpolation],
+    snippet_start: TextSize,
+    restored: &mut [bool],
+) -> Vec<FormatElement> {
+    elements
+        .into_iter()
+        .map(|element| match element {
+            FormatElement::Text {
+                text,
+                source_position,
+            } => restore_text(
+                &text,
+                source_position,
+                interpolations,
+                snippet_start,
+                restored,
+            )
+            .map_or(
+                FormatElement::Text {
+                    text,
+                    source_position,
+                },
+                |text| FormatElement::Text {
+                    text: text.into(),
+                    source_position,
+                },
+            ),
+            FormatElement::LocatedTokenText {
+                source_position,
+                slice,
+            } => restore_text(
+                &slice,
+                source_position,
+                interpolations,
+                snippet_start,
+                restored,
+            )
+            .map_or(
+                FormatElement::LocatedTokenText {
+                    source_position,
+                    slice,
+                },
+                |text| FormatElement::Text {
+                    text: text.into(),
+                    source_position,
+                },
+            ),
+            FormatElement::Interned(interned) => {
+                let elements = restore_interpolations(
+                    interned.deref().to_vec(),
+                    interpolations,
+                    snippet_start,
+                    restored,
+                );
+                FormatElement::Interned(Interned::new(elements))
+            }
+            FormatElement::BestFitting(mut best_fitting) => {
+                // The variants can't change their length, only their interned elements are visited
+                for element in best_fitting.as_slice_mut() {
+                    if let FormatElement::Interned(interned) = element {
+                        let elements = restore_interpolations(
+                            interned.deref().to_vec(),
+                            interpolations,
+                            snippet_start,
+                            restored,
+                        );
+                        *element = FormatElement::Interned(Interned::new(elements));
+                    }
+                }
+                FormatElement::BestFitting(best_fitting)
+            }
+            element => element,
+        })
+        .collect()
+}
+
+/// Returns `text` with the placeholders it contains replaced by the code of their
+/// interpolations, or `None` if it doesn't contain any placeholder.
+///
+/// A placeholder is only replaced when it's printed as it is, at its position in the source code.
+fn restore_text(
+    text: &str,
+    source_position: TextSize,
+    interpolations: &[Interpolation],
+    snippet_start: TextSize,
+    restored: &mut [bool],
+) -> Option<String> {
+    let text_range = TextRange::at(snippet_start + source_position, TextSize::of(text));
+    let mut result = None;
+    let mut last_end = 0;
+
+    for (index, interpolation) in interpolations.iter().enumerate() {
+        if !text_range.contains_range(interpolation.range) {
+            continue;
+        }
+
+        let start = usize::from(interpolation.range.start() - text_range.start());
+        let end = start + interpolation.placeholder.len();
+        if start < last_end || text.get(start..end) != Some(interpolation.placeholder.as_str()) {
+            continue;
+        }
+
+        let result = result.get_or_insert_with(String::new);
+        result.push_str(&text[last_end..start]);
+        result.push_str(&interpolation.text);
+        last_end = end;
+        restored[index] = true;
+    }
+
+    let mut result = result?;
+    result.push_str(&text[last_end..]);
+    Some(result)
+}
@@ -1,6 +1,7 @@
 use super::{
     AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource, EnabledForPath,
-    ExtensionHandler, ParseResult, ProcessFixAll, ProcessLint, SearchCapabilities,
+    ExtensionHandler, NavigationCapabilities, ParseResult, ProcessFixAll, ProcessLint,
+    SearchCapabilities, collect_document_symbols, folding_ranges_of_brackets, search,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
@@ -12,7 +13,10 @@ use crate::settings::{
     FormatSettings, LanguageListSettings, LanguageSettings, OverrideSettings, ServiceLanguage,
     Settings, check_feature_activity, check_override_feature_activity,
 };
-use crate::workspace::{CodeAction, FixFileResult, GetSyntaxTreeResult, PullActionsResult};
+use crate::workspace::{
+    CodeAction, CompletionItem, DocumentServices, DocumentSymbol, FixFileResult, FoldingRange,
+    GetCompletionsResult, GetSyntaxTreeResult, PullActionsResult, SymbolKind,
+};
 use crate::{WorkspaceError, extension_error};
 use biome_analyze::options::PreferredQuote;
 use biome_analyze::{AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never};
@@ -28,10 +32,12 @@ use biome_formatter::{
 };
 use biome_fs::{BiomePath, ConfigName};
 use biome_json_analyze::{ExtendedConfigurationProvider, JsonAnalyzeServices, analyze};
-use biome_json_formatter::context::{JsonFormatOptions, TrailingCommas};
+use biome_json_formatter::context::{JsonFormatOptions, QuoteProperties, TrailingCommas};
 use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
-use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
+use biome_json_syntax::{
+    AnyJsonValue, JsonFileSource, JsonLanguage, JsonMember, JsonRoot, JsonSyntaxNode,
+};
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
@@ -50,6 +56,7 @@ pub struct JsonFormatterSettings {
     pub trailing_commas: Option<TrailingCommas>,
     pub expand: Option<Expand>,
     pub bracket_spacing: Option<BracketSpacing>,
+    pub quote_properties: Option<QuoteProperties>,
     pub enabled: Option<JsonFormatterEnabled>,
 }
 
@@ -63,6 +70,7 @@ impl From<JsonFormatterConfiguration> for JsonFormatterSettings {
             trailing_commas: configuration.trailing_commas,
             expand: configuration.expand,
             bracket_spacing: configuration.bracket_spacing,
+            quote_properties: configuration.quote_properties,
             enabled: configuration.enabled,
         }
     }
@@ -88,12 +96,15 @@ impl From<JsonParserConfiguration> for JsonParserSettings {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JsonLinterSettings {
     pub enabled: Option<JsonLinterEnabled>,
+    /// Path to the JSON Schema of the files, relative to the configuration file
+    pub schema: Option<String>,
 }
 
 impl From<JsonLinterConfiguration> for JsonLinterSettings {
     fn from(configuration: JsonLinterConfiguration) -> Self {
         Self {
             enabled: configuration.enabled,
+            schema: configuration.schema,
         }
     }
 }
@@ -150,6 +161,8 @@ impl ServiceLanguage for JsonLanguage {
                     || optional_json_file_source.is_some_and(|x| x.allow_trailing_commas()),
                     |value| value.value(),
                 ),
+                json5: optional_json_file_source.is_some_and(|x| x.is_json5()),
+                grit_metavariables: false,
             };
 
             overrides.apply_override_json_parser_options(path, &mut options);
@@ -214,7 +227,8 @@ impl ServiceLanguage for JsonLanguage {
             .with_line_width(line_width)
             .with_trailing_commas(trailing_commas)
             .with_expand(expand_lists)
-            .with_bracket_spacing(bracket_spacing);
+            .with_bracket_spacing(bracket_spacing)
+            .with_quote_properties(language.quote_properties.unwrap_or_default());
 
         overrides.apply_override_json_format_options(path, &mut options);
 
@@ -348,6 +362,8 @@ impl ExtensionHandler for JsonFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                prepare_rename: None,
+                rename_imports: None,
                 fix_all: Some(fix_all),
                 update_snippets: None,
                 pull_diagnostics_and_actions: None,
@@ -358,7 +374,20 @@ impl ExtensionHandler for JsonFileHandler {
                 format_on_type: Some(format_on_type),
                 format_embedded: None,
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
+            navigation: NavigationCapabilities {
+                hover: None,
+                definition: None,
+                references: None,
+                namespace_member_references: None,
+                completions: Some(completions),
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(folding_ranges),
+                semantic_tokens: None,
+                inlay_hints: None,
+            },
         }
     }
 }
@@ -505,11 +534,14 @@ fn lint(params: LintParams) -> LintResults {
     };
     let root: JsonRoot = params.parse.tree();
 
-    let analyzer_options = params.settings.analyzer_options::<JsonLanguage>(
-        params.path,
-        &params.language,
-        params.suppression_reason.as_deref(),
-    );
+    let analyzer_options = params
+        .settings
+        .analyzer_options::<JsonLanguage>(
+            params.path,
+            &params.language,
+            params.suppression_reason.as_deref(),
+        )
+        .with_suppressed_signals(params.pull_suppressed);
 
     let (enabled_rules, disabled_rules, analyzer_options) =
         AnalyzerVisitorBuilder::new(params.settings, analyzer_options)
@@ -534,10 +566,16 @@ fn lint(params: LintParams) -> LintResults {
             .settings
             .full_source()
             .map(|s| s as std::sync::Arc<dyn ExtendedConfigurationProvider>),
+        json_schema: params.document_services.json_schema(),
     };
-    let (_, analyze_diagnostics) = analyze(&root, filter, &analyzer_options, services, |signal| {
-        process_lint.process_signal(signal)
-    });
+    let (_, analyze_diagnostics) = analyze(
+        &root,
+        filter,
+        &analyzer_options,
+        services,
+        &params.plugins,
+        |signal| process_lint.process_signal(signal),
+    );
 
     let mut diagnostics = params
         .parse
@@ -573,10 +611,10 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         only,
         enabled_rules: rules,
         suppression_reason,
-        plugins: _,
+        plugins,
         categories,
         action_offset,
-        document_services: _,
+        document_services,
     } = params;
 
     let _ = debug_span!("Code actions JSON",  range =? range, path =? path).entered();
@@ -612,21 +650,29 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         configuration_provider: workspace
             .full_source()
             .map(|s| s as std::sync::Arc<dyn ExtendedConfigurationProvider>),
+        json_schema: document_services.json_schema(),
     };
-    analyze(&tree, filter, &analyzer_options, services, |signal| {
-        actions.extend(signal.actions().into_code_action_iter().map(|item| {
-            CodeAction {
-                category: item.category.clone(),
-                rule_name: item
-                    .rule_name
-                    .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                suggestion: item.suggestion,
-                offset: action_offset,
-            }
-        }));
-
-        ControlFlow::<Never>::Continue(())
-    });
+    analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        services,
+        &plugins,
+        |signal| {
+            actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                CodeAction {
+                    category: item.category.clone(),
+                    rule_name: item
+                        .rule_name
+                        .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                    suggestion: item.suggestion,
+                    offset: action_offset,
+                }
+            }));
+
+            ControlFlow::<Never>::Continue(())
+        },
+    );
 
     PullActionsResult { actions }
 }
@@ -678,10 +724,16 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                 .settings
                 .full_source()
                 .map(|s| s as std::sync::Arc<dyn ExtendedConfigurationProvider>),
+            json_schema: params.document_services.json_schema(),
         };
-        let (action, _) = analyze(&tree, filter, &analyzer_options, services, |signal| {
-            process_fix_all.process_signal(signal)
-        });
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            services,
+            &params.plugins,
+            |signal| process_fix_all.process_signal(signal),
+        );
 
         let result = process_fix_all.process_action(action, |root| {
             tree = match JsonRoot::cast(root) {
@@ -708,3 +760,58 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
         }
     }
 }
+
+fn completions(
+    parse: AnyParse,
+    offset: TextSize,
+    document_services: &DocumentServices,
+) -> Result<GetCompletionsResult, WorkspaceError> {
+    let Some(schema) = document_services.json_schema() else {
+        return Ok(GetCompletionsResult::default());
+    };
+    let Some(instance) = biome_json_schema::json::to_instance(&parse.tree()) else {
+        return Ok(GetCompletionsResult::default());
+    };
+
+    Ok(GetCompletionsResult {
+        items: schema
+            .completions(&instance, offset)
+            .into_iter()
+            .map(CompletionItem::from)
+            .collect(),
+    })
+}
+
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: JsonRoot = parse.tree();
+    collect_document_symbols(root.syntax(), document_symbol)
+}
+
+/// Returns the symbol of an object member, whose kind is the type of its
+/// value.
+fn document_symbol(node: &JsonSyntaxNode) -> Option<DocumentSymbol> {
+    let member = JsonMember::cast_ref(node)?;
+    let name = member.name().ok()?;
+    let kind = match member.value().ok()? {
+        AnyJsonValue::JsonArrayValue(_) => SymbolKind::Array,
+        AnyJsonValue::JsonBooleanValue(_) => SymbolKind::Boolean,
+        AnyJsonValue::JsonNullValue(_) => SymbolKind::Null,
+        AnyJsonValue::JsonNumberValue(_) => SymbolKind::Number,
+        AnyJsonValue::JsonObjectValue(_) => SymbolKind::Object,
+        AnyJsonValue::JsonStringValue(_) => SymbolKind::String,
+        AnyJsonValue::JsonBogusValue(_) | AnyJsonValue::JsonMetavariable(_) => return None,
+    };
+
+    Some(DocumentSymbol {
+        name: name.inner_string_text().ok()?.to_string(),
+        kind,
+        range: node.text_trimmed_range(),
+        selection_range: name.syntax().text_trimmed_range(),
+        children: Vec::new(),
+    })
+}
+
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let root: JsonRoot = parse.tree();
+    folding_ranges_of_brackets(root.syntax())
+}
@@ -0,0 +1,529 @@
+use super::{
+    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, EnabledForPath,
+    ExtensionHandler, FormatEmbedNode, FormatterCapabilities, NavigationCapabilities,
+    ParseEmbedResult, ParseResult, ParserCapabilities, SearchCapabilities,
+};
+use crate::WorkspaceError;
+use crate::settings::{
+    FormatSettings, LanguageListSettings, LanguageSettings, OverrideSettings, ServiceLanguage,
+    Settings, check_feature_activity, check_override_feature_activity,
+};
+use crate::workspace::{
+    AnyEmbeddedSnippet, DocumentServices, EmbeddedSnippet, GetSyntaxTreeResult,
+};
+use biome_analyze::AnalyzerOptions;
+use biome_configuration::markdown::{MarkdownFormatterConfiguration, MarkdownFormatterEnabled};
+use biome_css_parser::parse_css_with_offset_and_cache;
+use biome_css_syntax::{CssFileSource, CssLanguage};
+use biome_formatter::format_element::{Interned, LineMode};
+use biome_formatter::prelude::Document;
+use biome_formatter::{FormatElement, FormatError, IndentWidth, LineEnding, LineWidth, Printed};
+use biome_fs::BiomePath;
+use biome_graphql_parser::parse_graphql_with_offset_and_cache;
+use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
+use biome_js_parser::parse_js_with_offset_and_cache;
+use biome_js_syntax::{JsFileSource, JsLanguage};
+use biome_json_parser::parse_json_with_offset_and_cache;
+use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use biome_markdown_formatter::context::{
+    BulletListMarker, EmphasisStyle, MarkdownFormatOptions, ProseWrap,
+};
+use biome_markdown_formatter::{format_node, format_sub_tree};
+use biome_markdown_parser::parse_markdown_with_cache;
+use biome_markdown_syntax::{MarkdownLanguage, MarkdownSyntaxNode, MdDocument, MdFencedCodeBlock};
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, NodeCache, TextRange, TextSize, TokenAtOffset};
+use biome_string_case::StrLikeExtension;
+use camino::Utf8Path;
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MarkdownFormatterSettings {
+    pub line_ending: Option<LineEnding>,
+    pub line_width: Option<LineWidth>,
+    pub indent_width: Option<IndentWidth>,
+    pub bullet_list_marker: Option<BulletListMarker>,
+    pub emphasis_style: Option<EmphasisStyle>,
+    pub prose_wrap: Option<ProseWrap>,
+    pub enabled: Option<MarkdownFormatterEnabled>,
+}
+
+impl From<MarkdownFormatterConfiguration> for MarkdownFormatterSettings {
+    fn from(configuration: MarkdownFormatterConfiguration) -> Self {
+        Self {
+            line_ending: configuration.line_ending,
+            line_width: configuration.line_width,
+            indent_width: configuration.indent_width,
+            bullet_list_marker: configuration.bullet_list_marker,
+            emphasis_style: configuration.emphasis_style,
+            prose_wrap: configuration.prose_wrap,
+            enabled: configuration.enabled,
+        }
+    }
+}
+
+impl ServiceLanguage for MarkdownLanguage {
+    type FormatterSettings = MarkdownFormatterSettings;
+    type LinterSettings = ();
+    type AssistSettings = ();
+    type FormatOptions = MarkdownFormatOptions;
+    type ParserSettings = ();
+    type ParserOptions = ();
+    type EnvironmentSettings = ();
+
+    fn lookup_settings(language: &LanguageListSettings) -> &LanguageSettings<Self> {
+        &language.markdown
+    }
+
+    fn resolve_environment(_settings: &Settings) -> Option<&Self::EnvironmentSettings> {
+        None
+    }
+
+    fn resolve_parse_options(
+        _overrides: &OverrideSettings,
+        _language: &Self::ParserSettings,
+        _path: &BiomePath,
+        _file_source: &DocumentFileSource,
+    ) -> Self::ParserOptions {
+    }
+
+    fn resolve_format_options(
+        global: &FormatSettings,
+        overrides: &OverrideSettings,
+        language: &Self::FormatterSettings,
+        path: &BiomePath,
+        document_file_source: &DocumentFileSource,
+    ) -> Self::FormatOptions {
+        let line_width = language
+            .line_width
+            .or(global.line_width)
+            .unwrap_or_default();
+        let indent_width = language
+            .indent_width
+            .or(global.indent_width)
+            .unwrap_or_default();
+
+        let line_ending = language
+            .line_ending
+            .or(global.line_ending)
+            .unwrap_or_default();
+
+        // The indentation of Markdown is meaningful, so the indent style isn't read
+        let mut options = MarkdownFormatOptions::new(
+            document_file_source
+                .to_markdown_file_source()
+                .unwrap_or_default(),
+        )
+        .with_indent_width(indent_width)
+        .with_line_width(line_width)
+        .with_line_ending(line_ending)
+        .with_bullet_list_marker(language.bullet_list_marker.unwrap_or_default())
+        .with_emphasis_style(language.emphasis_style.unwrap_or_default())
+        .with_prose_wrap(language.prose_wrap.unwrap_or_default());
+
+        overrides.apply_override_markdown_format_options(path, &mut options);
+
+        options
+    }
+
+    fn resolve_analyzer_options(
+        _global: &Settings,
+        _language: &Self::LinterSettings,
+        _environment: Option<&Self::EnvironmentSettings>,
+        path: &BiomePath,
+        _file_source: &DocumentFileSource,
+        suppression_reason: Option<&str>,
+    ) -> AnalyzerOptions {
+        AnalyzerOptions::default()
+            .with_file_path(path.as_path())
+            .with_suppression_reason(suppression_reason)
+    }
+
+    fn linter_enabled_for_file_path(_settings: &Settings, _path: &Utf8Path) -> bool {
+        false
+    }
+
+    fn formatter_enabled_for_file_path(settings: &Settings, path: &Utf8Path) -> bool {
+        let overrides_activity =
+            settings
+                .override_settings
+                .patterns
+                .iter()
+                .rev()
+                .find_map(|pattern| {
+                    check_override_feature_activity(
+                        pattern.languages.markdown.formatter.enabled,
+                        pattern.formatter.enabled,
+                    )
+                    .filter(|_| {
+                        // Then check whether the path satisfies
+                        pattern.is_file_included(path)
+                    })
+                });
+
+        overrides_activity
+            .or(check_feature_activity(
+                settings.languages.markdown.formatter.enabled,
+                settings.formatter.enabled,
+            ))
+            .unwrap_or_default()
+            .into()
+    }
+
+    fn assist_enabled_for_file_path(_settings: &Settings, _path: &Utf8Path) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct MarkdownFileHandler;
+
+impl ExtensionHandler for MarkdownFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            enabled_for_path: EnabledForPath {
+                formatter: Some(formatter_enabled),
+                linter: None,
+                assist: None,
+                search: Some(search_enabled),
+            },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                parse_embedded_nodes: Some(parse_embedded_nodes),
+            },
+            debug: DebugCapabilities {
+                debug_syntax_tree: Some(debug_syntax_tree),
+                debug_control_flow: None,
+                debug_formatter_ir: Some(debug_formatter_ir),
+                debug_type_info: None,
+                debug_registered_types: None,
+                debug_semantic_model: None,
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: None,
+                code_actions: None,
+                rename: None,
+                prepare_rename: None,
+                rename_imports: None,
+                fix_all: None,
+                update_snippets: None,
+                pull_diagnostics_and_actions: None,
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: Some(format_range),
+                format_on_type: Some(format_on_type),
+                format_embedded: Some(format_embedded),
+            },
+            search: SearchCapabilities { search: None },
+            navigation: NavigationCapabilities {
+                hover: None,
+                definition: None,
+                references: None,
+                namespace_member_references: None,
+                completions: None,
+                document_symbols: None,
+                folding_ranges: None,
+                semantic_tokens: None,
+                inlay_hints: None,
+            },
+        }
+    }
+}
+
+fn formatter_enabled(path: &Utf8Path, settings: &Settings) -> bool {
+    settings.formatter_enabled_for_file_path::<MarkdownLanguage>(path)
+}
+
+fn search_enabled(_path: &Utf8Path, _settings: &Settings) -> bool {
+    false
+}
+
+fn parse(
+    _biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    text: &str,
+    _settings: &Settings,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let parse = parse_markdown_with_cache(text, cache);
+
+    ParseResult {
+        any_parse: parse.into(),
+        language: Some(file_source),
+    }
+}
+
+/// Parses the code of the fenced code blocks whose language has a formatter.
+///
+/// The code blocks inside block quotes aren't parsed, because the `>` markers are part of their lines.
+fn parse_embedded_nodes(
+    root: &AnyParse,
+    biome_path: &BiomePath,
+    _file_source: &DocumentFileSource,
+    settings: &Settings,
+    cache: &mut NodeCache,
+) -> ParseEmbedResult {
+    let mut nodes = Vec::new();
+    let document: MdDocument = root.tree();
+    let syntax = document.syntax();
+
+    for code_block in syntax.descendants().filter_map(MdFencedCodeBlock::cast) {
+        if code_block.is_in_quote() {
+            continue;
+        }
+
+        let result = parse_code_block(&code_block, syntax, cache, biome_path, settings);
+        if let Some((content, file_source)) = result {
+            nodes.push((content, file_source));
+        }
+    }
+
+    ParseEmbedResult { nodes }
+}
+
+/// Returns the file source of the code of a fenced code block tagged with `language`.
+fn code_block_file_source(language: &str) -> Option<DocumentFileSource> {
+    let file_source = match language.to_ascii_lowercase_cow().as_ref() {
+        "js" | "javascript" | "mjs" | "cjs" => JsFileSource::js_module().into(),
+        "jsx" => JsFileSource::jsx().into(),
+        "ts" | "typescript" | "mts" | "cts" => JsFileSource::ts().into(),
+        "tsx" => JsFileSource::tsx().into(),
+        "json" => JsonFileSource::json().into(),
+        "jsonc" => JsonFileSource::json_allow_comments("jsonc").into(),
+        "css" => CssFileSource::css().into(),
+        "graphql" | "gql" => GraphqlFileSource::default().into(),
+        _ => return None,
+    };
+
+    Some(file_source)
+}
+
+/// Parses the code of `code_block` with the parser of its language.
+///
+/// The code that has syntax errors is skipped, so that it's printed as it is.
+fn parse_code_block(
+    code_block: &MdFencedCodeBlock,
+    root: &MarkdownSyntaxNode,
+    cache: &mut NodeCache,
+    biome_path: &BiomePath,
+    settings: &Settings,
+) -> Option<(AnyEmbeddedSnippet, DocumentFileSource)> {
+    let file_source = code_block_file_source(&code_block.language()?)?;
+    let content_range = code_block.code_range()?;
+    let code = root.text_with_trivia().slice(content_range).to_string();
+    let offset = content_range.start();
+    let element_range = code_block.range();
+
+    let snippet = match file_source {
+        DocumentFileSource::Js(js_file_source) => {
+            let options = settings.parse_options::<JsLanguage>(biome_path, &file_source);
+            let parse =
+                parse_js_with_offset_and_cache(&code, offset, js_file_source, options, cache);
+            if parse.has_errors() {
+                return None;
+            }
+            EmbeddedSnippet::<JsLanguage>::new(parse.into(), element_range, content_range, offset)
+                .into()
+        }
+        DocumentFileSource::Json(_) => {
+            let options = settings.parse_options::<JsonLanguage>(biome_path, &file_source);
+            let parse = parse_json_with_offset_and_cache(&code, offset, cache, options);
+            if parse.has_errors() {
+                return None;
+            }
+            EmbeddedSnippet::<JsonLanguage>::new(parse.into(), element_range, content_range, offset)
+                .into()
+        }
+        DocumentFileSource::Css(_) => {
+            let options = settings.parse_options::<CssLanguage>(biome_path, &file_source);
+            let parse = parse_css_with_offset_and_cache(&code, offset, cache, options);
+            if parse.has_errors() {
+                return None;
+            }
+            let snippet = EmbeddedSnippet::<CssLanguage>::new(
+                parse.into(),
+                element_range,
+                content_range,
+                offset,
+            );
+            (snippet, DocumentServices::none()).into()
+        }
+        DocumentFileSource::Graphql(_) => {
+            let parse = parse_graphql_with_offset_and_cache(&code, offset, cache);
+            if parse.has_errors() {
+                return None;
+            }
+            EmbeddedSnippet::<GraphqlLanguage>::new(
+                parse.into(),
+                element_range,
+                content_range,
+                offset,
+            )
+            .into()
+        }
+        _ => return None,
+    };
+
+    Some((snippet, file_source))
+}
+
+fn debug_syntax_tree(_biome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
+    let syntax: MarkdownSyntaxNode = parse.syntax();
+    let tree: MdDocument = parse.tree();
+    GetSyntaxTreeResult {
+        cst: format!("{syntax:#?}"),
+        ast: format!("{tree:#?}"),
+    }
+}
+
+fn debug_formatter_ir(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: &Settings,
+) -> Result<String, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+
+    let tree = parse.syntax();
+    let formatted = format_node(options, &tree, false)?;
+
+    let root_element = formatted.into_document();
+    Ok(root_element.to_string())
+}
+
+#[tracing::instrument(level = "debug", skip(parse, settings))]
+fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: &Settings,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+
+    let tree = parse.syntax();
+    let formatted = format_node(options, &tree, false)?;
+
+    match formatted.print() {
+        Ok(printed) => Ok(printed),
+        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    }
+}
+
+/// Formats the Markdown document and the code of its fenced code blocks, each with the
+/// formatter of its language.
+fn format_embedded(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: &Settings,
+    embedded_nodes: Vec<FormatEmbedNode>,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+
+    let tree = parse.syntax();
+    let mut formatted = format_node(options, &tree, true)?;
+    formatted.format_embedded(move |range| {
+        let mut iter = embedded_nodes.iter();
+        let node = iter.find(|node| node.range == range)?;
+
+        // The code starts on the line that follows the opening fence
+        let wrap_document = |document: Document| {
+            Document::new(vec![
+                FormatElement::Line(LineMode::Hard),
+                FormatElement::Interned(Interned::new(document.into_elements())),
+            ])
+        };
+
+        match node.source {
+            DocumentFileSource::Js(_) => {
+                let js_options = settings.format_options::<JsLanguage>(biome_path, &node.source);
+                let node = node.node.clone().embedded_syntax::<JsLanguage>().clone();
+                let formatted =
+                    biome_js_formatter::format_node_with_offset(js_options, &node).ok()?;
+                Some(wrap_document(formatted.into_document()))
+            }
+            DocumentFileSource::Json(_) => {
+                let json_options =
+                    settings.format_options::<JsonLanguage>(biome_path, &node.source);
+                let node = node.node.clone().embedded_syntax::<JsonLanguage>().clone();
+                let formatted =
+                    biome_json_formatter::format_node_with_offset(json_options, &node).ok()?;
+                Some(wrap_document(formatted.into_document()))
+            }
+            DocumentFileSource::Css(_) => {
+                let css_options = settings.format_options::<CssLanguage>(biome_path, &node.source);
+                let node = node.node.clone().embedded_syntax::<CssLanguage>();
+                let formatted =
+                    biome_css_formatter::format_node_with_offset(css_options, &node).ok()?;
+                Some(wrap_document(formatted.into_document()))
+            }
+            DocumentFileSource::Graphql(_) => {
+                let graphql_options =
+                    settings.format_options::<GraphqlLanguage>(biome_path, &node.source);
+                let node = node.node.clone().embedded_syntax::<GraphqlLanguage>();
+                let formatted =
+                    biome_graphql_formatter::format_node_with_offset(graphql_options, &node)
+                        .ok()?;
+                Some(wrap_document(formatted.into_document()))
+            }
+            _ => None,
+        }
+    });
+
+    match formatted.print() {
+        Ok(printed) => Ok(printed),
+        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    }
+}
+
+fn format_range(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: &Settings,
+    range: TextRange,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+
+    let tree = parse.syntax();
+    let printed = biome_markdown_formatter::format_range(options, &tree, range)?;
+    Ok(printed)
+}
+
+fn format_on_type(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: &Settings,
+    offset: TextSize,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+
+    let tree = parse.syntax();
+
+    let range = tree.text_range_with_trivia();
+    if offset < range.start() || offset > range.end() {
+        return Err(WorkspaceError::FormatError(FormatError::RangeError {
+            input: TextRange::at(offset, TextSize::from(0)),
+            tree: range,
+        }));
+    }
+
+    let token = match tree.token_at_offset(offset) {
+        // File is empty, do nothing
+        TokenAtOffset::None => panic!("empty file"),
+        TokenAtOffset::Single(token) => token,
+        // The cursor should be right after the closing character that was just typed,
+        // select the previous token as the correct one
+        TokenAtOffset::Between(token, _) => token,
+    };
+
+    let root_node = match token.parent() {
+        Some(node) => node,
+        None => panic!("found a token with no parent"),
+    };
+
+    let printed = format_sub_tree(options, &root_node)?;
+    Ok(printed)
+}
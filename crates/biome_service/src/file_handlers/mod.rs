@@ -12,8 +12,10 @@ pub use crate::file_handlers::vue::VueFileHandler;
 use crate::settings::Settings;
 use crate::utils::growth_guard::GrowthGuard;
 use crate::workspace::{
-    AnyEmbeddedSnippet, CodeAction, DocumentServices, FixAction, FixFileMode, FixFileResult,
-    GetSyntaxTreeResult, PullActionsResult, PullDiagnosticsAndActionsResult, RenameResult,
+    AnyEmbeddedSnippet, CodeAction, DocumentServices, DocumentSymbol, FixAction, FixFileMode,
+    FixFileResult, FoldingRange, FoldingRangeKind, GetCompletionsResult, GetDefinitionResult,
+    GetHoverResult, GetReferencesResult, GetSyntaxTreeResult, InlayHint, PrepareRenameResult,
+    PullActionsResult, PullDiagnosticsAndActionsResult, RenameResult, SearchResults, SemanticToken,
 };
 use biome_analyze::{
     AnalyzerAction, AnalyzerDiagnostic, AnalyzerOptions, AnalyzerPluginVec, AnalyzerSignal,
@@ -42,22 +44,29 @@ use biome_js_syntax::{
 };
 use biome_json_analyze::METADATA as json_metadata;
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
-use biome_module_graph::ModuleGraph;
+use biome_markdown_syntax::MarkdownFileSource;
+use biome_module_graph::{ModuleGraph, NamespaceImport};
 use biome_package::PackageJson;
 use biome_parser::AnyParse;
 use biome_project_layout::ProjectLayout;
-use biome_rowan::{FileSourceError, NodeCache, SendNode, SyntaxNode, TokenText};
+use biome_rowan::{Direction, FileSourceError, NodeCache, SendNode, SyntaxNode, TokenText};
 use biome_string_case::StrLikeExtension;
+use biome_text_edit::TextEdit;
+use biome_yaml_analyze::METADATA as yaml_metadata;
+use biome_yaml_syntax::{YamlFileSource, YamlLanguage};
 use camino::Utf8Path;
 use either::Either;
 use grit::GritFileHandler;
 use html::HtmlFileHandler;
 pub use javascript::JsFormatterSettings;
+use markdown::MarkdownFileHandler;
 use rustc_hash::FxHashSet;
 use std::borrow::Cow;
 use std::collections::HashSet;
+use std::ops::Range;
 use std::sync::Arc;
 use tracing::instrument;
+use yaml::YamlFileHandler;
 
 pub mod astro;
 pub(crate) mod css;
@@ -67,9 +76,11 @@ pub(crate) mod html;
 mod ignore;
 pub(crate) mod javascript;
 pub(crate) mod json;
+pub(crate) mod markdown;
 pub mod svelte;
 mod unknown;
 pub mod vue;
+pub(crate) mod yaml;
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
@@ -82,6 +93,8 @@ pub enum DocumentFileSource {
     Graphql(GraphqlFileSource),
     Html(HtmlFileSource),
     Grit(GritFileSource),
+    Yaml(YamlFileSource),
+    Markdown(MarkdownFileSource),
     // Ignore files
     Ignore,
     #[default]
@@ -124,6 +137,18 @@ impl From<GritFileSource> for DocumentFileSource {
     }
 }
 
+impl From<YamlFileSource> for DocumentFileSource {
+    fn from(value: YamlFileSource) -> Self {
+        Self::Yaml(value)
+    }
+}
+
+impl From<MarkdownFileSource> for DocumentFileSource {
+    fn from(value: MarkdownFileSource) -> Self {
+        Self::Markdown(value)
+    }
+}
+
 impl From<&Utf8Path> for DocumentFileSource {
     fn from(path: &Utf8Path) -> Self {
         Self::from_path(path, false)
@@ -160,6 +185,9 @@ impl DocumentFileSource {
         if let Ok(file_source) = GraphqlFileSource::try_from_well_known(path) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_well_known(path) {
+            return Ok(file_source.into());
+        }
 
         Err(FileSourceError::UnknownFileName)
     }
@@ -204,6 +232,12 @@ impl DocumentFileSource {
         if let Ok(file_source) = GritFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = MarkdownFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
         Err(FileSourceError::UnknownExtension)
     }
 
@@ -232,6 +266,12 @@ impl DocumentFileSource {
         if let Ok(file_source) = GritFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = MarkdownFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
         Err(FileSourceError::UnknownLanguageId)
     }
 
@@ -370,6 +410,20 @@ impl DocumentFileSource {
         }
     }
 
+    pub fn to_yaml_file_source(&self) -> Option<YamlFileSource> {
+        match self {
+            Self::Yaml(yaml) => Some(*yaml),
+            _ => None,
+        }
+    }
+
+    pub fn to_markdown_file_source(&self) -> Option<MarkdownFileSource> {
+        match self {
+            Self::Markdown(markdown) => Some(*markdown),
+            _ => None,
+        }
+    }
+
     /// The file can be parsed
     pub fn can_parse(path: &Utf8Path) -> bool {
         let file_source = Self::from(path);
@@ -379,7 +433,9 @@ impl DocumentFileSource {
             | Self::Graphql(_)
             | Self::Json(_)
             | Self::Html(_)
-            | Self::Grit(_) => true,
+            | Self::Grit(_)
+            | Self::Yaml(_)
+            | Self::Markdown(_) => true,
             Self::Ignore => false,
             Self::Unknown => false,
         }
@@ -394,7 +450,9 @@ impl DocumentFileSource {
             | Self::Graphql(_)
             | Self::Json(_)
             | Self::Html(_)
-            | Self::Grit(_) => true,
+            | Self::Grit(_)
+            | Self::Yaml(_)
+            | Self::Markdown(_) => true,
             Self::Ignore => true,
             Self::Unknown => false,
         }
@@ -404,12 +462,12 @@ impl DocumentFileSource {
     pub fn can_contain_embeds(path: &Utf8Path, experimental_full_html_support: bool) -> bool {
         let file_source = Self::from_path(path, experimental_full_html_support);
         match file_source {
-            Self::Html(_) => true,
-            Self::Js(_)
-            | Self::Css(_)
+            Self::Html(_) | Self::Markdown(_) | Self::Js(_) => true,
+            Self::Css(_)
             | Self::Graphql(_)
             | Self::Json(_)
             | Self::Grit(_)
+            | Self::Yaml(_)
             | Self::Ignore
             | Self::Unknown => false,
         }
@@ -427,6 +485,8 @@ impl std::fmt::Display for DocumentFileSource {
                     } else {
                         write!(fmt, "TypeScript")
                     }
+                } else if js.is_flow() {
+                    write!(fmt, "Flow")
                 } else if is_jsx {
                     write!(fmt, "JSX")
                 } else {
@@ -434,7 +494,9 @@ impl std::fmt::Display for DocumentFileSource {
                 }
             }
             Self::Json(json) => {
-                if json.allow_comments() {
+                if json.is_json5() {
+                    write!(fmt, "JSON5")
+                } else if json.allow_comments() {
                     write!(fmt, "JSONC")
                 } else {
                     write!(fmt, "JSON")
@@ -444,6 +506,8 @@ impl std::fmt::Display for DocumentFileSource {
             Self::Graphql(_) => write!(fmt, "GraphQL"),
             Self::Html(_) => write!(fmt, "HTML"),
             Self::Grit(_) => write!(fmt, "Grit"),
+            Self::Yaml(_) => write!(fmt, "YAML"),
+            Self::Markdown(_) => write!(fmt, "Markdown"),
             Self::Ignore => write!(fmt, "Ignore"),
             Self::Unknown => write!(fmt, "Unknown"),
         }
@@ -483,6 +547,7 @@ pub struct Capabilities {
     pub(crate) analyzer: AnalyzerCapabilities,
     pub(crate) formatter: FormatterCapabilities,
     pub(crate) search: SearchCapabilities,
+    pub(crate) navigation: NavigationCapabilities,
     pub(crate) enabled_for_path: EnabledForPath,
 }
 
@@ -547,6 +612,7 @@ pub(crate) struct LintParams<'a> {
     pub(crate) enabled_selectors: &'a [AnalyzerSelector],
     pub(crate) plugins: AnalyzerPluginVec,
     pub(crate) pull_code_actions: bool,
+    pub(crate) pull_suppressed: bool,
     pub(crate) diagnostic_offset: Option<TextSize>,
     pub(crate) document_services: &'a DocumentServices,
 }
@@ -613,7 +679,12 @@ impl<'a> ProcessLint<'a> {
                 return ControlFlow::<Never>::Continue(());
             }
 
-            self.diagnostic_count += 1;
+            // Suppressed diagnostics are only reported to the clients that
+            // requested them, they don't count as emitted diagnostics
+            let is_suppressed = diagnostic.tags().is_suppressed();
+            if !is_suppressed {
+                self.diagnostic_count += 1;
+            }
 
             // We do now check if the severity of the diagnostics should be changed.
             // The configuration allows to change the severity of the diagnostics emitted by rules.
@@ -628,7 +699,7 @@ impl<'a> ProcessLint<'a> {
                 .or_else(|| Some(diagnostic.severity()))
                 .unwrap_or(Severity::Warning);
 
-            if severity >= Severity::Error {
+            if severity >= Severity::Error && !is_suppressed {
                 self.errors += 1;
             }
 
@@ -671,9 +742,13 @@ impl<'a> ProcessLint<'a> {
                 .map(biome_diagnostics::serde::Diagnostic::new)
                 .collect::<Vec<_>>(),
         );
+        let emitted_diagnostics = diagnostics
+            .iter()
+            .filter(|diagnostic| !diagnostic.tags().is_suppressed())
+            .count();
         let skipped_diagnostics = self
             .diagnostic_count
-            .saturating_sub(diagnostics.len() as u32);
+            .saturating_sub(emitted_diagnostics as u32);
 
         LintResults {
             errors,
@@ -923,6 +998,26 @@ type Lint = fn(LintParams) -> LintResults;
 type CodeActions = fn(CodeActionsParams) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
 type Rename = fn(&BiomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
+type PrepareRename =
+    fn(&BiomePath, AnyParse, TextSize) -> Result<PrepareRenameResult, WorkspaceError>;
+type RenameImports = fn(
+    &BiomePath,
+    AnyParse,
+    &RenamedExport,
+    Arc<ModuleGraph>,
+) -> Result<Option<(TextRange, TextEdit)>, WorkspaceError>;
+
+/// An exported symbol that is renamed in the module that declares it.
+pub(crate) struct RenamedExport<'a> {
+    /// Path of the module that declares the symbol
+    pub(crate) path: &'a Utf8Path,
+    /// Range of the binding of the symbol
+    pub(crate) range: TextRange,
+    /// Name of the symbol before the rename
+    pub(crate) name: &'a str,
+    /// Name of the symbol after the rename
+    pub(crate) new_name: &'a str,
+}
 type UpdateSnippets = fn(AnyParse, Vec<UpdateSnippetsNodes>) -> Result<SendNode, WorkspaceError>;
 type PullDiagnosticsAndActions = fn(DiagnosticsAndActionsParams) -> PullDiagnosticsAndActionsResult;
 
@@ -936,6 +1031,10 @@ pub struct AnalyzerCapabilities {
     pub(crate) fix_all: Option<FixAll>,
     /// It renames a binding inside a file
     pub(crate) rename: Option<Rename>,
+    /// It returns the symbol that would be renamed at a given position
+    pub(crate) prepare_rename: Option<PrepareRename>,
+    /// It updates the references to an exported symbol renamed in another file
+    pub(crate) rename_imports: Option<RenameImports>,
     /// It updates the snippets contained in the original root
     pub(crate) update_snippets: Option<UpdateSnippets>,
     /// Pulls diagnostics with relative code actions
@@ -994,7 +1093,7 @@ type Search = fn(
     AnyParse,
     &GritQuery,
     &Settings,
-) -> Result<Vec<TextRange>, WorkspaceError>;
+) -> Result<SearchResults, WorkspaceError>;
 
 #[derive(Default)]
 pub(crate) struct SearchCapabilities {
@@ -1002,6 +1101,53 @@ pub(crate) struct SearchCapabilities {
     pub(crate) search: Option<Search>,
 }
 
+type Hover =
+    fn(&BiomePath, AnyParse, TextSize, Arc<ModuleGraph>) -> Result<GetHoverResult, WorkspaceError>;
+type Definition = fn(
+    &BiomePath,
+    AnyParse,
+    TextSize,
+    Arc<ModuleGraph>,
+) -> Result<GetDefinitionResult, WorkspaceError>;
+type Completions =
+    fn(AnyParse, TextSize, &DocumentServices) -> Result<GetCompletionsResult, WorkspaceError>;
+type References = fn(
+    &BiomePath,
+    AnyParse,
+    TextSize,
+    bool,
+    Arc<ModuleGraph>,
+) -> Result<GetReferencesResult, WorkspaceError>;
+type NamespaceMemberReferences = fn(AnyParse, &NamespaceImport) -> Vec<TextRange>;
+type DocumentSymbols = fn(AnyParse) -> Vec<DocumentSymbol>;
+type FoldingRanges = fn(AnyParse) -> Vec<FoldingRange>;
+type SemanticTokens = fn(AnyParse) -> Vec<SemanticToken>;
+type InlayHints = fn(&BiomePath, AnyParse, TextRange, Arc<ModuleGraph>) -> Vec<InlayHint>;
+
+#[derive(Default)]
+pub(crate) struct NavigationCapabilities {
+    /// It describes the symbol at a given position
+    pub(crate) hover: Option<Hover>,
+    /// It finds the declaration of the symbol at a given position
+    pub(crate) definition: Option<Definition>,
+    /// It finds the references of the symbol at a given position
+    pub(crate) references: Option<References>,
+    /// It finds the members accessed through a namespace import, e.g.
+    /// `ns.name`, which aren't tracked by the module graph
+    pub(crate) namespace_member_references: Option<NamespaceMemberReferences>,
+    /// It suggests completions at a given position
+    pub(crate) completions: Option<Completions>,
+    /// It returns the outline of a file
+    pub(crate) document_symbols: Option<DocumentSymbols>,
+    /// It returns the regions of a file that can be folded
+    pub(crate) folding_ranges: Option<FoldingRanges>,
+    /// It classifies the identifiers of a file for highlighting
+    pub(crate) semantic_tokens: Option<SemanticTokens>,
+    /// It returns the hints to display inline in a range, such as inferred
+    /// types
+    pub(crate) inlay_hints: Option<InlayHints>,
+}
+
 #[derive(Default)]
 pub(crate) struct EnabledForPath {
     pub(crate) formatter: Option<Enabled>,
@@ -1030,6 +1176,8 @@ pub(crate) struct Features {
     graphql: GraphqlFileHandler,
     html: HtmlFileHandler,
     grit: GritFileHandler,
+    yaml: YamlFileHandler,
+    markdown: MarkdownFileHandler,
     ignore: IgnoreFileHandler,
 }
 
@@ -1045,6 +1193,8 @@ impl Features {
             graphql: GraphqlFileHandler {},
             html: HtmlFileHandler {},
             grit: GritFileHandler {},
+            yaml: YamlFileHandler {},
+            markdown: MarkdownFileHandler {},
             ignore: IgnoreFileHandler {},
             unknown: UnknownFileHandler::default(),
         }
@@ -1065,6 +1215,8 @@ impl Features {
             DocumentFileSource::Graphql(_) => self.graphql.capabilities(),
             DocumentFileSource::Html(_) => self.html.capabilities(),
             DocumentFileSource::Grit(_) => self.grit.capabilities(),
+            DocumentFileSource::Yaml(_) => self.yaml.capabilities(),
+            DocumentFileSource::Markdown(_) => self.markdown.capabilities(),
             DocumentFileSource::Ignore => self.ignore.capabilities(),
             DocumentFileSource::Unknown => self.unknown.capabilities(),
         }
@@ -1075,6 +1227,88 @@ impl Features {
 ///
 /// The function checks the diagnostic against the current configured rules.
 // TODO: this function works only with lint rules, but it should work with assist actions too
+/// Collects the symbols of the subtree of `node`.
+///
+/// `to_symbol` returns the symbol declared by a node, if any. The symbols
+/// found in the subtree of a symbol become its children, while the nodes that
+/// don't declare a symbol are transparent.
+pub(crate) fn collect_document_symbols<L: biome_rowan::Language>(
+    node: &SyntaxNode<L>,
+    to_symbol: fn(&SyntaxNode<L>) -> Option<DocumentSymbol>,
+) -> Vec<DocumentSymbol> {
+    let mut symbols = Vec::new();
+    for child in node.children() {
+        match to_symbol(&child) {
+            Some(mut symbol) => {
+                symbol.children = collect_document_symbols(&child, to_symbol);
+                symbols.push(symbol);
+            }
+            None => symbols.extend(collect_document_symbols(&child, to_symbol)),
+        }
+    }
+    symbols
+}
+
+/// Returns the folding ranges of the regions delimited by brackets, and of
+/// the comments that span multiple lines.
+///
+/// The regions are found by matching the bracket tokens, so it works for any
+/// language whose blocks are delimited by `{}`, `[]` or `()`.
+pub(crate) fn folding_ranges_of_brackets<L: biome_rowan::Language>(
+    root: &SyntaxNode<L>,
+) -> Vec<FoldingRange> {
+    let text = root.text_with_trivia().to_string();
+    let base = root.text_range_with_trivia().start();
+    let spans_lines = |range: TextRange| {
+        text.get(Range::<usize>::from(range - base))
+            .is_some_and(|text| text.contains('\n'))
+    };
+
+    let mut ranges = Vec::new();
+    let mut open_brackets: Vec<(&str, TextSize)> = Vec::new();
+    for token in root.descendants_tokens(Direction::Next) {
+        for piece in token.leading_trivia().pieces() {
+            if piece.is_comments() && piece.text().contains('\n') {
+                ranges.push(FoldingRange {
+                    range: piece.text_range(),
+                    kind: FoldingRangeKind::Comment,
+                });
+            }
+        }
+
+        let range = token.text_trimmed_range();
+        match token.text_trimmed() {
+            "{" | "${" => open_brackets.push(("}", range.start())),
+            "[" => open_brackets.push(("]", range.start())),
+            "(" => open_brackets.push((")", range.start())),
+            closing @ ("}" | "]" | ")") => {
+                // Unbalanced brackets only happen with syntax errors, the
+                // closing bracket is matched with the nearest opening one.
+                let Some(index) = open_brackets
+                    .iter()
+                    .rposition(|(expected, _)| *expected == closing)
+                else {
+                    continue;
+                };
+                let (_, start) = open_brackets[index];
+                open_brackets.truncate(index);
+
+                let range = TextRange::new(start, range.end());
+                if spans_lines(range) {
+                    ranges.push(FoldingRange {
+                        range,
+                        kind: FoldingRangeKind::Region,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    ranges.sort_by_key(|range| (range.range.start(), range.range.end()));
+    ranges
+}
+
 pub(crate) fn is_diagnostic_error(
     diagnostic: &'_ AnalyzerDiagnostic,
     rules: Option<&'_ Rules>,
@@ -1187,24 +1421,34 @@ pub(crate) fn search(
     parse: AnyParse,
     query: &GritQuery,
     _settings: &Settings,
-) -> Result<Vec<TextRange>, WorkspaceError> {
+) -> Result<SearchResults, WorkspaceError> {
     let result = query
         .execute(GritTargetFile::new(path.as_path(), parse))
         .map_err(|err| {
             WorkspaceError::SearchError(SearchError::QueryError(QueryDiagnostic(err.to_string())))
         })?;
 
-    let matches = result
-        .effects
-        .into_iter()
-        .flat_map(|result| match result {
-            GritQueryEffect::Match(m) => m.ranges,
-            _ => Vec::new(),
-        })
-        .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
-        .collect();
+    let mut matches = Vec::new();
+    let mut rewritten = None;
+    for effect in result.effects {
+        match effect {
+            GritQueryEffect::Match(m) => matches.extend(m.ranges),
+            GritQueryEffect::Rewrite(rewrite) => {
+                matches.extend(rewrite.original.ranges);
+                rewritten = Some(rewrite.rewritten.content);
+            }
+            GritQueryEffect::CreateFile(_) => {}
+        }
+    }
 
-    Ok(matches)
+    Ok(SearchResults {
+        path: path.clone(),
+        matches: matches
+            .into_iter()
+            .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
+            .collect(),
+        rewritten,
+    })
 }
 
 /// Type meant to register all the syntax rules for each language supported by Biome
@@ -1310,6 +1554,25 @@ impl RegistryVisitor<HtmlLanguage> for SyntaxVisitor<'_> {
     }
 }
 
+impl RegistryVisitor<YamlLanguage> for SyntaxVisitor<'_> {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Syntax {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.enabled_rules.push(RuleFilter::Rule(
+            <R::Group as RuleGroup>::NAME,
+            R::METADATA.name,
+        ))
+    }
+}
+
 /// Type meant to register all the lint rules for each language supported by Biome
 ///
 #[derive(Debug)]
@@ -1648,6 +1911,30 @@ impl RegistryVisitor<HtmlLanguage> for LintVisitor<'_, '_> {
     }
 }
 
+impl RegistryVisitor<YamlLanguage> for LintVisitor<'_, '_> {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = YamlLanguage>>(&mut self) {
+        G::record_rules(self)
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>(
+            yaml_metadata
+                .find_rule(R::Group::NAME, R::METADATA.name)
+                .map(RuleFilter::from),
+        )
+    }
+}
+
 struct AssistsVisitor<'a, 'b> {
     settings: &'b Settings,
     enabled_rules: Vec<RuleFilter<'a>>,
@@ -1816,6 +2103,22 @@ impl RegistryVisitor<HtmlLanguage> for AssistsVisitor<'_, '_> {
     }
 }
 
+impl RegistryVisitor<YamlLanguage> for AssistsVisitor<'_, '_> {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Action {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>();
+    }
+}
+
 pub(crate) struct AnalyzerVisitorBuilder<'a> {
     settings: &'a Settings,
     only: Option<&'a [AnalyzerSelector]>,
@@ -1894,6 +2197,7 @@ impl<'b> AnalyzerVisitorBuilder<'b> {
         biome_json_analyze::visit_registry(&mut syntax);
         biome_graphql_analyze::visit_registry(&mut syntax);
         biome_html_analyze::visit_registry(&mut syntax);
+        biome_yaml_analyze::visit_registry(&mut syntax);
         enabled_rules.extend(syntax.enabled_rules);
 
         let package_json = self
@@ -1915,6 +2219,7 @@ impl<'b> AnalyzerVisitorBuilder<'b> {
         biome_json_analyze::visit_registry(&mut lint);
         biome_graphql_analyze::visit_registry(&mut lint);
         biome_html_analyze::visit_registry(&mut lint);
+        biome_yaml_analyze::visit_registry(&mut lint);
         let (linter_enabled_rules, linter_disabled_rules) = lint.finish();
         enabled_rules.extend(linter_enabled_rules);
         disabled_rules.extend(linter_disabled_rules);
@@ -1926,6 +2231,7 @@ impl<'b> AnalyzerVisitorBuilder<'b> {
         biome_json_analyze::visit_registry(&mut assist);
         biome_graphql_analyze::visit_registry(&mut assist);
         biome_html_analyze::visit_registry(&mut assist);
+        biome_yaml_analyze::visit_registry(&mut assist);
         let (assists_enabled_rules, assists_disabled_rules) = assist.finish();
         enabled_rules.extend(assists_enabled_rules);
         disabled_rules.extend(assists_disabled_rules);
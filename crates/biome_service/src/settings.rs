@@ -1,3 +1,4 @@
+use crate::file_handlers::javascript::JsEmbeddedLanguagesSettings;
 use crate::workspace::{DocumentFileSource, FeatureKind};
 use crate::{WorkspaceError, is_dir};
 use biome_analyze::{AnalyzerOptions, AnalyzerRules};
@@ -14,9 +15,10 @@ use biome_configuration::{
     BiomeDiagnostic, Configuration, ConfigurationSource, CssConfiguration,
     DEFAULT_SCANNER_IGNORE_ENTRIES, ExtendedConfigurations, FilesConfiguration,
     FilesIgnoreUnknownEnabled, FormatterConfiguration, GraphqlConfiguration, GritConfiguration,
-    JsConfiguration, JsonConfiguration, LinterConfiguration, OverrideAssistConfiguration,
-    OverrideFormatterConfiguration, OverrideGlobs, OverrideLinterConfiguration, Overrides, Rules,
-    push_to_analyzer_assist, push_to_analyzer_rules,
+    JsConfiguration, JsonConfiguration, LinterConfiguration, MarkdownConfiguration,
+    OverrideAssistConfiguration, OverrideFormatterConfiguration, OverrideGlobs,
+    OverrideLinterConfiguration, Overrides, Rules, YamlConfiguration, push_to_analyzer_assist,
+    push_to_analyzer_rules,
 };
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_parser::CssParserOptions;
@@ -40,7 +42,11 @@ use biome_js_syntax::JsLanguage;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
+use biome_markdown_formatter::context::MarkdownFormatOptions;
+use biome_markdown_syntax::MarkdownLanguage;
 use biome_plugin_loader::Plugins;
+use biome_yaml_formatter::context::YamlFormatOptions;
+use biome_yaml_syntax::YamlLanguage;
 use camino::{Utf8Path, Utf8PathBuf};
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use std::borrow::Cow;
@@ -175,6 +181,14 @@ impl Settings {
             self.experimental_full_html_support = html.experimental_full_support_enabled;
             self.languages.html = html.into();
         }
+        // yaml settings
+        if let Some(yaml) = configuration.yaml {
+            self.languages.yaml = yaml.into();
+        }
+        // markdown settings
+        if let Some(markdown) = configuration.markdown {
+            self.languages.markdown = markdown.into();
+        }
 
         // plugin settings
         if let Some(plugins) = configuration.plugins {
@@ -280,6 +294,34 @@ impl Settings {
         result
     }
 
+    /// Returns the path of the JSON Schema that the configuration assigns to
+    /// the given JSON or YAML `path`, taking overrides into account. The last
+    /// matching override wins.
+    pub fn json_schema_path(
+        &self,
+        path: &Utf8Path,
+        file_source: &DocumentFileSource,
+    ) -> Option<Utf8PathBuf> {
+        let schema = |languages: &LanguageListSettings| match file_source {
+            DocumentFileSource::Json(_) => languages.json.linter.schema.clone(),
+            DocumentFileSource::Yaml(_) => languages.yaml.linter.schema.clone(),
+            _ => None,
+        };
+        let schema = self
+            .override_settings
+            .patterns
+            .iter()
+            .rev()
+            .filter(|pattern| pattern.is_file_included(path))
+            .find_map(|pattern| schema(&pattern.languages))
+            .or_else(|| schema(&self.languages))?;
+
+        Some(match self.source_path() {
+            Some(directory) => directory.join(schema),
+            None => Utf8PathBuf::from(schema),
+        })
+    }
+
     /// Returns the plugins that should be enabled for the given `path`, taking overrides into account.
     pub fn get_plugins_for_path(&self, path: &Utf8Path) -> Cow<'_, Plugins> {
         let mut result = Cow::Borrowed(&self.plugins);
@@ -502,6 +544,8 @@ pub struct LanguageListSettings {
     pub graphql: LanguageSettings<GraphqlLanguage>,
     pub html: LanguageSettings<HtmlLanguage>,
     pub grit: LanguageSettings<GritLanguage>,
+    pub yaml: LanguageSettings<YamlLanguage>,
+    pub markdown: LanguageSettings<MarkdownLanguage>,
 }
 
 impl From<JsConfiguration> for LanguageSettings<JsLanguage> {
@@ -528,6 +572,10 @@ impl From<JsConfiguration> for LanguageSettings<JsLanguage> {
             language_setting.environment = jsx_runtime.into();
         }
 
+        if let Some(embedded_languages) = javascript.embedded_languages {
+            language_setting.parser.embedded_languages = embedded_languages.into();
+        }
+
         if let Some(globals) = javascript.globals {
             language_setting.globals = Some(globals);
         }
@@ -603,6 +651,34 @@ impl From<GraphqlConfiguration> for LanguageSettings<GraphqlLanguage> {
     }
 }
 
+impl From<YamlConfiguration> for LanguageSettings<YamlLanguage> {
+    fn from(yaml: YamlConfiguration) -> Self {
+        let mut language_setting: Self = Self::default();
+
+        if let Some(formatter) = yaml.formatter {
+            language_setting.formatter = formatter.into();
+        }
+
+        if let Some(linter) = yaml.linter {
+            language_setting.linter = linter.into();
+        }
+
+        language_setting
+    }
+}
+
+impl From<MarkdownConfiguration> for LanguageSettings<MarkdownLanguage> {
+    fn from(markdown: MarkdownConfiguration) -> Self {
+        let mut language_setting: Self = Self::default();
+
+        if let Some(formatter) = markdown.formatter {
+            language_setting.formatter = formatter.into();
+        }
+
+        language_setting
+    }
+}
+
 impl From<GritConfiguration> for LanguageSettings<GritLanguage> {
     fn from(grit: GritConfiguration) -> Self {
         let mut language_setting: Self = Self::default();
@@ -1277,6 +1353,30 @@ impl OverrideSettings {
             .unwrap_or(base_setting)
     }
 
+    pub fn override_js_embedded_languages(
+        &self,
+        path: &BiomePath,
+        base_setting: &JsEmbeddedLanguagesSettings,
+    ) -> JsEmbeddedLanguagesSettings {
+        self.patterns
+            .iter()
+            // Reverse the traversal as only the last override takes effect
+            .rev()
+            .find_map(|pattern| {
+                let embedded_languages = &pattern.languages.javascript.parser.embedded_languages;
+                if (embedded_languages.enabled.is_some()
+                    || embedded_languages.css_tags.is_some()
+                    || embedded_languages.graphql_tags.is_some())
+                    && pattern.is_file_included(path)
+                {
+                    Some(embedded_languages.clone())
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_else(|| base_setting.clone())
+    }
+
     pub fn apply_override_grit_format_options(
         &self,
         path: &Utf8Path,
@@ -1398,6 +1498,32 @@ impl OverrideSettings {
         }
     }
 
+    /// Scans and aggregates all the overrides into a single [YamlFormatOptions]
+    pub fn apply_override_yaml_format_options(
+        &self,
+        path: &Utf8Path,
+        options: &mut YamlFormatOptions,
+    ) {
+        for pattern in self.patterns.iter() {
+            if pattern.is_file_included(path) {
+                pattern.apply_overrides_to_yaml_format_options(options);
+            }
+        }
+    }
+
+    /// Scans and aggregates all the overrides into a single [MarkdownFormatOptions]
+    pub fn apply_override_markdown_format_options(
+        &self,
+        path: &Utf8Path,
+        options: &mut MarkdownFormatOptions,
+    ) {
+        for pattern in self.patterns.iter() {
+            if pattern.is_file_included(path) {
+                pattern.apply_overrides_to_markdown_format_options(options);
+            }
+        }
+    }
+
     /// Retrieves the options of lint rules that have been overridden
     pub fn override_analyzer_rules(
         &self,
@@ -1432,6 +1558,11 @@ impl OverrideSettings {
                         biome_html_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    push_to_analyzer_rules(
+                        rules,
+                        biome_yaml_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
                 }
 
                 if let Some(actions) = pattern.assist.actions.as_ref() {
@@ -1460,6 +1591,11 @@ impl OverrideSettings {
                         biome_html_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    push_to_analyzer_assist(
+                        actions,
+                        biome_yaml_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
                 }
             }
         }
@@ -1572,6 +1708,9 @@ impl OverrideSettingPattern {
         {
             options.set_bracket_spacing(bracket_spacing);
         }
+        if let Some(quote_properties) = json_formatter.quote_properties {
+            options.set_quote_properties(quote_properties);
+        }
     }
 
     fn apply_overrides_to_css_format_options(&self, options: &mut CssFormatOptions) {
@@ -1691,6 +1830,54 @@ impl OverrideSettingPattern {
         // #endregion
     }
 
+    fn apply_overrides_to_yaml_format_options(&self, options: &mut YamlFormatOptions) {
+        let yaml_formatter = &self.languages.yaml.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_width) = yaml_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = yaml_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = yaml_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+        if let Some(quote_style) = yaml_formatter.quote_style {
+            options.set_quote_style(quote_style);
+        }
+        if let Some(indent_sequences) = yaml_formatter.indent_sequences {
+            options.set_indent_sequences(indent_sequences);
+        }
+        if let Some(preserve_comments) = yaml_formatter.preserve_comments {
+            options.set_preserve_comments(preserve_comments);
+        }
+    }
+
+    fn apply_overrides_to_markdown_format_options(&self, options: &mut MarkdownFormatOptions) {
+        let markdown_formatter = &self.languages.markdown.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_width) = markdown_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = markdown_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = markdown_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+        if let Some(bullet_list_marker) = markdown_formatter.bullet_list_marker {
+            options.set_bullet_list_marker(bullet_list_marker);
+        }
+        if let Some(emphasis_style) = markdown_formatter.emphasis_style {
+            options.set_emphasis_style(emphasis_style);
+        }
+        if let Some(prose_wrap) = markdown_formatter.prose_wrap {
+            options.set_prose_wrap(prose_wrap);
+        }
+    }
+
     fn apply_overrides_to_js_parser_options(&self, options: &mut JsParserOptions) {
         let js_parser = &self.languages.javascript.parser;
 
@@ -1795,6 +1982,8 @@ pub fn to_override_settings(
         let graphql = pattern.graphql.take().unwrap_or_default();
         let grit = pattern.grit.take().unwrap_or_default();
         let html = pattern.html.take().unwrap_or_default();
+        let yaml = pattern.yaml.take().unwrap_or_default();
+        let markdown = pattern.markdown.take().unwrap_or_default();
 
         languages.javascript =
             to_javascript_language_settings(javascript, &current_settings.languages.javascript);
@@ -1805,6 +1994,9 @@ pub fn to_override_settings(
             to_graphql_language_settings(graphql, &current_settings.languages.graphql);
         languages.grit = to_grit_language_settings(grit, &current_settings.languages.grit);
         languages.html = to_html_language_settings(html, &current_settings.languages.html);
+        languages.yaml = to_yaml_language_settings(yaml, &current_settings.languages.yaml);
+        languages.markdown =
+            to_markdown_language_settings(markdown, &current_settings.languages.markdown);
 
         let pattern_setting = OverrideSettingPattern {
             includes: OverrideIncludes::new(working_directory.clone(), pattern.includes),
@@ -1840,6 +2032,18 @@ fn to_javascript_language_settings(
         .unsafe_parameter_decorators_enabled
         .or(parent_parser.parse_class_parameter_decorators);
 
+    let embedded_languages = conf.embedded_languages.take().unwrap_or_default();
+    let parent_embedded_languages = &parent_parser.embedded_languages;
+    language_setting.parser.embedded_languages.enabled = embedded_languages
+        .enabled
+        .or(parent_embedded_languages.enabled);
+    language_setting.parser.embedded_languages.css_tags = embedded_languages
+        .css_tags
+        .or_else(|| parent_embedded_languages.css_tags.clone());
+    language_setting.parser.embedded_languages.graphql_tags = embedded_languages
+        .graphql_tags
+        .or_else(|| parent_embedded_languages.graphql_tags.clone());
+
     language_setting.globals = conf.globals;
     language_setting.environment.jsx_runtime =
         conf.jsx_runtime.or(parent_settings.environment.jsx_runtime);
@@ -1866,6 +2070,7 @@ fn to_json_language_settings(
 
     let linter = conf.linter.take().unwrap_or_default();
     language_setting.linter.enabled = linter.enabled;
+    language_setting.linter.schema = linter.schema;
 
     let assist = conf.assist.take().unwrap_or_default();
     language_setting.assist.enabled = assist.enabled;
@@ -1949,6 +2154,34 @@ fn to_html_language_settings(
     language_setting
 }
 
+fn to_yaml_language_settings(
+    mut conf: YamlConfiguration,
+    _parent_settings: &LanguageSettings<YamlLanguage>,
+) -> LanguageSettings<YamlLanguage> {
+    let mut language_setting: LanguageSettings<YamlLanguage> = LanguageSettings::default();
+    let formatter = conf.formatter.take().unwrap_or_default();
+
+    language_setting.formatter = formatter.into();
+
+    let linter = conf.linter.take().unwrap_or_default();
+    language_setting.linter.enabled = linter.enabled;
+    language_setting.linter.schema = linter.schema;
+
+    language_setting
+}
+
+fn to_markdown_language_settings(
+    mut conf: MarkdownConfiguration,
+    _parent_settings: &LanguageSettings<MarkdownLanguage>,
+) -> LanguageSettings<MarkdownLanguage> {
+    let mut language_setting: LanguageSettings<MarkdownLanguage> = LanguageSettings::default();
+    let formatter = conf.formatter.take().unwrap_or_default();
+
+    language_setting.formatter = formatter.into();
+
+    language_setting
+}
+
 pub fn to_format_settings(
     working_directory: Option<Utf8PathBuf>,
     conf: FormatterConfiguration,
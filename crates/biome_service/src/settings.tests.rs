@@ -162,6 +162,7 @@ fn json_to_settings_includes_linter_and_assist() {
     let config = JsonConfiguration {
         linter: Some(JsonLinterConfiguration {
             enabled: Some(true.into()),
+            ..Default::default()
         }),
         assist: Some(JsonAssistConfiguration {
             enabled: Some(true.into()),
@@ -63,6 +63,7 @@ use biome_formatter::Printed;
 use biome_fs::BiomePath;
 use biome_grit_patterns::GritTargetLanguage;
 use biome_js_syntax::{TextRange, TextSize};
+use biome_json_schema::{SchemaCompletion, SchemaCompletionKind};
 use biome_module_graph::SerializedModuleInfo;
 use biome_resolver::FsWithResolverProxy;
 use biome_text_edit::TextEdit;
@@ -943,6 +944,22 @@ pub struct UpdateModuleGraphParams {
     pub update_kind: UpdateKind,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetDependentPackagesParams {
+    /// The paths whose packages should be looked up.
+    pub paths: Vec<BiomePath>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetDependentPackagesResult {
+    /// The paths of the dependent packages, sorted and without duplicates.
+    pub package_paths: Vec<BiomePath>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
@@ -967,6 +984,10 @@ pub struct PullDiagnosticsParams {
     pub enabled_rules: Vec<AnalyzerSelector>,
     /// When `false` the diagnostics, don't have code frames of the code actions (fixes, suppressions, etc.)
     pub pull_code_actions: bool,
+    /// When `true`, the diagnostics suppressed by suppression comments are returned too,
+    /// tagged as `suppressed`
+    #[serde(default)]
+    pub pull_suppressed: bool,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -1138,6 +1159,396 @@ pub struct RenameResult {
     pub range: TextRange,
     /// List of text edit operations to apply on the source code
     pub indels: TextEdit,
+    /// Edits to apply to other files, when the renamed symbol is exported and
+    /// other modules refer to it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_edits: Vec<RenameFileEdit>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct RenameFileEdit {
+    /// Path of the file to edit
+    pub path: BiomePath,
+    /// Range of source code modified in the file
+    pub range: TextRange,
+    /// List of text edit operations to apply on the source code of the file
+    pub indels: TextEdit,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PrepareRenameParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PrepareRenameResult {
+    /// Range of the symbol that would be renamed, if it can be renamed
+    pub range: Option<TextRange>,
+    /// Current name of the symbol
+    pub placeholder: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetHoverParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    /// Position of the cursor inside the file
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetHoverResult {
+    /// Range of the symbol the hover information refers to
+    pub range: Option<TextRange>,
+    /// Markdown content describing the symbol, such as its inferred type
+    pub content: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetCompletionsParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    /// Position of the cursor inside the file
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetCompletionsResult {
+    pub items: Vec<CompletionItem>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionItem {
+    /// Text inserted by the completion
+    pub label: String,
+    pub kind: CompletionItemKind,
+    /// Markdown content describing the completion
+    pub documentation: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum CompletionItemKind {
+    /// The name of a property
+    Property,
+    /// A value
+    Value,
+}
+
+impl From<SchemaCompletion> for CompletionItem {
+    fn from(completion: SchemaCompletion) -> Self {
+        Self {
+            label: completion.label,
+            kind: match completion.kind {
+                SchemaCompletionKind::Property => CompletionItemKind::Property,
+                SchemaCompletionKind::Value => CompletionItemKind::Value,
+            },
+            documentation: completion.documentation,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetDefinitionParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    /// Position of the cursor inside the file
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetDefinitionResult {
+    /// Locations where the symbol under the cursor is declared
+    pub locations: Vec<SymbolLocation>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetReferencesParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    /// Position of the cursor inside the file
+    pub offset: TextSize,
+    /// Whether the declaration of the symbol should be part of the result
+    #[serde(default)]
+    pub include_declaration: bool,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetReferencesResult {
+    /// Locations where the symbol under the cursor is referenced
+    pub locations: Vec<SymbolLocation>,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolLocation {
+    /// Path of the file that contains the symbol
+    pub path: BiomePath,
+    /// Range of the symbol inside the file
+    pub range: TextRange,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetDocumentSymbolsParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetDocumentSymbolsResult {
+    /// The symbols declared at the top level of the file
+    pub symbols: Vec<DocumentSymbol>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSymbol {
+    /// The name of the symbol, e.g. the name of a class or the selector of a CSS rule
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Range of the whole declaration of the symbol
+    pub range: TextRange,
+    /// Range of the name of the symbol, contained in [DocumentSymbol::range]
+    pub selection_range: TextRange,
+    /// The symbols declared inside this symbol, e.g. the members of a class
+    pub children: Vec<Self>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SymbolKind {
+    Module,
+    Namespace,
+    Class,
+    Method,
+    Property,
+    Field,
+    Constructor,
+    Enum,
+    Interface,
+    Function,
+    Variable,
+    Constant,
+    String,
+    Number,
+    Boolean,
+    Array,
+    Object,
+    Null,
+    EnumMember,
+    Struct,
+    TypeParameter,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetWorkspaceSymbolsParams {
+    pub project_key: ProjectKey,
+    /// The text to search. The symbols match when their name contains the
+    /// characters of the query in the same order, ignoring the case.
+    pub query: String,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetWorkspaceSymbolsResult {
+    /// The symbols exported by the modules of the project that match the query
+    pub symbols: Vec<WorkspaceSymbol>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceSymbol {
+    /// The name the symbol is exported with
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Location of the declaration of the symbol
+    pub location: SymbolLocation,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetFoldingRangesParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetFoldingRangesResult {
+    pub ranges: Vec<FoldingRange>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FoldingRange {
+    /// Range of the region that can be folded, including its delimiters
+    pub range: TextRange,
+    pub kind: FoldingRangeKind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum FoldingRangeKind {
+    /// A region delimited by brackets or tags, e.g. a block or an object
+    Region,
+    /// A comment that spans multiple lines
+    Comment,
+    /// A group of consecutive import statements
+    Imports,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetSemanticTokensParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetSemanticTokensResult {
+    /// The tokens of the file, sorted by position and not overlapping
+    pub tokens: Vec<SemanticToken>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticToken {
+    pub range: TextRange,
+    pub kind: SemanticTokenKind,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modifiers: Vec<SemanticTokenModifier>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SemanticTokenKind {
+    Namespace,
+    Type,
+    Class,
+    Enum,
+    Interface,
+    Struct,
+    TypeParameter,
+    Parameter,
+    Variable,
+    EnumMember,
+    Function,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SemanticTokenModifier {
+    /// The token is the name of a binding where it's declared
+    Declaration,
+    /// The binding can't be reassigned, e.g. a `const` variable or an import
+    Readonly,
+    /// The token refers to a global provided by the environment
+    DefaultLibrary,
+    /// The binding is never referenced
+    Unused,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetInlayHintsParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    /// Range of the file the hints are requested for
+    pub range: TextRange,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetInlayHintsResult {
+    /// The hints of the range, sorted by position
+    pub hints: Vec<InlayHint>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHint {
+    /// Position where the hint is displayed
+    pub position: TextSize,
+    pub label: String,
+    pub kind: InlayHintKind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum InlayHintKind {
+    /// The inferred return type of a function without annotation
+    ReturnType,
+    /// The inferred type of a variable without annotation
+    VariableType,
+    /// The name of the parameter an argument is passed to
+    ParameterName,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetProjectFilesParams {
+    pub project_key: ProjectKey,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetProjectFilesResult {
+    /// The paths of the files, sorted
+    pub paths: Vec<BiomePath>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -1238,6 +1649,8 @@ pub struct SearchPatternParams {
 pub struct SearchResults {
     pub path: BiomePath,
     pub matches: Vec<TextRange>,
+    /// The content of the file after applying the rewrites of the pattern
+    pub rewritten: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -1516,8 +1929,112 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     fn fix_file(&self, params: FixFileParams) -> Result<FixFileResult, WorkspaceError>;
 
     /// Returns the content of the file after renaming a symbol.
+    ///
+    /// If the symbol is exported, the result also contains the edits for the
+    /// modules of the module graph that import it.
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
+    /// Returns the range and the name of the symbol at the given position,
+    /// if it can be renamed.
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError>;
+
+    /// Returns information about the symbol at the given position, such as
+    /// its inferred type.
+    ///
+    /// Returns an empty result if the language of the file doesn't support
+    /// it.
+    fn get_hover(&self, params: GetHoverParams) -> Result<GetHoverResult, WorkspaceError>;
+
+    /// Returns the completions at the given position, such as the properties
+    /// and the values allowed by the JSON Schema of the document.
+    ///
+    /// Returns an empty result if the language of the file doesn't support
+    /// it.
+    fn get_completions(
+        &self,
+        params: GetCompletionsParams,
+    ) -> Result<GetCompletionsResult, WorkspaceError>;
+
+    /// Returns the locations where the symbol at the given position is
+    /// declared.
+    ///
+    /// Imported symbols are resolved through the module graph, so the result
+    /// may point to a different file.
+    fn get_definition(
+        &self,
+        params: GetDefinitionParams,
+    ) -> Result<GetDefinitionResult, WorkspaceError>;
+
+    /// Returns the locations where the symbol at the given position is
+    /// referenced, including references in other modules that import it.
+    fn get_references(
+        &self,
+        params: GetReferencesParams,
+    ) -> Result<GetReferencesResult, WorkspaceError>;
+
+    /// Returns the outline of a file: the symbols it declares, such as
+    /// classes and functions, nested by their enclosing symbol.
+    ///
+    /// Returns an empty result if the language of the file doesn't support
+    /// it.
+    fn get_document_symbols(
+        &self,
+        params: GetDocumentSymbolsParams,
+    ) -> Result<GetDocumentSymbolsResult, WorkspaceError>;
+
+    /// Returns the symbols exported by the modules of the project whose name
+    /// matches the query.
+    ///
+    /// The symbols come from the module graph, so only the files indexed by
+    /// the scanner are searched.
+    fn get_workspace_symbols(
+        &self,
+        params: GetWorkspaceSymbolsParams,
+    ) -> Result<GetWorkspaceSymbolsResult, WorkspaceError>;
+
+    /// Returns the regions of a file that can be folded, computed from its
+    /// syntax tree.
+    ///
+    /// Returns an empty result if the language of the file doesn't support
+    /// it.
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError>;
+
+    /// Returns the identifiers of a file classified using the semantic model
+    /// of its language, so that they can be highlighted by the editor.
+    ///
+    /// Returns an empty result if the language of the file doesn't support
+    /// it.
+    fn get_semantic_tokens(
+        &self,
+        params: GetSemanticTokensParams,
+    ) -> Result<GetSemanticTokensResult, WorkspaceError>;
+
+    /// Returns the hints to display inline in the given range of a file,
+    /// such as the inferred types of its bindings.
+    ///
+    /// Returns an empty result if the language of the file doesn't support
+    /// it.
+    fn get_inlay_hints(
+        &self,
+        params: GetInlayHintsParams,
+    ) -> Result<GetInlayHintsResult, WorkspaceError>;
+
+    /// Returns the files of a project that are opened in the workspace, either
+    /// by a client or by the scanner.
+    ///
+    /// Files inside `node_modules` aren't returned, because they aren't part
+    /// of the project.
+    fn get_project_files(
+        &self,
+        params: GetProjectFilesParams,
+    ) -> Result<GetProjectFilesResult, WorkspaceError>;
+
     /// Closes a file that is opened in the workspace.
     ///
     /// This only unloads the document from the workspace if the file is NOT
@@ -1533,6 +2050,16 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// the method [Workspace::open_file] before updating the module graph.
     fn update_module_graph(&self, params: UpdateModuleGraphParams) -> Result<(), WorkspaceError>;
 
+    /// Returns the packages of the workspace that depend, directly or
+    /// transitively, on the packages that contain the given paths.
+    ///
+    /// Only packages whose manifests have been indexed by the scanner are
+    /// taken into account.
+    fn get_dependent_packages(
+        &self,
+        params: GetDependentPackagesParams,
+    ) -> Result<GetDependentPackagesResult, WorkspaceError>;
+
     /// Returns the filesystem implementation to open files with.
     ///
     /// This may be an in-memory file system.
@@ -1711,6 +2238,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         only: Vec<AnalyzerSelector>,
         skip: Vec<AnalyzerSelector>,
         pull_code_actions: bool,
+        pull_suppressed: bool,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         self.workspace.pull_diagnostics(PullDiagnosticsParams {
             project_key: self.project_key,
@@ -1720,6 +2248,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             skip,
             enabled_rules: vec![],
             pull_code_actions,
+            pull_suppressed,
         })
     }
 
@@ -312,6 +312,7 @@ fn correctly_pulls_lint_diagnostics() {
         vec![RuleSelector::Rule(RuleGroup::Style.as_str(), "useDeprecatedReason").into()],
         vec![],
         true,
+        false,
     );
     assert!(result.is_ok());
     let diagnostics = result.unwrap().diagnostics;
@@ -537,6 +538,7 @@ fn plugins_are_loaded_and_used_during_analysis() {
             skip: Vec::new(),
             enabled_rules: Vec::new(),
             pull_code_actions: true,
+            pull_suppressed: false,
         })
         .unwrap();
     assert_debug_snapshot!(result.diagnostics);
@@ -605,6 +607,7 @@ language css;
             skip: Vec::new(),
             enabled_rules: Vec::new(),
             pull_code_actions: true,
+            pull_suppressed: false,
         })
         .unwrap();
     assert_debug_snapshot!(result.diagnostics);
@@ -669,6 +672,7 @@ fn plugins_may_use_invalid_span() {
             skip: Vec::new(),
             enabled_rules: Vec::new(),
             pull_code_actions: true,
+            pull_suppressed: false,
         })
         .unwrap();
     assert_debug_snapshot!(result.diagnostics);
@@ -798,6 +802,7 @@ const hasOwn = Object.hasOwn({ foo: 'bar' }, 'foo');"#,
                 skip: Vec::new(),
                 enabled_rules: Vec::new(),
                 pull_code_actions: true,
+                pull_suppressed: false,
             })
             .unwrap();
         // Filter only diagnostics with category name "plugin"
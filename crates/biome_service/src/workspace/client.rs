@@ -1,17 +1,23 @@
 use super::{
     ChangeFileParams, ChangeFileResult, CloseFileParams, FileExitsParams, FixFileParams,
-    FixFileResult, FormatFileParams, FormatOnTypeParams, FormatRangeParams,
-    GetControlFlowGraphParams, GetFormatterIRParams, GetModuleGraphParams, GetModuleGraphResult,
-    GetSemanticModelParams, GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams,
-    OpenFileResult, PullActionsParams, PullActionsResult, PullDiagnosticsAndActionsParams,
+    FixFileResult, FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetCompletionsParams,
+    GetCompletionsResult, GetControlFlowGraphParams, GetDefinitionParams, GetDefinitionResult,
+    GetFormatterIRParams, GetHoverParams, GetHoverResult, GetModuleGraphParams,
+    GetModuleGraphResult, GetReferencesParams, GetReferencesResult, GetSemanticModelParams,
+    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, OpenFileResult, PrepareRenameParams,
+    PrepareRenameResult, PullActionsParams, PullActionsResult, PullDiagnosticsAndActionsParams,
     PullDiagnosticsAndActionsResult, PullDiagnosticsParams, PullDiagnosticsResult, RenameParams,
     RenameResult, ScanProjectParams, ScanProjectResult, SearchPatternParams, SearchResults,
     SupportsFeatureParams, UpdateModuleGraphParams, UpdateSettingsParams, UpdateSettingsResult,
 };
 use crate::workspace::{
     CheckFileSizeParams, CheckFileSizeResult, CloseProjectParams, FileFeaturesResult,
-    GetFileContentParams, GetRegisteredTypesParams, GetTypeInfoParams, OpenProjectParams,
-    OpenProjectResult, PathIsIgnoredParams, RageParams, RageResult, ServerInfo,
+    GetDependentPackagesParams, GetDependentPackagesResult, GetDocumentSymbolsParams,
+    GetDocumentSymbolsResult, GetFileContentParams, GetFoldingRangesParams, GetFoldingRangesResult,
+    GetInlayHintsParams, GetInlayHintsResult, GetProjectFilesParams, GetProjectFilesResult,
+    GetRegisteredTypesParams, GetSemanticTokensParams, GetSemanticTokensResult, GetTypeInfoParams,
+    GetWorkspaceSymbolsParams, GetWorkspaceSymbolsResult, OpenProjectParams, OpenProjectResult,
+    PathIsIgnoredParams, RageParams, RageResult, ServerInfo,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -199,6 +205,80 @@ where
         self.request("biome/rename", params)
     }
 
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError> {
+        self.request("biome/prepare_rename", params)
+    }
+
+    fn get_hover(&self, params: GetHoverParams) -> Result<GetHoverResult, WorkspaceError> {
+        self.request("biome/get_hover", params)
+    }
+
+    fn get_completions(
+        &self,
+        params: GetCompletionsParams,
+    ) -> Result<GetCompletionsResult, WorkspaceError> {
+        self.request("biome/get_completions", params)
+    }
+
+    fn get_definition(
+        &self,
+        params: GetDefinitionParams,
+    ) -> Result<GetDefinitionResult, WorkspaceError> {
+        self.request("biome/get_definition", params)
+    }
+
+    fn get_references(
+        &self,
+        params: GetReferencesParams,
+    ) -> Result<GetReferencesResult, WorkspaceError> {
+        self.request("biome/get_references", params)
+    }
+
+    fn get_document_symbols(
+        &self,
+        params: GetDocumentSymbolsParams,
+    ) -> Result<GetDocumentSymbolsResult, WorkspaceError> {
+        self.request("biome/get_document_symbols", params)
+    }
+
+    fn get_workspace_symbols(
+        &self,
+        params: GetWorkspaceSymbolsParams,
+    ) -> Result<GetWorkspaceSymbolsResult, WorkspaceError> {
+        self.request("biome/get_workspace_symbols", params)
+    }
+
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError> {
+        self.request("biome/get_folding_ranges", params)
+    }
+
+    fn get_semantic_tokens(
+        &self,
+        params: GetSemanticTokensParams,
+    ) -> Result<GetSemanticTokensResult, WorkspaceError> {
+        self.request("biome/get_semantic_tokens", params)
+    }
+
+    fn get_inlay_hints(
+        &self,
+        params: GetInlayHintsParams,
+    ) -> Result<GetInlayHintsResult, WorkspaceError> {
+        self.request("biome/get_inlay_hints", params)
+    }
+
+    fn get_project_files(
+        &self,
+        params: GetProjectFilesParams,
+    ) -> Result<GetProjectFilesResult, WorkspaceError> {
+        self.request("biome/get_project_files", params)
+    }
+
     fn close_file(&self, params: CloseFileParams) -> Result<(), WorkspaceError> {
         self.request("biome/close_file", params)
     }
@@ -207,6 +287,13 @@ where
         self.request("biome/update_module_graph", params)
     }
 
+    fn get_dependent_packages(
+        &self,
+        params: GetDependentPackagesParams,
+    ) -> Result<GetDependentPackagesResult, WorkspaceError> {
+        self.request("biome/get_dependent_packages", params)
+    }
+
     fn fs(&self) -> &dyn FsWithResolverProxy {
         self.fs.as_ref()
     }
@@ -5,17 +5,21 @@ use crate::workspace::DocumentFileSource;
 use biome_css_syntax::{CssLanguage, CssRoot};
 use biome_diagnostics::Error;
 use biome_diagnostics::serde::Diagnostic as SerdeDiagnostic;
+use biome_graphql_syntax::GraphqlLanguage;
 use biome_js_syntax::JsLanguage;
+use biome_json_schema::JsonSchema;
 use biome_json_syntax::JsonLanguage;
 use biome_parser::AnyParse;
 use biome_rowan::{SyntaxNodeWithOffset, TextRange, TextSize};
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub enum AnyEmbeddedSnippet {
     Js(EmbeddedSnippet<JsLanguage>, DocumentServices),
     Css(EmbeddedSnippet<CssLanguage>, DocumentServices),
     Json(EmbeddedSnippet<JsonLanguage>, DocumentServices),
+    Graphql(EmbeddedSnippet<GraphqlLanguage>, DocumentServices),
 }
 
 impl From<EmbeddedSnippet<JsLanguage>> for AnyEmbeddedSnippet {
@@ -36,6 +40,12 @@ impl From<EmbeddedSnippet<JsonLanguage>> for AnyEmbeddedSnippet {
     }
 }
 
+impl From<EmbeddedSnippet<GraphqlLanguage>> for AnyEmbeddedSnippet {
+    fn from(content: EmbeddedSnippet<GraphqlLanguage>) -> Self {
+        Self::Graphql(content, DocumentServices::none())
+    }
+}
+
 impl AnyEmbeddedSnippet {
     pub const fn is_js(&self) -> bool {
         matches!(self, Self::Js(..))
@@ -69,11 +79,20 @@ impl AnyEmbeddedSnippet {
         }
     }
 
+    pub fn as_graphql_embedded_snippet(&self) -> Option<&EmbeddedSnippet<GraphqlLanguage>> {
+        if let Self::Graphql(content, _) = self {
+            Some(content)
+        } else {
+            None
+        }
+    }
+
     pub fn content_range(&self) -> TextRange {
         match self {
             Self::Js(node, _) => node.content_range,
             Self::Css(node, _) => node.content_range,
             Self::Json(node, _) => node.content_range,
+            Self::Graphql(node, _) => node.content_range,
         }
     }
 
@@ -82,6 +101,7 @@ impl AnyEmbeddedSnippet {
             Self::Js(node, _) => node.element_range,
             Self::Css(node, _) => node.element_range,
             Self::Json(node, _) => node.element_range,
+            Self::Graphql(node, _) => node.element_range,
         }
     }
 
@@ -90,6 +110,7 @@ impl AnyEmbeddedSnippet {
             Self::Js(node, _) => node.parse.clone(),
             Self::Css(node, _) => node.parse.clone(),
             Self::Json(node, _) => node.parse.clone(),
+            Self::Graphql(node, _) => node.parse.clone(),
         }
     }
 
@@ -98,6 +119,7 @@ impl AnyEmbeddedSnippet {
             Self::Js(node, _) => node.file_source_index,
             Self::Css(node, _) => node.file_source_index,
             Self::Json(node, _) => node.file_source_index,
+            Self::Graphql(node, _) => node.file_source_index,
         }
     }
 
@@ -106,6 +128,7 @@ impl AnyEmbeddedSnippet {
             Self::Js(node, _) => node.file_source_index = index,
             Self::Css(node, _) => node.file_source_index = index,
             Self::Json(node, _) => node.file_source_index = index,
+            Self::Graphql(node, _) => node.file_source_index = index,
         }
     }
 
@@ -114,6 +137,7 @@ impl AnyEmbeddedSnippet {
             Self::Js(node, _) => node.content_offset,
             Self::Css(node, _) => node.content_offset,
             Self::Json(node, _) => node.content_offset,
+            Self::Graphql(node, _) => node.content_offset,
         }
     }
 
@@ -122,6 +146,7 @@ impl AnyEmbeddedSnippet {
             Self::Js(node, _) => node.into_serde_diagnostics(),
             Self::Css(node, _) => node.into_serde_diagnostics(),
             Self::Json(node, _) => node.into_serde_diagnostics(),
+            Self::Graphql(node, _) => node.into_serde_diagnostics(),
         }
     }
 
@@ -130,11 +155,12 @@ impl AnyEmbeddedSnippet {
             Self::Js(_, services) => services,
             Self::Css(_, services) => services,
             Self::Json(_, services) => services,
+            Self::Graphql(_, services) => services,
         }
     }
 }
 
-/// Represents embedded content extracted from HTML documents.
+/// Represents embedded content extracted from HTML and Markdown documents.
 ///
 /// This struct stores parsing metadata and provides access to the parsed
 /// content with offset-aware positioning to maintain correct source locations.
@@ -260,6 +286,8 @@ pub enum DocumentServices {
     /// The document doesn't have any services
     None,
     Css(CssDocumentServices),
+    /// Services of JSON and YAML documents
+    JsonSchema(JsonSchemaDocumentServices),
 }
 
 impl From<CssDocumentServices> for DocumentServices {
@@ -268,6 +296,12 @@ impl From<CssDocumentServices> for DocumentServices {
     }
 }
 
+impl From<JsonSchemaDocumentServices> for DocumentServices {
+    fn from(services: JsonSchemaDocumentServices) -> Self {
+        Self::JsonSchema(services)
+    }
+}
+
 impl DocumentServices {
     pub fn new_css() -> Self {
         Self::Css(CssDocumentServices {
@@ -286,6 +320,20 @@ impl DocumentServices {
             None
         }
     }
+
+    pub fn as_json_schema_services(&self) -> Option<&JsonSchemaDocumentServices> {
+        if let Self::JsonSchema(services) = self {
+            Some(services)
+        } else {
+            None
+        }
+    }
+
+    /// The JSON Schema the document must satisfy, if any
+    pub(crate) fn json_schema(&self) -> Option<Arc<JsonSchema>> {
+        self.as_json_schema_services()
+            .and_then(|services| services.schema.clone())
+    }
 }
 #[derive(Clone, Default, Debug)]
 pub struct CssDocumentServices {
@@ -299,3 +347,16 @@ impl CssDocumentServices {
         self
     }
 }
+
+#[derive(Clone, Default, Debug)]
+pub struct JsonSchemaDocumentServices {
+    /// JSON Schema that the document must satisfy
+    pub(crate) schema: Option<Arc<JsonSchema>>,
+}
+
+impl JsonSchemaDocumentServices {
+    pub fn with_schema(mut self, schema: Option<Arc<JsonSchema>>) -> Self {
+        self.schema = schema;
+        self
+    }
+}
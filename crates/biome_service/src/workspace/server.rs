@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::panic::RefUnwindSafe;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
@@ -8,14 +9,17 @@ use crate::configuration::{LoadedConfiguration, read_config};
 use crate::diagnostics::{FileTooLarge, NoIgnoreFileFound, VcsDiagnostic};
 use crate::file_handlers::{
     Capabilities, CodeActionsParams, DiagnosticsAndActionsParams, DocumentFileSource, Features,
-    FixAllParams, FormatEmbedNode, LintParams, LintResults, ParseResult, UpdateSnippetsNodes,
+    FixAllParams, FormatEmbedNode, LintParams, LintResults, ParseResult, RenamedExport,
+    UpdateSnippetsNodes,
 };
 use crate::projects::{GetFileFeaturesParams, Projects};
 use crate::scanner::{
     IndexRequestKind, IndexTrigger, ScanOptions, Scanner, ScannerWatcherBridge, WatcherInstruction,
     WorkspaceScannerBridge,
 };
-use crate::workspace::document::{AnyEmbeddedSnippet, DocumentServices};
+use crate::workspace::document::{
+    AnyEmbeddedSnippet, DocumentServices, JsonSchemaDocumentServices,
+};
 use biome_analyze::{AnalyzerPluginVec, RuleCategory};
 use biome_configuration::bool::Bool;
 use biome_configuration::max_size::MaxSize;
@@ -29,12 +33,14 @@ use biome_diagnostics::{
     Diagnostic, DiagnosticExt, Severity, serde::Diagnostic as SerdeDiagnostic,
 };
 use biome_formatter::Printed;
-use biome_fs::{BiomePath, ConfigName, PathKind};
+use biome_fs::{BiomePath, ConfigName, PathKind, normalize_path};
 use biome_grit_patterns::{CompilePatternOptions, GritQuery, compile_pattern_with_options};
+use biome_js_analyze::utils::rename::RenameError;
 use biome_js_syntax::{AnyJsRoot, LanguageVariant, ModuleKind};
 use biome_json_parser::JsonParserOptions;
+use biome_json_schema::{JsonSchema, is_local_schema_reference};
 use biome_json_syntax::JsonFileSource;
-use biome_module_graph::{ModuleDependencies, ModuleDiagnostic, ModuleGraph};
+use biome_module_graph::{JsDeclarationKind, ModuleDependencies, ModuleDiagnostic, ModuleGraph};
 use biome_package::PackageType;
 use biome_parser::AnyParse;
 use biome_plugin_loader::{BiomePlugin, PluginCache, PluginDiagnostic};
@@ -76,6 +82,10 @@ pub struct WorkspaceServer {
     /// Stores patterns to search for.
     patterns: HashMap<PatternId, GritQuery, FxBuildHasher>,
 
+    /// Stores the JSON Schemas used by JSON and YAML documents, along with the
+    /// content they were parsed from.
+    json_schemas: HashMap<Utf8PathBuf, (String, Arc<JsonSchema>), FxBuildHasher>,
+
     /// Node cache for faster parsing of modified documents.
     ///
     /// ## Concurrency
@@ -133,6 +143,7 @@ impl WorkspaceServer {
             documents: Default::default(),
             file_sources: boxcar::Vec::default(),
             patterns: Default::default(),
+            json_schemas: Default::default(),
             node_cache: Default::default(),
             scanner: Scanner::new(watcher_tx),
             fs,
@@ -348,7 +359,11 @@ impl WorkspaceServer {
             }
         }
 
-        if let DocumentFileSource::Css(css) = &mut source {
+        // The SCSS and Less files keep their variant, CSS Modules and Tailwind CSS apply to CSS files only
+        if let DocumentFileSource::Css(css) = &mut source
+            && !css.is_scss()
+            && !css.is_less()
+        {
             if settings
                 .languages
                 .css
@@ -412,6 +427,8 @@ impl WorkspaceServer {
                     services = CssDocumentServices::default()
                         .with_css_semantic_model(&any_parse.tree())
                         .into();
+                } else if settings.is_linter_enabled() {
+                    services = self.json_schema_services(&path, &language, &any_parse, &settings);
                 }
             }
 
@@ -710,6 +727,66 @@ impl WorkspaceServer {
         }
     }
 
+    /// Returns the services of a JSON or YAML document, which hold the JSON
+    /// Schema that the document must satisfy.
+    ///
+    /// A local schema referenced by the document takes precedence over the one
+    /// assigned by the configuration.
+    fn json_schema_services(
+        &self,
+        path: &Utf8Path,
+        file_source: &DocumentFileSource,
+        parse: &AnyParse,
+        settings: &Settings,
+    ) -> DocumentServices {
+        let reference = match file_source {
+            DocumentFileSource::Json(_) => biome_json_schema::json::schema_reference(&parse.tree()),
+            DocumentFileSource::Yaml(_) => biome_json_schema::yaml::schema_reference(&parse.tree()),
+            _ => return DocumentServices::none(),
+        };
+        let schema_path = reference
+            .filter(|reference| is_local_schema_reference(reference))
+            .and_then(|reference| Some(path.parent()?.join(reference)))
+            .or_else(|| settings.json_schema_path(path, file_source));
+        let schema = schema_path
+            .and_then(|schema_path| self.load_json_schema(&normalize_path(&schema_path)));
+
+        JsonSchemaDocumentServices::default()
+            .with_schema(schema)
+            .into()
+    }
+
+    /// Loads the JSON Schema at the given `path`. The schema is parsed again
+    /// only when the content of the file changed since it was last loaded.
+    fn load_json_schema(&self, path: &Utf8Path) -> Option<Arc<JsonSchema>> {
+        let content = match self.fs.read_file_from_path(path) {
+            Ok(content) => content,
+            Err(error) => {
+                warn!("Could not read the JSON Schema {path}: {error:?}");
+                return None;
+            }
+        };
+
+        let json_schemas = self.json_schemas.pin();
+        if let Some((cached_content, schema)) = json_schemas.get(path)
+            && *cached_content == content
+        {
+            return Some(schema.clone());
+        }
+
+        match JsonSchema::parse(&content) {
+            Ok(schema) => {
+                let schema = Arc::new(schema);
+                json_schemas.insert(path.to_path_buf(), (content, schema.clone()));
+                Some(schema)
+            }
+            Err(error) => {
+                warn!("Could not parse the JSON Schema {path}: {error}");
+                None
+            }
+        }
+    }
+
     /// Returns whether the given `path` that falls under the project with the
     /// given `project_key` is ignored, assuming the given `scan_kind` and
     /// `reason`.
@@ -914,6 +991,22 @@ impl WorkspaceServer {
                         .remove_turbo_json_from_package(&package_path);
                 }
             }
+        } else if filename.is_some_and(|filename| filename == "pnpm-workspace.yaml") {
+            let package_path = path
+                .parent()
+                .map(|parent| parent.to_path_buf())
+                .ok_or_else(WorkspaceError::not_found)?;
+
+            match update_kind {
+                UpdateKind::AddedOrChanged(_, root, _) => {
+                    self.project_layout
+                        .insert_serialized_pnpm_workspace(package_path, root);
+                }
+                UpdateKind::Removed => {
+                    self.project_layout
+                        .remove_pnpm_workspace_from_package(&package_path);
+                }
+            }
         }
 
         Ok(())
@@ -1479,6 +1572,9 @@ impl Workspace for WorkspaceServer {
             services = CssDocumentServices::default()
                 .with_css_semantic_model(&parsed.any_parse.tree())
                 .into();
+        } else if settings.is_linter_enabled() {
+            services =
+                self.json_schema_services(&path, &document_source, &parsed.any_parse, &settings);
         }
 
         // Second-pass parsing for HTML files with embedded JavaScript and CSS content
@@ -1575,6 +1671,7 @@ impl Workspace for WorkspaceServer {
             skip,
             enabled_rules,
             pull_code_actions,
+            pull_suppressed,
         } = params;
         let settings = self
             .projects
@@ -1612,6 +1709,7 @@ impl Workspace for WorkspaceServer {
                 suppression_reason: None,
                 enabled_selectors: &enabled_rules,
                 pull_code_actions,
+                pull_suppressed,
                 plugins: plugins.clone(),
                 diagnostic_offset: None,
                 document_services: &services,
@@ -1645,6 +1743,7 @@ impl Workspace for WorkspaceServer {
                     suppression_reason: None,
                     enabled_selectors: &enabled_rules,
                     pull_code_actions,
+                    pull_suppressed,
                     plugins: plugins.clone(),
                     diagnostic_offset: Some(embedded_node.content_offset()),
                     document_services: services,
@@ -2142,11 +2241,411 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(&params.path)?;
-        let result = rename(&params.path, parse, params.symbol_at, params.new_name)?;
+        let mut result = rename(
+            &params.path,
+            parse.clone(),
+            params.symbol_at,
+            params.new_name.clone(),
+        )?;
+
+        let (Some(prepare_rename), Some(definition), Some(rename_imports)) = (
+            capabilities.analyzer.prepare_rename,
+            capabilities.navigation.definition,
+            capabilities.analyzer.rename_imports,
+        ) else {
+            return Ok(result);
+        };
+
+        // Symbols are renamed across modules only when they're exported by the
+        // module that declares them under the same name. Renaming the alias of
+        // an import, for example, only affects the current module.
+        let Some(name) = prepare_rename(&params.path, parse.clone(), params.symbol_at)?.placeholder
+        else {
+            return Ok(result);
+        };
+        let definition = definition(
+            &params.path,
+            parse,
+            params.symbol_at,
+            self.module_graph.clone(),
+        )?;
+        let Some(declaration) = definition.locations.into_iter().next() else {
+            return Ok(result);
+        };
+        let is_exported = self
+            .module_graph
+            .find_exported_symbol_declaration(&declaration.path, &name)
+            .is_some_and(|(path, range)| {
+                path == declaration.path.as_path() && range == declaration.range
+            });
+        if !is_exported {
+            return Ok(result);
+        }
+
+        if declaration.path != params.path {
+            let parse = self.get_parse(&declaration.path)?;
+            let RenameResult { range, indels, .. } = rename(
+                &declaration.path,
+                parse,
+                declaration.range.start(),
+                params.new_name.clone(),
+            )?;
+            result.file_edits.push(RenameFileEdit {
+                path: declaration.path.clone(),
+                range,
+                indels,
+            });
+        }
+
+        let export = RenamedExport {
+            path: &declaration.path,
+            range: declaration.range,
+            name: &name,
+            new_name: &params.new_name,
+        };
+        for path in self.module_graph.find_modules_importing_declaration(
+            &declaration.path,
+            declaration.range,
+            &name,
+        ) {
+            if path == params.path.as_path() {
+                continue;
+            }
+
+            let path = BiomePath::from(path);
+            // The rename fails as a whole when an importing module can't be
+            // parsed, e.g. because it's too large. Renaming the export alone
+            // would break the imports of that module.
+            let parse = self.get_parse(&path).map_err(|_| {
+                WorkspaceError::RenameError(RenameError::CannotUpdateImports(path.to_string()))
+            })?;
+            if let Some((range, indels)) =
+                rename_imports(&path, parse, &export, self.module_graph.clone())?
+            {
+                result.file_edits.push(RenameFileEdit {
+                    path,
+                    range,
+                    indels,
+                });
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError> {
+        let settings = self
+            .projects
+            .get_settings_based_on_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let Some(prepare_rename) = capabilities.analyzer.prepare_rename else {
+            return Ok(PrepareRenameResult::default());
+        };
+
+        let parse = self.get_parse(&params.path)?;
+        prepare_rename(&params.path, parse, params.symbol_at)
+    }
+
+    fn get_hover(&self, params: GetHoverParams) -> Result<GetHoverResult, WorkspaceError> {
+        let settings = self
+            .projects
+            .get_settings_based_on_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let Some(hover) = capabilities.navigation.hover else {
+            return Ok(GetHoverResult::default());
+        };
+
+        let parse = self.get_parse(&params.path)?;
+        hover(
+            &params.path,
+            parse,
+            params.offset,
+            self.module_graph.clone(),
+        )
+    }
+
+    fn get_completions(
+        &self,
+        params: GetCompletionsParams,
+    ) -> Result<GetCompletionsResult, WorkspaceError> {
+        let settings = self
+            .projects
+            .get_settings_based_on_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let Some(completions) = capabilities.navigation.completions else {
+            return Ok(GetCompletionsResult::default());
+        };
+
+        let (parse, services) = self.get_parse_and_services(&params.path)?;
+        completions(parse, params.offset, &services)
+    }
+
+    fn get_definition(
+        &self,
+        params: GetDefinitionParams,
+    ) -> Result<GetDefinitionResult, WorkspaceError> {
+        let settings = self
+            .projects
+            .get_settings_based_on_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let Some(definition) = capabilities.navigation.definition else {
+            return Ok(GetDefinitionResult::default());
+        };
+
+        let parse = self.get_parse(&params.path)?;
+        definition(
+            &params.path,
+            parse,
+            params.offset,
+            self.module_graph.clone(),
+        )
+    }
+
+    fn get_references(
+        &self,
+        params: GetReferencesParams,
+    ) -> Result<GetReferencesResult, WorkspaceError> {
+        let settings = self
+            .projects
+            .get_settings_based_on_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let Some(references) = capabilities.navigation.references else {
+            return Ok(GetReferencesResult::default());
+        };
+
+        let parse = self.get_parse(&params.path)?;
+        let mut result = references(
+            &params.path,
+            parse.clone(),
+            params.offset,
+            params.include_declaration,
+            self.module_graph.clone(),
+        )?;
+
+        let Some(definition) = capabilities.navigation.definition else {
+            return Ok(result);
+        };
+
+        // The members accessed through namespace imports, e.g. `ns.name`,
+        // aren't tracked by the module graph, so they're looked up in the
+        // syntax of the importing modules.
+        let definition = definition(
+            &params.path,
+            parse,
+            params.offset,
+            self.module_graph.clone(),
+        )?;
+        let Some(declaration) = definition.locations.into_iter().next() else {
+            return Ok(result);
+        };
+        for namespace_import in self
+            .module_graph
+            .find_namespace_imports_of_declaration(&declaration.path, declaration.range)
+        {
+            let path = BiomePath::from(namespace_import.path.clone());
+            let Some(namespace_member_references) = self
+                .get_file_capabilities(&path, settings.experimental_full_html_support_enabled())
+                .navigation
+                .namespace_member_references
+            else {
+                continue;
+            };
+            let Ok(parse) = self.get_parse(&path) else {
+                continue;
+            };
+            result.locations.extend(
+                namespace_member_references(parse, &namespace_import)
+                    .into_iter()
+                    .map(|range| SymbolLocation {
+                        path: path.clone(),
+                        range,
+                    }),
+            );
+        }
 
         Ok(result)
     }
 
+    fn get_document_symbols(
+        &self,
+        params: GetDocumentSymbolsParams,
+    ) -> Result<GetDocumentSymbolsResult, WorkspaceError> {
+        let settings = self
+            .projects
+            .get_settings_based_on_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let Some(document_symbols) = capabilities.navigation.document_symbols else {
+            return Ok(GetDocumentSymbolsResult::default());
+        };
+
+        let parse = self.get_parse(&params.path)?;
+        Ok(GetDocumentSymbolsResult {
+            symbols: document_symbols(parse),
+        })
+    }
+
+    fn get_workspace_symbols(
+        &self,
+        params: GetWorkspaceSymbolsParams,
+    ) -> Result<GetWorkspaceSymbolsResult, WorkspaceError> {
+        let project_path = self
+            .projects
+            .get_project_path(params.project_key)
+            .ok_or_else(WorkspaceError::no_project)?;
+
+        let mut symbols: Vec<_> = self
+            .module_graph
+            .exported_declarations()
+            .into_iter()
+            .filter(|declaration| declaration.path.starts_with(&project_path))
+            .filter(|declaration| matches_symbol_query(declaration.name.text(), &params.query))
+            .filter_map(|declaration| {
+                Some(WorkspaceSymbol {
+                    kind: symbol_kind_of_declaration(declaration.kind)?,
+                    name: declaration.name.to_string(),
+                    location: SymbolLocation {
+                        path: declaration.path.into(),
+                        range: declaration.range,
+                    },
+                })
+            })
+            .collect();
+        symbols.sort_by(|a, b| {
+            (
+                a.name.as_str(),
+                a.location.path.as_path(),
+                a.location.range.start(),
+            )
+                .cmp(&(
+                    b.name.as_str(),
+                    b.location.path.as_path(),
+                    b.location.range.start(),
+                ))
+        });
+
+        Ok(GetWorkspaceSymbolsResult { symbols })
+    }
+
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError> {
+        let settings = self
+            .projects
+            .get_settings_based_on_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let Some(folding_ranges) = capabilities.navigation.folding_ranges else {
+            return Ok(GetFoldingRangesResult::default());
+        };
+
+        let parse = self.get_parse(&params.path)?;
+        Ok(GetFoldingRangesResult {
+            ranges: folding_ranges(parse),
+        })
+    }
+
+    fn get_semantic_tokens(
+        &self,
+        params: GetSemanticTokensParams,
+    ) -> Result<GetSemanticTokensResult, WorkspaceError> {
+        let settings = self
+            .projects
+            .get_settings_based_on_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let Some(semantic_tokens) = capabilities.navigation.semantic_tokens else {
+            return Ok(GetSemanticTokensResult::default());
+        };
+
+        let parse = self.get_parse(&params.path)?;
+        Ok(GetSemanticTokensResult {
+            tokens: semantic_tokens(parse),
+        })
+    }
+
+    fn get_inlay_hints(
+        &self,
+        params: GetInlayHintsParams,
+    ) -> Result<GetInlayHintsResult, WorkspaceError> {
+        let settings = self
+            .projects
+            .get_settings_based_on_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let Some(inlay_hints) = capabilities.navigation.inlay_hints else {
+            return Ok(GetInlayHintsResult::default());
+        };
+
+        let parse = self.get_parse(&params.path)?;
+        Ok(GetInlayHintsResult {
+            hints: inlay_hints(&params.path, parse, params.range, self.module_graph.clone()),
+        })
+    }
+
+    fn get_project_files(
+        &self,
+        params: GetProjectFilesParams,
+    ) -> Result<GetProjectFilesResult, WorkspaceError> {
+        let project_path = self
+            .projects
+            .get_project_path(params.project_key)
+            .ok_or_else(WorkspaceError::no_project)?;
+
+        let mut paths: Vec<_> = self
+            .documents
+            .pin()
+            .keys()
+            .filter(|path| path.starts_with(&project_path))
+            .cloned()
+            .collect();
+        paths.sort();
+
+        Ok(GetProjectFilesResult {
+            paths: paths
+                .into_iter()
+                .map(BiomePath::new)
+                .filter(|path| !path.is_dependency())
+                .collect(),
+        })
+    }
+
     /// Closes a file that is opened in the workspace.
     ///
     /// This only unloads the document from the workspace if the file is NOT
@@ -2185,6 +2684,24 @@ impl Workspace for WorkspaceServer {
         Ok(())
     }
 
+    fn get_dependent_packages(
+        &self,
+        params: GetDependentPackagesParams,
+    ) -> Result<GetDependentPackagesResult, WorkspaceError> {
+        let package_paths: BTreeSet<_> = params
+            .paths
+            .iter()
+            .filter_map(|path| self.project_layout.find_node_manifest_for_path(path))
+            .flat_map(|(package_path, _)| {
+                self.project_layout.find_dependent_packages(&package_path)
+            })
+            .collect();
+
+        Ok(GetDependentPackagesResult {
+            package_paths: package_paths.into_iter().map(BiomePath::from).collect(),
+        })
+    }
+
     fn fs(&self) -> &dyn FsWithResolverProxy {
         self.fs.as_ref()
     }
@@ -2233,9 +2750,7 @@ impl Workspace for WorkspaceServer {
 
         let document_file_source =
             self.get_file_source(&path, settings.experimental_full_html_support_enabled());
-        let matches = search(&path, &document_file_source, parse, query, &settings)?;
-
-        Ok(SearchResults { path, matches })
+        search(&path, &document_file_source, parse, query, &settings)
     }
 
     fn drop_pattern(&self, params: DropPatternParams) -> Result<(), WorkspaceError> {
@@ -2301,9 +2816,13 @@ impl WorkspaceScannerBridge for WorkspaceServer {
     #[inline]
     fn is_indexed(&self, path: &Utf8Path) -> bool {
         match path.file_name() {
-            Some("package.json" | "tsconfig.json" | "turbo.json" | "turbo.jsonc") => {
-                self.project_layout.is_indexed(path)
-            }
+            Some(
+                "package.json"
+                | "tsconfig.json"
+                | "turbo.json"
+                | "turbo.jsonc"
+                | "pnpm-workspace.yaml",
+            ) => self.project_layout.is_indexed(path),
             _ => self.module_graph.contains(path),
         }
     }
@@ -2568,3 +3087,34 @@ fn make_search_pattern_id() -> PatternId {
 #[cfg(test)]
 #[path = "server.tests.rs"]
 mod tests;
+
+/// Whether the name of a symbol matches the query of a workspace symbol
+/// search: the characters of the query must appear in the name in the same
+/// order, ignoring the case.
+fn matches_symbol_query(name: &str, query: &str) -> bool {
+    let mut name = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !c.is_whitespace())
+        .all(|query_char| name.any(|name_char| name_char == query_char))
+}
+
+/// Returns the kind of symbol of an exported declaration, or [None] for the
+/// declarations that aren't symbols, such as imports.
+fn symbol_kind_of_declaration(kind: JsDeclarationKind) -> Option<SymbolKind> {
+    match kind {
+        JsDeclarationKind::Class => Some(SymbolKind::Class),
+        JsDeclarationKind::Enum => Some(SymbolKind::Enum),
+        JsDeclarationKind::HoistedValue => Some(SymbolKind::Function),
+        JsDeclarationKind::Interface => Some(SymbolKind::Interface),
+        JsDeclarationKind::Module => Some(SymbolKind::Module),
+        JsDeclarationKind::Namespace => Some(SymbolKind::Namespace),
+        JsDeclarationKind::Type => Some(SymbolKind::TypeParameter),
+        JsDeclarationKind::Using | JsDeclarationKind::Value => Some(SymbolKind::Variable),
+        JsDeclarationKind::Generic
+        | JsDeclarationKind::Import
+        | JsDeclarationKind::ImportType
+        | JsDeclarationKind::Unknown => None,
+    }
+}
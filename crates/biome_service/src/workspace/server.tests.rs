@@ -538,3 +538,293 @@ fn extends_root_resolves_globs_from_project_root() {
     );
     assert!(tests_is_ignored, "file outside includes should be ignored");
 }
+
+#[test]
+fn lint_documents_against_their_json_schema() {
+    const CONFIGURATION: &str = r#"{
+        "linter": {
+            "rules": { "nursery": { "noJsonSchemaViolations": "error" } }
+        },
+        "overrides": [
+            {
+                "includes": ["**/*.config.json"],
+                "json": { "linter": { "schema": "./schemas/config.schema.json" } }
+            }
+        ]
+    }"#;
+    const SCHEMA: &[u8] = br#"{
+        "type": "object",
+        "required": ["name"],
+        "properties": {
+            "name": { "type": "string" },
+            "replicas": { "type": "integer", "minimum": 1 }
+        }
+    }"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8PathBuf::from("/project/schemas/config.schema.json"),
+        SCHEMA,
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/app.config.json"),
+        br#"{ "name": 1 }"#,
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/other.json"),
+        br#"{ "name": 1 }"#,
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/deploy/config.yaml"),
+        b"# yaml-language-server: $schema=../schemas/config.schema.json\nname: biome\nreplicas: 0\n",
+    );
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/project");
+
+    let configuration = deserialize_from_json_str::<Configuration>(
+        CONFIGURATION,
+        JsonParserOptions::default(),
+        "biome.json",
+    )
+    .into_deserialized()
+    .expect("valid configuration");
+    workspace
+        .update_settings(UpdateSettingsParams {
+            project_key,
+            configuration,
+            workspace_directory: Some(BiomePath::new("/project")),
+            extended_configurations: Default::default(),
+        })
+        .unwrap();
+
+    let violations = |path: &str| {
+        workspace
+            .open_file(OpenFileParams {
+                project_key,
+                path: BiomePath::new(path),
+                content: FileContent::FromServer,
+                document_file_source: None,
+                persist_node_cache: false,
+            })
+            .unwrap();
+        workspace
+            .pull_diagnostics(PullDiagnosticsParams {
+                project_key,
+                path: BiomePath::new(path),
+                categories: RuleCategories::all(),
+                only: vec![],
+                skip: vec![],
+                enabled_rules: vec![],
+                pull_code_actions: false,
+                pull_suppressed: false,
+            })
+            .unwrap()
+            .diagnostics
+            .iter()
+            .map(|diagnostic| biome_diagnostics::PrintDescription(diagnostic).to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        violations("/project/app.config.json"),
+        ["Incorrect type, expected string."]
+    );
+    assert!(violations("/project/other.json").is_empty());
+    assert_eq!(
+        violations("/project/deploy/config.yaml"),
+        ["Value is below the minimum of 1."]
+    );
+}
+
+#[test]
+fn document_symbols_of_css_json_and_graphql_files() {
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8PathBuf::from("/project/style.css"),
+        b":root {\n  --main-color: red;\n}\n@media (min-width: 100px) {\n  .a,\n  .b {}\n}\n",
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/data.json"),
+        br#"{ "name": "biome", "tags": [], "nested": { "count": 1 } }"#,
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/schema.graphql"),
+        b"type Query {\n  user(id: ID): User\n}\nenum Role { ADMIN }\nquery { user { id } }\n",
+    );
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
+
+    let outline = |path: &str| {
+        workspace
+            .open_file(OpenFileParams {
+                project_key,
+                path: BiomePath::new(path),
+                content: FileContent::FromServer,
+                document_file_source: None,
+                persist_node_cache: false,
+            })
+            .unwrap();
+
+        fn flatten(symbols: Vec<DocumentSymbol>, depth: usize) -> Vec<(usize, String, SymbolKind)> {
+            symbols
+                .into_iter()
+                .flat_map(|symbol| {
+                    let mut symbols = vec![(depth, symbol.name, symbol.kind)];
+                    symbols.extend(flatten(symbol.children, depth + 1));
+                    symbols
+                })
+                .collect()
+        }
+
+        let result = workspace
+            .get_document_symbols(GetDocumentSymbolsParams {
+                project_key,
+                path: BiomePath::new(path),
+            })
+            .unwrap();
+        flatten(result.symbols, 0)
+    };
+
+    assert_eq!(
+        outline("/project/style.css"),
+        [
+            (0, ":root".to_string(), SymbolKind::Class),
+            (1, "--main-color".to_string(), SymbolKind::Variable),
+            (
+                0,
+                "@media (min-width: 100px)".to_string(),
+                SymbolKind::Namespace
+            ),
+            (1, ".a, .b".to_string(), SymbolKind::Class),
+        ]
+    );
+    assert_eq!(
+        outline("/project/data.json"),
+        [
+            (0, "name".to_string(), SymbolKind::String),
+            (0, "tags".to_string(), SymbolKind::Array),
+            (0, "nested".to_string(), SymbolKind::Object),
+            (1, "count".to_string(), SymbolKind::Number),
+        ]
+    );
+    assert_eq!(
+        outline("/project/schema.graphql"),
+        [
+            (0, "Query".to_string(), SymbolKind::Class),
+            (1, "user".to_string(), SymbolKind::Field),
+            (0, "Role".to_string(), SymbolKind::Enum),
+            (1, "ADMIN".to_string(), SymbolKind::EnumMember),
+            (0, "query".to_string(), SymbolKind::Function),
+        ]
+    );
+}
+
+#[test]
+fn semantic_tokens_of_css_and_graphql_files() {
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8PathBuf::from("/project/style.css"),
+        b":root { --gap: 1px; }\ndiv.card #main { margin: var(--gap); }\n",
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/schema.graphql"),
+        b"type User { id: ID }\nquery Get($unused: ID) { ...Fields }\nfragment Fields on User { id }\nfragment Other on User { id }\n",
+    );
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
+
+    let tokens = |path: &str| {
+        workspace
+            .open_file(OpenFileParams {
+                project_key,
+                path: BiomePath::new(path),
+                content: FileContent::FromServer,
+                document_file_source: None,
+                persist_node_cache: false,
+            })
+            .unwrap();
+
+        let content = workspace
+            .get_file_content(GetFileContentParams {
+                project_key,
+                path: BiomePath::new(path),
+            })
+            .unwrap();
+        let result = workspace
+            .get_semantic_tokens(GetSemanticTokensParams {
+                project_key,
+                path: BiomePath::new(path),
+            })
+            .unwrap();
+        result
+            .tokens
+            .into_iter()
+            .map(|token| {
+                (
+                    content[token.range].to_string(),
+                    token.kind,
+                    token.modifiers,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    use SemanticTokenModifier::*;
+    assert_eq!(
+        tokens("/project/style.css"),
+        [
+            (
+                "--gap".to_string(),
+                SemanticTokenKind::Variable,
+                vec![Declaration]
+            ),
+            ("div".to_string(), SemanticTokenKind::Type, vec![]),
+            ("card".to_string(), SemanticTokenKind::Class, vec![]),
+            ("main".to_string(), SemanticTokenKind::Variable, vec![]),
+            ("--gap".to_string(), SemanticTokenKind::Variable, vec![]),
+        ]
+    );
+    assert_eq!(
+        tokens("/project/schema.graphql"),
+        [
+            (
+                "User".to_string(),
+                SemanticTokenKind::Class,
+                vec![Declaration]
+            ),
+            (
+                "ID".to_string(),
+                SemanticTokenKind::Type,
+                vec![DefaultLibrary]
+            ),
+            (
+                "Get".to_string(),
+                SemanticTokenKind::Function,
+                vec![Declaration]
+            ),
+            (
+                "$unused".to_string(),
+                SemanticTokenKind::Parameter,
+                vec![Declaration, Unused]
+            ),
+            (
+                "ID".to_string(),
+                SemanticTokenKind::Type,
+                vec![DefaultLibrary]
+            ),
+            ("Fields".to_string(), SemanticTokenKind::Function, vec![]),
+            (
+                "Fields".to_string(),
+                SemanticTokenKind::Function,
+                vec![Declaration]
+            ),
+            ("User".to_string(), SemanticTokenKind::Class, vec![]),
+            (
+                "Other".to_string(),
+                SemanticTokenKind::Function,
+                vec![Declaration, Unused]
+            ),
+            ("User".to_string(), SemanticTokenKind::Class, vec![]),
+        ]
+    );
+}
@@ -757,7 +757,7 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 30] {
+pub fn methods() -> [WorkspaceMethod; 42] {
     [
         workspace_method!(file_features),
         workspace_method!(update_settings),
@@ -769,6 +769,7 @@ pub fn methods() -> [WorkspaceMethod; 30] {
         workspace_method!(file_exists),
         workspace_method!(is_path_ignored),
         workspace_method!(update_module_graph),
+        workspace_method!(get_dependent_packages),
         workspace_method!(get_syntax_tree),
         workspace_method!(check_file_size),
         workspace_method!(get_file_content),
@@ -786,6 +787,17 @@ pub fn methods() -> [WorkspaceMethod; 30] {
         workspace_method!(format_on_type),
         workspace_method!(fix_file),
         workspace_method!(rename),
+        workspace_method!(prepare_rename),
+        workspace_method!(get_hover),
+        workspace_method!(get_completions),
+        workspace_method!(get_definition),
+        workspace_method!(get_references),
+        workspace_method!(get_document_symbols),
+        workspace_method!(get_workspace_symbols),
+        workspace_method!(get_folding_ranges),
+        workspace_method!(get_semantic_tokens),
+        workspace_method!(get_inlay_hints),
+        workspace_method!(get_project_files),
         workspace_method!(parse_pattern),
         workspace_method!(search_pattern),
         workspace_method!(drop_pattern),
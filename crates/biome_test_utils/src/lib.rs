@@ -15,6 +15,7 @@ use biome_fs::{BiomePath, FileSystem, OsFileSystem};
 use biome_js_parser::{AnyJsRoot, JsFileSource, JsParserOptions};
 use biome_js_type_info::{TypeData, TypeResolver};
 use biome_json_parser::ParseDiagnostic;
+use biome_json_schema::{JsonSchema, is_local_schema_reference};
 use biome_module_graph::ModuleGraph;
 use biome_package::{Manifest, PackageJson, TsConfigJson, TurboJson};
 use biome_project_layout::ProjectLayout;
@@ -120,6 +121,20 @@ pub fn load_configuration_source(
     Some((configuration, extended_configurations))
 }
 
+/// Loads the JSON Schema referenced by a test file, resolved relative to the
+/// directory of the test file.
+pub fn load_json_schema(input_file: &Utf8Path, reference: &str) -> Option<Arc<JsonSchema>> {
+    if !is_local_schema_reference(reference) {
+        return None;
+    }
+    let path = input_file.parent()?.join(reference);
+    let content = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("failed to read {path:?}: {err:?}"));
+    let schema =
+        JsonSchema::parse(&content).unwrap_or_else(|err| panic!("invalid schema {path:?}: {err}"));
+    Some(Arc::new(schema))
+}
+
 /// It loads `<input_file>.options.json`
 pub fn load_configuration_for_test_file(
     input_file: &Utf8Path,
@@ -55,6 +55,9 @@ fn main() -> io::Result<()> {
                 AnyJsDeclaration::TsModuleDeclaration(decl) => {
                     AnyJsStatement::TsModuleDeclaration(decl)
                 }
+                AnyJsDeclaration::TsOpaqueTypeAliasDeclaration(decl) => {
+                    AnyJsStatement::TsOpaqueTypeAliasDeclaration(decl)
+                }
                 AnyJsDeclaration::TsTypeAliasDeclaration(decl) => {
                     AnyJsStatement::TsTypeAliasDeclaration(decl)
                 }
@@ -66,7 +69,12 @@ fn main() -> io::Result<()> {
 
     // Wasm-bindgen will paste the generated TS code as-is into the final .d.ts file,
     // ensure it looks good by running it through the formatter
-    let formatted = format_node(JsFormatOptions::new(JsFileSource::ts()), module.syntax()).unwrap();
+    let formatted = format_node(
+        JsFormatOptions::new(JsFileSource::ts()),
+        module.syntax(),
+        false,
+    )
+    .unwrap();
     let printed = formatted.print().unwrap();
     let definitions = printed.into_code();
 
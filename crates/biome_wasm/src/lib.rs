@@ -5,12 +5,14 @@ use wasm_bindgen::prelude::*;
 
 use biome_service::workspace::{
     self, ChangeFileParams, CloseFileParams, DropPatternParams, FileExitsParams, FixFileParams,
-    FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams,
-    GetFileContentParams, GetFormatterIRParams, GetModuleGraphParams, GetRegisteredTypesParams,
-    GetSemanticModelParams, GetSyntaxTreeParams, GetTypeInfoParams, OpenProjectParams,
-    ParsePatternParams, PathIsIgnoredParams, PullActionsParams, PullDiagnosticsParams,
-    RenameParams, ScanProjectParams, SearchPatternParams, UpdateModuleGraphParams,
-    UpdateSettingsParams,
+    FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetCompletionsParams,
+    GetControlFlowGraphParams, GetDefinitionParams, GetDocumentSymbolsParams, GetFileContentParams,
+    GetFoldingRangesParams, GetFormatterIRParams, GetHoverParams, GetInlayHintsParams,
+    GetModuleGraphParams, GetProjectFilesParams, GetReferencesParams, GetRegisteredTypesParams,
+    GetSemanticModelParams, GetSemanticTokensParams, GetSyntaxTreeParams, GetTypeInfoParams,
+    GetWorkspaceSymbolsParams, OpenProjectParams, ParsePatternParams, PathIsIgnoredParams,
+    PrepareRenameParams, PullActionsParams, PullDiagnosticsParams, RenameParams, ScanProjectParams,
+    SearchPatternParams, UpdateModuleGraphParams, UpdateSettingsParams,
 };
 use biome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 use camino::{Utf8Path, Utf8PathBuf};
@@ -342,6 +344,152 @@ impl Workspace {
             .map(IRenameResult::from)
             .map_err(into_error)
     }
+
+    #[wasm_bindgen(js_name = prepareRename)]
+    pub fn prepare_rename(
+        &self,
+        params: IPrepareRenameParams,
+    ) -> Result<IPrepareRenameResult, Error> {
+        let params: PrepareRenameParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.prepare_rename(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IPrepareRenameResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getHover)]
+    pub fn get_hover(&self, params: IGetHoverParams) -> Result<IGetHoverResult, Error> {
+        let params: GetHoverParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_hover(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetHoverResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getCompletions)]
+    pub fn get_completions(
+        &self,
+        params: IGetCompletionsParams,
+    ) -> Result<IGetCompletionsResult, Error> {
+        let params: GetCompletionsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_completions(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetCompletionsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getDefinition)]
+    pub fn get_definition(
+        &self,
+        params: IGetDefinitionParams,
+    ) -> Result<IGetDefinitionResult, Error> {
+        let params: GetDefinitionParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_definition(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetDefinitionResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getReferences)]
+    pub fn get_references(
+        &self,
+        params: IGetReferencesParams,
+    ) -> Result<IGetReferencesResult, Error> {
+        let params: GetReferencesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_references(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetReferencesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getDocumentSymbols)]
+    pub fn get_document_symbols(
+        &self,
+        params: IGetDocumentSymbolsParams,
+    ) -> Result<IGetDocumentSymbolsResult, Error> {
+        let params: GetDocumentSymbolsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_document_symbols(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGetDocumentSymbolsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getWorkspaceSymbols)]
+    pub fn get_workspace_symbols(
+        &self,
+        params: IGetWorkspaceSymbolsParams,
+    ) -> Result<IGetWorkspaceSymbolsResult, Error> {
+        let params: GetWorkspaceSymbolsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_workspace_symbols(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGetWorkspaceSymbolsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getFoldingRanges)]
+    pub fn get_folding_ranges(
+        &self,
+        params: IGetFoldingRangesParams,
+    ) -> Result<IGetFoldingRangesResult, Error> {
+        let params: GetFoldingRangesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_folding_ranges(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetFoldingRangesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getSemanticTokens)]
+    pub fn get_semantic_tokens(
+        &self,
+        params: IGetSemanticTokensParams,
+    ) -> Result<IGetSemanticTokensResult, Error> {
+        let params: GetSemanticTokensParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_semantic_tokens(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetSemanticTokensResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getInlayHints)]
+    pub fn get_inlay_hints(
+        &self,
+        params: IGetInlayHintsParams,
+    ) -> Result<IGetInlayHintsResult, Error> {
+        let params: GetInlayHintsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_inlay_hints(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetInlayHintsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getProjectFiles)]
+    pub fn get_project_files(
+        &self,
+        params: IGetProjectFilesParams,
+    ) -> Result<IGetProjectFilesResult, Error> {
+        let params: GetProjectFilesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_project_files(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetProjectFilesResult::from)
+            .map_err(into_error)
+    }
 }
 
 impl Default for Workspace {
@@ -0,0 +1,122 @@
+use biome_analyze::{Ast, Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_rowan::{AstNode, TokenText};
+use biome_rule_options::no_ambiguous_boolean_values::NoAmbiguousBooleanValuesOptions;
+use biome_yaml_syntax::{
+    AnyYamlFlowMapEntry, AnyYamlPropertiesCombination, YamlBlockMapImplicitEntry,
+    YamlFlowYamlNode, YamlPlainScalar,
+};
+
+declare_lint_rule! {
+    /// Disallow plain scalars that are booleans only in YAML 1.1.
+    ///
+    /// YAML 1.1 treats values such as `yes`, `no`, `on`, `off`, `y` and `n` as booleans,
+    /// while YAML 1.2 treats them as strings. Depending on the parser that reads the file,
+    /// `country: NO` is either the string `"NO"` or the boolean `false`.
+    ///
+    /// Use `true` or `false` when the value is a boolean, and quote the value when it's a string.
+    ///
+    /// Mapping keys aren't checked, so that keys such as `on` in GitHub Actions workflows
+    /// aren't reported. Values with an explicit tag, e.g. `!!str yes`, aren't reported either.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// enabled: yes
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// countries:
+    ///   - NO
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// enabled: true
+    /// countries:
+    ///   - "NO"
+    /// on: push
+    /// ```
+    ///
+    pub NoAmbiguousBooleanValues {
+        version: "next",
+        name: "noAmbiguousBooleanValues",
+        language: "yaml",
+        recommended: false,
+    }
+}
+
+/// Plain scalars that are booleans in YAML 1.1, but strings in YAML 1.2.
+///
+/// See <https://yaml.org/type/bool.html>
+const YAML_1_1_BOOLEANS: &[&str] = &[
+    "N", "NO", "No", "OFF", "ON", "Off", "On", "Y", "YES", "Yes", "n", "no", "off", "on", "y",
+    "yes",
+];
+
+impl Rule for NoAmbiguousBooleanValues {
+    type Query = Ast<YamlPlainScalar>;
+    type State = TokenText;
+    type Signals = Option<Self::State>;
+    type Options = NoAmbiguousBooleanValuesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let scalar = ctx.query();
+        let text = scalar.inner_string_text().ok()?;
+        if YAML_1_1_BOOLEANS.binary_search(&text.text()).is_err() {
+            return None;
+        }
+
+        let node = scalar.parent::<YamlFlowYamlNode>()?;
+        if has_tag(&node) || is_mapping_key(&node) {
+            return None;
+        }
+
+        Some(text)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, text: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The value "<Emphasis>{text.text()}</Emphasis>" is a boolean in YAML 1.1, but a string in YAML 1.2."
+                },
+            )
+            .note(markup! {
+                "Parsers don't agree on the type of this value. Use "<Emphasis>"true"</Emphasis>" or "<Emphasis>"false"</Emphasis>" for a boolean, or quote the value for a string."
+            }),
+        )
+    }
+}
+
+fn has_tag(node: &YamlFlowYamlNode) -> bool {
+    node.properties().is_some_and(|properties| match properties {
+        AnyYamlPropertiesCombination::YamlPropertiesAnchorFirst(properties) => {
+            properties.tag().is_some()
+        }
+        AnyYamlPropertiesCombination::YamlPropertiesTagFirst(_) => true,
+    })
+}
+
+fn is_mapping_key(node: &YamlFlowYamlNode) -> bool {
+    let Some(parent) = node.syntax().parent() else {
+        return false;
+    };
+    let key = if let Some(entry) = YamlBlockMapImplicitEntry::cast_ref(&parent) {
+        entry.key()
+    } else if let Some(entry) = AnyYamlFlowMapEntry::cast(parent) {
+        match entry {
+            AnyYamlFlowMapEntry::YamlFlowMapExplicitEntry(entry) => entry.key(),
+            AnyYamlFlowMapEntry::YamlFlowMapImplicitEntry(entry) => entry.key(),
+        }
+    } else {
+        None
+    };
+
+    key.is_some_and(|key| key.syntax() == node.syntax())
+}
@@ -0,0 +1,108 @@
+use biome_analyze::{Ast, Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_rowan::{AstNode, TextRange, TokenText};
+use biome_rule_options::no_duplicate_mapping_keys::NoDuplicateMappingKeysOptions;
+use biome_yaml_syntax::AnyYamlMapping;
+use rustc_hash::FxHashMap;
+
+declare_lint_rule! {
+    /// Disallow duplicate keys in a mapping.
+    ///
+    /// The YAML specification requires the keys of a mapping to be unique.
+    /// Most YAML parsers don't report duplicate keys: they silently keep the value of the
+    /// last one, which usually hides a mistake.
+    ///
+    /// Keys are compared by their content, so `key`, `'key'` and `"key"` are the same key.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// name: biome
+    /// version: 1
+    /// name: other
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// { a: 1, "a": 2 }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// name: biome
+    /// nested:
+    ///   name: other
+    /// ```
+    ///
+    pub NoDuplicateMappingKeys {
+        version: "next",
+        name: "noDuplicateMappingKeys",
+        language: "yaml",
+        recommended: false,
+    }
+}
+
+pub struct DuplicateKey {
+    name: TokenText,
+    first_range: TextRange,
+    range: TextRange,
+}
+
+impl Rule for NoDuplicateMappingKeys {
+    type Query = Ast<AnyYamlMapping>;
+    type State = DuplicateKey;
+    type Signals = Box<[Self::State]>;
+    type Options = NoDuplicateMappingKeysOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mapping = ctx.query();
+        let mut seen: FxHashMap<TokenText, TextRange> = FxHashMap::default();
+        let mut duplicates = Vec::new();
+
+        for key in mapping.keys() {
+            let Some(name) = key.inner_string_text() else {
+                continue;
+            };
+            let range = key.range();
+            if let Some(first_range) = seen.get(&name) {
+                duplicates.push(DuplicateKey {
+                    name,
+                    first_range: *first_range,
+                    range,
+                });
+            } else {
+                seen.insert(name, range);
+            }
+        }
+
+        duplicates.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let DuplicateKey {
+            name,
+            first_range,
+            range,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The key "<Emphasis>{name.text()}</Emphasis>" is declared more than once in this mapping."
+                },
+            )
+            .detail(
+                first_range,
+                markup! {
+                    "The key is first declared here."
+                },
+            )
+            .note(markup! {
+                "Most YAML parsers only keep the value of the last key. Remove or rename the duplicate key."
+            }),
+        )
+    }
+}
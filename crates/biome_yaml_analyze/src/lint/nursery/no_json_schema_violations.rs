@@ -0,0 +1,83 @@
+use crate::Schema;
+use biome_analyze::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_json_schema::SchemaViolation;
+use biome_rule_options::no_json_schema_violations::NoJsonSchemaViolationsOptions;
+use biome_yaml_syntax::YamlRoot;
+
+declare_lint_rule! {
+    /// Enforce that documents satisfy their JSON Schema.
+    ///
+    /// The schema is either referenced by a `# yaml-language-server: $schema=<path>`
+    /// comment, by a `$schema` key at the root of the document, or assigned to the
+    /// file with the `yaml.linter.schema` option of the configuration, usually in
+    /// `overrides`. Only local schemas are supported: the path is resolved
+    /// relative to the document or to the configuration file.
+    ///
+    /// Every document of the stream is validated. Values that depend on the YAML
+    /// processor, such as aliases, merge keys and custom tags, are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// Given the following `config.schema.json`:
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "type": "object",
+    ///     "required": ["name"],
+    ///     "properties": {
+    ///         "name": { "type": "string" },
+    ///         "replicas": { "type": "integer", "minimum": 1 }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,ignore
+    /// # yaml-language-server: $schema=./config.schema.json
+    /// replicas: 0
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml,ignore
+    /// # yaml-language-server: $schema=./config.schema.json
+    /// name: biome
+    /// replicas: 2
+    /// ```
+    ///
+    pub NoJsonSchemaViolations {
+        version: "next",
+        name: "noJsonSchemaViolations",
+        language: "yaml",
+        recommended: false,
+    }
+}
+
+impl Rule for NoJsonSchemaViolations {
+    type Query = Schema<YamlRoot>;
+    type State = SchemaViolation;
+    type Signals = Box<[Self::State]>;
+    type Options = NoJsonSchemaViolationsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(schema) = ctx.json_schema() else {
+            return Box::default();
+        };
+        biome_json_schema::yaml::to_instances(ctx.query())
+            .iter()
+            .flat_map(|instance| schema.validate(instance))
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            state.range,
+            markup! {
+                {state.kind.to_string()}
+            },
+        ))
+    }
+}
@@ -0,0 +1,89 @@
+use biome_analyze::{Ast, Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_rowan::{AstNode, TokenText};
+use biome_rule_options::no_undeclared_aliases::NoUndeclaredAliasesOptions;
+use biome_yaml_syntax::{YamlAliasNode, YamlAnchorProperty, YamlDocument};
+use rustc_hash::FxHashSet;
+
+declare_lint_rule! {
+    /// Disallow aliases that refer to an undeclared anchor.
+    ///
+    /// An alias (`*name`) can only refer to an anchor (`&name`) declared before it
+    /// in the same document. YAML parsers reject documents that contain an alias
+    /// to an unknown anchor.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// defaults: &defaults
+    ///   adapter: postgres
+    /// development: *default
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// first: *value
+    /// second: &value 1
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// defaults: &defaults
+    ///   adapter: postgres
+    /// development: *defaults
+    /// ```
+    ///
+    pub NoUndeclaredAliases {
+        version: "next",
+        name: "noUndeclaredAliases",
+        language: "yaml",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUndeclaredAliases {
+    type Query = Ast<YamlDocument>;
+    type State = YamlAliasNode;
+    type Signals = Box<[Self::State]>;
+    type Options = NoUndeclaredAliasesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let document = ctx.query();
+        let mut anchors: FxHashSet<TokenText> = FxHashSet::default();
+        let mut undeclared = Vec::new();
+
+        // Descendants are visited in document order, so an alias is only
+        // resolved against the anchors declared before it
+        for node in document.syntax().descendants() {
+            if let Some(anchor) = YamlAnchorProperty::cast_ref(&node) {
+                if let Ok(name) = anchor.name() {
+                    anchors.insert(name);
+                }
+            } else if let Some(alias) = YamlAliasNode::cast(node)
+                && alias.name().is_ok_and(|name| !anchors.contains(&name))
+            {
+                undeclared.push(alias);
+            }
+        }
+
+        undeclared.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, alias: &Self::State) -> Option<RuleDiagnostic> {
+        let name = alias.name().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                alias.range(),
+                markup! {
+                    "The anchor "<Emphasis>{name.text()}</Emphasis>" isn't declared before this alias."
+                },
+            )
+            .note(markup! {
+                "An alias can only refer to an anchor declared before it in the same document. Declare the anchor or fix the name of the alias."
+            }),
+        )
+    }
+}
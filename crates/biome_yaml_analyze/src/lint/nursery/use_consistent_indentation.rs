@@ -0,0 +1,184 @@
+use biome_analyze::{Ast, Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_rowan::{AstNode, AstNodeList, TextRange, declare_node_union};
+use biome_rule_options::use_consistent_indentation::UseConsistentIndentationOptions;
+use biome_yaml_syntax::{YamlBlockMapping, YamlBlockSequence, YamlSyntaxToken};
+
+declare_lint_rule! {
+    /// Require nested blocks to be indented by the same number of spaces.
+    ///
+    /// YAML uses indentation to nest mappings and sequences, and accepts any number of spaces
+    /// as long as the entries of the same block are aligned. Mixing indentation widths in the
+    /// same file makes the structure of the document hard to follow.
+    ///
+    /// A sequence that is the value of a mapping entry may also be written at the same
+    /// indentation as the key, e.g. `key:` followed by `- item`.
+    /// Blocks that start on the same line as their parent entry, such as `- name: biome`,
+    /// are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// biome:
+    ///     linter: true
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// files:
+    ///  - a.yaml
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// biome:
+    ///   linter: true
+    ///   files:
+    ///     - a.yaml
+    ///   includes:
+    ///   - b.yaml
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `indentWidth`
+    ///
+    /// The number of spaces a nested block is indented by.
+    /// Default: `2`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "indentWidth": 4
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```yaml,use_options,expect_diagnostic
+    /// biome:
+    ///   linter: true
+    /// ```
+    ///
+    pub UseConsistentIndentation {
+        version: "next",
+        name: "useConsistentIndentation",
+        language: "yaml",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyYamlBlockCollection = YamlBlockMapping | YamlBlockSequence
+}
+
+impl AnyYamlBlockCollection {
+    /// Returns the first token of the first entry of the collection.
+    fn first_entry_token(&self) -> Option<YamlSyntaxToken> {
+        match self {
+            Self::YamlBlockMapping(mapping) => mapping.entries().first()?.syntax().first_token(),
+            Self::YamlBlockSequence(sequence) => {
+                sequence.entries().first()?.syntax().first_token()
+            }
+        }
+    }
+}
+
+pub struct InconsistentIndentation {
+    range: TextRange,
+    indentation: u32,
+}
+
+impl Rule for UseConsistentIndentation {
+    type Query = Ast<AnyYamlBlockCollection>;
+    type State = InconsistentIndentation;
+    type Signals = Option<Self::State>;
+    type Options = UseConsistentIndentationOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let collection = ctx.query();
+        let token = collection.first_entry_token()?;
+        let (column, starts_line) = column_of(&token);
+        if !starts_line {
+            return None;
+        }
+
+        let parent = collection
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .find_map(AnyYamlBlockCollection::cast)?;
+        let (parent_column, _) = column_of(&parent.first_entry_token()?);
+        let indentation = column.saturating_sub(parent_column);
+
+        let is_indentless_sequence = indentation == 0
+            && matches!(collection, AnyYamlBlockCollection::YamlBlockSequence(_))
+            && matches!(parent, AnyYamlBlockCollection::YamlBlockMapping(_));
+        if is_indentless_sequence || indentation == u32::from(ctx.options().indent_width()) {
+            return None;
+        }
+
+        Some(InconsistentIndentation {
+            range: token.text_trimmed_range(),
+            indentation,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let InconsistentIndentation { range, indentation } = state;
+        let indent_width = ctx.options().indent_width();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This block should be indented by "{indent_width}" spaces, but it's indented by "{indentation}"."
+                },
+            )
+            .note(markup! {
+                "The indentation of a nested block is counted from the entries of its parent block."
+            }),
+        )
+    }
+}
+
+/// Returns the column where `token` starts, and whether only whitespace precedes it on its line.
+fn column_of(token: &YamlSyntaxToken) -> (u32, bool) {
+    let mut column = 0;
+    let mut starts_line = true;
+    let mut token = token.clone();
+
+    loop {
+        for piece in token.leading_trivia().pieces().rev() {
+            if piece.is_newline() {
+                return (column, starts_line);
+            }
+            column += piece.text().chars().count() as u32;
+            starts_line &= piece.is_whitespace();
+        }
+
+        let Some(previous) = token.prev_token() else {
+            return (column, starts_line);
+        };
+        for piece in previous.trailing_trivia().pieces().rev() {
+            if piece.is_newline() {
+                return (column, starts_line);
+            }
+            column += piece.text().chars().count() as u32;
+            starts_line &= piece.is_whitespace();
+        }
+
+        // Multiline scalars contain newlines in the text of their token
+        let text = previous.text_trimmed();
+        if let Some((_, last_line)) = text.rsplit_once('\n') {
+            return (column + last_line.chars().count() as u32, false);
+        }
+        if !text.is_empty() {
+            column += text.chars().count() as u32;
+            starts_line = false;
+        }
+
+        token = previous;
+    }
+}
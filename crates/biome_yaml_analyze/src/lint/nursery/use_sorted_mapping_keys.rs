@@ -0,0 +1,126 @@
+use biome_analyze::{Ast, Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_rowan::{AstNode, TextRange};
+use biome_rule_options::use_sorted_mapping_keys::{SortOrder, UseSortedMappingKeysOptions};
+use biome_string_case::comparable_token::ComparableToken;
+use biome_yaml_syntax::AnyYamlMapping;
+use std::cmp::Ordering;
+
+declare_lint_rule! {
+    /// Require the keys of a mapping to be sorted.
+    ///
+    /// Sorted keys make large configuration files easier to scan and reduce merge conflicts.
+    /// Only keys that are scalars are compared: keys that are collections are ignored.
+    ///
+    /// [Natural order](https://en.wikipedia.org/wiki/Natural_sort_order) is used by default:
+    /// uppercase letters come before lowercase letters (e.g. `A` < `a` < `B` < `b`)
+    /// and numbers are compared in a human way (e.g. `9` < `10`).
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// name: biome
+    /// license: MIT
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// { b: 1, a: 2 }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// license: MIT
+    /// name: biome
+    /// version10: 10
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `sortOrder`
+    ///
+    /// The order used to compare the keys, either `natural` or `lexicographic`.
+    /// Default: `natural`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "sortOrder": "lexicographic"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```yaml,use_options,expect_diagnostic
+    /// val2: 2
+    /// val10: 10
+    /// ```
+    ///
+    pub UseSortedMappingKeys {
+        version: "next",
+        name: "useSortedMappingKeys",
+        language: "yaml",
+        recommended: false,
+    }
+}
+
+pub struct UnsortedKey {
+    key: ComparableToken,
+    previous_key: ComparableToken,
+    range: TextRange,
+}
+
+impl Rule for UseSortedMappingKeys {
+    type Query = Ast<AnyYamlMapping>;
+    type State = UnsortedKey;
+    type Signals = Option<Self::State>;
+    type Options = UseSortedMappingKeysOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let comparator = match ctx.options().sort_order.unwrap_or_default() {
+            SortOrder::Natural => ComparableToken::ascii_nat_cmp,
+            SortOrder::Lexicographic => ComparableToken::lexicographic_cmp,
+        };
+
+        let mut previous_key: Option<ComparableToken> = None;
+        for key in ctx.query().keys() {
+            let Some(name) = key.inner_string_text() else {
+                continue;
+            };
+            let name = ComparableToken::new(name);
+            if let Some(previous_key) = previous_key.take()
+                && comparator(&previous_key, &name) == Ordering::Greater
+            {
+                return Some(UnsortedKey {
+                    key: name,
+                    previous_key,
+                    range: key.range(),
+                });
+            }
+            previous_key = Some(name);
+        }
+
+        None
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnsortedKey {
+            key,
+            previous_key,
+            range,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The keys of this mapping aren't sorted."
+                },
+            )
+            .note(markup! {
+                "The key "<Emphasis>{key.as_ref()}</Emphasis>" should come before "<Emphasis>{previous_key.as_ref()}</Emphasis>"."
+            }),
+        )
+    }
+}
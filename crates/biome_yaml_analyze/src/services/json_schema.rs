@@ -0,0 +1,65 @@
+use biome_analyze::{
+    AddVisitor, FromServices, Phase, Phases, QueryKey, Queryable, RuleKey, RuleMetadata,
+    ServiceBag, ServicesDiagnostic, SyntaxVisitor,
+};
+use biome_json_schema::JsonSchema;
+use biome_rowan::AstNode;
+use biome_yaml_syntax::{YamlLanguage, YamlRoot, YamlSyntaxNode};
+use std::sync::Arc;
+
+#[derive(Debug, Default)]
+pub struct JsonSchemaService(Option<Arc<JsonSchema>>);
+
+impl JsonSchemaService {
+    /// The JSON Schema the document must satisfy, if any.
+    pub(crate) fn json_schema(&self) -> Option<&JsonSchema> {
+        self.0.as_deref()
+    }
+}
+
+impl FromServices for JsonSchemaService {
+    fn from_services(
+        rule_key: &RuleKey,
+        _rule_metadata: &RuleMetadata,
+        services: &ServiceBag,
+    ) -> Result<Self, ServicesDiagnostic> {
+        let schema: &Option<Arc<JsonSchema>> = services
+            .get_service()
+            .ok_or_else(|| ServicesDiagnostic::new(rule_key.rule_name(), &["JsonSchema"]))?;
+
+        Ok(Self(schema.clone()))
+    }
+}
+
+impl Phase for JsonSchemaService {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the JSON Schema of the document** and match on specific [AstNode] types.
+#[derive(Clone)]
+pub struct Schema<N>(pub N);
+
+impl<N> Queryable for Schema<N>
+where
+    N: AstNode<Language = YamlLanguage> + 'static,
+{
+    type Input = YamlSyntaxNode;
+    type Output = N;
+
+    type Language = YamlLanguage;
+    type Services = JsonSchemaService;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<YamlLanguage>, _: &YamlRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
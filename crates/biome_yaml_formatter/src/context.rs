@@ -0,0 +1,299 @@
+use crate::comments::{FormatYamlLeadingComment, YamlCommentStyle, YamlComments};
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_formatter::printer::PrinterOptions;
+use biome_formatter::{
+    CstFormatContext, FormatContext, FormatOptions, IndentStyle, IndentWidth, LineEnding,
+    LineWidth, QuoteStyle, TransformSourceMap,
+};
+use biome_yaml_syntax::{YamlFileSource, YamlLanguage};
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct YamlFormatContext {
+    options: YamlFormatOptions,
+    /// The comments of the nodes and tokens in the document.
+    comments: Rc<YamlComments>,
+    source_map: Option<TransformSourceMap>,
+}
+
+impl YamlFormatContext {
+    pub fn new(options: YamlFormatOptions, comments: YamlComments) -> Self {
+        Self {
+            options,
+            comments: Rc::new(comments),
+            source_map: None,
+        }
+    }
+
+    pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
+        self.source_map = source_map;
+        self
+    }
+}
+
+impl FormatContext for YamlFormatContext {
+    type Options = YamlFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_map(&self) -> Option<&TransformSourceMap> {
+        None
+    }
+}
+
+impl CstFormatContext for YamlFormatContext {
+    type Language = YamlLanguage;
+    type Style = YamlCommentStyle;
+    type CommentRule = FormatYamlLeadingComment;
+
+    fn comments(&self) -> &YamlComments {
+        &self.comments
+    }
+}
+
+/// The options of the YAML formatter.
+///
+/// YAML doesn't allow tabs for indentation, so there's no indent style: the
+/// formatter always indents with spaces.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct YamlFormatOptions {
+    indent_width: IndentWidth,
+    line_ending: LineEnding,
+    line_width: LineWidth,
+    quote_style: QuoteStyle,
+    /// Whether block sequences nested in a mapping are indented. Default is `true`.
+    indent_sequences: IndentSequences,
+    /// Whether comments are kept in the formatted output. Default is `true`.
+    preserve_comments: PreserveComments,
+    _file_source: YamlFileSource,
+}
+
+impl YamlFormatOptions {
+    pub fn new(file_source: YamlFileSource) -> Self {
+        Self {
+            _file_source: file_source,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_indent_width(mut self, indent_width: IndentWidth) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    pub fn with_indent_sequences(mut self, indent_sequences: IndentSequences) -> Self {
+        self.indent_sequences = indent_sequences;
+        self
+    }
+
+    pub fn with_preserve_comments(mut self, preserve_comments: PreserveComments) -> Self {
+        self.preserve_comments = preserve_comments;
+        self
+    }
+
+    pub fn set_indent_width(&mut self, indent_width: IndentWidth) {
+        self.indent_width = indent_width;
+    }
+
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    pub fn set_line_width(&mut self, line_width: LineWidth) {
+        self.line_width = line_width;
+    }
+
+    pub fn set_quote_style(&mut self, quote_style: QuoteStyle) {
+        self.quote_style = quote_style;
+    }
+
+    pub fn set_indent_sequences(&mut self, indent_sequences: IndentSequences) {
+        self.indent_sequences = indent_sequences;
+    }
+
+    pub fn set_preserve_comments(&mut self, preserve_comments: PreserveComments) {
+        self.preserve_comments = preserve_comments;
+    }
+
+    pub fn quote_style(&self) -> QuoteStyle {
+        self.quote_style
+    }
+
+    pub fn indent_sequences(&self) -> IndentSequences {
+        self.indent_sequences
+    }
+
+    pub fn preserve_comments(&self) -> PreserveComments {
+        self.preserve_comments
+    }
+}
+
+impl FormatOptions for YamlFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        IndentStyle::Space
+    }
+
+    fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::from(self)
+    }
+}
+
+impl fmt::Display for YamlFormatOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Indent width: {}", self.indent_width.value())?;
+        writeln!(f, "Line ending: {}", self.line_ending)?;
+        writeln!(f, "Line width: {}", self.line_width.value())?;
+        writeln!(f, "Quote style: {}", self.quote_style)?;
+        writeln!(f, "Indent sequences: {}", self.indent_sequences.value())?;
+        writeln!(f, "Preserve comments: {}", self.preserve_comments.value())
+    }
+}
+
+/// Whether a block sequence that is the value of a mapping entry is indented.
+///
+/// ```yaml
+/// # true
+/// key:
+///   - item
+/// # false
+/// key:
+/// - item
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserializable, Merge)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IndentSequences(bool);
+
+impl IndentSequences {
+    pub fn new(value: bool) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl Default for IndentSequences {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl From<bool> for IndentSequences {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl FromStr for IndentSequences {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match bool::from_str(s) {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(
+                "Value not supported for IndentSequences. Supported values are 'true' and 'false'.",
+            ),
+        }
+    }
+}
+
+impl Deref for IndentSequences {
+    type Target = bool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Whether the comments of the document are kept in the formatted output.
+///
+/// When false, the formatter removes every comment from the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserializable, Merge)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PreserveComments(bool);
+
+impl PreserveComments {
+    pub fn new(value: bool) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl Default for PreserveComments {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl From<bool> for PreserveComments {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl FromStr for PreserveComments {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match bool::from_str(s) {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(
+                "Value not supported for PreserveComments. Supported values are 'true' and 'false'.",
+            ),
+        }
+    }
+}
+
+impl Deref for PreserveComments {
+    type Target = bool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,117 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_yaml_syntax::{AnyYamlPropertiesCombination, YamlSyntaxKind, YamlSyntaxNode};
+
+/// Where the entries of a block mapping or a block sequence start, relative to the
+/// node that contains the collection.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum BlockCollectionLayout {
+    /// The collection is the content of a document: the entries start at the
+    /// current indentation.
+    ///
+    /// ```yaml
+    /// a: b
+    /// c: d
+    /// ```
+    Root,
+
+    /// The collection is the value of an implicit mapping entry: the entries
+    /// start on the line after the key.
+    ///
+    /// ```yaml
+    /// key:
+    ///   a: b
+    ///   c: d
+    /// ```
+    Nested,
+
+    /// The collection follows an indicator (`-`, `?` or `:`) and the first entry
+    /// starts on the same line. The parent aligns the following entries with the
+    /// first one.
+    ///
+    /// ```yaml
+    /// - a: b
+    ///   c: d
+    /// ```
+    Compact,
+}
+
+impl BlockCollectionLayout {
+    pub(crate) fn from_node(node: &YamlSyntaxNode) -> Self {
+        match node.parent().map(|parent| parent.kind()) {
+            Some(YamlSyntaxKind::YAML_BLOCK_MAP_IMPLICIT_ENTRY) => Self::Nested,
+            Some(
+                YamlSyntaxKind::YAML_BLOCK_SEQUENCE_ENTRY
+                | YamlSyntaxKind::YAML_BLOCK_MAP_EXPLICIT_ENTRY,
+            ) => Self::Compact,
+            _ => Self::Root,
+        }
+    }
+}
+
+/// Formats the properties and the entries of a block mapping or a block sequence
+/// according to its [BlockCollectionLayout].
+///
+/// The leading comments of the collection are printed right before its first
+/// entry, so that they end up at the indentation of the entries.
+pub(crate) struct FormatBlockCollection<'a> {
+    node: &'a YamlSyntaxNode,
+    properties: Option<AnyYamlPropertiesCombination>,
+    entries: &'a dyn Format<YamlFormatContext>,
+    indent_nested: bool,
+}
+
+impl<'a> FormatBlockCollection<'a> {
+    pub(crate) fn new(
+        node: &'a YamlSyntaxNode,
+        properties: Option<AnyYamlPropertiesCombination>,
+        entries: &'a dyn Format<YamlFormatContext>,
+    ) -> Self {
+        Self {
+            node,
+            properties,
+            entries,
+            indent_nested: true,
+        }
+    }
+
+    /// Whether the entries of a [BlockCollectionLayout::Nested] collection are indented.
+    pub(crate) fn with_indent_nested(mut self, indent_nested: bool) -> Self {
+        self.indent_nested = indent_nested;
+        self
+    }
+}
+
+impl Format<YamlFormatContext> for FormatBlockCollection<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let entries =
+            format_with(|f| write!(f, [format_leading_comments(self.node), self.entries]));
+
+        match BlockCollectionLayout::from_node(self.node) {
+            BlockCollectionLayout::Root => {
+                write!(f, [format_leading_comments(self.node)])?;
+                if let Some(properties) = &self.properties {
+                    write!(f, [properties.format(), hard_line_break()])?;
+                }
+                write!(f, [self.entries])
+            }
+            BlockCollectionLayout::Compact => {
+                if let Some(properties) = &self.properties {
+                    write!(f, [properties.format(), hard_line_break()])?;
+                }
+                write!(f, [entries])
+            }
+            BlockCollectionLayout::Nested => {
+                if let Some(properties) = &self.properties {
+                    write!(f, [space(), properties.format()])?;
+                }
+
+                if self.indent_nested {
+                    write!(f, [indent(&format_args![hard_line_break(), entries])])
+                } else {
+                    write!(f, [hard_line_break(), entries])
+                }
+            }
+        }
+    }
+}
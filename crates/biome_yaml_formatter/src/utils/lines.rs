@@ -0,0 +1,116 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_rowan::{TextLen, TextRange, TextSize};
+use biome_yaml_syntax::YamlSyntaxToken;
+
+/// Writes a line break followed by `empty_lines` empty lines.
+///
+/// YAML scalars can span multiple lines and their empty lines are part of the
+/// content, so they can't be collapsed like the empty lines between entries.
+/// The empty lines are printed at the root indentation to avoid leaving
+/// trailing whitespace in the document.
+pub(crate) struct FormatLineBreaks {
+    empty_lines: usize,
+    position: TextSize,
+}
+
+impl FormatLineBreaks {
+    pub(crate) fn new(empty_lines: usize, position: TextSize) -> Self {
+        Self {
+            empty_lines,
+            position,
+        }
+    }
+}
+
+impl Format<YamlFormatContext> for FormatLineBreaks {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        if self.empty_lines == 0 {
+            return write!(f, [hard_line_break()]);
+        }
+
+        write!(
+            f,
+            [
+                dedent_to_root(&format_with(|f| {
+                    write!(f, [hard_line_break()])?;
+                    for _ in 0..self.empty_lines {
+                        write!(f, [text("\n", self.position)])?;
+                    }
+                    Ok(())
+                })),
+                hard_line_break()
+            ]
+        )
+    }
+}
+
+/// Formats the text of a plain or quoted scalar token.
+///
+/// Multi-line flow scalars are folded by YAML: the indentation of the
+/// continuation lines isn't part of the value. The formatter re-indents them
+/// relative to the enclosing node and keeps their empty lines, which stand for
+/// line feeds.
+pub(crate) struct FormatFlowScalarLines<'a> {
+    token: &'a YamlSyntaxToken,
+}
+
+impl<'a> FormatFlowScalarLines<'a> {
+    pub(crate) fn new(token: &'a YamlSyntaxToken) -> Self {
+        Self { token }
+    }
+}
+
+impl Format<YamlFormatContext> for FormatFlowScalarLines<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let token = self.token;
+        let mut offset = token.text_trimmed_range().start();
+        let mut empty_lines = 0;
+
+        for (index, line) in token.text_trimmed().split('\n').enumerate() {
+            let line_start = offset;
+            offset += line.text_len() + TextSize::from(1);
+
+            let (content_start, content) = if index == 0 {
+                (line_start, trim_line_end(line))
+            } else {
+                let trimmed = line.trim_start_matches([' ', '\t']);
+                (
+                    line_start + (line.text_len() - trimmed.text_len()),
+                    trim_line_end(trimmed),
+                )
+            };
+
+            if index > 0 {
+                if content.is_empty() {
+                    empty_lines += 1;
+                    continue;
+                }
+                write!(f, [FormatLineBreaks::new(empty_lines, line_start)])?;
+                empty_lines = 0;
+            }
+
+            write!(
+                f,
+                [located_token_text(
+                    token,
+                    TextRange::at(content_start, content.text_len())
+                )]
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Removes the whitespace at the end of a line, unless it's escaped by a
+/// backslash in a double quoted scalar.
+fn trim_line_end(line: &str) -> &str {
+    let line = line.trim_end_matches('\r');
+    let trimmed = line.trim_end_matches([' ', '\t']);
+    if trimmed.ends_with('\\') {
+        line
+    } else {
+        trimmed
+    }
+}
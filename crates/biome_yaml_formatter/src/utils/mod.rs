@@ -0,0 +1,3 @@
+pub(crate) mod block_collection;
+pub(crate) mod lines;
+pub(crate) mod quoted;
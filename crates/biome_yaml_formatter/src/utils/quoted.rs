@@ -0,0 +1,62 @@
+use crate::prelude::*;
+use crate::utils::lines::FormatFlowScalarLines;
+use biome_formatter::{QuoteStyle, write};
+use biome_yaml_syntax::YamlSyntaxToken;
+
+/// Formats a single or double quoted scalar.
+///
+/// Single line scalars use the preferred quote when the value can be written
+/// with it without escapes. Multi-line scalars keep their quotes.
+pub(crate) struct FormatQuotedScalar<'a> {
+    token: &'a YamlSyntaxToken,
+}
+
+impl<'a> FormatQuotedScalar<'a> {
+    pub(crate) fn new(token: &'a YamlSyntaxToken) -> Self {
+        Self { token }
+    }
+}
+
+impl Format<YamlFormatContext> for FormatQuotedScalar<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let token = self.token;
+        let token_text = token.text_trimmed();
+
+        if token_text.contains('\n') || token_text.len() < 2 {
+            return write!(
+                f,
+                [format_replaced(token, &FormatFlowScalarLines::new(token))]
+            );
+        }
+
+        let quote_style = f.options().quote_style();
+        let content = &token_text[1..token_text.len() - 1];
+
+        let normalized = match quote_style {
+            QuoteStyle::Single if token_text.starts_with('"') => {
+                // Escape sequences only exist in double quoted scalars
+                (!content.contains(['\\', '\''])).then(|| std::format!("'{content}'"))
+            }
+            QuoteStyle::Double if token_text.starts_with('\'') => {
+                // `''` is an escaped single quote in a single quoted scalar
+                (!content.contains(['\\', '"']))
+                    .then(|| std::format!("\"{}\"", content.replace("''", "'")))
+            }
+            _ => None,
+        };
+
+        match normalized {
+            Some(normalized) => write!(
+                f,
+                [format_replaced(
+                    token,
+                    &text(&normalized, token.text_trimmed_range().start())
+                )]
+            ),
+            None => write!(
+                f,
+                [format_replaced(token, &FormatFlowScalarLines::new(token))]
+            ),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod block_header;
+pub(crate) mod block_in_block_node;
+pub(crate) mod block_map_entry;
+pub(crate) mod block_node;
+pub(crate) mod block_sequence_entry;
+pub(crate) mod document;
+pub(crate) mod flow_map_entry;
+pub(crate) mod flow_node;
+pub(crate) mod flow_sequence_entry;
+pub(crate) mod json_content;
+pub(crate) mod mapping_implicit_key;
+pub(crate) mod properties_combination;
@@ -0,0 +1,104 @@
+use crate::prelude::*;
+use crate::utils::lines::FormatLineBreaks;
+use biome_formatter::write;
+use biome_rowan::{TextLen, TextRange, TextSize};
+use biome_yaml_syntax::{
+    YamlBlockContent, YamlBlockContentFields, YamlSyntaxKind, YamlSyntaxToken,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockContent;
+impl FormatNodeRule<YamlBlockContent> for FormatYamlBlockContent {
+    fn fmt_fields(&self, node: &YamlBlockContent, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockContentFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        // The indentation of the content is fixed by the indicator, it can't be changed
+        if has_indentation_indicator(node) {
+            return write!(f, [format_yaml_verbatim_node(node.syntax())]);
+        }
+
+        write!(
+            f,
+            [format_replaced(
+                &value_token,
+                &FormatBlockContentLines {
+                    token: &value_token
+                }
+            )]
+        )
+    }
+}
+
+fn has_indentation_indicator(node: &YamlBlockContent) -> bool {
+    node.syntax().parent().is_some_and(|scalar| {
+        scalar
+            .children()
+            .filter(|child| child.kind() == YamlSyntaxKind::YAML_BLOCK_HEADER_LIST)
+            .flat_map(|headers| headers.children())
+            .any(|header| header.kind() == YamlSyntaxKind::YAML_INDENTATION_INDICATOR)
+    })
+}
+
+/// Prints the lines of a block scalar, relative to the current indentation.
+///
+/// The indentation of the block is detected from its least indented line and
+/// removed from every line. Anything past it, including trailing whitespace,
+/// is part of the value and is kept as is.
+struct FormatBlockContentLines<'a> {
+    token: &'a YamlSyntaxToken,
+}
+
+impl Format<YamlFormatContext> for FormatBlockContentLines<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let token = self.token;
+        let text = token.text_trimmed();
+
+        let block_indent = text
+            .split('\n')
+            .filter(|line| !is_blank(line))
+            .map(|line| line.len() - line.trim_start_matches(' ').len())
+            .min()
+            .unwrap_or_default();
+
+        let mut offset = token.text_trimmed_range().start();
+        let mut empty_lines = 0;
+
+        // The content starts with the line break that follows the block header
+        for (index, line) in text.split('\n').enumerate() {
+            let line_start = offset;
+            offset += line.text_len() + TextSize::from(1);
+
+            if index == 0 {
+                continue;
+            }
+
+            let line = line.trim_end_matches('\r');
+            if line.len() <= block_indent && is_blank(line) {
+                empty_lines += 1;
+                continue;
+            }
+
+            write!(
+                f,
+                [
+                    FormatLineBreaks::new(empty_lines, line_start),
+                    located_token_text(
+                        token,
+                        TextRange::at(
+                            line_start + TextSize::from(block_indent as u32),
+                            TextSize::from((line.len() - block_indent) as u32)
+                        )
+                    )
+                ]
+            )?;
+            empty_lines = 0;
+        }
+
+        Ok(())
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim_end_matches('\r').bytes().all(|byte| byte == b' ')
+}
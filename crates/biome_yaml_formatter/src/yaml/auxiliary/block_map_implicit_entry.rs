@@ -0,0 +1,62 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_yaml_syntax::{
+    AnyYamlBlockInBlockNode, AnyYamlBlockNode, AnyYamlFlowNode, AnyYamlJsonContent,
+    YamlBlockMapImplicitEntry, YamlBlockMapImplicitEntryFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockMapImplicitEntry;
+impl FormatNodeRule<YamlBlockMapImplicitEntry> for FormatYamlBlockMapImplicitEntry {
+    fn fmt_fields(
+        &self,
+        node: &YamlBlockMapImplicitEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlBlockMapImplicitEntryFields {
+            key,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(f, [key.format(), colon_token.format()])?;
+
+        let Some(value) = value else {
+            return Ok(());
+        };
+
+        match &value {
+            // Block collections decide where their entries start
+            AnyYamlBlockNode::AnyYamlBlockInBlockNode(
+                AnyYamlBlockInBlockNode::YamlBlockMapping(_)
+                | AnyYamlBlockInBlockNode::YamlBlockSequence(_),
+            ) => write!(f, [value.format()]),
+            // Flow collections that don't fit move to the next line
+            //
+            // ```yaml
+            // key:
+            //   [first, second]
+            // ```
+            AnyYamlBlockNode::YamlFlowInBlockNode(flow) if is_flow_collection(flow.flow()?) => {
+                write!(
+                    f,
+                    [group(&indent(&format_args![
+                        soft_line_break_or_space(),
+                        value.format()
+                    ]))]
+                )
+            }
+            _ => write!(f, [space(), indent(&value.format())]),
+        }
+    }
+}
+
+fn is_flow_collection(node: AnyYamlFlowNode) -> bool {
+    matches!(
+        node,
+        AnyYamlFlowNode::YamlFlowJsonNode(node) if matches!(
+            node.content(),
+            Some(AnyYamlJsonContent::YamlFlowMapping(_) | AnyYamlJsonContent::YamlFlowSequence(_))
+        )
+    )
+}
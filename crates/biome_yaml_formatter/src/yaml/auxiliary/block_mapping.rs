@@ -0,0 +1,35 @@
+use crate::prelude::*;
+use crate::utils::block_collection::FormatBlockCollection;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockMapping, YamlBlockMappingFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockMapping;
+impl FormatNodeRule<YamlBlockMapping> for FormatYamlBlockMapping {
+    fn fmt_fields(&self, node: &YamlBlockMapping, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockMappingFields {
+            mapping_start_token,
+            properties,
+            entries,
+            mapping_end_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                mapping_start_token.format(),
+                FormatBlockCollection::new(node.syntax(), properties, &entries.format()),
+                mapping_end_token.format()
+            ]
+        )
+    }
+
+    fn fmt_leading_comments(
+        &self,
+        _: &YamlBlockMapping,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted inside of `fmt_fields`
+        Ok(())
+    }
+}
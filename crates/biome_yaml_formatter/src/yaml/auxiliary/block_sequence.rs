@@ -0,0 +1,38 @@
+use crate::prelude::*;
+use crate::utils::block_collection::FormatBlockCollection;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockSequence, YamlBlockSequenceFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockSequence;
+impl FormatNodeRule<YamlBlockSequence> for FormatYamlBlockSequence {
+    fn fmt_fields(&self, node: &YamlBlockSequence, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockSequenceFields {
+            sequence_start_token,
+            properties,
+            entries,
+            sequence_end_token,
+        } = node.as_fields();
+
+        let indent_sequences = f.options().indent_sequences().value();
+
+        write!(
+            f,
+            [
+                sequence_start_token.format(),
+                FormatBlockCollection::new(node.syntax(), properties, &entries.format())
+                    .with_indent_nested(indent_sequences),
+                sequence_end_token.format()
+            ]
+        )
+    }
+
+    fn fmt_leading_comments(
+        &self,
+        _: &YamlBlockSequence,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted inside of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlDirective, YamlDirectiveFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDirective;
+impl FormatNodeRule<YamlDirective> for FormatYamlDirective {
+    fn fmt_fields(&self, node: &YamlDirective, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlDirectiveFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
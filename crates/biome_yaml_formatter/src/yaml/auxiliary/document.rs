@@ -0,0 +1,58 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{
+    AnyYamlBlockInBlockNode, AnyYamlBlockNode, YamlDocument, YamlDocumentFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDocument;
+impl FormatNodeRule<YamlDocument> for FormatYamlDocument {
+    fn fmt_fields(&self, node: &YamlDocument, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlDocumentFields {
+            bom_token,
+            directives,
+            dashdashdash_token,
+            node,
+            dotdotdot_token,
+        } = node.as_fields();
+
+        write!(f, [bom_token.format(), directives.format()])?;
+
+        if !directives.is_empty() {
+            write!(f, [hard_line_break()])?;
+        }
+
+        if let Some(dashdashdash_token) = dashdashdash_token {
+            write!(f, [dashdashdash_token.format()])?;
+
+            match &node {
+                Some(AnyYamlBlockNode::AnyYamlBlockInBlockNode(
+                    AnyYamlBlockInBlockNode::YamlBlockMapping(_)
+                    | AnyYamlBlockInBlockNode::YamlBlockSequence(_),
+                )) => write!(f, [hard_line_break()])?,
+                Some(_) => write!(f, [space()])?,
+                None => {}
+            }
+        }
+
+        match &node {
+            // The content of a block scalar must be indented when it's the
+            // content of the document, otherwise it could be confused with a
+            // document marker.
+            Some(
+                node @ AnyYamlBlockNode::AnyYamlBlockInBlockNode(
+                    AnyYamlBlockInBlockNode::YamlLiteralScalar(_)
+                    | AnyYamlBlockInBlockNode::YamlFoldedScalar(_),
+                ),
+            ) => write!(f, [indent(&node.format())])?,
+            Some(node) => write!(f, [node.format()])?,
+            None => {}
+        }
+
+        if let Some(dotdotdot_token) = dotdotdot_token {
+            write!(f, [hard_line_break(), dotdotdot_token.format()])?;
+        }
+
+        Ok(())
+    }
+}
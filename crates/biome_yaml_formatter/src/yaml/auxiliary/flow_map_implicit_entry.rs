@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlFlowMapImplicitEntry, YamlFlowMapImplicitEntryFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowMapImplicitEntry;
+impl FormatNodeRule<YamlFlowMapImplicitEntry> for FormatYamlFlowMapImplicitEntry {
+    fn fmt_fields(
+        &self,
+        node: &YamlFlowMapImplicitEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlFlowMapImplicitEntryFields {
+            key,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(f, [key.format(), colon_token.format()])?;
+
+        if let Some(value) = value {
+            write!(f, [space(), value.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlFlowMapping, YamlFlowMappingFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowMapping;
+impl FormatNodeRule<YamlFlowMapping> for FormatYamlFlowMapping {
+    fn fmt_fields(&self, node: &YamlFlowMapping, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFlowMappingFields {
+            l_curly_token,
+            entries,
+            r_curly_token,
+        } = node.as_fields();
+
+        let should_insert_space = !entries.is_empty();
+
+        write!(
+            f,
+            [
+                l_curly_token.format(),
+                group(&soft_block_indent_with_maybe_space(
+                    &entries.format(),
+                    should_insert_space
+                )),
+                r_curly_token.format()
+            ]
+        )
+    }
+}
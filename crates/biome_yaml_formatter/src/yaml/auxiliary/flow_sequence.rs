@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlFlowSequence, YamlFlowSequenceFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowSequence;
+impl FormatNodeRule<YamlFlowSequence> for FormatYamlFlowSequence {
+    fn fmt_fields(&self, node: &YamlFlowSequence, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFlowSequenceFields {
+            l_brack_token,
+            entries,
+            r_brack_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_brack_token.format(),
+                group(&soft_block_indent(&entries.format())),
+                r_brack_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,8 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+#[expect(clippy::module_inception)]
+pub(crate) mod bogus;
+pub(crate) mod bogus_block_header;
+pub(crate) mod bogus_block_map_entry;
+pub(crate) mod bogus_block_node;
+pub(crate) mod bogus_flow_node;
@@ -0,0 +1,9 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod block_header_list;
+pub(crate) mod block_map_entry_list;
+pub(crate) mod block_sequence_entry_list;
+pub(crate) mod directive_list;
+pub(crate) mod document_list;
+pub(crate) mod flow_map_entry_list;
+pub(crate) mod flow_sequence_entry_list;
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlFoldedScalar, YamlFoldedScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFoldedScalar;
+impl FormatNodeRule<YamlFoldedScalar> for FormatYamlFoldedScalar {
+    fn fmt_fields(&self, node: &YamlFoldedScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFoldedScalarFields {
+            properties,
+            r_angle_token,
+            headers,
+            content,
+        } = node.as_fields();
+
+        if let Some(properties) = properties {
+            write!(f, [properties.format(), space()])?;
+        }
+
+        write!(
+            f,
+            [r_angle_token.format(), headers.format(), content.format()]
+        )
+    }
+}
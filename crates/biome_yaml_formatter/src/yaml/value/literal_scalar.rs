@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlLiteralScalar, YamlLiteralScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlLiteralScalar;
+impl FormatNodeRule<YamlLiteralScalar> for FormatYamlLiteralScalar {
+    fn fmt_fields(&self, node: &YamlLiteralScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlLiteralScalarFields {
+            properties,
+            bitwise_or_token,
+            headers,
+            content,
+        } = node.as_fields();
+
+        if let Some(properties) = properties {
+            write!(f, [properties.format(), space()])?;
+        }
+
+        write!(
+            f,
+            [
+                bitwise_or_token.format(),
+                headers.format(),
+                content.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use crate::utils::lines::FormatFlowScalarLines;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlPlainScalar, YamlPlainScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlPlainScalar;
+impl FormatNodeRule<YamlPlainScalar> for FormatYamlPlainScalar {
+    fn fmt_fields(&self, node: &YamlPlainScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlPlainScalarFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        write!(
+            f,
+            [format_replaced(
+                &value_token,
+                &FormatFlowScalarLines::new(&value_token)
+            )]
+        )
+    }
+}
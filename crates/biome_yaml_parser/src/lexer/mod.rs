@@ -48,23 +48,28 @@ impl<'src> YamlLexer<'src> {
     /// - [a, b, c]
     /// ```
     fn consume_tokens(&mut self) {
+        let mut tokens = self.lex_tokens();
+        self.tokens.append(&mut tokens);
+    }
+
+    /// Lex the next group of tokens. The group always contains at least one token.
+    fn lex_tokens(&mut self) -> LinkedList<LexToken> {
         let Some(current) = self.current_byte() else {
             let mut tokens = self.close_all_scopes();
-            self.tokens.append(&mut tokens);
-            self.tokens
-                .push_back(LexToken::pseudo(EOF, self.current_coordinate));
-            return;
+            tokens.push_back(LexToken::pseudo(EOF, self.current_coordinate));
+            return tokens;
         };
 
         let start = self.text_position();
 
-        let mut tokens = match current {
+        let tokens = match current {
             c if is_break(c) => self.evaluate_block_scope(),
             c if is_space(c) => self.consume_whitespace_token().into(),
             b'#' => self.consume_comment().into(),
             b'.' if self.is_at_doc_end() => self.consume_doc_end(),
+            b'&' | b'!' => self.consume_node_with_properties(),
             current if maybe_at_mapping_start(current, self.peek_byte()) => {
-                self.consume_potential_mapping_start(current)
+                self.consume_potential_mapping_start(current, self.current_coordinate)
             }
             // ':', '?', '-' can be a valid plain token start
             b'?' | b':' => self.consume_mapping_key(current),
@@ -72,9 +77,157 @@ impl<'src> YamlLexer<'src> {
             b'|' | b'>' => self.consume_block_scalar(current),
             _ => self.consume_unexpected_token().into(),
         };
-        self.tokens.append(&mut tokens);
 
         debug_assert!(self.text_position() > start, "Lexer did not advance");
+        tokens
+    }
+
+    /// Consume the properties (anchor and tag) of a node, along with the tokens needed to
+    /// determine which node they belong to.
+    ///
+    /// The properties of a block collection are lexed before the pseudo token that starts the
+    /// collection, but they belong to that collection:
+    /// ```yaml
+    /// key: &anchor
+    ///   nested: value
+    /// ```
+    /// In this case, the start token of the collection is moved in front of the properties.
+    /// Properties that aren't followed by any node belong to an empty flow node.
+    fn consume_node_with_properties(&mut self) -> LinkedList<LexToken> {
+        let start = self.current_coordinate;
+        let mut tokens = self.consume_node_properties();
+
+        match self.current_byte() {
+            // The node starts on the same line as its properties
+            Some(current) if maybe_at_mapping_start(current, self.peek_byte()) => {
+                let mut node = self.consume_potential_mapping_start(current, start);
+                if node
+                    .front()
+                    .is_some_and(|token| matches!(token.kind, MAPPING_START | FLOW_START))
+                {
+                    tokens.push_front(node.pop_front().unwrap());
+                }
+                tokens.append(&mut node);
+            }
+            // The node, e.g. a block scalar, is lexed on its own
+            Some(current) if !is_break(current) => {}
+            // The node starts on one of the following lines, or is empty
+            _ => {
+                let properties_end = self.current_coordinate;
+                let mut following = self.lex_tokens();
+                while following.iter().all(LexToken::is_trivia) {
+                    following.append(&mut self.lex_tokens());
+                }
+
+                let index = following
+                    .iter()
+                    .position(|token| !token.is_trivia())
+                    .unwrap_or_default();
+                let mut node = following.split_off(index);
+                let node_start = node.front().map(|token| token.kind);
+
+                if let Some(kind @ (MAPPING_START | SEQUENCE_START)) = node_start {
+                    node.pop_front();
+                    tokens.push_front(LexToken::pseudo(kind, start));
+                } else {
+                    tokens.push_front(LexToken::pseudo(FLOW_START, start));
+                    tokens.push_back(LexToken::pseudo(FLOW_END, properties_end));
+                }
+                tokens.append(&mut following);
+                tokens.append(&mut node);
+            }
+        }
+
+        tokens
+    }
+
+    /// Consume the anchor and tag of a node, along with the trivia on the same line.
+    /// https://yaml.org/spec/1.2.2/#rule-c-ns-properties
+    fn consume_node_properties(&mut self) -> LinkedList<LexToken> {
+        let mut tokens = LinkedList::new();
+        while let Some(current @ (b'&' | b'!')) = self.current_byte() {
+            let property = if current == b'&' {
+                self.consume_anchor_property()
+            } else {
+                self.consume_tag_property()
+            };
+            tokens.push_back(property);
+            let mut trivia = self.consume_trivia(true);
+            tokens.append(&mut trivia);
+        }
+        tokens
+    }
+
+    /// https://yaml.org/spec/1.2.2/#rule-c-ns-anchor-property
+    fn consume_anchor_property(&mut self) -> LexToken {
+        self.assert_byte(b'&');
+        self.consume_anchor_name(ANCHOR_PROPERTY_LITERAL)
+    }
+
+    /// https://yaml.org/spec/1.2.2/#rule-c-ns-alias-node
+    fn consume_alias_literal(&mut self) -> LexToken {
+        self.assert_byte(b'*');
+        self.consume_anchor_name(ALIAS_LITERAL)
+    }
+
+    /// Consume the indicator and the name of an anchor or an alias.
+    /// https://yaml.org/spec/1.2.2/#rule-ns-anchor-name
+    fn consume_anchor_name(&mut self, kind: YamlSyntaxKind) -> LexToken {
+        let start = self.current_coordinate;
+        self.advance(1);
+        while self
+            .current_byte()
+            .is_some_and(|c| is_non_blank_char(c) && !is_flow_collection_indicator(c))
+        {
+            self.advance_char_unchecked();
+        }
+
+        if self.current_coordinate.offset - start.offset == 1 {
+            let err = ParseDiagnostic::new(
+                "Expected an anchor name",
+                TextSize::from(start)..self.text_position(),
+            );
+            self.diagnostics.push(err);
+            return LexToken::new(ERROR_TOKEN, start, self.current_coordinate);
+        }
+
+        LexToken::new(kind, start, self.current_coordinate)
+    }
+
+    /// https://yaml.org/spec/1.2.2/#rule-c-ns-tag-property
+    fn consume_tag_property(&mut self) -> LexToken {
+        self.assert_byte(b'!');
+        let start = self.current_coordinate;
+        self.advance(1);
+
+        if self.current_byte() == Some(b'<') {
+            // Verbatim tag, e.g. `!<tag:yaml.org,2002:str>`
+            while let Some(c) = self.current_byte() {
+                if is_blank(c) {
+                    break;
+                }
+                self.advance_char_unchecked();
+                if c == b'>' {
+                    return LexToken::new(TAG_PROPERTY_LITERAL, start, self.current_coordinate);
+                }
+            }
+            let err = ParseDiagnostic::new(
+                "Missing closing `>` of the verbatim tag",
+                TextSize::from(start)..self.text_position(),
+            );
+            self.diagnostics.push(err);
+            return LexToken::new(ERROR_TOKEN, start, self.current_coordinate);
+        }
+
+        // Shorthand tags, e.g. `!local`, `!!str`, or `!e!tag`. A lone `!` is the non-specific tag
+        while self
+            .current_byte()
+            .is_some_and(|c| is_non_blank_char(c) && !is_flow_collection_indicator(c))
+        {
+            self.advance_char_unchecked();
+        }
+
+        LexToken::new(TAG_PROPERTY_LITERAL, start, self.current_coordinate)
     }
 
     fn consume_sequence_entry(&mut self) -> LinkedList<LexToken> {
@@ -126,10 +279,15 @@ impl<'src> YamlLexer<'src> {
 
     /// Consume and disambiguate a YAML value to determine whether it's a YAML block map or just a
     /// YAML flow value
-    fn consume_potential_mapping_start(&mut self, current: u8) -> LinkedList<LexToken> {
+    /// The `start` coordinate is usually the current one, but it's placed before the properties
+    /// when the node has any.
+    fn consume_potential_mapping_start(
+        &mut self,
+        current: u8,
+        start: TextCoordinate,
+    ) -> LinkedList<LexToken> {
         debug_assert!(maybe_at_mapping_start(current, self.peek_byte()));
 
-        let start = self.current_coordinate;
         let mut tokens = self.consume_potential_mapping_key(current);
         if self.scopes.last().is_none_or(|scope| scope.indent(start)) {
             if self.is_at_mapping_indicator() {
@@ -287,9 +445,9 @@ impl<'src> YamlLexer<'src> {
     }
 
     fn close_all_scopes(&mut self) -> LinkedList<LexToken> {
-        let tokens = LinkedList::new();
+        let mut tokens = LinkedList::new();
         while let Some(scope) = self.scopes.pop() {
-            self.tokens.push_back(LexToken::pseudo(
+            tokens.push_back(LexToken::pseudo(
                 scope.close_token_kind(),
                 self.current_coordinate,
             ));
@@ -306,6 +464,8 @@ impl<'src> YamlLexer<'src> {
             self.consume_double_quoted_literal().into()
         } else if current == b'\'' {
             self.consume_single_quoted_literal().into()
+        } else if current == b'*' {
+            self.consume_alias_literal().into()
         } else {
             self.consume_plain_literal(current, false).into()
         }
@@ -373,6 +533,12 @@ impl<'src> YamlLexer<'src> {
                     self.consume_byte_as_token(T!['}'])
                 }
                 (b',', _) => self.consume_byte_as_token(T![,]),
+                (b'&', _) => self.consume_anchor_property(),
+                (b'!', _) => self.consume_tag_property(),
+                (b'*', _) => {
+                    just_lexed_json_key = false;
+                    self.consume_alias_literal()
+                }
                 (current, peek) if is_start_of_plain(current, peek, true) => {
                     self.consume_plain_literal(current, true)
                 }
@@ -840,6 +1006,10 @@ impl LexToken {
     fn start_position(&self) -> TextSize {
         self.start.into()
     }
+
+    fn is_trivia(&self) -> bool {
+        matches!(self.kind, NEWLINE | WHITESPACE | COMMENT)
+    }
 }
 
 impl From<LexToken> for LinkedList<LexToken> {
@@ -957,6 +1127,7 @@ fn maybe_at_mapping_start(current: u8, peek: Option<u8>) -> bool {
         || is_start_of_plain(current, peek, false)
         || current == b'"'
         || current == b'\''
+        || current == b'*'
 }
 
 // https://yaml.org/spec/1.2.2/#rule-ns-plain-first
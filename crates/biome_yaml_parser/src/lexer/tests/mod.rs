@@ -3,6 +3,7 @@
 mod block;
 mod document;
 mod flow;
+mod properties;
 
 use super::TextSize;
 use crate::lexer::YamlLexer;
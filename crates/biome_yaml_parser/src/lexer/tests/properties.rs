@@ -0,0 +1,160 @@
+use crate::assert_lex;
+
+#[test]
+fn lex_anchor_before_plain_value() {
+    assert_lex!(
+        "a: &anchor b",
+        MAPPING_START:0,
+        PLAIN_LITERAL:1,
+        COLON:1,
+        WHITESPACE:1,
+        FLOW_START:0,
+        ANCHOR_PROPERTY_LITERAL:7,
+        WHITESPACE:1,
+        PLAIN_LITERAL:1,
+        FLOW_END:0,
+        MAPPING_END:0,
+    );
+}
+
+#[test]
+fn lex_alias_value() {
+    assert_lex!(
+        "a: *anchor",
+        MAPPING_START:0,
+        PLAIN_LITERAL:1,
+        COLON:1,
+        WHITESPACE:1,
+        FLOW_START:0,
+        ALIAS_LITERAL:7,
+        FLOW_END:0,
+        MAPPING_END:0,
+    );
+}
+
+#[test]
+fn lex_properties_before_block_mapping() {
+    assert_lex!(
+        "a: &anchor !!map\n  b: c",
+        MAPPING_START:0,
+        PLAIN_LITERAL:1,
+        COLON:1,
+        WHITESPACE:1,
+        MAPPING_START:0,
+        ANCHOR_PROPERTY_LITERAL:7,
+        WHITESPACE:1,
+        TAG_PROPERTY_LITERAL:5,
+        NEWLINE:1,
+        WHITESPACE:2,
+        PLAIN_LITERAL:1,
+        COLON:1,
+        WHITESPACE:1,
+        FLOW_START:0,
+        PLAIN_LITERAL:1,
+        FLOW_END:0,
+        MAPPING_END:0,
+        MAPPING_END:0,
+    );
+}
+
+#[test]
+fn lex_properties_before_block_sequence() {
+    assert_lex!(
+        "a: &anchor\n- b",
+        MAPPING_START:0,
+        PLAIN_LITERAL:1,
+        COLON:1,
+        WHITESPACE:1,
+        SEQUENCE_START:0,
+        ANCHOR_PROPERTY_LITERAL:7,
+        NEWLINE:1,
+        DASH:1,
+        WHITESPACE:1,
+        FLOW_START:0,
+        PLAIN_LITERAL:1,
+        FLOW_END:0,
+        SEQUENCE_END:0,
+        MAPPING_END:0,
+    );
+}
+
+#[test]
+fn lex_properties_of_empty_node() {
+    assert_lex!(
+        "a: &anchor\nb: c",
+        MAPPING_START:0,
+        PLAIN_LITERAL:1,
+        COLON:1,
+        WHITESPACE:1,
+        FLOW_START:0,
+        ANCHOR_PROPERTY_LITERAL:7,
+        FLOW_END:0,
+        NEWLINE:1,
+        PLAIN_LITERAL:1,
+        COLON:1,
+        WHITESPACE:1,
+        FLOW_START:0,
+        PLAIN_LITERAL:1,
+        FLOW_END:0,
+        MAPPING_END:0,
+    );
+}
+
+#[test]
+fn lex_properties_before_block_scalar() {
+    assert_lex!(
+        "a: !!str |\n  b",
+        MAPPING_START:0,
+        PLAIN_LITERAL:1,
+        COLON:1,
+        WHITESPACE:1,
+        TAG_PROPERTY_LITERAL:5,
+        WHITESPACE:1,
+        PIPE:1,
+        BLOCK_CONTENT_LITERAL:4,
+        MAPPING_END:0,
+    );
+}
+
+#[test]
+fn lex_properties_inside_flow_collection() {
+    assert_lex!(
+        "[&a !local b, *a]",
+        FLOW_START:0,
+        L_BRACK:1,
+        ANCHOR_PROPERTY_LITERAL:2,
+        WHITESPACE:1,
+        TAG_PROPERTY_LITERAL:6,
+        WHITESPACE:1,
+        PLAIN_LITERAL:1,
+        COMMA:1,
+        WHITESPACE:1,
+        ALIAS_LITERAL:2,
+        R_BRACK:1,
+        FLOW_END:0,
+    );
+}
+
+#[test]
+fn lex_verbatim_tag() {
+    assert_lex!(
+        "!<tag:yaml.org,2002:str> a",
+        FLOW_START:0,
+        TAG_PROPERTY_LITERAL:24,
+        WHITESPACE:1,
+        PLAIN_LITERAL:1,
+        FLOW_END:0,
+    );
+}
+
+#[test]
+fn lex_empty_anchor_name() {
+    assert_lex!(
+        "& a",
+        FLOW_START:0,
+        ERROR_TOKEN:1,
+        WHITESPACE:1,
+        PLAIN_LITERAL:1,
+        FLOW_END:0,
+    );
+}
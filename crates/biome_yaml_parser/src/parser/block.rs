@@ -16,10 +16,10 @@ use crate::parser::{flow::parse_any_flow_node, parse_error::expected_header};
 
 use super::{
     YamlParser,
-    flow::{
-        is_at_flow_json_node, is_at_flow_yaml_node, parse_flow_json_node, parse_flow_yaml_node,
+    flow::{is_at_flow_content_node, is_at_properties, parse_flow_content_node, parse_properties},
+    parse_error::{
+        expected_block_mapping_entry, expected_block_scalar, expected_block_sequence_entry,
     },
-    parse_error::{expected_block_mapping_entry, expected_block_sequence_entry},
 };
 
 pub(crate) fn parse_any_block_node(p: &mut YamlParser) -> ParsedSyntax {
@@ -29,10 +29,8 @@ pub(crate) fn parse_any_block_node(p: &mut YamlParser) -> ParsedSyntax {
         Present(parse_block_sequence(p))
     } else if p.at(FLOW_START) {
         Present(parse_flow_in_block_node(p))
-    } else if p.at(T![|]) {
-        Present(parse_literal_scalar(p))
-    } else if p.at(T![>]) {
-        Present(parse_folded_scalar(p))
+    } else if is_at_block_scalar(p) {
+        Present(parse_block_scalar(p))
     } else {
         Absent
     }
@@ -42,6 +40,7 @@ fn parse_block_mapping(p: &mut YamlParser) -> CompletedMarker {
     debug_assert!(p.at(MAPPING_START));
     let m = p.start();
     p.bump(MAPPING_START);
+    parse_properties(p).ok();
     BlockMapEntryList.parse_list(p);
     debug_assert!(p.at(MAPPING_END));
     p.expect(MAPPING_END);
@@ -138,32 +137,24 @@ fn parse_block_map_explicit_entry(p: &mut YamlParser) -> ParsedSyntax {
 }
 
 fn parse_block_map_implicit_entry(p: &mut YamlParser) -> ParsedSyntax {
-    if is_at_flow_yaml_node(p) {
-        let m = p.start();
-        parse_flow_yaml_node(p);
-
-        // TODO: improve error handling message here
-        p.expect(T![:]);
-        // Value can be completely empty according to the spec
-        parse_any_block_node(p).ok();
-        Present(m.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY))
-    } else if is_at_flow_json_node(p) {
-        let m = p.start();
-        parse_flow_json_node(p);
-
-        p.expect(T![:]);
-        // Value can be completely empty according to the spec
-        parse_any_block_node(p).ok();
-        Present(m.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY))
-    } else {
-        Absent
+    if !is_at_flow_content_node(p) {
+        return Absent;
     }
+    let m = p.start();
+    parse_flow_content_node(p).ok();
+
+    // TODO: improve error handling message here
+    p.expect(T![:]);
+    // Value can be completely empty according to the spec
+    parse_any_block_node(p).ok();
+    Present(m.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY))
 }
 
 fn parse_block_sequence(p: &mut YamlParser) -> CompletedMarker {
     debug_assert!(p.at(SEQUENCE_START));
     let m = p.start();
     p.bump(SEQUENCE_START);
+    parse_properties(p).ok();
     BlockSequenceEntryList.parse_list(p);
     debug_assert!(p.at(SEQUENCE_END));
     p.expect(SEQUENCE_END);
@@ -254,20 +245,24 @@ fn parse_flow_in_block_node(p: &mut YamlParser) -> CompletedMarker {
     m.complete(p, YAML_FLOW_IN_BLOCK_NODE)
 }
 
-fn parse_literal_scalar(p: &mut YamlParser) -> CompletedMarker {
+/// Parse a literal or a folded block scalar. The properties of a block scalar are lexed right
+/// before its style indicator.
+fn parse_block_scalar(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
-    p.bump(T![|]);
-    BlockHeaderList.parse_list(p);
-    parse_block_content(p);
-    m.complete(p, YAML_LITERAL_SCALAR)
-}
+    parse_properties(p).ok();
+
+    let kind = if p.eat(T![|]) {
+        YAML_LITERAL_SCALAR
+    } else if p.eat(T![>]) {
+        YAML_FOLDED_SCALAR
+    } else {
+        p.error(expected_block_scalar(p, p.cur_range()));
+        return m.complete(p, YAML_BOGUS_BLOCK_NODE);
+    };
 
-fn parse_folded_scalar(p: &mut YamlParser) -> CompletedMarker {
-    let m = p.start();
-    p.bump(T![>]);
     BlockHeaderList.parse_list(p);
     parse_block_content(p);
-    m.complete(p, YAML_FOLDED_SCALAR)
+    m.complete(p, kind)
 }
 
 #[derive(Default)]
@@ -332,7 +327,11 @@ fn parse_block_content(p: &mut YamlParser) -> CompletedMarker {
 }
 
 pub(crate) fn is_at_any_block_node(p: &YamlParser) -> bool {
-    p.at(MAPPING_START) || p.at(SEQUENCE_START) || p.at(FLOW_START) || p.at(T![|]) || p.at(T![>])
+    p.at(MAPPING_START) || p.at(SEQUENCE_START) || p.at(FLOW_START) || is_at_block_scalar(p)
+}
+
+fn is_at_block_scalar(p: &YamlParser) -> bool {
+    p.at(T![|]) || p.at(T![>]) || is_at_properties(p)
 }
 
 fn is_at_explicit_mapping_key(p: &YamlParser) -> bool {
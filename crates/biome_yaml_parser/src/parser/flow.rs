@@ -16,17 +16,21 @@ use super::{
 };
 
 pub(crate) fn parse_any_flow_node(p: &mut YamlParser) -> ParsedSyntax {
-    if is_at_flow_json_node(p) {
-        Present(parse_flow_json_node(p))
-    } else if is_at_flow_yaml_node(p) {
-        Present(parse_flow_yaml_node(p))
+    if is_at_alias_node(p) {
+        Present(parse_alias_node(p))
     } else {
-        Absent
+        parse_flow_content_node(p)
     }
 }
 
-pub(crate) fn parse_flow_json_node(p: &mut YamlParser) -> CompletedMarker {
+/// Parse a flow node that isn't an alias, along with its properties
+pub(crate) fn parse_flow_content_node(p: &mut YamlParser) -> ParsedSyntax {
+    if !is_at_flow_content_node(p) {
+        return Absent;
+    }
+
     let m = p.start();
+    parse_properties(p).ok();
 
     if is_at_flow_sequence(p) {
         parse_flow_sequence(p);
@@ -36,15 +40,53 @@ pub(crate) fn parse_flow_json_node(p: &mut YamlParser) -> CompletedMarker {
         parse_double_quoted_scalar(p);
     } else if is_at_single_quoted_scalar(p) {
         parse_single_quoted_scalar(p);
+    } else {
+        // A node with properties can have an empty content
+        if is_at_plain_scalar(p) {
+            parse_plain_scalar(p);
+        }
+        return Present(m.complete(p, YAML_FLOW_YAML_NODE));
+    }
+
+    Present(m.complete(p, YAML_FLOW_JSON_NODE))
+}
+
+fn parse_alias_node(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(ALIAS_LITERAL);
+    m.complete(p, YAML_ALIAS_NODE)
+}
+
+pub(crate) fn parse_properties(p: &mut YamlParser) -> ParsedSyntax {
+    if is_at_anchor_property(p) {
+        let m = p.start();
+        parse_anchor_property(p);
+        if is_at_tag_property(p) {
+            parse_tag_property(p);
+        }
+        Present(m.complete(p, YAML_PROPERTIES_ANCHOR_FIRST))
+    } else if is_at_tag_property(p) {
+        let m = p.start();
+        parse_tag_property(p);
+        if is_at_anchor_property(p) {
+            parse_anchor_property(p);
+        }
+        Present(m.complete(p, YAML_PROPERTIES_TAG_FIRST))
+    } else {
+        Absent
     }
+}
 
-    m.complete(p, YAML_FLOW_JSON_NODE)
+fn parse_anchor_property(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(ANCHOR_PROPERTY_LITERAL);
+    m.complete(p, YAML_ANCHOR_PROPERTY)
 }
 
-pub(crate) fn parse_flow_yaml_node(p: &mut YamlParser) -> CompletedMarker {
+fn parse_tag_property(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
-    parse_plain_scalar(p);
-    m.complete(p, YAML_FLOW_YAML_NODE)
+    p.bump(TAG_PROPERTY_LITERAL);
+    m.complete(p, YAML_TAG_PROPERTY)
 }
 
 fn parse_plain_scalar(p: &mut YamlParser) -> CompletedMarker {
@@ -97,7 +139,7 @@ impl ParseRecovery for FlowSequenceEntryRecovery {
     const RECOVERED_KIND: Self::Kind = YAML_BOGUS_FLOW_NODE;
 
     fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
-        p.at(T![,]) || p.at(T![']']) || is_at_flow_yaml_node(p) || is_at_flow_json_node(p)
+        p.at(T![,]) || p.at(T![']']) || is_at_any_flow_node(p)
     }
 }
 
@@ -115,25 +157,15 @@ impl ParseSeparatedList for FlowSequenceEntryList {
         // e.g. [a, b, c: d, e: f], which is equivalent to [a, b, {c: d}, {e: f}]
         if p.at(T![?]) {
             parse_flow_map_explicit_entry(p)
-        } else if is_at_flow_yaml_node(p) {
+        } else if is_at_flow_content_node(p) {
             let m = p.start();
-            let flow_yaml_node = parse_flow_yaml_node(p);
+            let node = parse_flow_content_node(p);
             if p.at(T![:]) {
                 parse_flow_map_value(p);
                 Present(m.complete(p, YAML_FLOW_MAP_IMPLICIT_ENTRY))
             } else {
                 m.abandon(p);
-                Present(flow_yaml_node)
-            }
-        } else if is_at_flow_json_node(p) {
-            let m = p.start();
-            let flow_json_node = parse_flow_json_node(p);
-            if p.at(T![:]) {
-                parse_flow_map_value(p);
-                Present(m.complete(p, YAML_FLOW_MAP_IMPLICIT_ENTRY))
-            } else {
-                m.abandon(p);
-                Present(flow_json_node)
+                node
             }
         } else if p.at(T![:]) {
             let m = p.start();
@@ -177,11 +209,7 @@ impl ParseRecovery for FlowMapEntryRecovery {
     const RECOVERED_KIND: Self::Kind = YAML_BOGUS_FLOW_NODE;
 
     fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
-        p.at(T![,])
-            || p.at(T!['}'])
-            || p.at(T![:])
-            || is_at_flow_yaml_node(p)
-            || is_at_flow_json_node(p)
+        p.at(T![,]) || p.at(T!['}']) || p.at(T![:]) || is_at_any_flow_node(p)
     }
 }
 
@@ -233,17 +261,8 @@ fn parse_flow_map_explicit_entry(p: &mut YamlParser) -> ParsedSyntax {
     p.bump(T![?]);
 
     // The entry after '?' is optional
-    if is_at_flow_yaml_node(p) {
-        parse_flow_yaml_node(p);
-        if p.at(T![:]) {
-            parse_flow_map_value(p);
-        }
-    } else if is_at_flow_json_node(p) {
-        parse_flow_json_node(p);
-        if p.at(T![:]) {
-            parse_flow_map_value(p);
-        }
-    } else if p.at(T![:]) {
+    parse_flow_content_node(p).ok();
+    if p.at(T![:]) {
         parse_flow_map_value(p);
     }
 
@@ -251,16 +270,9 @@ fn parse_flow_map_explicit_entry(p: &mut YamlParser) -> ParsedSyntax {
 }
 
 fn parse_flow_map_implicit_entry(p: &mut YamlParser) -> ParsedSyntax {
-    if is_at_flow_yaml_node(p) {
-        let m = p.start();
-        parse_flow_yaml_node(p);
-        if p.at(T![:]) {
-            parse_flow_map_value(p);
-        }
-        Present(m.complete(p, YAML_FLOW_MAP_IMPLICIT_ENTRY))
-    } else if is_at_flow_json_node(p) {
+    if is_at_flow_content_node(p) {
         let m = p.start();
-        parse_flow_json_node(p);
+        parse_flow_content_node(p).ok();
         if p.at(T![:]) {
             parse_flow_map_value(p);
         }
@@ -282,7 +294,16 @@ fn parse_flow_map_value(p: &mut YamlParser) {
     parse_any_flow_node(p).ok();
 }
 
-pub(crate) fn is_at_flow_json_node(p: &YamlParser) -> bool {
+pub(crate) fn is_at_any_flow_node(p: &YamlParser) -> bool {
+    is_at_alias_node(p) || is_at_flow_content_node(p)
+}
+
+/// Whether the parser is at a flow node that can be used as an implicit mapping key
+pub(crate) fn is_at_flow_content_node(p: &YamlParser) -> bool {
+    is_at_flow_json_node(p) || is_at_flow_yaml_node(p) || is_at_properties(p)
+}
+
+fn is_at_flow_json_node(p: &YamlParser) -> bool {
     is_at_flow_sequence(p)
         || is_at_flow_mapping(p)
         || is_at_double_quoted_scalar(p)
@@ -305,10 +326,26 @@ fn is_at_single_quoted_scalar(p: &YamlParser) -> bool {
     p.at(SINGLE_QUOTED_LITERAL)
 }
 
-pub(crate) fn is_at_flow_yaml_node(p: &YamlParser) -> bool {
+fn is_at_flow_yaml_node(p: &YamlParser) -> bool {
     is_at_plain_scalar(p)
 }
 
 fn is_at_plain_scalar(p: &YamlParser) -> bool {
     p.at(PLAIN_LITERAL)
 }
+
+fn is_at_alias_node(p: &YamlParser) -> bool {
+    p.at(ALIAS_LITERAL)
+}
+
+pub(crate) fn is_at_properties(p: &YamlParser) -> bool {
+    is_at_anchor_property(p) || is_at_tag_property(p)
+}
+
+fn is_at_anchor_property(p: &YamlParser) -> bool {
+    p.at(ANCHOR_PROPERTY_LITERAL)
+}
+
+fn is_at_tag_property(p: &YamlParser) -> bool {
+    p.at(TAG_PROPERTY_LITERAL)
+}
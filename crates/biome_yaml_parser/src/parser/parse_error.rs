@@ -15,6 +15,10 @@ pub(crate) fn expected_block_sequence_entry(p: &YamlParser, range: TextRange) ->
     expected_node("block sequence entry", range, p)
 }
 
+pub(crate) fn expected_block_scalar(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("block scalar", range, p)
+}
+
 pub(crate) fn expected_directive(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
     expected_node("directive", range, p)
 }
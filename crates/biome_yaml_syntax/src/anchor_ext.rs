@@ -0,0 +1,22 @@
+use crate::{YamlAliasNode, YamlAnchorProperty, YamlSyntaxToken};
+use biome_rowan::{SyntaxResult, TextRange, TextSize, TokenText};
+
+impl YamlAnchorProperty {
+    /// Returns the name of the anchor, without the leading `&`.
+    pub fn name(&self) -> SyntaxResult<TokenText> {
+        Ok(name_without_indicator(&self.value_token()?))
+    }
+}
+
+impl YamlAliasNode {
+    /// Returns the name of the anchor referenced by the alias, without the leading `*`.
+    pub fn name(&self) -> SyntaxResult<TokenText> {
+        Ok(name_without_indicator(&self.value_token()?))
+    }
+}
+
+fn name_without_indicator(token: &YamlSyntaxToken) -> TokenText {
+    let text = token.token_text_trimmed();
+    let range = TextRange::new(TextSize::from(1), text.len());
+    text.slice(range)
+}
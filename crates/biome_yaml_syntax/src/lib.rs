@@ -2,12 +2,17 @@
 
 #[macro_use]
 mod generated;
+mod anchor_ext;
 mod file_source;
+mod mapping_ext;
+mod scalar_ext;
 mod syntax_node;
 
 pub use self::generated::*;
 use biome_rowan::{AstNode, RawSyntaxKind};
 pub use biome_rowan::{TextLen, TextRange, TextSize, TokenAtOffset, TriviaPieceKind, WalkEvent};
+pub use file_source::YamlFileSource;
+pub use mapping_ext::AnyYamlMapping;
 pub use syntax_node::*;
 
 impl From<u16> for YamlSyntaxKind {
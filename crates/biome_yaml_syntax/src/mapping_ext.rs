@@ -0,0 +1,46 @@
+use crate::{
+    AnyYamlBlockMapEntry, AnyYamlBlockNode, AnyYamlFlowMapEntry, AnyYamlFlowNode,
+    AnyYamlMappingImplicitKey, YamlBlockMapping, YamlFlowMapping,
+};
+use biome_rowan::{AstNodeList, AstSeparatedList, declare_node_union};
+
+declare_node_union! {
+    pub AnyYamlMapping = YamlBlockMapping | YamlFlowMapping
+}
+
+impl AnyYamlMapping {
+    /// Returns the keys of the entries of the mapping, in order of appearance.
+    ///
+    /// Missing keys and keys that are block collections, e.g. `? - a`, are skipped.
+    pub fn keys(&self) -> Vec<AnyYamlMappingImplicitKey> {
+        match self {
+            Self::YamlBlockMapping(mapping) => mapping
+                .entries()
+                .iter()
+                .filter_map(|entry| match entry {
+                    AnyYamlBlockMapEntry::YamlBlockMapImplicitEntry(entry) => entry.key(),
+                    AnyYamlBlockMapEntry::YamlBlockMapExplicitEntry(entry) => {
+                        let AnyYamlBlockNode::YamlFlowInBlockNode(node) = entry.key()? else {
+                            return None;
+                        };
+                        match node.flow().ok()? {
+                            AnyYamlFlowNode::YamlFlowJsonNode(node) => Some(node.into()),
+                            AnyYamlFlowNode::YamlFlowYamlNode(node) => Some(node.into()),
+                            AnyYamlFlowNode::YamlAliasNode(_)
+                            | AnyYamlFlowNode::YamlBogusFlowNode(_) => None,
+                        }
+                    }
+                    AnyYamlBlockMapEntry::YamlBogusBlockMapEntry(_) => None,
+                })
+                .collect(),
+            Self::YamlFlowMapping(mapping) => mapping
+                .entries()
+                .iter()
+                .filter_map(|entry| match entry.ok()? {
+                    AnyYamlFlowMapEntry::YamlFlowMapExplicitEntry(entry) => entry.key(),
+                    AnyYamlFlowMapEntry::YamlFlowMapImplicitEntry(entry) => entry.key(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    AnyYamlFlowNode, AnyYamlJsonContent, AnyYamlMappingImplicitKey, YamlDoubleQuotedScalar,
+    YamlFlowJsonNode, YamlFlowYamlNode, YamlPlainScalar, YamlSingleQuotedScalar, YamlSyntaxToken,
+};
+use biome_rowan::{SyntaxResult, TextRange, TextSize, TokenText};
+
+impl YamlPlainScalar {
+    /// Returns the text of the scalar.
+    pub fn inner_string_text(&self) -> SyntaxResult<TokenText> {
+        Ok(self.value_token()?.token_text_trimmed())
+    }
+}
+
+impl YamlDoubleQuotedScalar {
+    /// Returns the text of the scalar without the surrounding quotes.
+    ///
+    /// Escape sequences are kept as is.
+    pub fn inner_string_text(&self) -> SyntaxResult<TokenText> {
+        Ok(unquoted_text(&self.value_token()?))
+    }
+}
+
+impl YamlSingleQuotedScalar {
+    /// Returns the text of the scalar without the surrounding quotes.
+    ///
+    /// Escaped quotes (`''`) are kept as is.
+    pub fn inner_string_text(&self) -> SyntaxResult<TokenText> {
+        Ok(unquoted_text(&self.value_token()?))
+    }
+}
+
+impl YamlFlowJsonNode {
+    /// Returns the text of the content when it's a quoted scalar, without the quotes.
+    pub fn inner_string_text(&self) -> Option<TokenText> {
+        match self.content()? {
+            AnyYamlJsonContent::YamlDoubleQuotedScalar(scalar) => scalar.inner_string_text().ok(),
+            AnyYamlJsonContent::YamlSingleQuotedScalar(scalar) => scalar.inner_string_text().ok(),
+            AnyYamlJsonContent::YamlFlowMapping(_) | AnyYamlJsonContent::YamlFlowSequence(_) => {
+                None
+            }
+        }
+    }
+}
+
+impl YamlFlowYamlNode {
+    /// Returns the text of the plain scalar content, if any.
+    pub fn inner_string_text(&self) -> Option<TokenText> {
+        self.content()?.inner_string_text().ok()
+    }
+}
+
+impl AnyYamlMappingImplicitKey {
+    /// Returns the text of the key when it's a scalar, without the quotes.
+    ///
+    /// Returns [None] when the key is a collection or is empty.
+    pub fn inner_string_text(&self) -> Option<TokenText> {
+        match self {
+            Self::YamlFlowJsonNode(node) => node.inner_string_text(),
+            Self::YamlFlowYamlNode(node) => node.inner_string_text(),
+        }
+    }
+}
+
+impl AnyYamlFlowNode {
+    /// Returns the text of the node when it's a scalar, without the quotes.
+    pub fn inner_string_text(&self) -> Option<TokenText> {
+        match self {
+            Self::YamlFlowJsonNode(node) => node.inner_string_text(),
+            Self::YamlFlowYamlNode(node) => node.inner_string_text(),
+            Self::YamlAliasNode(_) | Self::YamlBogusFlowNode(_) => None,
+        }
+    }
+}
+
+fn unquoted_text(token: &YamlSyntaxToken) -> TokenText {
+    let text = token.token_text_trimmed();
+    let quote_len = TextSize::from(1);
+    let quote = &text.text()[..1];
+    // An unterminated quoted scalar doesn't have a closing quote
+    let end = if text.len() > quote_len && text.text().ends_with(quote) {
+        text.len() - quote_len
+    } else {
+        text.len()
+    };
+    text.slice(TextRange::new(quote_len, end))
+}
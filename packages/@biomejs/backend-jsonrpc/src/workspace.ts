@@ -84,6 +84,10 @@ export interface Configuration {
 	 * The configuration for the linter
 	 */
 	linter?: LinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: MarkdownConfiguration;
 	/**
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
@@ -101,6 +105,10 @@ project. By default, this is `true`.
 	 * The configuration of the VCS integration
 	 */
 	vcs?: VcsConfiguration;
+	/**
+	 * Specific configuration for the YAML language
+	 */
+	yaml?: YamlConfiguration;
 }
 export type Schema = string;
 export interface AssistConfiguration {
@@ -292,6 +300,10 @@ export interface JsConfiguration {
 	 * Assist options
 	 */
 	assist?: JsAssistConfiguration;
+	/**
+	 * Options for the snippets of other languages embedded in tagged template literals.
+	 */
+	embeddedLanguages?: JsEmbeddedLanguagesConfiguration;
 	/**
 	 * Formatting options
 	 */
@@ -355,6 +367,15 @@ match these patterns.
 	 */
 	rules?: Rules;
 }
+/**
+ * Options applied to Markdown files
+ */
+export interface MarkdownConfiguration {
+	/**
+	 * Markdown formatter options
+	 */
+	formatter?: MarkdownFormatterConfiguration;
+}
 export type Overrides = OverridePattern[];
 export type Plugins = PluginConfiguration[];
 export type Bool = boolean;
@@ -389,6 +410,19 @@ specified in the ignore file.
 	 */
 	useIgnoreFile?: Bool;
 }
+/**
+ * Options applied to YAML files
+ */
+export interface YamlConfiguration {
+	/**
+	 * YAML formatter options
+	 */
+	formatter?: YamlFormatterConfiguration;
+	/**
+	 * YAML linter options
+	 */
+	linter?: YamlLinterConfiguration;
+}
 export interface Actions {
 	/**
 	 * It enables the assist actions recommended by Biome. `true` by default.
@@ -647,6 +681,33 @@ export interface JsAssistConfiguration {
 	 */
 	enabled?: Bool;
 }
+/**
+	* Options that control the formatting and linting of the snippets of other languages
+embedded in tagged template literals, such as ``styled.div`color: red;` `` and
+``gql`query { user }` ``. 
+	 */
+export interface JsEmbeddedLanguagesConfiguration {
+	/**
+	* The tags of the template literals that contain CSS. A template literal matches
+when the leftmost identifier of its tag is in the list, so `styled` matches
+``styled.div`...` `` and ``styled(Button)`...` ``.
+
+Defaults to `["css", "keyframes", "createGlobalStyle", "injectGlobal", "styled"]`. 
+	 */
+	cssTags?: string[];
+	/**
+	* Enables the formatting and linting of the embedded snippets.
+Defaults to `false`. 
+	 */
+	enabled?: Bool;
+	/**
+	* The tags of the template literals that contain GraphQL. A template literal also matches
+when it's the only argument of a function in the list, such as ``graphql(`...`)``.
+
+Defaults to `["gql", "graphql"]`. 
+	 */
+	graphqlTags?: string[];
+}
 /**
  * Formatting options specific to the JavaScript files
  */
@@ -799,6 +860,10 @@ When formatting `package.json`, Biome will use `always` unless configured otherw
 	 * What's the max width of a line applied to JSON (and its super languages) files. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * When properties in objects are quoted in JSON5 files. Defaults to "preserve".
+	 */
+	quoteProperties?: JsonQuoteProperties;
 	/**
 	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
 	 */
@@ -812,6 +877,12 @@ export interface JsonLinterConfiguration {
 	 * Control the linter for JSON (and its super languages) files.
 	 */
 	enabled?: Bool;
+	/**
+	* Path to a local JSON Schema that the files must satisfy, relative to the
+configuration file. Usually set inside `overrides`. A `$schema` property in
+the file takes precedence. 
+	 */
+	schema?: string;
 }
 /**
  * Options that changes how the JSON parser behaves
@@ -841,6 +912,39 @@ export interface Rules {
 	style?: SeverityOrStyle;
 	suspicious?: SeverityOrSuspicious;
 }
+/**
+ * Options that changes how the Markdown formatter behaves
+ */
+export interface MarkdownFormatterConfiguration {
+	/**
+	 * The marker of the items of bullet lists. Defaults to dash.
+	 */
+	bulletListMarker?: BulletListMarker;
+	/**
+	 * The delimiter of italic and bold texts. Defaults to asterisk.
+	 */
+	emphasisStyle?: EmphasisStyle;
+	/**
+	 * Control the formatter for Markdown files.
+	 */
+	enabled?: Bool;
+	/**
+	 * The size of the indentation applied to the code blocks of Markdown files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to Markdown files. `auto` uses CRLF on Windows and LF on other platforms.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to Markdown files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+	/**
+	 * How the text of paragraphs is wrapped. Defaults to preserve.
+	 */
+	proseWrap?: ProseWrap;
+}
 export interface OverridePattern {
 	/**
 	 * Specific configuration for the Json language
@@ -887,13 +991,69 @@ match these patterns.
 	 * Specific configuration for the Json language
 	 */
 	linter?: OverrideLinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: MarkdownConfiguration;
 	/**
 	 * Specific configuration for additional plugins
 	 */
 	plugins?: Plugins;
+	/**
+	 * Specific configuration for the YAML language
+	 */
+	yaml?: YamlConfiguration;
 }
 export type PluginConfiguration = string;
 export type VcsClientKind = "git";
+/**
+ * Options that changes how the YAML formatter behaves
+ */
+export interface YamlFormatterConfiguration {
+	/**
+	 * Control the formatter for YAML files.
+	 */
+	enabled?: Bool;
+	/**
+	 * Whether block sequences that are the value of a mapping entry are indented. Defaults to true.
+	 */
+	indentSequences?: IndentSequences;
+	/**
+	 * The size of the indentation applied to YAML files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to YAML files. `auto` uses CRLF on Windows and LF on other platforms.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to YAML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+	/**
+	 * Whether comments are kept in the formatted YAML files. Defaults to true.
+	 */
+	preserveComments?: PreserveComments;
+	/**
+	 * The type of quotes used in YAML strings. Defaults to double.
+	 */
+	quoteStyle?: QuoteStyle;
+}
+/**
+ * Options that change how the YAML linter behaves.
+ */
+export interface YamlLinterConfiguration {
+	/**
+	 * Control the linter for YAML files.
+	 */
+	enabled?: Bool;
+	/**
+	* Path to a local JSON Schema that the files must satisfy, relative to the
+configuration file. Usually set inside `overrides`. A schema referenced
+by the file takes precedence. 
+	 */
+	schema?: string;
+}
 /**
  * A list of rules that belong to this group
  */
@@ -968,6 +1128,10 @@ export type Semicolons = "always" | "asNeeded";
  * Print trailing commas wherever possible in multi-line comma-separated syntactic structures for JavaScript/TypeScript files.
  */
 export type JsTrailingCommas = "all" | "es5" | "none";
+/**
+ * When the keys of objects should be quoted in JSON5 files.
+ */
+export type JsonQuoteProperties = "preserve" | "asNeeded";
 /**
  * Print trailing commas wherever possible in multi-line comma-separated syntactic structures for JSON files.
  */
@@ -994,6 +1158,21 @@ export type SeverityOrPerformance = GroupPlainConfiguration | Performance;
 export type SeverityOrSecurity = GroupPlainConfiguration | Security;
 export type SeverityOrStyle = GroupPlainConfiguration | Style;
 export type SeverityOrSuspicious = GroupPlainConfiguration | Suspicious;
+/**
+	* The marker of the items of bullet lists.
+
+When two bullet lists follow each other, the second list uses another marker,
+otherwise both lists would be merged into one. 
+	 */
+export type BulletListMarker = "dash" | "asterisk" | "plus";
+/**
+ * The delimiter of italic and bold texts.
+ */
+export type EmphasisStyle = "asterisk" | "underscore";
+/**
+ * How the text of paragraphs is wrapped.
+ */
+export type ProseWrap = "preserve" | "always" | "never";
 export interface OverrideAssistConfiguration {
 	/**
 	 * List of actions
@@ -1074,6 +1253,25 @@ export interface OverrideLinterConfiguration {
 	 */
 	rules?: Rules;
 }
+/**
+	* Whether a block sequence that is the value of a mapping entry is indented.
+
+```yaml
+# true
+key:
+  - item
+# false
+key:
+- item
+``` 
+	 */
+export type IndentSequences = boolean;
+/**
+	* Whether the comments of the document are kept in the formatted output.
+
+When false, the formatter removes every comment from the document. 
+	 */
+export type PreserveComments = boolean;
 export type OrganizeImportsConfiguration =
 	| RuleAssistPlainConfiguration
 	| RuleAssistWithOrganizeImportsOptions;
@@ -1864,6 +2062,11 @@ See https://biomejs.dev/linter/rules/no-ambiguous-anchor-text
 	 */
 	noAmbiguousAnchorText?: NoAmbiguousAnchorTextConfiguration;
 	/**
+	* Disallow plain scalars that are booleans only in YAML 1.1.
+See https://biomejs.dev/linter/rules/no-ambiguous-boolean-values 
+	 */
+	noAmbiguousBooleanValues?: NoAmbiguousBooleanValuesConfiguration;
+	/**
 	* Prevent usage of next/script's beforeInteractive strategy outside of pages/_document.js in a Next.js project.
 See https://biomejs.dev/linter/rules/no-before-interactive-script-outside-document 
 	 */
@@ -1929,6 +2132,11 @@ See https://biomejs.dev/linter/rules/no-duplicate-input-field-names
 	 */
 	noDuplicateInputFieldNames?: NoDuplicateInputFieldNamesConfiguration;
 	/**
+	* Disallow duplicate keys in a mapping.
+See https://biomejs.dev/linter/rules/no-duplicate-mapping-keys 
+	 */
+	noDuplicateMappingKeys?: NoDuplicateMappingKeysConfiguration;
+	/**
 	* Require all variable definitions to be unique.
 See https://biomejs.dev/linter/rules/no-duplicate-variable-names 
 	 */
@@ -1989,6 +2197,11 @@ See https://biomejs.dev/linter/rules/no-increment-decrement
 	 */
 	noIncrementDecrement?: NoIncrementDecrementConfiguration;
 	/**
+	* Enforce that documents satisfy their JSON Schema.
+See https://biomejs.dev/linter/rules/no-json-schema-violations 
+	 */
+	noJsonSchemaViolations?: NoJsonSchemaViolationsConfiguration;
+	/**
 	* Disallow string literals inside JSX elements.
 See https://biomejs.dev/linter/rules/no-jsx-literals 
 	 */
@@ -2079,6 +2292,11 @@ See https://biomejs.dev/linter/rules/no-ternary
 	 */
 	noTernary?: NoTernaryConfiguration;
 	/**
+	* Disallow aliases that refer to an undeclared anchor.
+See https://biomejs.dev/linter/rules/no-undeclared-aliases 
+	 */
+	noUndeclaredAliases?: NoUndeclaredAliasesConfiguration;
+	/**
 	* Disallow the use of undeclared environment variables.
 See https://biomejs.dev/linter/rules/no-undeclared-env-vars 
 	 */
@@ -2188,6 +2406,11 @@ See https://biomejs.dev/linter/rules/use-consistent-graphql-descriptions
 	 */
 	useConsistentGraphqlDescriptions?: UseConsistentGraphqlDescriptionsConfiguration;
 	/**
+	* Require nested blocks to be indented by the same number of spaces.
+See https://biomejs.dev/linter/rules/use-consistent-indentation 
+	 */
+	useConsistentIndentation?: UseConsistentIndentationConfiguration;
+	/**
 	* Enforce consistent use of either method signatures or function properties within interfaces and type aliases.
 See https://biomejs.dev/linter/rules/use-consistent-method-signatures 
 	 */
@@ -2278,6 +2501,11 @@ See https://biomejs.dev/linter/rules/use-sorted-classes
 	 */
 	useSortedClasses?: UseSortedClassesConfiguration;
 	/**
+	* Require the keys of a mapping to be sorted.
+See https://biomejs.dev/linter/rules/use-sorted-mapping-keys 
+	 */
+	useSortedMappingKeys?: UseSortedMappingKeysConfiguration;
+	/**
 	* Enforce the use of the spread operator over .apply().
 See https://biomejs.dev/linter/rules/use-spread 
 	 */
@@ -3804,6 +4032,9 @@ export type UseYieldConfiguration =
 export type NoAmbiguousAnchorTextConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoAmbiguousAnchorTextOptions;
+export type NoAmbiguousBooleanValuesConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoAmbiguousBooleanValuesOptions;
 export type NoBeforeInteractiveScriptOutsideDocumentConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoBeforeInteractiveScriptOutsideDocumentOptions;
@@ -3843,6 +4074,9 @@ export type NoDuplicateGraphqlOperationNameConfiguration =
 export type NoDuplicateInputFieldNamesConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoDuplicateInputFieldNamesOptions;
+export type NoDuplicateMappingKeysConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoDuplicateMappingKeysOptions;
 export type NoDuplicateVariableNamesConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoDuplicateVariableNamesOptions;
@@ -3879,6 +4113,9 @@ export type NoImportCyclesConfiguration =
 export type NoIncrementDecrementConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoIncrementDecrementOptions;
+export type NoJsonSchemaViolationsConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoJsonSchemaViolationsOptions;
 export type NoJsxLiteralsConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoJsxLiteralsOptions;
@@ -3933,6 +4170,9 @@ export type NoSyncScriptsConfiguration =
 export type NoTernaryConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoTernaryOptions;
+export type NoUndeclaredAliasesConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoUndeclaredAliasesOptions;
 export type NoUndeclaredEnvVarsConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoUndeclaredEnvVarsOptions;
@@ -3996,6 +4236,9 @@ export type UseConsistentEnumValueTypeConfiguration =
 export type UseConsistentGraphqlDescriptionsConfiguration =
 	| RulePlainConfiguration
 	| RuleWithUseConsistentGraphqlDescriptionsOptions;
+export type UseConsistentIndentationConfiguration =
+	| RulePlainConfiguration
+	| RuleWithUseConsistentIndentationOptions;
 export type UseConsistentMethodSignaturesConfiguration =
 	| RulePlainConfiguration
 	| RuleWithUseConsistentMethodSignaturesOptions;
@@ -4050,6 +4293,9 @@ export type UseRequiredScriptsConfiguration =
 export type UseSortedClassesConfiguration =
 	| RulePlainConfiguration
 	| RuleWithUseSortedClassesOptions;
+export type UseSortedMappingKeysConfiguration =
+	| RulePlainConfiguration
+	| RuleWithUseSortedMappingKeysOptions;
 export type UseSpreadConfiguration =
 	| RulePlainConfiguration
 	| RuleWithUseSpreadOptions;
@@ -5341,6 +5587,10 @@ export interface RuleWithNoAmbiguousAnchorTextOptions {
 	level: RulePlainConfiguration;
 	options?: NoAmbiguousAnchorTextOptions;
 }
+export interface RuleWithNoAmbiguousBooleanValuesOptions {
+	level: RulePlainConfiguration;
+	options?: NoAmbiguousBooleanValuesOptions;
+}
 export interface RuleWithNoBeforeInteractiveScriptOutsideDocumentOptions {
 	level: RulePlainConfiguration;
 	options?: NoBeforeInteractiveScriptOutsideDocumentOptions;
@@ -5394,6 +5644,10 @@ export interface RuleWithNoDuplicateInputFieldNamesOptions {
 	level: RulePlainConfiguration;
 	options?: NoDuplicateInputFieldNamesOptions;
 }
+export interface RuleWithNoDuplicateMappingKeysOptions {
+	level: RulePlainConfiguration;
+	options?: NoDuplicateMappingKeysOptions;
+}
 export interface RuleWithNoDuplicateVariableNamesOptions {
 	level: RulePlainConfiguration;
 	options?: NoDuplicateVariableNamesOptions;
@@ -5444,6 +5698,10 @@ export interface RuleWithNoIncrementDecrementOptions {
 	level: RulePlainConfiguration;
 	options?: NoIncrementDecrementOptions;
 }
+export interface RuleWithNoJsonSchemaViolationsOptions {
+	level: RulePlainConfiguration;
+	options?: NoJsonSchemaViolationsOptions;
+}
 export interface RuleWithNoJsxLiteralsOptions {
 	level: RulePlainConfiguration;
 	options?: NoJsxLiteralsOptions;
@@ -5519,6 +5777,10 @@ export interface RuleWithNoTernaryOptions {
 	level: RulePlainConfiguration;
 	options?: NoTernaryOptions;
 }
+export interface RuleWithNoUndeclaredAliasesOptions {
+	level: RulePlainConfiguration;
+	options?: NoUndeclaredAliasesOptions;
+}
 export interface RuleWithNoUndeclaredEnvVarsOptions {
 	level: RulePlainConfiguration;
 	options?: NoUndeclaredEnvVarsOptions;
@@ -5609,6 +5871,10 @@ export interface RuleWithUseConsistentGraphqlDescriptionsOptions {
 	level: RulePlainConfiguration;
 	options?: UseConsistentGraphqlDescriptionsOptions;
 }
+export interface RuleWithUseConsistentIndentationOptions {
+	level: RulePlainConfiguration;
+	options?: UseConsistentIndentationOptions;
+}
 export interface RuleWithUseConsistentMethodSignaturesOptions {
 	level: RulePlainConfiguration;
 	options?: UseConsistentMethodSignaturesOptions;
@@ -5683,6 +5949,10 @@ export interface RuleWithUseSortedClassesOptions {
 	level: RulePlainConfiguration;
 	options?: UseSortedClassesOptions;
 }
+export interface RuleWithUseSortedMappingKeysOptions {
+	level: RulePlainConfiguration;
+	options?: UseSortedMappingKeysOptions;
+}
 export interface RuleWithUseSpreadOptions {
 	fix?: FixKind;
 	level: RulePlainConfiguration;
@@ -6874,6 +7144,7 @@ export interface NoAmbiguousAnchorTextOptions {
 	 */
 	words?: string[];
 }
+export type NoAmbiguousBooleanValuesOptions = {};
 export type NoBeforeInteractiveScriptOutsideDocumentOptions = {};
 export type NoContinueOptions = {};
 export type NoDeprecatedImportsOptions = {};
@@ -6892,6 +7163,7 @@ export type NoDuplicateEnumValuesOptions = {};
 export type NoDuplicateFieldDefinitionNamesOptions = {};
 export type NoDuplicateGraphqlOperationNameOptions = {};
 export type NoDuplicateInputFieldNamesOptions = {};
+export type NoDuplicateMappingKeysOptions = {};
 export type NoDuplicateVariableNamesOptions = {};
 export type NoDuplicatedSpreadPropsOptions = {};
 export interface NoEmptySourceOptions {
@@ -6936,6 +7208,7 @@ export interface NoIncrementDecrementOptions {
 	 */
 	allowForLoopAfterthoughts?: boolean;
 }
+export type NoJsonSchemaViolationsOptions = {};
 export interface NoJsxLiteralsOptions {
 	/**
 	 * An array of strings that won't trigger the rule. Whitespaces are taken into consideration
@@ -6973,6 +7246,7 @@ export type NoScriptUrlOptions = {};
 export type NoShadowOptions = {};
 export type NoSyncScriptsOptions = {};
 export type NoTernaryOptions = {};
+export type NoUndeclaredAliasesOptions = {};
 export interface NoUndeclaredEnvVarsOptions {
 	/**
 	* Environment variables that should always be allowed.
@@ -7027,6 +7301,12 @@ export interface UseConsistentGraphqlDescriptionsOptions {
 	 */
 	style?: UseConsistentGraphqlDescriptionsStyle;
 }
+export interface UseConsistentIndentationOptions {
+	/**
+	 * The number of spaces a nested block is indented by. Defaults to `2`.
+	 */
+	indentWidth?: number;
+}
 /**
  * Options type for `useConsistentMethodSignatures`.
  */
@@ -7089,6 +7369,12 @@ export interface UseSortedClassesOptions {
 	 */
 	functions?: string[];
 }
+export interface UseSortedMappingKeysOptions {
+	/**
+	 * The order used to compare the keys. Defaults to `natural`.
+	 */
+	sortOrder?: SortOrder;
+}
 export type UseSpreadOptions = {};
 export interface UseVueConsistentDefinePropsDeclarationOptions {
 	style?: DeclarationStyle;
@@ -7864,6 +8150,7 @@ export type Category =
 	| "lint/correctness/useValidTypeof"
 	| "lint/correctness/useYield"
 	| "lint/nursery/noAmbiguousAnchorText"
+	| "lint/nursery/noAmbiguousBooleanValues"
 	| "lint/nursery/noBeforeInteractiveScriptOutsideDocument"
 	| "lint/nursery/noColorInvalidHex"
 	| "lint/nursery/noContinue"
@@ -7878,6 +8165,7 @@ export type Category =
 	| "lint/nursery/noDuplicateFieldDefinitionNames"
 	| "lint/nursery/noDuplicateGraphqlOperationName"
 	| "lint/nursery/noDuplicateInputFieldNames"
+	| "lint/nursery/noDuplicateMappingKeys"
 	| "lint/nursery/noDuplicateVariableNames"
 	| "lint/nursery/noDuplicatedSpreadProps"
 	| "lint/nursery/noEmptySource"
@@ -7891,6 +8179,7 @@ export type Category =
 	| "lint/nursery/noImplicitCoercion"
 	| "lint/nursery/noImportCycles"
 	| "lint/nursery/noIncrementDecrement"
+	| "lint/nursery/noJsonSchemaViolations"
 	| "lint/nursery/noJsxLiterals"
 	| "lint/nursery/noJsxPropsBind"
 	| "lint/nursery/noLeakedRender"
@@ -7910,6 +8199,7 @@ export type Category =
 	| "lint/nursery/noShadow"
 	| "lint/nursery/noSyncScripts"
 	| "lint/nursery/noTernary"
+	| "lint/nursery/noUndeclaredAliases"
 	| "lint/nursery/noUndeclaredEnvVars"
 	| "lint/nursery/noUnknownAttribute"
 	| "lint/nursery/noUnnecessaryConditions"
@@ -7934,6 +8224,7 @@ export type Category =
 	| "lint/nursery/useConsistentArrowReturn"
 	| "lint/nursery/useConsistentEnumValueType"
 	| "lint/nursery/useConsistentGraphqlDescriptions"
+	| "lint/nursery/useConsistentIndentation"
 	| "lint/nursery/useConsistentMethodSignatures"
 	| "lint/nursery/useConsistentObjectDefinition"
 	| "lint/nursery/useDeprecatedDate"
@@ -7956,6 +8247,7 @@ export type Category =
 	| "lint/nursery/useRegexpExec"
 	| "lint/nursery/useRequiredScripts"
 	| "lint/nursery/useSortedClasses"
+	| "lint/nursery/useSortedMappingKeys"
 	| "lint/nursery/useSpread"
 	| "lint/nursery/useVueConsistentDefinePropsDeclaration"
 	| "lint/nursery/useVueConsistentVBindStyle"
@@ -8180,6 +8472,7 @@ export type Category =
 	| "init"
 	| "configuration"
 	| "assist"
+	| "baseline"
 	| "migrate"
 	| "deserialize"
 	| "plugin"
@@ -8254,7 +8547,8 @@ export type DiagnosticTag =
 	| "internal"
 	| "unnecessaryCode"
 	| "deprecatedCode"
-	| "verbose";
+	| "verbose"
+	| "suppressed";
 /**
 	* The category for a log advice, defines how the message should be presented
 to the user. 
@@ -8401,7 +8695,9 @@ export type DocumentFileSource =
 	| { Css: CssFileSource }
 	| { Graphql: GraphqlFileSource }
 	| { Html: HtmlFileSource }
-	| { Grit: GritFileSource };
+	| { Grit: GritFileSource }
+	| { Yaml: YamlFileSource }
+	| { Markdown: MarkdownFileSource };
 export interface JsFileSource {
 	/**
 	* Used to mark if the JavaScript is embedded inside some particular files. This affects the parsing.
@@ -8430,6 +8726,8 @@ export interface HtmlFileSource {
 export interface GritFileSource {
 	variant: GritVariant;
 }
+export type YamlFileSource = {};
+export type MarkdownFileSource = {};
 export type EmbeddingKind =
 	| "Svelte"
 	| "None"
@@ -8451,7 +8749,8 @@ export type EmbeddingKind =
 	  };
 export type Language =
 	| "javaScript"
-	| { typeScript: { definition_file: boolean } };
+	| { typeScript: { definition_file: boolean } }
+	| "flow";
 /**
 	* Is the source file an ECMAScript Module or Script.
 Changes the parsing semantic. 
@@ -8468,16 +8767,22 @@ export type LanguageVersion = "eS2022" | "eSNext";
 /**
  * It represents the extension of the file
  */
-export type JsonFileVariant = "standard" | "jsonc";
+export type JsonFileVariant = "standard" | "jsonc" | "json5";
 /**
 	* The style of CSS contained in the file.
 
 Currently, Biome aims to be compatible with
 the latest Recommendation level standards.
 
-It also supports Tailwind CSS syntax additions, when the parser option is enabled. 
-	 */
-export type CssVariant = "standard" | "cssModules" | "tailwindCss";
+It also supports Tailwind CSS syntax additions, when the parser option is enabled,
+and the SCSS and Less syntaxes. 
+	 */
+export type CssVariant =
+	| "standard"
+	| "cssModules"
+	| "tailwindCss"
+	| "scss"
+	| "less";
 /**
  * The style of GraphQL contained in the file.
  */
@@ -8533,6 +8838,18 @@ export interface UpdateModuleGraphParams {
 	updateKind: UpdateKind;
 }
 export type UpdateKind = "addOrUpdate" | "remove";
+export interface GetDependentPackagesParams {
+	/**
+	 * The paths whose packages should be looked up.
+	 */
+	paths: BiomePath[];
+}
+export interface GetDependentPackagesResult {
+	/**
+	 * The paths of the dependent packages, sorted and without duplicates.
+	 */
+	packagePaths: BiomePath[];
+}
 export interface GetSyntaxTreeParams {
 	path: BiomePath;
 	projectKey: ProjectKey;
@@ -8635,6 +8952,11 @@ export interface PullDiagnosticsParams {
 	 * When `false` the diagnostics, don't have code frames of the code actions (fixes, suppressions, etc.)
 	 */
 	pullCodeActions: boolean;
+	/**
+	* When `true`, the diagnostics suppressed by suppression comments are returned too,
+tagged as `suppressed` 
+	 */
+	pullSuppressed?: boolean;
 	skip?: AnalyzerSelector[];
 }
 export type RuleCategories = RuleCategory[];
@@ -8814,6 +9136,11 @@ export interface RenameParams {
 	symbolAt: TextSize;
 }
 export interface RenameResult {
+	/**
+	* Edits to apply to other files, when the renamed symbol is exported and
+other modules refer to it 
+	 */
+	fileEdits?: RenameFileEdit[];
 	/**
 	 * List of text edit operations to apply on the source code
 	 */
@@ -8823,11 +9150,283 @@ export interface RenameResult {
 	 */
 	range: TextRange;
 }
+export interface RenameFileEdit {
+	/**
+	 * List of text edit operations to apply on the source code of the file
+	 */
+	indels: TextEdit;
+	/**
+	 * Path of the file to edit
+	 */
+	path: BiomePath;
+	/**
+	 * Range of source code modified in the file
+	 */
+	range: TextRange;
+}
+export interface PrepareRenameParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+	symbolAt: TextSize;
+}
+export interface PrepareRenameResult {
+	/**
+	 * Current name of the symbol
+	 */
+	placeholder?: string;
+	/**
+	 * Range of the symbol that would be renamed, if it can be renamed
+	 */
+	range?: TextRange;
+}
+export interface GetHoverParams {
+	/**
+	 * Position of the cursor inside the file
+	 */
+	offset: TextSize;
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface GetHoverResult {
+	/**
+	 * Markdown content describing the symbol, such as its inferred type
+	 */
+	content?: string;
+	/**
+	 * Range of the symbol the hover information refers to
+	 */
+	range?: TextRange;
+}
+export interface GetCompletionsParams {
+	/**
+	 * Position of the cursor inside the file
+	 */
+	offset: TextSize;
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface GetCompletionsResult {
+	items: CompletionItem[];
+}
+export interface CompletionItem {
+	/**
+	 * Markdown content describing the completion
+	 */
+	documentation?: string;
+	kind: CompletionItemKind;
+	/**
+	 * Text inserted by the completion
+	 */
+	label: string;
+}
+export type CompletionItemKind = "property" | "value";
+export interface GetDefinitionParams {
+	/**
+	 * Position of the cursor inside the file
+	 */
+	offset: TextSize;
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface GetDefinitionResult {
+	/**
+	 * Locations where the symbol under the cursor is declared
+	 */
+	locations: SymbolLocation[];
+}
+export interface SymbolLocation {
+	/**
+	 * Path of the file that contains the symbol
+	 */
+	path: BiomePath;
+	/**
+	 * Range of the symbol inside the file
+	 */
+	range: TextRange;
+}
+export interface GetReferencesParams {
+	/**
+	 * Whether the declaration of the symbol should be part of the result
+	 */
+	includeDeclaration?: boolean;
+	/**
+	 * Position of the cursor inside the file
+	 */
+	offset: TextSize;
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface GetReferencesResult {
+	/**
+	 * Locations where the symbol under the cursor is referenced
+	 */
+	locations: SymbolLocation[];
+}
+export interface GetDocumentSymbolsParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface GetDocumentSymbolsResult {
+	/**
+	 * The symbols declared at the top level of the file
+	 */
+	symbols: DocumentSymbol[];
+}
+export interface DocumentSymbol {
+	/**
+	 * The symbols declared inside this symbol, e.g. the members of a class
+	 */
+	children: DocumentSymbol[];
+	kind: SymbolKind;
+	/**
+	 * The name of the symbol, e.g. the name of a class or the selector of a CSS rule
+	 */
+	name: string;
+	/**
+	 * Range of the whole declaration of the symbol
+	 */
+	range: TextRange;
+	/**
+	 * Range of the name of the symbol, contained in [DocumentSymbol::range]
+	 */
+	selectionRange: TextRange;
+}
+export type SymbolKind =
+	| "module"
+	| "namespace"
+	| "class"
+	| "method"
+	| "property"
+	| "field"
+	| "constructor"
+	| "enum"
+	| "interface"
+	| "function"
+	| "variable"
+	| "constant"
+	| "string"
+	| "number"
+	| "boolean"
+	| "array"
+	| "object"
+	| "null"
+	| "enumMember"
+	| "struct"
+	| "typeParameter";
+export interface GetWorkspaceSymbolsParams {
+	projectKey: ProjectKey;
+	/**
+	* The text to search. The symbols match when their name contains the
+characters of the query in the same order, ignoring the case. 
+	 */
+	query: string;
+}
+export interface GetWorkspaceSymbolsResult {
+	/**
+	 * The symbols exported by the modules of the project that match the query
+	 */
+	symbols: WorkspaceSymbol[];
+}
+export interface WorkspaceSymbol {
+	kind: SymbolKind;
+	/**
+	 * Location of the declaration of the symbol
+	 */
+	location: SymbolLocation;
+	/**
+	 * The name the symbol is exported with
+	 */
+	name: string;
+}
+export interface GetFoldingRangesParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface GetFoldingRangesResult {
+	ranges: FoldingRange[];
+}
+export interface FoldingRange {
+	kind: FoldingRangeKind;
+	/**
+	 * Range of the region that can be folded, including its delimiters
+	 */
+	range: TextRange;
+}
+export type FoldingRangeKind = "region" | "comment" | "imports";
+export interface GetSemanticTokensParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface GetSemanticTokensResult {
+	/**
+	 * The tokens of the file, sorted by position and not overlapping
+	 */
+	tokens: SemanticToken[];
+}
+export interface SemanticToken {
+	kind: SemanticTokenKind;
+	modifiers?: SemanticTokenModifier[];
+	range: TextRange;
+}
+export type SemanticTokenKind =
+	| "namespace"
+	| "type"
+	| "class"
+	| "enum"
+	| "interface"
+	| "struct"
+	| "typeParameter"
+	| "parameter"
+	| "variable"
+	| "enumMember"
+	| "function";
+export type SemanticTokenModifier =
+	| "declaration"
+	| "readonly"
+	| "defaultLibrary"
+	| "unused";
+export interface GetInlayHintsParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+	/**
+	 * Range of the file the hints are requested for
+	 */
+	range: TextRange;
+}
+export interface GetInlayHintsResult {
+	/**
+	 * The hints of the range, sorted by position
+	 */
+	hints: InlayHint[];
+}
+export interface InlayHint {
+	kind: InlayHintKind;
+	label: string;
+	/**
+	 * Position where the hint is displayed
+	 */
+	position: TextSize;
+}
+export type InlayHintKind = "returnType" | "variableType" | "parameterName";
+export interface GetProjectFilesParams {
+	projectKey: ProjectKey;
+}
+export interface GetProjectFilesResult {
+	/**
+	 * The paths of the files, sorted
+	 */
+	paths: BiomePath[];
+}
 export interface ParsePatternParams {
 	defaultLanguage: GritTargetLanguage;
 	pattern: string;
 }
-export type GritTargetLanguage = "CSS" | "JavaScript";
+export type GritTargetLanguage =
+	| "CSS"
+	| "GraphQL"
+	| "HTML"
+	| "JavaScript"
+	| "JSON";
 export interface ParsePatternResult {
 	patternId: PatternId;
 }
@@ -8840,6 +9439,10 @@ export interface SearchPatternParams {
 export interface SearchResults {
 	matches: TextRange[];
 	path: BiomePath;
+	/**
+	 * The content of the file after applying the rewrites of the pattern
+	 */
+	rewritten?: string;
 }
 export interface DropPatternParams {
 	pattern: PatternId;
@@ -8855,6 +9458,9 @@ export interface Workspace {
 	fileExists(params: FileExitsParams): Promise<boolean>;
 	isPathIgnored(params: PathIsIgnoredParams): Promise<boolean>;
 	updateModuleGraph(params: UpdateModuleGraphParams): Promise<null>;
+	getDependentPackages(
+		params: GetDependentPackagesParams,
+	): Promise<GetDependentPackagesResult>;
 	getSyntaxTree(params: GetSyntaxTreeParams): Promise<GetSyntaxTreeResult>;
 	checkFileSize(params: CheckFileSizeParams): Promise<CheckFileSizeResult>;
 	getFileContent(params: GetFileContentParams): Promise<string>;
@@ -8876,6 +9482,27 @@ export interface Workspace {
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
+	prepareRename(params: PrepareRenameParams): Promise<PrepareRenameResult>;
+	getHover(params: GetHoverParams): Promise<GetHoverResult>;
+	getCompletions(params: GetCompletionsParams): Promise<GetCompletionsResult>;
+	getDefinition(params: GetDefinitionParams): Promise<GetDefinitionResult>;
+	getReferences(params: GetReferencesParams): Promise<GetReferencesResult>;
+	getDocumentSymbols(
+		params: GetDocumentSymbolsParams,
+	): Promise<GetDocumentSymbolsResult>;
+	getWorkspaceSymbols(
+		params: GetWorkspaceSymbolsParams,
+	): Promise<GetWorkspaceSymbolsResult>;
+	getFoldingRanges(
+		params: GetFoldingRangesParams,
+	): Promise<GetFoldingRangesResult>;
+	getSemanticTokens(
+		params: GetSemanticTokensParams,
+	): Promise<GetSemanticTokensResult>;
+	getInlayHints(params: GetInlayHintsParams): Promise<GetInlayHintsResult>;
+	getProjectFiles(
+		params: GetProjectFilesParams,
+	): Promise<GetProjectFilesResult>;
 	parsePattern(params: ParsePatternParams): Promise<ParsePatternResult>;
 	searchPattern(params: SearchPatternParams): Promise<SearchResults>;
 	dropPattern(params: DropPatternParams): Promise<null>;
@@ -8913,6 +9540,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		updateModuleGraph(params) {
 			return transport.request("biome/update_module_graph", params);
 		},
+		getDependentPackages(params) {
+			return transport.request("biome/get_dependent_packages", params);
+		},
 		getSyntaxTree(params) {
 			return transport.request("biome/get_syntax_tree", params);
 		},
@@ -8964,6 +9594,39 @@ export function createWorkspace(transport: Transport): Workspace {
 		rename(params) {
 			return transport.request("biome/rename", params);
 		},
+		prepareRename(params) {
+			return transport.request("biome/prepare_rename", params);
+		},
+		getHover(params) {
+			return transport.request("biome/get_hover", params);
+		},
+		getCompletions(params) {
+			return transport.request("biome/get_completions", params);
+		},
+		getDefinition(params) {
+			return transport.request("biome/get_definition", params);
+		},
+		getReferences(params) {
+			return transport.request("biome/get_references", params);
+		},
+		getDocumentSymbols(params) {
+			return transport.request("biome/get_document_symbols", params);
+		},
+		getWorkspaceSymbols(params) {
+			return transport.request("biome/get_workspace_symbols", params);
+		},
+		getFoldingRanges(params) {
+			return transport.request("biome/get_folding_ranges", params);
+		},
+		getSemanticTokens(params) {
+			return transport.request("biome/get_semantic_tokens", params);
+		},
+		getInlayHints(params) {
+			return transport.request("biome/get_inlay_hints", params);
+		},
+		getProjectFiles(params) {
+			return transport.request("biome/get_project_files", params);
+		},
 		parsePattern(params) {
 			return transport.request("biome/parse_pattern", params);
 		},
@@ -40,6 +40,9 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         ("%=", "PERCENTEQ"),
         ("@", "AT"),
         ("$=", "DOLLAR_EQ"),
+        ("$", "DOLLAR"),
+        ("==", "EQ2"),
+        ("...", "DOT3"),
         ("~=", "TILDE_EQ"),
         ("-->", "CDC"),
         ("<!--", "CDO"),
@@ -132,6 +135,28 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "plugin",
         "slot",
         "inline",
+        // SCSS keywords
+        "mixin",
+        "include",
+        "content",
+        "extend",
+        "use",
+        "forward",
+        "function",
+        "return",
+        "each",
+        "for",
+        "while",
+        "through",
+        "debug",
+        "warn",
+        "error",
+        "show",
+        "hide",
+        "with",
+        "optional",
+        // Less keywords
+        "when",
         // HERE: Add new regular keywords _above_ here. Be sure to also add them
         // to `consume_identifier` in `biome_css_parser/src/lexer/mod.rs` as well.
         // CSS-wide keywords
@@ -544,6 +569,74 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "TW_FUNCTIONAL_UTILITY_NAME",
         "TW_CUSTOM_VARIANT_SHORTHAND",
         "TW_SOURCE_INLINE",
+        // SCSS nodes
+        "SCSS_IDENTIFIER",
+        "SCSS_DECLARATION",
+        "SCSS_VARIABLE_MODIFIER_LIST",
+        "SCSS_VARIABLE_MODIFIER",
+        "SCSS_QUALIFIED_NAME",
+        "SCSS_OPERATOR",
+        "SCSS_EXPRESSION",
+        "SCSS_MAP_EXPRESSION",
+        "SCSS_MAP_EXPRESSION_PAIR_LIST",
+        "SCSS_MAP_EXPRESSION_PAIR",
+        "SCSS_PARENTHESIZED_EXPRESSION",
+        "SCSS_PLACEHOLDER_SELECTOR",
+        "SCSS_USE_AT_RULE",
+        "SCSS_USE_AS_CLAUSE",
+        "SCSS_USE_ALL_NAMESPACE",
+        "SCSS_WITH_CLAUSE",
+        "SCSS_FORWARD_AT_RULE",
+        "SCSS_FORWARD_AS_CLAUSE",
+        "SCSS_FORWARD_VISIBILITY_CLAUSE",
+        "SCSS_FORWARD_MEMBER_LIST",
+        "SCSS_MIXIN_AT_RULE",
+        "SCSS_FUNCTION_AT_RULE",
+        "SCSS_PARAMETERS",
+        "SCSS_PARAMETER_LIST",
+        "SCSS_PARAMETER",
+        "SCSS_PARAMETER_DEFAULT_VALUE",
+        "SCSS_INCLUDE_AT_RULE",
+        "SCSS_ARGUMENTS",
+        "SCSS_ARGUMENT_LIST",
+        "SCSS_KEYWORD_ARGUMENT",
+        "SCSS_CONTENT_AT_RULE",
+        "SCSS_EXTEND_AT_RULE",
+        "SCSS_EXTEND_OPTIONAL_MODIFIER",
+        "SCSS_RETURN_AT_RULE",
+        "SCSS_IF_AT_RULE",
+        "SCSS_ELSE_CLAUSE",
+        "SCSS_EACH_AT_RULE",
+        "SCSS_EACH_BINDING_LIST",
+        "SCSS_FOR_AT_RULE",
+        "SCSS_WHILE_AT_RULE",
+        "SCSS_DEBUG_AT_RULE",
+        "SCSS_WARN_AT_RULE",
+        "SCSS_ERROR_AT_RULE",
+        // Less nodes
+        "LESS_IDENTIFIER",
+        "LESS_DECLARATION",
+        "LESS_OPERATOR",
+        "LESS_EXPRESSION",
+        "LESS_PARENTHESIZED_EXPRESSION",
+        "LESS_ESCAPED_VALUE",
+        "LESS_MIXIN_DECLARATION",
+        "LESS_MIXIN_PARAMETERS",
+        "LESS_MIXIN_PARAMETER_LIST",
+        "LESS_MIXIN_SEMICOLON_PARAMETERS",
+        "LESS_MIXIN_SEMICOLON_PARAMETER_LIST",
+        "LESS_MIXIN_PARAMETER",
+        "LESS_MIXIN_PARAMETER_DEFAULT_VALUE",
+        "LESS_MIXIN_REST_PARAMETER",
+        "LESS_MIXIN_GUARD",
+        "LESS_MIXIN_CALL",
+        "LESS_MIXIN_PATH_SEGMENT_LIST",
+        "LESS_MIXIN_PATH_SEGMENT",
+        "LESS_MIXIN_ARGUMENTS",
+        "LESS_MIXIN_ARGUMENT_LIST",
+        "LESS_MIXIN_SEMICOLON_ARGUMENTS",
+        "LESS_MIXIN_SEMICOLON_ARGUMENT_LIST",
+        "LESS_KEYWORD_ARGUMENT",
         // Unknowns
         "CSS_UNKNOWN_BLOCK_AT_RULE",
         "CSS_UNKNOWN_VALUE_AT_RULE",
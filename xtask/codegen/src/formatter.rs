@@ -569,6 +569,10 @@ enum NodeDialect {
     Svelte,
     Vue,
     Tailwind,
+    Scss,
+    Less,
+    Yaml,
+    Markdown,
 }
 
 impl NodeDialect {
@@ -582,6 +586,10 @@ impl NodeDialect {
             Self::Grit,
             Self::Graphql,
             Self::Html,
+            Self::Scss,
+            Self::Less,
+            Self::Yaml,
+            Self::Markdown,
         ]
     }
 
@@ -603,6 +611,10 @@ impl NodeDialect {
             Self::Svelte => "svelte",
             Self::Vue => "vue",
             Self::Tailwind => "tailwind",
+            Self::Scss => "scss",
+            Self::Less => "less",
+            Self::Yaml => "yaml",
+            Self::Markdown => "markdown",
         }
     }
 
@@ -620,6 +632,10 @@ impl NodeDialect {
             "Svelte" => Self::Svelte,
             "Vue" => Self::Vue,
             "Tw" => Self::Tailwind,
+            "Scss" => Self::Scss,
+            "Less" => Self::Less,
+            "Yaml" => Self::Yaml,
+            "Md" => Self::Markdown,
             _ => {
                 eprintln!("missing prefix {name}");
                 Self::Js
@@ -842,8 +858,10 @@ fn get_node_concept(
                 _ => NodeConcept::Auxiliary,
             },
 
-            // TODO: implement formatter
-            LanguageKind::Yaml => NodeConcept::Auxiliary,
+            LanguageKind::Yaml => match name {
+                _ if name.ends_with("Scalar") || name == "AliasNode" => NodeConcept::Value,
+                _ => NodeConcept::Auxiliary,
+            },
 
             LanguageKind::Tailwind => match name {
                 _ if name.ends_with("Value") => NodeConcept::Value,
@@ -865,8 +883,13 @@ fn name_to_module(kind: &NodeKind, in_name: &str, language: LanguageKind) -> Nod
 
     // AnyJsX
     if dialect_prefix == "Any" {
-        let (third_upper_start, _) = upper_case_indices.next().expect("Node name malformed");
-        (dialect_prefix, name) = name.split_at(third_upper_start - dialect_prefix.len());
+        // Some Markdown unions don't repeat the dialect prefix, e.g. AnyLeafBlock
+        if language == LanguageKind::Markdown && !name.starts_with("Md") {
+            dialect_prefix = "Md";
+        } else {
+            let (third_upper_start, _) = upper_case_indices.next().expect("Node name malformed");
+            (dialect_prefix, name) = name.split_at(third_upper_start - dialect_prefix.len());
+        }
     }
 
     let dialect = NodeDialect::from_str(dialect_prefix);
@@ -915,7 +938,7 @@ impl LanguageKind {
             Self::Grit => "GritFormatter",
             Self::Html => "HtmlFormatter",
             Self::Yaml => "YamlFormatter",
-            Self::Markdown => "DemoFormatter",
+            Self::Markdown => "MarkdownFormatter",
             Self::Tailwind => "TailwindFormatter",
         };
 
@@ -931,7 +954,7 @@ impl LanguageKind {
             Self::Grit => "GritFormatContext",
             Self::Html => "HtmlFormatContext",
             Self::Yaml => "YamlFormatContext",
-            Self::Markdown => "DemoFormatterContext",
+            Self::Markdown => "MarkdownFormatContext",
             Self::Tailwind => "TailwindFormatContext",
         };
 
@@ -139,6 +139,11 @@ pub fn generate_analyzer() -> Result<()> {
         &["lint"],
         update_html_registry_builder,
     )?;
+    generate_analyzer_crate(
+        "biome_yaml_analyze",
+        &["lint"],
+        update_yaml_registry_builder,
+    )?;
     Ok(())
 }
 
@@ -337,3 +342,22 @@ fn update_html_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>)
 
     Ok(())
 }
+
+fn update_yaml_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+    let path = project_root().join("crates/biome_yaml_analyze/src/registry.rs");
+
+    let categories = analyzers.into_values();
+
+    let tokens = reformat(quote! {
+        use biome_analyze::RegistryVisitor;
+        use biome_yaml_syntax::YamlLanguage;
+
+        pub fn visit_registry<V: RegistryVisitor<YamlLanguage>>(registry: &mut V) {
+            #( #categories )*
+        }
+    })?;
+
+    fs2::write(path, tokens)?;
+
+    Ok(())
+}
@@ -239,6 +239,9 @@ pub(crate) fn generate_workspace_bindings(mode: Mode) -> Result<()> {
                 AnyJsDeclaration::TsModuleDeclaration(decl) => {
                     AnyJsDeclarationClause::TsModuleDeclaration(decl)
                 }
+                AnyJsDeclaration::TsOpaqueTypeAliasDeclaration(decl) => {
+                    AnyJsDeclarationClause::TsOpaqueTypeAliasDeclaration(decl)
+                }
                 AnyJsDeclaration::TsTypeAliasDeclaration(decl) => {
                     AnyJsDeclarationClause::TsTypeAliasDeclaration(decl)
                 }
@@ -404,7 +407,12 @@ pub(crate) fn generate_workspace_bindings(mode: Mode) -> Result<()> {
     )
     .build();
 
-    let formatted = format_node(JsFormatOptions::new(JsFileSource::ts()), module.syntax()).unwrap();
+    let formatted = format_node(
+        JsFormatOptions::new(JsFileSource::ts()),
+        module.syntax(),
+        false,
+    )
+    .unwrap();
     let printed = formatted.print().unwrap();
     let code = printed.into_code();
 
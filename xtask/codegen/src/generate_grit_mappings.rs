@@ -149,6 +149,21 @@ impl LanguageConfig {
                 syntax_module: "biome_css_syntax",
                 legacy_patterns: &[],
             },
+            LanguageKind::Graphql => Self {
+                syntax_kind_type: "GraphqlSyntaxKind",
+                syntax_module: "biome_graphql_syntax",
+                legacy_patterns: &[],
+            },
+            LanguageKind::Html => Self {
+                syntax_kind_type: "HtmlSyntaxKind",
+                syntax_module: "biome_html_syntax",
+                legacy_patterns: &[],
+            },
+            LanguageKind::Json => Self {
+                syntax_kind_type: "JsonSyntaxKind",
+                syntax_module: "biome_json_syntax",
+                legacy_patterns: &[],
+            },
             _ => unimplemented!("Grit mappings are not supported for {:?}", language_kind),
         }
     }
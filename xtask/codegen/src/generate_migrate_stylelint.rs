@@ -0,0 +1,102 @@
+use biome_analyze::{
+    GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup, RuleMetadata,
+    RuleSourceKind, RuleSourceWithKind,
+};
+use biome_rowan::syntax::Language;
+use biome_string_case::Case;
+use quote::{format_ident, quote};
+use std::collections::BTreeMap;
+use xtask_codegen::update;
+use xtask_glue::*;
+
+pub(crate) fn generate_migrate_stylelint(mode: Mode) -> Result<()> {
+    let mut visitor = StylelintLintRulesVisitor::default();
+    biome_css_analyze::visit_registry(&mut visitor);
+    let mut lines = Vec::with_capacity(visitor.0.len());
+    for ((stylelint_name, source_kind), (group_name, rule_metadata)) in visitor.0 {
+        let name = rule_metadata.name;
+        let name_ident = format_ident!("{}", Case::Snake.convert(name));
+        let group_ident = format_ident!("{group_name}");
+        let check_inspired = if source_kind.is_inspired() {
+            quote! {
+                if !options.include_inspired {
+                    results.add(stylelint_name, eslint_to_biome::RuleMigrationResult::Inspired);
+                    return false;
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let check_nursery = if group_name == "nursery" {
+            quote! {
+                if !options.include_nursery {
+                    results.add(stylelint_name, eslint_to_biome::RuleMigrationResult::Nursery);
+                    return false;
+                }
+            }
+        } else {
+            quote! {}
+        };
+        lines.push(quote! {
+            #stylelint_name => {
+                #check_inspired
+                #check_nursery
+                let group = rules.#group_ident.get_or_insert_with(Default::default);
+                let rule = group.unwrap_group_as_mut().#name_ident.get_or_insert(Default::default());
+                rule.set_level(rule.level().max(rule_severity));
+            }
+        });
+    }
+    let tokens = xtask_glue::reformat(quote! {
+        use super::{eslint_to_biome, stylelint_to_biome};
+        pub(crate) fn migrate_stylelint_any_rule(
+            rules: &mut biome_configuration::Rules,
+            stylelint_name: &str,
+            rule_severity: biome_configuration::RulePlainConfiguration,
+            options: &eslint_to_biome::MigrationOptions,
+            results: &mut stylelint_to_biome::MigrationResults,
+        ) -> bool {
+            match stylelint_name {
+                #( #lines )*
+                _ => {
+                    results.add(stylelint_name, eslint_to_biome::RuleMigrationResult::Unsupported);
+                    return false;
+                }
+            }
+            results.add(stylelint_name, eslint_to_biome::RuleMigrationResult::Migrated);
+            true
+        }
+    });
+    let file_path =
+        project_root().join("crates/biome_cli/src/execute/migrate/stylelint_any_rule_to_biome.rs");
+    update(&file_path, &tokens?, &mode)?;
+    Ok(())
+}
+
+#[derive(Default)]
+struct StylelintLintRulesVisitor(
+    BTreeMap<(&'static str, RuleSourceKind), (&'static str, RuleMetadata)>,
+);
+
+impl<L: Language> RegistryVisitor<L> for StylelintLintRulesVisitor {
+    fn record_category<C: GroupCategory<Language = L>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R: Rule + 'static>(&mut self)
+    where
+        R::Query: Queryable<Language = L>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        for RuleSourceWithKind { kind, source } in R::METADATA.sources {
+            if source.is_stylelint() {
+                self.0.insert(
+                    (source.as_rule_name(), *kind),
+                    (<R::Group as RuleGroup>::NAME, R::METADATA),
+                );
+            }
+        }
+    }
+}
@@ -35,18 +35,36 @@ pub fn generate_target_language_constants(
 
     let syntax_kind = match language_kind {
         LanguageKind::Css => "CssSyntaxKind",
+        LanguageKind::Graphql => "GraphqlSyntaxKind",
+        LanguageKind::Html => "HtmlSyntaxKind",
         LanguageKind::Js => "JsSyntaxKind",
+        LanguageKind::Json => "JsonSyntaxKind",
         _ => unimplemented!(),
     };
     let syntax_kind_module = match language_kind {
         LanguageKind::Css => "biome_css_syntax",
+        LanguageKind::Graphql => "biome_graphql_syntax",
+        LanguageKind::Html => "biome_html_syntax",
         LanguageKind::Js => "biome_js_syntax",
+        LanguageKind::Json => "biome_json_syntax",
         _ => unimplemented!(),
     };
 
+    // Glob imports would be unused if there are no disregarded slots.
+    let imports = if disregarded_slots.is_empty() {
+        format!(
+            "use crate::grit_target_language::DisregardedSlotCondition;
+use {syntax_kind_module}::{syntax_kind};"
+        )
+    } else {
+        format!(
+            "use crate::grit_target_language::DisregardedSlotCondition::{{self, *}};
+use {syntax_kind_module}::{syntax_kind}::{{self, *}};"
+        )
+    };
+
     let result = format!(
-        "use crate::grit_target_language::DisregardedSlotCondition::{{self, *}};
-use {syntax_kind_module}::{syntax_kind}::{{self, *}};
+        "{imports}
 
 pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[({syntax_kind}, u32, DisregardedSlotCondition)] = &[
     {disregarded_slots}
@@ -69,6 +69,7 @@ pub const GRAPHQL_KINDS_SRC: KindsSrc = KindsSrc {
         "IDENT",
         "COMMENT",
         "COMMA",
+        "GRIT_METAVARIABLE",
     ],
     nodes: &[
         "GRAPHQL_ROOT",
@@ -147,6 +148,7 @@ pub const GRAPHQL_KINDS_SRC: KindsSrc = KindsSrc {
         "GRAPHQL_INT_VALUE",
         "GRAPHQL_BOOLEAN_VALUE",
         "GRAPHQL_NULL_VALUE",
+        "GRAPHQL_METAVARIABLE",
         // Bogus nodes
         "GRAPHQL_BOGUS",
         "GRAPHQL_BOGUS_DEFINITION",
@@ -54,7 +54,13 @@ pub const HTML_KINDS_SRC: KindsSrc = KindsSrc {
         "snippet",
     ],
     literals: &["HTML_STRING_LITERAL", "HTML_LITERAL"],
-    tokens: &["ERROR_TOKEN", "NEWLINE", "WHITESPACE", "IDENT"],
+    tokens: &[
+        "ERROR_TOKEN",
+        "NEWLINE",
+        "WHITESPACE",
+        "IDENT",
+        "GRIT_METAVARIABLE",
+    ],
     nodes: &[
         "HTML_ROOT",
         "HTML_DIRECTIVE",
@@ -79,6 +85,7 @@ pub const HTML_KINDS_SRC: KindsSrc = KindsSrc {
         "HTML_TEXT_EXPRESSION",
         "HTML_ATTRIBUTE_DOUBLE_TEXT_EXPRESSION",
         "HTML_ATTRIBUTE_SINGLE_TEXT_EXPRESSION",
+        "HTML_METAVARIABLE",
         // Astro nodes
         "ASTRO_FRONTMATTER_ELEMENT",
         "ASTRO_EMBEDDED_CONTENT",
@@ -147,6 +147,7 @@ pub const JS_KINDS_SRC: KindsSrc = KindsSrc {
         "source",
         "symbol",
         "type",
+        "opaque",
         "undefined",
         "unique",
         "unknown",
@@ -407,6 +408,7 @@ pub const JS_KINDS_SRC: KindsSrc = KindsSrc {
         "TS_MAPPED_TYPE_READONLY_MODIFIER_CLAUSE",
         "TS_MAPPED_TYPE_AS_CLAUSE",
         "TS_TYPE_ALIAS_DECLARATION",
+        "TS_OPAQUE_TYPE_ALIAS_DECLARATION",
         "TS_MODULE_DECLARATION",
         "TS_GLOBAL_DECLARATION",
         "TS_QUALIFIED_MODULE_NAME",
@@ -420,6 +422,7 @@ pub const JS_KINDS_SRC: KindsSrc = KindsSrc {
         "TS_INTERSECTION_TYPE",
         "TS_INTERSECTION_TYPE_ELEMENT_LIST",
         "TS_OBJECT_TYPE",
+        "TS_EXACT_OBJECT_TYPE",
         "TS_TYPE_MEMBER_LIST",
         "TS_INTERFACE_DECLARATION",
         "TS_EXTENDS_CLAUSE",
@@ -443,6 +446,7 @@ pub const JS_KINDS_SRC: KindsSrc = KindsSrc {
         "TS_OPTIONAL_TUPLE_TYPE_ELEMENT",
         "TS_NAMED_TUPLE_TYPE_ELEMENT",
         "TS_TYPE_OPERATOR_TYPE",
+        "TS_MAYBE_TYPE",
         "TS_INFER_TYPE",
         "TS_CONSTRUCTOR_TYPE",
         "TS_FUNCTION_TYPE",
@@ -659,6 +663,7 @@ impl Field {
                     (">>=", _) => "left_shift_assign",
                     ("<<=", _) => "right_shift_assign",
                     (">>>=", _) => "unsigned_right_shift_assign",
+                    ("~", LanguageKind::Css) => "tilde",
                     ("~", _) => "bitwise_not",
                     ("&=", _) => "bitwise_and_assign",
                     ("|=", LanguageKind::Js) => "bitwise_or_assign",
@@ -689,7 +694,7 @@ impl Field {
                     ("||", _) => "logical_or",
                     ("&&", _) => "logical_and",
                     ("$=", _) => "suffix",
-                    ("$", LanguageKind::Graphql) => "dollar",
+                    ("$", LanguageKind::Graphql | LanguageKind::Css) => "dollar",
                     ("~=", _) => "whitespace_like",
                     (",", _) => "comma",
                     ("---", LanguageKind::Yaml) => "dashdashdash",
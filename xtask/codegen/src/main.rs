@@ -6,6 +6,8 @@ mod generate_configuration;
 mod generate_license;
 #[cfg(feature = "configuration")]
 mod generate_migrate_eslint;
+#[cfg(feature = "configuration")]
+mod generate_migrate_stylelint;
 mod move_rule;
 use xtask_glue::{Result, project_root, pushd};
 
@@ -20,6 +22,8 @@ use crate::generate_configuration::generate_rules_configuration;
 use crate::generate_license::generate_license;
 #[cfg(feature = "configuration")]
 use crate::generate_migrate_eslint::generate_migrate_eslint;
+#[cfg(feature = "configuration")]
+use crate::generate_migrate_stylelint::generate_migrate_stylelint;
 use crate::move_rule::move_rule;
 
 use xtask_codegen::{
@@ -49,6 +53,10 @@ fn main() -> Result<()> {
             #[cfg(feature = "configuration")]
             generate_migrate_eslint(Overwrite)?;
         }
+        TaskCommand::MigrateStylelint => {
+            #[cfg(feature = "configuration")]
+            generate_migrate_stylelint(Overwrite)?;
+        }
         TaskCommand::Schema => {
             #[cfg(feature = "schema")]
             xtask_codegen::generate_schema::generate_configuration_schema(Overwrite)?;